@@ -3,6 +3,8 @@
 
 #![allow(unreachable_patterns)]
 
+use crate::AttributeType;
+
 /// S-57 Attribute with code and name
 ///
 /// Complete catalogue of IHO S-57 attributes from GDAL reference.
@@ -11,6 +13,7 @@ pub struct AttributeInfo {
     pub code: u16,
     pub acronym: &'static str,
     pub name: &'static str,
+    pub attribute_type: AttributeType,
 }
 
 impl AttributeInfo {
@@ -21,2401 +24,2881 @@ impl AttributeInfo {
                 code: 1,
                 acronym: "AGENCY",
                 name: "Agency responsible for production",
+                attribute_type: AttributeType::CodedString,
             }),
             2 => Some(Self {
                 code: 2,
                 acronym: "BCNSHP",
                 name: "Beacon shape",
+                attribute_type: AttributeType::Enumerated,
             }),
             3 => Some(Self {
                 code: 3,
                 acronym: "BUISHP",
                 name: "Building shape",
+                attribute_type: AttributeType::Enumerated,
             }),
             4 => Some(Self {
                 code: 4,
                 acronym: "BOYSHP",
                 name: "Buoy shape",
+                attribute_type: AttributeType::Enumerated,
             }),
             5 => Some(Self {
                 code: 5,
                 acronym: "BURDEP",
                 name: "Buried depth",
+                attribute_type: AttributeType::Float,
             }),
             6 => Some(Self {
                 code: 6,
                 acronym: "CALSGN",
                 name: "Call sign",
+                attribute_type: AttributeType::FreeText,
             }),
             7 => Some(Self {
                 code: 7,
                 acronym: "CATAIR",
                 name: "Category of airport/airfield",
+                attribute_type: AttributeType::List,
             }),
             8 => Some(Self {
                 code: 8,
                 acronym: "CATACH",
                 name: "Category of anchorage",
+                attribute_type: AttributeType::List,
             }),
             9 => Some(Self {
                 code: 9,
                 acronym: "CATBRG",
                 name: "Category of bridge",
+                attribute_type: AttributeType::List,
             }),
             10 => Some(Self {
                 code: 10,
                 acronym: "CATBUA",
                 name: "Category of built-up area",
+                attribute_type: AttributeType::Enumerated,
             }),
             11 => Some(Self {
                 code: 11,
                 acronym: "CATCBL",
                 name: "Category of cable",
+                attribute_type: AttributeType::Enumerated,
             }),
             12 => Some(Self {
                 code: 12,
                 acronym: "CATCAN",
                 name: "Category of canal",
+                attribute_type: AttributeType::Enumerated,
             }),
             13 => Some(Self {
                 code: 13,
                 acronym: "CATCAM",
                 name: "Category of cardinal mark",
+                attribute_type: AttributeType::Enumerated,
             }),
             14 => Some(Self {
                 code: 14,
                 acronym: "CATCHP",
                 name: "Category of checkpoint",
+                attribute_type: AttributeType::Enumerated,
             }),
             15 => Some(Self {
                 code: 15,
                 acronym: "CATCOA",
                 name: "Category of coastline",
+                attribute_type: AttributeType::Enumerated,
             }),
             16 => Some(Self {
                 code: 16,
                 acronym: "CATCTR",
                 name: "Category of control point",
+                attribute_type: AttributeType::Enumerated,
             }),
             17 => Some(Self {
                 code: 17,
                 acronym: "CATCON",
                 name: "Category of conveyor",
+                attribute_type: AttributeType::Enumerated,
             }),
             18 => Some(Self {
                 code: 18,
                 acronym: "CATCOV",
                 name: "Category of coverage",
+                attribute_type: AttributeType::Enumerated,
             }),
             19 => Some(Self {
                 code: 19,
                 acronym: "CATCRN",
                 name: "Category of crane",
+                attribute_type: AttributeType::Enumerated,
             }),
             20 => Some(Self {
                 code: 20,
                 acronym: "CATDAM",
                 name: "Category of dam",
+                attribute_type: AttributeType::Enumerated,
             }),
             21 => Some(Self {
                 code: 21,
                 acronym: "CATDIS",
                 name: "Category of distance mark",
+                attribute_type: AttributeType::Enumerated,
             }),
             22 => Some(Self {
                 code: 22,
                 acronym: "CATDOC",
                 name: "Category of dock",
+                attribute_type: AttributeType::Enumerated,
             }),
             23 => Some(Self {
                 code: 23,
                 acronym: "CATDPG",
                 name: "Category of dumping ground",
+                attribute_type: AttributeType::List,
             }),
             24 => Some(Self {
                 code: 24,
                 acronym: "CATFNC",
                 name: "Category of fence/wall",
+                attribute_type: AttributeType::Enumerated,
             }),
             25 => Some(Self {
                 code: 25,
                 acronym: "CATFRY",
                 name: "Category of ferry",
+                attribute_type: AttributeType::Enumerated,
             }),
             26 => Some(Self {
                 code: 26,
                 acronym: "CATFIF",
                 name: "Category of fishing  facility",
+                attribute_type: AttributeType::Enumerated,
             }),
             27 => Some(Self {
                 code: 27,
                 acronym: "CATFOG",
                 name: "Category of fog signal",
+                attribute_type: AttributeType::Enumerated,
             }),
             28 => Some(Self {
                 code: 28,
                 acronym: "CATFOR",
                 name: "Category of fortified structure",
+                attribute_type: AttributeType::Enumerated,
             }),
             29 => Some(Self {
                 code: 29,
                 acronym: "CATGAT",
                 name: "Category of gate",
+                attribute_type: AttributeType::Enumerated,
             }),
             30 => Some(Self {
                 code: 30,
                 acronym: "CATHAF",
                 name: "Category of harbour facility",
+                attribute_type: AttributeType::List,
             }),
             31 => Some(Self {
                 code: 31,
                 acronym: "CATHLK",
                 name: "Category of hulk",
+                attribute_type: AttributeType::List,
             }),
             32 => Some(Self {
                 code: 32,
                 acronym: "CATICE",
                 name: "Category of ice",
+                attribute_type: AttributeType::Enumerated,
             }),
             33 => Some(Self {
                 code: 33,
                 acronym: "CATINB",
                 name: "Category of installation buoy",
+                attribute_type: AttributeType::Enumerated,
             }),
             34 => Some(Self {
                 code: 34,
                 acronym: "CATLND",
                 name: "Category of land region",
+                attribute_type: AttributeType::List,
             }),
             35 => Some(Self {
                 code: 35,
                 acronym: "CATLMK",
                 name: "Category of landmark",
+                attribute_type: AttributeType::List,
             }),
             36 => Some(Self {
                 code: 36,
                 acronym: "CATLAM",
                 name: "Category of lateral mark",
+                attribute_type: AttributeType::Enumerated,
             }),
             37 => Some(Self {
                 code: 37,
                 acronym: "CATLIT",
                 name: "Category of light",
+                attribute_type: AttributeType::List,
             }),
             38 => Some(Self {
                 code: 38,
                 acronym: "CATMFA",
                 name: "Category of marine farm/culture",
+                attribute_type: AttributeType::Enumerated,
             }),
             39 => Some(Self {
                 code: 39,
                 acronym: "CATMPA",
                 name: "Category of military practice area",
+                attribute_type: AttributeType::List,
             }),
             40 => Some(Self {
                 code: 40,
                 acronym: "CATMOR",
                 name: "Category of mooring/warping facility",
+                attribute_type: AttributeType::Enumerated,
             }),
             41 => Some(Self {
                 code: 41,
                 acronym: "CATNAV",
                 name: "Category of navigation line",
+                attribute_type: AttributeType::Enumerated,
             }),
             42 => Some(Self {
                 code: 42,
                 acronym: "CATOBS",
                 name: "Category of obstruction",
+                attribute_type: AttributeType::Enumerated,
             }),
             43 => Some(Self {
                 code: 43,
                 acronym: "CATOFP",
                 name: "Category of offshore platform",
+                attribute_type: AttributeType::List,
             }),
             44 => Some(Self {
                 code: 44,
                 acronym: "CATOLB",
                 name: "Category of oil barrier",
+                attribute_type: AttributeType::Enumerated,
             }),
             45 => Some(Self {
                 code: 45,
                 acronym: "CATPLE",
                 name: "Category of pile",
+                attribute_type: AttributeType::Enumerated,
             }),
             46 => Some(Self {
                 code: 46,
                 acronym: "CATPIL",
                 name: "Category of pilot boarding place",
+                attribute_type: AttributeType::Enumerated,
             }),
             47 => Some(Self {
                 code: 47,
                 acronym: "CATPIP",
                 name: "Category of pipeline / pipe",
+                attribute_type: AttributeType::List,
             }),
             48 => Some(Self {
                 code: 48,
                 acronym: "CATPRA",
                 name: "Category of production area",
+                attribute_type: AttributeType::Enumerated,
             }),
             49 => Some(Self {
                 code: 49,
                 acronym: "CATPYL",
                 name: "Category of pylon",
+                attribute_type: AttributeType::Enumerated,
             }),
             50 => Some(Self {
                 code: 50,
                 acronym: "CATQUA",
                 name: "Category of quality of data",
+                attribute_type: AttributeType::Enumerated,
             }),
             51 => Some(Self {
                 code: 51,
                 acronym: "CATRAS",
                 name: "Category of radar station",
+                attribute_type: AttributeType::Enumerated,
             }),
             52 => Some(Self {
                 code: 52,
                 acronym: "CATRTB",
                 name: "Category of radar transponder beacon",
+                attribute_type: AttributeType::Enumerated,
             }),
             53 => Some(Self {
                 code: 53,
                 acronym: "CATROS",
                 name: "Category of radio station",
+                attribute_type: AttributeType::List,
             }),
             54 => Some(Self {
                 code: 54,
                 acronym: "CATTRK",
                 name: "Category of recommended track",
+                attribute_type: AttributeType::Enumerated,
             }),
             55 => Some(Self {
                 code: 55,
                 acronym: "CATRSC",
                 name: "Category of rescue station",
+                attribute_type: AttributeType::List,
             }),
             56 => Some(Self {
                 code: 56,
                 acronym: "CATREA",
                 name: "Category of restricted area",
+                attribute_type: AttributeType::List,
             }),
             57 => Some(Self {
                 code: 57,
                 acronym: "CATROD",
                 name: "Category of road",
+                attribute_type: AttributeType::Enumerated,
             }),
             58 => Some(Self {
                 code: 58,
                 acronym: "CATRUN",
                 name: "Category of runway",
+                attribute_type: AttributeType::Enumerated,
             }),
             59 => Some(Self {
                 code: 59,
                 acronym: "CATSEA",
                 name: "Category of sea area",
+                attribute_type: AttributeType::Enumerated,
             }),
             60 => Some(Self {
                 code: 60,
                 acronym: "CATSLC",
                 name: "Category of shoreline construction",
+                attribute_type: AttributeType::Enumerated,
             }),
             61 => Some(Self {
                 code: 61,
                 acronym: "CATSIT",
                 name: "Category of signal station, traffic",
+                attribute_type: AttributeType::List,
             }),
             62 => Some(Self {
                 code: 62,
                 acronym: "CATSIW",
                 name: "Category of signal station, warning",
+                attribute_type: AttributeType::List,
             }),
             63 => Some(Self {
                 code: 63,
                 acronym: "CATSIL",
                 name: "Category of silo/tank",
+                attribute_type: AttributeType::Enumerated,
             }),
             64 => Some(Self {
                 code: 64,
                 acronym: "CATSLO",
                 name: "Category of slope",
+                attribute_type: AttributeType::Enumerated,
             }),
             65 => Some(Self {
                 code: 65,
                 acronym: "CATSCF",
                 name: "Category of small craft facility",
+                attribute_type: AttributeType::List,
             }),
             66 => Some(Self {
                 code: 66,
                 acronym: "CATSPM",
                 name: "Category of special purpose mark",
+                attribute_type: AttributeType::List,
             }),
             67 => Some(Self {
                 code: 67,
                 acronym: "CATTSS",
                 name: "Category of Traffic Separation Scheme",
+                attribute_type: AttributeType::Enumerated,
             }),
             68 => Some(Self {
                 code: 68,
                 acronym: "CATVEG",
                 name: "Category of vegetation",
+                attribute_type: AttributeType::List,
             }),
             69 => Some(Self {
                 code: 69,
                 acronym: "CATWAT",
                 name: "Category of water turbulence",
+                attribute_type: AttributeType::Enumerated,
             }),
             70 => Some(Self {
                 code: 70,
                 acronym: "CATWED",
                 name: "Category of weed/kelp",
+                attribute_type: AttributeType::Enumerated,
             }),
             71 => Some(Self {
                 code: 71,
                 acronym: "CATWRK",
                 name: "Category of wreck",
+                attribute_type: AttributeType::Enumerated,
             }),
             72 => Some(Self {
                 code: 72,
                 acronym: "CATZOC",
                 name: "Category of zone of confidence data",
+                attribute_type: AttributeType::Enumerated,
             }),
             73 => Some(Self {
                 code: 73,
                 acronym: "$SPACE",
                 name: "Character spacing",
+                attribute_type: AttributeType::Enumerated,
             }),
             74 => Some(Self {
                 code: 74,
                 acronym: "$CHARS",
                 name: "Character specification",
+                attribute_type: AttributeType::CodedString,
             }),
             75 => Some(Self {
                 code: 75,
                 acronym: "COLOUR",
                 name: "Colour",
+                attribute_type: AttributeType::List,
             }),
             76 => Some(Self {
                 code: 76,
                 acronym: "COLPAT",
                 name: "Colour pattern",
+                attribute_type: AttributeType::List,
             }),
             77 => Some(Self {
                 code: 77,
                 acronym: "COMCHA",
                 name: "Communication channel",
+                attribute_type: AttributeType::CodedString,
             }),
             78 => Some(Self {
                 code: 78,
                 acronym: "$CSIZE",
                 name: "Compass size",
+                attribute_type: AttributeType::Float,
             }),
             79 => Some(Self {
                 code: 79,
                 acronym: "CPDATE",
                 name: "Compilation date",
+                attribute_type: AttributeType::CodedString,
             }),
             80 => Some(Self {
                 code: 80,
                 acronym: "CSCALE",
                 name: "Compilation scale",
+                attribute_type: AttributeType::Integer,
             }),
             81 => Some(Self {
                 code: 81,
                 acronym: "CONDTN",
                 name: "Condition",
+                attribute_type: AttributeType::Enumerated,
             }),
             82 => Some(Self {
                 code: 82,
                 acronym: "CONRAD",
                 name: "Conspicuous, Radar",
+                attribute_type: AttributeType::Enumerated,
             }),
             83 => Some(Self {
                 code: 83,
                 acronym: "CONVIS",
                 name: "Conspicuous, visual",
+                attribute_type: AttributeType::Enumerated,
             }),
             84 => Some(Self {
                 code: 84,
                 acronym: "CURVEL",
                 name: "Current velocity",
+                attribute_type: AttributeType::Float,
             }),
             85 => Some(Self {
                 code: 85,
                 acronym: "DATEND",
                 name: "Date end",
+                attribute_type: AttributeType::CodedString,
             }),
             86 => Some(Self {
                 code: 86,
                 acronym: "DATSTA",
                 name: "Date start",
+                attribute_type: AttributeType::CodedString,
             }),
             87 => Some(Self {
                 code: 87,
                 acronym: "DRVAL1",
                 name: "Depth range value 1",
+                attribute_type: AttributeType::Float,
             }),
             88 => Some(Self {
                 code: 88,
                 acronym: "DRVAL2",
                 name: "Depth range value 2",
+                attribute_type: AttributeType::Float,
             }),
             89 => Some(Self {
                 code: 89,
                 acronym: "DUNITS",
                 name: "Depth units",
+                attribute_type: AttributeType::Enumerated,
             }),
             90 => Some(Self {
                 code: 90,
                 acronym: "ELEVAT",
                 name: "Elevation",
+                attribute_type: AttributeType::Float,
             }),
             91 => Some(Self {
                 code: 91,
                 acronym: "ESTRNG",
                 name: "Estimated range of transmission",
+                attribute_type: AttributeType::Float,
             }),
             92 => Some(Self {
                 code: 92,
                 acronym: "EXCLIT",
                 name: "Exhibition condition of light",
+                attribute_type: AttributeType::Enumerated,
             }),
             93 => Some(Self {
                 code: 93,
                 acronym: "EXPSOU",
                 name: "Exposition of sounding",
+                attribute_type: AttributeType::Enumerated,
             }),
             94 => Some(Self {
                 code: 94,
                 acronym: "FUNCTN",
                 name: "Function",
+                attribute_type: AttributeType::List,
             }),
             95 => Some(Self {
                 code: 95,
                 acronym: "HEIGHT",
                 name: "Height",
+                attribute_type: AttributeType::Float,
             }),
             96 => Some(Self {
                 code: 96,
                 acronym: "HUNITS",
                 name: "Height/length units",
+                attribute_type: AttributeType::Enumerated,
             }),
             97 => Some(Self {
                 code: 97,
                 acronym: "HORACC",
                 name: "Horizontal accuracy",
+                attribute_type: AttributeType::Float,
             }),
             98 => Some(Self {
                 code: 98,
                 acronym: "HORCLR",
                 name: "Horizontal clearance",
+                attribute_type: AttributeType::Float,
             }),
             99 => Some(Self {
                 code: 99,
                 acronym: "HORLEN",
                 name: "Horizontal length",
+                attribute_type: AttributeType::Float,
             }),
             100 => Some(Self {
                 code: 100,
                 acronym: "HORWID",
                 name: "Horizontal width",
+                attribute_type: AttributeType::Float,
             }),
             101 => Some(Self {
                 code: 101,
                 acronym: "ICEFAC",
                 name: "Ice factor",
+                attribute_type: AttributeType::Float,
             }),
             102 => Some(Self {
                 code: 102,
                 acronym: "INFORM",
                 name: "Information",
+                attribute_type: AttributeType::FreeText,
             }),
             103 => Some(Self {
                 code: 103,
                 acronym: "JRSDTN",
                 name: "Jurisdiction",
+                attribute_type: AttributeType::Enumerated,
             }),
             104 => Some(Self {
                 code: 104,
                 acronym: "$JUSTH",
                 name: "Justification - horizontal",
+                attribute_type: AttributeType::Enumerated,
             }),
             105 => Some(Self {
                 code: 105,
                 acronym: "$JUSTV",
                 name: "Justification - vertical",
+                attribute_type: AttributeType::Enumerated,
             }),
             106 => Some(Self {
                 code: 106,
                 acronym: "LIFCAP",
                 name: "Lifting capacity",
+                attribute_type: AttributeType::Float,
             }),
             107 => Some(Self {
                 code: 107,
                 acronym: "LITCHR",
                 name: "Light characteristic",
+                attribute_type: AttributeType::Enumerated,
             }),
             108 => Some(Self {
                 code: 108,
                 acronym: "LITVIS",
                 name: "Light visibility",
+                attribute_type: AttributeType::List,
             }),
             109 => Some(Self {
                 code: 109,
                 acronym: "MARSYS",
                 name: "Marks navigational - System of",
+                attribute_type: AttributeType::Enumerated,
             }),
             110 => Some(Self {
                 code: 110,
                 acronym: "MLTYLT",
                 name: "Multiplicity of lights",
+                attribute_type: AttributeType::Integer,
             }),
             111 => Some(Self {
                 code: 111,
                 acronym: "NATION",
                 name: "Nationality",
+                attribute_type: AttributeType::CodedString,
             }),
             112 => Some(Self {
                 code: 112,
                 acronym: "NATCON",
                 name: "Nature of construction",
+                attribute_type: AttributeType::List,
             }),
             113 => Some(Self {
                 code: 113,
                 acronym: "NATSUR",
                 name: "Nature of surface",
+                attribute_type: AttributeType::List,
             }),
             114 => Some(Self {
                 code: 114,
                 acronym: "NATQUA",
                 name: "Nature of surface - qualifying terms",
+                attribute_type: AttributeType::List,
             }),
             115 => Some(Self {
                 code: 115,
                 acronym: "NMDATE",
                 name: "Notice to Mariners date",
+                attribute_type: AttributeType::CodedString,
             }),
             116 => Some(Self {
                 code: 116,
                 acronym: "OBJNAM",
                 name: "Object name",
+                attribute_type: AttributeType::FreeText,
             }),
             117 => Some(Self {
                 code: 117,
                 acronym: "ORIENT",
                 name: "Orientation",
+                attribute_type: AttributeType::Float,
             }),
             118 => Some(Self {
                 code: 118,
                 acronym: "PEREND",
                 name: "Periodic date end",
+                attribute_type: AttributeType::CodedString,
             }),
             119 => Some(Self {
                 code: 119,
                 acronym: "PERSTA",
                 name: "Periodic date start",
+                attribute_type: AttributeType::CodedString,
             }),
             120 => Some(Self {
                 code: 120,
                 acronym: "PICREP",
                 name: "Pictorial representation",
+                attribute_type: AttributeType::FreeText,
             }),
             121 => Some(Self {
                 code: 121,
                 acronym: "PILDST",
                 name: "Pilot district",
+                attribute_type: AttributeType::FreeText,
             }),
             122 => Some(Self {
                 code: 122,
                 acronym: "PRCTRY",
                 name: "Producing country",
+                attribute_type: AttributeType::CodedString,
             }),
             123 => Some(Self {
                 code: 123,
                 acronym: "PRODCT",
                 name: "Product",
+                attribute_type: AttributeType::List,
             }),
             124 => Some(Self {
                 code: 124,
                 acronym: "PUBREF",
                 name: "Publication reference",
+                attribute_type: AttributeType::FreeText,
             }),
             125 => Some(Self {
                 code: 125,
                 acronym: "QUASOU",
                 name: "Quality of sounding measurement",
+                attribute_type: AttributeType::List,
             }),
             126 => Some(Self {
                 code: 126,
                 acronym: "RADWAL",
                 name: "Radar wave length",
+                attribute_type: AttributeType::CodedString,
             }),
             127 => Some(Self {
                 code: 127,
                 acronym: "RADIUS",
                 name: "Radius",
+                attribute_type: AttributeType::Float,
             }),
             128 => Some(Self {
                 code: 128,
                 acronym: "RECDAT",
                 name: "Recording date",
+                attribute_type: AttributeType::CodedString,
             }),
             129 => Some(Self {
                 code: 129,
                 acronym: "RECIND",
                 name: "Recording indication",
+                attribute_type: AttributeType::CodedString,
             }),
             130 => Some(Self {
                 code: 130,
                 acronym: "RYRMGV",
                 name: "Reference year for magnetic variation",
+                attribute_type: AttributeType::CodedString,
             }),
             131 => Some(Self {
                 code: 131,
                 acronym: "RESTRN",
                 name: "Restriction",
+                attribute_type: AttributeType::List,
             }),
             132 => Some(Self {
                 code: 132,
                 acronym: "SCAMAX",
                 name: "Scale maximum",
+                attribute_type: AttributeType::Integer,
             }),
             133 => Some(Self {
                 code: 133,
                 acronym: "SCAMIN",
                 name: "Scale minimum",
+                attribute_type: AttributeType::Integer,
             }),
             134 => Some(Self {
                 code: 134,
                 acronym: "SCVAL1",
                 name: "Scale value one",
+                attribute_type: AttributeType::Integer,
             }),
             135 => Some(Self {
                 code: 135,
                 acronym: "SCVAL2",
                 name: "Scale value two",
+                attribute_type: AttributeType::Integer,
             }),
             136 => Some(Self {
                 code: 136,
                 acronym: "SECTR1",
                 name: "Sector limit one",
+                attribute_type: AttributeType::Float,
             }),
             137 => Some(Self {
                 code: 137,
                 acronym: "SECTR2",
                 name: "Sector limit two",
+                attribute_type: AttributeType::Float,
             }),
             138 => Some(Self {
                 code: 138,
                 acronym: "SHIPAM",
                 name: "Shift parameters",
+                attribute_type: AttributeType::CodedString,
             }),
             139 => Some(Self {
                 code: 139,
                 acronym: "SIGFRQ",
                 name: "Signal frequency",
+                attribute_type: AttributeType::Integer,
             }),
             140 => Some(Self {
                 code: 140,
                 acronym: "SIGGEN",
                 name: "Signal generation",
+                attribute_type: AttributeType::Enumerated,
             }),
             141 => Some(Self {
                 code: 141,
                 acronym: "SIGGRP",
                 name: "Signal group",
+                attribute_type: AttributeType::CodedString,
             }),
             142 => Some(Self {
                 code: 142,
                 acronym: "SIGPER",
                 name: "Signal period",
+                attribute_type: AttributeType::Float,
             }),
             143 => Some(Self {
                 code: 143,
                 acronym: "SIGSEQ",
                 name: "Signal sequence",
+                attribute_type: AttributeType::CodedString,
             }),
             144 => Some(Self {
                 code: 144,
                 acronym: "SOUACC",
                 name: "Sounding accuracy",
+                attribute_type: AttributeType::Float,
             }),
             145 => Some(Self {
                 code: 145,
                 acronym: "SDISMX",
                 name: "Sounding distance - maximum",
+                attribute_type: AttributeType::Integer,
             }),
             146 => Some(Self {
                 code: 146,
                 acronym: "SDISMN",
                 name: "Sounding distance - minimum",
+                attribute_type: AttributeType::Integer,
             }),
             147 => Some(Self {
                 code: 147,
                 acronym: "SORDAT",
                 name: "Source date",
+                attribute_type: AttributeType::CodedString,
             }),
             148 => Some(Self {
                 code: 148,
                 acronym: "SORIND",
                 name: "Source indication",
+                attribute_type: AttributeType::CodedString,
             }),
             149 => Some(Self {
                 code: 149,
                 acronym: "STATUS",
                 name: "Status",
+                attribute_type: AttributeType::List,
             }),
             150 => Some(Self {
                 code: 150,
                 acronym: "SURATH",
                 name: "Survey authority",
+                attribute_type: AttributeType::FreeText,
             }),
             151 => Some(Self {
                 code: 151,
                 acronym: "SUREND",
                 name: "Survey date - end",
+                attribute_type: AttributeType::CodedString,
             }),
             152 => Some(Self {
                 code: 152,
                 acronym: "SURSTA",
                 name: "Survey date - start",
+                attribute_type: AttributeType::CodedString,
             }),
             153 => Some(Self {
                 code: 153,
                 acronym: "SURTYP",
                 name: "Survey type",
+                attribute_type: AttributeType::List,
             }),
             154 => Some(Self {
                 code: 154,
                 acronym: "$SCALE",
                 name: "Symbol scaling factor",
+                attribute_type: AttributeType::Float,
             }),
             155 => Some(Self {
                 code: 155,
                 acronym: "$SCODE",
                 name: "Symbolization code",
+                attribute_type: AttributeType::CodedString,
             }),
             156 => Some(Self {
                 code: 156,
                 acronym: "TECSOU",
                 name: "Technique of sounding measurement",
+                attribute_type: AttributeType::List,
             }),
             157 => Some(Self {
                 code: 157,
                 acronym: "$TXSTR",
                 name: "Text string",
+                attribute_type: AttributeType::FreeText,
             }),
             158 => Some(Self {
                 code: 158,
                 acronym: "TXTDSC",
                 name: "Textual description",
+                attribute_type: AttributeType::FreeText,
             }),
             159 => Some(Self {
                 code: 159,
                 acronym: "TS_TSP",
                 name: "Tidal stream - panel values",
+                attribute_type: AttributeType::CodedString,
             }),
             160 => Some(Self {
                 code: 160,
                 acronym: "TS_TSV",
                 name: "Tidal stream, current - time series values",
+                attribute_type: AttributeType::CodedString,
             }),
             161 => Some(Self {
                 code: 161,
                 acronym: "T_ACWL",
                 name: "Tide - accuracy of water level",
+                attribute_type: AttributeType::Enumerated,
             }),
             162 => Some(Self {
                 code: 162,
                 acronym: "T_HWLW",
                 name: "Tide - high and low water values",
+                attribute_type: AttributeType::CodedString,
             }),
             163 => Some(Self {
                 code: 163,
                 acronym: "T_MTOD",
                 name: "Tide - method of tidal prediction",
+                attribute_type: AttributeType::Enumerated,
             }),
             164 => Some(Self {
                 code: 164,
                 acronym: "T_THDF",
                 name: "Tide - time and height differences",
+                attribute_type: AttributeType::CodedString,
             }),
             165 => Some(Self {
                 code: 165,
                 acronym: "T_TINT",
                 name: "Tide, current - time interval of values",
+                attribute_type: AttributeType::Integer,
             }),
             166 => Some(Self {
                 code: 166,
                 acronym: "T_TSVL",
                 name: "Tide - time series values",
+                attribute_type: AttributeType::CodedString,
             }),
             167 => Some(Self {
                 code: 167,
                 acronym: "T_VAHC",
                 name: "Tide - value of harmonic constituents",
+                attribute_type: AttributeType::CodedString,
             }),
             168 => Some(Self {
                 code: 168,
                 acronym: "TIMEND",
                 name: "Time end",
+                attribute_type: AttributeType::CodedString,
             }),
             169 => Some(Self {
                 code: 169,
                 acronym: "TIMSTA",
                 name: "Time start",
+                attribute_type: AttributeType::CodedString,
             }),
             170 => Some(Self {
                 code: 170,
                 acronym: "$TINTS",
                 name: "Tint",
+                attribute_type: AttributeType::Enumerated,
             }),
             171 => Some(Self {
                 code: 171,
                 acronym: "TOPSHP",
                 name: "Topmark/daymark shape",
+                attribute_type: AttributeType::Enumerated,
             }),
             172 => Some(Self {
                 code: 172,
                 acronym: "TRAFIC",
                 name: "Traffic flow",
+                attribute_type: AttributeType::Enumerated,
             }),
             173 => Some(Self {
                 code: 173,
                 acronym: "VALACM",
                 name: "Value of annual change in magnetic variation",
+                attribute_type: AttributeType::Float,
             }),
             174 => Some(Self {
                 code: 174,
                 acronym: "VALDCO",
                 name: "Value of depth contour",
+                attribute_type: AttributeType::Float,
             }),
             175 => Some(Self {
                 code: 175,
                 acronym: "VALLMA",
                 name: "Value of local magnetic anomaly",
+                attribute_type: AttributeType::Float,
             }),
             176 => Some(Self {
                 code: 176,
                 acronym: "VALMAG",
                 name: "Value of magnetic variation",
+                attribute_type: AttributeType::Float,
             }),
             177 => Some(Self {
                 code: 177,
                 acronym: "VALMXR",
                 name: "Value of maximum range",
+                attribute_type: AttributeType::Float,
             }),
             178 => Some(Self {
                 code: 178,
                 acronym: "VALNMR",
                 name: "Value of nominal range",
+                attribute_type: AttributeType::Float,
             }),
             179 => Some(Self {
                 code: 179,
                 acronym: "VALSOU",
                 name: "Value of sounding",
+                attribute_type: AttributeType::Float,
             }),
             180 => Some(Self {
                 code: 180,
                 acronym: "VERACC",
                 name: "Vertical accuracy",
+                attribute_type: AttributeType::Float,
             }),
             181 => Some(Self {
                 code: 181,
                 acronym: "VERCLR",
                 name: "Vertical clearance",
+                attribute_type: AttributeType::Float,
             }),
             182 => Some(Self {
                 code: 182,
                 acronym: "VERCCL",
                 name: "Vertical clearance, closed",
+                attribute_type: AttributeType::Float,
             }),
             183 => Some(Self {
                 code: 183,
                 acronym: "VERCOP",
                 name: "Vertical clearance, open",
+                attribute_type: AttributeType::Float,
             }),
             184 => Some(Self {
                 code: 184,
                 acronym: "VERCSA",
                 name: "Vertical clearance, safe",
+                attribute_type: AttributeType::Float,
             }),
             185 => Some(Self {
                 code: 185,
                 acronym: "VERDAT",
                 name: "Vertical datum",
+                attribute_type: AttributeType::Enumerated,
             }),
             186 => Some(Self {
                 code: 186,
                 acronym: "VERLEN",
                 name: "Vertical length",
+                attribute_type: AttributeType::Float,
             }),
             187 => Some(Self {
                 code: 187,
                 acronym: "WATLEV",
                 name: "Water level effect",
+                attribute_type: AttributeType::Enumerated,
             }),
             188 => Some(Self {
                 code: 188,
                 acronym: "CAT_TS",
                 name: "Category of Tidal stream",
+                attribute_type: AttributeType::Enumerated,
             }),
             189 => Some(Self {
                 code: 189,
                 acronym: "PUNITS",
                 name: "Positional accuracy units",
+                attribute_type: AttributeType::Enumerated,
             }),
             190 => Some(Self {
                 code: 190,
                 acronym: "CLSDEF",
                 name: "Object class definition",
+                attribute_type: AttributeType::FreeText,
             }),
             191 => Some(Self {
                 code: 191,
                 acronym: "CLSNAM",
                 name: "Object class name",
+                attribute_type: AttributeType::FreeText,
             }),
             192 => Some(Self {
                 code: 192,
                 acronym: "SYMINS",
                 name: "Symbol instruction",
+                attribute_type: AttributeType::FreeText,
             }),
             300 => Some(Self {
                 code: 300,
                 acronym: "NINFOM",
                 name: "Information in national language",
+                attribute_type: AttributeType::FreeText,
             }),
             301 => Some(Self {
                 code: 301,
                 acronym: "NOBJNM",
                 name: "Object name in national language",
+                attribute_type: AttributeType::FreeText,
             }),
             302 => Some(Self {
                 code: 302,
                 acronym: "NPLDST",
                 name: "Pilot district in national language",
+                attribute_type: AttributeType::FreeText,
             }),
             303 => Some(Self {
                 code: 303,
                 acronym: "$NTXST",
                 name: "Text string in national language",
+                attribute_type: AttributeType::FreeText,
             }),
             304 => Some(Self {
                 code: 304,
                 acronym: "NTXTDS",
                 name: "Textual description in national language",
+                attribute_type: AttributeType::FreeText,
             }),
             400 => Some(Self {
                 code: 400,
                 acronym: "HORDAT",
                 name: "Horizontal datum",
+                attribute_type: AttributeType::Enumerated,
             }),
             401 => Some(Self {
                 code: 401,
                 acronym: "POSACC",
                 name: "Positional Accuracy",
+                attribute_type: AttributeType::Float,
             }),
             402 => Some(Self {
                 code: 402,
                 acronym: "QUAPOS",
                 name: "Quality of position",
+                attribute_type: AttributeType::Enumerated,
             }),
             17000 => Some(Self {
                 code: 17000,
                 acronym: "catach",
                 name: "Category of Anchorage area",
+                attribute_type: AttributeType::List,
             }),
             17001 => Some(Self {
                 code: 17001,
                 acronym: "catdis",
                 name: "Category of distance mark",
+                attribute_type: AttributeType::Enumerated,
             }),
             17002 => Some(Self {
                 code: 17002,
                 acronym: "catsit",
                 name: "Category of signal station trafficcatsit",
+                attribute_type: AttributeType::List,
             }),
             17003 => Some(Self {
                 code: 17003,
                 acronym: "catsiw",
                 name: "Category of signal station warning",
+                attribute_type: AttributeType::List,
             }),
             17004 => Some(Self {
                 code: 17004,
                 acronym: "restrn",
                 name: "Restriction",
+                attribute_type: AttributeType::List,
             }),
             17005 => Some(Self {
                 code: 17005,
                 acronym: "verdat",
                 name: "Vertical datum",
+                attribute_type: AttributeType::Enumerated,
             }),
             17006 => Some(Self {
                 code: 17006,
                 acronym: "catbrg",
                 name: "Category of bridge",
+                attribute_type: AttributeType::List,
             }),
             17007 => Some(Self {
                 code: 17007,
                 acronym: "catfry",
                 name: "Category of ferry",
+                attribute_type: AttributeType::List,
             }),
             17008 => Some(Self {
                 code: 17008,
                 acronym: "cathaf",
                 name: "Category of harbour facilities",
+                attribute_type: AttributeType::List,
             }),
             17009 => Some(Self {
                 code: 17009,
                 acronym: "marsys",
                 name: "Marks navigational  System of",
+                attribute_type: AttributeType::Enumerated,
             }),
             17050 => Some(Self {
                 code: 17050,
                 acronym: "addmrk",
                 name: "Additional mark",
+                attribute_type: AttributeType::List,
             }),
             17051 => Some(Self {
                 code: 17051,
                 acronym: "catbnk",
                 name: "Category of bank",
+                attribute_type: AttributeType::Enumerated,
             }),
             17052 => Some(Self {
                 code: 17052,
                 acronym: "catnmk",
                 name: "Category of notice mark",
+                attribute_type: AttributeType::Enumerated,
             }),
             17055 => Some(Self {
                 code: 17055,
                 acronym: "clsdng",
                 name: "Class of dangerous cargo",
+                attribute_type: AttributeType::Enumerated,
             }),
             17056 => Some(Self {
                 code: 17056,
                 acronym: "dirimp",
                 name: "Direction of impact",
+                attribute_type: AttributeType::List,
             }),
             17057 => Some(Self {
                 code: 17057,
                 acronym: "disbk1",
                 name: "Distance from bank",
+                attribute_type: AttributeType::Float,
             }),
             17058 => Some(Self {
                 code: 17058,
                 acronym: "disbk2",
                 name: "Distance from bank",
+                attribute_type: AttributeType::Float,
             }),
             17059 => Some(Self {
                 code: 17059,
                 acronym: "disipu",
                 name: "Distance of impact, upstream",
+                attribute_type: AttributeType::Float,
             }),
             17060 => Some(Self {
                 code: 17060,
                 acronym: "disipd",
                 name: "Distance of impact, downstream",
+                attribute_type: AttributeType::Float,
             }),
             17061 => Some(Self {
                 code: 17061,
                 acronym: "eleva1",
                 name: "Elevation 1",
+                attribute_type: AttributeType::Float,
             }),
             17062 => Some(Self {
                 code: 17062,
                 acronym: "eleva2",
                 name: "Elevation 2",
+                attribute_type: AttributeType::Float,
             }),
             17063 => Some(Self {
                 code: 17063,
                 acronym: "fnctnm",
                 name: "Function of notice mark",
+                attribute_type: AttributeType::Enumerated,
             }),
             17064 => Some(Self {
                 code: 17064,
                 acronym: "wtwdis",
                 name: "Waterway distance",
+                attribute_type: AttributeType::Float,
             }),
             17065 => Some(Self {
                 code: 17065,
                 acronym: "bunves",
                 name: "Bunker vessel",
+                attribute_type: AttributeType::Enumerated,
             }),
             17066 => Some(Self {
                 code: 17066,
                 acronym: "catbrt",
                 name: "Category of berth",
+                attribute_type: AttributeType::List,
             }),
             17067 => Some(Self {
                 code: 17067,
                 acronym: "catbun",
                 name: "Category of bunker",
+                attribute_type: AttributeType::List,
             }),
             17068 => Some(Self {
                 code: 17068,
                 acronym: "catccl",
                 name: "Category of CEMT class",
+                attribute_type: AttributeType::List,
             }),
             17069 => Some(Self {
                 code: 17069,
                 acronym: "catcom",
                 name: "Category of communication",
+                attribute_type: AttributeType::List,
             }),
             17070 => Some(Self {
                 code: 17070,
                 acronym: "cathbr",
                 name: "Category of harbour area",
+                attribute_type: AttributeType::List,
             }),
             17071 => Some(Self {
                 code: 17071,
                 acronym: "catrfd",
                 name: "Category of refuse dump",
+                attribute_type: AttributeType::List,
             }),
             17072 => Some(Self {
                 code: 17072,
                 acronym: "cattml",
                 name: "Category of terminal",
+                attribute_type: AttributeType::List,
             }),
             17073 => Some(Self {
                 code: 17073,
                 acronym: "comctn",
                 name: "Communication",
+                attribute_type: AttributeType::FreeText,
             }),
             17074 => Some(Self {
                 code: 17074,
                 acronym: "horcll",
                 name: "Horizontal clearance, length",
+                attribute_type: AttributeType::Float,
             }),
             17075 => Some(Self {
                 code: 17075,
                 acronym: "horclw",
                 name: "Horizontal clearance, width",
+                attribute_type: AttributeType::Float,
             }),
             17076 => Some(Self {
                 code: 17076,
                 acronym: "trshgd",
                 name: "Transshipping goods",
+                attribute_type: AttributeType::List,
             }),
             17077 => Some(Self {
                 code: 17077,
                 acronym: "unlocd",
                 name: "UN Location Code",
+                attribute_type: AttributeType::FreeText,
             }),
             17112 => Some(Self {
                 code: 17112,
                 acronym: "catwwm",
                 name: "Category of waterway mark",
+                attribute_type: AttributeType::Enumerated,
             }),
             20484 => Some(Self {
                 code: 20484,
                 acronym: "databa",
                 name: "Abandonment Date",
+                attribute_type: AttributeType::CodedString,
             }),
             20485 => Some(Self {
                 code: 20485,
                 acronym: "attutn",
                 name: "Attenuation",
+                attribute_type: AttributeType::Float,
             }),
             20486 => Some(Self {
                 code: 20486,
                 acronym: "vesbem",
                 name: "Beam of Vessel",
+                attribute_type: AttributeType::Float,
             }),
             20487 => Some(Self {
                 code: 20487,
                 acronym: "bearng",
                 name: "Bearing",
+                attribute_type: AttributeType::Float,
             }),
             20488 => Some(Self {
                 code: 20488,
                 acronym: "blndzn",
                 name: "Blind Zone",
+                attribute_type: AttributeType::CodedString,
             }),
             20489 => Some(Self {
                 code: 20489,
                 acronym: "brktyp",
                 name: "Breaker Type",
+                attribute_type: AttributeType::Enumerated,
             }),
             20490 => Some(Self {
                 code: 20490,
                 acronym: "bulkdn",
                 name: "Density",
+                attribute_type: AttributeType::Float,
             }),
             20491 => Some(Self {
                 code: 20491,
                 acronym: "brmchm",
                 name: "Burial Mechanism",
+                attribute_type: AttributeType::Enumerated,
             }),
             20492 => Some(Self {
                 code: 20492,
                 acronym: "brpctg",
                 name: "Burial Percentage",
+                attribute_type: AttributeType::Integer,
             }),
             20493 => Some(Self {
                 code: 20493,
                 acronym: "brperd",
                 name: "Burial Period",
+                attribute_type: AttributeType::Integer,
             }),
             20494 => Some(Self {
                 code: 20494,
                 acronym: "brprob",
                 name: "Burial Probability",
+                attribute_type: AttributeType::Enumerated,
             }),
             20495 => Some(Self {
                 code: 20495,
                 acronym: "orcard",
                 name: "Cardinal Point Orientation",
+                attribute_type: AttributeType::Enumerated,
             }),
             20496 => Some(Self {
                 code: 20496,
                 acronym: "catadm",
                 name: "Category of administration area",
+                attribute_type: AttributeType::Enumerated,
             }),
             20497 => Some(Self {
                 code: 20497,
                 acronym: "catasr",
                 name: "Category of airspace restriction",
+                attribute_type: AttributeType::Enumerated,
             }),
             20498 => Some(Self {
                 code: 20498,
                 acronym: "N/A",
                 name: "Category of bedrock",
+                attribute_type: AttributeType::Unspecified,
             }),
             20499 => Some(Self {
                 code: 20499,
                 acronym: "catbot",
                 name: "Bottom Feature Classification",
+                attribute_type: AttributeType::Enumerated,
             }),
             20500 => Some(Self {
                 code: 20500,
                 acronym: "catcgs",
                 name: "Category of coastguard station",
+                attribute_type: AttributeType::Enumerated,
             }),
             20501 => Some(Self {
                 code: 20501,
                 acronym: "catcas",
                 name: "Category of controlled airspace",
+                attribute_type: AttributeType::Enumerated,
             }),
             20502 => Some(Self {
                 code: 20502,
                 acronym: "catfsh",
                 name: "Fishing Activity",
+                attribute_type: AttributeType::Enumerated,
             }),
             20503 => Some(Self {
                 code: 20503,
                 acronym: "catimg",
                 name: "Type of Imagery",
+                attribute_type: AttributeType::List,
             }),
             20504 => Some(Self {
                 code: 20504,
                 acronym: "catmma",
                 name: "Category of marine management area",
+                attribute_type: AttributeType::Enumerated,
             }),
             20505 => Some(Self {
                 code: 20505,
                 acronym: "catmsi",
                 name: "Category of maritime safety information",
+                attribute_type: AttributeType::Enumerated,
             }),
             20506 => Some(Self {
                 code: 20506,
                 acronym: "catmea",
                 name: "Category of military exercise airspace ",
+                attribute_type: AttributeType::Enumerated,
             }),
             20507 => Some(Self {
                 code: 20507,
                 acronym: "catpat",
                 name: "Category of patrol area",
+                attribute_type: AttributeType::Enumerated,
             }),
             20508 => Some(Self {
                 code: 20508,
                 acronym: "catrep",
                 name: "Category of reporting/radio calling-in point",
+                attribute_type: AttributeType::Enumerated,
             }),
             20509 => Some(Self {
                 code: 20509,
                 acronym: "N/A",
                 name: "Category of regulated airspace",
+                attribute_type: AttributeType::Unspecified,
             }),
             20510 => Some(Self {
                 code: 20510,
                 acronym: "catsbl",
                 name: "Category of territorial sea baseline",
+                attribute_type: AttributeType::Enumerated,
             }),
             20511 => Some(Self {
                 code: 20511,
                 acronym: "cattrf",
                 name: "Trafficability",
+                attribute_type: AttributeType::Enumerated,
             }),
             20512 => Some(Self {
                 code: 20512,
                 acronym: "comsys",
                 name: "Command System",
+                attribute_type: AttributeType::FreeText,
             }),
             20515 => Some(Self {
                 code: 20515,
                 acronym: "caircd",
                 name: "Controlled airspace class designation",
+                attribute_type: AttributeType::Enumerated,
             }),
             20516 => Some(Self {
                 code: 20516,
                 acronym: "authty",
                 name: "Controlling authority",
+                attribute_type: AttributeType::FreeText,
             }),
             20517 => Some(Self {
                 code: 20517,
                 acronym: "scrdim",
                 name: "Current Scour Dimensions",
+                attribute_type: AttributeType::CodedString,
             }),
             20518 => Some(Self {
                 code: 20518,
                 acronym: "dgmrlf",
                 name: "Dangerous Marine and Land Life",
+                attribute_type: AttributeType::List,
             }),
             20519 => Some(Self {
                 code: 20519,
                 acronym: "datsnk",
                 name: "Date Sunk",
+                attribute_type: AttributeType::CodedString,
             }),
             20520 => Some(Self {
                 code: 20520,
                 acronym: "debfld",
                 name: "Debris Field",
+                attribute_type: AttributeType::CodedString,
             }),
             20521 => Some(Self {
                 code: 20521,
                 acronym: "depact",
                 name: "Depth of Activity",
+                attribute_type: AttributeType::Float,
             }),
             20522 => Some(Self {
                 code: 20522,
                 acronym: "deplyr",
                 name: "Depth of Layer",
+                attribute_type: AttributeType::Float,
             }),
             20523 => Some(Self {
                 code: 20523,
                 acronym: "discon",
                 name: "Distance from Small Bottom Object",
+                attribute_type: AttributeType::Float,
             }),
             20524 => Some(Self {
                 code: 20524,
                 acronym: "dttdep",
                 name: "Diver’s Thrust Test Depth",
+                attribute_type: AttributeType::Enumerated,
             }),
             20525 => Some(Self {
                 code: 20525,
                 acronym: "dttnum",
                 name: "Diver’s Thrust Test Number",
+                attribute_type: AttributeType::Integer,
             }),
             20526 => Some(Self {
                 code: 20526,
                 acronym: "divact",
                 name: "Diving Activity",
+                attribute_type: AttributeType::Enumerated,
             }),
             20527 => Some(Self {
                 code: 20527,
                 acronym: "vesdgh",
                 name: "Draught of Vessel",
+                attribute_type: AttributeType::Float,
             }),
             20528 => Some(Self {
                 code: 20528,
                 acronym: "exitus",
                 name: "Exit Usability",
+                attribute_type: AttributeType::Enumerated,
             }),
             20529 => Some(Self {
                 code: 20529,
                 acronym: "fldnam",
                 name: "Field Name",
+                attribute_type: AttributeType::FreeText,
             }),
             20530 => Some(Self {
                 code: 20530,
                 acronym: "datfir",
                 name: "First Detection Year",
+                attribute_type: AttributeType::CodedString,
             }),
             20531 => Some(Self {
                 code: 20531,
                 acronym: "senfir",
                 name: "First Sensor",
+                attribute_type: AttributeType::Enumerated,
             }),
             20532 => Some(Self {
                 code: 20532,
                 acronym: "sorfir",
                 name: "First Source",
+                attribute_type: AttributeType::Enumerated,
             }),
             20533 => Some(Self {
                 code: 20533,
                 acronym: "folinx",
                 name: "Foliar Index",
+                attribute_type: AttributeType::Float,
             }),
             20534 => Some(Self {
                 code: 20534,
                 acronym: "gascon",
                 name: "Gas Content",
+                attribute_type: AttributeType::Integer,
             }),
             20535 => Some(Self {
                 code: 20535,
                 acronym: "gendep",
                 name: "General Water Depth",
+                attribute_type: AttributeType::Integer,
             }),
             20536 => Some(Self {
                 code: 20536,
                 acronym: "gradnt",
                 name: "Gradient",
+                attribute_type: AttributeType::Enumerated,
             }),
             20537 => Some(Self {
                 code: 20537,
                 acronym: "grnsiz",
                 name: "Grain Size",
+                attribute_type: AttributeType::Float,
             }),
             20538 => Some(Self {
                 code: 20538,
                 acronym: "incltn",
                 name: "Inclination",
+                attribute_type: AttributeType::Float,
             }),
             20539 => Some(Self {
                 code: 20539,
                 acronym: "N/A",
                 name: "Internal Data Record Identification Number",
+                attribute_type: AttributeType::Unspecified,
             }),
             20540 => Some(Self {
                 code: 20540,
                 acronym: "datlst",
                 name: "Last Detection Year",
+                attribute_type: AttributeType::CodedString,
             }),
             20541 => Some(Self {
                 code: 20541,
                 acronym: "senlst",
                 name: "Last Sensor",
+                attribute_type: AttributeType::Enumerated,
             }),
             20542 => Some(Self {
                 code: 20542,
                 acronym: "sorlst",
                 name: "Last Source",
+                attribute_type: AttributeType::Enumerated,
             }),
             20543 => Some(Self {
                 code: 20543,
                 acronym: "layptm",
                 name: "Lay Platform",
+                attribute_type: AttributeType::Enumerated,
             }),
             20544 => Some(Self {
                 code: 20544,
                 acronym: "layrfn",
                 name: "Lay Reference Number",
+                attribute_type: AttributeType::FreeText,
             }),
             20545 => Some(Self {
                 code: 20545,
                 acronym: "laytim",
                 name: "Lay Time",
+                attribute_type: AttributeType::CodedString,
             }),
             20546 => Some(Self {
                 code: 20546,
                 acronym: "laynum",
                 name: "Layer Number",
+                attribute_type: AttributeType::Integer,
             }),
             20547 => Some(Self {
                 code: 20547,
                 acronym: "legsta",
                 name: "Legal Status",
+                attribute_type: AttributeType::FreeText,
             }),
             20548 => Some(Self {
                 code: 20548,
                 acronym: "veslen",
                 name: "Length of Vessel",
+                attribute_type: AttributeType::Float,
             }),
             20549 => Some(Self {
                 code: 20549,
                 acronym: "madsig",
                 name: "Magnetic Anomaly Detector (MAD) Signature",
+                attribute_type: AttributeType::Enumerated,
             }),
             20550 => Some(Self {
                 code: 20550,
                 acronym: "magint",
                 name: "Magnetic Intensity",
+                attribute_type: AttributeType::Integer,
             }),
             20551 => Some(Self {
                 code: 20551,
                 acronym: "msstrg",
                 name: "Mean Shear Strength",
+                attribute_type: AttributeType::Float,
             }),
             20552 => Some(Self {
                 code: 20552,
                 acronym: "migdir",
                 name: "Migration Direction",
+                attribute_type: AttributeType::Integer,
             }),
             20553 => Some(Self {
                 code: 20553,
                 acronym: "migspd",
                 name: "Migration Speed",
+                attribute_type: AttributeType::Float,
             }),
             20554 => Some(Self {
                 code: 20554,
                 acronym: "milden",
                 name: "Milec Density",
+                attribute_type: AttributeType::Enumerated,
             }),
             20555 => Some(Self {
                 code: 20555,
                 acronym: "mnimnc",
                 name: "Mine Index Mine Case",
+                attribute_type: AttributeType::Enumerated,
             }),
             20556 => Some(Self {
                 code: 20556,
                 acronym: "mnimnt",
                 name: "Mine Index Mine Type",
+                attribute_type: AttributeType::List,
             }),
             20557 => Some(Self {
                 code: 20557,
                 acronym: "minern",
                 name: "Mine Reference Number",
+                attribute_type: AttributeType::FreeText,
             }),
             20558 => Some(Self {
                 code: 20558,
                 acronym: "mhclas",
                 name: "Mine-Hunting Classification",
+                attribute_type: AttributeType::Enumerated,
             }),
             20559 => Some(Self {
                 code: 20559,
                 acronym: "mnhsys",
                 name: "Minehunting System",
+                attribute_type: AttributeType::FreeText,
             }),
             20560 => Some(Self {
                 code: 20560,
                 acronym: "mnssys",
                 name: "Minesweeping System",
+                attribute_type: AttributeType::FreeText,
             }),
             20561 => Some(Self {
                 code: 20561,
                 acronym: "miscls",
                 name: "Mission Classification",
+                attribute_type: AttributeType::Enumerated,
             }),
             20562 => Some(Self {
                 code: 20562,
                 acronym: "miscom",
                 name: "Mission Comments",
+                attribute_type: AttributeType::FreeText,
             }),
             20563 => Some(Self {
                 code: 20563,
                 acronym: "misdat",
                 name: "Mission Date",
+                attribute_type: AttributeType::CodedString,
             }),
             20564 => Some(Self {
                 code: 20564,
                 acronym: "misnme",
                 name: "Mission Name",
+                attribute_type: AttributeType::FreeText,
             }),
             20565 => Some(Self {
                 code: 20565,
                 acronym: "mwdcrn",
                 name: "MWDC Reference Number",
+                attribute_type: AttributeType::FreeText,
             }),
             20566 => Some(Self {
                 code: 20566,
                 acronym: "natsed",
                 name: "Nature of Geological Layer",
+                attribute_type: AttributeType::Enumerated,
             }),
             20567 => Some(Self {
                 code: 20567,
                 acronym: "navsys",
                 name: "Navigation System",
+                attribute_type: AttributeType::FreeText,
             }),
             20568 => Some(Self {
                 code: 20568,
                 acronym: "nomden",
                 name: "NOMBO Density",
+                attribute_type: AttributeType::Enumerated,
             }),
             20569 => Some(Self {
                 code: 20569,
                 acronym: "notfnd",
                 name: "Not Found",
+                attribute_type: AttributeType::FreeText,
             }),
             20570 => Some(Self {
                 code: 20570,
                 acronym: "nmprob",
                 name: "Number of Previous Observations",
+                attribute_type: AttributeType::Integer,
             }),
             20571 => Some(Self {
                 code: 20571,
                 acronym: "oprtor",
                 name: "Operator",
+                attribute_type: AttributeType::FreeText,
             }),
             20572 => Some(Self {
                 code: 20572,
                 acronym: "orbobn",
                 name: "Orientation of Best Observation",
+                attribute_type: AttributeType::Float,
             }),
             20573 => Some(Self {
                 code: 20573,
                 acronym: "orgdat",
                 name: "Origin of Data",
+                attribute_type: AttributeType::Enumerated,
             }),
             20574 => Some(Self {
                 code: 20574,
                 acronym: "orgntr",
                 name: "Originator",
+                attribute_type: AttributeType::FreeText,
             }),
             20575 => Some(Self {
                 code: 20575,
                 acronym: "porsty",
                 name: "Porosity",
+                attribute_type: AttributeType::Integer,
             }),
             20576 => Some(Self {
                 code: 20576,
                 acronym: "quabch",
                 name: "Quality of Beach Data",
+                attribute_type: AttributeType::CodedString,
             }),
             20577 => Some(Self {
                 code: 20577,
                 acronym: "datren",
                 name: "Re-entered Date",
+                attribute_type: AttributeType::CodedString,
             }),
             20578 => Some(Self {
                 code: 20578,
                 acronym: "datres",
                 name: "Re-suspended Date",
+                attribute_type: AttributeType::CodedString,
             }),
             20579 => Some(Self {
                 code: 20579,
                 acronym: "revebn",
                 name: "Reverberation",
+                attribute_type: AttributeType::Enumerated,
             }),
             20580 => Some(Self {
                 code: 20580,
                 acronym: "N/A",
                 name: "Safety Zone",
+                attribute_type: AttributeType::Unspecified,
             }),
             20581 => Some(Self {
                 code: 20581,
                 acronym: "samret",
                 name: "Sample Retained",
+                attribute_type: AttributeType::FreeText,
             }),
             20582 => Some(Self {
                 code: 20582,
                 acronym: "sbdcov",
                 name: "Seabed Coverage",
+                attribute_type: AttributeType::Integer,
             }),
             20583 => Some(Self {
                 code: 20583,
                 acronym: "shpspd",
                 name: "Ships Speed",
+                attribute_type: AttributeType::Float,
             }),
             20584 => Some(Self {
                 code: 20584,
                 acronym: "snrfrq",
                 name: "Sonar Frequency",
+                attribute_type: AttributeType::Enumerated,
             }),
             20585 => Some(Self {
                 code: 20585,
                 acronym: "snrrsc",
                 name: "Sonar Range Scale",
+                attribute_type: AttributeType::Float,
             }),
             20586 => Some(Self {
                 code: 20586,
                 acronym: "snrflc",
                 name: "Sonar Reflectivity",
+                attribute_type: AttributeType::Enumerated,
             }),
             20587 => Some(Self {
                 code: 20587,
                 acronym: "sonsig",
                 name: "Sonar Signal Strength",
+                attribute_type: AttributeType::Enumerated,
             }),
             20588 => Some(Self {
                 code: 20588,
                 acronym: "sndvel",
                 name: "Sound Velocity",
+                attribute_type: AttributeType::Float,
             }),
             20589 => Some(Self {
                 code: 20589,
                 acronym: "soudat",
                 name: "Sounding Datum",
+                attribute_type: AttributeType::Enumerated,
             }),
             20590 => Some(Self {
                 code: 20590,
                 acronym: "datspd",
                 name: "Spudded Date",
+                attribute_type: AttributeType::CodedString,
             }),
             20592 => Some(Self {
                 code: 20592,
                 acronym: "stfotn",
                 name: "Steepest Face Orientation",
+                attribute_type: AttributeType::Float,
             }),
             20593 => Some(Self {
                 code: 20593,
                 acronym: "ricsca",
                 name: "Strength According to Richter Scale",
+                attribute_type: AttributeType::Integer,
             }),
             20594 => Some(Self {
                 code: 20594,
                 acronym: "magany",
                 name: "Strength of Magnetic Anomaly",
+                attribute_type: AttributeType::Enumerated,
             }),
             20595 => Some(Self {
                 code: 20595,
                 acronym: "stbacv",
                 name: "Suitability for ACV Use",
+                attribute_type: AttributeType::Enumerated,
             }),
             20596 => Some(Self {
                 code: 20596,
                 acronym: "srfhgt",
                 name: "Surf Height",
+                attribute_type: AttributeType::Float,
             }),
             20597 => Some(Self {
                 code: 20597,
                 acronym: "srfzne",
                 name: "Surf Zone",
+                attribute_type: AttributeType::Integer,
             }),
             20598 => Some(Self {
                 code: 20598,
                 acronym: "surdat",
                 name: "Survey Date and Time",
+                attribute_type: AttributeType::CodedString,
             }),
             20599 => Some(Self {
                 code: 20599,
                 acronym: "datsus",
                 name: "Suspension Date",
+                attribute_type: AttributeType::CodedString,
             }),
             20600 => Some(Self {
                 code: 20600,
                 acronym: "swlhgt",
                 name: "Swell Height",
+                attribute_type: AttributeType::Float,
             }),
             20601 => Some(Self {
                 code: 20601,
                 acronym: "tdlrng",
                 name: "Tidal Range",
+                attribute_type: AttributeType::Float,
             }),
             20602 => Some(Self {
                 code: 20602,
                 acronym: "timeyr",
                 name: "Time of Year",
+                attribute_type: AttributeType::List,
             }),
             20603 => Some(Self {
                 code: 20603,
                 acronym: "tonage",
                 name: "Tonnage",
+                attribute_type: AttributeType::Integer,
             }),
             20604 => Some(Self {
                 code: 20604,
                 acronym: "twdbdp",
                 name: "Towed Body Depth",
+                attribute_type: AttributeType::Float,
             }),
             20605 => Some(Self {
                 code: 20605,
                 acronym: "milact",
                 name: "Type of military activity",
+                attribute_type: AttributeType::List,
             }),
             20606 => Some(Self {
                 code: 20606,
                 acronym: "typton",
                 name: "Type of Tonnage",
+                attribute_type: AttributeType::Enumerated,
             }),
             20607 => Some(Self {
                 code: 20607,
                 acronym: "typewk",
                 name: "Type of Wreck",
+                attribute_type: AttributeType::Enumerated,
             }),
             20608 => Some(Self {
                 code: 20608,
                 acronym: "unwrfm",
                 name: "Underwater Reference Mark",
+                attribute_type: AttributeType::Enumerated,
             }),
             20609 => Some(Self {
                 code: 20609,
                 acronym: "N/A",
                 name: "Unique ID from a Navigational Product",
+                attribute_type: AttributeType::Unspecified,
             }),
             20610 => Some(Self {
                 code: 20610,
                 acronym: "watclr",
                 name: "Water Clarity",
+                attribute_type: AttributeType::Float,
             }),
             20611 => Some(Self {
                 code: 20611,
                 acronym: "wavlen",
                 name: "Wavelength",
+                attribute_type: AttributeType::Float,
             }),
             20612 => Some(Self {
                 code: 20612,
                 acronym: "wbrcap",
                 name: "Weight Bearing Capability",
+                attribute_type: AttributeType::Integer,
             }),
             20613 => Some(Self {
                 code: 20613,
                 acronym: "lftwid",
                 name: "Width (left)",
+                attribute_type: AttributeType::Float,
             }),
             20614 => Some(Self {
                 code: 20614,
                 acronym: "rgtwid",
                 name: "Width (right)",
+                attribute_type: AttributeType::Float,
             }),
             20615 => Some(Self {
                 code: 20615,
                 acronym: "hypcat",
                 name: "Contour Type",
+                attribute_type: AttributeType::Enumerated,
             }),
             20616 => Some(Self {
                 code: 20616,
                 acronym: "souvel",
                 name: "Sounding Velocity",
+                attribute_type: AttributeType::Enumerated,
             }),
             20617 => Some(Self {
                 code: 20617,
                 acronym: "accres",
                 name: "Access Restriction",
+                attribute_type: AttributeType::FreeText,
             }),
             20618 => Some(Self {
                 code: 20618,
                 acronym: "apprch",
                 name: "Approach",
+                attribute_type: AttributeType::FreeText,
             }),
             20619 => Some(Self {
                 code: 20619,
                 acronym: "catbch",
                 name: "Category of Beach",
+                attribute_type: AttributeType::Enumerated,
             }),
             20620 => Some(Self {
                 code: 20620,
                 acronym: "clperc",
                 name: "Clearance Percentage",
+                attribute_type: AttributeType::Integer,
             }),
             20621 => Some(Self {
                 code: 20621,
                 acronym: "commns",
                 name: "Communications",
+                attribute_type: AttributeType::List,
             }),
             20622 => Some(Self {
                 code: 20622,
                 acronym: "conlev",
                 name: "Confidence Level",
+                attribute_type: AttributeType::Float,
             }),
             20624 => Some(Self {
                 code: 20624,
                 acronym: "extdes",
                 name: "Exit Description",
+                attribute_type: AttributeType::FreeText,
             }),
             20625 => Some(Self {
                 code: 20625,
                 acronym: "indtry",
                 name: "Industry",
+                attribute_type: AttributeType::FreeText,
             }),
             20626 => Some(Self {
                 code: 20626,
                 acronym: "lndcon",
                 name: "Landing Conditions",
+                attribute_type: AttributeType::FreeText,
             }),
             20627 => Some(Self {
                 code: 20627,
                 acronym: "lsract",
                 name: "Leisure Activity",
+                attribute_type: AttributeType::FreeText,
             }),
             20628 => Some(Self {
                 code: 20628,
                 acronym: "logtcs",
                 name: "Logistics",
+                attribute_type: AttributeType::List,
             }),
             20629 => Some(Self {
                 code: 20629,
                 acronym: "manvrg",
                 name: "Manoeuvring",
+                attribute_type: AttributeType::FreeText,
             }),
             20630 => Some(Self {
                 code: 20630,
                 acronym: "mntden",
                 name: "Mine Threat Density",
+                attribute_type: AttributeType::Integer,
             }),
             20631 => Some(Self {
                 code: 20631,
                 acronym: "mulcon",
                 name: "Multiple Contacts",
+                attribute_type: AttributeType::Integer,
             }),
             20632 => Some(Self {
                 code: 20632,
                 acronym: "navdes",
                 name: "Navigational Description",
+                attribute_type: AttributeType::FreeText,
             }),
             20633 => Some(Self {
                 code: 20633,
                 acronym: "navdif",
                 name: "Navigational Difficulty",
+                attribute_type: AttributeType::Enumerated,
             }),
             20634 => Some(Self {
                 code: 20634,
                 acronym: "numrmn",
                 name: "Number of Remaining Mines",
+                attribute_type: AttributeType::Integer,
             }),
             20635 => Some(Self {
                 code: 20635,
                 acronym: "pierod",
                 name: "Pier Contact Details",
+                attribute_type: AttributeType::FreeText,
             }),
             20636 => Some(Self {
                 code: 20636,
                 acronym: "pierdn",
                 name: "Pier Description",
+                attribute_type: AttributeType::FreeText,
             }),
             20637 => Some(Self {
                 code: 20637,
                 acronym: "prsden",
                 name: "Prairies Density",
+                attribute_type: AttributeType::Integer,
             }),
             20638 => Some(Self {
                 code: 20638,
                 acronym: "prbrmn",
                 name: "Probability for Remaining Mines",
+                attribute_type: AttributeType::Float,
             }),
             20639 => Some(Self {
                 code: 20639,
                 acronym: "rmnlmn",
                 name: "Remaining Mines Likely, Maximum Number",
+                attribute_type: AttributeType::Integer,
             }),
             20640 => Some(Self {
                 code: 20640,
                 acronym: "sfptna",
                 name: "Self Protection (Air)",
+                attribute_type: AttributeType::Enumerated,
             }),
             20641 => Some(Self {
                 code: 20641,
                 acronym: "sptnnd",
                 name: "Self Protection (Near Defence)",
+                attribute_type: AttributeType::Enumerated,
             }),
             20642 => Some(Self {
                 code: 20642,
                 acronym: "sfptns",
                 name: "Self Protection (Surface)",
+                attribute_type: AttributeType::Enumerated,
             }),
             20643 => Some(Self {
                 code: 20643,
                 acronym: "sencov",
                 name: "Sensor Coverage",
+                attribute_type: AttributeType::FreeText,
             }),
             20644 => Some(Self {
                 code: 20644,
                 acronym: "sminth",
                 name: "Simple Initial Threat",
+                attribute_type: AttributeType::Float,
             }),
             20645 => Some(Self {
                 code: 20645,
                 acronym: "tgrfwt",
                 name: "Target Reference Weight",
+                attribute_type: AttributeType::Enumerated,
             }),
             20646 => Some(Self {
                 code: 20646,
                 acronym: "tdltyp",
                 name: "Tidal Type",
+                attribute_type: AttributeType::Enumerated,
             }),
             20647 => Some(Self {
                 code: 20647,
                 acronym: "typres",
                 name: "Type of Resource Location",
+                attribute_type: AttributeType::Enumerated,
             }),
             20648 => Some(Self {
                 code: 20648,
                 acronym: "undmnr",
                 name: "Undetectable Mines Ratio",
+                attribute_type: AttributeType::Float,
             }),
             20649 => Some(Self {
                 code: 20649,
                 acronym: "umnrwb",
                 name: "Undetectable Mines Ratio with Burial",
+                attribute_type: AttributeType::Float,
             }),
             20650 => Some(Self {
                 code: 20650,
                 acronym: "umrwob",
                 name: "Undetectable Mines Ratio without Burial",
+                attribute_type: AttributeType::Float,
             }),
             20651 => Some(Self {
                 code: 20651,
                 acronym: "wpncov",
                 name: "Weapon Coverage",
+                attribute_type: AttributeType::FreeText,
             }),
             20652 => Some(Self {
                 code: 20652,
                 acronym: "onsonr",
                 name: "On Sonar",
+                attribute_type: AttributeType::Enumerated,
             }),
             20653 => Some(Self {
                 code: 20653,
                 acronym: "hfbmls",
                 name: "HF Bottom Loss",
+                attribute_type: AttributeType::Float,
             }),
             20654 => Some(Self {
                 code: 20654,
                 acronym: "lfbmls",
                 name: "LF Bottom Loss",
+                attribute_type: AttributeType::Float,
             }),
             20655 => Some(Self {
                 code: 20655,
                 acronym: "dtprob",
                 name: "Detection Probability",
+                attribute_type: AttributeType::Float,
             }),
             20656 => Some(Self {
                 code: 20656,
                 acronym: "dsprob",
                 name: "Disposal Probability",
+                attribute_type: AttributeType::Float,
             }),
             20657 => Some(Self {
                 code: 20657,
                 acronym: "clprob",
                 name: "Classification Probability",
+                attribute_type: AttributeType::Float,
             }),
             20658 => Some(Self {
                 code: 20658,
                 acronym: "cswidt",
                 name: "Characteristic Detection Width (A)",
+                attribute_type: AttributeType::Integer,
             }),
             20659 => Some(Self {
                 code: 20659,
                 acronym: "csprob",
                 name: "Characteristic Detection Probability (B)",
+                attribute_type: AttributeType::Float,
             }),
             20660 => Some(Self {
                 code: 20660,
                 acronym: "znecol",
                 name: "Zone Colour",
+                attribute_type: AttributeType::Enumerated,
             }),
             20661 => Some(Self {
                 code: 20661,
                 acronym: "revfqy",
                 name: "Reverberation Frequency",
+                attribute_type: AttributeType::Float,
             }),
             20662 => Some(Self {
                 code: 20662,
                 acronym: "revgan",
                 name: "Reverberation Grazing Angle",
+                attribute_type: AttributeType::Float,
             }),
             20663 => Some(Self {
                 code: 20663,
                 acronym: "secido",
                 name: "International Defence Organisation (IDO) status",
+                attribute_type: AttributeType::Enumerated,
             }),
             20664 => Some(Self {
                 code: 20664,
                 acronym: "secpmk",
                 name: "Protective Marking",
+                attribute_type: AttributeType::Enumerated,
             }),
             20665 => Some(Self {
                 code: 20665,
                 acronym: "secown",
                 name: "Owner Authority",
+                attribute_type: AttributeType::FreeText,
             }),
             20666 => Some(Self {
                 code: 20666,
                 acronym: "seccvt",
                 name: "Caveat ",
+                attribute_type: AttributeType::FreeText,
             }),
             20667 => Some(Self {
                 code: 20667,
                 acronym: "spcies",
                 name: "Species",
+                attribute_type: AttributeType::FreeText,
             }),
             20668 => Some(Self {
                 code: 20668,
                 acronym: "swpdat",
                 name: "Swept date",
+                attribute_type: AttributeType::CodedString,
             }),
             20669 => Some(Self {
                 code: 20669,
                 acronym: "rwylen",
                 name: "Runway length",
+                attribute_type: AttributeType::Integer,
             }),
             20670 => Some(Self {
                 code: 20670,
                 acronym: "actper",
                 name: "Active period",
+                attribute_type: AttributeType::FreeText,
             }),
             20671 => Some(Self {
                 code: 20671,
                 acronym: "maxalt",
                 name: "Maximum altitude",
+                attribute_type: AttributeType::Integer,
             }),
             20672 => Some(Self {
                 code: 20672,
                 acronym: "minalt",
                 name: "Minimum altitude",
+                attribute_type: AttributeType::Integer,
             }),
             20673 => Some(Self {
                 code: 20673,
                 acronym: "maxftl",
                 name: "Maximum Flight Level",
+                attribute_type: AttributeType::Integer,
             }),
             20674 => Some(Self {
                 code: 20674,
                 acronym: "minftl",
                 name: "Minimum Flight Level",
+                attribute_type: AttributeType::Integer,
             }),
             20675 => Some(Self {
                 code: 20675,
                 acronym: "bverss",
                 name: "Bottom Vertical Safety Separation",
+                attribute_type: AttributeType::Integer,
             }),
             20676 => Some(Self {
                 code: 20676,
                 acronym: "mindep",
                 name: "Minimum Safe Depth",
+                attribute_type: AttributeType::Integer,
             }),
             20677 => Some(Self {
                 code: 20677,
                 acronym: "linech",
                 name: "Interpolated line characteristic",
+                attribute_type: AttributeType::Enumerated,
             }),
             20678 => Some(Self {
                 code: 20678,
                 acronym: "identy",
                 name: "Identification",
+                attribute_type: AttributeType::FreeText,
             }),
             20679 => Some(Self {
                 code: 20679,
                 acronym: "rclass",
                 name: "Route Classification",
+                attribute_type: AttributeType::Enumerated,
             }),
             20680 => Some(Self {
                 code: 20680,
                 acronym: "popltn",
                 name: "Population",
+                attribute_type: AttributeType::Integer,
             }),
             20681 => Some(Self {
                 code: 20681,
                 acronym: "surtht",
                 name: "Surface Threat",
+                attribute_type: AttributeType::Enumerated,
             }),
             20682 => Some(Self {
                 code: 20682,
                 acronym: "upbear",
                 name: "Heading-Up Bearing",
+                attribute_type: AttributeType::Float,
             }),
             20683 => Some(Self {
                 code: 20683,
                 acronym: "dnbear",
                 name: "Heading-Down Bearing",
+                attribute_type: AttributeType::Float,
             }),
             20684 => Some(Self {
                 code: 20684,
                 acronym: "icencn",
                 name: "Ice Concentration",
+                attribute_type: AttributeType::Integer,
             }),
             20685 => Some(Self {
                 code: 20685,
                 acronym: "dgrhgt",
                 name: "Danger height",
+                attribute_type: AttributeType::Integer,
             }),
             20686 => Some(Self {
                 code: 20686,
                 acronym: "depres",
                 name: "Depth Restriction",
+                attribute_type: AttributeType::FreeText,
             }),
             20687 => Some(Self {
                 code: 20687,
                 acronym: "arecat",
                 name: "Area Category",
+                attribute_type: AttributeType::Enumerated,
             }),
             20688 => Some(Self {
                 code: 20688,
                 acronym: "exzres",
                 name: "Existence of Restricted Area",
+                attribute_type: AttributeType::Enumerated,
             }),
             20689 => Some(Self {
                 code: 20689,
                 acronym: "tarstg",
                 name: "Target Strength",
+                attribute_type: AttributeType::Integer,
             }),
             20690 => Some(Self {
                 code: 20690,
                 acronym: "quarad",
                 name: "Qualification of Radar Coverage",
+                attribute_type: AttributeType::Integer,
             }),
             20691 => Some(Self {
                 code: 20691,
                 acronym: "condet",
                 name: "Contact Details",
+                attribute_type: AttributeType::FreeText,
             }),
             20692 => Some(Self {
                 code: 20692,
                 acronym: "limanc",
                 name: "Limit of Anchors and Chains",
+                attribute_type: AttributeType::Float,
             }),
             20693 => Some(Self {
                 code: 20693,
                 acronym: "ccmidx",
                 name: "CCM Index",
+                attribute_type: AttributeType::Integer,
             }),
             20694 => Some(Self {
                 code: 20694,
                 acronym: "mlclas",
                 name: "Military Load Classification",
+                attribute_type: AttributeType::Enumerated,
             }),
             20695 => Some(Self {
                 code: 20695,
                 acronym: "mgstyp",
                 name: "MGS Type",
+                attribute_type: AttributeType::Enumerated,
             }),
             20696 => Some(Self {
                 code: 20696,
                 acronym: "iceact",
                 name: "Ice Attribute Concentration Total",
+                attribute_type: AttributeType::Enumerated,
             }),
             20697 => Some(Self {
                 code: 20697,
                 acronym: "icesod",
                 name: "Ice Stage of Development",
+                attribute_type: AttributeType::Enumerated,
             }),
             20698 => Some(Self {
                 code: 20698,
                 acronym: "iceadc",
                 name: "Ice Advisory Code",
+                attribute_type: AttributeType::FreeText,
             }),
             20699 => Some(Self {
                 code: 20699,
                 acronym: "icebnm",
                 name: "Number of Icebergs in Area",
+                attribute_type: AttributeType::Integer,
             }),
             20700 => Some(Self {
                 code: 20700,
                 acronym: "icelnc",
                 name: "Ice Line Category",
+                attribute_type: AttributeType::Enumerated,
             }),
             20701 => Some(Self {
                 code: 20701,
                 acronym: "icepty",
                 name: "Ice Polynya Type",
+                attribute_type: AttributeType::Enumerated,
             }),
             20702 => Some(Self {
                 code: 20702,
                 acronym: "icepst",
                 name: "Ice Polynya Status",
+                attribute_type: AttributeType::Enumerated,
             }),
             20703 => Some(Self {
                 code: 20703,
                 acronym: "icelty",
                 name: "Ice Lead Type",
+                attribute_type: AttributeType::Enumerated,
             }),
             20704 => Some(Self {
                 code: 20704,
                 acronym: "icelst",
                 name: "Ice Lead Status",
+                attribute_type: AttributeType::Enumerated,
             }),
             20705 => Some(Self {
                 code: 20705,
                 acronym: "icebsz",
                 name: "Iceberg Size",
+                attribute_type: AttributeType::Enumerated,
             }),
             20706 => Some(Self {
                 code: 20706,
                 acronym: "icebsh",
                 name: "Iceberg Shape",
+                attribute_type: AttributeType::Enumerated,
             }),
             20707 => Some(Self {
                 code: 20707,
                 acronym: "icebdr",
                 name: "Icedrift or Iceberg Direction",
+                attribute_type: AttributeType::Enumerated,
             }),
             20708 => Some(Self {
                 code: 20708,
                 acronym: "icebsp",
                 name: "Icedrift or Iceberg Speed",
+                attribute_type: AttributeType::Float,
             }),
             20709 => Some(Self {
                 code: 20709,
                 acronym: "icemax",
                 name: "Maximum Ice Thickness",
+                attribute_type: AttributeType::Float,
             }),
             20710 => Some(Self {
                 code: 20710,
                 acronym: "icemin",
                 name: "Minimum Ice Thickness",
+                attribute_type: AttributeType::Float,
             }),
             20711 => Some(Self {
                 code: 20711,
                 acronym: "icerdv",
                 name: "Ice Ridge Development",
+                attribute_type: AttributeType::Enumerated,
             }),
             20712 => Some(Self {
                 code: 20712,
                 acronym: "icelnd",
                 name: "Land Ice",
+                attribute_type: AttributeType::Enumerated,
             }),
             20713 => Some(Self {
                 code: 20713,
                 acronym: "seadir",
                 name: "Sea Direction",
+                attribute_type: AttributeType::Enumerated,
             }),
             20714 => Some(Self {
                 code: 20714,
                 acronym: "traden",
                 name: "Traffic density",
+                attribute_type: AttributeType::FreeText,
             }),
             20715 => Some(Self {
                 code: 20715,
                 acronym: "typshp",
                 name: "Type of shipping",
+                attribute_type: AttributeType::List,
             }),
             20716 => Some(Self {
                 code: 20716,
                 acronym: "icecvt",
                 name: "Ice Coverage Type",
+                attribute_type: AttributeType::Enumerated,
             }),
             20718 => Some(Self {
                 code: 20718,
                 acronym: "staobj",
                 name: "Status of Small Bottom Object",
+                attribute_type: AttributeType::List,
             }),
             20719 => Some(Self {
                 code: 20719,
                 acronym: "icaocd",
                 name: "ICAO code",
+                attribute_type: AttributeType::FreeText,
             }),
             20720 => Some(Self {
                 code: 20720,
                 acronym: "txtdes",
                 name: "textual description",
+                attribute_type: AttributeType::FreeText,
             }),
             20721 => Some(Self {
                 code: 20721,
                 acronym: "objtrn",
                 name: "Object Reference Number",
+                attribute_type: AttributeType::FreeText,
             }),
             20722 => Some(Self {
                 code: 20722,
                 acronym: "objshp",
                 name: "Object Shape",
+                attribute_type: AttributeType::FreeText,
             }),
             22484 => Some(Self {
                 code: 22484,
                 acronym: "catcnf",
                 name: "Category of completeness",
+                attribute_type: AttributeType::Enumerated,
             }),
             22485 => Some(Self {
                 code: 22485,
                 acronym: "errell",
                 name: "Error Ellipse",
+                attribute_type: AttributeType::CodedString,
             }),
             22486 => Some(Self {
                 code: 22486,
                 acronym: "N/A",
                 name: "Object classes",
+                attribute_type: AttributeType::Unspecified,
             }),
             22487 => Some(Self {
                 code: 22487,
                 acronym: "N/A",
                 name: "Security classification",
+                attribute_type: AttributeType::Unspecified,
             }),
             22488 => Some(Self {
                 code: 22488,
                 acronym: "vershf",
                 name: "Vertical Datum Shift Parameter",
+                attribute_type: AttributeType::Float,
             }),
             22489 => Some(Self {
                 code: 22489,
                 acronym: "elvacc",
                 name: "Absolute Vertical Accuracy",
+                attribute_type: AttributeType::Float,
             }),
             22490 => Some(Self {
                 code: 22490,
                 acronym: "reflco",
                 name: "Reflection Coefficient",
+                attribute_type: AttributeType::Float,
             }),
             22491 => Some(Self {
                 code: 22491,
                 acronym: "cpyrit",
                 name: "Copyright statement",
+                attribute_type: AttributeType::FreeText,
             }),
             40000 => Some(Self {
                 code: 40000,
                 acronym: "updmsg",
                 name: "Update message",
+                attribute_type: AttributeType::FreeText,
             }),
             _ => None,
         }
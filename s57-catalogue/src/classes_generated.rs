@@ -0,0 +1,2235 @@
+// Generated from s57objectclasses.csv
+// DO NOT EDIT - run scripts/generate_catalogue.nu to regenerate
+
+use crate::ClassAttributes;
+
+/// Look up the attribute sets of an object class by OBJL code
+pub(crate) fn class_attributes(objl: u16) -> Option<ClassAttributes> {
+    let (a, b, c): (&[&str], &[&str], &[&str]) = match objl {
+        1 => (
+            &["JRSDTN", "NATION", "NOBJNM", "OBJNAM"],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        2 => (
+            &["CATAIR", "CONDTN", "CONVIS", "NOBJNM", "OBJNAM", "STATUS"],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        3 => (
+            &[
+                "CATACH", "DATEND", "DATSTA", "NOBJNM", "OBJNAM", "PEREND", "PERSTA", "RADIUS",
+                "STATUS",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        4 => (
+            &[
+                "CATACH", "DATEND", "DATSTA", "NOBJNM", "OBJNAM", "PEREND", "PERSTA", "RESTRN",
+                "STATUS",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        5 => (
+            &[
+                "BCNSHP", "CATCAM", "COLOUR", "COLPAT", "CONDTN", "CONVIS", "CONRAD", "DATEND",
+                "DATSTA", "ELEVAT", "HEIGHT", "MARSYS", "NATCON", "NOBJNM", "OBJNAM", "PEREND",
+                "PERSTA", "STATUS", "VERACC", "VERDAT", "VERLEN",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        6 => (
+            &[
+                "BCNSHP", "COLOUR", "COLPAT", "CONDTN", "CONRAD", "CONVIS", "DATEND", "DATSTA",
+                "ELEVAT", "HEIGHT", "MARSYS", "NATCON", "NOBJNM", "OBJNAM", "PEREND", "PERSTA",
+                "STATUS", "VERACC", "VERDAT", "VERLEN",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        7 => (
+            &[
+                "BCNSHP", "CATLAM", "COLOUR", "COLPAT", "CONDTN", "CONRAD", "CONVIS", "DATEND",
+                "DATSTA", "ELEVAT", "HEIGHT", "MARSYS", "NATCON", "NOBJNM", "OBJNAM", "PEREND",
+                "PERSTA", "STATUS", "VERACC", "VERDAT", "VERLEN",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        8 => (
+            &[
+                "BCNSHP", "COLOUR", "COLPAT", "CONDTN", "CONRAD", "CONVIS", "DATEND", "DATSTA",
+                "ELEVAT", "HEIGHT", "MARSYS", "NATCON", "NOBJNM", "OBJNAM", "PEREND", "PERSTA",
+                "STATUS", "VERACC", "VERDAT", "VERLEN",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        9 => (
+            &[
+                "BCNSHP", "CATSPM", "COLOUR", "COLPAT", "CONDTN", "CONRAD", "CONVIS", "DATEND",
+                "DATSTA", "ELEVAT", "HEIGHT", "MARSYS", "NATCON", "NOBJNM", "OBJNAM", "PEREND",
+                "PERSTA", "STATUS", "VERACC", "VERDAT", "VERLEN",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        10 => (
+            &[
+                "DATEND", "DATSTA", "DRVAL1", "NOBJNM", "OBJNAM", "PEREND", "PERSTA", "QUASOU",
+                "SOUACC", "STATUS", "VERDAT",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        11 => (
+            &[
+                "CATBRG", "COLOUR", "COLPAT", "CONDTN", "CONRAD", "CONVIS", "DATEND", "DATSTA",
+                "HORACC", "HORCLR", "NATCON", "NOBJNM", "OBJNAM", "VERACC", "VERCCL", "VERCLR",
+                "VERCOP", "VERDAT",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        12 => (
+            &[
+                "BUISHP", "COLOUR", "COLPAT", "CONDTN", "CONRAD", "CONVIS", "ELEVAT", "FUNCTN",
+                "HEIGHT", "NATCON", "NOBJNM", "OBJNAM", "STATUS", "VERACC", "VERDAT", "VERLEN",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        13 => (
+            &[
+                "CATBUA", "CONDTN", "CONRAD", "CONVIS", "HEIGHT", "NOBJNM", "OBJNAM", "VERACC",
+                "VERDAT",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        14 => (
+            &[
+                "BOYSHP", "CATCAM", "COLOUR", "COLPAT", "CONRAD", "DATEND", "DATSTA", "MARSYS",
+                "NATCON", "NOBJNM", "OBJNAM", "PEREND", "PERSTA", "STATUS", "VERACC", "VERLEN",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        15 => (
+            &[
+                "BOYSHP", "CATINB", "COLOUR", "COLPAT", "CONRAD", "DATEND", "DATSTA", "MARSYS",
+                "NATCON", "NOBJNM", "OBJNAM", "PEREND", "PERSTA", "PRODCT", "STATUS", "VERACC",
+                "VERLEN",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        16 => (
+            &[
+                "BOYSHP", "COLOUR", "COLPAT", "CONRAD", "DATEND", "DATSTA", "MARSYS", "NATCON",
+                "NOBJNM", "OBJNAM", "PEREND", "PERSTA", "STATUS", "VERACC", "VERLEN",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        17 => (
+            &[
+                "BOYSHP", "CATLAM", "COLOUR", "COLPAT", "CONRAD", "DATEND", "DATSTA", "MARSYS",
+                "NATCON", "NOBJNM", "OBJNAM", "PEREND", "PERSTA", "STATUS", "VERACC", "VERLEN",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        18 => (
+            &[
+                "BOYSHP", "COLOUR", "COLPAT", "CONRAD", "DATEND", "DATSTA", "MARSYS", "NATCON",
+                "NOBJNM", "OBJNAM", "PEREND", "PERSTA", "STATUS", "VERACC", "VERLEN",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        19 => (
+            &[
+                "BOYSHP", "CATSPM", "COLOUR", "COLPAT", "CONRAD", "DATEND", "DATSTA", "MARSYS",
+                "NATCON", "NOBJNM", "OBJNAM", "PEREND", "PERSTA", "STATUS", "VERACC", "VERLEN",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        20 => (
+            &[
+                "CATCBL", "DATEND", "DATSTA", "NOBJNM", "OBJNAM", "RESTRN", "STATUS",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        21 => (
+            &[
+                "CATCBL", "CONDTN", "CONRAD", "CONVIS", "DATEND", "DATSTA", "ICEFAC", "NOBJNM",
+                "OBJNAM", "STATUS", "VERACC", "VERCLR", "VERCSA", "VERDAT",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        22 => (
+            &[
+                "BURDEP", "CATCBL", "CONDTN", "DATEND", "DATSTA", "DRVAL1", "DRVAL2", "NOBJNM",
+                "OBJNAM", "STATUS", "VERDAT",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        23 => (
+            &[
+                "CATCAN", "CONDTN", "DATEND", "DATSTA", "HORACC", "HORCLR", "HORWID", "NOBJNM",
+                "OBJNAM", "STATUS",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        24 => (
+            &["CONDTN", "DATEND", "DATSTA", "NOBJNM", "OBJNAM"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        25 => (
+            &[
+                "DATEND", "DATSTA", "NOBJNM", "OBJNAM", "PEREND", "PERSTA", "STATUS",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        26 => (
+            &["CONDTN", "NATCON", "NOBJNM", "OBJNAM", "STATUS", "WATLEV"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        27 => (
+            &["DATEND", "DATSTA", "PEREND", "PERSTA"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        28 => (
+            &["CATCHP", "NOBJNM", "OBJNAM", "STATUS"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        29 => (
+            &[
+                "DATEND", "DATSTA", "NOBJNM", "OBJNAM", "PEREND", "PERSTA", "STATUS",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        30 => (
+            &[
+                "CATCOA", "COLOUR", "CONRAD", "CONVIS", "ELEVAT", "NOBJNM", "OBJNAM", "VERACC",
+                "VERDAT",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        31 => (
+            &["DATEND", "DATSTA", "NATION", "STATUS"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        32 => (
+            &["NATION", "NOBJNM", "OBJNAM"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        33 => (
+            &[
+                "CATCTR", "DATEND", "DATSTA", "ELEVAT", "NOBJNM", "OBJNAM", "VERACC", "VERDAT",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        34 => (
+            &[
+                "CATCON", "COLOUR", "COLPAT", "CONDTN", "CONRAD", "CONVIS", "DATEND", "DATSTA",
+                "HEIGHT", "LIFCAP", "NOBJNM", "OBJNAM", "PRODCT", "STATUS", "VERACC", "VERCLR",
+                "VERDAT", "VERLEN",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        35 => (
+            &[
+                "CATCRN", "COLOUR", "COLPAT", "CONDTN", "CONRAD", "CONVIS", "HEIGHT", "LIFCAP",
+                "NOBJNM", "OBJNAM", "ORIENT", "RADIUS", "STATUS", "VERACC", "VERCLR", "VERDAT",
+                "VERLEN",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        36 => (
+            &[
+                "CURVEL", "DATEND", "DATSTA", "NOBJNM", "OBJNAM", "ORIENT", "PEREND", "PERSTA",
+            ],
+            &["INFORM", "NINFOM", "SCAMAX", "SCAMIN"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        37 => (
+            &["NATION"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        38 => (
+            &[
+                "CATDAM", "COLOUR", "COLPAT", "CONDTN", "CONRAD", "CONVIS", "DATEND", "DATSTA",
+                "HEIGHT", "NATCON", "NOBJNM", "OBJNAM", "VERACC", "VERDAT", "VERLEN",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        39 => (
+            &[
+                "CATSPM", "COLOUR", "COLPAT", "DATEND", "DATSTA", "ELEVAT", "HEIGHT", "NATCON",
+                "NOBJNM", "OBJNAM", "PEREND", "PERSTA", "STATUS", "TOPSHP", "VERACC", "VERDAT",
+                "VERLEN",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        40 => (
+            &[
+                "CATTRK", "DATEND", "DATSTA", "DRVAL1", "DRVAL2", "NOBJNM", "OBJNAM", "ORIENT",
+                "QUASOU", "SOUACC", "STATUS", "TECSOU", "TRAFIC", "VERDAT",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        41 => (
+            &[
+                "DATEND", "DATSTA", "DRVAL1", "DRVAL2", "NOBJNM", "OBJNAM", "ORIENT", "QUASOU",
+                "SOUACC", "STATUS", "TECSOU", "TRAFIC", "VERDAT", "RESTRN",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        42 => (
+            &["DRVAL1", "DRVAL2", "QUASOU", "SOUACC", "VERDAT"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        43 => (
+            &["VALDCO", "VERDAT"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND", "hypcat"],
+        ),
+        44 => (
+            &["CATDIS", "DATEND", "DATSTA", "NOBJNM", "OBJNAM"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        45 => (
+            &[
+                "CATDOC", "CONDTN", "DATEND", "DATSTA", "HORACC", "HORCLR", "NOBJNM", "OBJNAM",
+                "STATUS",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        46 => (
+            &[
+                "DRVAL1", "DRVAL2", "NOBJNM", "OBJNAM", "QUASOU", "RESTRN", "SOUACC", "TECSOU",
+                "VERDAT",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        47 => (
+            &[
+                "CONDTN", "HORACC", "HORCLR", "HORLEN", "HORWID", "NOBJNM", "OBJNAM", "STATUS",
+                "DRVAL1", "QUASOU", "SOUACC", "VERDAT",
+            ],
+            &["INFORM", "NINFOM", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        48 => (
+            &["CATDPG", "NOBJNM", "OBJNAM", "RESTRN", "STATUS"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        49 => (
+            &[
+                "CONDTN", "CONRAD", "DATEND", "DATSTA", "HEIGHT", "NATCON", "VERACC", "VERDAT",
+                "VERLEN",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        50 => (
+            &["NATION"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        51 => (
+            &[
+                "DATEND", "DATSTA", "DRVAL1", "NOBJNM", "OBJNAM", "ORIENT", "QUASOU", "RESTRN",
+                "SOUACC", "STATUS", "TRAFIC", "VERDAT",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        52 => (
+            &[
+                "CATFNC", "COLOUR", "COLPAT", "CONDTN", "CONRAD", "CONVIS", "ELEVAT", "HEIGHT",
+                "NATCON", "NOBJNM", "OBJNAM", "STATUS", "VERACC", "VERDAT", "VERLEN",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        53 => (
+            &[
+                "CATFRY", "DATEND", "DATSTA", "NOBJNM", "OBJNAM", "PEREND", "PERSTA", "STATUS",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        54 => (
+            &["NATION", "NOBJNM", "OBJNAM", "STATUS"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        55 => (
+            &[
+                "CATFIF", "NOBJNM", "OBJNAM", "PEREND", "PERSTA", "STATUS", "VERACC", "VERLEN",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        56 => (
+            &["NOBJNM", "OBJNAM", "PEREND", "PERSTA", "STATUS"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        57 => (
+            &[
+                "COLOUR", "COLPAT", "CONDTN", "CONRAD", "CONVIS", "DATEND", "DATSTA", "DRVAL1",
+                "HORACC", "HORCLR", "HORLEN", "HORWID", "LIFCAP", "NOBJNM", "OBJNAM", "STATUS",
+                "VERACC", "VERLEN", "VERDAT",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        58 => (
+            &[
+                "CATFOG", "DATEND", "DATSTA", "NOBJNM", "OBJNAM", "PEREND", "PERSTA", "SIGFRQ",
+                "SIGGEN", "SIGGRP", "SIGPER", "SIGSEQ", "STATUS", "VALMXR",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        59 => (
+            &[
+                "CATFOR", "CONDTN", "CONRAD", "CONVIS", "HEIGHT", "NATCON", "NOBJNM", "OBJNAM",
+                "VERACC", "VERDAT", "VERLEN",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        60 => (
+            &["NOBJNM", "OBJNAM", "STATUS"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        61 => (
+            &[
+                "CATGAT", "CONDTN", "DRVAL1", "HORACC", "HORCLR", "NATCON", "NOBJNM", "OBJNAM",
+                "QUASOU", "SOUACC", "STATUS", "VERACC", "VERCLR", "VERDAT",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        62 => (
+            &[
+                "HORACC", "HORLEN", "HORWID", "NATCON", "NOBJNM", "OBJNAM", "STATUS", "VERACC",
+                "VERLEN", "WATLEV",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        63 => (
+            &["NOBJNM", "OBJNAM", "STATUS"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        64 => (
+            &[
+                "CATHAF", "CONDTN", "DATEND", "DATSTA", "NATCON", "NOBJNM", "OBJNAM", "PEREND",
+                "PERSTA", "STATUS",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        65 => (
+            &[
+                "CATHLK", "COLOUR", "COLPAT", "CONRAD", "CONVIS", "HORACC", "HORLEN", "HORWID",
+                "NOBJNM", "OBJNAM", "VERACC", "VERLEN", "CONDTN",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        66 => (
+            &[
+                "CATICE", "CONVIS", "ELEVAT", "HEIGHT", "NOBJNM", "OBJNAM", "PEREND", "PERSTA",
+                "STATUS", "VERACC", "VERDAT", "VERLEN",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        67 => (
+            &["NOBJNM", "OBJNAM", "PEREND", "PERSTA", "RESTRN", "STATUS"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        68 => (
+            &["CATTSS", "DATEND", "DATSTA", "RESTRN", "STATUS"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        69 => (
+            &["ELEVAT", "NOBJNM", "OBJNAM", "VERACC", "VERDAT"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        70 => (
+            &["NOBJNM", "OBJNAM"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        71 => (
+            &["CONDTN", "NOBJNM", "OBJNAM", "STATUS"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        72 => (
+            &["CONVIS", "ELEVAT", "NOBJNM", "OBJNAM", "VERACC", "VERDAT"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        73 => (
+            &["CATLND", "NATQUA", "NATSUR", "NOBJNM", "OBJNAM", "WATLEV"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        74 => (
+            &[
+                "CATLMK", "COLOUR", "COLPAT", "CONDTN", "CONRAD", "CONVIS", "ELEVAT", "FUNCTN",
+                "HEIGHT", "NATCON", "NOBJNM", "OBJNAM", "STATUS", "VERACC", "VERDAT", "VERLEN",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        75 => (
+            &[
+                "CATLIT", "COLOUR", "DATEND", "DATSTA", "EXCLIT", "HEIGHT", "LITCHR", "LITVIS",
+                "MARSYS", "MLTYLT", "NOBJNM", "OBJNAM", "ORIENT", "PEREND", "PERSTA", "SECTR1",
+                "SECTR2", "SIGGRP", "SIGPER", "SIGSEQ", "STATUS", "VERACC", "VALNMR", "VERDAT",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        76 => (
+            &[
+                "COLOUR", "COLPAT", "CONRAD", "CONVIS", "DATEND", "DATSTA", "HORACC", "HORLEN",
+                "HORWID", "MARSYS", "NATCON", "NOBJNM", "OBJNAM", "PEREND", "PERSTA", "STATUS",
+                "VERACC", "VERLEN",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        77 => (
+            &[
+                "COLOUR", "COLPAT", "CONRAD", "CONVIS", "DATEND", "DATSTA", "HORACC", "HORLEN",
+                "HORWID", "NATCON", "NOBJNM", "OBJNAM", "PEREND", "PERSTA", "STATUS", "VERACC",
+                "VERLEN",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        78 => (
+            &["NOBJNM", "OBJNAM", "VALLMA"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        79 => (
+            &[
+                "DATEND", "DATSTA", "HORACC", "HORCLR", "HORLEN", "HORWID", "NOBJNM", "OBJNAM",
+                "STATUS",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        80 => (
+            &["NOBJNM", "OBJNAM", "STATUS"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        81 => (
+            &["DATEND", "DATSTA", "RYRMGV", "VALACM", "VALMAG"],
+            &["INFORM", "NINFOM", "SCAMAX", "SCAMIN"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        82 => (
+            &[
+                "CATMFA", "DATEND", "DATSTA", "EXPSOU", "NOBJNM", "OBJNAM", "PEREND", "PERSTA",
+                "QUASOU", "RESTRN", "SOUACC", "STATUS", "VALSOU", "VERACC", "VERDAT", "VERLEN",
+                "WATLEV",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        83 => (
+            &[
+                "CATMPA", "DATEND", "DATSTA", "NOBJNM", "OBJNAM", "PEREND", "PERSTA", "RESTRN",
+                "STATUS",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        84 => (
+            &[
+                "BOYSHP", "CATMOR", "COLOUR", "COLPAT", "CONDTN", "CONRAD", "CONVIS", "DATEND",
+                "DATSTA", "HEIGHT", "NATCON", "NOBJNM", "OBJNAM", "PEREND", "PERSTA", "STATUS",
+                "VERACC", "VERDAT", "VERLEN", "WATLEV",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        85 => (
+            &[
+                "CATNAV", "DATEND", "DATSTA", "ORIENT", "PEREND", "PERSTA", "STATUS",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        86 => (
+            &[
+                "CATOBS", "CONDTN", "EXPSOU", "HEIGHT", "NATCON", "NATQUA", "NOBJNM", "OBJNAM",
+                "PRODCT", "QUASOU", "SOUACC", "STATUS", "TECSOU", "VALSOU", "VERACC", "VERDAT",
+                "VERLEN", "WATLEV", "NATSUR",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        87 => (
+            &[
+                "CATOFP", "COLOUR", "COLPAT", "CONDTN", "CONRAD", "CONVIS", "DATEND", "DATSTA",
+                "HEIGHT", "NATCON", "NOBJNM", "OBJNAM", "PRODCT", "STATUS", "VERACC", "VERDAT",
+                "VERLEN",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        88 => (
+            &[
+                "CATPRA", "CONDTN", "CONRAD", "CONVIS", "DATEND", "DATSTA", "HEIGHT", "NOBJNM",
+                "OBJNAM", "PRODCT", "RESTRN", "STATUS", "VERACC", "VERLEN",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        89 => (
+            &[
+                "CATOLB", "CONDTN", "DATEND", "DATSTA", "NOBJNM", "OBJNAM", "STATUS",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        90 => (
+            &[
+                "CATPLE", "COLOUR", "COLPAT", "CONDTN", "CONVIS", "DATEND", "DATSTA", "HEIGHT",
+                "NOBJNM", "OBJNAM", "VERACC", "VERDAT", "VERLEN",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        91 => (
+            &[
+                "CATPIL", "COMCHA", "DATEND", "DATSTA", "NOBJNM", "NPLDST", "OBJNAM", "PEREND",
+                "PERSTA", "PILDST", "STATUS",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        92 => (
+            &[
+                "CONDTN", "DATEND", "DATSTA", "NOBJNM", "OBJNAM", "PRODCT", "RESTRN", "STATUS",
+                "CATPIP",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        93 => (
+            &[
+                "CATPIP", "CONDTN", "CONRAD", "CONVIS", "DATEND", "DATSTA", "NOBJNM", "OBJNAM",
+                "PRODCT", "STATUS", "VERACC", "VERCLR", "VERDAT",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        94 => (
+            &[
+                "BURDEP", "CATPIP", "CONDTN", "DATEND", "DATSTA", "DRVAL1", "DRVAL2", "NOBJNM",
+                "OBJNAM", "PRODCT", "STATUS", "VERACC", "VERLEN", "VERDAT",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        95 => (
+            &[
+                "CONDTN", "CONRAD", "CONVIS", "DATEND", "DATSTA", "NATCON", "NOBJNM", "OBJNAM",
+                "PEREND", "PERSTA", "STATUS", "VERACC", "VERLEN",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        96 => (
+            &["DATEND", "DATSTA", "RESTRN", "STATUS"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        97 => (
+            &[
+                "CATPRA", "CONDTN", "CONRAD", "CONVIS", "DATEND", "DATSTA", "ELEVAT", "HEIGHT",
+                "NOBJNM", "OBJNAM", "PRODCT", "STATUS", "VERACC", "VERDAT", "VERLEN",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        98 => (
+            &[
+                "CATPYL", "COLOUR", "COLPAT", "CONDTN", "CONRAD", "CONVIS", "DATEND", "DATSTA",
+                "HEIGHT", "NATCON", "NOBJNM", "OBJNAM", "VERACC", "VERDAT", "VERLEN", "WATLEV",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        99 => (
+            &["NOBJNM", "OBJNAM", "ORIENT", "STATUS"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        100 => (
+            &["COMCHA", "DATEND", "DATSTA", "NOBJNM", "OBJNAM", "STATUS"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        101 => (
+            &["HEIGHT", "STATUS", "VERACC", "VERDAT"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        102 => (
+            &[
+                "CATRAS", "DATEND", "DATSTA", "HEIGHT", "NOBJNM", "OBJNAM", "PEREND", "PERSTA",
+                "STATUS", "VERACC", "VALMXR", "VERDAT",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        103 => (
+            &[
+                "CATRTB", "DATEND", "DATSTA", "NOBJNM", "OBJNAM", "PEREND", "PERSTA", "RADWAL",
+                "SECTR1", "SECTR2", "SIGGRP", "SIGSEQ", "STATUS", "VALMXR",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        104 => (
+            &[
+                "COMCHA", "DATEND", "DATSTA", "NOBJNM", "OBJNAM", "ORIENT", "PEREND", "PERSTA",
+                "STATUS", "TRAFIC",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        105 => (
+            &[
+                "CALSGN", "CATROS", "COMCHA", "DATEND", "DATSTA", "ESTRNG", "NOBJNM", "OBJNAM",
+                "ORIENT", "PEREND", "PERSTA", "SIGFRQ", "STATUS",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        106 => (
+            &["CONDTN", "HEIGHT", "NOBJNM", "OBJNAM", "STATUS", "VERACC"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        107 => (
+            &["NOBJNM", "OBJNAM", "VERACC", "VERLEN"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        108 => (
+            &[
+                "CATTRK", "DATEND", "DATSTA", "DRVAL1", "DRVAL2", "NOBJNM", "OBJNAM", "ORIENT",
+                "PEREND", "PERSTA", "QUASOU", "SOUACC", "STATUS", "TECSOU", "TRAFIC", "VERDAT",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        109 => (
+            &[
+                "CATTRK", "DATEND", "DATSTA", "DRVAL1", "DRVAL2", "NOBJNM", "OBJNAM", "ORIENT",
+                "PEREND", "PERSTA", "QUASOU", "SOUACC", "STATUS", "TECSOU", "TRAFIC", "VERDAT",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        110 => (
+            &["DATEND", "DATSTA", "ORIENT", "STATUS"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        111 => (
+            &[
+                "CATRSC", "DATEND", "DATSTA", "NOBJNM", "OBJNAM", "PEREND", "PERSTA", "STATUS",
+            ],
+            &["INFORM", "NINFOM", "SCAMAX", "SCAMIN"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        112 => (
+            &[
+                "CATREA", "DATEND", "DATSTA", "NOBJNM", "OBJNAM", "PEREND", "PERSTA", "RESTRN",
+                "STATUS",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        113 => (
+            &[
+                "COLOUR", "COLPAT", "DATEND", "DATSTA", "HEIGHT", "MARSYS", "PEREND", "PERSTA",
+                "STATUS", "VERACC", "VERDAT",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        114 => (
+            &["NOBJNM", "OBJNAM", "STATUS"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        115 => (
+            &["NOBJNM", "OBJNAM"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        116 => (
+            &["CATROD", "CONDTN", "NATCON", "NOBJNM", "OBJNAM", "STATUS"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        117 => (
+            &[
+                "CATRUN", "CONDTN", "CONVIS", "NATCON", "NOBJNM", "OBJNAM", "PEREND", "PERSTA",
+                "STATUS",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        118 => (
+            &["VERACC", "VERLEN"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        119 => (
+            &["CATSEA", "NOBJNM", "OBJNAM"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        120 => (
+            &["NOBJNM", "OBJNAM", "PEREND", "PERSTA", "RESTRN", "STATUS"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        121 => (
+            &["COLOUR", "NATQUA", "NATSUR", "WATLEV", "OBJNAM", "NOBJNM"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        122 => (
+            &[
+                "CATSLC", "COLOUR", "COLPAT", "CONDTN", "CONRAD", "CONVIS", "DATEND", "DATSTA",
+                "HEIGHT", "HORACC", "HORCLR", "HORLEN", "HORWID", "NATCON", "NOBJNM", "OBJNAM",
+                "STATUS", "VERACC", "VERDAT", "VERLEN", "WATLEV",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        123 => (
+            &[
+                "CATSIT", "COMCHA", "DATEND", "DATSTA", "NOBJNM", "OBJNAM", "PEREND", "PERSTA",
+                "STATUS",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        124 => (
+            &[
+                "CATSIW", "COMCHA", "DATEND", "DATSTA", "NOBJNM", "OBJNAM", "PEREND", "PERSTA",
+                "STATUS",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        125 => (
+            &[
+                "BUISHP", "CATSIL", "COLOUR", "COLPAT", "CONDTN", "CONRAD", "CONVIS", "ELEVAT",
+                "HEIGHT", "NATCON", "NOBJNM", "OBJNAM", "PRODCT", "STATUS", "VERACC", "VERDAT",
+                "VERLEN",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        126 => (
+            &[
+                "CATSLO", "COLOUR", "CONRAD", "CONVIS", "ELEVAT", "NATCON", "NATQUA", "NATSUR",
+                "NOBJNM", "OBJNAM", "VERACC", "VERDAT",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        127 => (
+            &[
+                "CATSLO", "COLOUR", "CONRAD", "CONVIS", "NATCON", "NATQUA", "NATSUR", "NOBJNM",
+                "OBJNAM",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        128 => (
+            &["CATSCF", "NOBJNM", "OBJNAM", "PEREND", "PERSTA", "STATUS"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        129 => (
+            &[
+                "EXPSOU", "NOBJNM", "OBJNAM", "QUASOU", "SOUACC", "TECSOU", "VERDAT", "STATUS",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        130 => (
+            &["NOBJNM", "OBJNAM"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        131 => (
+            &["CONDTN", "NATCON", "NOBJNM", "OBJNAM", "STATUS"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        132 => (
+            &["NATION"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        133 => (
+            &["NOBJNM", "OBJNAM", "RESTRN"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        134 => (
+            &["DRVAL1", "QUASOU", "SOUACC", "TECSOU", "VERDAT"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        135 => (
+            &["NATION", "RESTRN"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        136 => (
+            &["NOBJNM", "OBJNAM", "T_MTOD", "T_VAHC", "STATUS"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        137 => (
+            &["NOBJNM", "OBJNAM", "T_MTOD", "T_THDF", "STATUS"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        138 => (
+            &["NOBJNM", "OBJNAM", "TS_TSP"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        139 => (
+            &[
+                "NOBJNM", "OBJNAM", "STATUS", "TIMEND", "TIMSTA", "T_TINT", "TS_TSV",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        140 => (
+            &["NOBJNM", "OBJNAM", "T_ACWL", "T_MTOD", "T_VAHC", "STATUS"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        141 => (
+            &["NOBJNM", "OBJNAM", "T_ACWL", "T_MTOD", "T_THDF", "STATUS"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        142 => (
+            &[
+                "NOBJNM", "OBJNAM", "T_HWLW", "T_TINT", "T_TSVL", "TIMEND", "TIMSTA", "STATUS",
+                "T_ACWL",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        143 => (
+            &["NOBJNM", "OBJNAM"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        144 => (
+            &[
+                "COLOUR", "COLPAT", "DATEND", "DATSTA", "HEIGHT", "MARSYS", "PEREND", "PERSTA",
+                "STATUS", "TOPSHP", "VERACC", "VERDAT", "VERLEN",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        145 => (
+            &["CATTSS", "DATEND", "DATSTA", "STATUS"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        146 => (
+            &["CATTSS", "DATEND", "DATSTA", "STATUS"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        147 => (
+            &["CATTSS", "DATEND", "DATSTA", "RESTRN", "STATUS"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        148 => (
+            &["CATTSS", "DATEND", "DATSTA", "ORIENT", "RESTRN", "STATUS"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        149 => (
+            &["CATTSS", "DATEND", "DATSTA", "RESTRN", "STATUS"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        150 => (
+            &["CATTSS", "DATEND", "DATSTA", "STATUS"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        151 => (
+            &[
+                "BURDEP", "CONDTN", "HORACC", "HORCLR", "NOBJNM", "OBJNAM", "STATUS", "VERACC",
+                "VERCLR",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        152 => (
+            &[
+                "CATTRK", "DATEND", "DATSTA", "DRVAL1", "DRVAL2", "ORIENT", "QUASOU", "SOUACC",
+                "STATUS", "TECSOU", "TRAFIC", "VERDAT",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        153 => (
+            &[
+                "EXPSOU", "NATSUR", "NATQUA", "NOBJNM", "OBJNAM", "QUASOU", "SOUACC", "STATUS",
+                "TECSOU", "VALSOU", "VERDAT", "WATLEV",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        154 => (
+            &[],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        155 => (
+            &[
+                "CATVEG", "CONVIS", "ELEVAT", "HEIGHT", "NOBJNM", "OBJNAM", "VERACC", "VERDAT",
+                "VERLEN",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        156 => (
+            &["CATWAT", "NOBJNM", "OBJNAM"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        157 => (
+            &["CONVIS", "NOBJNM", "OBJNAM", "VERACC", "VERLEN"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        158 => (
+            &["CATWED", "NOBJNM", "OBJNAM"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        159 => (
+            &[
+                "CATWRK", "CONRAD", "CONVIS", "EXPSOU", "HEIGHT", "NOBJNM", "OBJNAM", "QUASOU",
+                "SOUACC", "STATUS", "TECSOU", "VALSOU", "VERACC", "VERDAT", "VERLEN", "WATLEV",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        160 => (
+            &[
+                "CAT_TS", "CURVEL", "DATEND", "DATSTA", "NOBJNM", "OBJNAM", "ORIENT", "PEREND",
+                "PERSTA",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        161 => (
+            &["DATEND", "DATSTA", "NATION", "NOBJNM", "OBJNAM"],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        162 => (
+            &["DATEND", "DATSTA", "NATION", "NOBJNM", "OBJNAM"],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        163 => (
+            &[
+                "CLSDEF", "CLSNAM", "COLOUR", "COLPAT", "CONDTN", "CONRAD", "CONVIS", "DATEND",
+                "DATSTA", "NATION", "NOBJNM", "OBJNAM", "PEREND", "PERSTA", "RESTRN", "STATUS",
+                "WATLEV",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "SYMINS", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        300 => (
+            &["HORACC", "POSACC", "SOUACC", "VERACC"],
+            &["INFORM", "NINFOM", "NTXTDS", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        301 => (
+            &["CSCALE"],
+            &["INFORM", "NINFOM", "NTXTDS", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        302 => (
+            &["CATCOV"],
+            &["INFORM", "NINFOM"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        303 => (
+            &["HORDAT"],
+            &["INFORM", "NINFOM", "NTXTDS", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        304 => (
+            &["HORDAT", "SHIPAM"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        305 => (
+            &[],
+            &["INFORM", "NINFOM", "NTXTDS", "PICREP", "PUBREF", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        306 => (
+            &["MARSYS", "ORIENT"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMAX", "SCAMIN", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        307 => (
+            &["AGENCY", "CPDATE", "NATION", "NMDATE", "PRCTRY"],
+            &["INFORM", "NINFOM", "NTXTDS", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        308 => (
+            &[
+                "CATQUA", "CATZOC", "DRVAL1", "DRVAL2", "POSACC", "SOUACC", "SUREND", "SURSTA",
+                "TECSOU", "VERDAT",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        309 => (
+            &["VERDAT"],
+            &["INFORM", "NINFOM", "NTXTDS", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        310 => (
+            &[
+                "QUAPOS", "QUASOU", "SCVAL1", "SCVAL2", "SDISMN", "SDISMX", "SURATH", "SUREND",
+                "SURSTA", "SURTYP", "TECSOU",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        311 => (
+            &["DUNITS", "HUNITS", "PUNITS"],
+            &["INFORM", "NINFOM", "NTXTDS", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        312 => (
+            &["VERDAT"],
+            &["INFORM", "NINFOM", "NTXTDS", "TXTDSC"],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        400 => (
+            &["NOBJNM", "OBJNAM"],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        401 => (
+            &["NOBJNM", "OBJNAM"],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        402 => (
+            &[],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        500 => (
+            &["COLOUR", "ORIENT", "$SCODE", "$TINTS"],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        501 => (
+            &["$SCODE"],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        502 => (
+            &["ORIENT", "$SCALE", "$SCODE"],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        503 => (
+            &["$CSIZE", "RYRMGV", "VALACM", "VALMAG"],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        504 => (
+            &[
+                "$CHARS", "COLOUR", "$JUSTH", "$JUSTV", "$NTXST", "$SPACE", "$TXSTR",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMAX", "SCAMIN", "TXTDSC",
+            ],
+            &["RECDAT", "RECIND", "SORDAT", "SORIND"],
+        ),
+        17000 => (
+            &[
+                "catach", "clsdng", "comctn", "DATEND", "DATSTA", "NOBJNM", "OBJNAM", "PEREND",
+                "PERSTA", "RADIUS", "restrn", "STATUS",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMIN", "TXTDSC", "updmsg",
+            ],
+            &["SORDAT", "SORIND"],
+        ),
+        17001 => (
+            &[
+                "catach", "clsdng", "comctn", "DATEND", "DATSTA", "NOBJNM", "OBJNAM", "PEREND",
+                "PERSTA", "restrn", "STATUS",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMIN", "TXTDSC", "updmsg",
+            ],
+            &["SORDAT", "SORIND"],
+        ),
+        17002 => (
+            &[
+                "catbnk", "CONRAD", "DATEND", "DATSTA", "NATSUR", "NOBJNM", "OBJNAM",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMIN", "TXTDSC", "updmsg",
+            ],
+            &["SORDAT", "SORIND"],
+        ),
+        17003 => (
+            &[
+                "DRVAL1", "DRVAL2", "eleva1", "eleva2", "wtwdis", "QUASOU", "SOUACC", "verdat",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMIN", "TXTDSC", "updmsg",
+            ],
+            &["SORDAT", "SORIND"],
+        ),
+        17004 => (
+            &[
+                "catdis", "wtwdis", "unlocd", "DATEND", "DATSTA", "NOBJNM", "OBJNAM",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMIN", "TXTDSC", "updmsg",
+            ],
+            &["SORDAT", "SORIND"],
+        ),
+        17005 => (
+            &[
+                "CATREA", "DATEND", "DATSTA", "NOBJNM", "OBJNAM", "PEREND", "PERSTA", "restrn",
+                "STATUS",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMIN", "TXTDSC", "updmsg",
+            ],
+            &["SORDAT", "SORIND"],
+        ),
+        17006 => (
+            &["catbnk", "CONRAD", "NATSUR", "NOBJNM", "OBJNAM"],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMIN", "TXTDSC", "updmsg",
+            ],
+            &["SORDAT", "SORIND"],
+        ),
+        17007 => (
+            &[
+                "catsit", "COMCHA", "DATEND", "DATSTA", "dirimp", "NOBJNM", "OBJNAM", "PEREND",
+                "PERSTA", "STATUS",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMIN", "TXTDSC", "updmsg",
+            ],
+            &["SORDAT", "SORIND"],
+        ),
+        17008 => (
+            &[
+                "catsiw", "COMCHA", "DATEND", "DATSTA", "NOBJNM", "OBJNAM", "PEREND", "PERSTA",
+                "STATUS",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMIN", "TXTDSC", "updmsg",
+            ],
+            &["SORDAT", "SORIND"],
+        ),
+        17009 => (
+            &[
+                "COLOUR", "COLPAT", "HEIGHT", "marsys", "STATUS", "TOPSHP", "VERACC", "verdat",
+                "VERLEN",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMIN", "TXTDSC", "updmsg",
+            ],
+            &["SORDAT", "SORIND"],
+        ),
+        17010 => (
+            &[
+                "catbrt", "clsdng", "comctn", "DATEND", "DATSTA", "DRVAL1", "NOBJNM", "OBJNAM",
+                "PEREND", "PERSTA", "QUASOU", "SOUACC", "STATUS", "trshgd", "verdat",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMIN", "TXTDSC", "updmsg",
+            ],
+            &["SORDAT", "SORIND"],
+        ),
+        17011 => (
+            &[
+                "catbrg", "comctn", "COLOUR", "COLPAT", "CONDTN", "CONRAD", "CONVIS", "DATEND",
+                "DATSTA", "HORACC", "HORCLR", "NATCON", "NOBJNM", "OBJNAM", "TIMEND", "TIMSTA",
+                "VERACC", "VERCCL", "VERCLR", "VERCOP", "verdat",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMIN", "TXTDSC", "updmsg",
+            ],
+            &["SORDAT", "SORIND"],
+        ),
+        17012 => (
+            &[
+                "CATCBL", "CONDTN", "CONRAD", "CONVIS", "DATEND", "DATSTA", "ICEFAC", "NOBJNM",
+                "OBJNAM", "STATUS", "VERACC", "VERCLR", "VERCSA", "verdat",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "SCAMIN", "TXTDSC", "updmsg", "RECDAT", "RECIND",
+            ],
+            &["SORDAT", "SORIND"],
+        ),
+        17013 => (
+            &[
+                "catfry", "comctn", "DATEND", "DATSTA", "NOBJNM", "OBJNAM", "PEREND", "PERSTA",
+                "STATUS", "TIMEND", "TIMSTA",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMIN", "TXTDSC", "updmsg",
+            ],
+            &["SORDAT", "SORIND"],
+        ),
+        17014 => (
+            &["cathbr", "comctn", "NOBJNM", "OBJNAM", "STATUS", "unlocd"],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMIN", "TXTDSC", "updmsg",
+            ],
+            &["SORDAT", "SORIND"],
+        ),
+        17015 => (
+            &[
+                "cathaf", "CONDTN", "DATEND", "DATSTA", "NATCON", "NOBJNM", "OBJNAM", "PEREND",
+                "PERSTA", "STATUS",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMIN", "TXTDSC", "updmsg"],
+            &["SORDAT", "SORIND"],
+        ),
+        17016 => (
+            &[
+                "HORACC", "horcll", "horclw", "HORLEN", "HORWID", "NOBJNM", "OBJNAM", "STATUS",
+                "TIMEND", "TIMSTA",
+            ],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMIN", "TXTDSC", "updmsg"],
+            &["SORDAT", "SORIND"],
+        ),
+        17017 => (
+            &[
+                "catcom", "comctn", "COMCHA", "DATEND", "DATSTA", "NOBJNM", "OBJNAM", "ORIENT",
+                "PEREND", "PERSTA", "STATUS", "TRAFIC", "dirimp",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMIN", "TXTDSC", "updmsg",
+            ],
+            &["SORDAT", "SORIND"],
+        ),
+        17018 => (
+            &["marsys", "ORIENT"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMIN", "TXTDSC", "updmsg"],
+            &["SORDAT", "SORIND"],
+        ),
+        17050 => (
+            &[
+                "catnmk", "fnctnm", "dirimp", "disipd", "disipu", "disbk1", "disbk2", "addmrk",
+                "marsys", "ORIENT", "CONDTN", "NOBJNM", "OBJNAM", "STATUS",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMIN", "TXTDSC", "updmsg",
+            ],
+            &["SORDAT", "SORIND"],
+        ),
+        17051 => (
+            &["catccl", "NOBJNM", "OBJNAM"],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMIN", "TXTDSC", "updmsg",
+            ],
+            &["SORDAT", "SORIND"],
+        ),
+        17052 => (
+            &["wtwdis", "HEIGHT", "verdat"],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMIN", "TXTDSC", "updmsg",
+            ],
+            &["SORDAT", "SORIND"],
+        ),
+        17053 => (
+            &["comctn", "NOBJNM", "OBJNAM"],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMIN", "TXTDSC", "updmsg",
+            ],
+            &["SORDAT", "SORIND"],
+        ),
+        17054 => (
+            &[
+                "bunves", "catbun", "comctn", "NOBJNM", "OBJNAM", "TIMEND", "TIMSTA",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMIN", "TXTDSC", "updmsg",
+            ],
+            &["SORDAT", "SORIND"],
+        ),
+        17055 => (
+            &[
+                "catcom", "COMCHA", "DATEND", "DATSTA", "NOBJNM", "OBJNAM", "STATUS", "TIMEND",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMIN", "TXTDSC", "updmsg",
+            ],
+            &["SORDAT", "SORIND"],
+        ),
+        17056 => (
+            &["HORACC", "HORLEN", "HORWID", "NOBJNM", "OBJNAM", "STATUS"],
+            &["INFORM", "NINFOM", "NTXTDS", "SCAMIN", "TXTDSC", "updmsg"],
+            &["SORDAT", "SORIND"],
+        ),
+        17057 => (
+            &["comctn", "NOBJNM", "OBJNAM", "STATUS"],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMIN", "TXTDSC", "updmsg",
+            ],
+            &["SORDAT", "SORIND"],
+        ),
+        17058 => (
+            &[
+                "HORACC", "horcll", "horclw", "HORLEN", "HORWID", "NOBJNM", "OBJNAM", "STATUS",
+                "TIMEND", "TIMSTA",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMIN", "TXTDSC", "updmsg",
+            ],
+            &["SORDAT", "SORIND"],
+        ),
+        17059 => (
+            &["comctn", "NOBJNM", "OBJNAM", "STATUS", "unlocd"],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMIN", "TXTDSC", "updmsg",
+            ],
+            &["SORDAT", "SORIND"],
+        ),
+        17060 => (
+            &[
+                "BCNSHP", "catwwm", "COLOUR", "COLPAT", "CONDTN", "CONRAD", "CONVIS", "DATEND",
+                "DATSTA", "dirimp", "ELEVAT", "HEIGHT", "marsys", "NATCON", "NOBJNM", "OBJNAM",
+                "PEREND", "PERSTA", "STATUS", "VERACC", "verdat", "VERLEN",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMIN", "TXTDSC", "updmsg",
+            ],
+            &["SORDAT", "SORIND"],
+        ),
+        17061 => (
+            &[
+                "BOYSHP", "catwwm", "COLOUR", "COLPAT", "CONDTN", "CONRAD", "CONVIS", "DATEND",
+                "DATSTA", "marsys", "NATCON", "NOBJNM", "OBJNAM", "PEREND", "PERSTA", "STATUS",
+                "VERLEN",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMIN", "TXTDSC", "updmsg",
+            ],
+            &["SORDAT", "SORIND"],
+        ),
+        17062 => (
+            &[
+                "catrfd", "comctn", "NOBJNM", "OBJNAM", "STATUS", "TIMEND", "TIMSTA",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMIN", "TXTDSC", "updmsg",
+            ],
+            &["SORDAT", "SORIND"],
+        ),
+        17063 => (
+            &["NOBJNM", "OBJNAM"],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMIN", "TXTDSC", "updmsg",
+            ],
+            &["SORDAT", "SORIND"],
+        ),
+        17064 => (
+            &[
+                "cattml", "comctn", "NOBJNM", "OBJNAM", "STATUS", "TIMEND", "TIMSTA", "trshgd",
+                "unlocd",
+            ],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMIN", "TXTDSC", "updmsg",
+            ],
+            &["SORDAT", "SORIND"],
+        ),
+        17065 => (
+            &["HORCLR", "NOBJNM", "STATUS", "OBJNAM"],
+            &[
+                "INFORM", "NINFOM", "NTXTDS", "PICREP", "SCAMIN", "TXTDSC", "updmsg",
+            ],
+            &["SORDAT", "SORIND"],
+        ),
+        20484 => (
+            &["authty", "linech", "NOBJNM", "OBJNAM"],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20485 => (
+            &[
+                "authty", "catasr", "linech", "maxalt", "maxftl", "minalt", "minftl", "NOBJNM",
+                "OBJNAM", "HUNITS", "VERDAT",
+            ],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20486 => (
+            &[
+                "bearng", "catimg", "ELEVAT", "HUNITS", "orgntr", "SUREND", "VERDAT",
+            ],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20487 => (
+            &[
+                "ccmidx", "exitus", "gradnt", "HORCLR", "HORLEN", "HORWID", "HUNITS", "VERCSA",
+                "wbrcap",
+            ],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20488 => (
+            &["bearng", "gradnt", "SUREND"],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20489 => (
+            &[
+                "accres", "brktyp", "ccmidx", "dgmrlf", "HORLEN", "HORWID", "HUNITS", "quabch",
+                "orgntr", "srfhgt", "srfzne", "stbacv", "SUREND", "SURSTA", "swlhgt", "tdlrng",
+                "tdltyp",
+            ],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20490 => (&["N/A"], &["N/A"], &[]),
+        20491 => (
+            &[
+                "catbot", "DUNITS", "gradnt", "HORLEN", "HORWID", "HUNITS", "migspd", "migdir",
+                "NOBJNM", "OBJNAM", "ORIENT", "soudat", "stfotn", "VALSOU", "VERLEN", "WATLEV",
+                "wavlen",
+            ],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20492 => (&["N/A"], &["N/A"], &[]),
+        20494 => (
+            &["orgntr", "surdat", "SUREND"],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20495 => (
+            &[
+                "authty", "catcas", "caircd", "linech", "maxalt", "maxftl", "minalt", "minftl",
+                "NOBJNM", "OBJNAM", "HUNITS", "VERDAT",
+            ],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20496 => (
+            &[
+                "depact", "divact", "DUNITS", "OBJNAM", "NOBJNM", "timeyr", "watclr",
+            ],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20497 => (&["N/A"], &["N/A"], &[]),
+        20498 => (
+            &["apprch", "extdes", "lndcon", "OBJNAM", "NOBJNM", "STATUS"],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20499 => (
+            &["authty", "legsta", "OBJNAM", "NOBJNM", "PEREND", "PERSTA"],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20500 => (
+            &["catfsh", "STATUS", "timeyr"],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20501 => (
+            &[
+                "datfir", "datlst", "depwat", "DUNITS", "gendep", "HORLEN", "HORWID", "HUNITS",
+                "NATQUA", "NATSUR", "NOBJNM", "OBJNAM", "orcard", "ORIENT", "QUASOU", "senfir",
+                "senlst", "sonsig", "sorfir", "sorlst", "SOUACC", "soudat", "STATUS", "TECSOU",
+                "VALSOU", "VERLEN", "WATLEV",
+            ],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20502 => (
+            &["apprch", "extdes", "lndcon", "OBJNAM", "NOBJNM", "STATUS"],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20503 => (
+            &["gradnt", "STATUS", "wbrcap"],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20504 => (
+            &["apprch", "extdes", "lndcon", "OBJNAM", "NOBJNM", "STATUS"],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20505 => (
+            &["apprch", "extdes", "lndcon", "OBJNAM", "NOBJNM", "STATUS"],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20506 => (
+            &["apprch", "extdes", "lndcon", "OBJNAM", "NOBJNM", "STATUS"],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20507 => (
+            &["apprch", "extdes", "lndcon", "OBJNAM", "NOBJNM", "STATUS"],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20508 => (
+            &[
+                "actper", "authty", "catmma", "identy", "linech", "NOBJNM", "OBJNAM", "NATION",
+                "spcies", "STATUS",
+            ],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20509 => (
+            &["catmsi", "condet", "NATION", "NOBJNM", "OBJNAM"],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20510 => (
+            &["mhclas", "milden", "nomden"],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20511 => (
+            &[
+                "actper", "authty", "catmea", "linech", "maxalt", "maxftl", "minalt", "minftl",
+                "NOBJNM", "OBJNAM", "HUNITS", "VERDAT",
+            ],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20513 => (
+            &[
+                "authty", "catpat", "identy", "linech", "NOBJNM", "OBJNAM", "NATION", "STATUS",
+            ],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20514 => (
+            &[
+                "actper", "dnbear", "lftwid", "NATION", "NOBJNM", "OBJNAM", "rclass", "rgtwid",
+                "STATUS", "TRAFIC", "HUNITS", "upbear",
+            ],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20515 => (
+            &["NOBJNM", "OBJNAM"],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20516 => (&["N/A"], &["N/A"], &[]),
+        20517 => (
+            &[
+                "attutn", "bulkdn", "COLOUR", "deplyr", "dttdep", "dttnum", "DUNITS", "gascon",
+                "grnsiz", "hfbmls", "laynum", "lfbmls", "mgstyp", "reflco", "migspd", "migdir",
+                "msstrg", "natsed", "NATQUA", "porsty", "revebn", "revfqy", "revgan", "samret",
+                "sndvel", "snrflc", "soudat", "WATLEV", "wbrcap",
+            ],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20518 => (
+            &["bearng", "ricsca"],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20519 => (
+            &[
+                "datfir", "datlst", "DUNITS", "gendep", "HUNITS", "madsig", "magany", "magint",
+                "NOBJNM", "OBJNAM", "orcard", "ORIENT", "QUASOU", "scrdim", "senfir", "senlst",
+                "sonsig", "sorfir", "sorlst", "soudat", "SOUACC", "STATUS", "TECSOU", "VALSOU",
+                "WATLEV",
+            ],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20520 => (
+            &["OBJNAM", "NOBJNM", "STATUS"],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20521 => (&["N/A"], &["N/A"], &[]),
+        20522 => (
+            &["cattrf"],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20523 => (
+            &["HUNITS", "HORWID", "ORIENT"],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20524 => (
+            &["NOBJNM", "OBJNAM"],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20525 => (
+            &[
+                "bearng", "discon", "DUNITS", "shpspd", "snrfrq", "snrrsc", "twdbdp",
+            ],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20526 => (
+            &["mntden", "undmnr", "umnrwb", "umrwob"],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20527 => (
+            &["brmchm", "brperd", "brprob", "tgrfwt"],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20528 => (
+            &["lsract", "timeyr"],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20529 => (
+            &["clperc", "clprob", "csprob", "cswidt", "dsprob", "dtprob"],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20530 => (
+            &["typres", "STATUS"],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20531 => (
+            &["conlev", "numrmn", "prbrmn", "rmnlmn", "sminth", "znecol"],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20532 => (
+            &[
+                "actper", "CALSGN", "CATROS", "COMCHA", "NOBJNM", "OBJNAM", "SIGFRQ",
+            ],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20533 => (
+            &["linech", "NATION", "RESTRN", "STATUS"],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20534 => (
+            &[
+                "iceact", "icecvt", "icesod", "icemax", "icemin", "icerdv", "NOBJNM", "OBJNAM",
+            ],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20535 => (
+            &["iceadc", "NOBJNM", "OBJNAM"],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20536 => (
+            &["icebnm", "NOBJNM", "OBJNAM"],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20537 => (
+            &["icelnd", "NOBJNM", "OBJNAM"],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20538 => (
+            &["icelnc", "NOBJNM", "OBJNAM"],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20539 => (
+            &["NOBJNM", "OBJNAM"],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20540 => (
+            &["icepst", "icepty", "NOBJNM", "OBJNAM"],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20541 => (
+            &["icelty", "icelst", "NOBJNM", "OBJNAM"],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20542 => (
+            &["icebsz", "icebsh", "icebdr", "icebsp", "NOBJNM", "OBJNAM"],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20543 => (
+            &["icebsp", "icebdr", "NOBJNM", "OBJNAM"],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20544 => (
+            &[
+                "linech", "NOBJNM", "OBJNAM", "PEREND", "PERSTA", "traden", "TRAFIC", "typshp",
+            ],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20717 => (
+            &["txtdes"],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        20718 => (
+            &[
+                "blndzn", "brmchm", "brpctg", "COLOUR", "comsys", "datfir", "datlst", "depwat",
+                "DUNITS", "gendep", "HORLEN", "HORWID", "HUNITS", "incltn", "layptm", "layrfn",
+                "laytim", "madsig", "magany", "magint", "minern", "miscls", "miscom", "misdat",
+                "misnme", "mnhsys", "mnimnc", "mnimnt", "mnssys", "mulcon", "mwdcrn", "NATCON",
+                "navsys", "notfnd", "nmprob", "objtrn", "objshp", "onsonr", "orbobn", "orgdat",
+                "orgntr", "ORIENT", "QUASOU", "scrdim", "senfir", "senlst", "snrflc", "soudat",
+                "stacon", "surdat", "SUREND", "tarstg", "TECSOU", "unwrfm", "VERLEN",
+            ],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        21484 => (
+            &["catcnf"],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        21485 => (
+            &[],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        21486 => (
+            &["vershf"],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        21487 => (
+            &["linech"],
+            &[
+                "AGENCY", "CSCALE", "elvacc", "errell", "HORACC", "INFORM", "NINFOM", "NTXTDS",
+                "PICREP", "POSACC", "PRCTRY", "PUBREF", "RECDAT", "QUAPOS", "seccvt", "secido",
+                "secown", "secpmk", "SORDAT", "SORIND", "TXTDSC", "VERACC",
+            ],
+            &[],
+        ),
+        _ => return None,
+    };
+    Some(ClassAttributes { a, b, c })
+}
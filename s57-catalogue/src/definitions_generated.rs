@@ -0,0 +1,281 @@
+// Generated from s57definitions.csv
+// DO NOT EDIT - run scripts/generate_catalogue.nu to regenerate
+
+/// Definition text and references from the IHO Object Catalogue
+///
+/// Taken from S-57 Appendix A, Chapters 1 (object classes) and 2 (attributes).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Definition {
+    /// Definition of the object class or attribute
+    pub text: &'static str,
+    /// INT 1 / M-4 chart specification references; empty if none are given
+    pub references: &'static str,
+}
+
+/// Look up an object class definition by OBJL code
+pub(crate) fn object_definition(objl: u16) -> Option<Definition> {
+    let (text, references) = match objl {
+        1 => ("A defined (and possibly named) administrative area.", "INT 1: not specified; M-4: not specified"),
+        2 => ("An area containing at least one runway, used for landing, take-off, and movement of aircraft.", "INT 1: ID 17; M-4: 366"),
+        3 => ("A designated area of water where a single vessel, sea plane, etc... may anchor.", "INT 1: IN 11.1-2; M-4: 431.2"),
+        4 => ("An area in which vessels anchor or may anchor. (IHO Dictionary, S-32, 5th Edition, 130)", "INT 1: IN 12.1-9; M-4: 431.3"),
+        5 => ("A beacon is a prominent, specially constructed object forming a conspicuous mark as a fixed aid to navigation or for use in hydrographic survey (IHO Dictionary, S-32, 5th Edition, 420). A cardinal beacon is used in conjunction with the compass to indicate where the mariner may find the best navigable water. It is placed in one of the four quadrants (North, East, South and West), bounded by inter-cardinal bearings from the point marked. (UKHO NP 735, 5th Edition)", "INT 1: IQ 130.3; M-4: 461"),
+        6 => ("A beacon is a prominent specially constructed object forming a conspicuous mark as a fixed aid to navigation or for use in hydrographic survey (IHO Dictionary, S-32, 5th Edition, 420). An isolated danger beacon is a beacon erected on an isolated danger of limited extent, which has navigable water all around it. (UKHO NP735, 5th Edition)", "INT 1: IQ 130.4; M-4: 463.1"),
+        7 => ("A beacon is a prominent specially constructed object forming a conspicuous mark as a fixed aid to navigation or for use in hydrographic survey (IHO Dictionary, S-32, 5th Edition, 420). A lateral beacon is used to indicate the port or starboard hand side of the route to be followed. They are generally used for well defined channels and are used in conjunction with a conventional direction of buoyage. (UKHO NP 735, 5th Edition)", "INT 1: IQ 91-92, 130.1; M-4: not specified"),
+        8 => ("A safe water beacon is a prominent specially constructed object forming a conspicuous mark as a fixed aid to navigation or for use in hydrographic survey (IHO Dictionary, S-32, 5th Edition, 420). A safe water beacon may be used to indicate that there is navigable water around the mark. (UKHO NP735, 5th Edition)", "INT 1: IQ 130.5; M-4: 456.4"),
+        9 => ("A beacon is a prominent specially constructed object forming a conspicuous mark as a fixed aid to navigation or for use in hydrographic survey (IHO Dictionary, S-32, 5th Edition, 420). A special purpose beacon is primarily used to indicate an area or feature, the nature of which is apparent from reference to a chart, Sailing Directions or Notices to Mariners. (UKHO NP 735, 5th Edition) Beacon in general: A beacon whose appearance or purpose is not adequately known.", "INT 1: IQ 130.6; M-4: 456.4"),
+        10 => ("A named or numbered place where a vessel is moored at a wharf. (IHO Dictionary, S-32, 5th Edition, 470)", "INT 1: IF 19; M-4: 321.1"),
+        11 => ("A structure erected over a depression or an obstacle such as a body of water, railroad, etc... to provide a roadway for vehicles, pedestrians or to carry utility services. (IHO Dictionary, S-32, 5th Edition, 544)", "INT 1: ID 20, 21, 22, 23.1-6, 24; M-4: 381.1-3"),
+        12 => ("A relatively permanent structure, roofed and usually walled. It is designed for some particular use which it may be important to indicate. (Digital Geographic Information Working Group, Oct.87)", "INT 1: ID 5-6, 13; IE 10.1, 10.3, 11, 13-18, 28-30.1; IF 51, 60-63; M-4: 325.1-3; 328.1; 362.2; 370.3,5; 372.1; 373.1-4; 375.1,2; 487.3"),
+        13 => ("An area containing a concentration of buildings and the supporting road or rail infrastructure.", "INT 1: ID 1-4; M-4: 370.3-4; 370.6-7"),
+        14 => ("A buoy is a floating object moored to the bottom in a particular place, as an aid to navigation or for other specific purposes. (IHO Dictionary S-32 5th Edition, 565). A cardinal buoy is used in conjunction with the compass to indicate where the mariner may find the best navigable water. It is placed in one of the four quadrants (North, East, South and West), bounded by inter-cardinal bearings from the point marked. (UKHO NP 735, 5th Edition)", "INT 1: IQ 130.3; M-4: 461; 462.5, 462.6"),
+        15 => ("A buoy is a floating object moored to the bottom in a particular place, as an aid to navigation or for other specific purposes. (IHO Dictionary, S-32, 5th Edition, 565). An installation buoy is a buoy used for loading tankers with gas or oil. (IHO Chart Specifications, M-4)", "INT 1: IL 16; M-4: 445.4"),
+        16 => ("A buoy is a floating object moored to the bottom in a particular place, as an aid to navigation or for other specific purposes. (IHO Dictionary, S-32, 5th Edition, 565). A isolated danger buoy is a buoy moored on or above an isolated danger of limited extent, which has navigable water all around it. (UKHO NP735, 5th Edition)", "INT 1: IQ 130.4; M-4: 461"),
+        17 => ("A buoy is a floating object moored to the bottom in a particular place, as an aid to navigation or for other specific purposes. (IHO Dictionary, S-32, 5th Edition, 565). A lateral buoy is used to indicate the port or starboard hand side of the route to be followed. They are generally used for well defined channels and are used in conjunction with a conventional direction of buoyage. (UKHO NP 735, 5th Edition)", "INT 1: IQ 130.1; M-4: 461"),
+        18 => ("A buoy is a floating object moored to the bottom in a particular place, as an aid to navigation or for other specific purposes. (IHO Dictionary, S-32, 5th Edition, 565). A safe water buoy is used to indicate that there is navigable water around the mark. (UKHO NP735, 5th Edition)", "INT 1: IQ 130.5; M-4: 461"),
+        19 => ("A buoy is a floating object moored to the bottom in a particular place, as an aid to navigation or for other specific purposes. (IHO Dictionary, S-32, 5th Edition, 565). A special purpose buoy is primarily used to indicate an area or feature, the nature of which is apparent from reference to a chart, Sailing Directions or Notices to Mariners. (UKHO NP 735, 5th Edition) Buoy in general: A buoy whose appearance or purpose is not adequately known.", "INT 1: IQ 130.6; M-4: 461"),
+        20 => ("An area which contains one or more submarine cables.", "INT 1: IL 30.2, 31.2; M-4: 439.3; 443.2"),
+        21 => ("An overhead cable is an assembly of wires or fibres, or a wire rope or chain, which is supported by structures such as poles or pylons and passing over or nearby navigable waters. (Hydrographic Service, Royal Australian Navy).", "INT 1: ID 26, 27; M-4: 382; 382.1-2"),
+        22 => ("An assembly of wires or fibres, or a wire rope or chain which has been laid underwater or buried beneath the seabed (Hydrographic Service, Royal Australian Navy)", "INT 1: IL 30.1, 31.1, 32; M-4: 443.1; 443.3; 443.7"),
+        23 => ("An artificial waterway with no flow, or a controlled flow, used for navigation, or for draining or irrigating land (ditch). (United States Geological Survey, Jan.89)", "INT 1: IF 40; M-4: 361.6"),
+        24 => ("The limit line between the water area of a canal and the area of land.", "INT 1: IF 40; M-4: 361.6"),
+        25 => ("An area designated for the transfer of cargo from one vessel to another. (adapted from IHO Dictionary, S-32, 5th Edition, 5593).", "INT 1: IN 64; M-4: 449.4"),
+        26 => ("A raised way across low or wet ground or water. (IHO Dictionary, S-32, 5th Edition, 662)", "INT 1: IF 3; M-4: 313.3"),
+        27 => ("Generally, an area where the mariner has to be made aware of circumstances influencing the safety of navigation.", "INT 1: IM 29.2; M-4: not specified"),
+        28 => ("An official place to register, declare or check goods and people.", "INT 1: not specified; M-4: not specified"),
+        29 => ("Watch keeping stations at which a watch is kept either continuously, or at certain times only. (IHO Chart Specifications, M-4)", "INT 1: IT 10; M-4: 492"),
+        30 => ("The line where shore and water meet. Although the terminology of coasts and shores is rather confused, shoreline and coastline are generally used as synonyms. (IHO Dictionary, S-32, 5th Edition, 858,4695)", "INT 1: IC 1-8, 32-33; M-4: 310; 312.1-4"),
+        31 => ("A zone contiguous to a coastal State's territorial sea, which may not extend beyond 24 nautical miles from the baselines from which the breadth of the territorial sea is measured. The coastal state may exercise certain control in this zone subject to the provisions of International Law. (IHO Dictionary, S-32, 5th Edition, 993)", "INT 1: IN 44; M-4: 440.6"),
+        32 => ("The continental shelf of a coastal State comprises the sea bed and subsoil of the submarine areas that extend beyond its territorial sea throughout the natural prolongation of its land territory to the outer edge of the continental margin, or to a distance of 200 nautical miles from the baselines from which the breadth of the territorial sea is measured where the outer edge of the continental margin does not extend out to that distance. (IHO Publication S-51)", "INT 1: IN 46; M-4: 440.8"),
+        33 => ("A point on the ground where position (horizontal and vertical) is used as a base for a dependent survey. Also referred to as a control station. (IHO Dictionary, S-32, 5th Edition, 1026)", "INT 1: IB 20-24; M-4: 304.1-3; 305.1; 306"),
+        34 => ("A mechanical apparatus for moving bulk material or people from place to place (as by a moving belt or chain of receptacles).", "INT 1: ID25; M-4: 382.3"),
+        35 => ("A machine for lifting, shifting and lowering objects or materials by means of a swinging boom or with a lifting apparatus supported on an overhead track. (Digital Geographic Information Working Group, Oct.87)", "INT 1: IF 53.1-3; M-4: 328.3"),
+        36 => ("Currents (non-gravitational) include either singly or in combination: ocean currents (wind and/or density driven), inter-oceanic equalising currents, currents of navigable rivers, river outflow effects offshore and other non-tidal flows.", "INT 1: IH 42-43; M-4: 408.2-3"),
+        37 => ("The area within which national custom regulations are in force.", "INT 1: IN 48; M-4: 440.2"),
+        38 => ("A barrier to check or confine anything in motion; particularly one constructed to hold back water and raise its level to form a reservoir, or to prevent flooding. (IHO Dictionary, S-32, 5th Edition, 1196)", "INT 1: IF 44; M-4: 364.2"),
+        39 => ("The identifying characteristics of an aid to navigation which serve to facilitate its recognition against a daylight viewing background. On those structures that do not by themselves present an adequate viewing area to be seen at the required distance, the aid is made more visible by affixing a daymark to the structure. A daymark so affixed has a distinctive colour and shape depending on the purpose of the aid. (IHO Dictionary, S-32, 5th Edition, 1248)", "INT 1: IQ 101; M-4: 456.2"),
+        40 => ("A deep water route is a route in a designated area, within defined limits, which has been accurately surveyed for clearance of sea bottom and submerged obstacles to a minimum indicated depth of water. (IHO Dictionary, S-32, 5th Edition, 1280) The deep water route centerline indicates the centerline of a route, the width of which is not explicitly defined.", "INT 1: IM 27.3; M-4: 435.3"),
+        41 => ("A deep water route is a route in a designated area, within defined limits, which has been accurately surveyed for clearance of sea bottom and submerged obstacles to a minimum indicated depth of water. (IHO Dictionary, S-32, 5th Edition, 1280)", "INT 1: IM 27.1-2; M-4: 435, 435.3; 436.3"),
+        42 => ("A depth area is a water area whose depth is within a defined range of values.", "INT 1: not specified; M-4: not specified"),
+        43 => ("A line connecting points of equal water depth which is sometimes significantly displaced outside of soundings, symbols and other chart detail for clarity as well as generalization. Depth contours, therefore, often represent an approximate location of the line of equal depth as related to the surveyed line delineated on the source. Also referred to as depth curve. (IHO Dictionary, S-32, 5th Edition, 1314, 1315)", "INT 1: II 15, 30, 31; M-4: 404.2; 410; 411, 411.2; 413-413.2"),
+        44 => ("A distance mark indicates the distance measured from an origin and consists of either a solid visible structure or a distinct location without special installation. Usually found on canals.", "INT 1: IF 40; M-4: 361.3; 307"),
+        45 => ("A dock is an artificially enclosed area within which ships may moor and which may have gates to regulate water level (adapted from IHO Chart Specifications, M-4).", "INT 1: IF 27,28; M-4: 326.3-4"),
+        46 => ("An area of the bottom of a body of water which has been deepened by dredging. (IHO Dictionary, S- 32, 5th Edition, 1462)", "INT 1: II 20-23; M-4: 414.1-2; 414.4"),
+        47 => ("An artificial basin fitted with a gate or caisson, into which vessels can be floated and the water pumped out to expose the vessel's bottom. Also called graving dock. (IHO Dictionary, S-32, 5th Edition, 1426)", ""),
+        48 => ("A sea area where dredged material or other potentially more harmful material, e.g. explosives, chemical waste, is deliberately deposited. (Derived from IHO Chart Specifications, M-4).", "INT 1: IN 23-24, 62.1-2; M-4: 442.1-4; 446a"),
+        49 => ("A dyke (or dike) is an artificial embankment to contain or hold back water.(IHO Dictionary, S-32, 5th Edition, 1361)", "INT 1: IF 1; M-4: 313.1"),
+        50 => ("An area, not exceeding 200 nautical miles from the baselines from which the breadth of the territorial sea is measured, subject to a specific legal regime established in the United Nations Convention on the Law of the Sea under which the coastal state has certain rights and jurisdiction. (IHO Dictionary, S-32, 5th Edition, 1723)", "INT 1: IN 47; M-4: 440.9"),
+        51 => ("That part of a river, harbour and so on, where the main navigable channel for vessels of larger size lies. It is also the usual course followed by vessels entering or leaving harbours, called 'ship channel' (International Maritime Dictionary, 2nd Ed.)", "INT 1: not specified; M-4: not specified"),
+        52 => ("A natural or man-made barrier used as an enclosure or boundary or for protection. (adapted from Digital Geographic Information Working Group, Oct.1987)", "INT 1: not specified; M-4: not specified"),
+        53 => ("A route in a body of water where a ferry crosses from one shoreline to another. (Digital Geographic Information Working Group, Oct.87)", "INT 1: IM 50, 51; M-4: 438.1, 438.2"),
+        54 => ("The offshore zone in which exclusive fishing rights and management are held by the coastal nation. (IHO Dictionary, S-32, 5th Edition, 1816)", "INT 1: IN 45; M-4: 440.7"),
+        55 => ("A structure in shallow water for fishing purposes which can be an obstruction to ships in general. The position of these structures may vary frequently over time.", "INT 1: IK 44.1-2, 45; M-4: 447.1-3"),
+        56 => ("A water area in which fishing is frequently carried on. (IHO Dictionary, S-32, 5th Edition, 1814)", "INT 1: not specified; M-4: not specified"),
+        57 => ("A form of dry dock consisting of a floating structure of one or more sections which can be partly submerged by controlled flooding to receive a vessel, then raised by pumping out the water so that the vessel's bottom can be exposed. (IHO Dictionary, S-32, 5th Edition, 1427)", "INT 1: IF 26; M-4: 326.2"),
+        58 => ("A warning signal transmitted by a vessel, or aid to navigation, during periods of low visibility. Also, the device producing such a signal. (IHO Dictionary, S-32, 5th Edition, 1890)", "INT 1: IR 1, 10-16, 20-22; M-4: 452-452.8"),
+        59 => ("A structure for the military defence of a site.", "INT 1: IE 34.1-3; M-4: 379.1-2"),
+        60 => ("A port where certain import and export duties are waived (unless goods pass into the country) to facilitate reshipment to other countries. (IHO Dictionary, S-32, 5th Edition, 1927)", "INT 1: not specified; M-4: not specified"),
+        61 => ("A structure that may be swung, drawn, or lowered to block an entrance or passageway. (United States Geological Survey, Jan.89)", "INT 1: IF 27, 41.1-2, 42-43; M-4: 326.3; 326.5; 326.6; 326.7"),
+        62 => ("A timber structure in the intertidal zone serving as a support for vessels at low stages of the tide to permit work on the exposed portion of the vessel's hull. Also called careening grid. (adapted from IHO Dictionary, S-32, 5th Edition, 649)", "INT 1: IF 24; M-4: 326.8"),
+        63 => ("The area over which a harbour authority has jurisdiction.", "INT 1: IN 49; M-4: 430.1"),
+        64 => ("A harbour installation with a service or commercial operation of public interest.", "INT 1: IF 10, 50; IU 1.1,; M-4: 320.1-2; 321.5; 328.2"),
+        65 => ("A permanently moored ship.", "INT 1: IF 34; M-4: not specified"),
+        66 => ("An area of ice over land or water.", "INT 1: IC 25; IN 60.1-2; M-4: 353.8; 449.1"),
+        67 => ("An offshore area officially designated as suitable for the burning of chemical waste by specially equipped ships. (IHO Dictionary, S-32, 5th Edition, 2408)", "INT 1: IN 65; M-4: 449.3"),
+        68 => ("A routeing measure comprising a designated area between the landward boundary of a traffic separation scheme and the adjacent coast, to be used in accordance with the provisions of the International Regulations for Preventing Collisions at Sea. (IHO Dictionary, S-32, 5th Edition, 2457)", "INT 1: IM 25.1; M-4: 435.1"),
+        69 => ("A large body of water entirely surrounded by land. (IHO Dictionary, S-32, 5th Edition, 2629)", "INT 1: IC 23; M-4: 353.6"),
+        70 => ("The limit line between the water area of a lake and the area of land.", "INT 1: IC 23; M-4: 353, 353.6"),
+        71 => ("The solid portion of the Earth's surface, as opposed to sea, water. (IHO Dictionary, S-32, 5th Edition, 2635)", "INT 1: IK 10; M-4: 421.1"),
+        72 => ("An elevation is the vertical distance of a point or a level, on, or affixed to, the surface of the earth, measured from a specified vertical datum. (IHO Dictionary, S-32, 5th Edition, 1590)", "INT 1: IC 10-13; M-4: 351; 352.1-2"),
+        73 => ("An area of natural scenery on land. It is defined by its geographical characteristics and may be known by its proper name.", "INT 1: IC 24, 26, 33; M-4: 312.1-4; 355"),
+        74 => ("A prominent object at a fixed location which can be used in determining a location or a direction. (adapted from IHO Dictionary, S-32, 5th Edition, 2643).", "INT 1: ID 5-6, 13; IE 10.1-20, 22-30.1, 30.3-4, 31; IL 11; IQ 100; M-4: 373.6; 374.1; 374.4; 374.5; 374.6; 374.7; 375.1-2; 375.4; 445.6; 456.2; 487.3"),
+        75 => ("A luminous or lighted aid to navigation. (adapted from IHO Dictionary, S-32, 5th Edition, 2766)", "INT 1: IP 1-30.3, 40-65; M-4: 470-473.5; 475-475.7; 476-478,5"),
+        76 => ("A boat-like structure used instead of a light buoy in waters where strong streams or currents are experienced, or when a greater elevation than that of a light buoy is necessary (IHO Dictionary, S-32, 5th Edition, 2821).", "INT 1: IQ 30-31; M-4: 462.8"),
+        77 => ("A distinctively marked vessel anchored or moored at a charted point, to serve as an aid to navigation. By night, it displays a characteristic light(s) and is usually equipped with other devices, such as fog signal, submarine sound signal, and radio-beacon, to assist navigation. Also called light ship. (IHO Dictionary, S-32, 5th Edition, 2828,2829)", "INT 1: IP 6; M-4: 474.1-3; 474.5-6"),
+        78 => ("An anomaly of the magnetic field of the Earth, extending over a relatively small area, due to local magnetic influences. (IHO Dictionary, S-32, 5th Edition, 2874, 2984)", "INT 1: IB 82.1-2; M-4: 274"),
+        79 => ("A lock basin is a wet dock in a waterway, permitting a ship to pass from one level to another. (adapted from IHO Dictionary, S-32, 5th Edition, 2881)", "INT 1: IF 41.1; M-4: 326.6"),
+        80 => ("A maritime area enclosed with connected floating timbers used as a staging area for sawn logs.", "INT 1: IN 61; M-4: 449.2"),
+        81 => ("The angle between the magnetic and geographic (true) north at a location, expressed in degrees east or west from the direction of true north.", "INT 1: IB 64-66, 71; M-4: 261; 272.1,3"),
+        82 => ("An assemblage of cages, nets, rafts and floats or posts where fish, including shellfish, are artificially cultivated. Also called fish farm. (IHO Dictionary, S-32, 5th Edition, 1811)", "INT 1: IK 47, 48.1-2; M-4: 447.4,6"),
+        83 => ("An area within which naval, military or aerial exercises are carried out. Also called an exercise area. (adapted from IHO Dictionary, S-32, 5th Edition, 1722)", "INT 1: IN 30-33; M-4: 441.1-6"),
+        84 => ("The equipment or structure used to secure a vessel (adapted from IHO Dictionary, S-32, 5th Edition, 3322)", "INT 1: IF 20,21,22; IG 181; IQ 40-43; M-4: 327.1-2,3; 431.5-6"),
+        85 => ("A navigation line is a straight line extending towards an area of navigational interest and generally generated by two navigational aids or one navigational aid and a bearing. (Service Hydrographique et OcÃ©anographique de la Marine, France)", "INT 1: IM 1-3; M-4: 433-433.5"),
+        86 => ("In marine navigation, anything that hinders or prevents movement, particularly anything that endangers or prevents passage of a vessel. The term is usually used to refer to an isolated danger to navigation... (IHO Dictionary, S-32, 5th Edition, 3503)", "INT 1: IK 1, 31, 40-43, 46.1-2; IL 21, 23; IQ 42; M-4: 422.8-9; 431.6; 445.1; 447.5"),
+        87 => ("A permanent offshore structure, either fixed or floating, used in the production of oil or natural gas. (IHO Dictionary, S-32, 5th Edition, 3895)", "INT 1: IL 2, 10, 11-15, 17; M-4: 445.2; 445.3; 445.4; 445.6"),
+        88 => ("An area at sea within which there are production facilities.", "INT 1: IL 4; M-4: not specified"),
+        89 => ("A construction to dam oil flow on water.", "INT 1: IF 29.1-2; M-4: not specified"),
+        90 => ("A long heavy timber or section of steel, wood, concrete, etc.. forced into the earth which may serve as a support, as for a pier, or a free standing pole within a marine environment. (Adapted from IHO Dictionary, S-32, 5th Edition, 3840)", "INT 1: IF 22; M-4: 327.3"),
+        91 => ("The meeting place to which the pilot comes out. (IHO Chart Specifications, M-4)", "INT 1: IT 1.1-4; M-4: 491.1-2"),
+        92 => ("An area containing one or more pipelines.", "INT 1: IL 40.2, 41.2; M-4: 439.3; 444.2"),
+        93 => ("A pipeline is a string of interconnected pipes used for the transport of matter, nowadays mainly oil or gas. (IHO Dictionary, S-32, 5th Edition, 3857) An overhead pipeline is a pipeline supported by pylons and passing over or nearby navigable waters.", "INT 1: ID 28; M-4: 383"),
+        94 => ("A pipeline is a string of interconnected pipes used for the transport of matter, nowadays mainly oil or gas. (IHO Dictionary, S-32, 5th Edition, 3857) A submarine or land pipeline is a pipeline lying on or buried under the seabed or the land.", "INT 1: ID 29; IL 40.1, 41.1, 42, 44; M-4: 377; 444.1; 444.4-5; 444.7"),
+        95 => ("A floating structure, usually rectangular in shape which serves as landing, pier head or bridge support. (IHO Dictionary, S-32, 5th Edition, 3947)", "INT 1: IF 16; M-4: 326.9"),
+        96 => ("A routeing measure comprising an area within defined limits where ships must navigate with particular caution and within which the direction of traffic flow may be recommended. (IHO Dictionary, S-32, 5th Edition, 3982)", "INT 1: IM 16, 24; M-4: 435.2"),
+        97 => ("An area on land for the exploitation or storage of natural resources.", "INT 1: IE 35.1-2, 36; IF 52; M-4: 367.1-2"),
+        98 => ("A vertical construction consisting, for example, of a steel framework or pre-stressed concrete to carry cables, a bridge, etc.", "INT 1: ID 26; M-4: 382.1"),
+        99 => ("A track along which ships may be guided by coastal radar stations in the event of bad visibility. Also known as a radar guided track. (IHO Dictionary, S-32, 5th Edition, 4146).", "INT 1: IM 32.1-2; M-4: 487.2"),
+        100 => ("Indicates the coverage of a sea area by a radar surveillance station. Inside this area a vessel may request shore-based radar assistance, particularly in poor visibility.", "INT 1: IM 31; M-4: 487.1"),
+        101 => ("A device capable of, or intended for, reflecting radar signals. (IHO Dictionary, S-32, 5th Edition, 4147) A radar reflector is usually a 'tetrahedron or pentagonal corner reflector (...) to facilitate reflection towards the sender' . (International Maritime Dictionary, 2nd Ed.)", "INT 1: IS 4; M-4: 465.1-2"),
+        102 => ("A station with a transmitter emitting pulses of ultra-high frequency radio waves which are reflected by solid objects and are detected upon their return to the sending station. (International Maritime Dictionary, 2nd Ed.)", "INT 1: IM 30; IS 1; M-4: 485.1; 487.3"),
+        103 => ("A transponder beacon transmitting a coded signal on radar frequency, permitting an interrogating craft to determine the bearing and range of the transponder. Also called racon. (IHO Dictionary, S-32, 5th Edition, 4137)", "INT 1: IS 2-3; M-4: 486.1-3"),
+        104 => ("Also called radio reporting points, they have been established in certain busy waterways and port approaches to assist traffic control. On passing these points or crossing a defined line vessels are required to report on VHF to a Traffic Control Centre. (adapted from IHO Chart Specifications, M-4)", "INT 1: IM 40; M-4: 488"),
+        105 => ("A place equipped to transmit radio waves. Such a station may be either stationary or mobile, and may also be provided with a radio receiver. In British terminology, also called w/t station. (IHO Dictionary, S-32, 5th Edition, 4191)", "INT 1: IS 10-16; M-4: 480.1; 481.1-3; 482; 483; 484"),
+        106 => ("A rail or set of parallel rails on which a train or tram runs. (Digital Geographic Information Working Group, Oct.87)", "INT 1: ID 13; M-4: 328.4; 362.1-2"),
+        107 => ("Portions of a stream with accelerated current where it descends rapidly but without a break in the slope of the bed sufficient to form a waterfall. Usually used in the plural. (IHO Dictionary, S-32, 5th Edition, 4228)", "INT 1: IC 22; M-4: 353.5"),
+        108 => ("A recommended route is a route of undefined width, for the convenience of ships in transit, which is often marked by centerline buoys. (IHO Dictionary, S-32, 5th Edition, 4448) The recommended route centerline indicates the 'centerline' of a recommended route.", "INT 1: IM 28.1; M-4: 435.4"),
+        109 => ("A track recommended to all or only certain vessels. (IHO Dictionary, S-32, 5th Edition, 5576)", "INT 1: IM 3-4, 5.1, 5.2, 6; M-4: 432.1; 434"),
+        110 => ("An optional part of an IMO-adopted routing measure.... Several Hydrographic Offices, in consultation with their Ministries of Transport, have added recommended directions in areas such as the outer approaches to major ports in order to show the best routes for crossing traffic or to minimize head-on encounters.(...) (IHO Chart Specifications, M-4)", "INT 1: IM 26.1-2; M-4: 435.5"),
+        111 => ("A place at which life saving equipment is held. (IHO Chart Specifications, M-4)", "INT 1: IT 12-14; IQ 124; M-4: 493; 493.1-2"),
+        112 => ("A specified area designated by an appropriate authority within which navigation is restricted in accordance with certain specified conditions. (adapted from IHO Dictionary, S-32, 5th Edition, 4366)", "INT 1: IL 3; IN 2.1-2, 20-22, 25-26, 31, 34; M-4: 431.4; 439.2-4; 441.8; 445.2; 448.1-2; 449.5"),
+        113 => ("A means of distinguishing unlighted marks at night. Retro-reflective material is secured to the mark in a particular pattern to reflect back light. (Adapted from the UKHO NP735, 5th Edition).", "INT 1: not specified; M-4: not specified"),
+        114 => ("A relatively large natural stream of water. (IHO Dictionary, S-32, 5th Edition, 4405)", "INT 1: IC 20, 21; M-4: 353.1-4"),
+        115 => ("The limit line between the water area of a river and the area of land.", "INT 1: IC 20, 21; M-4: 353.1-4"),
+        116 => ("A road is an open way for the passage of vehicles. (United States Geological Survey, Jan.89)", "INT 1: ID 10-12; M-4: 365.1-3"),
+        117 => ("A defined rectangular area, on a land aerodrome, prepared for the landing and take-off run of aircraft along its length. (IHO Dictionary, S-32, 5th Edition, 4465) A site on which helicopters may land and take off. (IHO Dictionary, S-32, 5th Edition, 2232)", "INT 1: ID 17; M-4: 366"),
+        118 => ("A large mobile wave-like sediment feature in shallow water and composed of sand. The wavelength may reach 100 metres, the amplitude may be up to 20 metres.", "INT 1: IJ 14; M-4: 428.1"),
+        119 => ("A geographically defined part of the sea or other navigable waters. It may be specified within its limits by its proper name.", "INT 1: not specified; M-4: not specified"),
+        120 => ("A designated portion of water for the landing and take-off of sea-planes.", "INT 1: IN 13; M-4: 449.6"),
+        121 => ("An area of the sea where the nature of bottom is homogeneous. The nature of bottom includes the material of which it is composed and its physical characteristics. Also called character (or characteristics) of the bottom, or quality of the bottom. (IHO Dictionary, S- 32, 5th Edition, 515).", "INT 1: IJ 1-11, 30-39; M-4: 425.5-6; 426; 427"),
+        122 => ("A fixed (not afloat) artificial structure between the water and the land, i.e. a man-made coastline.", "INT 1: IF 2, 4, 5, 6, 12-15, 18,23, 33; M-4: 313.2, 4; 321.1-4; 322.1-2; 324.1"),
+        123 => ("A signal station is a place on shore from which signals are made to ships at sea. (IHO Dictionary, S- 32, 5th Edition, 4742) Traffic signal stations regulate the movement of traffic. (IHO Chart Specifications, M-4)", "INT 1: IT 21-25.2; M-4: 495.1-5"),
+        124 => ("A signal station is a place on shore from which signals are made to ships at sea. (IHO Dictionary, S- 32, 5th Edition, 4742)", "INT 1: IT 20, 26, 28-36; M-4: 490.3; 494.1-2; 496.1-3; 497"),
+        125 => ("An enclosed container, used for storage (Digital Geographic Information Working Group, Oct.87)", "INT 1: IE 2, 32-33; M-4: 340.2; 376.2-3"),
+        126 => ("The upper marking of a slope, e.g. the ridge line or the separation line between two different gradients.", "INT 1: IC 3; ID 14, 15; M-4: 312.1; 363.2; 364.1"),
+        127 => ("An inclined surface (adapted from IHO Dictionary, S-32, 5th Edition, 4776).", "INT 1: IC 3, 4, 8; ID 14, 15; IF 1; M-4: 312.1; 312.3; 313.1; 313.2"),
+        128 => ("A place at which a service generally of interest to small craft or pleasure boats is available.", "INT 1: IU 2, 3-4, 6-13, 15-31; M-4: not specified"),
+        129 => ("A measured water depth or spot which has been reduced to a vertical datum (may be a drying height).", "INT 1: II 10-15; M-4: 403.1; 410; 412-412.4; 413.1; 417.3"),
+        130 => ("A natural issue of water or other substances from the earth. One on the bottom of the sea is called a submarine spring. (IHO Dictionary, S-32, 5th Edition, 4939)", "INT 1: IJ 15; M-4: 428.3"),
+        131 => ("An open area within a built-up area surrounded by roads.", "INT 1: not specified; M-4: not specified"),
+        132 => ("A baseline is the line from which the outer limits of the territorial sea and certain other outer limits are measured. (IHO Dictionary, S-32, 5th Edition, 390) Straight baselines are a system of straight lines joining specified or discrete points on the low-water line, usually known as straight baseline turning points. (IHO Dictionary, S-32, 5th Edition, 393)", "INT 1: IN 42; M-4: 440.4"),
+        133 => ("An area where submarines may navigate under water or at the surface.", "INT 1: IN 33; M-4: 441.5"),
+        134 => ("An area that has been determined to be clear of navigational dangers to a specified depth (adapted from IHO Dictionary, S-32, 5th Edition, 5248).", "INT 1: II 24; M-4: 415.1; 415.2"),
+        135 => ("The territorial sea is a belt of water of a defined breadth but not exceeding 12 nautical miles measured seaward from the territorial sea baseline. (IHO Dictionary, S-32, 5th Edition, 5360)", "INT 1: IN 43; M-4: 440.5"),
+        136 => ("A tidal stream (or tidal current) is an alternating horizontal movement of water associated with the rise and fall of the tide caused by tide-producing forces. (IHO Dictionary, S-32, 5th Edition, 1169) Predicted tidal stream rates may be calculated using parameters (harmonic constituents) and an appropriate harmonic calculation algorithm.", "INT 1: IH 40-41; M-4: 407.4; 408.2"),
+        137 => ("A tidal stream (or tidal current) is an alternating horizontal movement of water associated with the rise and fall of the tide caused by tide-producing forces. (IHO Dictionary, S-32, 5th Edition, 1169) Predicted tidal stream rates may be calculated using time and rate differences with respect to a reference station (and associated tidal stream predictions).", "INT 1: IH 40-41; M-4: 407.4; 408.2"),
+        138 => ("A tidal stream (or tidal current) is an alternating horizontal movement of water associated with the rise and fall of the tide caused by tide-producing forces. (IHO Dictionary, S-32, 5th Edition, 1169) Approximate tidal stream rates may be given as discrete rate values at a specified interval before or after a high water.", "INT 1: IH 40-41; M-4: 407.4; 408.2"),
+        139 => ("A tidal stream (or tidal current) is an alternating horizontal movement of water associated with the rise and fall of the tide caused by tide-producing forces. (IHO Dictionary, S-32, 5th Edition, 1169) Tidal stream rates over time may be approximated by a series of rate values given at regular time intervals, starting from a specified moment in time.", "INT 1: IH 40-41; M-4: 407.4; 408.2"),
+        140 => ("Tide - the periodic rise and fall of the surface of the sea, due principally to the gravitational interaction between moon, sun and earth. (adopted from IHO Dictionary, S-32, 5th Edition, 5429) Predicted tidal heights may be calculated using parameters (harmonic constituents) and an appropriate harmonic calculation algorithm.", "INT 1: not specified; M-4: not specified"),
+        141 => ("Tide - the periodic rise and fall of the surface of the sea, due principally to the gravitational interaction between moon, sun and earth. (adopted from IHO Dictionary, S-32, 5th Edition, 5429) Predicted tidal heights may be calculated using time and height differences with respect to a reference port (and associated tidal predictions).", "INT 1: not specified; M-4: not specified"),
+        142 => ("Tide - the periodic rise and fall of the surface of the sea, due principally to the gravitational interaction between moon, sun and earth. (adopted from IHO Dictionary, S-32, 5th Edition, 5429) Tidal heights over time may be approximated by a series of height values given at regular time intervals, starting from a specified moment in time.", "INT 1: not specified; M-4: not specified"),
+        143 => ("A natural water course in intertidal areas where water flows during the ebb or flow. A channel through which a tidal current runs. (IHO Dictionary, S-32, 5th Edition, 5502)", "INT 1: not specified; M-4: not specified"),
+        144 => ("A characteristic shape secured at the top of a buoy or beacon to aid in its identification. (IHO Dictionary, S-32, 5th Edition, 5548)", "INT 1: IQ 9; M-4: 463.1"),
+        145 => ("A traffic separation scheme is a scheme which aims to reduce the risk of collision in congested and/or converging areas by separating traffic moving in opposite, or nearly opposite, directions. (IHO Dictionary, S-32, 5th Edition, 5585) A traffic separation line is a line separating traffic lanes in which ships are travelling in opposite or nearly opposite directions; or separating traffic lanes designated for particular classes of ships proceeding in the same direction (IMO Ships Routeing, 6th Edition).", "INT 1: IM 12; M-4: 435.1"),
+        146 => ("A traffic separation scheme is a scheme which aims to reduce the risk of collision in congested and/or converging areas by separating traffic moving in opposite, or nearly opposite, directions. (IHO Dictionary, S-32, 5th Edition, 5585) The boundary of a traffic separation scheme is the outer limit of a traffic lane part or a traffic separation scheme roundabout.", "INT 1: not specified; M-4: 436"),
+        147 => ("A traffic separation scheme is a scheme which aims to reduce the risk of collision in congested and/or converging areas by separating traffic moving in opposite, or nearly opposite, directions. (IHO Dictionary, S-32, 5th Edition, 5585) A traffic separation scheme crossing is a defined area where traffic lanes cross.", "INT 1: IM 23; M-4: 435.1"),
+        148 => ("A traffic separation scheme is a scheme which aims to reduce the risk of collision in congested and/or converging areas by separating traffic moving in opposite, or nearly opposite, directions. (IHO Dictionary, S-32, 5th Edition, 5585) A traffic lane is an area within defined limits in which one-way traffic flow is established (IMO Ships Routeing, 6th Edition). A traffic separation scheme lane part is an area of a traffic lane in which the direction of flow of traffic is uniform.", "INT 1: not specified; M-4: not specified"),
+        149 => ("A traffic separation scheme is a scheme which aims to reduce the risk of collision in congested and/or converging areas by separating traffic moving in opposite, or nearly opposite, directions. (IHO Dictionary, S-32, 5th Edition, 5585) A roundabout is a traffic separation scheme in which traffic moves in a counter-clockwise direction around a specified point or zone. (IHO Dictionary, S-32, 5th Edition, 4448)", "INT 1: IM 21; M-4: 435.1"),
+        150 => ("A traffic separation scheme is a scheme which aims to reduce the risk of collision in congested and/or converging areas by separating traffic moving in opposite, or nearly opposite, directions. (IHO Dictionary, S-32, 5th Edition, 5585) A traffic separation zone is a zone separating the lanes in which ships are proceeding in opposite or nearly opposite directions; or separating traffic lanes designated for particular classes of ships proceeding in the same direction (IMO Ships Routeing, 6th Edition).", "INT 1: IM 13, 20.1; M-4: 435.1"),
+        151 => ("A passage that is open to the atmosphere at both ends, buried under the sea bed or laid over the sea floor or bored under the ground or through mountains.", "INT 1: ID 16; M-4: 363.1"),
+        152 => ("A two-way route is a route within defined limits inside which two-way traffic is established, aimed at providing safe passage of ships through waters where navigation is difficult or dangerous. (IHO Dictionary, S-32, 5th Edition, 5712) A two-way route part is an area of a two-way route within which traffic flow is generally along one bearing (and possibly its reciprocal).", "INT 1: IM 28.2; M-4: 435.6"),
+        153 => ("A concreted mass of stony material or coral which dries, is awash or is below the water surface.", "INT 1: IK 12, 13, 14-16; M-4: 421.3-5"),
+        154 => ("An area for which no bathymetric survey information is available.", "INT 1: not specified; M-4: 417.8"),
+        155 => ("Collections of, or individual plants.", "INT 1: IC 14, 30, 31.1-8, 32; M-4: 312.4; 354.1-2,4"),
+        156 => ("The disturbance of water caused by the interaction of any combination of waves, currents, tidal streams, wind, shoal patches and obstructions.", "INT 1: IH 44, 45; IK 17; M-4: 423.1; 423.2; 423.3"),
+        157 => ("A sudden descent of water over a step in the bed of a river. In place names commonly shortened to fall or falls, e.g. Niagara Falls.", "INT 1: IC 22; M-4: 353.5"),
+        158 => ("Seaweed is the general name for marine plants of the Algae class which grow in long narrow ribbons. (International Maritime Dictionary, 2nd Ed.) Kelp is one of an order (laminariales) of usually large, blade-shaped or vine-like brown algae. (IHO Dictionary, S-32, 5th Edition, 2611)", "INT 1: IJ 13.1, 13.2; M-4: 428.2"),
+        159 => ("The ruined remains of a stranded or sunken vessel which has been rendered useless. (IHO Dictionary, S-32, 5th Edition, 6027)", "INT 1: IK 20-30; M-4: 422-422.8"),
+        160 => ("A tidal stream (or tidal current) is a horizontal movement of water associated with the rise and fall of the tide caused by tide-producing forces. (Adapted from IHO Dictionary, S-32, 5th Edition) Approximate tidal stream rates may be given as discrete rate values for flood and ebb flow during springs.", "INT 1: IH 40-41; M-4: 407.4"),
+        300 => ("An area within which the best estimate of the overall accuracy of the data is uniform. The overall accuracy takes into account for example the source accuracy, chart scale, digitising accuracy etc.", "INT 1: not specified; M-4: not specified"),
+        301 => ("An area within which the data was originally compiled at a uniform scale. For example, it may define the scale of the paper chart from which the data was digitised.", "INT 1: not specified; M-4: not specified"),
+        302 => ("A geographical area that describes the coverage and extent of spatial objects.", "INT 1: not specified; M-4: not specified"),
+        303 => ("An area of uniform horizontal datum.", "INT 1: not specified; M-4: not specified"),
+        304 => ("An area within which a uniform shift exists between a specific geodetic datum and the datum of the data within this area.", "INT 1: not specified; M-4: not specified"),
+        305 => ("Used to relate additional nautical information or publications to the data.", "INT 1: not specified; M-4: not specified"),
+        306 => ("An area within which a specific system of navigational marks applies and/or a common direction of buoyage.", "INT 1: IQ 130-130.6; M-4: 461.1-4"),
+        307 => ("An area within which uniform data production parameters apply.", "INT 1: not specified; M-4: not specified"),
+        308 => ("An area within which a uniform assessment of the quality of the data exists.", "INT 1: not specified; M-4: not specified"),
+        309 => ("An area of uniform sounding datum.", "INT 1: not specified; M-4: not specified"),
+        310 => ("An area within which a uniform assessment of the reliability of source survey information exists.", "INT 1: not specified; M-4: 170.2; 178"),
+        311 => ("An area of uniform units of depth and/or height measurement.", "INT 1: not specified; M-4: not specified"),
+        312 => ("An area of uniform vertical datum.", "INT 1: not specified; M-4: not specified"),
+        400 => ("Used to identify an aggregation of two or more objects. This aggregation may be named.", ""),
+        401 => ("Used to identify an association between two or more objects. The association may be named.", ""),
+        402 => ("Used to identify the order of stacking of objects (eg. bridge on top of road).", ""),
+        500 => ("An area in which a certain cartographic symbolization is required.", ""),
+        501 => ("A line with a certain cartographic symbolization.", ""),
+        502 => ("A point with a certain cartographic symbolization.", ""),
+        503 => ("A circle graduated in degrees clockwise form 0 (north) to 360 used to facilitate measurements of direction. May be oriented to true or magnetic north. (adapted from IHO Dictionary, S-32, 5th Edition, 942)", ""),
+        504 => ("A text string that is to be represented using a certain cartographic symbolization.", ""),
+        _ => return None,
+    };
+    Some(Definition { text, references })
+}
+
+/// Look up an attribute definition by ATTL code
+pub(crate) fn attribute_definition(attl: u16) -> Option<Definition> {
+    let (text, references) = match attl {
+        1 => ("This attribute identifies the agency which produced the data.", "INT 1: not specified; M-4: not specified"),
+        5 => ("The depth below the sea bed to which an object is buried.", "INT 1: IL 42; M-4: 444.5"),
+        6 => ("The designated call-sign of a radio station.", "INT 1: not specified; M-4: not specified"),
+        77 => ("A channel number assigned to a specific radio frequency, frequencies or frequency band.", "INT 1: IM 40; M-4: 488"),
+        78 => ("specifies the display radius for a cartographic compass rose.", ""),
+        79 => ("The date on which the compilation of the data was completed.", ""),
+        80 => ("The scale at which the data was originally compiled.", ""),
+        84 => ("The rate of travel of a current.", "INT 1: IH 40, 43; M-4: 407.1; 407.4; 408.3"),
+        87 => ("The minimum (shoalest) value of a depth range.", "INT 1: II 21; IM 6; M-4: 414; 432.4; 434.3-4"),
+        88 => ("The maximum (deepest) value of a depth range.", "INT 1: II 21; IM 6; M-4: 414; 432.4; 434.3-4"),
+        90 => ("The altitude of the ground level of an object, measured from a specified vertical datum.", "INT 1: IC 10-13; IH 20; M-4: 352.1-2; 302.2; 405"),
+        91 => ("The estimated range of a non-optical electromagnetic transmission.", "INT 1: none specified; M-4: none specified"),
+        92 => ("light shown without change of character: a light shown throughout the 24 hours without change of character. IHO Chart pecifications, M-4 daytime light: a light which is only exhibited by day. fog light: a light which is exhibited in fog or conditions of reduced visibility. night light: a light which is only exhibited at night.", ""),
+        95 => ("The value of the vertical distance to the highest point of the object, measured from a specified vertical datum.", "INT 1: IC 14; IE 4; IK 10-11; M-4: 302; 352.4; 421.1-2"),
+        97 => ("The best estimate of the horizontal accuracy of horizontal clearance and distances.", ""),
+        98 => ("The width of an object, such as a canal or a tunnel, which is available for safe navigation. This may, or may not, be the same as the total physical width of the object.", "INT 1: ID 21; M-4: 380.2"),
+        99 => ("A measurement of the longer of two linear axis. (Digital Geographic Information Working Group -DGIWG, Oct.87)", ""),
+        100 => ("A measurement of the shorter of two linear axis. (Digital Geographic Information Working Group -DGIWG, Oct.87)", ""),
+        101 => ("The value of the maximum variation in the vertical clearance of an overhead cable due to an accumulation of ice.", ""),
+        102 => ("Textual information about the object.", "INT 1: IA 16; M-4: 242.3-5"),
+        103 => ("The jurisdiction applicable to an administrative area.", ""),
+        106 => ("The specific safe lifting capacity of an object.", "INT 1: IF 53.1-2; M-4: 328.3"),
+        110 => ("The number of lights of identical character that exist as a co-located group.", "INT 1: not specified"),
+        116 => ("The individual name of an object.", "INT 1: ID 7, IF 19, IN 12.2-3; M-4: 371; 323.1-2; 431.2-3; 431.5"),
+        117 => ("The angular distance measured from true north to the major axis of the object. (Digital Geographic Information Working Group -DGIWG, Oct.87)", "INT 1: IM 1-4, 40; IP 20.1-2, 21, 30.1-2, 31; IS 3.5, 11; M-4: 433.2-6; 434.1-2; 475.6-8; 487.2; 488"),
+        118 => ("The end of the active period for a seasonal object (e.g. a buoy). See also 'date end'", "INT 1: IQ71; M-4: 460.5"),
+        119 => ("The start of the active period for a seasonal object (e.g. a buoy). See also 'date start'", "INT 1: IQ71; M-4: 460.5"),
+        120 => ("Indicates whether a pictorial representation of the object is available.", "INT 1: IE 3.1-2; M-4: 456.5; 457.3"),
+        121 => ("The area within which a particular pilotage service operates.", "INT 1: IT 1.2; M-4: 491.1-2"),
+        122 => ("The country responsible for data production.", ""),
+        124 => ("A reference to a nautical publication.", ""),
+        126 => ("The distance between two successive peaks (or other points of identical phase) on an electromagnetic wave in the radar band of the electromagnetic spectrum.", "INT 1: IS 3.1-4; M-4: 486.3-4"),
+        127 => ("The vector extending from the centre to the periphery of a circular or spherical object.", "INT 1: IN 11.2; M-4: not specified"),
+        128 => ("The date when the specific object or cartographic primitive was captured, edited or deleted.", "INT 1: II 22; M-4: 414.1"),
+        129 => ("The procedure for the encoding and entering of data.", ""),
+        130 => ("The reference calendar year for magnetic variation values.", "INT 1: IB 68.1, 70-71; M-4: 270"),
+        132 => ("The maximum scale at which the object may be used e.g. for ECDIS presentation.", ""),
+        133 => ("The minimum scale at which the object may be used e.g. for ECDIS presentation.", ""),
+        134 => ("The largest scale for the range of survey scale as used in source diagram information.", ""),
+        135 => ("The smallest scale for the range of survey scale as used in source diagram information.", ""),
+        136 => ("A sector is the part of a circle between two straight lines drawn from the centre to the circumference. (Advanced Learner's Dictionary, 2nd Edition) Sector limit 1 specifies the first limit of the sector. The order of sector limit 1 and sector limit 2 is clockwise around the central object (e.g. a light).", "INT 1: IP 40; M-4: 475; 475.1"),
+        137 => ("A sector is the part of a circle between two straight lines drawn from the centre to the circumference. (Advanced Learner's Dictionary, 2nd Edition) The sector limit 2 specifies the second limit of the sector. The order of sector limit 1 and sector limit 2 is clockwise around the central object (e.g. a light).", "INT 1: IP 40; M-4: 475; 475.1"),
+        138 => ("Latitude and longitude offsets required to shift a position from one geodetic datum to another.", ""),
+        139 => ("The frequency of a signal.", ""),
+        141 => ("The number of signals, the combination of signals or the morse character(s) within one period of full sequence.", "INT 1: IP 10.2-9; IR 20, 22; M-4: 453; 453.1-4; 471.2"),
+        142 => ("The time occupied by an entire cycle of intervals of light and eclipse.", "INT 1: IP 12; IR 20, 22; M-4: 453.5; 471.5"),
+        143 => ("The sequence of times occupied by intervals of light and eclipse for all 'light characteristics' except for occulting where the sequence of times is occupied by intervals of eclipse and light.", ""),
+        144 => ("The best estimate of the accuracy of the sounding data.", ""),
+        145 => ("The maximum spacing of the principal sounding lines of a survey.", ""),
+        146 => ("The minimum spacing of the principal sounding lines of a survey.", ""),
+        147 => ("The production date of the source, e.g. the date of measurement.", ""),
+        148 => ("Information about the source of the object.", ""),
+        150 => ("The authority which was responsible for the survey.", ""),
+        151 => ("The end date of the survey.", ""),
+        152 => ("The start date of the survey.", ""),
+        167 => ("Harmonic constituents are the harmonic elements in a mathematical expression for the tide producing force and in the corresponding formula for the tidal curve. Each constituent represents a periodic change or variation in the relative positions of the earth, moon and sun.", ""),
+        173 => ("The annual change in magnetic variation values.", "INT 1: IB 68.1, 71; M-4: 272.1"),
+        174 => ("The depth of a sea bottom contour.", "INT 1: II 30; M-4: 410; 411"),
+        175 => ("The value of the deviation from the normal magnetic variation.", "INT 1: IB 82.1-2; M-4: 274"),
+        176 => ("The magnetic variation value.", "INT 1: IB 68.1, 71; M-4: 272.1"),
+        177 => ("The extreme distance at which an object can be seen or a signal detected.", "INT 1: not specified; M-4: not specified"),
+        178 => ("The nominal range at which an object can be seen or a signal detected.", "INT 1: IB 45; IP 14; M-4: 451.1; 471.7"),
+        179 => ("The value of the measurement of a sounding relative to the chart datum.", "INT 1: II 10, 11, 14, 15; M-4: 410; 412 413.1"),
+        180 => ("The best estimate of the vertical accuracy of heights, vertical distances and vertical clearances, excluding sounding measurements.", ""),
+        181 => ("The vertical clearance measured from the plane towards the object overhead.", "INT 1: ID 25-28; M-4: 380; 380.1; 382; 383"),
+        182 => ("The vertical clearance of an object in closed condition (e.g. a closed lifting bridge) measured from the plane towards the object overhead.", "INT 1: ID 23.3; M-4: 380; 380.1; 381.3"),
+        183 => ("The vertical clearance of an object in opened condition (e.g. an opened lifting bridge) measured from the plane towards the object overhead.", "INT 1: ID 23.3; M-4: 380; 380.2; 381.3"),
+        184 => ("The safe vertical clearance measured from the plane towards the object overhead.", "INT 1: ID 26; M-4: 382.1"),
+        186 => ("The total vertical length of an object.", "INT 1: IE 5; IL 21.3; M-4:303"),
+        189 => ("metres: Positional accuracy is specified in metres (SI units of positional accuracy). degrees of arc: Positional accuracy is specified in degrees of arc. millimeters: Positional accuracy is specified in millimeters. feet: Positional accuracy is specified in feet (imperial units of positional accuracy). cables: a unit of distance originally equal to the length of a ship's anchor cable, but now generally considered to be about 600 feet. In the British Navy it is 608 feet, or exactly one-tenth of a nautical mile. In the United States Navy it is 720 feet but is infrequently used. Sometimes called cable length. (IHO Dictionary, S-32, 5 th Edition, 589 ).", ""),
+        401 => ("The best estimate of the accuracy of a position.", ""),
+        _ => return None,
+    };
+    Some(Definition { text, references })
+}
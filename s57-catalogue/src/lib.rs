@@ -7,17 +7,147 @@
 //! - Complete enum types for all 286 object classes (OBJL codes)
 //! - Complete struct for all 483 attributes (ATTL codes)
 //! - Lookup functions for decoding raw codes
+//! - Definition texts and INT 1 / M-4 references from Appendix A, for
+//!   tooltips and help text
+//! - The attributes each object class may carry, and the Expected input
+//!   list of each enumerated and list attribute
+//! - Attribute types and Expected input tables, for decoding raw attribute
+//!   values such as `CATLAM=2` to "starboard-hand lateral mark"
+//! - Object class names in French and German (features `lang-fr`,
+//!   `lang-de`) for non-English chart tools
+//! - Ranked free-text search over acronyms and names (`search("anchor")`,
+//!   with the default `std` feature)
 //!
 //! Generated from GDAL reference CSVs using scripts/generate_catalogue.nu
 //!
+//! The crate is `no_std` when built without the default `std` feature: all
+//! lookups work on `&'static str` and plain integers, so no allocator is
+//! needed either.
+//!
 //! Reference: IHO S-57 Edition 3.1, November 2000
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
 // Generated modules - DO NOT EDIT MANUALLY
 mod attributes_generated;
+mod classes_generated;
+mod definitions_generated;
+mod localization;
 mod objects_generated;
+mod value;
+mod values_generated;
+
+#[cfg(feature = "lang-de")]
+mod names_de;
+#[cfg(feature = "lang-fr")]
+mod names_fr;
+#[cfg(feature = "serde")]
+mod serde_impl;
+
+#[cfg(feature = "std")]
+mod search;
 
 pub use attributes_generated::AttributeInfo;
+pub use definitions_generated::Definition;
+pub use localization::Language;
 pub use objects_generated::ObjectClass;
+#[cfg(feature = "std")]
+pub use search::{search, SearchHit, SearchResult};
+pub use value::{AttributeType, AttributeValue, EnumValue, ListValues, DELETE_MARKER};
+
+/// ClassAttributes: Attributes an object class may carry, by attribute set
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClassAttributes {
+    /// Set A: the object's individual characteristics
+    pub a: &'static [&'static str],
+    /// Set B: information relevant to the use of the data
+    pub b: &'static [&'static str],
+    /// Set C: administrative information about the data
+    pub c: &'static [&'static str],
+}
+
+impl ClassAttributes {
+    /// Acronyms of every set, A then B then C
+    pub fn all(&self) -> impl Iterator<Item = &'static str> {
+        self.a.iter().chain(self.b).chain(self.c).copied()
+    }
+}
+
+impl ObjectClass {
+    /// Definition and references from S-57 Appendix A, Chapter 1
+    ///
+    /// None for national/extension classes and for the classes added by the
+    /// Edition 3.1 supplements (ARCSLN, ASLXIS, NEWOBJ).
+    pub fn definition(&self) -> Option<Definition> {
+        definitions_generated::object_definition(self.code())
+    }
+
+    /// Attribute acronyms the class may carry, from the Object Catalogue
+    pub fn attributes(&self) -> Option<ClassAttributes> {
+        classes_generated::class_attributes(self.code())
+    }
+}
+
+impl AttributeInfo {
+    /// Definition and references from S-57 Appendix A, Chapter 2
+    ///
+    /// The table only covers part of the chapter: enumerated and list
+    /// attributes (e.g. COLOUR, CATLIT) are defined by their values and have
+    /// no attribute-level entry, and several free-text, date and national
+    /// attributes (e.g. DATEND, DATSTA, NINFOM, NOBJNM, NTXTDS) have not been
+    /// transcribed yet. This returns None for all of them, as it does for
+    /// national/extension attributes.
+    pub fn definition(&self) -> Option<Definition> {
+        definitions_generated::attribute_definition(self.code)
+    }
+
+    /// The Expected input list of an enumerated or list attribute, in ID order
+    ///
+    /// Empty for attributes of other types.
+    pub fn expected_values(&self) -> impl Iterator<Item = EnumValue> + '_ {
+        values_generated::expected_ids(self.code)
+            .iter()
+            .map(|&id| EnumValue::new(self.code, id))
+    }
+
+    /// Look up an attribute by its acronym (e.g. "OBJNAM")
+    ///
+    /// Scans the catalogue, so prefer `from_code` on hot paths.
+    pub fn from_acronym(acronym: &str) -> Option<Self> {
+        (1..=u16::MAX)
+            .filter_map(Self::from_code)
+            .find(|info| info.acronym == acronym)
+    }
+
+    /// Decode a raw ATTF/NATF value according to the attribute's type
+    ///
+    /// Enumerated and list IDs are looked up in the Expected input tables of
+    /// Appendix A, Chapter 2. An empty value is `Unknown` and the update
+    /// delete marker is `Removed`, whatever the type. Returns None for
+    /// unknown ATTL codes and for values that don't parse as the
+    /// attribute's type.
+    pub fn decode_value(attl: u16, raw: &str) -> Option<AttributeValue<'_>> {
+        let info = Self::from_code(attl)?;
+        if raw.is_empty() {
+            return Some(AttributeValue::Unknown);
+        }
+        if raw == DELETE_MARKER {
+            return Some(AttributeValue::Removed);
+        }
+        let value = match info.attribute_type {
+            AttributeType::Enumerated => {
+                AttributeValue::Enum(EnumValue::new(attl, value::parse_id(raw)?))
+            }
+            AttributeType::List => AttributeValue::List(ListValues::parse(attl, raw)?),
+            AttributeType::Float => AttributeValue::Float(raw.trim().parse().ok()?),
+            AttributeType::Integer => AttributeValue::Integer(raw.trim().parse().ok()?),
+            AttributeType::CodedString | AttributeType::FreeText | AttributeType::Unspecified => {
+                AttributeValue::Text(raw)
+            }
+        };
+        Some(value)
+    }
+}
 
 /// Decode object class from OBJL code
 ///
@@ -40,3 +170,114 @@ pub fn decode_object(objl: u16) -> Option<ObjectClass> {
 pub fn get_attribute_name(attl: u16) -> Option<&'static str> {
     AttributeInfo::attribute_name(attl)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_definitions() {
+        let depare = ObjectClass::from_code(42).unwrap().definition().unwrap();
+        assert!(depare.text.contains("depth"));
+        assert!(depare.references.starts_with("INT 1"));
+
+        let objnam = AttributeInfo::from_code(116).unwrap().definition().unwrap();
+        assert_eq!(objnam.text, "The individual name of an object.");
+
+        // COLOUR is defined by its enumerated values only
+        assert!(AttributeInfo::from_code(75).unwrap().definition().is_none());
+        // Not yet transcribed from Appendix A
+        assert!(AttributeInfo::from_code(85).unwrap().definition().is_none());
+    }
+
+    #[test]
+    fn test_class_attributes_and_expected_values() {
+        let depare = ObjectClass::from_code(42).unwrap().attributes().unwrap();
+        assert_eq!(depare.a[..2], ["DRVAL1", "DRVAL2"]);
+        assert!(depare.b.contains(&"SCAMIN"));
+        assert_eq!(depare.all().last(), Some("SORIND"));
+        assert_eq!(
+            depare.all().count(),
+            depare.a.len() + depare.b.len() + depare.c.len()
+        );
+
+        let catlam = AttributeInfo::from_code(36).unwrap();
+        let values: Vec<EnumValue> = catlam.expected_values().collect();
+        assert_eq!(values[0], EnumValue::new(36, 1));
+        assert_eq!(values[1].meaning, Some("starboard-hand lateral mark"));
+        assert!(values.windows(2).all(|pair| pair[0].id < pair[1].id));
+        // DRVAL1 is a float
+        assert_eq!(
+            AttributeInfo::from_code(87)
+                .unwrap()
+                .expected_values()
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_decode_value() {
+        // CATLAM (E)
+        let AttributeValue::Enum(catlam) = AttributeInfo::decode_value(36, "2").unwrap() else {
+            panic!("CATLAM is enumerated");
+        };
+        assert_eq!(catlam.meaning, Some("starboard-hand lateral mark"));
+        assert_eq!(
+            AttributeInfo::decode_value(36, "1").unwrap(),
+            AttributeValue::Enum(EnumValue {
+                id: 1,
+                meaning: Some("port-hand lateral mark"),
+            })
+        );
+        // Undefined IDs keep their number
+        let AttributeValue::Enum(odd) = AttributeInfo::decode_value(36, "99").unwrap() else {
+            panic!("CATLAM is enumerated");
+        };
+        assert_eq!((odd.id, odd.meaning), (99, None));
+        assert!(AttributeInfo::decode_value(36, "red").is_none());
+
+        // COLOUR (L)
+        let AttributeValue::List(colours) = AttributeInfo::decode_value(75, "3,1").unwrap() else {
+            panic!("COLOUR is a list");
+        };
+        let names: Vec<_> = colours.map(|c| c.meaning.unwrap()).collect();
+        assert_eq!(names, ["red", "white"]);
+        assert!(AttributeInfo::decode_value(75, "3,,1").is_none());
+
+        // DRVAL1 (F), SCAMIN (I), OBJNAM (S), NATION (A)
+        assert_eq!(
+            AttributeInfo::decode_value(87, "12.5"),
+            Some(AttributeValue::Float(12.5))
+        );
+        assert_eq!(
+            AttributeInfo::decode_value(133, "50000"),
+            Some(AttributeValue::Integer(50000))
+        );
+        assert_eq!(
+            AttributeInfo::decode_value(116, "Dover"),
+            Some(AttributeValue::Text("Dover"))
+        );
+        assert_eq!(
+            AttributeInfo::from_code(111).unwrap().attribute_type,
+            AttributeType::CodedString
+        );
+
+        // Empty values are unknown whatever the type; unknown codes decode to nothing
+        assert_eq!(
+            AttributeInfo::decode_value(87, ""),
+            Some(AttributeValue::Unknown)
+        );
+        assert!(AttributeInfo::decode_value(9999, "1").is_none());
+
+        // The delete marker alone, not a value that starts with it
+        assert_eq!(
+            AttributeInfo::decode_value(36, DELETE_MARKER),
+            Some(AttributeValue::Removed)
+        );
+        assert_eq!(
+            AttributeInfo::decode_value(116, "\u{7f}x"),
+            Some(AttributeValue::Text("\u{7f}x"))
+        );
+    }
+}
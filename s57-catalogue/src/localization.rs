@@ -0,0 +1,131 @@
+//! Translated object class names
+//!
+//! English names come with the catalogue. Translation tables are compiled
+//! in per language with a feature (`lang-fr`, `lang-de`), so tools that
+//! only need English don't carry them. `ObjectClass::name_localized` falls
+//! back to the English name for a language whose feature is off and for
+//! classes a table doesn't cover.
+
+use crate::ObjectClass;
+use core::fmt;
+
+/// Language: Display language of catalogue names
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Language {
+    #[default]
+    English,
+    French,
+    German,
+}
+
+impl Language {
+    /// Language from a BCP 47 tag ("fr", "de-CH") or an ISO 639-2 code
+    /// ("fra", "ger"), ignoring case and any region
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let primary = tag.split(['-', '_']).next().unwrap_or_default();
+        let is = |codes: &[&str]| codes.iter().any(|c| c.eq_ignore_ascii_case(primary));
+        if is(&["en", "eng"]) {
+            Some(Language::English)
+        } else if is(&["fr", "fra", "fre"]) {
+            Some(Language::French)
+        } else if is(&["de", "deu", "ger"]) {
+            Some(Language::German)
+        } else {
+            None
+        }
+    }
+
+    /// ISO 639-1 code
+    pub fn tag(&self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::French => "fr",
+            Language::German => "de",
+        }
+    }
+
+    /// True if this build has the language's translation table
+    pub fn is_available(&self) -> bool {
+        match self {
+            Language::English => true,
+            Language::French => cfg!(feature = "lang-fr"),
+            Language::German => cfg!(feature = "lang-de"),
+        }
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.tag())
+    }
+}
+
+impl ObjectClass {
+    /// Human-readable name in `language`, or the English `name()` when there
+    /// is no translation
+    pub fn name_localized(&self, language: Language) -> &'static str {
+        let translated = match language {
+            Language::English => None,
+            #[cfg(feature = "lang-fr")]
+            Language::French => crate::names_fr::object_name(self.code()),
+            #[cfg(feature = "lang-de")]
+            Language::German => crate::names_de::object_name(self.code()),
+            #[allow(unreachable_patterns)]
+            _ => None,
+        };
+        translated.unwrap_or_else(|| self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_language_tags() {
+        assert_eq!(Language::from_tag("fr"), Some(Language::French));
+        assert_eq!(Language::from_tag("de-CH"), Some(Language::German));
+        assert_eq!(Language::from_tag("FRE"), Some(Language::French));
+        assert_eq!(Language::from_tag("en_GB"), Some(Language::English));
+        assert_eq!(Language::from_tag("nl"), None);
+        assert_eq!(Language::from_tag(""), None);
+        assert_eq!(Language::German.tag(), "de");
+        assert!(Language::English.is_available());
+    }
+
+    #[test]
+    fn test_name_localized() {
+        let class = ObjectClass::from_code(42).unwrap();
+        assert_eq!(class.name_localized(Language::English), "Depth area");
+        let expected = |language: Language, translated| {
+            if language.is_available() {
+                translated
+            } else {
+                "Depth area"
+            }
+        };
+        assert_eq!(
+            class.name_localized(Language::French),
+            expected(Language::French, "Zone de profondeur")
+        );
+        assert_eq!(
+            class.name_localized(Language::German),
+            expected(Language::German, "Tiefengebiet")
+        );
+
+        // Classes outside the IHO catalogue keep their English names
+        let inland = ObjectClass::from_code(17050).unwrap();
+        assert_eq!(inland.name_localized(Language::German), inland.name());
+    }
+
+    #[cfg(all(feature = "lang-fr", feature = "lang-de"))]
+    #[test]
+    fn test_tables_cover_iho_classes() {
+        let iho = (1..=163).chain(300..=312).chain(400..=402);
+        for code in iho {
+            assert!(ObjectClass::from_code(code).is_some(), "OBJL {}", code);
+            assert!(crate::names_fr::object_name(code).is_some(), "fr {}", code);
+            assert!(crate::names_de::object_name(code).is_some(), "de {}", code);
+        }
+    }
+}
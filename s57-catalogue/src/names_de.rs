@@ -0,0 +1,192 @@
+//! German object class names (feature "lang-de")
+//!
+//! Terms follow German nautical chart usage (BSH). Only the IHO
+//! catalogue classes are covered; national and extension classes fall
+//! back to their English names.
+
+/// German name of an object class by OBJL code
+pub(crate) fn object_name(objl: u16) -> Option<&'static str> {
+    let name = match objl {
+        1 => "Verwaltungsgebiet (benannt)",
+        2 => "Flughafen / Flugplatz",
+        3 => "Ankerliegeplatz",
+        4 => "Ankergebiet",
+        5 => "Kardinalbake",
+        6 => "Einzelgefahrenbake",
+        7 => "Lateralbake",
+        8 => "Bake für sicheres Fahrwasser",
+        9 => "Sonderbake / allgemeine Bake",
+        10 => "Liegeplatz",
+        11 => "Brücke",
+        12 => "Einzelgebäude",
+        13 => "Bebautes Gebiet",
+        14 => "Kardinaltonne",
+        15 => "Anlagentonne",
+        16 => "Einzelgefahrentonne",
+        17 => "Lateraltonne",
+        18 => "Tonne für sicheres Fahrwasser",
+        19 => "Sondertonne / allgemeine Tonne",
+        20 => "Kabelgebiet",
+        21 => "Freileitung",
+        22 => "Seekabel",
+        23 => "Kanal",
+        24 => "Kanalufer",
+        25 => "Umschlaggebiet",
+        26 => "Straßendamm",
+        27 => "Warngebiet",
+        28 => "Kontrollpunkt",
+        29 => "Küstenwachstation",
+        30 => "Küstenlinie",
+        31 => "Anschlusszone",
+        32 => "Festlandsockelgebiet",
+        33 => "Festpunkt",
+        34 => "Förderanlage",
+        35 => "Kran",
+        36 => "Strömung, nicht gezeitenbedingt",
+        37 => "Zollgebiet",
+        38 => "Staudamm",
+        39 => "Tagesmarke",
+        40 => "Mittellinie des Tiefwasserwegs",
+        41 => "Teil des Tiefwasserwegs",
+        42 => "Tiefengebiet",
+        43 => "Tiefenlinie",
+        44 => "Entfernungsmarke",
+        45 => "Hafenbecken",
+        46 => "Gebaggertes Gebiet",
+        47 => "Trockendock",
+        48 => "Verklappungsgebiet",
+        49 => "Deich",
+        50 => "Ausschließliche Wirtschaftszone",
+        51 => "Fahrwasser",
+        52 => "Zaun / Mauer",
+        53 => "Fährlinie",
+        54 => "Fischereizone",
+        55 => "Fischereianlage",
+        56 => "Fischgrund",
+        57 => "Schwimmdock",
+        58 => "Nebelsignal",
+        59 => "Befestigungsanlage",
+        60 => "Freihafengebiet",
+        61 => "Tor",
+        62 => "Kielbank",
+        63 => "Hafengebiet (Verwaltung)",
+        64 => "Hafenanlage",
+        65 => "Hulk",
+        66 => "Eisgebiet",
+        67 => "Verbrennungsgebiet",
+        68 => "Küstenverkehrszone",
+        69 => "Binnensee",
+        70 => "Seeufer",
+        71 => "Landfläche",
+        72 => "Geländehöhe",
+        73 => "Landregion",
+        74 => "Landmarke",
+        75 => "Leuchtfeuer",
+        76 => "Leuchtfloß",
+        77 => "Feuerschiff",
+        78 => "Örtliche magnetische Anomalie",
+        79 => "Schleusenkammer",
+        80 => "Holzlagerbecken",
+        81 => "Missweisung",
+        82 => "Aquakultur",
+        83 => "Militärisches Übungsgebiet",
+        84 => "Festmache- / Verholeinrichtung",
+        85 => "Leitlinie",
+        86 => "Schifffahrtshindernis",
+        87 => "Offshore-Plattform",
+        88 => "Offshore-Produktionsgebiet",
+        89 => "Ölsperre",
+        90 => "Pfahl",
+        91 => "Lotsenversetzpunkt",
+        92 => "Rohrleitungsgebiet",
+        93 => "Rohrleitung, oberirdisch",
+        94 => "Rohrleitung, unterseeisch / an Land",
+        95 => "Ponton",
+        96 => "Vorsichtsgebiet",
+        97 => "Förder- / Lagergebiet",
+        98 => "Mast / Brückenpfeiler",
+        99 => "Radarlinie",
+        100 => "Radarbereich",
+        101 => "Radarreflektor",
+        102 => "Radarstation",
+        103 => "Radarantwortbake",
+        104 => "Meldepunkt",
+        105 => "Funkstation",
+        106 => "Eisenbahn",
+        107 => "Stromschnellen",
+        108 => "Mittellinie des empfohlenen Wegs",
+        109 => "Empfohlene Kurslinie",
+        110 => "Teil eines empfohlenen Verkehrswegs",
+        111 => "Rettungsstation",
+        112 => "Beschränkungsgebiet",
+        113 => "Retroreflektor",
+        114 => "Fluss",
+        115 => "Flussufer",
+        116 => "Straße",
+        117 => "Start- und Landebahn",
+        118 => "Sandwellen",
+        119 => "Seegebiet / benanntes Gewässer",
+        120 => "Landegebiet für Wasserflugzeuge",
+        121 => "Meeresbodengebiet",
+        122 => "Uferbauwerk",
+        123 => "Verkehrssignalstelle",
+        124 => "Warnsignalstelle",
+        125 => "Silo / Tank",
+        126 => "Böschungsoberkante",
+        127 => "Hanggelände",
+        128 => "Einrichtung für Kleinfahrzeuge",
+        129 => "Lotung",
+        130 => "Quelle",
+        131 => "Platz",
+        132 => "Gerade Basislinie des Küstenmeers",
+        133 => "U-Boot-Transitweg",
+        134 => "Abgesuchtes Gebiet",
+        135 => "Küstenmeer",
+        136 => "Gezeitenstrom - harmonische Vorhersage",
+        137 => "Gezeitenstrom - nicht harmonische Vorhersage",
+        138 => "Gezeitenstrom - Tabellendaten",
+        139 => "Gezeitenstrom - Zeitreihe (TS_TIS)",
+        140 => "Gezeiten - harmonische Vorhersage",
+        141 => "Gezeiten - nicht harmonische Vorhersage",
+        142 => "Gezeitenstrom - Zeitreihe (T_TIMS)",
+        143 => "Priel",
+        144 => "Toppzeichen",
+        145 => "Trennlinie",
+        146 => "Grenze des Verkehrstrennungsgebiets",
+        147 => "Kreuzung im Verkehrstrennungsgebiet",
+        148 => "Einbahnweg des Verkehrstrennungsgebiets",
+        149 => "Kreisverkehr im Verkehrstrennungsgebiet",
+        150 => "Trennzone",
+        151 => "Tunnel",
+        152 => "Teil eines Zweiwegewegs",
+        153 => "Unterwasserfelsen / trockenfallender Felsen",
+        154 => "Nicht vermessenes Gebiet",
+        155 => "Vegetation",
+        156 => "Stromkabbelung",
+        157 => "Wasserfall",
+        158 => "Seetang / Kelp",
+        159 => "Wrack",
+        160 => "Gezeitenstrom - Flut/Ebbe",
+        161 => "Archipelschifffahrtsweg",
+        162 => "Achse des Archipelschifffahrtswegs",
+        163 => "Neues Objekt",
+        300 => "Datengenauigkeit",
+        301 => "Erfassungsmaßstab der Daten",
+        302 => "Abdeckung",
+        303 => "Horizontales Bezugssystem der Daten",
+        304 => "Parameter der Bezugssystemverschiebung",
+        305 => "Angaben zu nautischen Veröffentlichungen",
+        306 => "Betonnungssystem",
+        307 => "Herstellungsangaben",
+        308 => "Datenqualität",
+        309 => "Kartennull",
+        310 => "Vermessungszuverlässigkeit",
+        311 => "Maßeinheiten der Daten",
+        312 => "Vertikales Bezugssystem der Daten",
+        400 => "Aggregation",
+        401 => "Assoziation",
+        402 => "Gestapelt darauf / darunter",
+        _ => return None,
+    };
+    Some(name)
+}
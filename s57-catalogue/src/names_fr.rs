@@ -0,0 +1,192 @@
+//! French object class names (feature "lang-fr")
+//!
+//! Terms follow French nautical chart usage (SHOM). Only the IHO
+//! catalogue classes are covered; national and extension classes fall
+//! back to their English names.
+
+/// French name of an object class by OBJL code
+pub(crate) fn object_name(objl: u16) -> Option<&'static str> {
+    let name = match objl {
+        1 => "Zone administrative (nommée)",
+        2 => "Aéroport / aérodrome",
+        3 => "Poste de mouillage",
+        4 => "Zone de mouillage",
+        5 => "Balise cardinale",
+        6 => "Balise de danger isolé",
+        7 => "Balise latérale",
+        8 => "Balise d'eaux saines",
+        9 => "Balise spéciale / générale",
+        10 => "Poste à quai",
+        11 => "Pont",
+        12 => "Bâtiment isolé",
+        13 => "Zone bâtie",
+        14 => "Bouée cardinale",
+        15 => "Bouée d'installation",
+        16 => "Bouée de danger isolé",
+        17 => "Bouée latérale",
+        18 => "Bouée d'eaux saines",
+        19 => "Bouée spéciale / générale",
+        20 => "Zone de câbles",
+        21 => "Câble aérien",
+        22 => "Câble sous-marin",
+        23 => "Canal",
+        24 => "Berge de canal",
+        25 => "Zone de transbordement de marchandises",
+        26 => "Chaussée",
+        27 => "Zone de mise en garde",
+        28 => "Point de contrôle",
+        29 => "Station de garde-côtes",
+        30 => "Trait de côte",
+        31 => "Zone contiguë",
+        32 => "Zone du plateau continental",
+        33 => "Point géodésique",
+        34 => "Convoyeur",
+        35 => "Grue",
+        36 => "Courant non gravitationnel",
+        37 => "Zone douanière",
+        38 => "Barrage",
+        39 => "Marque de jour",
+        40 => "Axe de route en eau profonde",
+        41 => "Partie de route en eau profonde",
+        42 => "Zone de profondeur",
+        43 => "Isobathe",
+        44 => "Marque de distance",
+        45 => "Bassin",
+        46 => "Zone draguée",
+        47 => "Cale sèche",
+        48 => "Zone de clapage",
+        49 => "Digue",
+        50 => "Zone économique exclusive",
+        51 => "Chenal",
+        52 => "Clôture / mur",
+        53 => "Ligne de bac",
+        54 => "Zone de pêche",
+        55 => "Installation de pêche",
+        56 => "Lieu de pêche",
+        57 => "Dock flottant",
+        58 => "Signal de brume",
+        59 => "Ouvrage fortifié",
+        60 => "Zone franche portuaire",
+        61 => "Porte",
+        62 => "Gril de carénage",
+        63 => "Zone portuaire (administrative)",
+        64 => "Installation portuaire",
+        65 => "Ponton désarmé",
+        66 => "Zone de glace",
+        67 => "Zone d'incinération",
+        68 => "Zone de navigation côtière",
+        69 => "Lac",
+        70 => "Rive de lac",
+        71 => "Zone terrestre",
+        72 => "Altitude terrestre",
+        73 => "Région terrestre",
+        74 => "Amer",
+        75 => "Feu",
+        76 => "Feu flottant",
+        77 => "Bateau-feu",
+        78 => "Anomalie magnétique locale",
+        79 => "Bassin d'écluse",
+        80 => "Étang à grumes",
+        81 => "Déclinaison magnétique",
+        82 => "Aquaculture marine",
+        83 => "Zone d'exercices militaires",
+        84 => "Installation d'amarrage / de touage",
+        85 => "Ligne de navigation",
+        86 => "Obstruction",
+        87 => "Plate-forme en mer",
+        88 => "Zone de production en mer",
+        89 => "Barrage anti-pollution",
+        90 => "Pieu",
+        91 => "Lieu d'embarquement du pilote",
+        92 => "Zone de conduites",
+        93 => "Conduite aérienne",
+        94 => "Conduite sous-marine / terrestre",
+        95 => "Ponton",
+        96 => "Zone de prudence",
+        97 => "Zone de production / stockage",
+        98 => "Pylône / pile de pont",
+        99 => "Ligne radar",
+        100 => "Portée radar",
+        101 => "Réflecteur radar",
+        102 => "Station radar",
+        103 => "Balise répondeuse radar",
+        104 => "Point d'appel radio",
+        105 => "Station radio",
+        106 => "Voie ferrée",
+        107 => "Rapides",
+        108 => "Axe de route recommandée",
+        109 => "Alignement recommandé",
+        110 => "Partie de voie de circulation recommandée",
+        111 => "Station de sauvetage",
+        112 => "Zone réglementée",
+        113 => "Rétroréflecteur",
+        114 => "Rivière",
+        115 => "Berge de rivière",
+        116 => "Route terrestre",
+        117 => "Piste d'atterrissage",
+        118 => "Dunes sous-marines",
+        119 => "Zone maritime / étendue d'eau nommée",
+        120 => "Zone d'amerrissage d'hydravions",
+        121 => "Zone de nature du fond",
+        122 => "Ouvrage côtier",
+        123 => "Station de signalisation du trafic",
+        124 => "Station de signalisation d'avertissement",
+        125 => "Silo / réservoir",
+        126 => "Crête de talus",
+        127 => "Terrain en pente",
+        128 => "Installation pour petites embarcations",
+        129 => "Sonde",
+        130 => "Source",
+        131 => "Place",
+        132 => "Ligne de base droite de la mer territoriale",
+        133 => "Couloir de transit sous-marin",
+        134 => "Zone balayée",
+        135 => "Zone de la mer territoriale",
+        136 => "Courant de marée - prédiction harmonique",
+        137 => "Courant de marée - prédiction non harmonique",
+        138 => "Courant de marée - données de panneau",
+        139 => "Courant de marée - série chronologique (TS_TIS)",
+        140 => "Marée - prédiction harmonique",
+        141 => "Marée - prédiction non harmonique",
+        142 => "Courant de marée - série chronologique (T_TIMS)",
+        143 => "Chenal de marée",
+        144 => "Voyant",
+        145 => "Ligne de séparation du trafic",
+        146 => "Limite de dispositif de séparation du trafic",
+        147 => "Croisement de dispositif de séparation du trafic",
+        148 => "Partie de voie de dispositif de séparation du trafic",
+        149 => "Giratoire de dispositif de séparation du trafic",
+        150 => "Zone de séparation du trafic",
+        151 => "Tunnel",
+        152 => "Partie de route à double sens",
+        153 => "Roche submergée / découvrante",
+        154 => "Zone non hydrographiée",
+        155 => "Végétation",
+        156 => "Remous",
+        157 => "Cascade",
+        158 => "Algues / varech",
+        159 => "Épave",
+        160 => "Courant de marée - flot/jusant",
+        161 => "Voie de circulation archipélagique",
+        162 => "Axe de voie de circulation archipélagique",
+        163 => "Nouvel objet",
+        300 => "Précision des données",
+        301 => "Échelle de compilation des données",
+        302 => "Couverture",
+        303 => "Système géodésique des données",
+        304 => "Paramètres de changement de système géodésique",
+        305 => "Informations sur les publications nautiques",
+        306 => "Système de balisage",
+        307 => "Informations de production",
+        308 => "Qualité des données",
+        309 => "Zéro des sondes",
+        310 => "Fiabilité des levés",
+        311 => "Unités de mesure des données",
+        312 => "Référence verticale des données",
+        400 => "Agrégation",
+        401 => "Association",
+        402 => "Empilé dessus / dessous",
+        _ => return None,
+    };
+    Some(name)
+}
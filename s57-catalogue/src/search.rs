@@ -0,0 +1,202 @@
+//! Free-text search over object classes and attributes (feature "std")
+//!
+//! `search("anchor")` matches the query against every acronym and name in
+//! the catalogue and ranks what it finds, best first:
+//! 1. an exact acronym ("ACHARE") or exact name ("Anchorage area")
+//! 2. an acronym starting with the query ("ACH")
+//! 3. names whose words match every query word, whole words above word
+//!    prefixes above substrings; a word one typo away also counts
+//! 4. acronyms containing the query's letters in order ("BYLT" for BOYLAT)
+//!
+//! Matching ignores case. Equal scores are ordered object classes first,
+//! then by acronym.
+
+use crate::{AttributeInfo, ObjectClass};
+use std::cmp::Reverse;
+
+/// Scores of the match kinds, highest first
+const EXACT: u32 = 1000;
+const ACRONYM_PREFIX: u32 = 800;
+const WORDS: u32 = 500;
+const SUBSEQUENCE: u32 = 100;
+
+/// SearchHit: A catalogue entry found by `search`
+#[derive(Debug, Clone, PartialEq)]
+pub enum SearchHit {
+    Object(ObjectClass),
+    Attribute(AttributeInfo),
+}
+
+impl SearchHit {
+    /// OBJL or ATTL code
+    pub fn code(&self) -> u16 {
+        match self {
+            SearchHit::Object(class) => class.code(),
+            SearchHit::Attribute(info) => info.code,
+        }
+    }
+
+    /// Acronym, e.g. "ACHARE"
+    pub fn acronym(&self) -> String {
+        match self {
+            SearchHit::Object(class) => class.to_string(),
+            SearchHit::Attribute(info) => info.acronym.to_string(),
+        }
+    }
+
+    /// Human-readable name, e.g. "Anchorage area"
+    pub fn name(&self) -> &'static str {
+        match self {
+            SearchHit::Object(class) => class.name(),
+            SearchHit::Attribute(info) => info.name,
+        }
+    }
+}
+
+/// SearchResult: A hit and how well it matched (higher is better)
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchResult {
+    pub hit: SearchHit,
+    pub score: u32,
+}
+
+/// Object classes and attributes matching a free-text query, best first
+///
+/// An empty query matches nothing.
+pub fn search(query: &str) -> Vec<SearchResult> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return Vec::new();
+    }
+    let objects = (1..=u16::MAX)
+        .filter_map(ObjectClass::from_code)
+        .map(SearchHit::Object);
+    let attributes = (1..=u16::MAX)
+        .filter_map(AttributeInfo::from_code)
+        .map(SearchHit::Attribute);
+    let mut results: Vec<(SearchResult, String)> = objects
+        .chain(attributes)
+        .filter_map(|hit| {
+            let acronym = hit.acronym();
+            let score = score(&query, &acronym.to_lowercase(), &hit.name().to_lowercase())?;
+            Some((SearchResult { hit, score }, acronym))
+        })
+        .collect();
+    results.sort_by_key(|(result, acronym)| {
+        (
+            Reverse(result.score),
+            matches!(result.hit, SearchHit::Attribute(_)),
+            acronym.clone(),
+        )
+    });
+    results.into_iter().map(|(result, _)| result).collect()
+}
+
+/// Score of one entry against a lowercase query, or None if it doesn't match
+fn score(query: &str, acronym: &str, name: &str) -> Option<u32> {
+    if acronym == query || name == query {
+        return Some(EXACT);
+    }
+    if acronym.starts_with(query) {
+        return Some(ACRONYM_PREFIX);
+    }
+    let words: Vec<&str> = name
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect();
+    let terms: Vec<u32> = query
+        .split_whitespace()
+        .map(|term| {
+            words
+                .iter()
+                .map(|word| word_score(term, word))
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    if terms.iter().all(|&s| s > 0) {
+        return Some(WORDS + terms.iter().sum::<u32>());
+    }
+    let compact: String = query.chars().filter(|c| !c.is_whitespace()).collect();
+    (compact.len() >= 2 && is_subsequence(&compact, acronym)).then_some(SUBSEQUENCE)
+}
+
+/// How well one query word matches one name word (0 for no match)
+fn word_score(term: &str, word: &str) -> u32 {
+    if word == term {
+        4
+    } else if word.starts_with(term) {
+        3
+    } else if word.contains(term) {
+        2
+    } else if term.len() >= 4 && edit_distance(term, word) <= 1 {
+        1
+    } else {
+        0
+    }
+}
+
+/// True if the characters of `needle` appear in `haystack` in order
+fn is_subsequence(needle: &str, haystack: &str) -> bool {
+    let mut rest = haystack.chars();
+    needle.chars().all(|c| rest.any(|h| h == c))
+}
+
+/// Levenshtein distance
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != *cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn acronyms(query: &str) -> Vec<String> {
+        search(query).iter().map(|r| r.hit.acronym()).collect()
+    }
+
+    #[test]
+    fn test_search() {
+        // Whole word before word prefix, then other classes mentioning it
+        let anchor = acronyms("anchor");
+        assert_eq!(anchor[0], "ACHBRT");
+        assert!(anchor.iter().position(|a| a == "ACHARE") > Some(0));
+
+        // Exact acronyms and acronym prefixes, in either case
+        let results = search("depare");
+        assert_eq!(
+            results[0].hit,
+            SearchHit::Object(ObjectClass::from_code(42).unwrap())
+        );
+        assert_eq!(results[0].score, EXACT);
+        assert_eq!(acronyms("DRVAL")[..2], ["DRVAL1", "DRVAL2"]);
+        assert_eq!(search("drval")[0].hit.code(), 87);
+
+        // Every word must match; one typo is forgiven
+        assert_eq!(acronyms("depth contour")[0], "DEPCNT");
+        assert!(acronyms("depth contur").contains(&"DEPCNT".to_string()));
+        assert!(!acronyms("depth xyzzy").contains(&"DEPCNT".to_string()));
+
+        // Letters of the acronym in order
+        assert!(acronyms("bylt").contains(&"BOYLAT".to_string()));
+
+        // Attributes are found too
+        let colour = search("colour");
+        assert!(colour
+            .iter()
+            .any(|r| r.hit == SearchHit::Attribute(AttributeInfo::from_code(75).unwrap())));
+
+        assert!(search("  ").is_empty());
+    }
+}
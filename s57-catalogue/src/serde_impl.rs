@@ -0,0 +1,105 @@
+//! Serde support (feature "serde")
+//!
+//! Object classes and attributes serialize as their 6-character acronyms
+//! ("DEPARE", "OBJNAM"), which are stable across catalogue regenerations,
+//! unlike enum variant names. Deserialization accepts the acronym or the
+//! numeric OBJL/ATTL code.
+
+use crate::{AttributeInfo, ObjectClass};
+use core::fmt;
+use core::str::FromStr;
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+impl Serialize for ObjectClass {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ObjectClass {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ObjectClassVisitor;
+
+        impl Visitor<'_> for ObjectClassVisitor {
+            type Value = ObjectClass;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an S-57 object class acronym or OBJL code")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<ObjectClass, E> {
+                ObjectClass::from_str(v)
+                    .map_err(|_| E::invalid_value(de::Unexpected::Str(v), &self))
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<ObjectClass, E> {
+                u16::try_from(v)
+                    .ok()
+                    .and_then(ObjectClass::from_code)
+                    .ok_or_else(|| E::invalid_value(de::Unexpected::Unsigned(v), &self))
+            }
+        }
+
+        deserializer.deserialize_any(ObjectClassVisitor)
+    }
+}
+
+impl Serialize for AttributeInfo {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.acronym)
+    }
+}
+
+impl<'de> Deserialize<'de> for AttributeInfo {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct AttributeVisitor;
+
+        impl Visitor<'_> for AttributeVisitor {
+            type Value = AttributeInfo;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an S-57 attribute acronym or ATTL code")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<AttributeInfo, E> {
+                AttributeInfo::from_acronym(v)
+                    .ok_or_else(|| E::invalid_value(de::Unexpected::Str(v), &self))
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<AttributeInfo, E> {
+                u16::try_from(v)
+                    .ok()
+                    .and_then(AttributeInfo::from_code)
+                    .ok_or_else(|| E::invalid_value(de::Unexpected::Unsigned(v), &self))
+            }
+        }
+
+        deserializer.deserialize_any(AttributeVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_uses_acronyms() {
+        let class = ObjectClass::from_code(42).unwrap();
+        assert_eq!(serde_json::to_string(&class).unwrap(), "\"DEPARE\"");
+        assert_eq!(
+            serde_json::from_str::<ObjectClass>("\"DEPARE\"").unwrap(),
+            class
+        );
+        assert_eq!(serde_json::from_str::<ObjectClass>("42").unwrap(), class);
+        assert!(serde_json::from_str::<ObjectClass>("\"NOPE\"").is_err());
+
+        let attr = AttributeInfo::from_code(116).unwrap();
+        assert_eq!(serde_json::to_string(&attr).unwrap(), "\"OBJNAM\"");
+        assert_eq!(
+            serde_json::from_str::<AttributeInfo>("\"OBJNAM\"").unwrap(),
+            attr
+        );
+        assert_eq!(serde_json::from_str::<AttributeInfo>("116").unwrap(), attr);
+    }
+}
@@ -0,0 +1,111 @@
+//! Attribute value decoding
+//!
+//! ATTF/NATF values are strings whose meaning depends on the attribute type
+//! given in Appendix A, Chapter 2: enumerated (E) and list (L) values are IDs
+//! into the attribute's Expected input table, float (F) and integer (I)
+//! values are decimal numbers, and coded strings (A) and free text (S) are
+//! used as they are.
+
+use crate::values_generated::expected_input;
+use core::fmt;
+
+/// AttributeType: Attribute type from the IHO Attribute Catalogue
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AttributeType {
+    /// E: a single ID from the Expected input list
+    Enumerated,
+    /// L: comma-separated IDs from the Expected input list
+    List,
+    /// F: floating point number
+    Float,
+    /// I: integer
+    Integer,
+    /// A: coded string, e.g. an ISO 3166 country code or a date
+    CodedString,
+    /// S: free-format text
+    FreeText,
+    /// Extension attributes the catalogue gives no type for
+    Unspecified,
+}
+
+/// EnumValue: An enumerated ID with its catalogue meaning
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EnumValue {
+    pub id: u32,
+    /// Meaning from the Expected input list; None for IDs the catalogue
+    /// doesn't define (national extensions, later editions)
+    pub meaning: Option<&'static str>,
+}
+
+/// ATVL of an update that deletes the attribute
+pub const DELETE_MARKER: &str = "\u{7f}";
+
+impl EnumValue {
+    pub(crate) fn new(attl: u16, id: u32) -> Self {
+        Self {
+            id,
+            meaning: expected_input(attl, id),
+        }
+    }
+}
+
+impl fmt::Display for EnumValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.meaning {
+            Some(meaning) => f.write_str(meaning),
+            None => write!(f, "{}", self.id),
+        }
+    }
+}
+
+/// AttributeValue: A raw attribute value decoded by attribute type
+#[derive(Debug, Clone, PartialEq)]
+pub enum AttributeValue<'a> {
+    /// Zero-length value: the attribute applies but its value is unknown
+    /// (S-57 Part 3, 2.1)
+    Unknown,
+    /// The delete marker (a lone DEL, 0x7F): in an update, the attribute is
+    /// removed from the feature (S-57 Part 3, 8.4.2.2)
+    Removed,
+    Enum(EnumValue),
+    List(ListValues<'a>),
+    Float(f64),
+    Integer(i64),
+    /// Coded string, free text, or a value of unspecified type
+    Text(&'a str),
+}
+
+/// ListValues: Iterator over the IDs of a list (L) attribute value
+///
+/// Only built from values whose every ID parses, so iteration can't fail.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListValues<'a> {
+    attl: u16,
+    rest: &'a str,
+}
+
+impl<'a> ListValues<'a> {
+    /// None if any comma-separated ID is not an integer
+    pub(crate) fn parse(attl: u16, raw: &'a str) -> Option<Self> {
+        raw.split(',')
+            .all(|id| parse_id(id).is_some())
+            .then_some(Self { attl, rest: raw })
+    }
+}
+
+impl Iterator for ListValues<'_> {
+    type Item = EnumValue;
+
+    fn next(&mut self) -> Option<EnumValue> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let (id, rest) = self.rest.split_once(',').unwrap_or((self.rest, ""));
+        self.rest = rest;
+        parse_id(id).map(|id| EnumValue::new(self.attl, id))
+    }
+}
+
+pub(crate) fn parse_id(id: &str) -> Option<u32> {
+    id.trim().parse().ok()
+}
@@ -0,0 +1,65 @@
+//! Exchange set listing and CRC verification
+
+use s57_interp::chart_catalog::ChartCatalog;
+use s57_parse::catalog::ExchangeSet;
+use s57_parse::S57File;
+use std::path::Path;
+
+/// List the catalogue's entries; with `verify`, check them against the disk
+pub fn catalog(catalog_path: &Path, catalog_file: &S57File, verify: bool) {
+    let exchange_set = match ExchangeSet::from_catalog(catalog_file) {
+        Ok(set) => set,
+        Err(e) => {
+            eprintln!("Error reading catalogue: {}", e);
+            std::process::exit(1);
+        }
+    };
+    let root = catalog_path.parent().unwrap_or(Path::new(""));
+    let catalog = ChartCatalog::new(root, exchange_set);
+
+    let set = catalog.exchange_set();
+    println!(
+        "{} file(s), {} cell file(s)",
+        set.entries.len(),
+        set.cells().count()
+    );
+    if !verify {
+        for entry in &set.entries {
+            let coverage = entry
+                .bounds()
+                .map(|(s, w, n, e)| format!("  {:.4},{:.4} to {:.4},{:.4}", s, w, n, e))
+                .unwrap_or_default();
+            println!(
+                "  {:<28} {:<3} {:<6} {:>8}{}",
+                entry.file, entry.impl_, entry.volm, entry.crcs, coverage
+            );
+        }
+        return;
+    }
+
+    let check = match catalog.verify() {
+        Ok(check) => check,
+        Err(e) => {
+            eprintln!("Error checking exchange set: {}", e);
+            std::process::exit(1);
+        }
+    };
+    for member in &check.members {
+        println!("  {:<28} {}", member.entry.file, member.status);
+    }
+    for path in &check.extra {
+        let path = path.strip_prefix(root).unwrap_or(path);
+        println!("  {:<28} not in catalogue", path.display());
+    }
+    if !check.is_ok() {
+        let bad = check.members.iter().filter(|m| !m.status.is_ok()).count();
+        eprintln!(
+            "Error: {} catalogue entr{} failed, {} unlisted file(s)",
+            bad,
+            if bad == 1 { "y" } else { "ies" },
+            check.extra.len()
+        );
+        std::process::exit(1);
+    }
+    println!("All files present and intact");
+}
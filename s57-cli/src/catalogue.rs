@@ -0,0 +1,130 @@
+//! Offline reference to the S-57 Object Catalogue
+
+use crate::CatalogueEntry;
+use s57_catalogue::{search, AttributeInfo, AttributeType, ObjectClass, SearchHit};
+
+/// Print a catalogue entry, or the closest matches if the name is unknown
+pub fn catalogue(entry: &CatalogueEntry) {
+    match entry {
+        CatalogueEntry::Object { name } => match find_object(name) {
+            Some(class) => print_object(class),
+            None => not_found("object class", name, |hit| {
+                matches!(hit, SearchHit::Object(_))
+            }),
+        },
+        CatalogueEntry::Attribute { name } => match find_attribute(name) {
+            Some(info) => print_attribute(&info),
+            None => not_found("attribute", name, |hit| {
+                matches!(hit, SearchHit::Attribute(_))
+            }),
+        },
+    }
+}
+
+/// Class by OBJL code, acronym or exact name
+fn find_object(name: &str) -> Option<ObjectClass> {
+    if let Ok(code) = name.trim().parse() {
+        return ObjectClass::from_code(code);
+    }
+    name.trim()
+        .to_uppercase()
+        .parse()
+        .ok()
+        .or_else(|| match named(name)? {
+            SearchHit::Object(class) => Some(class),
+            SearchHit::Attribute(_) => None,
+        })
+}
+
+/// Attribute by ATTL code, acronym or exact name
+fn find_attribute(name: &str) -> Option<AttributeInfo> {
+    if let Ok(code) = name.trim().parse() {
+        return AttributeInfo::from_code(code);
+    }
+    AttributeInfo::from_acronym(&name.trim().to_uppercase()).or_else(|| match named(name)? {
+        SearchHit::Attribute(info) => Some(info),
+        SearchHit::Object(_) => None,
+    })
+}
+
+/// The entry whose name is exactly `name`, ignoring case
+fn named(name: &str) -> Option<SearchHit> {
+    search(name)
+        .into_iter()
+        .map(|result| result.hit)
+        .find(|hit| hit.name().eq_ignore_ascii_case(name.trim()))
+}
+
+fn print_object(class: ObjectClass) {
+    println!("{} ({}): {}", class, class.code(), class.name());
+    if let Some(definition) = class.definition() {
+        println!("\n{}", definition.text);
+        if !definition.references.is_empty() {
+            println!("{}", definition.references);
+        }
+    }
+    if let Some(attributes) = class.attributes() {
+        for (set, acronyms) in [
+            ("A", attributes.a),
+            ("B", attributes.b),
+            ("C", attributes.c),
+        ] {
+            if acronyms.is_empty() {
+                continue;
+            }
+            println!("\nAttributes ({}):", set);
+            for acronym in acronyms {
+                let name = AttributeInfo::from_acronym(acronym).map_or("", |info| info.name);
+                println!("  {:<6}  {}", acronym, name);
+            }
+        }
+    }
+}
+
+fn print_attribute(info: &AttributeInfo) {
+    println!("{} ({}): {}", info.acronym, info.code, info.name);
+    println!("Type: {}", type_name(info.attribute_type));
+    if let Some(definition) = info.definition() {
+        println!("\n{}", definition.text);
+        if !definition.references.is_empty() {
+            println!("{}", definition.references);
+        }
+    }
+    let values: Vec<_> = info.expected_values().collect();
+    if !values.is_empty() {
+        println!("\nExpected values:");
+        for value in values {
+            println!("  {:>3}  {}", value.id, value);
+        }
+    }
+}
+
+fn type_name(attribute_type: AttributeType) -> &'static str {
+    match attribute_type {
+        AttributeType::Enumerated => "enumerated (E)",
+        AttributeType::List => "list (L)",
+        AttributeType::Float => "float (F)",
+        AttributeType::Integer => "integer (I)",
+        AttributeType::CodedString => "coded string (A)",
+        AttributeType::FreeText => "free text (S)",
+        AttributeType::Unspecified => "unspecified",
+    }
+}
+
+/// Report an unknown name with the closest entries of the same kind; exits
+fn not_found(kind: &str, name: &str, same_kind: impl Fn(&SearchHit) -> bool) {
+    eprintln!("Error: no {} named {}", kind, name);
+    let matches: Vec<_> = search(name)
+        .into_iter()
+        .filter(|result| same_kind(&result.hit))
+        .take(5)
+        .collect();
+    if !matches.is_empty() {
+        eprintln!("Closest matches:");
+        for result in matches {
+            let hit = result.hit;
+            eprintln!("  {:<6} {:>5}  {}", hit.acronym(), hit.code(), hit.name());
+        }
+    }
+    std::process::exit(1);
+}
@@ -0,0 +1,43 @@
+//! Coverage gap/overlap check across the cells of an exchange set
+
+use s57_interp::cell_coverage::{coverage_issues, CellCoverage};
+use s57_interp::chart_catalog::ChartCatalog;
+use s57_interp::ParseOptions;
+use s57_parse::catalog::ExchangeSet;
+use s57_parse::S57File;
+use std::path::Path;
+
+/// Load every base cell and report gaps and overlaps in M_COVR coverage
+pub fn check_coverage(catalog_path: &Path, catalog_file: &S57File) {
+    let exchange_set = match ExchangeSet::from_catalog(catalog_file) {
+        Ok(set) => set,
+        Err(e) => {
+            eprintln!("Error reading catalogue: {}", e);
+            std::process::exit(1);
+        }
+    };
+    let root = catalog_path.parent().unwrap_or(Path::new(""));
+    let catalog = ChartCatalog::new(root, exchange_set);
+
+    let options = ParseOptions::default();
+    let mut cells = Vec::new();
+    for entry in catalog.base_cells() {
+        match catalog.load_world(entry, &options) {
+            Ok(world) => {
+                let name = entry.file_name();
+                let name = name.rsplit_once('.').map_or(name, |(stem, _)| stem);
+                cells.push(CellCoverage::from_world(name, &world));
+            }
+            Err(e) => eprintln!("Warning: skipping {}: {}", entry.file, e),
+        }
+    }
+    println!("Checked {} cell(s)", cells.len());
+
+    let issues = coverage_issues(&cells);
+    if issues.is_empty() {
+        println!("No coverage gaps or overlaps");
+    }
+    for issue in issues {
+        println!("  {}", issue);
+    }
+}
@@ -0,0 +1,54 @@
+//! `compile`: a cell pre-built into a SENC file for viewers to load fast
+
+use s57_interp::senc::Senc;
+use s57_parse::S57File;
+use std::path::Path;
+use std::time::Instant;
+
+/// Compile the cell into a SENC file at `output`
+///
+/// The file is read back once to check it and to report how long a load
+/// takes compared with building the World from the cell.
+pub fn compile(file: &S57File, output: &Path) {
+    let start = Instant::now();
+    let senc = match Senc::compile(file) {
+        Ok(senc) => senc,
+        Err(e) => {
+            eprintln!("Error building world: {}", e);
+            std::process::exit(1);
+        }
+    };
+    let built = start.elapsed();
+    let bytes = match senc.to_bytes() {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            eprintln!("Error compiling: {}", e);
+            std::process::exit(1);
+        }
+    };
+    if let Err(e) = std::fs::write(output, &bytes) {
+        eprintln!("Error writing {}: {}", output.display(), e);
+        std::process::exit(1);
+    }
+
+    let start = Instant::now();
+    if let Err(e) = Senc::from_bytes(&bytes) {
+        eprintln!("Error reading back {}: {}", output.display(), e);
+        std::process::exit(1);
+    }
+    let loaded = start.elapsed();
+
+    println!("SENC written to: {}", output.display());
+    println!(
+        "  {} features, {} with resolved geometry, {} indexed",
+        senc.world.features().count(),
+        senc.geometry.len(),
+        senc.index.len()
+    );
+    println!("  Size: {} bytes", bytes.len());
+    println!(
+        "  Build from cell: {:.1} ms, load from SENC: {:.1} ms",
+        built.as_secs_f64() * 1000.0,
+        loaded.as_secs_f64() * 1000.0
+    );
+}
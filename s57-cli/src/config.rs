@@ -0,0 +1,278 @@
+//! User defaults for rendering, from `~/.config/s57/config.toml`
+//!
+//! The file is optional. Its `[render]` table gives defaults for `render`
+//! and `extract`; every key is the long name of one of their options, and
+//! an option given on the command line always wins:
+//!
+//! ```toml
+//! [render]
+//! preset = "bathymetry"
+//! classes = ["DEPARE", "DEPCNT", "dangers"]
+//! palette = "dangers"
+//! stylesheet = "styles/night.txt"
+//! width = 1920
+//! height = 1080
+//! ```
+//!
+//! A relative stylesheet path is taken from the config file's directory.
+//! `$XDG_CONFIG_HOME/s57/config.toml` is used instead when that variable is
+//! set.
+
+use crate::preset::{Palette, Preset};
+use crate::render::RenderOptions;
+use crate::stylesheet::Stylesheet;
+use clap::ValueEnum;
+use serde::{Deserialize, Deserializer};
+use std::path::{Path, PathBuf};
+
+/// Config: Contents of the config file
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub render: RenderDefaults,
+}
+
+/// RenderDefaults: Defaults for `render` and `extract` options
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct RenderDefaults {
+    #[serde(deserialize_with = "value_enum")]
+    pub preset: Option<Preset>,
+    pub classes: Option<Vec<String>>,
+    #[serde(deserialize_with = "value_enum")]
+    pub palette: Option<Palette>,
+    pub stylesheet: Option<PathBuf>,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+}
+
+impl Config {
+    /// Where the config file is looked for, if a home directory is known
+    pub fn default_path() -> Option<PathBuf> {
+        let base = match std::env::var_os("XDG_CONFIG_HOME") {
+            Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+            _ => PathBuf::from(std::env::var_os("HOME")?).join(".config"),
+        };
+        Some(base.join("s57").join("config.toml"))
+    }
+
+    /// Read the config file at the default path; a missing file gives the
+    /// built-in defaults
+    pub fn load_default() -> Result<Self, String> {
+        match Self::default_path() {
+            Some(path) if path.exists() => Self::load(&path),
+            _ => Ok(Self::default()),
+        }
+    }
+
+    /// Read a config file
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text =
+            std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        let mut config: Config =
+            toml::from_str(&text).map_err(|e| format!("{}: {}", path.display(), e))?;
+        if let (Some(stylesheet), Some(dir)) = (&mut config.render.stylesheet, path.parent()) {
+            if stylesheet.is_relative() {
+                *stylesheet = dir.join(&*stylesheet);
+            }
+        }
+        Ok(config)
+    }
+}
+
+/// RenderSettings: Options shared by `render` and `extract`, resolved from
+/// the command line, then the config file, then the built-in defaults
+pub struct RenderSettings {
+    pub preset: Preset,
+    pub options: RenderOptions,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl RenderSettings {
+    /// Resolve the command-line values against the config file; exits if
+    /// the config file or stylesheet cannot be read
+    pub fn resolve(
+        preset: Option<Preset>,
+        palette: Option<Palette>,
+        classes: Option<&[String]>,
+        stylesheet: Option<&Path>,
+        width: Option<u32>,
+        height: Option<u32>,
+    ) -> Self {
+        let defaults = match Config::load_default() {
+            Ok(config) => config.render,
+            Err(e) => {
+                eprintln!("Error loading config: {}", e);
+                std::process::exit(1);
+            }
+        };
+        Self::with_defaults(
+            &defaults, preset, palette, classes, stylesheet, width, height,
+        )
+    }
+
+    /// Resolve the command-line values against `defaults`
+    fn with_defaults(
+        defaults: &RenderDefaults,
+        preset: Option<Preset>,
+        palette: Option<Palette>,
+        classes: Option<&[String]>,
+        stylesheet: Option<&Path>,
+        width: Option<u32>,
+        height: Option<u32>,
+    ) -> Self {
+        let preset = preset.or(defaults.preset).unwrap_or(Preset::Harbor);
+        let classes = classes.or(defaults.classes.as_deref());
+        let mut options = RenderOptions::from_preset(preset, classes);
+        if let Some(palette) = palette.or(defaults.palette) {
+            options.palette = palette;
+        }
+        options.stylesheet = load_stylesheet(stylesheet.or(defaults.stylesheet.as_deref()));
+        Self {
+            preset,
+            options,
+            width: width.or(defaults.width).unwrap_or(1200),
+            height: height.or(defaults.height).unwrap_or(800),
+        }
+    }
+}
+
+/// Load the --stylesheet file, or the default draw order; exits on error
+fn load_stylesheet(path: Option<&Path>) -> Stylesheet {
+    let Some(path) = path else {
+        return Stylesheet::default();
+    };
+    match Stylesheet::load(path) {
+        Ok(stylesheet) => stylesheet,
+        Err(e) => {
+            eprintln!("Error loading stylesheet: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Deserialize a clap value enum from the name its command-line option takes
+fn value_enum<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: ValueEnum,
+{
+    let name = String::deserialize(deserializer)?;
+    T::from_str(&name, true)
+        .map(Some)
+        .map_err(serde::de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(text: &str) -> Result<Config, String> {
+        toml::from_str(text).map_err(|e| e.to_string())
+    }
+
+    fn defaults() -> RenderDefaults {
+        parse(
+            r#"
+            [render]
+            preset = "bathymetry"
+            classes = ["DEPARE"]
+            palette = "dangers"
+            width = 640
+            height = 480
+            "#,
+        )
+        .unwrap()
+        .render
+    }
+
+    #[test]
+    fn test_parse() {
+        let render = defaults();
+        assert_eq!(render.preset, Some(Preset::Bathymetry));
+        assert_eq!(render.classes, Some(vec!["DEPARE".to_string()]));
+        assert_eq!(render.palette, Some(Palette::Dangers));
+        assert_eq!((render.width, render.height), (Some(640), Some(480)));
+        assert_eq!(render.stylesheet, None);
+
+        // Names match case-insensitively, as on the command line
+        let render = parse("[render]\npalette = \"S52-Night\"").unwrap().render;
+        assert_eq!(render.palette, Some(Palette::S52Night));
+
+        // Every key is optional
+        assert!(parse("").unwrap().render.preset.is_none());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        // Unknown tables and keys
+        assert!(parse("[view]\nwidth = 640").is_err());
+        assert!(parse("[render]\ncolour = \"blue\"").is_err());
+        // Bad values
+        assert!(parse("[render]\npreset = \"harbour\"").is_err());
+        assert!(parse("[render]\npalette = 3").is_err());
+        assert!(parse("[render]\nwidth = -1").is_err());
+        assert!(parse("[render]\nheight = \"tall\"").is_err());
+        assert!(parse("[render]\nclasses = \"DEPARE\"").is_err());
+    }
+
+    #[test]
+    fn test_load_resolves_stylesheet() {
+        let dir = std::env::temp_dir().join(format!("s57-config-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.toml");
+
+        std::fs::write(&path, "[render]\nstylesheet = \"night.txt\"").unwrap();
+        let config = Config::load(&path).unwrap();
+        assert_eq!(config.render.stylesheet, Some(dir.join("night.txt")));
+
+        // Errors name the file
+        std::fs::write(&path, "[render]\nwidth = \"wide\"").unwrap();
+        let error = Config::load(&path).unwrap_err();
+        assert!(error.starts_with(&path.display().to_string()), "{}", error);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_precedence() {
+        // Nothing given: the built-in defaults
+        let settings = RenderSettings::with_defaults(
+            &RenderDefaults::default(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        assert_eq!(settings.preset, Preset::Harbor);
+        assert_eq!(settings.options.palette, Preset::Harbor.palette());
+        assert_eq!((settings.width, settings.height), (1200, 800));
+
+        // The config file over the built-in defaults
+        let defaults = defaults();
+        let settings = RenderSettings::with_defaults(&defaults, None, None, None, None, None, None);
+        assert_eq!(settings.preset, Preset::Bathymetry);
+        assert_eq!(settings.options.palette, Palette::Dangers);
+        assert_eq!(settings.options.allowed_classes, [42].into());
+        assert_eq!((settings.width, settings.height), (640, 480));
+
+        // The command line over the config file, one option at a time
+        let classes = ["LNDARE".to_string()];
+        let settings = RenderSettings::with_defaults(
+            &defaults,
+            Some(Preset::Overview),
+            Some(Palette::Standard),
+            Some(&classes),
+            None,
+            Some(1920),
+            None,
+        );
+        assert_eq!(settings.preset, Preset::Overview);
+        assert_eq!(settings.options.palette, Palette::Standard);
+        assert_eq!(settings.options.allowed_classes, [71].into());
+        assert_eq!((settings.width, settings.height), (1920, 480));
+    }
+}
@@ -0,0 +1,81 @@
+//! Exporting a cell's features to GeoJSON / JSON-FG
+
+use crate::{ExportFormat, OutputProjection};
+use s57_interp::geojson::{to_geojson, CoordinatePrecision, GeoJsonFlavor, GeoJsonOptions};
+use s57_parse::S57File;
+use std::path::Path;
+
+/// Build the World and write it as GeoJSON to `output` (or stdout)
+///
+/// Transverse Mercator is centred on the cell's extent. With `since`, only
+/// the features that changed from that older edition are written.
+pub fn export(
+    file: &S57File,
+    format: ExportFormat,
+    options: GeoJsonOptions,
+    projection: OutputProjection,
+    since: Option<&Path>,
+    output: Option<&Path>,
+) {
+    let world = crate::render::build_world(file);
+    let options = match since {
+        Some(path) => {
+            let old = read_cell(path);
+            let diff = crate::render::build_world(&old).diff(&world);
+            eprintln!("Changes since {}: {}", path.display(), diff);
+            options.changes(diff)
+        }
+        None => options,
+    };
+    let options = match world.extent() {
+        Some(extent) => options.projection(projection.around(&extent)),
+        None => options,
+    };
+
+    let flavor = match format {
+        ExportFormat::Geojson => GeoJsonFlavor::Rfc7946,
+        ExportFormat::JsonFg => GeoJsonFlavor::JsonFg,
+    };
+    let collection = to_geojson(&world, &options.flavor(flavor));
+
+    let text = collection.to_string();
+    match output {
+        Some(path) => {
+            if let Err(e) = std::fs::write(path, text) {
+                eprintln!("Error writing {}: {}", path.display(), e);
+                std::process::exit(1);
+            }
+            eprintln!("GeoJSON written to: {}", path.display());
+        }
+        None => println!("{}", text),
+    }
+}
+
+/// Read and parse a cell other than FILE (e.g. the `--since` edition),
+/// exiting on error
+pub fn read_cell(path: &Path) -> S57File {
+    let parsed = std::fs::read(path)
+        .map_err(|e| e.to_string())
+        .and_then(|data| S57File::from_bytes(&data).map_err(|e| e.to_string()));
+    match parsed {
+        Ok(file) => file,
+        Err(e) => {
+            eprintln!("Error reading {}: {}", path.display(), e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Parse `--precision`: a number of decimals, or "comf" for dataset precision
+pub fn parse_precision(value: &str) -> Result<CoordinatePrecision, String> {
+    if value.eq_ignore_ascii_case("comf") {
+        return Ok(CoordinatePrecision::Dataset);
+    }
+    match value.parse::<u32>() {
+        Ok(decimals) if decimals <= 15 => Ok(CoordinatePrecision::Decimals(decimals)),
+        _ => Err(format!(
+            "expected a number of decimals (0-15) or \"comf\", got \"{}\"",
+            value
+        )),
+    }
+}
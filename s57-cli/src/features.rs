@@ -1,6 +1,8 @@
 use num_traits::ToPrimitive;
 use s57_catalogue::{AttributeInfo, ObjectClass};
-use s57_interp::ecs::EntityType;
+use s57_interp::ecs::{EntityType, NamePreference, Relationship};
+use s57_parse::format;
+use s57_parse::interpret::interpret_quality_of_position;
 use s57_parse::S57File;
 
 pub fn list_features(file: &S57File) {
@@ -41,18 +43,11 @@ pub fn list_features(file: &S57File) {
                 .map(|c| c.name().to_string())
                 .unwrap_or_else(|| format!("Unknown ({})", meta.objl));
 
-            // Get object name from attributes (OBJNAM = 116)
+            // Get object name (OBJNAM, or NOBJNM if that's all there is)
             let objnam = world
-                .feature_attributes
-                .get(&entity)
-                .and_then(|attrs| {
-                    attrs
-                        .attf
-                        .iter()
-                        .find(|(attl, _)| *attl == 116)
-                        .map(|(_, atvl)| atvl.clone())
-                })
-                .unwrap_or_default();
+                .feature_name(entity, NamePreference::International)
+                .unwrap_or_default()
+                .to_string();
 
             // Format FOID
             let foid_str = format!("{}:{}:{}", meta.foid.agen, meta.foid.fidn, meta.foid.fids);
@@ -132,6 +127,9 @@ pub fn show_object(file: &S57File, target_rcid: u32) {
     );
     println!("  Record Version: {}", meta.rver);
     println!("  Update Instruction: {}", meta.ruin);
+    if let Some(source) = world.record_sources.get(&entity) {
+        println!("  Source: {}", source);
+    }
 
     // Print attributes
     if let Some(attrs) = world.feature_attributes.get(&entity) {
@@ -139,14 +137,14 @@ pub fn show_object(file: &S57File, target_rcid: u32) {
             println!("\nAttributes (ATTF):");
             for (attl, atvl) in &attrs.attf {
                 let attr_name = AttributeInfo::attribute_name(*attl).unwrap_or("Unknown");
-                println!("  {} = \"{}\" ({})", attl, atvl, attr_name);
+                println!("  {} = {} ({})", attl, format::quote(atvl), attr_name);
             }
         }
 
         if !attrs.natf.is_empty() {
             println!("\nNational Attributes (NATF):");
             for (attl, atvl) in &attrs.natf {
-                println!("  {} = \"{}\"", attl, atvl);
+                println!("  {} = {}", attl, format::quote(atvl));
             }
         }
     }
@@ -235,25 +233,61 @@ pub fn show_object(file: &S57File, target_rcid: u32) {
             }
         }
 
+        let quality = world.feature_positional_quality(entity);
+        if !quality.is_empty() {
+            println!("\nPositional Quality ({} vectors):", quality.len());
+            for (vector, q) in &quality {
+                let name = world
+                    .vector_meta
+                    .get(vector)
+                    .map(|vmeta| format!("{}:{}", vmeta.name.rcnm, vmeta.name.rcid))
+                    .unwrap_or_else(|| "?".to_string());
+                let quapos = q
+                    .quapos
+                    .map(|v| format!("QUAPOS={} ({})", v, interpret_quality_of_position(v)))
+                    .unwrap_or_default();
+                let posacc = q
+                    .posacc
+                    .as_ref()
+                    .map(|v| format!("POSACC={}", v.to_f64().unwrap_or(0.0)))
+                    .unwrap_or_default();
+                println!("  Vector {} {} {}", name, quapos, posacc);
+            }
+        }
+
         if !pointers.related_features.is_empty() {
             println!("\nRelated Features ({}):", pointers.related_features.len());
-            for (idx, related_entity) in pointers.related_features.iter().enumerate() {
-                if let Some(rmeta) = world.feature_meta.get(related_entity) {
+            for (idx, related) in pointers.related_features.iter().enumerate() {
+                if let Some(rmeta) = world.feature_meta.get(&related.entity) {
                     let objl_str = ObjectClass::from_code(rmeta.objl)
                         .map(|c| c.name().to_string())
                         .unwrap_or_else(|| format!("Unknown ({})", rmeta.objl));
+                    let role = match related.rind {
+                        Some(Relationship::Master) => "master",
+                        Some(Relationship::Slave) => "slave",
+                        Some(Relationship::Peer) => "peer",
+                        None => "unknown role",
+                    };
                     println!(
-                        "  [{}] FOID {}:{}:{} - {} ({})",
+                        "  [{}] FOID {}:{}:{} - {} ({}), {}",
                         idx,
                         rmeta.foid.agen,
                         rmeta.foid.fidn,
                         rmeta.foid.fids,
                         rmeta.objl,
-                        objl_str
+                        objl_str,
+                        role
                     );
                 }
             }
         }
+
+        if let Some(raw_fields) = world.raw_fields.get(&entity) {
+            println!("\nNon-standard Fields ({}):", raw_fields.len());
+            for raw in raw_fields {
+                println!("  {} ({} bytes)", raw.tag, raw.data.len());
+            }
+        }
     }
 }
 
@@ -289,48 +323,52 @@ pub fn print_extent(
     };
 
     // Compute extent from all coordinates (S-57 files don't store pre-computed bounds)
-    let mut min_lat: Option<f64> = None;
-    let mut max_lat: Option<f64> = None;
-    let mut min_lon: Option<f64> = None;
-    let mut max_lon: Option<f64> = None;
-    let mut coord_count = 0;
-
-    // Iterate through all vector entities with exact positions
-    for (_entity, positions) in &world.exact_positions {
-        for i in 0..positions.lat.len() {
-            let lat = positions.lat[i].to_f64().unwrap_or(0.0);
-            let lon = positions.lon[i].to_f64().unwrap_or(0.0);
-
-            min_lat = Some(min_lat.map_or(lat, |v| v.min(lat)));
-            max_lat = Some(max_lat.map_or(lat, |v| v.max(lat)));
-            min_lon = Some(min_lon.map_or(lon, |v| v.min(lon)));
-            max_lon = Some(max_lon.map_or(lon, |v| v.max(lon)));
-            coord_count += 1;
-        }
+    let coord_count: usize = world
+        .exact_positions
+        .values()
+        .map(|positions| positions.lat.len())
+        .sum();
+    let metadata = world.metadata();
+    if !metadata.name.is_empty() {
+        let number = |n: Option<u32>| n.map_or("?".to_string(), |n| n.to_string());
+        println!(
+            "Cell: {} (edition {}, update {})",
+            metadata.name,
+            number(metadata.edition),
+            number(metadata.update)
+        );
     }
-
-    if coord_count == 0 {
+    if let Some(purpose) = metadata.purpose {
+        println!("Navigational purpose: {} ({})", purpose, purpose.intu());
+    }
+    if let Some(scale) = metadata.compilation_scale {
+        println!("Compilation scale: 1:{}", scale);
+    }
+    let Some(extent) = metadata.extent else {
         println!("No coordinates found in chart");
         return;
-    }
+    };
+    let deg = |v: &num_rational::BigRational| v.to_f64().unwrap_or(0.0);
+    let (min_lat, max_lat) = (deg(&extent.min_lat), deg(&extent.max_lat));
 
     // Print extent
     println!("Geographic Extent:");
+    println!("  Latitude:  {:.7} to {:.7}", min_lat, max_lat);
     println!(
-        "  Latitude:  {:.7} to {:.7}",
-        min_lat.unwrap(),
-        max_lat.unwrap()
-    );
-    println!(
-        "  Longitude: {:.7} to {:.7}",
-        min_lon.unwrap(),
-        max_lon.unwrap()
+        "  Longitude: {:.7} to {:.7}{}",
+        deg(&extent.min_lon),
+        deg(&extent.max_lon),
+        if extent.crosses_antimeridian() {
+            " (crosses the antimeridian)"
+        } else {
+            ""
+        }
     );
     println!("  Total coordinates: {}", coord_count);
 
     // Calculate dimensions
-    let lat_span = max_lat.unwrap() - min_lat.unwrap();
-    let lon_span = max_lon.unwrap() - min_lon.unwrap();
+    let lat_span = max_lat - min_lat;
+    let lon_span = deg(&extent.lon_span());
     println!("\nDimensions:");
     println!(
         "  Latitude span:  {:.7}° ({:.2} km)",
@@ -340,10 +378,6 @@ pub fn print_extent(
     println!(
         "  Longitude span: {:.7}° ({:.2} km at center)",
         lon_span,
-        lon_span
-            * 111.0
-            * ((min_lat.unwrap() + max_lat.unwrap()) / 2.0)
-                .to_radians()
-                .cos()
+        lon_span * 111.0 * ((min_lat + max_lat) / 2.0).to_radians().cos()
     );
 }
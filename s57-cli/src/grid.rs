@@ -0,0 +1,35 @@
+//! Gridding a cell's soundings into a raster depth model
+
+use crate::GridFormat;
+use s57_interp::grid::{DepthGrid, GridOptions};
+use s57_parse::S57File;
+use std::path::Path;
+
+/// Build the World, grid its depths and write them to `output`
+pub fn grid(file: &S57File, format: GridFormat, options: &GridOptions, output: &Path) {
+    let world = crate::render::build_world(file);
+    let grid = match DepthGrid::build(&world, options) {
+        Ok(grid) => grid,
+        Err(e) => {
+            eprintln!("Error gridding depths: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let bytes = match format {
+        GridFormat::Geotiff => grid.to_geotiff(),
+        GridFormat::Xyz => grid.to_xyz().into_bytes(),
+    };
+    if let Err(e) = std::fs::write(output, bytes) {
+        eprintln!("Error writing {}: {}", output.display(), e);
+        std::process::exit(1);
+    }
+    let filled = grid.values.iter().filter(|v| !v.is_nan()).count();
+    println!(
+        "Depth grid ({}x{} cells, {} with depths) written to: {}",
+        grid.width,
+        grid.height,
+        filled,
+        output.display()
+    );
+}
@@ -0,0 +1,70 @@
+//! Edition/update timeline of a cell across an ENC archive directory
+
+use s57_catalogue::ObjectClass;
+use s57_interp::diff::ChangeKind;
+use s57_interp::history::cell_history;
+use s57_interp::ParseOptions;
+use std::path::Path;
+
+/// Print one line per edition and update of `cell` found under `dir`
+///
+/// With `verbose`, each line is followed by the features it changed.
+pub fn history(dir: &Path, cell: &str, verbose: bool) {
+    if !dir.is_dir() {
+        eprintln!(
+            "Error: {} is not a directory (history searches it for the cell's files)",
+            dir.display()
+        );
+        std::process::exit(1);
+    }
+    let entries = match cell_history(dir, cell, &ParseOptions::default()) {
+        Ok(entries) => entries,
+        Err(e) => {
+            eprintln!("Error building history: {}", e);
+            std::process::exit(1);
+        }
+    };
+    if entries.is_empty() {
+        eprintln!("Error: no files for cell {} under {}", cell, dir.display());
+        std::process::exit(1);
+    }
+
+    println!("{}: {} file(s)", cell, entries.len());
+    for entry in &entries {
+        let kind = if entry.is_base() { "edition" } else { "update" };
+        println!(
+            "  Edition {} update {} ({}), issued {}, applied {}: {}",
+            entry.edition,
+            entry.update,
+            kind,
+            or_dash(&entry.issue_date),
+            or_dash(&entry.application_date),
+            entry.changes
+        );
+        println!("    {}", entry.path.display());
+        if verbose {
+            for change in &entry.changes.changes {
+                let sign = match change.kind {
+                    ChangeKind::Added => '+',
+                    ChangeKind::Removed => '-',
+                    ChangeKind::Modified => '~',
+                };
+                let class = ObjectClass::from_code(change.objl)
+                    .map(|c| c.name().to_string())
+                    .unwrap_or_else(|| format!("Unknown ({})", change.objl));
+                println!(
+                    "    {} {}:{}:{} {}",
+                    sign, change.foid.agen, change.foid.fidn, change.foid.fids, class
+                );
+            }
+        }
+    }
+}
+
+fn or_dash(date: &str) -> &str {
+    if date.trim().is_empty() {
+        "-"
+    } else {
+        date
+    }
+}
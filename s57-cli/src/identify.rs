@@ -0,0 +1,73 @@
+//! Pick report, depth and zone of confidence at a position
+
+use num_rational::BigRational;
+use num_traits::{Signed, ToPrimitive};
+use s57_catalogue::decode_object;
+use s57_interp::ecs::{parse_decimal, NamePreference};
+use s57_interp::topology::TraversalContext;
+use s57_parse::format;
+use s57_parse::S57File;
+
+/// Print the pick report at a (lat, lon) position given as decimal degrees
+pub fn identify(file: &S57File, lat: &str, lon: &str, tolerance: &str) {
+    let (Some(lat), Some(lon)) = (parse_decimal(lat), parse_decimal(lon)) else {
+        eprintln!("Error: position must be decimal degrees, e.g. 50.1 -1.25");
+        std::process::exit(1);
+    };
+    let Some(tolerance) = parse_decimal(tolerance).filter(|t| !t.is_negative()) else {
+        eprintln!("Error: --tolerance must be a non-negative number of degrees");
+        std::process::exit(1);
+    };
+    let point = (lat, lon);
+
+    let world = crate::render::build_world(file);
+    let ctx = TraversalContext::new(&world);
+
+    let picked = ctx.pick_report(&point, &tolerance);
+    println!("Features: {}", picked.len());
+    let mut current = None;
+    for entity in &picked {
+        let (Some(meta), Some(group)) = (world.feature_meta.get(entity), world.pick_group(*entity))
+        else {
+            continue;
+        };
+        if current != Some(group) {
+            println!("  {}:", capitalize(&group.to_string()));
+            current = Some(group);
+        }
+        let class = decode_object(meta.objl)
+            .map(|c| c.to_string())
+            .unwrap_or_else(|| meta.objl.to_string());
+        match world.feature_name(*entity, NamePreference::International) {
+            Some(name) if !name.is_empty() => {
+                println!("    {} {} {}", class, meta.foid.fidn, format::quote(name))
+            }
+            _ => println!("    {} {}", class, meta.foid.fidn),
+        }
+    }
+
+    if let Some(depth) = ctx.depth_at(&point) {
+        let show = |v: Option<BigRational>| {
+            v.and_then(|v| v.to_f64())
+                .map(|v| format!("{:.1}", v))
+                .unwrap_or_else(|| "?".to_string())
+        };
+        println!("Depth: {} - {} m", show(depth.drval1), show(depth.drval2));
+    }
+    if let Some(catzoc) = ctx.zoc_at(&point) {
+        let zoc = ["A1", "A2", "B", "C", "D", "U"]
+            .get(usize::from(catzoc).wrapping_sub(1))
+            .copied()
+            .unwrap_or("?");
+        println!("CATZOC: {} ({})", zoc, catzoc);
+    }
+}
+
+/// "dangers" -> "Dangers"
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
@@ -5,6 +5,7 @@ use num_traits::ToPrimitive;
 use rusqlite::{Connection, Result as SqlResult};
 use s57_catalogue::ObjectClass;
 use s57_interp::ecs::EntityType;
+use s57_interp::region::BoundingBox;
 use s57_interp::topology::{ContinuityPolicy, EdgeWalker, TraversalContext};
 use s57_parse::S57File;
 use std::path::Path;
@@ -27,9 +28,9 @@ pub struct IndexStats {
     pub indexed_features: usize,
     pub chart_min_lat: Option<f64>,
     pub chart_max_lat: Option<f64>,
+    /// Greater than chart_max_lon if the chart crosses the antimeridian
     pub chart_min_lon: Option<f64>,
     pub chart_max_lon: Option<f64>,
-    pub scale: u32,
 }
 
 /// Initialize database and create tables
@@ -57,7 +58,7 @@ fn init_database(db_path: &Path) -> SqlResult<Connection> {
             update_instruction INTEGER,
             min_lat REAL NOT NULL,
             max_lat REAL NOT NULL,
-            min_lon REAL NOT NULL,
+            min_lon REAL NOT NULL, -- > max_lon across the antimeridian
             max_lon REAL NOT NULL,
             PRIMARY KEY (filename, entity_id)
         )",
@@ -117,8 +118,8 @@ pub fn index_features(
         chart_max_lat: None,
         chart_min_lon: None,
         chart_max_lon: None,
-        scale,
     };
+    let mut chart_extent: Option<BoundingBox> = None;
 
     for entity in features {
         if let Some(meta) = world.feature_meta.get(&entity) {
@@ -133,50 +134,36 @@ pub fn index_features(
             };
 
             // Compute feature extent from its spatial references
-            let mut feat_min_lat: Option<f64> = None;
-            let mut feat_max_lat: Option<f64> = None;
-            let mut feat_min_lon: Option<f64> = None;
-            let mut feat_max_lon: Option<f64> = None;
-
+            let mut coords = Vec::new();
             if let Some(pointers) = world.feature_pointers.get(&entity) {
                 for sref in &pointers.spatial_refs {
                     // Get vector coordinates
                     if let Some(vmeta) = world.vector_meta.get(&sref.entity) {
                         let mut walker = EdgeWalker::new(&ctx);
-                        if let Ok(coords) = walker.resolve_line_2d(vmeta.name) {
-                            for (lat, lon) in coords {
-                                let lat_f64 = lat.to_f64().unwrap_or(0.0);
-                                let lon_f64 = lon.to_f64().unwrap_or(0.0);
-
-                                feat_min_lat =
-                                    Some(feat_min_lat.map_or(lat_f64, |v| v.min(lat_f64)));
-                                feat_max_lat =
-                                    Some(feat_max_lat.map_or(lat_f64, |v| v.max(lat_f64)));
-                                feat_min_lon =
-                                    Some(feat_min_lon.map_or(lon_f64, |v| v.min(lon_f64)));
-                                feat_max_lon =
-                                    Some(feat_max_lon.map_or(lon_f64, |v| v.max(lon_f64)));
-                            }
+                        if let Ok(line) = walker.resolve_line_2d(vmeta.name) {
+                            coords.extend(line);
                         }
                     }
                 }
             }
 
             // Skip features with no coordinates
-            if feat_min_lat.is_none() {
+            let Some(extent) = BoundingBox::from_points(coords.iter().map(|(lat, lon)| (lat, lon)))
+            else {
                 continue;
-            }
+            };
 
-            let min_lat = feat_min_lat.unwrap();
-            let max_lat = feat_max_lat.unwrap();
-            let min_lon = feat_min_lon.unwrap();
-            let max_lon = feat_max_lon.unwrap();
+            // min_lon > max_lon for features crossing the antimeridian
+            let min_lat = extent.min_lat.to_f64().unwrap_or(0.0);
+            let max_lat = extent.max_lat.to_f64().unwrap_or(0.0);
+            let min_lon = extent.min_lon.to_f64().unwrap_or(0.0);
+            let max_lon = extent.max_lon.to_f64().unwrap_or(0.0);
 
             // Update chart extent
-            stats.chart_min_lat = Some(stats.chart_min_lat.map_or(min_lat, |v| v.min(min_lat)));
-            stats.chart_max_lat = Some(stats.chart_max_lat.map_or(max_lat, |v| v.max(max_lat)));
-            stats.chart_min_lon = Some(stats.chart_min_lon.map_or(min_lon, |v| v.min(min_lon)));
-            stats.chart_max_lon = Some(stats.chart_max_lon.map_or(max_lon, |v| v.max(max_lon)));
+            chart_extent = Some(match chart_extent {
+                Some(chart) => extent.union(&chart),
+                None => extent,
+            });
 
             // Format entity ID
             let entity_id = format!("{}:{}:{}", meta.foid.agen, meta.foid.fidn, meta.foid.fids);
@@ -208,7 +195,7 @@ pub fn index_features(
             ]) {
                 Ok(_) => {
                     stats.indexed_features += 1;
-                    if stats.indexed_features % 100 == 0 {
+                    if stats.indexed_features.is_multiple_of(100) {
                         info!("Indexed {} features...", stats.indexed_features);
                     }
                 }
@@ -219,5 +206,12 @@ pub fn index_features(
         }
     }
 
+    if let Some(extent) = chart_extent {
+        stats.chart_min_lat = extent.min_lat.to_f64();
+        stats.chart_max_lat = extent.max_lat.to_f64();
+        stats.chart_min_lon = extent.min_lon.to_f64();
+        stats.chart_max_lon = extent.max_lon.to_f64();
+    }
+
     Ok(stats)
 }
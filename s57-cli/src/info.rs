@@ -0,0 +1,208 @@
+//! `info --format json`: a machine-readable summary of a cell
+//!
+//! The JSON carries what the text report prints (record and field
+//! definition summaries, DSSI record counts) plus leader statistics, the
+//! DSID identification and the geographic extent, so chart holdings can be
+//! indexed without parsing text.
+
+use num_rational::BigRational;
+use num_traits::ToPrimitive;
+use s57_interp::ecs::World;
+use s57_interp::metadata::agency;
+use s57_interp::record_counts::StructureIssue;
+use s57_parse::ddr::DDR;
+use s57_parse::S57File;
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Print the JSON summary of a cell to stdout, with the content
+/// fingerprint if `fingerprint` is set
+pub fn print_info_json(path: &Path, file_size: usize, file: &S57File, fingerprint: bool) {
+    let records = file.records();
+    let mut info = Map::new();
+    info.insert("file".into(), json!(path.display().to_string()));
+    info.insert("size".into(), json!(file_size));
+    info.insert("records".into(), json!(records.len()));
+    info.insert("leader".into(), leader_stats(file));
+
+    if let Some(ddr_record) = records.first().filter(|r| r.leader.is_ddr()) {
+        match DDR::parse(ddr_record) {
+            Ok(ddr) => {
+                info.insert("field_definitions".into(), field_definitions(&ddr));
+            }
+            Err(e) => eprintln!("Warning: Failed to parse DDR: {}", e),
+        }
+    }
+    info.insert("record_types".into(), record_types(file));
+
+    match s57_interp::build_world(file) {
+        Ok(world) => {
+            info.insert("dsid".into(), dsid(&world));
+            info.insert("dssi".into(), dssi(&world));
+            info.insert("extent".into(), extent(&world));
+            if fingerprint {
+                info.insert("fingerprint".into(), json!(world.fingerprint().to_string()));
+            }
+        }
+        Err(e) => eprintln!("Warning: Failed to build world: {}", e),
+    }
+
+    match serde_json::to_string_pretty(&Value::Object(info)) {
+        Ok(text) => println!("{}", text),
+        Err(e) => {
+            eprintln!("Error writing JSON: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Record lengths and the leader values seen, with how many records use each
+fn leader_stats(file: &S57File) -> Value {
+    let records = file.records();
+    let lengths: Vec<u32> = records.iter().map(|r| r.leader.record_length).collect();
+    let mut interchange_levels: BTreeMap<String, usize> = BTreeMap::new();
+    let mut entry_maps: BTreeMap<String, usize> = BTreeMap::new();
+    for record in records {
+        let leader = &record.leader;
+        *interchange_levels
+            .entry(leader.interchange_level.to_string())
+            .or_default() += 1;
+        // Sizes of the directory's length, position and tag fields
+        let entry_map = format!(
+            "{}{}{}",
+            leader.size_of_field_length_field,
+            leader.size_of_field_position_field,
+            leader.size_of_field_tag
+        );
+        *entry_maps.entry(entry_map).or_default() += 1;
+    }
+    json!({
+        "record_length": {
+            "min": lengths.iter().min(),
+            "max": lengths.iter().max(),
+            "total": lengths.iter().map(|&l| u64::from(l)).sum::<u64>(),
+        },
+        "interchange_levels": interchange_levels,
+        "entry_maps": entry_maps,
+    })
+}
+
+/// DDR field definitions by tag, leaving out the ISO 8211 control fields
+fn field_definitions(ddr: &DDR) -> Value {
+    let mut fields: Vec<_> = ddr
+        .field_defs()
+        .iter()
+        .filter(|(tag, _)| !tag.starts_with('0'))
+        .collect();
+    fields.sort_by(|a, b| a.0.cmp(b.0));
+    fields
+        .into_iter()
+        .map(|(tag, def)| {
+            json!({
+                "tag": tag,
+                "name": def.name,
+                "subfields": def.subfield_count(),
+                "repeating": def.is_repeating,
+                "format_controls": def.format_controls,
+                "built_in": ddr.is_fallback(tag),
+            })
+        })
+        .collect()
+}
+
+/// Data records by their record-identifying field (DSID, FRID, VRID, ...)
+fn record_types(file: &S57File) -> Value {
+    let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+    for record in file.records().iter().filter(|r| !r.leader.is_ddr()) {
+        // The first field after the 0001 record identifier names the kind
+        if let Some(field) = record.fields.iter().find(|f| !f.tag.starts_with('0')) {
+            *counts.entry(field.tag.clone()).or_default() += 1;
+        }
+    }
+    json!(counts)
+}
+
+/// DSID subfields, with the file's kind and the producing agency's name
+fn dsid(world: &World) -> Value {
+    let Some(dsid) = &world.dataset_id else {
+        return Value::Null;
+    };
+    let metadata = world.metadata();
+    let agency = metadata.agency.and_then(agency);
+    json!({
+        "dsnm": dsid.dsnm,
+        "edtn": dsid.edtn,
+        "updn": dsid.updn,
+        "uadt": dsid.uadt,
+        "isdt": dsid.isdt,
+        "intu": dsid.intu,
+        "purpose": metadata.purpose.map(|purpose| purpose.to_string()),
+        "expp": dsid.expp,
+        "kind": metadata.kind.map(|kind| kind.to_string()),
+        "sted": dsid.sted,
+        "prsp": dsid.prsp,
+        "pred": dsid.pred,
+        "prof": dsid.prof,
+        "agen": dsid.agen,
+        "agency": agency.map(|(_, name)| name),
+        "producer": agency.map(|(producer, _)| producer),
+        "comt": dsid.comt,
+    })
+}
+
+/// DSSI subfields, plus any record count or structure that disagrees with the file
+fn dssi(world: &World) -> Value {
+    let Some(dssi) = &world.dataset_structure else {
+        return Value::Null;
+    };
+    let mismatches: Vec<Value> = world
+        .check_record_counts()
+        .into_iter()
+        .map(|m| {
+            json!({
+                "kind": m.kind.description(),
+                "declared": m.declared,
+                "found": m.actual,
+            })
+        })
+        .collect();
+    // Count mismatches are listed above in structured form
+    let issues: Vec<String> = world
+        .validate_structure()
+        .into_iter()
+        .filter(|issue| !matches!(issue, StructureIssue::RecordCount(_)))
+        .map(|issue| issue.to_string())
+        .collect();
+    json!({
+        "dstr": dssi.dstr,
+        "topology": dssi.topology().map(|level| level.to_string()),
+        "aall": dssi.aall,
+        "nall": dssi.nall,
+        "nomr": dssi.nomr,
+        "nocr": dssi.nocr,
+        "nogr": dssi.nogr,
+        "nolr": dssi.nolr,
+        "noin": dssi.noin,
+        "nocn": dssi.nocn,
+        "noed": dssi.noed,
+        "nofa": dssi.nofa,
+        "record_count_mismatches": mismatches,
+        "structure_issues": issues,
+    })
+}
+
+/// Extent in degrees; min_lon > max_lon when it crosses the antimeridian
+fn extent(world: &World) -> Value {
+    let deg = |v: &BigRational| v.to_f64();
+    match world.extent() {
+        Some(bbox) => json!({
+            "min_lat": deg(&bbox.min_lat),
+            "min_lon": deg(&bbox.min_lon),
+            "max_lat": deg(&bbox.max_lat),
+            "max_lon": deg(&bbox.max_lon),
+            "crosses_antimeridian": bbox.crosses_antimeridian(),
+        }),
+        None => Value::Null,
+    }
+}
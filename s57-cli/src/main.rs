@@ -1,32 +1,94 @@
+mod catalog;
+mod catalogue;
+mod cell_coverage;
+mod compile;
+mod config;
+mod export;
 mod features;
+mod grid;
+mod history;
+mod identify;
 mod index;
+mod info;
+mod plugin;
+mod png;
+mod preset;
+mod priority;
+mod query;
+mod region;
 mod render;
+mod route;
+mod s52;
+mod schema;
+mod selector;
+mod soundings;
+mod stylesheet;
 mod svg;
+mod validate;
+mod watch;
 
-use clap::{Parser, Subcommand, ValueEnum};
+use clap::error::ErrorKind;
+use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
+use s57_interp::grid::{GridOptions, Interpolation};
+use s57_interp::ParseOptions;
 use s57_parse::S57File;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 #[derive(Parser)]
 #[command(name = "s57")]
 #[command(about = "Parse and inspect S-57 Electronic Navigational Chart files", long_about = None)]
+#[command(
+    override_usage = "s57 [OPTIONS] <FILE> <COMMAND>\n       s57 catalogue <object|attribute> <NAME>\n       s57 schema-diff <FIRST> <SECOND>\n       s57 --completions <SHELL>"
+)]
 struct Cli {
     /// S-57 file to parse
     #[arg(value_name = "FILE")]
-    file: PathBuf,
+    file: Option<PathBuf>,
+
+    /// Print a shell completion script and exit
+    #[arg(long, value_name = "SHELL", exclusive = true)]
+    completions: Option<clap_complete::Shell>,
 
     /// Show verbose output
     #[arg(short, long)]
     verbose: bool,
 
+    /// Skip records that don't parse, with a warning for each, instead of failing
+    #[arg(long)]
+    lossy: bool,
+
+    /// Apply the cell's update files (.001, .002, ...) before running the command
+    #[arg(short = 'u', long)]
+    apply_updates: bool,
+
+    /// What to do with update records that don't fit the cell (with --apply-updates)
+    #[arg(long, value_enum, default_value = "fail", requires = "apply_updates")]
+    on_conflict: ConflictAction,
+
     #[command(subcommand)]
-    command: Commands,
+    command: Option<Commands>,
 }
 
 #[derive(Subcommand)]
 enum Commands {
     /// Display summary information about the file
-    Info,
+    Info {
+        /// Output format
+        #[arg(short, long, value_enum, default_value = "text")]
+        format: InfoFormat,
+
+        /// Also build the World and report estimated memory use per component
+        #[arg(long, conflicts_with = "format")]
+        memory: bool,
+
+        /// Also print a content fingerprint (stable across record order and RCIDs)
+        #[arg(long)]
+        fingerprint: bool,
+
+        /// Also report, per object class, how often each attribute is populated
+        #[arg(long, conflicts_with = "format")]
+        coverage: bool,
+    },
 
     /// Print file contents
     Print {
@@ -62,11 +124,16 @@ enum Commands {
 
     /// Render features to SVG
     Render {
-        /// Output SVG file path
+        /// Output file path
         #[arg(short, long, value_name = "FILE")]
         output: PathBuf,
 
-        /// Maximum number of features to render
+        /// Image format
+        #[arg(long, value_enum, default_value = "svg")]
+        format: RenderFormat,
+
+        /// Maximum number of features to render; the lowest display priority
+        /// (S-52 style: base areas first, aids to navigation last) is dropped first
         #[arg(short, long)]
         limit: Option<usize>,
 
@@ -74,26 +141,375 @@ enum Commands {
         #[arg(long, value_name = "FIDN")]
         feature: Option<u32>,
 
-        /// Filter by comma-separated list of S-57 object class codes (e.g., "COALNE,BRIDGE,WRECKS")
-        /// Use 6-character S-57 codes from the object catalogue.
+        /// Render preset: class list, palette and scale settings [default: harbor]
+        #[arg(long, value_enum)]
+        preset: Option<preset::Preset>,
+
+        /// Colours, overriding the preset's
+        #[arg(long, value_enum)]
+        palette: Option<preset::Palette>,
+
+        /// Comma-separated object classes to draw, overriding the preset's: acronyms
+        /// ("COALNE"), numeric OBJL codes ("42"), wildcards ("BOY*") or groups
+        /// ("aids-to-navigation", "dangers", "depths", "land", "meta")
+        #[arg(long, value_name = "CLASSES", value_delimiter = ',')]
+        classes: Option<Vec<String>>,
+
+        /// Stylesheet file with per-class draw-order overrides
+        /// (`CLASSES = ORDER` per line)
+        #[arg(long, value_name = "FILE")]
+        stylesheet: Option<PathBuf>,
+
+        /// Safety depth in metres: draw the depth contour at this depth, or
+        /// the next deeper one charted, in bold (S-52 palettes also shade
+        /// depth areas against it)
+        #[arg(long, value_name = "DEPTH")]
+        safety_contour: Option<String>,
+
+        /// Canvas width in pixels [default: 1200]
+        #[arg(long)]
+        width: Option<u32>,
+
+        /// Canvas height in pixels [default: 800]
+        #[arg(long)]
+        height: Option<u32>,
+
+        /// Map projection
+        #[arg(long, value_enum, default_value = "plate-carree")]
+        projection: OutputProjection,
+
+        /// Keep running and re-render when the cell or its update files change
+        #[arg(long)]
+        watch: bool,
+
+        /// Draw a route from a GPX or RTZ file and warn about hazards along it
+        #[arg(long, value_name = "FILE")]
+        route: Option<PathBuf>,
+
+        /// Hazard corridor either side of each route leg, in metres
+        #[arg(long, value_name = "METRES", default_value = "200")]
+        corridor: f64,
+
+        /// Draw dynamic targets (e.g. AIS) from a CSV file of lat,lon[,heading[,label]]
+        ///
+        /// The file is re-read on every render.
+        #[arg(long, value_name = "FILE")]
+        targets: Option<PathBuf>,
+
+        /// Which object name to label features with when both are present
+        #[arg(long, value_enum, default_value = "international")]
+        names: Names,
+
+        /// Simplify paths and rings to at most this many points each
+        #[arg(long, value_name = "POINTS")]
+        max_path_points: Option<usize>,
+
+        /// Stop drawing chart elements after this many
+        #[arg(long, value_name = "COUNT")]
+        max_elements: Option<usize>,
+
+        /// Resolve geometry in f64 rather than exactly; faster on large cells
+        #[arg(long)]
+        approx: bool,
+    },
+
+    /// Extract all features in a bounding box from the cells of an exchange set
+    ///
+    /// FILE must be the exchange set's CATALOG.031. Cells whose catalogue
+    /// coverage touches the box are loaded, and their features merged and cut
+    /// to the box.
+    Extract {
+        /// Bounding box as MIN_LON,MIN_LAT,MAX_LON,MAX_LAT (degrees); MIN_LON greater
+        /// than MAX_LON crosses the antimeridian
         #[arg(
             long,
-            value_name = "CLASSES",
+            value_name = "BBOX",
             value_delimiter = ',',
-            default_value = "COALNE,LNDARE,DEPARE,DEPCNT,SEAARE,BRIDGE,BUISGL,LNDMRK,LIGHTS,BCNCAR,BCNLAT,BCNISD,BCNSAW,BOYCAR,BOYLAT,BOYISD,BOYSAW,ACHARE,WRECKS,OBSTRN,RIVERS,LAKARE,CANALS,DAMCON,BERTHS,HRBARE,RESARE,FAIRWY,PILBOP,OSPARE"
+            allow_hyphen_values = true,
+            required = true
         )]
-        classes: Vec<String>,
+        bbox: Vec<f64>,
+
+        /// Output file path for the merged region
+        #[arg(short, long, value_name = "FILE")]
+        output: PathBuf,
+
+        /// Output format; the render options below only apply to SVG
+        #[arg(short, long, value_enum, default_value = "svg")]
+        format: ExtractFormat,
+
+        /// Render preset: class list, palette and scale settings [default: harbor]
+        #[arg(long, value_enum)]
+        preset: Option<preset::Preset>,
+
+        /// Colours, overriding the preset's
+        #[arg(long, value_enum)]
+        palette: Option<preset::Palette>,
+
+        /// Comma-separated object classes, overriding the preset's (acronyms, OBJL
+        /// codes, wildcards or groups, as for `render`)
+        #[arg(long, value_name = "CLASSES", value_delimiter = ',')]
+        classes: Option<Vec<String>>,
 
-        /// Canvas width in pixels
-        #[arg(long, default_value = "1200")]
-        width: u32,
+        /// Stylesheet file with per-class draw-order overrides
+        /// (`CLASSES = ORDER` per line)
+        #[arg(long, value_name = "FILE")]
+        stylesheet: Option<PathBuf>,
 
-        /// Canvas height in pixels
-        #[arg(long, default_value = "800")]
-        height: u32,
+        /// Canvas width in pixels [default: 1200]
+        #[arg(long)]
+        width: Option<u32>,
+
+        /// Canvas height in pixels [default: 800]
+        #[arg(long)]
+        height: Option<u32>,
+
+        /// Map projection
+        #[arg(long, value_enum, default_value = "plate-carree")]
+        projection: OutputProjection,
+    },
+
+    /// Report gaps and overlaps between the M_COVR coverage of cells
+    ///
+    /// FILE must be the exchange set's CATALOG.031. Cells are compared
+    /// within each usage band (navigational purpose).
+    CheckCoverage,
+
+    /// List the files of an exchange set
+    ///
+    /// FILE must be the exchange set's CATALOG.031. With --verify, files on
+    /// disk are checked against their catalogued CRC-32, and missing and
+    /// unlisted files are reported.
+    Catalog {
+        /// Check the files on disk against the catalogue
+        #[arg(long)]
+        verify: bool,
+    },
+
+    /// Look up an object class or attribute in the S-57 Object Catalogue
+    ///
+    /// Takes no FILE: `s57 catalogue object DEPARE`, `s57 catalogue
+    /// attribute COLOUR`. Unknown names list the closest matches.
+    Catalogue {
+        #[command(subcommand)]
+        entry: CatalogueEntry,
+    },
+
+    /// Export features as GeoJSON
+    Export {
+        /// Output format
+        #[arg(short, long, value_enum, default_value = "geojson")]
+        format: ExportFormat,
+
+        /// Output file path (default: stdout)
+        #[arg(short, long, value_name = "FILE")]
+        output: Option<PathBuf>,
+
+        /// Round coordinates to a number of decimals, or to the dataset's
+        /// COMF/SOMF resolution with "comf" (default: full precision)
+        #[arg(long, value_name = "DECIMALS|comf", value_parser = export::parse_precision)]
+        precision: Option<s57_interp::geojson::CoordinatePrecision>,
+
+        /// Which object name fills the `name` property when both are present
+        #[arg(long, value_enum, default_value = "international")]
+        names: Names,
+
+        /// Also write coordinates in this projection, as JSON-FG `place`
+        /// (requires --format json-fg)
+        #[arg(long, value_enum, default_value = "plate-carree")]
+        projection: OutputProjection,
+
+        /// Export only these object classes (acronyms, OBJL codes, wildcards or
+        /// groups, as for `render`)
+        #[arg(long, value_name = "CLASSES", value_delimiter = ',')]
+        classes: Option<Vec<String>>,
+
+        /// Also export depth bands rebuilt from contours where the cell lacks
+        /// DEPARE coverage (marked "derived")
+        #[arg(long)]
+        derived_depth_areas: bool,
+
+        /// Export only features added or modified since this older edition,
+        /// plus a stub with `"change": "removed"` for each feature it drops
+        #[arg(long, value_name = "OLD.000")]
+        since: Option<PathBuf>,
+
+        /// Add a "source" property with the record number, update number and
+        /// byte offset each feature was read from
+        #[arg(long)]
+        provenance: bool,
+    },
+
+    /// Grid soundings and depth areas into a raster depth model
+    Grid {
+        /// Output file path
+        #[arg(short, long, value_name = "FILE")]
+        output: PathBuf,
+
+        /// Output format
+        #[arg(short, long, value_enum, default_value = "geotiff")]
+        format: GridFormat,
+
+        /// Cell size in degrees (default: 512 cells along the longer side)
+        #[arg(long, value_name = "DEGREES")]
+        resolution: Option<f64>,
+
+        /// Interpolation between soundings
+        #[arg(long, value_enum, default_value = "idw")]
+        method: GridMethod,
+
+        /// IDW distance weighting power
+        #[arg(long, default_value_t = 2.0)]
+        power: f64,
+
+        /// IDW search radius in cells
+        #[arg(long, default_value_t = 8)]
+        radius: u32,
+    },
+
+    /// Dump every sounding (SOUNDG) with its exact depth
+    ///
+    /// Positions and depths are written with the decimals COMF and SOMF
+    /// resolve, so no precision is lost.
+    Soundings {
+        /// Output file path (default: stdout)
+        #[arg(short, long, value_name = "FILE")]
+        output: Option<PathBuf>,
+
+        /// Output format
+        #[arg(short, long, value_enum, default_value = "csv")]
+        format: SoundingFormat,
+    },
+
+    /// Pick report: the features at a position, with the depth and zone of confidence there
+    ///
+    /// Features are listed in S-52 pick report order: dangers, aids to
+    /// navigation, other point and line features, areas, then meta objects.
+    /// Positions on an area's edge count as inside it.
+    Identify {
+        /// Latitude in decimal degrees
+        #[arg(allow_hyphen_values = true)]
+        lat: String,
+
+        /// Longitude in decimal degrees
+        #[arg(allow_hyphen_values = true)]
+        lon: String,
+
+        /// How far from the position point and line features are picked (degrees)
+        #[arg(long, default_value = "0.0005")]
+        tolerance: String,
+    },
+
+    /// List the features matching an attribute filter expression
+    ///
+    /// e.g. --where "OBJL=LIGHTS && VALNMR>10". Conditions are OBJL=CLASSES,
+    /// PRIM=point|line|area, ATTR OP VALUE (=, !=, <, <=, >, >=) or a bare
+    /// ATTR (present), combined with !, && and || and parentheses. Classes
+    /// take anything --classes accepts; attributes are acronyms.
+    Query {
+        /// Filter expression
+        #[arg(long = "where", value_name = "EXPR")]
+        filter: String,
+
+        /// Output format
+        #[arg(short, long, value_enum, default_value = "table")]
+        format: QueryFormat,
+    },
+
+    /// Check the cell against a subset of the IHO S-58 validation checks
+    ///
+    /// Mandatory attributes, allowed primitives, dangling spatial pointers,
+    /// duplicate FOIDs, unreferenced vectors and missing edge end nodes.
+    /// Exits with status 1 if any check fails with an error.
+    Validate {
+        /// Output format
+        #[arg(short, long, value_enum, default_value = "text")]
+        format: ValidateFormat,
+    },
+
+    /// List every edition and update of a cell with what each one changed
+    ///
+    /// FILE is a directory (e.g. an ENC_ROOT or an archive of past exchange
+    /// sets), searched recursively for CELL.000, CELL.001, ...
+    History {
+        /// Cell name without extension, e.g. GB100001
+        cell: String,
+    },
+
+    /// Dump the DDR's field and subfield definitions as JSON
+    ///
+    /// Each field lists its subfields' labels, formats and widths, whether
+    /// it repeats, whether the DDR defines it or the built-in S-57
+    /// definition is used, and whether it matches the standard definition.
+    Schema,
+
+    /// Compare the DDR field definitions of two cells
+    ///
+    /// Takes no FILE: `s57 schema-diff A.000 B.000`. Lists fields defined
+    /// in only one cell, built in for only one, or with different subfield
+    /// labels, formats, widths or repetition.
+    SchemaDiff {
+        /// First cell
+        first: PathBuf,
+        /// Second cell
+        second: PathBuf,
+    },
+
+    /// Compile the cell into a SENC file for fast loading
+    ///
+    /// The SENC holds the built World with its feature geometry resolved
+    /// and its spatial index, for `World::from_senc`. Compile again after
+    /// applying updates or upgrading s57.
+    Compile {
+        /// Output SENC file path
+        #[arg(short, long, value_name = "FILE")]
+        output: PathBuf,
+    },
+
+    /// Any other NAME runs the `s57-NAME` executable on PATH as
+    /// `s57-NAME FILE [ARGS...]`
+    #[command(external_subcommand)]
+    External(Vec<String>),
+}
+
+#[derive(Subcommand)]
+enum CatalogueEntry {
+    /// Object class: code, name, definition and the attributes it may carry
+    Object {
+        /// Acronym (DEPARE), OBJL code or name
+        name: String,
+    },
+    /// Attribute: code, name, type, definition and expected values
+    Attribute {
+        /// Acronym (COLOUR), ATTL code or name
+        name: String,
     },
 }
 
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+enum QueryFormat {
+    /// One feature per line: FOID, class, primitive and name
+    Table,
+    /// JSON array of features with their attributes by acronym
+    Json,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+enum ValidateFormat {
+    /// One finding per line
+    Text,
+    /// JSON array of findings with record numbers
+    Json,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+enum InfoFormat {
+    /// Human-readable report
+    Text,
+    /// JSON summary with leader statistics, DSID/DSSI content and extent
+    Json,
+}
+
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
 enum OutputFormat {
     /// YAML format with semantic interpretation
@@ -102,9 +518,175 @@ enum OutputFormat {
     Hex,
 }
 
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+enum ExtractFormat {
+    /// Rendered chart of the region
+    Svg,
+    /// RFC 7946 GeoJSON of the region's features
+    Geojson,
+    /// The region's World as a SENC dataset (see `compile`)
+    Senc,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+enum ExportFormat {
+    /// RFC 7946 GeoJSON
+    Geojson,
+    /// OGC JSON-FG (explicit CRS and temporal fields)
+    JsonFg,
+}
+
+/// Image format of a render
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+enum RenderFormat {
+    /// SVG, streamed element by element
+    #[default]
+    Svg,
+    /// PNG raster, drawn in memory (no text labels)
+    Png,
+}
+
+impl RenderFormat {
+    fn name(self) -> &'static str {
+        match self {
+            RenderFormat::Svg => "SVG",
+            RenderFormat::Png => "PNG",
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+enum GridFormat {
+    /// Float32 GeoTIFF in WGS84, NaN for no data
+    Geotiff,
+    /// "lon lat depth" text, one line per cell with data
+    Xyz,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+enum SoundingFormat {
+    /// "lat,lon,depth" with a header line
+    Csv,
+    /// "lon lat depth", one sounding per line
+    Xyz,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+enum GridMethod {
+    /// Inverse distance weighting
+    Idw,
+    /// Linear interpolation across a triangulation of the soundings
+    Tin,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+enum ConflictAction {
+    /// Reject the update
+    Fail,
+    /// Leave the conflicting records out
+    Skip,
+    /// Apply conflicting records where possible, ignoring RVER mismatches
+    Force,
+}
+
+impl From<ConflictAction> for s57_parse::update::ConflictPolicy {
+    fn from(action: ConflictAction) -> Self {
+        match action {
+            ConflictAction::Fail => Self::Fail,
+            ConflictAction::Skip => Self::Skip,
+            ConflictAction::Force => Self::Force,
+        }
+    }
+}
+
+/// Names: `--names` values, one per NamePreference
+#[derive(Copy, Clone)]
+struct Names(s57_interp::ecs::NamePreference);
+
+impl ValueEnum for Names {
+    fn value_variants<'a>() -> &'a [Self] {
+        use s57_interp::ecs::NamePreference;
+        &[
+            Names(NamePreference::International),
+            Names(NamePreference::National),
+        ]
+    }
+
+    fn to_possible_value(&self) -> Option<clap::builder::PossibleValue> {
+        use clap::builder::PossibleValue;
+        use s57_interp::ecs::NamePreference;
+        Some(match self.0 {
+            NamePreference::International => {
+                PossibleValue::new("international").help("OBJNAM, falling back to NOBJNM")
+            }
+            NamePreference::National => PossibleValue::new("national")
+                .help("NOBJNM (national language), falling back to OBJNAM"),
+        })
+    }
+}
+
+/// Map projection for rendered and exported coordinates
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+enum OutputProjection {
+    /// Longitude and latitude as x and y; renders scale longitude by the
+    /// cosine of the middle latitude
+    #[default]
+    PlateCarree,
+    /// Web Mercator (EPSG:3857), as used by web maps
+    WebMercator,
+    /// Transverse Mercator centred on the data, true to scale across a cell
+    TransverseMercator,
+}
+
+impl OutputProjection {
+    /// The projection for data covering `bbox`
+    fn around(self, bbox: &s57_interp::region::BoundingBox) -> s57_interp::projection::Projection {
+        use s57_interp::projection::Projection;
+        match self {
+            OutputProjection::PlateCarree => Projection::PlateCarree,
+            OutputProjection::WebMercator => Projection::WebMercator,
+            OutputProjection::TransverseMercator => Projection::transverse_mercator_around(bbox),
+        }
+    }
+}
+
 fn main() {
     let cli = Cli::parse();
 
+    if let Some(shell) = cli.completions {
+        // Complete the installed binary's name, not the command's display name
+        let name = env!("CARGO_BIN_NAME");
+        clap_complete::generate(shell, &mut Cli::command(), name, &mut std::io::stdout());
+        return;
+    }
+    // The catalogue reference needs no chart, and schema-diff takes two
+    match &cli.command {
+        Some(Commands::Catalogue { entry }) => {
+            catalogue::catalogue(entry);
+            return;
+        }
+        Some(Commands::SchemaDiff { first, second }) => {
+            schema::schema_diff(first, second);
+            return;
+        }
+        _ => {}
+    }
+    // FILE and a command are optional only so that --completions,
+    // `catalogue` and `schema-diff` can stand alone
+    let Some(command) = cli.command.as_ref() else {
+        Cli::command()
+            .error(ErrorKind::MissingSubcommand, "a command is required")
+            .exit();
+    };
+    let Some(path) = cli.file.as_deref() else {
+        Cli::command()
+            .error(
+                ErrorKind::MissingRequiredArgument,
+                "the following required arguments were not provided:\n  <FILE>",
+            )
+            .exit();
+    };
+
     // Initialize logger
     if cli.verbose {
         env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug")).init();
@@ -112,27 +694,54 @@ fn main() {
         env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
     }
 
-    // Read the file
-    let data = match std::fs::read(&cli.file) {
-        Ok(data) => data,
-        Err(e) => {
-            eprintln!("Error reading file: {}", e);
-            std::process::exit(1);
-        }
-    };
+    if let Commands::External(args) = command {
+        plugin::run(&cli, path, args);
+    }
 
-    // Parse the S-57 file
-    let file = match S57File::from_bytes(&data) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Error parsing file: {}", e);
-            std::process::exit(1);
-        }
-    };
+    // History reads a directory of files rather than FILE itself
+    if let Commands::History { cell } = command {
+        history::history(path, cell, cli.verbose);
+        return;
+    }
 
-    match &cli.command {
-        Commands::Info => {
-            print_info(&cli.file, data.len(), &file);
+    let updates = cli.apply_updates.then(|| cli.on_conflict.into());
+    let load = || load_cell(path, cli.lossy, updates);
+    let (file, file_size) = load().unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    });
+
+    match command {
+        Commands::Info {
+            format: InfoFormat::Json,
+            fingerprint,
+            ..
+        } => {
+            info::print_info_json(path, file_size, &file, *fingerprint);
+        }
+        Commands::Info {
+            format: InfoFormat::Text,
+            memory,
+            fingerprint,
+            coverage,
+        } => {
+            print_info(path, file_size, &file);
+            match s57_interp::build_world(&file) {
+                Ok(world) => {
+                    print_dataset(&world);
+                    print_record_counts(&world);
+                    if *memory {
+                        print_memory(&world);
+                    }
+                    if *fingerprint {
+                        print_fingerprint(&world);
+                    }
+                    if *coverage {
+                        print_coverage(&world);
+                    }
+                }
+                Err(e) => eprintln!("Warning: Failed to build world: {}", e),
+            }
         }
         Commands::Print {
             format,
@@ -153,22 +762,238 @@ fn main() {
             features::show_object(&file, *rcid);
         }
         Commands::Extent { database } => {
-            features::print_extent(&file, &cli.file, database.as_deref());
+            features::print_extent(&file, path, database.as_deref());
         }
         Commands::Render {
             output,
+            format,
             limit,
             feature,
+            preset,
+            palette,
+            classes,
+            stylesheet,
+            safety_contour,
+            width,
+            height,
+            projection,
+            watch,
+            route,
+            corridor,
+            targets,
+            names,
+            max_path_points,
+            max_elements,
+            approx,
+        } => {
+            let settings = config::RenderSettings::resolve(
+                *preset,
+                *palette,
+                classes.as_deref(),
+                stylesheet.as_deref(),
+                *width,
+                *height,
+            );
+            let preset = settings.preset;
+            let options = render::RenderOptions {
+                limit: *limit,
+                feature_filter: *feature,
+                format: *format,
+                names: names.0,
+                safety_contour: safety_contour.as_deref().map(parse_depth),
+                approx: *approx,
+                ..settings.options
+            };
+            let overlay = route.as_deref().map(|path| match route::load_route(path) {
+                Ok(overlay) => overlay,
+                Err(e) => {
+                    eprintln!("Error loading route: {}", e);
+                    std::process::exit(1);
+                }
+            });
+
+            let render = |file: &S57File| {
+                let mut renderer = svg::SvgRenderer::new()
+                    .with_dimensions(settings.width, settings.height)
+                    .with_projection(*projection)
+                    .with_max_path_points(max_path_points.or(preset.max_path_points()))
+                    .with_max_elements(*max_elements);
+                if let Some(path) = targets {
+                    renderer = renderer
+                        .with_target_source(s57_interp::targets::CsvTargets::new(path.clone()));
+                }
+                let mut world = if *approx {
+                    let parse_options = ParseOptions::new().fixed_point().approx_positions();
+                    render::build_world_with_options(file, &parse_options)
+                } else {
+                    render::build_world(file)
+                };
+                if let Some(overlay) = &overlay {
+                    // Hazard checks need exact positions
+                    world.promote_fixed();
+                    world.overlay = overlay.clone();
+                    let hazards = route::annotate_hazards(&mut world, *corridor);
+                    println!("{} hazard(s) within {} m of the route", hazards, corridor);
+                }
+                render::render_world(&world, output, &options, renderer);
+            };
+            render(&file);
+            if *watch {
+                watch::watch(path, || load().map(|(file, _)| file), render);
+            }
+        }
+        Commands::Extract {
+            bbox,
+            output,
+            format,
+            preset,
+            palette,
             classes,
+            stylesheet,
             width,
             height,
+            projection,
         } => {
-            render::render_to_svg(&file, output, *limit, *feature, classes, *width, *height);
+            let settings = config::RenderSettings::resolve(
+                *preset,
+                *palette,
+                classes.as_deref(),
+                stylesheet.as_deref(),
+                *width,
+                *height,
+            );
+            let renderer = svg::SvgRenderer::new()
+                .with_dimensions(settings.width, settings.height)
+                .with_projection(*projection)
+                .with_max_path_points(settings.preset.max_path_points());
+            region::extract(
+                path,
+                &file,
+                bbox,
+                output,
+                *format,
+                &settings.options,
+                renderer,
+            );
+        }
+        Commands::CheckCoverage => {
+            cell_coverage::check_coverage(path, &file);
         }
+        Commands::Catalog { verify } => {
+            catalog::catalog(path, &file, *verify);
+        }
+        Commands::Export {
+            format,
+            output,
+            precision,
+            names,
+            projection,
+            classes,
+            derived_depth_areas,
+            since,
+            provenance,
+        } => {
+            if *projection != OutputProjection::PlateCarree && *format != ExportFormat::JsonFg {
+                eprintln!("Error: --projection needs --format json-fg (RFC 7946 is always WGS84)");
+                std::process::exit(1);
+            }
+            if *derived_depth_areas && since.is_some() {
+                eprintln!("Error: --derived-depth-areas can't be used with --since (derived areas have no FOID to compare)");
+                std::process::exit(1);
+            }
+            let mut options = s57_interp::geojson::GeoJsonOptions::new()
+                .precision(precision.unwrap_or_default())
+                .names(names.0)
+                .derived_depth_areas(*derived_depth_areas)
+                .provenance(*provenance);
+            if let Some(classes) = classes {
+                options = options.classes(render::parse_class_filter(classes));
+            }
+            export::export(
+                &file,
+                *format,
+                options,
+                *projection,
+                since.as_deref(),
+                output.as_deref(),
+            );
+        }
+        Commands::Grid {
+            output,
+            format,
+            resolution,
+            method,
+            power,
+            radius,
+        } => {
+            let interpolation = match method {
+                GridMethod::Idw => Interpolation::Idw {
+                    power: *power,
+                    radius: *radius,
+                },
+                GridMethod::Tin => Interpolation::Tin,
+            };
+            let mut options = GridOptions::new().interpolation(interpolation);
+            if let Some(resolution) = resolution {
+                options = options.resolution(*resolution);
+            }
+            grid::grid(&file, *format, &options, output);
+        }
+        Commands::Soundings { output, format } => {
+            soundings::soundings(&file, *format, output.as_deref());
+        }
+        Commands::Identify {
+            lat,
+            lon,
+            tolerance,
+        } => {
+            identify::identify(&file, lat, lon, tolerance);
+        }
+        Commands::Query { filter, format } => {
+            query::query(&file, filter, *format);
+        }
+        Commands::Validate { format } => {
+            validate::validate(&file, *format);
+        }
+        Commands::Schema => {
+            schema::print_schema(path, &file);
+        }
+        Commands::Compile { output } => {
+            compile::compile(&file, output);
+        }
+        Commands::History { .. } => unreachable!("handled before reading FILE"),
+        Commands::Catalogue { .. } => unreachable!("handled before reading FILE"),
+        Commands::SchemaDiff { .. } => unreachable!("handled before reading FILE"),
+        Commands::External(_) => unreachable!("handled before reading FILE"),
     }
 }
 
-fn print_info(path: &PathBuf, file_size: usize, file: &S57File) {
+/// Read and parse FILE as the global options ask: skipping malformed
+/// records with --lossy, and with its update files applied by `updates`
+/// (--apply-updates). Returns the cell and FILE's size in bytes, or the
+/// error to report.
+fn load_cell(
+    path: &Path,
+    lossy: bool,
+    updates: Option<s57_parse::update::ConflictPolicy>,
+) -> Result<(S57File, usize), String> {
+    let data = std::fs::read(path).map_err(|e| format!("Error reading file: {}", e))?;
+    let mut file = if lossy {
+        let (file, errors) = S57File::from_bytes_lossy(&data);
+        for e in &errors {
+            eprintln!("Warning: Skipped record: {}", e);
+        }
+        file
+    } else {
+        S57File::from_bytes(&data).map_err(|e| format!("Error parsing file: {}", e))?
+    };
+    if let Some(policy) = updates {
+        apply_updates(path, &mut file, policy)?;
+    }
+    Ok((file, data.len()))
+}
+
+fn print_info(path: &Path, file_size: usize, file: &S57File) {
     let records = file.records();
 
     println!("File: {}", path.display());
@@ -181,7 +1006,18 @@ fn print_info(path: &PathBuf, file_size: usize, file: &S57File) {
             match s57_parse::ddr::DDR::parse(ddr_record) {
                 Ok(ddr) => {
                     println!("\nData Descriptive Record (DDR):");
-                    println!("  Field definitions: {}", ddr.field_defs().len());
+                    let built_in = ddr
+                        .field_defs()
+                        .keys()
+                        .filter(|tag| ddr.is_fallback(tag))
+                        .count();
+                    println!("  Field definitions: {}", ddr.field_defs().len() - built_in);
+                    if built_in > 0 {
+                        println!(
+                            "  Built-in definitions (missing or damaged in the DDR): {}",
+                            built_in
+                        );
+                    }
 
                     // Count different record types in data records
                     let mut record_types = std::collections::HashMap::new();
@@ -211,12 +1047,18 @@ fn print_info(path: &PathBuf, file_size: usize, file: &S57File) {
                     for (tag, def) in ddr.field_defs() {
                         if !tag.starts_with('0') {
                             let repeating = if def.is_repeating { " [repeating]" } else { "" };
+                            let built_in = if ddr.is_fallback(tag) {
+                                " [built-in]"
+                            } else {
+                                ""
+                            };
                             println!(
-                                "  {}: {} ({} subfields{})",
+                                "  {}: {} ({} subfields{}{})",
                                 tag,
                                 def.name,
                                 def.subfield_count(),
-                                repeating
+                                repeating,
+                                built_in
                             );
                         }
                     }
@@ -229,6 +1071,116 @@ fn print_info(path: &PathBuf, file_size: usize, file: &S57File) {
     }
 }
 
+/// DSID identification: edition and update state, dates, usage band and
+/// producing agency
+fn print_dataset(world: &s57_interp::ecs::World) {
+    let Some(dsid) = &world.dataset_id else {
+        return;
+    };
+    let metadata = world.metadata();
+    let number = |value: Option<u32>, raw: &str| match value {
+        Some(n) => n.to_string(),
+        None => format!("{:?}", raw),
+    };
+    println!("\nData set identification (DSID):");
+    println!("  Name: {}", dsid.dsnm);
+    match metadata.kind {
+        Some(kind) => println!("  Kind: {}", kind),
+        None => println!("  Kind: unknown (EXPP {})", dsid.expp),
+    }
+    println!("  Edition: {}", number(metadata.edition, &dsid.edtn));
+    println!("  Update: {}", number(metadata.update, &dsid.updn));
+    println!("  Issue date: {}", dsid.isdt);
+    println!("  Update application date: {}", dsid.uadt);
+    match metadata.purpose {
+        Some(purpose) => println!("  Usage band: {} ({})", dsid.intu, purpose),
+        None => println!("  Usage band: {} (unknown)", dsid.intu),
+    }
+    match (
+        metadata.agency,
+        metadata.agency.and_then(s57_interp::metadata::agency),
+    ) {
+        (Some(agen), Some((producer, name))) => {
+            println!("  Producing agency: {} ({}, {})", name, producer, agen)
+        }
+        (Some(agen), None) => println!("  Producing agency: {}", agen),
+        (None, _) => println!("  Producing agency: not given"),
+    }
+    if !dsid.sted.is_empty() {
+        println!("  S-57 edition: {}", dsid.sted);
+    }
+    if !dsid.comt.is_empty() {
+        println!("  Comment: {}", dsid.comt);
+    }
+}
+
+fn print_record_counts(world: &s57_interp::ecs::World) {
+    if world.dataset_structure.is_none() {
+        return;
+    }
+    if let Some(level) = world.dataset_structure.as_ref().and_then(|d| d.topology()) {
+        println!("\nTopology: {}", level);
+    }
+    let issues = world.validate_structure();
+    if issues.is_empty() {
+        println!("\nDSSI structure: OK");
+    } else {
+        println!("\nDSSI structure:");
+        for issue in issues {
+            println!("  Warning: {}", issue);
+        }
+    }
+}
+
+fn print_memory(world: &s57_interp::ecs::World) {
+    println!("\nWorld memory (estimated):");
+    println!("{}", world.memory_stats());
+}
+
+fn print_fingerprint(world: &s57_interp::ecs::World) {
+    println!("\nFingerprint: {}", world.fingerprint());
+}
+
+fn print_coverage(world: &s57_interp::ecs::World) {
+    println!("\nAttribute coverage:");
+    println!("{}", world.attribute_coverage());
+}
+
+/// Apply the update files found next to a base cell, in order
+fn apply_updates(
+    path: &Path,
+    file: &mut S57File,
+    policy: s57_parse::update::ConflictPolicy,
+) -> Result<(), String> {
+    for update_path in s57_parse::update::update_paths(path) {
+        let update = std::fs::read(&update_path)
+            .map_err(|e| e.to_string())
+            .and_then(|data| S57File::from_bytes(&data).map_err(|e| e.to_string()));
+        let report = update
+            .and_then(|update| {
+                file.apply_update_with(&update, policy)
+                    .map_err(|e| e.to_string())
+            })
+            .map_err(|e| format!("Error applying {}: {}", update_path.display(), e))?;
+        for conflict in &report.conflicts {
+            eprintln!("Warning: {} ({})", conflict, conflict.resolution);
+        }
+        log::info!("Applied {}", update_path.display());
+    }
+    Ok(())
+}
+
+/// Parse a depth in metres given as a decimal, exiting on error
+fn parse_depth(value: &str) -> num_rational::BigRational {
+    match s57_interp::ecs::parse_decimal(value) {
+        Some(depth) => depth,
+        None => {
+            eprintln!("Error: depth must be a decimal number of metres, e.g. 10 or 7.5");
+            std::process::exit(1);
+        }
+    }
+}
+
 fn print_yaml(file: &S57File, record_filter: Option<usize>, limit: Option<usize>) {
     let records = file.records();
 
@@ -255,8 +1207,12 @@ fn print_yaml(file: &S57File, record_filter: Option<usize>, limit: Option<usize>
                 print_yaml_structure_with_ddr(&records_to_print, record_filter, limit, &ddr);
             }
             Err(e) => {
-                eprintln!("Warning: Failed to parse DDR: {}", e);
-                print_yaml_structure(&records_to_print, record_filter, limit);
+                eprintln!(
+                    "Warning: Failed to parse DDR ({}), using built-in S-57 field definitions",
+                    e
+                );
+                let ddr = s57_parse::ddr::DDR::canonical();
+                print_yaml_structure_with_ddr(&records_to_print, record_filter, limit, &ddr);
             }
         }
     } else {
@@ -315,7 +1271,7 @@ fn print_hex(file: &S57File, record_filter: Option<usize>) {
                 let ascii: String = chunk
                     .iter()
                     .map(|&b| {
-                        if b >= 0x20 && b <= 0x7E {
+                        if (0x20..=0x7E).contains(&b) {
                             b as char
                         } else if b == 0x1E {
                             '⊣' // field terminator
@@ -341,6 +1297,8 @@ fn print_yaml_structure_with_ddr(
     limit: Option<usize>,
     ddr: &s57_parse::ddr::DDR,
 ) {
+    use s57_parse::ddr::SubfieldValue;
+    use s57_parse::format;
     use s57_parse::interpret::*;
 
     // Only show field definitions if not filtering to a specific record
@@ -349,12 +1307,18 @@ fn print_yaml_structure_with_ddr(
         for (tag, def) in ddr.field_defs() {
             if !tag.starts_with('0') {
                 let repeating = if def.is_repeating { " [repeating]" } else { "" };
+                let built_in = if ddr.is_fallback(tag) {
+                    " [built-in]"
+                } else {
+                    ""
+                };
                 println!(
-                    "#   {}: {} ({} subfields{})",
+                    "#   {}: {} ({} subfields{}{})",
                     tag,
                     def.name,
                     def.subfield_count(),
-                    repeating
+                    repeating,
+                    built_in
                 );
             }
         }
@@ -365,7 +1329,7 @@ fn print_yaml_structure_with_ddr(
     let records_to_show = if record_filter.is_some() {
         records.len()
     } else {
-        limit.unwrap_or_else(|| records.len())
+        limit.unwrap_or(records.len())
     };
     for (idx, record) in records.iter().enumerate().take(records_to_show) {
         // When filtering, use the actual record number; otherwise use the index
@@ -377,6 +1341,12 @@ fn print_yaml_structure_with_ddr(
         };
 
         println!("  - record_{}:  # {}", i, record_type);
+        if let Some(span) = record.span() {
+            println!(
+                "      offset: {}  # bytes from the start of the file",
+                span.offset
+            );
+        }
         println!("      leader:");
         println!(
             "        record_length: {}  # bytes",
@@ -405,13 +1375,16 @@ fn print_yaml_structure_with_ddr(
             let interpretation = interpret_field_tag(&field.tag);
             println!("        - tag: {}  # {}", field.tag, interpretation);
             println!("          size: {}  # bytes", field.data.len());
+            if let Some(span) = field.span() {
+                println!("          offset: {}", span.offset);
+            }
 
             // Special handling for 0000 field (DDR field control)
             if field.tag == "0000" {
                 if let Some((_controls, title, tag_pairs)) = field.parse_field_control_field() {
                     println!("          data:");
                     if !title.is_empty() {
-                        println!("            external_title: \"{}\"", title);
+                        println!("            external_title: {}", format::quote(&title));
                     }
                     if !tag_pairs.is_empty() {
                         println!("            field_hierarchy:  # Parent-child tag pairs defining tree structure");
@@ -430,14 +1403,17 @@ fn print_yaml_structure_with_ddr(
                 {
                     // DDR: data descriptive field defining record identifier structure
                     println!("          data:");
-                    println!("            field_controls: \"{}\"", controls);
-                    println!("            field_name: \"{}\"", name);
+                    println!("            field_controls: {}", format::quote(&controls));
+                    println!("            field_name: {}", format::quote(&name));
                     if !array_desc.is_empty() {
-                        println!("            array_descriptor: \"{}\"", array_desc);
+                        println!(
+                            "            array_descriptor: {}",
+                            format::quote(&array_desc)
+                        );
                     }
                     println!(
-                        "            format_controls: \"{}\"  # Format for record ID in DRs",
-                        format_controls
+                        "            format_controls: {}  # Format for record ID in DRs",
+                        format::quote(&format_controls)
                     );
                 } else if let Some((seq_num, _reserved)) = parse_field_control(&field.data) {
                     // Data record: contains sequence number
@@ -454,17 +1430,17 @@ fn print_yaml_structure_with_ddr(
                 // Parse it as a data descriptive field (DDF)
                 if let Some(field_def) = ddr.get_field_def(&field.tag) {
                     println!("          definition:  # Data Descriptive Field (DDF)");
-                    println!("            field_name: \"{}\"", field_def.name);
+                    println!("            field_name: {}", format::quote(&field_def.name));
                     if !field_def.array_descriptor.is_empty() {
                         println!(
-                            "            array_descriptor: \"{}\"  # Subfield labels",
-                            field_def.array_descriptor
+                            "            array_descriptor: {}  # Subfield labels",
+                            format::quote(&field_def.array_descriptor)
                         );
                     }
                     if !field_def.format_controls.is_empty() {
                         println!(
-                            "            format_controls: \"{}\"  # Subfield types",
-                            field_def.format_controls
+                            "            format_controls: {}  # Subfield types",
+                            format::quote(&field_def.format_controls)
                         );
                     }
                     println!(
@@ -494,53 +1470,31 @@ fn print_yaml_structure_with_ddr(
                                 } else {
                                     "            "
                                 };
-                                match value {
-                                    s57_parse::ddr::SubfieldValue::Null => {
-                                        println!("{}{}: null", indent, label);
+                                let comment = match (value, *label) {
+                                    (SubfieldValue::Integer(i), "RCNM") => {
+                                        format!("  # {}", interpret_record_name(*i as u8))
                                     }
-                                    s57_parse::ddr::SubfieldValue::Integer(i) => {
-                                        let comment = match label.as_str() {
-                                            "RCNM" => {
-                                                format!("  # {}", interpret_record_name(*i as u8))
-                                            }
-                                            "PRIM" => {
-                                                format!("  # {}", interpret_primitive(*i as u8))
-                                            }
-                                            "OBJL" => {
-                                                format!("  # {}", interpret_object_label(*i as u16))
-                                            }
-                                            "RUIN" => format!(
-                                                "  # {}",
-                                                interpret_update_instruction(*i as u8)
-                                            ),
-                                            "ORNT" => {
-                                                format!("  # {}", interpret_orientation(*i as u8))
-                                            }
-                                            _ => String::new(),
-                                        };
-                                        println!("{}{}: {}{}", indent, label, i, comment);
+                                    (SubfieldValue::Integer(i), "PRIM") => {
+                                        format!("  # {}", interpret_primitive(*i as u8))
                                     }
-                                    s57_parse::ddr::SubfieldValue::UnsignedInteger(u) => {
-                                        // Large unsigned values like FIDN, RCID, etc.
-                                        println!("{}{}: {}", indent, label, u);
+                                    (SubfieldValue::Integer(i), "OBJL") => {
+                                        format!("  # {}", interpret_object_label(*i as u16))
                                     }
-                                    s57_parse::ddr::SubfieldValue::Real(f) => {
-                                        println!("{}{}: {:.6}", indent, label, f);
+                                    (SubfieldValue::Integer(i), "RUIN") => {
+                                        format!("  # {}", interpret_update_instruction(*i as u8))
                                     }
-                                    s57_parse::ddr::SubfieldValue::String(s) => {
-                                        println!("{}{}: \"{}\"", indent, label, s);
+                                    (SubfieldValue::Integer(i), "ORNT") => {
+                                        format!("  # {}", interpret_orientation(*i as u8))
                                     }
-                                    s57_parse::ddr::SubfieldValue::Bytes(b) => {
-                                        let hex: String = b
-                                            .iter()
-                                            .take(8)
-                                            .map(|byte| format!("{:02x}", byte))
-                                            .collect::<Vec<_>>()
-                                            .join(" ");
-                                        let more = if b.len() > 8 { "..." } else { "" };
-                                        println!("{}{}: <hex: {}{}>", indent, label, hex, more);
-                                    }
-                                }
+                                    _ => String::new(),
+                                };
+                                println!(
+                                    "{}{}: {}{}",
+                                    indent,
+                                    label,
+                                    format::yaml_value(value),
+                                    comment
+                                );
                             }
                         }
                     }
@@ -574,7 +1528,7 @@ fn print_yaml_structure(
     let records_to_show = if record_filter.is_some() {
         records.len()
     } else {
-        limit.unwrap_or_else(|| records.len())
+        limit.unwrap_or(records.len())
     };
     for (idx, record) in records.iter().enumerate().take(records_to_show) {
         let i = record_filter.unwrap_or(idx);
@@ -585,6 +1539,12 @@ fn print_yaml_structure(
         };
 
         println!("  - record_{}:  # {}", i, record_type);
+        if let Some(span) = record.span() {
+            println!(
+                "      offset: {}  # bytes from the start of the file",
+                span.offset
+            );
+        }
         println!("      leader:");
         println!(
             "        record_length: {}  # bytes",
@@ -613,6 +1573,9 @@ fn print_yaml_structure(
             let interpretation = interpret_field_tag(&field.tag);
             println!("        - tag: {}  # {}", field.tag, interpretation);
             println!("          size: {}  # bytes", field.data.len());
+            if let Some(span) = field.span() {
+                println!("          offset: {}", span.offset);
+            }
 
             // Try to interpret field data based on tag
             print_field_interpretation(&field.tag, &field.data);
@@ -630,6 +1593,7 @@ fn print_yaml_structure(
 }
 
 fn print_field_interpretation(tag: &str, data: &[u8]) {
+    use s57_parse::format;
     use s57_parse::interpret::*;
 
     match tag {
@@ -644,7 +1608,10 @@ fn print_field_interpretation(tag: &str, data: &[u8]) {
             } else {
                 // DDR: contains field control metadata (text format)
                 if let Ok(text) = std::str::from_utf8(&data[..data.len().saturating_sub(1)]) {
-                    println!("          data: \"{}\"  # ISO 8211 metadata", text.trim());
+                    println!(
+                        "          data: {}  # ISO 8211 metadata",
+                        format::quote(text.trim())
+                    );
                 } else {
                     println!("          data: <binary>  # {} bytes", data.len());
                 }
@@ -844,7 +1811,7 @@ fn print_field_interpretation(tag: &str, data: &[u8]) {
         }
         _ => {
             // For other fields, show hex preview
-            if data.len() > 0 {
+            if !data.is_empty() {
                 let preview: String = data
                     .iter()
                     .take(16)
@@ -0,0 +1,81 @@
+//! External subcommands
+//!
+//! `s57 FILE NAME [ARGS...]` with a NAME that isn't a built-in command runs
+//! the executable `s57-NAME` found on PATH, the same way git and cargo find
+//! theirs. The plugin is called as `s57-NAME FILE [ARGS...]`, and the global
+//! options reach it through the environment:
+//!
+//! - `S57_FILE`: the chart path, as also passed first
+//! - `S57_VERBOSE`: "1" with `--verbose`
+//! - `S57_APPLY_UPDATES`: "1" with `--apply-updates`
+//! - `S57_ON_CONFLICT`: the `--on-conflict` value ("fail", "skip", "force")
+//!
+//! A plugin written in Rust builds its World with `s57_interp` exactly as the
+//! built-in commands do. Its exit code becomes ours.
+
+use crate::Cli;
+use clap::ValueEnum;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Prefix of plugin executable names
+const PREFIX: &str = "s57-";
+
+/// Run the plugin for `args` (its name, then its arguments) and exit with
+/// its status
+pub fn run(cli: &Cli, file: &Path, args: &[String]) -> ! {
+    let (name, rest) = args
+        .split_first()
+        .expect("clap passes the external command name");
+    let Some(executable) = find(name) else {
+        eprintln!(
+            "Error: unknown command '{}' (no {}{} on PATH)",
+            name, PREFIX, name
+        );
+        std::process::exit(1);
+    };
+    let flag = |set: bool| if set { "1" } else { "0" };
+    let on_conflict = cli
+        .on_conflict
+        .to_possible_value()
+        .map(|value| value.get_name().to_string())
+        .unwrap_or_default();
+
+    let status = Command::new(&executable)
+        .arg(file)
+        .args(rest)
+        .env("S57_FILE", file)
+        .env("S57_VERBOSE", flag(cli.verbose))
+        .env("S57_APPLY_UPDATES", flag(cli.apply_updates))
+        .env("S57_ON_CONFLICT", on_conflict)
+        .status();
+    match status {
+        // None when killed by a signal
+        Ok(status) => std::process::exit(status.code().unwrap_or(1)),
+        Err(e) => {
+            eprintln!("Error running {}: {}", executable.display(), e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// First `s57-NAME` executable on PATH
+fn find(name: &str) -> Option<PathBuf> {
+    let file_name = format!("{}{}{}", PREFIX, name, std::env::consts::EXE_SUFFIX);
+    let path = std::env::var_os("PATH")?;
+    std::env::split_paths(&path)
+        .map(|dir| dir.join(&file_name))
+        .find(|candidate| is_executable(candidate))
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    path.metadata()
+        .is_ok_and(|meta| meta.is_file() && meta.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file()
+}
@@ -0,0 +1,375 @@
+//! PNG canvas for chart renders
+//!
+//! Draws the same elements as the SVG canvas into a tiny-skia pixmap and
+//! encodes it when the stream finishes, so the whole image is held in
+//! memory (4 bytes per pixel). There is no text: point titles and target
+//! labels are left out.
+
+use crate::svg::Canvas;
+use s57_interp::targets::Target;
+use std::collections::HashMap;
+use std::io::Write;
+use tiny_skia::{
+    Color, FillRule, FilterQuality, Paint, PathBuilder, Pattern, Pixmap, Rect, SpreadMode, Stroke,
+    StrokeDash, Transform,
+};
+
+/// Target fill and outline, as in the SVG
+const TARGET_FILL: &str = "#00a000";
+const TARGET_STROKE: &str = "#004000";
+
+/// Raster image encoded to `W` as PNG when finished
+pub struct PngCanvas<W: Write> {
+    writer: W,
+    /// Allocated by `begin`
+    pixmap: Option<Pixmap>,
+    /// Hatch pattern tiles by id
+    patterns: HashMap<String, (Pixmap, f64)>,
+}
+
+impl<W: Write> PngCanvas<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            pixmap: None,
+            patterns: HashMap::new(),
+        }
+    }
+
+    /// Fill a path with a colour or a hatch pattern
+    fn fill(&mut self, path: &tiny_skia::Path, fill: &str, rule: FillRule) {
+        let Some(pixmap) = self.pixmap.as_mut() else {
+            return;
+        };
+        let mut paint = Paint {
+            anti_alias: true,
+            ..Paint::default()
+        };
+        if let Some(id) = pattern_id(fill) {
+            let Some((tile, angle)) = self.patterns.get(id) else {
+                return;
+            };
+            paint.shader = Pattern::new(
+                tile.as_ref(),
+                SpreadMode::Repeat,
+                FilterQuality::Nearest,
+                1.0,
+                Transform::from_rotate(*angle as f32),
+            );
+        } else if let Some(colour) = parse_colour(fill) {
+            paint.set_color(colour);
+        } else {
+            return;
+        }
+        pixmap.fill_path(path, &paint, rule, Transform::identity(), None);
+    }
+
+    /// Outline a path; a zero width draws nothing, unlike tiny-skia's
+    /// hairlines
+    fn stroke(&mut self, path: &tiny_skia::Path, colour: &str, width: f64, dash: Option<&str>) {
+        let (Some(pixmap), Some(colour)) = (self.pixmap.as_mut(), parse_colour(colour)) else {
+            return;
+        };
+        if width <= 0.0 {
+            return;
+        }
+        let mut paint = Paint {
+            anti_alias: true,
+            ..Paint::default()
+        };
+        paint.set_color(colour);
+        let stroke = Stroke {
+            width: width as f32,
+            dash: dash.and_then(parse_dash),
+            ..Stroke::default()
+        };
+        pixmap.stroke_path(path, &paint, &stroke, Transform::identity(), None);
+    }
+}
+
+impl<W: Write> Canvas for PngCanvas<W> {
+    type Output = W;
+
+    fn begin(&mut self, width: u32, height: u32, background: Option<&str>) -> std::io::Result<()> {
+        let mut pixmap = Pixmap::new(width, height).ok_or_else(|| {
+            std::io::Error::other(format!("can't allocate a {}x{} image", width, height))
+        })?;
+        // With nothing to draw the image is left plain white
+        pixmap.fill(background.and_then(parse_colour).unwrap_or(Color::WHITE));
+        self.pixmap = Some(pixmap);
+        Ok(())
+    }
+
+    fn polyline(
+        &mut self,
+        points: &[(f64, f64)],
+        stroke: &str,
+        stroke_width: f64,
+        dash: Option<&str>,
+        _id: Option<&str>,
+    ) -> std::io::Result<()> {
+        if let Some(path) = path(std::iter::once(points), false) {
+            self.stroke(&path, stroke, stroke_width, dash);
+        }
+        Ok(())
+    }
+
+    fn polygon(
+        &mut self,
+        points: &[(f64, f64)],
+        fill: &str,
+        stroke: &str,
+        stroke_width: f64,
+        _id: Option<&str>,
+    ) -> std::io::Result<()> {
+        if let Some(path) = path(std::iter::once(points), true) {
+            self.fill(&path, fill, FillRule::Winding);
+            self.stroke(&path, stroke, stroke_width, None);
+        }
+        Ok(())
+    }
+
+    fn polygon_with_holes(
+        &mut self,
+        rings: &[Vec<(f64, f64)>],
+        fill: &str,
+        stroke: &str,
+        stroke_width: f64,
+        _id: Option<&str>,
+    ) -> std::io::Result<()> {
+        if let Some(path) = path(rings.iter().map(Vec::as_slice), true) {
+            self.fill(&path, fill, FillRule::EvenOdd);
+            self.stroke(&path, stroke, stroke_width, None);
+        }
+        Ok(())
+    }
+
+    fn hatch_pattern(
+        &mut self,
+        id: &str,
+        spacing: f64,
+        angle: f64,
+        colour: &str,
+        crossed: bool,
+    ) -> std::io::Result<()> {
+        let size = spacing.round().max(1.0) as u32;
+        let (Some(mut tile), Some(colour)) = (Pixmap::new(size, size), parse_colour(colour)) else {
+            return Ok(());
+        };
+        let mut paint = Paint::default();
+        paint.set_color(colour);
+        // One-pixel lines along the tile's left edge, and top edge if crossed
+        let mut lines = vec![Rect::from_xywh(0.0, 0.0, 1.0, size as f32)];
+        if crossed {
+            lines.push(Rect::from_xywh(0.0, 0.0, size as f32, 1.0));
+        }
+        for rect in lines.into_iter().flatten() {
+            tile.fill_rect(rect, &paint, Transform::identity(), None);
+        }
+        self.patterns.insert(id.to_string(), (tile, angle));
+        Ok(())
+    }
+
+    fn point(
+        &mut self,
+        x: f64,
+        y: f64,
+        radius: f64,
+        fill: &str,
+        _title: Option<&str>,
+        _id: Option<&str>,
+    ) -> std::io::Result<()> {
+        if let Some(circle) = PathBuilder::from_circle(x as f32, y as f32, radius as f32) {
+            self.fill(&circle, fill, FillRule::Winding);
+        }
+        Ok(())
+    }
+
+    fn target(&mut self, x: f64, y: f64, target: &Target) -> std::io::Result<()> {
+        let symbol = match target.heading {
+            Some(heading) => path(
+                std::iter::once(&[(0.0, -9.0), (5.0, 6.0), (-5.0, 6.0)][..]),
+                true,
+            )
+            .and_then(|triangle| {
+                triangle.transform(
+                    Transform::from_rotate(heading as f32).post_translate(x as f32, y as f32),
+                )
+            }),
+            None => PathBuilder::from_circle(x as f32, y as f32, 4.0),
+        };
+        if let Some(symbol) = symbol {
+            self.fill(&symbol, TARGET_FILL, FillRule::Winding);
+            if target.heading.is_some() {
+                self.stroke(&symbol, TARGET_STROKE, 1.0, None);
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(mut self) -> std::io::Result<W> {
+        if let Some(pixmap) = &self.pixmap {
+            let data = pixmap.encode_png().map_err(std::io::Error::other)?;
+            self.writer.write_all(&data)?;
+        }
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+/// Path through rings of pixel points, closing each if `closed`; None if
+/// nothing is left to draw
+fn path<'a>(
+    rings: impl IntoIterator<Item = &'a [(f64, f64)]>,
+    closed: bool,
+) -> Option<tiny_skia::Path> {
+    let mut builder = PathBuilder::new();
+    for ring in rings {
+        let Some(((x, y), rest)) = ring.split_first() else {
+            continue;
+        };
+        builder.move_to(*x as f32, *y as f32);
+        for (x, y) in rest {
+            builder.line_to(*x as f32, *y as f32);
+        }
+        if closed {
+            builder.close();
+        }
+    }
+    builder.finish()
+}
+
+/// `#rrggbb` or `#rrggbbaa`; None for `none` or anything else
+fn parse_colour(colour: &str) -> Option<Color> {
+    let hex = colour.strip_prefix('#')?;
+    if !matches!(hex.len(), 6 | 8) || !hex.is_ascii() {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    let alpha = if hex.len() == 8 { channel(6)? } else { 255 };
+    Some(Color::from_rgba8(
+        channel(0)?,
+        channel(2)?,
+        channel(4)?,
+        alpha,
+    ))
+}
+
+/// `url(#id)` fill
+fn pattern_id(fill: &str) -> Option<&str> {
+    fill.strip_prefix("url(#")?.strip_suffix(')')
+}
+
+/// SVG stroke-dasharray ("6,3"); an odd count repeats, as in SVG
+fn parse_dash(dash: &str) -> Option<StrokeDash> {
+    let mut intervals: Vec<f32> = dash
+        .split([',', ' '])
+        .filter(|s| !s.is_empty())
+        .map(|s| s.trim().parse().ok())
+        .collect::<Option<_>>()?;
+    if intervals.len() % 2 == 1 {
+        intervals.extend_from_within(..);
+    }
+    StrokeDash::new(intervals, 0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Pixel at (x, y) as [r, g, b, a]
+    fn rgba(pixmap: &Pixmap, x: u32, y: u32) -> [u8; 4] {
+        let pixel = pixmap.pixel(x, y).unwrap().demultiply();
+        [pixel.red(), pixel.green(), pixel.blue(), pixel.alpha()]
+    }
+
+    /// 20x10 image: a red square, a blue frame with a hole holding a black
+    /// dot, and a green line along the bottom
+    fn scene() -> Vec<u8> {
+        let mut canvas = PngCanvas::new(Vec::new());
+        canvas.begin(20, 10, Some("#ffff00")).unwrap();
+        canvas
+            .polygon(
+                &[(2.0, 2.0), (8.0, 2.0), (8.0, 7.0), (2.0, 7.0)],
+                "#ff0000",
+                "none",
+                0.0,
+                None,
+            )
+            .unwrap();
+        canvas
+            .polygon_with_holes(
+                &[
+                    vec![(10.0, 0.0), (20.0, 0.0), (20.0, 10.0), (10.0, 10.0)],
+                    vec![(12.0, 2.0), (18.0, 2.0), (18.0, 8.0), (12.0, 8.0)],
+                ],
+                "#0000ff",
+                "none",
+                0.0,
+                None,
+            )
+            .unwrap();
+        canvas
+            .point(15.0, 5.0, 1.5, "#000000", Some("no text"), None)
+            .unwrap();
+        canvas
+            .polyline(&[(0.0, 9.0), (10.0, 9.0)], "#00ff00", 2.0, None, None)
+            .unwrap();
+        canvas.finish().unwrap()
+    }
+
+    #[test]
+    fn test_scene_pixels() {
+        let pixmap = Pixmap::decode_png(&scene()).unwrap();
+        assert_eq!((pixmap.width(), pixmap.height()), (20, 10));
+
+        // Background around the square
+        assert_eq!(rgba(&pixmap, 0, 0), [255, 255, 0, 255]);
+        assert_eq!(rgba(&pixmap, 9, 4), [255, 255, 0, 255]);
+        // Square, edge to edge on whole pixels
+        assert_eq!(rgba(&pixmap, 2, 2), [255, 0, 0, 255]);
+        assert_eq!(rgba(&pixmap, 7, 6), [255, 0, 0, 255]);
+        assert_eq!(rgba(&pixmap, 1, 2), [255, 255, 0, 255]);
+        assert_eq!(rgba(&pixmap, 8, 6), [255, 255, 0, 255]);
+        // Frame filled even-odd, the hole showing the background
+        assert_eq!(rgba(&pixmap, 10, 0), [0, 0, 255, 255]);
+        assert_eq!(rgba(&pixmap, 19, 9), [0, 0, 255, 255]);
+        assert_eq!(rgba(&pixmap, 12, 2), [255, 255, 0, 255]);
+        assert_eq!(rgba(&pixmap, 17, 7), [255, 255, 0, 255]);
+        // Dot in the hole
+        assert_eq!(rgba(&pixmap, 14, 4), [0, 0, 0, 255]);
+        assert_eq!(rgba(&pixmap, 15, 5), [0, 0, 0, 255]);
+        // Line two pixels wide, centred on y = 9
+        assert_eq!(rgba(&pixmap, 5, 8), [0, 255, 0, 255]);
+        assert_eq!(rgba(&pixmap, 5, 9), [0, 255, 0, 255]);
+        assert_eq!(rgba(&pixmap, 5, 7), [255, 255, 0, 255]);
+    }
+
+    #[test]
+    fn test_png_round_trip() {
+        let bytes = scene();
+        assert!(bytes.starts_with(b"\x89PNG\r\n\x1a\n"));
+        let pixmap = Pixmap::decode_png(&bytes).unwrap();
+        assert_eq!(pixmap.encode_png().unwrap(), bytes);
+
+        // Colours the canvas can't parse, and zero-width strokes, draw nothing
+        let mut canvas = PngCanvas::new(Vec::new());
+        canvas.begin(4, 4, Some("none")).unwrap();
+        canvas
+            .polygon(
+                &[(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)],
+                "red",
+                "#ff0000",
+                0.0,
+                None,
+            )
+            .unwrap();
+        let blank = Pixmap::decode_png(&canvas.finish().unwrap()).unwrap();
+        assert!(blank
+            .pixels()
+            .iter()
+            .all(|p| p.demultiply() == Color::WHITE.to_color_u8()));
+
+        assert!(PngCanvas::new(Vec::new()).begin(0, 10, None).is_err());
+    }
+}
@@ -0,0 +1,198 @@
+//! Named render presets: class lists, palettes and scale settings
+
+use crate::s52::ColourScheme;
+use clap::ValueEnum;
+
+/// Preset: A bundle of render settings for a kind of chart use
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Preset {
+    /// Small-scale overview: coastline, land, depth areas, routeing and lights
+    Overview,
+    /// General harbour and approach chart with aids to navigation
+    Harbor,
+    /// Depth areas shaded by depth, contours and soundings
+    Bathymetry,
+    /// Wrecks, rocks, obstructions and restricted areas over a muted base
+    Dangers,
+}
+
+impl Preset {
+    /// Object classes drawn when --classes is not given
+    pub fn classes(self) -> &'static [&'static str] {
+        match self {
+            Preset::Overview => &[
+                "COALNE", "LNDARE", "DEPARE", "SEAARE", "LIGHTS", "TSSLPT", "TSEZNE", "FAIRWY",
+                "RESARE", "ACHARE", "HRBARE",
+            ],
+            Preset::Harbor => &[
+                "COALNE", "LNDARE", "DEPARE", "DEPCNT", "SEAARE", "BRIDGE", "BUISGL", "LNDMRK",
+                "LIGHTS", "BCNCAR", "BCNLAT", "BCNISD", "BCNSAW", "BOYCAR", "BOYLAT", "BOYISD",
+                "BOYSAW", "ACHARE", "WRECKS", "OBSTRN", "RIVERS", "LAKARE", "CANALS", "DAMCON",
+                "BERTHS", "HRBARE", "RESARE", "FAIRWY", "PILBOP", "OSPARE",
+            ],
+            Preset::Bathymetry => &[
+                "COALNE", "LNDARE", "DEPARE", "DRGARE", "DEPCNT", "SOUNDG", "UWTROC", "WRECKS",
+                "OBSTRN",
+            ],
+            Preset::Dangers => &[
+                "COALNE", "LNDARE", "DEPARE", "UWTROC", "WRECKS", "OBSTRN", "CTNARE", "MIPARE",
+                "RESARE", "PRCARE",
+            ],
+        }
+    }
+
+    /// Colours used for areas, lines and points
+    pub fn palette(self) -> Palette {
+        match self {
+            Preset::Overview | Preset::Harbor => Palette::Standard,
+            Preset::Bathymetry => Palette::Bathymetry,
+            Preset::Dangers => Palette::Dangers,
+        }
+    }
+
+    /// Radius of point markers, in pixels
+    pub fn point_radius(self) -> f64 {
+        match self {
+            Preset::Overview => 1.5,
+            Preset::Harbor | Preset::Bathymetry => 2.0,
+            Preset::Dangers => 3.5,
+        }
+    }
+
+    /// Path simplification used when --max-path-points is not given
+    ///
+    /// Overviews cover a lot of coastline at small scale, where full detail
+    /// only makes the file bigger.
+    pub fn max_path_points(self) -> Option<usize> {
+        match self {
+            Preset::Overview => Some(500),
+            _ => None,
+        }
+    }
+}
+
+/// Palette: How features are coloured
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Palette {
+    /// Light blue water, green land, coloured outlines for zones
+    Standard,
+    /// Depth areas shaded from dark (shallow) to white (deep)
+    Bathymetry,
+    /// Everything grey except dangers and the areas around them
+    Dangers,
+    /// S-52 day colours, styled from the Presentation Library look-up tables
+    S52Day,
+    /// S-52 dusk colours
+    S52Dusk,
+    /// S-52 night colours
+    S52Night,
+}
+
+impl Palette {
+    /// The S-52 colour scheme, for palettes styled by the `s52` module
+    pub fn s52_scheme(self) -> Option<ColourScheme> {
+        match self {
+            Palette::S52Day => Some(ColourScheme::Day),
+            Palette::S52Dusk => Some(ColourScheme::Dusk),
+            Palette::S52Night => Some(ColourScheme::Night),
+            _ => None,
+        }
+    }
+
+    /// Fill, stroke and stroke width for an area of class `objl`
+    ///
+    /// `drval1` is the area's shoalest depth, used to shade depth areas.
+    pub fn area(self, objl: u16, drval1: Option<f64>) -> (&'static str, &'static str, f64) {
+        match self {
+            // S-52 palettes are drawn by `s52` and never get here
+            Palette::Standard | Palette::S52Day | Palette::S52Dusk | Palette::S52Night => {
+                standard_area(objl)
+            }
+            Palette::Bathymetry => match objl {
+                // DEPARE, DRGARE
+                42 | 46 | 17003 => (depth_shade(drval1), "#4682b4", 0.3),
+                _ => standard_area(objl),
+            },
+            Palette::Dangers => match objl {
+                // CTNARE, MIPARE, PRCARE, RESARE
+                27 | 83 | 96 | 112 | 17005 => ("none", "#dc143c", 2.0),
+                // LNDARE
+                71 => ("#d9d9d9", "#808080", 0.5),
+                _ => ("#f4f4f4", "#c0c0c0", 0.3),
+            },
+        }
+    }
+
+    /// Stroke colour for line features
+    pub fn line(self) -> &'static str {
+        match self {
+            Palette::Standard | Palette::S52Day | Palette::S52Dusk | Palette::S52Night => "#0000ff",
+            Palette::Bathymetry => "#5f7f9f",
+            Palette::Dangers => "#a0a0a0",
+        }
+    }
+
+    /// Fill colour for point features
+    pub fn point(self) -> &'static str {
+        match self {
+            Palette::Standard
+            | Palette::Dangers
+            | Palette::S52Day
+            | Palette::S52Dusk
+            | Palette::S52Night => "#ff0000",
+            Palette::Bathymetry => "#303030",
+        }
+    }
+}
+
+/// Shade for a depth area by its shoalest depth (metres)
+fn depth_shade(drval1: Option<f64>) -> &'static str {
+    match drval1 {
+        None => "#c6e2f5",
+        Some(d) if d < 0.0 => "#b5d29c", // drying
+        Some(d) if d < 2.0 => "#73b6e6",
+        Some(d) if d < 5.0 => "#9ccbee",
+        Some(d) if d < 10.0 => "#c6e2f5",
+        Some(d) if d < 20.0 => "#e2f0fa",
+        Some(_) => "#ffffff",
+    }
+}
+
+/// The standard area colours, by object class code
+fn standard_area(objl: u16) -> (&'static str, &'static str, f64) {
+    match objl {
+        // Water areas - light blue fill
+        42 | 17003 => ("#87ceeb", "#4682b4", 0.5), // DEPARE - Depth area
+        119 => ("#87ceeb", "#4682b4", 0.5),        // SEAARE - Sea area / named water area
+
+        // Land areas - green
+        71 => ("#90ee90", "#228b22", 0.5), // LNDARE - Land area
+
+        // Zone-type features - transparent with colored outline
+        1 => ("none", "#ff6b6b", 1.5), // ADMARE - Administration area
+        4 | 17001 => ("none", "#9370db", 1.5), // ACHARE - Anchorage area
+        27 => ("none", "#ffa500", 2.0), // CTNARE - Caution area
+        31 => ("none", "#4169e1", 1.5), // CONZNE - Contiguous zone
+        32 => ("none", "#4682b4", 1.5), // COSARE - Continental shelf area
+        37 => ("none", "#daa520", 1.5), // CUSZNE - Custom zone
+        40 => ("none", "#ff1493", 2.0), // DWRTCL - Deep water route centerline
+        41 => ("none", "#ff69b4", 1.5), // DWRTPT - Deep water route part
+        50 => ("none", "#1e90ff", 1.5), // EXEZNE - Exclusive Economic Zone
+        51 => ("none", "#00ced1", 2.0), // FAIRWY - Fairway
+        54 => ("none", "#20b2aa", 1.5), // FSHZNE - Fishery zone
+        63 | 17014 => ("none", "#6a5acd", 1.5), // HRBARE - Harbour area
+        68 => ("none", "#48d1cc", 1.5), // ISTZNE - Inshore traffic zone
+        83 => ("none", "#dc143c", 2.0), // MIPARE - Military practice area
+        88 => ("none", "#ff8c00", 1.5), // OSPARE - Offshore production area
+        96 => ("none", "#ffa500", 2.0), // PRCARE - Precautionary area
+        97 => ("none", "#ff8c00", 1.5), // PRDARE - Production/storage area
+        108 => ("none", "#ff1493", 2.0), // RCRTCL - Recommended route centerline
+        112 | 17005 => ("none", "#ff0000", 2.0), // RESARE - Restricted area
+        135 => ("none", "#4682b4", 1.5), // TESARE - Territorial sea area
+        150 => ("none", "#ff00ff", 2.0), // TSEZNE - Traffic Separation Zone
+        152 => ("none", "#ff69b4", 1.5), // TWRTPT - Two-way route part
+
+        // Default - light green with darker outline
+        _ => ("#90ee90", "#228b22", 0.5),
+    }
+}
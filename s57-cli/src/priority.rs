@@ -0,0 +1,45 @@
+//! S-52-style display priority of chart features
+//!
+//! Every feature gets a priority from 1 (opaque base areas) to 8 (aids to
+//! navigation); mariner's data would be 9 and is drawn separately. When
+//! output has to be bounded the lowest priorities are dropped first, and
+//! within a draw-order layer (see `stylesheet`) higher priorities are drawn
+//! on top.
+//!
+//! The table follows the S-52 priority groups rather than the exact values
+//! of the Presentation Library look-up tables.
+
+use s57_interp::ecs::{EntityId, World};
+
+/// Display priority of a feature of class `objl` with primitive `prim`
+/// (1 = point, 2 = line, 3 = area)
+pub fn display_priority(objl: u16, prim: u8) -> u8 {
+    match objl {
+        // DEPARE, DRGARE, FLODOC, HULKES, LNDARE, PONTON, UNSARE
+        42 | 46 | 57 | 65 | 71 | 95 | 154 if prim == 3 => 1,
+        // CTNARE, MIPARE, PRCARE, RESARE
+        27 | 83 | 96 | 112 => 3,
+        // Routeing: DWRTCL, DWRTPT, ISTZNE, NAVLNE, RCRTCL, RECTRC, RCTLPT,
+        // TSELNE, TSSBND, TSSCRS, TSSLPT, TSSRON, TSEZNE, TWRTPT
+        40 | 41 | 68 | 85 | 108 | 109 | 110 | 145..=150 | 152 => 5,
+        // Dangers: OBSTRN, UWTROC, WRECKS
+        86 | 153 | 159 => 7,
+        // Aids to navigation: beacons, buoys, DAYMAR, FOGSIG, LIGHTS,
+        // LITFLT, LITVES, RTPBCN, TOPMAR
+        5..=9 | 14..=19 | 39 | 58 | 75..=77 | 103 | 144 => 8,
+        _ => match prim {
+            3 => 2,
+            2 => 4,
+            _ => 6,
+        },
+    }
+}
+
+/// Display priority of a feature entity (0 if it has no metadata)
+pub fn feature_priority(world: &World, entity: EntityId) -> u8 {
+    world
+        .feature_meta
+        .get(&entity)
+        .map(|meta| display_priority(meta.objl, meta.prim))
+        .unwrap_or(0)
+}
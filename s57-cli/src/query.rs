@@ -0,0 +1,576 @@
+//! Feature query with attribute filter expressions
+//!
+//! `--where` takes a small expression language:
+//!
+//! ```text
+//! OBJL=LIGHTS && VALNMR>10
+//! OBJL=BOY* && (COLOUR=3 || !CATLAM)
+//! PRIM=area && OBJNAM="Long Island Sound"
+//! ```
+//!
+//! - `OBJL=X` / `OBJL!=X` match the object class; X is anything `--classes`
+//!   accepts (acronym, OBJL code, wildcard or group)
+//! - `PRIM=X` / `PRIM!=X` match the primitive: point, line, area or 1-3
+//! - `ATTR OP VALUE` compares an attribute (acronym or ATTL code, from ATTF
+//!   or NATF) with `=`, `!=`, `<`, `<=`, `>` or `>=`. Values that are both
+//!   numbers compare exactly as numbers; otherwise only `=` and `!=` apply,
+//!   and `=` also matches one item of a list value (COLOUR "1,3" = 3)
+//! - a bare `ATTR` is true if the feature carries the attribute
+//! - `!`, `&&`, `||` and parentheses combine them, tightest first
+//!
+//! Names are case-insensitive. Values containing spaces or operators go in
+//! double quotes. A comparison with an attribute the feature doesn't carry
+//! is false, whatever the operator; test for absence with `!ATTR`.
+
+use crate::selector::ClassSelector;
+use crate::QueryFormat;
+use s57_catalogue::{AttributeInfo, ObjectClass};
+use s57_interp::ecs::{parse_decimal, EntityId, EntityType, NamePreference, World};
+use s57_parse::S57File;
+use serde_json::json;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+/// Comparison operator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl Op {
+    fn holds(self, ordering: Ordering) -> bool {
+        match self {
+            Op::Eq => ordering == Ordering::Equal,
+            Op::Ne => ordering != Ordering::Equal,
+            Op::Lt => ordering == Ordering::Less,
+            Op::Le => ordering != Ordering::Greater,
+            Op::Gt => ordering == Ordering::Greater,
+            Op::Ge => ordering != Ordering::Less,
+        }
+    }
+}
+
+/// Parsed filter expression
+#[derive(Debug, Clone)]
+enum Expr {
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Not(Box<Expr>),
+    /// OBJL in a set of classes
+    Class(HashSet<u16>),
+    /// PRIM equal to a value
+    Prim(u8),
+    /// Attribute present
+    Present(u16),
+    /// Attribute compared with a value
+    Compare {
+        attl: u16,
+        op: Op,
+        value: String,
+    },
+}
+
+impl Expr {
+    /// Parse a whole expression
+    fn parse(text: &str) -> Result<Self, String> {
+        let tokens = tokenize(text)?;
+        let mut parser = Parser { tokens, pos: 0 };
+        let expr = parser.or()?;
+        match parser.tokens.get(parser.pos) {
+            None => Ok(expr),
+            Some(token) => Err(format!("unexpected {}", token)),
+        }
+    }
+
+    /// True if a feature matches
+    fn matches(&self, world: &World, feature: EntityId) -> bool {
+        match self {
+            Expr::And(a, b) => a.matches(world, feature) && b.matches(world, feature),
+            Expr::Or(a, b) => a.matches(world, feature) || b.matches(world, feature),
+            Expr::Not(a) => !a.matches(world, feature),
+            Expr::Class(codes) => world
+                .feature_meta
+                .get(&feature)
+                .is_some_and(|meta| codes.contains(&meta.objl)),
+            Expr::Prim(prim) => world
+                .feature_meta
+                .get(&feature)
+                .is_some_and(|meta| meta.prim == *prim),
+            Expr::Present(attl) => attribute(world, feature, *attl).is_some(),
+            Expr::Compare { attl, op, value } => {
+                attribute(world, feature, *attl).is_some_and(|actual| compare(actual, *op, value))
+            }
+        }
+    }
+}
+
+/// Value of an attribute from ATTF, or else NATF
+fn attribute(world: &World, feature: EntityId, attl: u16) -> Option<&str> {
+    let attrs = world.feature_attributes.get(&feature)?;
+    attrs.get(attl).or_else(|| attrs.get_national(attl))
+}
+
+/// Compare an attribute value with a filter value
+fn compare(actual: &str, op: Op, expected: &str) -> bool {
+    if let (Some(a), Some(e)) = (parse_decimal(actual), parse_decimal(expected)) {
+        return op.holds(a.cmp(&e));
+    }
+    let equal = actual.trim() == expected || actual.split(',').any(|item| item.trim() == expected);
+    match op {
+        Op::Eq => equal,
+        Op::Ne => !equal,
+        _ => false,
+    }
+}
+
+/// Token of the filter language
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    /// Name or unquoted value
+    Word(String),
+    /// Double-quoted value
+    Quoted(String),
+    Op(Op),
+    And,
+    Or,
+    Not,
+    Open,
+    Close,
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Word(word) => write!(f, "'{}'", word),
+            Token::Quoted(text) => write!(f, "\"{}\"", text),
+            Token::Op(op) => write!(f, "operator {:?}", op),
+            Token::And => f.write_str("'&&'"),
+            Token::Or => f.write_str("'||'"),
+            Token::Not => f.write_str("'!'"),
+            Token::Open => f.write_str("'('"),
+            Token::Close => f.write_str("')'"),
+        }
+    }
+}
+
+fn tokenize(text: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '(' => Token::Open,
+            ')' => Token::Close,
+            '&' if chars.next_if_eq(&'&').is_some() => Token::And,
+            '|' if chars.next_if_eq(&'|').is_some() => Token::Or,
+            '!' if chars.next_if_eq(&'=').is_some() => Token::Op(Op::Ne),
+            '!' => Token::Not,
+            '=' => {
+                chars.next_if_eq(&'=');
+                Token::Op(Op::Eq)
+            }
+            '<' if chars.next_if_eq(&'=').is_some() => Token::Op(Op::Le),
+            '<' => Token::Op(Op::Lt),
+            '>' if chars.next_if_eq(&'=').is_some() => Token::Op(Op::Ge),
+            '>' => Token::Op(Op::Gt),
+            '"' => {
+                let mut value = String::new();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some(c) => value.push(c),
+                        None => return Err("unterminated string".to_string()),
+                    }
+                }
+                Token::Quoted(value)
+            }
+            c if is_word_char(c) => {
+                let mut word = c.to_string();
+                while let Some(c) = chars.next_if(|&c| is_word_char(c)) {
+                    word.push(c);
+                }
+                Token::Word(word)
+            }
+            c => return Err(format!("unexpected character '{}'", c)),
+        };
+        tokens.push(token);
+    }
+    Ok(tokens)
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '-' | '+' | '.' | '*' | '?' | ',')
+}
+
+/// Recursive-descent parser over the tokens
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn eat(&mut self, token: &Token) -> bool {
+        let found = self.tokens.get(self.pos) == Some(token);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn or(&mut self) -> Result<Expr, String> {
+        let mut expr = self.and()?;
+        while self.eat(&Token::Or) {
+            expr = Expr::Or(Box::new(expr), Box::new(self.and()?));
+        }
+        Ok(expr)
+    }
+
+    fn and(&mut self) -> Result<Expr, String> {
+        let mut expr = self.unary()?;
+        while self.eat(&Token::And) {
+            expr = Expr::And(Box::new(expr), Box::new(self.unary()?));
+        }
+        Ok(expr)
+    }
+
+    fn unary(&mut self) -> Result<Expr, String> {
+        match self.next() {
+            Some(Token::Not) => Ok(Expr::Not(Box::new(self.unary()?))),
+            Some(Token::Open) => {
+                let expr = self.or()?;
+                if !self.eat(&Token::Close) {
+                    return Err("missing ')'".to_string());
+                }
+                Ok(expr)
+            }
+            Some(Token::Word(name)) => self.condition(&name),
+            Some(token) => Err(format!("expected a condition, found {}", token)),
+            None => Err("expected a condition at the end".to_string()),
+        }
+    }
+
+    /// A name, optionally followed by an operator and value
+    fn condition(&mut self, name: &str) -> Result<Expr, String> {
+        let op = match self.tokens.get(self.pos) {
+            Some(Token::Op(op)) => *op,
+            _ => {
+                return match name.to_ascii_uppercase().as_str() {
+                    "OBJL" | "PRIM" => Err(format!("{} needs a value, e.g. {}=...", name, name)),
+                    _ => Ok(Expr::Present(attribute_code(name)?)),
+                };
+            }
+        };
+        self.pos += 1;
+        let value = match self.next() {
+            Some(Token::Word(value) | Token::Quoted(value)) => value,
+            Some(token) => return Err(format!("expected a value after {}, found {}", name, token)),
+            None => return Err(format!("expected a value after {}", name)),
+        };
+        let negate = |expr: Expr| match op {
+            Op::Eq => Ok(expr),
+            Op::Ne => Ok(Expr::Not(Box::new(expr))),
+            _ => Err(format!("{} can only be compared with = or !=", name)),
+        };
+        match name.to_ascii_uppercase().as_str() {
+            "OBJL" => negate(Expr::Class(ClassSelector::parse(&[&value])?.into_codes())),
+            "PRIM" => negate(Expr::Prim(primitive(&value)?)),
+            _ => Ok(Expr::Compare {
+                attl: attribute_code(name)?,
+                op,
+                value,
+            }),
+        }
+    }
+}
+
+/// ATTL code of an attribute acronym or number
+fn attribute_code(name: &str) -> Result<u16, String> {
+    name.parse()
+        .ok()
+        .or_else(|| AttributeInfo::from_acronym(&name.to_ascii_uppercase()).map(|a| a.code))
+        .ok_or_else(|| format!("unknown attribute '{}'", name))
+}
+
+/// PRIM value of a primitive name or number
+fn primitive(value: &str) -> Result<u8, String> {
+    match value.to_ascii_lowercase().as_str() {
+        "1" | "point" => Ok(1),
+        "2" | "line" => Ok(2),
+        "3" | "area" => Ok(3),
+        "255" | "none" => Ok(255),
+        _ => Err(format!(
+            "unknown primitive '{}' (point, line, area or none)",
+            value
+        )),
+    }
+}
+
+/// Print the features matching a filter expression
+pub fn query(file: &S57File, filter: &str, format: QueryFormat) {
+    let expr = match Expr::parse(filter) {
+        Ok(expr) => expr,
+        Err(e) => {
+            eprintln!("Error in --where: {}", e);
+            std::process::exit(1);
+        }
+    };
+    let world = match s57_interp::build_world(file) {
+        Ok(world) => world,
+        Err(e) => {
+            eprintln!("Error building world: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let matches: Vec<EntityId> = world
+        .entities_of_type(EntityType::Feature)
+        .into_iter()
+        .filter(|&feature| world.feature_meta.contains_key(&feature))
+        .filter(|&feature| expr.matches(&world, feature))
+        .collect();
+
+    match format {
+        QueryFormat::Table => {
+            println!(
+                "{:<14} {:<8} {:<6} {:<40}",
+                "FOID", "CLASS", "PRIM", "Object Name"
+            );
+            println!("{}", "-".repeat(71));
+            for &feature in &matches {
+                let meta = &world.feature_meta[&feature];
+                let name = world
+                    .feature_name(feature, NamePreference::International)
+                    .filter(|name| !name.is_empty())
+                    .unwrap_or("-");
+                println!(
+                    "{:<14} {:<8} {:<6} {:<40}",
+                    format!("{}:{}:{}", meta.foid.agen, meta.foid.fidn, meta.foid.fids),
+                    class(meta.objl),
+                    prim_name(meta.prim),
+                    name
+                );
+            }
+            println!("\nMatching features: {}", matches.len());
+        }
+        QueryFormat::Json => {
+            let features: Vec<_> = matches
+                .iter()
+                .map(|feature| {
+                    let meta = &world.feature_meta[feature];
+                    let attributes: serde_json::Map<String, serde_json::Value> = world
+                        .feature_attributes
+                        .get(feature)
+                        .into_iter()
+                        .flat_map(|attrs| attrs.attf.iter().chain(&attrs.natf))
+                        .map(|(attl, value)| {
+                            let key = AttributeInfo::from_code(*attl)
+                                .map(|a| a.acronym.to_string())
+                                .unwrap_or_else(|| attl.to_string());
+                            (key, json!(value))
+                        })
+                        .collect();
+                    json!({
+                        "foid": format!("{}:{}:{}", meta.foid.agen, meta.foid.fidn, meta.foid.fids),
+                        "objl": meta.objl,
+                        "class": class(meta.objl),
+                        "prim": prim_name(meta.prim),
+                        "attributes": attributes,
+                    })
+                })
+                .collect();
+            println!("{}", serde_json::to_string_pretty(&features).unwrap());
+        }
+    }
+}
+
+/// Class acronym, or the OBJL code if not in the catalogue
+fn class(objl: u16) -> String {
+    ObjectClass::from_code(objl)
+        .map(|c| c.to_string())
+        .unwrap_or_else(|| objl.to_string())
+}
+
+fn prim_name(prim: u8) -> &'static str {
+    match prim {
+        1 => "point",
+        2 => "line",
+        3 => "area",
+        _ => "none",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use s57_interp::ecs::{FeatureAttributes, FeatureMeta};
+    use s57_interp::FoidKey;
+
+    /// ATTF values by acronym
+    type Attributes<'a> = &'a [(&'a str, &'a str)];
+
+    /// Features to filter: two lights, a lateral buoy, a wreck and a depth
+    /// area, with their ATTF values by acronym
+    fn scene() -> World {
+        let features: [(u16, u8, Attributes); 5] = [
+            (
+                75,
+                1,
+                &[("COLOUR", "1,3"), ("VALNMR", "15"), ("OBJNAM", "Race Rock")],
+            ),
+            (75, 1, &[("COLOUR", "4"), ("VALNMR", "8")]),
+            (17, 1, &[("COLOUR", "3"), ("CATLAM", "1")]),
+            (159, 1, &[("VALSOU", "9.5"), ("OBJNAM", "A && B")]),
+            (42, 3, &[("OBJNAM", "Long Island Sound")]),
+        ];
+        let mut world = World::new();
+        for (fidn, (objl, prim, attributes)) in features.into_iter().enumerate() {
+            let entity = world.create_entity(EntityType::Feature);
+            world.feature_meta.insert(
+                entity,
+                FeatureMeta {
+                    foid: FoidKey {
+                        agen: 550,
+                        fidn: fidn as u32,
+                        fids: 1,
+                    },
+                    prim,
+                    grup: 2,
+                    objl,
+                    rver: 1,
+                    ruin: 1,
+                },
+            );
+            let attf = attributes
+                .iter()
+                .map(|(acronym, value)| (attribute_code(acronym).unwrap(), value.to_string()))
+                .collect();
+            world.feature_attributes.insert(
+                entity,
+                FeatureAttributes {
+                    attf,
+                    natf: Vec::new(),
+                },
+            );
+        }
+        world
+    }
+
+    /// Position in `scene` (the FIDN) of each feature matching `filter`
+    fn select(world: &World, filter: &str) -> Vec<u32> {
+        let expr = Expr::parse(filter).unwrap();
+        let mut matches: Vec<u32> = world
+            .feature_meta
+            .iter()
+            .filter(|(&feature, _)| expr.matches(world, feature))
+            .map(|(_, meta)| meta.foid.fidn)
+            .collect();
+        matches.sort();
+        matches
+    }
+
+    #[test]
+    fn test_precedence() {
+        let world = scene();
+        // && binds tighter than ||
+        assert_eq!(
+            select(&world, "OBJL=LIGHTS || OBJL=BOYLAT && COLOUR=4"),
+            [0, 1]
+        );
+        assert_eq!(
+            select(&world, "(OBJL=LIGHTS || OBJL=BOYLAT) && COLOUR=4"),
+            [1]
+        );
+        assert_eq!(select(&world, "OBJL=BOY* && (COLOUR=3 || !CATLAM)"), [2]);
+        assert_eq!(select(&world, "COLOUR=4 || VALSOU<10 || CATLAM"), [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_negation() {
+        let world = scene();
+        assert_eq!(select(&world, "!OBJNAM"), [1, 2]);
+        assert_eq!(select(&world, "!!OBJNAM"), [0, 3, 4]);
+        assert_eq!(select(&world, "!(OBJL=LIGHTS) && PRIM=point"), [2, 3]);
+        assert_eq!(select(&world, "OBJL!=LIGHTS && PRIM!=area"), [2, 3]);
+    }
+
+    #[test]
+    fn test_list_values() {
+        let world = scene();
+        // "1,3" holds 3; features without COLOUR match neither operator
+        assert_eq!(select(&world, "COLOUR=3"), [0, 2]);
+        assert_eq!(select(&world, "COLOUR!=3"), [1]);
+        assert_eq!(select(&world, "COLOUR=1,3"), [0]);
+        assert!(compare("1,3", Op::Ne, "4"));
+        assert!(!compare("1,3", Op::Lt, "4"));
+    }
+
+    #[test]
+    fn test_quoted_values() {
+        let world = scene();
+        assert_eq!(select(&world, "OBJNAM=\"Long Island Sound\""), [4]);
+        assert_eq!(
+            select(&world, "PRIM=area && OBJNAM=\"Long Island Sound\""),
+            [4]
+        );
+        assert_eq!(select(&world, "OBJNAM=\"A && B\""), [3]);
+        assert_eq!(select(&world, "OBJNAM!=\"Race Rock\""), [3, 4]);
+        assert_eq!(
+            tokenize("OBJNAM=\"(x || y)\"").unwrap(),
+            [
+                Token::Word("OBJNAM".to_string()),
+                Token::Op(Op::Eq),
+                Token::Quoted("(x || y)".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decimal_comparisons() {
+        let world = scene();
+        assert_eq!(select(&world, "VALSOU < 10"), [3]);
+        assert_eq!(select(&world, "VALSOU<9.5"), Vec::<u32>::new());
+        assert_eq!(select(&world, "VALSOU>=9.50"), [3]);
+        assert_eq!(select(&world, "VALSOU=9.5"), [3]);
+        // As numbers, not text: "15" > "8"
+        assert_eq!(select(&world, "VALNMR>10"), [0]);
+        assert_eq!(select(&world, "VALNMR<=8"), [1]);
+        assert!(compare("9.5", Op::Lt, "10"));
+        assert!(!compare("abc", Op::Lt, "10"));
+    }
+
+    #[test]
+    fn test_class_selectors() {
+        let world = scene();
+        assert_eq!(select(&world, "OBJL=75"), [0, 1]);
+        assert_eq!(select(&world, "objl=lights"), [0, 1]);
+        assert_eq!(select(&world, "OBJL=BOY*"), [2]);
+        assert_eq!(select(&world, "OBJL=dangers"), [3]);
+        assert_eq!(select(&world, "PRIM=3"), [4]);
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = |filter| Expr::parse(filter).unwrap_err();
+        assert_eq!(error("OBJNAM=\"Race"), "unterminated string");
+        assert_eq!(error("COLOUR=3 )"), "unexpected ')'");
+        assert_eq!(error("COLOUR=3 VALNMR"), "unexpected 'VALNMR'");
+        assert_eq!(error("(COLOUR=3"), "missing ')'");
+        assert_eq!(error("COLOUR=3 &&"), "expected a condition at the end");
+        assert_eq!(error("VALSOU <"), "expected a value after VALSOU");
+        assert_eq!(error("OBJL"), "OBJL needs a value, e.g. OBJL=...");
+        assert_eq!(error("PRIM<2"), "PRIM can only be compared with = or !=");
+        assert_eq!(error("NOSUCH=1"), "unknown attribute 'NOSUCH'");
+        assert_eq!(error("COLOUR=3 # x"), "unexpected character '#'");
+    }
+}
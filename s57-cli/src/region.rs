@@ -0,0 +1,226 @@
+//! Region extraction across the cells of an exchange set
+
+use crate::render::RenderOptions;
+use crate::svg::SvgRenderer;
+use crate::ExtractFormat;
+use s57_interp::chart_catalog::ChartCatalog;
+use s57_interp::ecs::World;
+use s57_interp::geojson::{to_geojson, GeoJsonOptions};
+use s57_interp::region::{extract_region, BoundingBox};
+use s57_interp::senc::Senc;
+use s57_interp::ParseOptions;
+use s57_parse::catalog::ExchangeSet;
+use s57_parse::S57File;
+use std::path::{Path, PathBuf};
+
+/// Load every cell touching `bbox` and write the merged features
+///
+/// `bbox` is [min_lon, min_lat, max_lon, max_lat] in degrees; a box with
+/// min_lon > max_lon crosses the antimeridian. The render options and
+/// renderer are only used for SVG output.
+pub fn extract(
+    catalog_path: &Path,
+    catalog_file: &S57File,
+    bbox: &[f64],
+    output_path: &PathBuf,
+    format: ExtractFormat,
+    options: &RenderOptions,
+    renderer: SvgRenderer,
+) {
+    let Some(region) = parse_bbox(bbox) else {
+        eprintln!("Error: --bbox must be MIN_LON,MIN_LAT,MAX_LON,MAX_LAT");
+        std::process::exit(1);
+    };
+
+    let exchange_set = match ExchangeSet::from_catalog(catalog_file) {
+        Ok(set) => set,
+        Err(e) => {
+            eprintln!("Error reading catalogue: {}", e);
+            std::process::exit(1);
+        }
+    };
+    let root = catalog_path.parent().unwrap_or(Path::new(""));
+    let catalog = ChartCatalog::new(root, exchange_set);
+
+    let cells = catalog.cells_in(&region);
+    println!("{} cell(s) touch the region", cells.len());
+
+    let parse_options = ParseOptions::default();
+    let mut worlds = Vec::with_capacity(cells.len());
+    for entry in cells {
+        match catalog.load_world(entry, &parse_options) {
+            Ok(world) => {
+                log::info!("Loaded {}", entry.file);
+                worlds.push(world);
+            }
+            Err(e) => eprintln!("Warning: skipping {}: {}", entry.file, e),
+        }
+    }
+
+    let world_refs: Vec<_> = worlds.iter().collect();
+    let merged = extract_region(&world_refs, &region);
+    println!(
+        "Extracted {} features",
+        merged
+            .entities_of_type(s57_interp::ecs::EntityType::Feature)
+            .len()
+    );
+
+    let written = match format {
+        ExtractFormat::Svg => {
+            crate::render::render_world(&merged, output_path, options, renderer);
+            return;
+        }
+        ExtractFormat::Geojson => write_geojson(&merged, output_path),
+        ExtractFormat::Senc => write_senc(merged, output_path),
+    };
+    if let Err(e) = written {
+        eprintln!("Error writing {}: {}", output_path.display(), e);
+        std::process::exit(1);
+    }
+    println!("Region written to: {}", output_path.display());
+}
+
+/// Parse `--bbox` values into a box, None unless they are four finite
+/// degrees with min_lat <= max_lat
+fn parse_bbox(bbox: &[f64]) -> Option<BoundingBox> {
+    match bbox {
+        [min_lon, min_lat, max_lon, max_lat] if min_lat <= max_lat => {
+            BoundingBox::from_degrees(*min_lat, *min_lon, *max_lat, *max_lon)
+        }
+        _ => None,
+    }
+}
+
+/// Write an extracted region as a GeoJSON FeatureCollection
+fn write_geojson(region: &World, output: &Path) -> Result<(), String> {
+    let text = to_geojson(region, &GeoJsonOptions::new()).to_string();
+    std::fs::write(output, text).map_err(|e| e.to_string())
+}
+
+/// Write an extracted region as a SENC dataset, for `World::from_senc`
+fn write_senc(region: World, output: &Path) -> Result<(), String> {
+    let bytes = Senc::from_world(region)
+        .to_bytes()
+        .map_err(|e| e.to_string())?;
+    std::fs::write(output, bytes).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_bigint::BigInt;
+    use num_rational::BigRational;
+    use s57_interp::ecs::{
+        EntityType, ExactPositions, FeatureMeta, FeaturePointers, SpatialRef, VectorMeta,
+    };
+    use s57_parse::bitstring::{FoidKey, NameKey};
+
+    fn deg(v: i64) -> BigRational {
+        BigRational::from_integer(BigInt::from(v))
+    }
+
+    /// One cell with a landmark (LNDMRK) on each (lat, lon) point
+    fn landmarks(points: &[(i64, i64)]) -> World {
+        let mut world = World::new();
+        for (i, (lat, lon)) in points.iter().enumerate() {
+            let node = world.create_entity(EntityType::Vector);
+            let name = NameKey {
+                rcnm: 110,
+                rcid: i as u32 + 1,
+            };
+            world.name_index.insert(name, node);
+            world.vector_meta.insert(
+                node,
+                VectorMeta {
+                    name,
+                    rver: 1,
+                    ruin: 1,
+                },
+            );
+            world.exact_positions.insert(
+                node,
+                ExactPositions {
+                    lat: [deg(*lat)].into_iter().collect(),
+                    lon: [deg(*lon)].into_iter().collect(),
+                },
+            );
+
+            let feature = world.create_entity(EntityType::Feature);
+            let foid = FoidKey {
+                agen: 550,
+                fidn: i as u32 + 1,
+                fids: 1,
+            };
+            world.foid_index.insert(foid, feature);
+            world.feature_meta.insert(
+                feature,
+                FeatureMeta {
+                    foid,
+                    prim: 1,
+                    grup: 2,
+                    objl: 74,
+                    rver: 1,
+                    ruin: 1,
+                },
+            );
+            world.feature_pointers.insert(
+                feature,
+                FeaturePointers {
+                    related_features: Vec::new(),
+                    spatial_refs: vec![SpatialRef {
+                        entity: node,
+                        ornt: 255,
+                        usag: 255,
+                        mask: 255,
+                    }],
+                },
+            );
+        }
+        world
+    }
+
+    #[test]
+    fn test_parse_bbox() {
+        assert_eq!(
+            parse_bbox(&[-5.0, 50.0, 2.0, 52.0]),
+            Some(BoundingBox::new(deg(50), deg(-5), deg(52), deg(2)))
+        );
+        // Across the antimeridian
+        assert!(parse_bbox(&[179.0, 10.0, -179.0, 12.0])
+            .unwrap()
+            .crosses_antimeridian());
+
+        assert_eq!(parse_bbox(&[0.0, 52.0, 1.0, 50.0]), None);
+        assert_eq!(parse_bbox(&[0.0, 50.0, 1.0]), None);
+        assert_eq!(parse_bbox(&[0.0, f64::NAN, 1.0, 52.0]), None);
+    }
+
+    #[test]
+    fn test_write_region() {
+        let dir = std::env::temp_dir().join(format!("s57-extract-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let cell = landmarks(&[(50, 0), (60, 0)]);
+        let bbox = parse_bbox(&[-1.0, 49.0, 1.0, 51.0]).unwrap();
+
+        // GeoJSON: only the landmark inside the box
+        let geojson = dir.join("region.geojson");
+        write_geojson(&extract_region(&[&cell], &bbox), &geojson).unwrap();
+        let text = std::fs::read_to_string(&geojson).unwrap();
+        let collection: serde_json::Value = serde_json::from_str(&text).unwrap();
+        let features = collection["features"].as_array().unwrap();
+        assert_eq!(features.len(), 1);
+        assert_eq!(
+            features[0]["geometry"]["coordinates"],
+            serde_json::json!([0.0, 50.0])
+        );
+
+        // SENC: the same region reads back as a dataset
+        let senc = dir.join("region.senc");
+        write_senc(extract_region(&[&cell], &bbox), &senc).unwrap();
+        let world = World::from_senc(&std::fs::read(&senc).unwrap()).unwrap();
+        assert_eq!(world.entities_of_type(EntityType::Feature).len(), 1);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
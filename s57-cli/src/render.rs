@@ -1,158 +1,569 @@
-//! Rendering S-57 features to SVG
-
+//! Rendering S-57 features to SVG or PNG
+
+use crate::png::PngCanvas;
+use crate::preset::{Palette, Preset};
+use crate::priority::feature_priority;
+use crate::s52::{AreaStyle, LineStroke, S52Style};
+use crate::selector::ClassSelector;
+use crate::stylesheet::Stylesheet;
+use crate::svg::{Canvas, ChartStream};
+use crate::RenderFormat;
 use log::info;
+use num_rational::BigRational;
 use num_traits::ToPrimitive;
-use s57_catalogue::ObjectClass;
-use s57_interp::ecs::{EntityId, EntityType, World};
-use s57_interp::topology::{ContinuityPolicy, EdgeWalker, FeatureBoundaryCursor, TraversalContext};
+use s57_interp::ecs::{parse_decimal, EntityId, EntityType, NamePreference, World};
+use s57_interp::geometry_cache::{ApproxGeometry, ResolvedGeometry};
+use s57_interp::overlay::MarinerObjectKind;
+use s57_interp::topology::{
+    approx_line, ApproxRing, ContinuityPolicy, EdgeMask, FeatureBoundaryCursor, TopologyResult,
+    TraversalContext,
+};
+use s57_interp::ParseOptions;
 use s57_parse::S57File;
 use std::collections::HashSet;
+use std::io::BufWriter;
 use std::path::PathBuf;
-use std::str::FromStr;
 
-pub fn render_to_svg(
-    file: &S57File,
-    output_path: &PathBuf,
-    limit: Option<usize>,
-    feature_filter: Option<u32>,
-    class_filter: &[String],
-    width: u32,
-    height: u32,
-) {
-    // Parse class filter into object codes
-    let allowed_classes: HashSet<u16> = {
-        let mut classes = HashSet::new();
-        for class_name in class_filter {
-            match ObjectClass::from_str(class_name) {
-                Ok(obj_class) => {
-                    classes.insert(obj_class.code());
-                }
-                Err(_) => {
-                    info!("Unknown object class '{}', skipping", class_name);
-                }
-            }
-        }
-        if classes.is_empty() {
-            log::error!("No valid object classes specified");
-            std::process::exit(1);
-        }
-        classes
-    };
-    // Build ECS World from S57 file
-    let world = match s57_interp::build_world(file) {
+/// M_QUAL: Quality of data
+const OBJL_M_QUAL: u16 = 308;
+/// CATZOC: Category of zone of confidence
+const ATTL_CATZOC: u16 = 72;
+/// Stroke width of the safety contour; other lines are 1
+const SAFETY_CONTOUR_WIDTH: f64 = 3.0;
+
+/// Build the World for a cell, exiting on error
+pub fn build_world(file: &S57File) -> World {
+    build_world_with_options(file, &ParseOptions::new())
+}
+
+/// Build the World for a cell with parse options, exiting on error
+pub fn build_world_with_options(file: &S57File, options: &ParseOptions) -> World {
+    match s57_interp::build_world_with_options(file, options) {
         Ok(world) => world,
         Err(e) => {
             eprintln!("Error building world: {}", e);
             std::process::exit(1);
         }
-    };
+    }
+}
 
-    println!("Rendering chart to SVG...");
+/// Resolve --classes items into codes, exiting if none are valid
+pub fn parse_class_filter<S: AsRef<str>>(class_filter: &[S]) -> HashSet<u16> {
+    match ClassSelector::parse(class_filter) {
+        Ok(selector) => selector.into_codes(),
+        Err(e) => {
+            log::error!("{}", e);
+            std::process::exit(1);
+        }
+    }
+}
 
-    // Create SVG renderer
-    let mut renderer = crate::svg::SvgRenderer::new().with_dimensions(width, height);
+/// RenderOptions: Which features to draw, and how
+pub struct RenderOptions {
+    /// Draw at most N features, dropping the lowest display priority first
+    pub limit: Option<usize>,
+    /// Draw only the feature with this FIDN
+    pub feature_filter: Option<u32>,
+    /// Image format written
+    pub format: RenderFormat,
+    /// Object class codes to draw
+    pub allowed_classes: HashSet<u16>,
+    /// Which object name to use in titles
+    pub names: NamePreference,
+    /// Colours and point size, usually from a preset
+    pub palette: Palette,
+    pub point_radius: f64,
+    /// Draw-order overrides
+    pub stylesheet: Stylesheet,
+    /// Safety depth (metres): the contour at this depth, or the next deeper
+    /// one charted, is drawn bold
+    pub safety_contour: Option<BigRational>,
+    /// Resolve geometry on the f64 path instead of converting exact geometry
+    pub approx: bool,
+}
+
+impl RenderOptions {
+    /// Options for a preset, drawing `classes` if given or else the
+    /// preset's own class list
+    pub fn from_preset(preset: Preset, classes: Option<&[String]>) -> Self {
+        let allowed_classes = match classes {
+            Some(classes) => parse_class_filter(classes),
+            None => parse_class_filter(preset.classes()),
+        };
+        Self {
+            limit: None,
+            feature_filter: None,
+            format: RenderFormat::default(),
+            allowed_classes,
+            names: NamePreference::default(),
+            palette: preset.palette(),
+            point_radius: preset.point_radius(),
+            stylesheet: Stylesheet::default(),
+            safety_contour: None,
+            approx: false,
+        }
+    }
+}
+
+/// Render an already built World in `options.format`
+pub fn render_world(
+    world: &World,
+    output_path: &PathBuf,
+    options: &RenderOptions,
+    mut renderer: crate::svg::SvgRenderer,
+) {
+    println!("Rendering chart to {}...", options.format.name());
 
     // Set up traversal context with gap marker and cycle policies
-    let ctx = TraversalContext::new(&world)
+    let ctx = TraversalContext::new(world)
         .with_continuity_policy(ContinuityPolicy::InsertGapMarker)
         .with_cycle_policy(s57_interp::topology::CyclePolicy::AllowVisitCount(2));
+    let resolver = Resolver {
+        ctx: &ctx,
+        approx: options.approx,
+    };
 
-    // Get all feature entities
-    let features = world.entities_of_type(EntityType::Feature);
-    let feature_count = limit.unwrap_or(features.len()).min(features.len());
+    let s52 = options
+        .palette
+        .s52_scheme()
+        .map(|scheme| S52Style::new(scheme, options.safety_contour.clone()));
+    if let Some(style) = &s52 {
+        renderer = renderer.with_background(style.background());
+    }
 
-    println!("Processing {} features...", feature_count);
+    let mut features = select_features(world, options, s52.as_ref());
+    let quality = if options.stylesheet.quality_hatching {
+        quality_areas(world)
+    } else {
+        Vec::new()
+    };
+    if let Some(max) = renderer.max_elements() {
+        // Mariner objects and the quality layer are drawn last but outrank
+        // every chart feature, so their elements are set aside first
+        let budget = max.saturating_sub(world.overlay.objects.len() + quality.len());
+        let before = features.len();
+        features = fit_element_budget(world, &resolver, features, budget);
+        if features.len() < before {
+            eprintln!(
+                "Warning: element limit reached, {} lowest-priority features not drawn",
+                before - features.len()
+            );
+        }
+    }
+    println!("Processing {} features...", features.len());
 
-    let mut rendered_count = 0;
+    let safety_contours = safety_contours(world, options);
 
-    for entity in features.iter().take(feature_count) {
-        if let Some(meta) = world.feature_meta.get(entity) {
-            // Filter by specific feature if requested
-            if let Some(fidn) = feature_filter {
-                if meta.foid.fidn != fidn {
-                    continue;
-                }
-            }
+    // The view must be known before anything is written; take it from the
+    // vectors the selected features reference
+    for entity in &features {
+        if let Some(bbox) = world.feature_bbox_approx(*entity) {
+            renderer.include_box(bbox.min_lat, bbox.min_lon, bbox.max_lat, bbox.max_lon);
+        }
+    }
+    for object in &world.overlay.objects {
+        let (lat, lon) = object.positions.to_f64();
+        renderer.include(lat.into_iter().zip(lon));
+    }
 
-            // Skip metadata features (chart quality/coverage info, objl 300-312)
-            if meta.objl >= 300 && meta.objl <= 312 {
-                continue;
+    let file = match std::fs::File::create(output_path) {
+        Ok(f) => f,
+        Err(e) => {
+            eprintln!("Error creating output file: {}", e);
+            std::process::exit(1);
+        }
+    };
+    let layers = Layers {
+        features,
+        s52,
+        safety_contours,
+        quality,
+        mask: EdgeMask::new(&ctx),
+    };
+    let writer = BufWriter::new(file);
+    let result = match options.format {
+        RenderFormat::Svg => {
+            draw_features(world, &resolver, options, &layers, renderer.begin(writer))
+        }
+        RenderFormat::Png => draw_features(
+            world,
+            &resolver,
+            options,
+            &layers,
+            renderer.begin_on(PngCanvas::new(writer)),
+        ),
+    };
+    if let Err(e) = result {
+        eprintln!("Error rendering {}: {}", options.format.name(), e);
+        std::process::exit(1);
+    }
+
+    println!(
+        "{} written to: {}",
+        options.format.name(),
+        output_path.display()
+    );
+}
+
+/// Resolver: Feature geometry in f64 for drawing
+///
+/// Either resolved exactly (through the World's geometry cache, if enabled)
+/// and converted, or resolved on the f64 path throughout.
+struct Resolver<'a> {
+    ctx: &'a TraversalContext<'a>,
+    /// Resolve on the f64 path (`--approx`)
+    approx: bool,
+}
+
+impl Resolver<'_> {
+    /// Resolved geometry of a line or area feature
+    fn geometry(&self, entity: EntityId) -> TopologyResult<Option<ApproxGeometry>> {
+        if self.approx {
+            return self.ctx.feature_geometry_approx(entity);
+        }
+        let geometry = self.ctx.feature_geometry(entity)?;
+        Ok(geometry.map(|geometry| geometry.to_approx()))
+    }
+
+    /// The feature's edges to stroke when some of them are masked
+    ///
+    /// None when nothing is masked, or the outline can't be resolved, and
+    /// the resolved geometry is stroked as it is.
+    fn masked_outline(&self, mask: &EdgeMask, entity: &EntityId) -> Option<Vec<ApproxRing>> {
+        let foid = self.ctx.world.feature_meta.get(entity)?.foid;
+        let cursor = FeatureBoundaryCursor::new(self.ctx, foid);
+        if !cursor.is_masked(mask) {
+            return None;
+        }
+        let outline = if self.approx {
+            cursor.resolve_outline_approx(mask)
+        } else {
+            cursor
+                .resolve_outline(mask)
+                .map(|lines| lines.iter().map(|line| approx_line(line)).collect())
+        };
+        match outline {
+            Ok(outline) => Some(outline),
+            Err(e) => {
+                info!("Failed to resolve the outline of {:?}: {}", foid, e);
+                None
             }
+        }
+    }
+}
+
+/// Layers: What a render draws, chosen before the output is started
+struct Layers {
+    /// Features in draw order
+    features: Vec<EntityId>,
+    /// S-52 styling, for S-52 palettes
+    s52: Option<S52Style>,
+    /// DEPCNT features drawn bold
+    safety_contours: HashSet<EntityId>,
+    /// M_QUAL areas to hatch, and their CATZOC
+    quality: Vec<(EntityId, u8)>,
+    /// Edges left unstroked at the cell boundary
+    mask: EdgeMask,
+}
 
-            // Filter by object class
-            if !allowed_classes.contains(&meta.objl) {
-                continue;
+/// Draw the selected features, then the quality layer and mariner objects,
+/// and finish the output
+fn draw_features<C: Canvas>(
+    world: &World,
+    geo: &Resolver,
+    options: &RenderOptions,
+    layers: &Layers,
+    mut svg: ChartStream<C>,
+) -> std::io::Result<C::Output> {
+    let Layers {
+        features,
+        s52,
+        safety_contours,
+        quality,
+        mask,
+    } = layers;
+    for entity in features {
+        let Some(meta) = world.feature_meta.get(entity) else {
+            continue;
+        };
+        let foid_str = format!("{}:{}:{}", meta.foid.agen, meta.foid.fidn, meta.foid.fids);
+        let obj_name = s57_catalogue::decode_object(meta.objl)
+            .map(|c| c.name())
+            .unwrap_or("Unknown");
+
+        info!("Rendering feature {} ({})", foid_str, obj_name);
+
+        let attrs = world.feature_attributes.get(entity);
+        let safety_contour = safety_contours.contains(entity);
+
+        // Render based on primitive type
+        match (meta.prim, s52.as_ref()) {
+            (1, Some(style)) => {
+                let fill = style.point(meta.objl, attrs);
+                render_point(world, entity, &foid_str, options, fill, &mut svg);
+            }
+            (1, None) => {
+                // Point feature - render as circle
+                let fill = options.palette.point();
+                render_point(world, entity, &foid_str, options, fill, &mut svg);
             }
+            (2, Some(style)) => {
+                let stroke = style.line(meta.objl, attrs, safety_contour, SAFETY_CONTOUR_WIDTH);
+                if let Some(stroke) = stroke {
+                    render_line(geo, mask, entity, &foid_str, &stroke, &mut svg);
+                }
+            }
+            (2, None) => {
+                // Line feature - render as polyline
+                let stroke = LineStroke {
+                    colour: options.palette.line(),
+                    width: if safety_contour {
+                        SAFETY_CONTOUR_WIDTH
+                    } else {
+                        1.0
+                    },
+                    dash: None,
+                };
+                render_line(geo, mask, entity, &foid_str, &stroke, &mut svg);
+            }
+            (3, Some(style)) => {
+                let area = style.area(meta.objl, attrs);
+                render_s52_area(geo, mask, entity, &foid_str, &area, &mut svg);
+            }
+            (3, None) => {
+                // Area feature - render as polygon
+                let palette = options.palette;
+                render_area(world, geo, mask, entity, &foid_str, palette, &mut svg);
+            }
+            _ => {}
+        }
+    }
 
-            let foid_str = format!("{}:{}:{}", meta.foid.agen, meta.foid.fidn, meta.foid.fids);
-            let obj_name = s57_catalogue::decode_object(meta.objl)
-                .map(|c| c.name())
-                .unwrap_or("Unknown");
+    println!("Rendered {} features", features.len());
 
-            info!("Rendering feature {} ({})", foid_str, obj_name);
+    if !quality.is_empty() {
+        render_quality(geo, quality, &mut svg);
+        println!("Hatched {} zone of confidence areas", quality.len());
+    }
 
-            // Render based on primitive type
-            match meta.prim {
-                1 => {
-                    // Point feature - render as circle
-                    render_point(&world, &ctx, entity, &foid_str, &mut renderer);
-                }
-                2 => {
-                    // Line feature - render as polyline
-                    render_line(&world, &ctx, entity, &foid_str, &mut renderer);
-                }
-                3 => {
-                    // Area feature - render as polygon
-                    render_area(&world, &ctx, meta.foid, &foid_str, &mut renderer);
-                }
-                _ => {}
+    if !world.overlay.is_empty() {
+        render_overlay(world, &mut svg);
+        println!("Rendered {} mariner objects", world.overlay.len());
+    }
+
+    println!("Wrote {} elements", svg.elements());
+    if svg.simplified_paths() > 0 {
+        println!(
+            "Simplified {} paths to fit --max-path-points",
+            svg.simplified_paths()
+        );
+    }
+    if svg.dropped_elements() > 0 {
+        eprintln!(
+            "Warning: element limit reached, {} elements not drawn",
+            svg.dropped_elements()
+        );
+    }
+
+    svg.finish()
+}
+
+/// DEPCNT features to draw bold as the safety contour
+///
+/// As on an ECDIS, a safety depth with no contour of its own selects the
+/// next deeper contour in the cell; the choice is reported.
+fn safety_contours(world: &World, options: &RenderOptions) -> HashSet<EntityId> {
+    let Some(requested) = &options.safety_contour else {
+        return HashSet::new();
+    };
+    match world.safety_contour(requested) {
+        Some(depth) => {
+            if &depth == requested {
+                println!("Safety contour: {} m", format_depth(&depth));
+            } else {
+                println!(
+                    "Safety contour: {} m (no {} m contour charted)",
+                    format_depth(&depth),
+                    format_depth(requested)
+                );
             }
+            world.contours_at(&depth).into_iter().collect()
+        }
+        None => {
+            eprintln!(
+                "Warning: no depth contour at or deeper than {} m, safety contour not drawn",
+                format_depth(requested)
+            );
+            HashSet::new()
+        }
+    }
+}
+
+/// A depth in metres, without trailing zeros
+fn format_depth(depth: &BigRational) -> String {
+    to_f64(depth).to_string()
+}
 
-            rendered_count += 1;
+/// Features to draw, in draw order
+///
+/// Features are sorted by the stylesheet's draw order, then by display
+/// priority, then by file order. With a limit, the features with the
+/// highest display priority are kept. S-52 palettes take display priorities
+/// from their look-up tables.
+fn select_features(
+    world: &World,
+    options: &RenderOptions,
+    s52: Option<&S52Style>,
+) -> Vec<EntityId> {
+    let mut features: Vec<EntityId> = world
+        .entities_of_type(EntityType::Feature)
+        .into_iter()
+        .filter(|entity| {
+            let Some(meta) = world.feature_meta.get(entity) else {
+                return false;
+            };
+            // Filter by specific feature if requested
+            if options
+                .feature_filter
+                .is_some_and(|fidn| meta.foid.fidn != fidn)
+            {
+                return false;
+            }
+            // Skip metadata features (chart quality/coverage info, objl 300-312)
+            // and anything not in the class filter
+            !(300..=312).contains(&meta.objl) && options.allowed_classes.contains(&meta.objl)
+        })
+        .collect();
+
+    if let Some(limit) = options.limit {
+        if limit < features.len() {
+            let mut ranked = features.clone();
+            ranked.sort_by_key(|entity| std::cmp::Reverse(draw_priority(world, *entity, s52)));
+            let kept: HashSet<EntityId> = ranked.into_iter().take(limit).collect();
+            features.retain(|entity| kept.contains(entity));
         }
     }
+    features.sort_by_key(|entity| {
+        let order = world
+            .feature_meta
+            .get(entity)
+            .map(|meta| options.stylesheet.draw_order(meta.objl, meta.prim))
+            .unwrap_or(0);
+        (order, draw_priority(world, *entity, s52))
+    });
+    features
+}
 
-    println!("Rendered {} features", rendered_count);
+/// Display priority from the S-52 look-up table if there is one, else the
+/// built-in priority groups
+fn draw_priority(world: &World, entity: EntityId, s52: Option<&S52Style>) -> u8 {
+    let looked_up = s52.and_then(|style| {
+        let meta = world.feature_meta.get(&entity)?;
+        style.priority(meta.objl, meta.prim, world.feature_attributes.get(&entity))
+    });
+    looked_up.unwrap_or_else(|| feature_priority(world, entity))
+}
 
-    // Write SVG to file
-    let mut file = match std::fs::File::create(output_path) {
-        Ok(f) => f,
-        Err(e) => {
-            eprintln!("Error creating output file: {}", e);
-            std::process::exit(1);
+/// Keep the highest-priority features whose elements fit in `budget`
+///
+/// Features are taken from the highest priority down and selection stops at
+/// the first one that doesn't fit, so nothing is drawn while a feature of
+/// higher priority is left out. Returns the kept features in their original
+/// order.
+fn fit_element_budget(
+    world: &World,
+    geo: &Resolver,
+    features: Vec<EntityId>,
+    budget: usize,
+) -> Vec<EntityId> {
+    let mut ranked = features.clone();
+    ranked.sort_by_key(|entity| std::cmp::Reverse(feature_priority(world, *entity)));
+
+    let mut used = 0;
+    let mut kept = HashSet::new();
+    for entity in ranked {
+        let count = element_count(world, geo, entity);
+        if used + count > budget {
+            break;
         }
-    };
+        used += count;
+        kept.insert(entity);
+    }
+    features
+        .into_iter()
+        .filter(|entity| kept.contains(entity))
+        .collect()
+}
 
-    if let Err(e) = renderer.render(&mut file) {
-        eprintln!("Error rendering SVG: {}", e);
-        std::process::exit(1);
+/// Number of SVG elements a feature draws as
+fn element_count(world: &World, geo: &Resolver, entity: EntityId) -> usize {
+    let Some(meta) = world.feature_meta.get(&entity) else {
+        return 0;
+    };
+    match meta.prim {
+        1 => world
+            .feature_pointers
+            .get(&entity)
+            .map(|pointers| {
+                pointers
+                    .spatial_refs
+                    .iter()
+                    .filter(|sref| {
+                        world
+                            .approx_points(sref.entity)
+                            .is_some_and(|positions| !positions.is_empty())
+                    })
+                    .count()
+            })
+            .unwrap_or(0),
+        2 | 3 => match geo.geometry(entity) {
+            Ok(Some(geometry)) => match geometry {
+                ResolvedGeometry::Lines(lines) if meta.prim == 2 => {
+                    lines.iter().filter(|line| !line.is_empty()).count()
+                }
+                ResolvedGeometry::Area(boundary) if meta.prim == 3 => {
+                    let rings = boundary.exterior.iter().chain(&boundary.interior);
+                    usize::from(rings.clone().any(|ring| !ring.is_empty()))
+                }
+                _ => 0,
+            },
+            _ => 0,
+        },
+        _ => 0,
     }
+}
+
+fn to_f64(value: &BigRational) -> f64 {
+    value.to_f64().unwrap_or(0.0)
+}
 
-    println!("SVG written to: {}", output_path.display());
+/// A ring's points, as the canvas takes them
+fn ring_points(ring: &ApproxRing) -> impl Iterator<Item = (f64, f64)> + '_ {
+    ring.iter().copied()
 }
 
 fn render_point(
     world: &World,
-    _ctx: &TraversalContext,
     entity: &EntityId,
     feature_id: &str,
-    renderer: &mut crate::svg::SvgRenderer,
+    options: &RenderOptions,
+    fill: &str,
+    svg: &mut ChartStream<impl Canvas>,
 ) {
-    let title = get_feature_title(world, entity);
+    let title = get_feature_title(world, entity, options.names);
 
     // Get spatial references
     if let Some(pointers) = world.feature_pointers.get(entity) {
         for sref in &pointers.spatial_refs {
-            if let Some(positions) = world.exact_positions.get(&sref.entity) {
-                let (lat, lon) = positions.to_f64();
-                if !lat.is_empty() {
-                    renderer.add_point(
-                        lat[0],
-                        lon[0],
-                        2.0,
-                        "#ff0000".to_string(),
-                        title.clone(),
-                        Some(feature_id.to_string()),
+            if let Some(positions) = world.approx_points(sref.entity) {
+                if let Some((lat, lon)) = positions.points().next() {
+                    svg.add_point(
+                        lat,
+                        lon,
+                        options.point_radius,
+                        fill,
+                        title.as_deref(),
+                        Some(feature_id),
                     );
                 }
             }
@@ -160,17 +571,107 @@ fn render_point(
     }
 }
 
-fn get_feature_title(world: &World, entity: &EntityId) -> Option<String> {
+/// M_QUAL areas with a hatched zone of confidence, and their CATZOC
+fn quality_areas(world: &World) -> Vec<(EntityId, u8)> {
+    world
+        .entities_of_type(EntityType::Feature)
+        .into_iter()
+        .filter(|entity| {
+            world
+                .feature_meta
+                .get(entity)
+                .is_some_and(|meta| meta.objl == OBJL_M_QUAL && meta.prim == 3)
+        })
+        .filter_map(|entity| {
+            let catzoc = world
+                .feature_attributes
+                .get(&entity)?
+                .get(ATTL_CATZOC)?
+                .trim()
+                .parse()
+                .ok()?;
+            zoc_hatch(catzoc).map(|_| (entity, catzoc))
+        })
+        .collect()
+}
+
+/// Hatch spacing in pixels, and whether it is cross-hatched, for a CATZOC
+/// value; the less reliable the survey, the denser the hatching
+///
+/// A1 is left clear. U (unassessed) is cross-hatched so it can't be taken
+/// for a surveyed category.
+fn zoc_hatch(catzoc: u8) -> Option<(f64, bool)> {
+    match catzoc {
+        2 => Some((24.0, false)), // A2
+        3 => Some((16.0, false)), // B
+        4 => Some((10.0, false)), // C
+        5 => Some((6.0, false)),  // D
+        6 => Some((10.0, true)),  // U
+        _ => None,
+    }
+}
+
+/// Hatch zone of confidence areas over the chart
+fn render_quality(geo: &Resolver, areas: &[(EntityId, u8)], svg: &mut ChartStream<impl Canvas>) {
+    let mut defined = HashSet::new();
+    for (entity, catzoc) in areas {
+        let Some((spacing, crossed)) = zoc_hatch(*catzoc) else {
+            continue;
+        };
+        let pattern = format!("zoc-{}", catzoc);
+        if defined.insert(*catzoc) {
+            svg.add_hatch_pattern(&pattern, spacing, 45.0, "#606060", crossed);
+        }
+        let Ok(Some(ResolvedGeometry::Area(boundary))) = geo.geometry(*entity) else {
+            continue;
+        };
+        svg.add_polygon_with_holes(
+            boundary
+                .exterior
+                .iter()
+                .chain(&boundary.interior)
+                .map(ring_points),
+            &format!("url(#{})", pattern),
+            "#606060",
+            0.5,
+            Some(&pattern),
+        );
+    }
+}
+
+/// Draw mariner overlay objects on top of the chart
+fn render_overlay(world: &World, svg: &mut ChartStream<impl Canvas>) {
+    for (i, object) in world.overlay.objects.iter().enumerate() {
+        let (lat, lon) = object.positions.to_f64();
+        let id = format!("overlay-{}", i);
+        let title = match &object.text {
+            Some(text) => format!("{} - {}", object.name, text),
+            None => object.name.clone(),
+        };
+
+        match object.kind {
+            MarinerObjectKind::Route => {
+                svg.add_polyline(lat.into_iter().zip(lon), "#ff8c00", 2.0, Some(&id));
+            }
+            MarinerObjectKind::Waypoint | MarinerObjectKind::Note => {
+                let fill = if object.kind == MarinerObjectKind::Note {
+                    "#8b008b"
+                } else {
+                    "#ff8c00"
+                };
+                if let (Some(lat), Some(lon)) = (lat.first(), lon.first()) {
+                    svg.add_point(*lat, *lon, 3.0, fill, Some(&title), Some(&id));
+                }
+            }
+        }
+    }
+}
+
+fn get_feature_title(world: &World, entity: &EntityId, names: NamePreference) -> Option<String> {
     let meta = world.feature_meta.get(entity)?;
 
-    // Get object name from ATTF attributes if available (OBJNAM = attribute code 116)
-    let name = world.feature_attributes.get(entity).and_then(|attrs| {
-        attrs
-            .attf
-            .iter()
-            .find(|(code, _)| *code == 116)
-            .map(|(_, value)| value.as_str())
-    });
+    // Get object name (OBJNAM/NOBJNM) if available
+    let name = world.feature_name(*entity, names);
 
     // Get object type label
     let obj_type = s57_catalogue::decode_object(meta.objl)
@@ -185,149 +686,160 @@ fn get_feature_title(world: &World, entity: &EntityId) -> Option<String> {
 }
 
 fn render_line(
-    world: &World,
-    ctx: &TraversalContext,
+    geo: &Resolver,
+    mask: &EdgeMask,
     entity: &EntityId,
     feature_id: &str,
-    renderer: &mut crate::svg::SvgRenderer,
+    stroke: &LineStroke,
+    svg: &mut ChartStream<impl Canvas>,
 ) {
-    // Get spatial references
-    if let Some(pointers) = world.feature_pointers.get(entity) {
-        for sref in &pointers.spatial_refs {
-            if let Some(vmeta) = world.vector_meta.get(&sref.entity) {
-                // Use EdgeWalker to resolve line geometry
-                let mut walker = EdgeWalker::new(ctx);
-                if let Ok(coords) = walker.resolve_line_2d(vmeta.name) {
-                    let points: Vec<_> = coords
-                        .iter()
-                        .filter_map(|(lat, lon)| Some((lat.to_f64()?, lon.to_f64()?)))
-                        .collect();
-
-                    if !points.is_empty() {
-                        renderer.add_polyline(
-                            points,
-                            "#0000ff".to_string(),
-                            1.0,
-                            Some(feature_id.to_string()),
-                        );
-                    }
-                }
-            }
+    let Ok(Some(ResolvedGeometry::Lines(lines))) = geo.geometry(*entity) else {
+        return;
+    };
+    let outline = geo.masked_outline(mask, entity);
+    for coords in outline.as_ref().unwrap_or(&lines) {
+        if !coords.is_empty() {
+            svg.add_dashed_polyline(
+                ring_points(coords),
+                stroke.colour,
+                stroke.width,
+                stroke.dash,
+                Some(feature_id),
+            );
         }
     }
 }
 
-/// Determine fill and stroke colors based on object class code
-fn get_area_colors(objl: u16) -> (String, String, f64) {
-    match objl {
-        // Water areas - light blue fill
-        42 | 17003 => ("#87ceeb".to_string(), "#4682b4".to_string(), 0.5), // DEPARE - Depth area
-        119 => ("#87ceeb".to_string(), "#4682b4".to_string(), 0.5), // SEAARE - Sea area / named water area
-
-        // Land areas - green
-        71 => ("#90ee90".to_string(), "#228b22".to_string(), 0.5), // LNDARE - Land area
-
-        // Zone-type features - transparent with colored outline
-        1 => ("none".to_string(), "#ff6b6b".to_string(), 1.5), // ADMARE - Administration area
-        4 | 17001 => ("none".to_string(), "#9370db".to_string(), 1.5), // ACHARE - Anchorage area
-        27 => ("none".to_string(), "#ffa500".to_string(), 2.0), // CTNARE - Caution area
-        31 => ("none".to_string(), "#4169e1".to_string(), 1.5), // CONZNE - Contiguous zone
-        32 => ("none".to_string(), "#4682b4".to_string(), 1.5), // COSARE - Continental shelf area
-        37 => ("none".to_string(), "#daa520".to_string(), 1.5), // CUSZNE - Custom zone
-        40 => ("none".to_string(), "#ff1493".to_string(), 2.0), // DWRTCL - Deep water route centerline
-        41 => ("none".to_string(), "#ff69b4".to_string(), 1.5), // DWRTPT - Deep water route part
-        50 => ("none".to_string(), "#1e90ff".to_string(), 1.5), // EXEZNE - Exclusive Economic Zone
-        51 => ("none".to_string(), "#00ced1".to_string(), 2.0), // FAIRWY - Fairway
-        54 => ("none".to_string(), "#20b2aa".to_string(), 1.5), // FSHZNE - Fishery zone
-        63 | 17014 => ("none".to_string(), "#6a5acd".to_string(), 1.5), // HRBARE - Harbour area
-        68 => ("none".to_string(), "#48d1cc".to_string(), 1.5), // ISTZNE - Inshore traffic zone
-        83 => ("none".to_string(), "#dc143c".to_string(), 2.0), // MIPARE - Military practice area
-        88 => ("none".to_string(), "#ff8c00".to_string(), 1.5), // OSPARE - Offshore production area
-        96 => ("none".to_string(), "#ffa500".to_string(), 2.0), // PRCARE - Precautionary area
-        97 => ("none".to_string(), "#ff8c00".to_string(), 1.5), // PRDARE - Production/storage area
-        108 => ("none".to_string(), "#ff1493".to_string(), 2.0), // RCRTCL - Recommended route centerline
-        112 | 17005 => ("none".to_string(), "#ff0000".to_string(), 2.0), // RESARE - Restricted area
-        135 => ("none".to_string(), "#4682b4".to_string(), 1.5), // TESARE - Territorial sea area
-        150 => ("none".to_string(), "#ff00ff".to_string(), 2.0), // TSEZNE - Traffic Separation Zone
-        152 => ("none".to_string(), "#ff69b4".to_string(), 1.5), // TWRTPT - Two-way route part
-
-        // Default - light green with darker outline
-        _ => ("#90ee90".to_string(), "#228b22".to_string(), 0.5),
-    }
-}
-
 fn render_area(
     world: &World,
-    ctx: &TraversalContext,
-    foid: s57_parse::bitstring::FoidKey,
+    geo: &Resolver,
+    mask: &EdgeMask,
+    entity: &EntityId,
     feature_id: &str,
-    renderer: &mut crate::svg::SvgRenderer,
+    palette: Palette,
+    svg: &mut ChartStream<impl Canvas>,
 ) {
     // Get object class for color selection
     let objl = world
         .feature_meta
-        .values()
-        .find(|meta| meta.foid == foid)
+        .get(entity)
         .map(|meta| meta.objl)
         .unwrap_or(0);
 
-    let (fill, stroke, stroke_width) = get_area_colors(objl);
-
-    // Use FeatureBoundaryCursor to resolve area boundary rings
-    let cursor = FeatureBoundaryCursor::new(ctx, foid);
-
-    match cursor.resolve_rings() {
-        Ok(rings) => {
+    let drval1 = world
+        .feature_attributes
+        .get(entity)
+        .and_then(|attrs| attrs.get(87))
+        .and_then(parse_decimal)
+        .and_then(|d| d.to_f64());
+    let (fill, stroke, stroke_width) = palette.area(objl, drval1);
+
+    // Resolve boundary rings (cached on the World when enabled)
+    match geo.geometry(*entity) {
+        Ok(Some(geometry)) => {
+            let ResolvedGeometry::Area(boundary) = geometry else {
+                return;
+            };
+            let rings: Vec<_> = boundary
+                .exterior
+                .iter()
+                .chain(&boundary.interior)
+                .filter(|ring| !ring.is_empty())
+                .collect();
             info!("Resolved {} rings for feature {}", rings.len(), feature_id);
 
-            // Convert all rings to f64 coordinates
-            let mut converted_rings = Vec::new();
-            for (i, ring) in rings.iter().enumerate() {
-                info!("Ring {} has {} points", i, ring.len());
-                let points: Vec<_> = ring
-                    .iter()
-                    .filter_map(|(lat, lon)| Some((lat.to_f64()?, lon.to_f64()?)))
-                    .collect();
-
-                if !points.is_empty() {
-                    converted_rings.push(points);
-                }
-            }
-
-            // Render as polygon with holes if we have multiple rings
-            if !converted_rings.is_empty() {
-                if converted_rings.len() == 1 {
+            // With masked edges, the fill has no outline and the visible
+            // edges are stroked on their own
+            let outline = geo.masked_outline(mask, entity);
+            let (ring_stroke, ring_stroke_width) = match outline {
+                Some(_) => ("none", 0.0),
+                None => (stroke, stroke_width),
+            };
+
+            match rings.as_slice() {
+                [] => {}
+                [ring] => {
                     // Simple polygon without holes
-                    info!(
-                        "Adding simple polygon with {} points",
-                        converted_rings[0].len()
-                    );
-                    renderer.add_polygon(
-                        converted_rings.into_iter().next().unwrap(),
+                    info!("Adding simple polygon with {} points", ring.len());
+                    svg.add_polygon(
+                        ring_points(ring),
                         fill,
-                        stroke,
-                        stroke_width,
-                        Some(feature_id.to_string()),
+                        ring_stroke,
+                        ring_stroke_width,
+                        Some(feature_id),
                     );
-                } else {
+                }
+                _ => {
                     // Polygon with holes
                     info!(
                         "Adding polygon with {} rings (1 exterior + {} holes)",
-                        converted_rings.len(),
-                        converted_rings.len() - 1
+                        rings.len(),
+                        rings.len() - 1
                     );
-                    renderer.add_polygon_with_holes(
-                        converted_rings,
+                    svg.add_polygon_with_holes(
+                        rings.iter().map(|ring| ring_points(ring)),
                         fill,
-                        stroke,
-                        stroke_width,
-                        Some(feature_id.to_string()),
+                        ring_stroke,
+                        ring_stroke_width,
+                        Some(feature_id),
                     );
                 }
             }
+            for line in outline.iter().flatten() {
+                svg.add_polyline(ring_points(line), stroke, stroke_width, Some(feature_id));
+            }
         }
+        Ok(None) => {}
         Err(e) => {
             info!("Failed to resolve rings for {}: {}", feature_id, e);
         }
     }
 }
+
+/// Draw an S-52 area: the fill without outline, then the boundary as lines
+fn render_s52_area(
+    geo: &Resolver,
+    mask: &EdgeMask,
+    entity: &EntityId,
+    feature_id: &str,
+    style: &AreaStyle,
+    svg: &mut ChartStream<impl Canvas>,
+) {
+    let Ok(Some(ResolvedGeometry::Area(boundary))) = geo.geometry(*entity) else {
+        return;
+    };
+    let rings: Vec<_> = boundary
+        .exterior
+        .iter()
+        .chain(&boundary.interior)
+        .filter(|ring| !ring.is_empty())
+        .collect();
+    if rings.is_empty() {
+        return;
+    }
+    if style.fill != "none" {
+        svg.add_polygon_with_holes(
+            rings.iter().map(|ring| ring_points(ring)),
+            &style.fill,
+            "none",
+            0.0,
+            Some(feature_id),
+        );
+    }
+    if let Some(stroke) = &style.boundary {
+        let outline = geo.masked_outline(mask, entity);
+        let lines = match &outline {
+            Some(outline) => outline.iter().collect(),
+            None => rings,
+        };
+        for ring in lines {
+            svg.add_dashed_polyline(
+                ring_points(ring),
+                stroke.colour,
+                stroke.width,
+                stroke.dash,
+                Some(feature_id),
+            );
+        }
+    }
+}
@@ -0,0 +1,255 @@
+//! Route file import (GPX and RTZ) for drawing over the chart
+
+use num_rational::BigRational;
+use s57_interp::ecs::{parse_decimal, World};
+use s57_interp::hazard::scan_route;
+use s57_interp::overlay::{MarinerObjectKind, Overlay};
+use std::path::Path;
+
+/// Load a GPX or RTZ file into an overlay with one route
+///
+/// The format is chosen by content: a `<gpx>` root element is GPX (first
+/// `<rte>`, or the first track if there are no routes), a `<route>` root
+/// element is RTZ. GPX waypoints (`<wpt>`) are added as waypoints.
+pub fn load_route(path: &Path) -> Result<Overlay, String> {
+    let text = std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let fallback_name = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    parse_route(&text, &fallback_name).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// Parse GPX or RTZ text; `fallback_name` names a route that has no name
+fn parse_route(text: &str, fallback_name: &str) -> Result<Overlay, String> {
+    let doc = roxmltree::Document::parse(text).map_err(|e| e.to_string())?;
+    let root = doc.root_element();
+
+    let mut overlay = Overlay::new();
+    match root.tag_name().name() {
+        "gpx" => read_gpx(root, fallback_name, &mut overlay)?,
+        "route" => read_rtz(root, fallback_name, &mut overlay)?,
+        other => return Err(format!("unsupported route file root element <{}>", other)),
+    }
+
+    if overlay.of_kind(MarinerObjectKind::Route).next().is_none() {
+        return Err("no route found".to_string());
+    }
+    Ok(overlay)
+}
+
+/// Scan the overlay's routes for hazards and add a note for each one found
+///
+/// Prints a warning line per hazard and returns how many were found.
+pub fn annotate_hazards(world: &mut World, corridor_m: f64) -> usize {
+    let mut notes = Vec::new();
+    for route in world.overlay.of_kind(MarinerObjectKind::Route) {
+        for hazard in scan_route(world, route, corridor_m) {
+            let class = s57_catalogue::decode_object(hazard.objl)
+                .map(|c| c.name())
+                .unwrap_or("Unknown");
+            let text = format!(
+                "{} {:.0} m from leg {} of {}",
+                class,
+                hazard.distance_m,
+                hazard.leg + 1,
+                route.name
+            );
+            println!("Warning: {}", text);
+            notes.push((text, hazard.lat, hazard.lon));
+        }
+    }
+
+    let count = notes.len();
+    for (text, lat, lon) in notes {
+        let (Some(lat), Some(lon)) = (BigRational::from_float(lat), BigRational::from_float(lon))
+        else {
+            continue;
+        };
+        world.overlay.add_note("Hazard", &text, lat, lon);
+    }
+    count
+}
+
+/// Exact (lat, lon) from a node's `lat`/`lon` attributes
+fn position(node: roxmltree::Node) -> Result<(BigRational, BigRational), String> {
+    let coord = |name: &str| {
+        node.attribute(name).and_then(parse_decimal).ok_or_else(|| {
+            format!(
+                "<{}> has no valid {} attribute",
+                node.tag_name().name(),
+                name
+            )
+        })
+    };
+    Ok((coord("lat")?, coord("lon")?))
+}
+
+/// Text of a named child element (e.g. GPX `<name>`)
+fn child_text<'a>(node: roxmltree::Node<'a, '_>, name: &str) -> Option<&'a str> {
+    node.children()
+        .find(|c| c.has_tag_name(name))
+        .and_then(|c| c.text())
+        .map(str::trim)
+}
+
+fn read_gpx(
+    root: roxmltree::Node,
+    fallback_name: &str,
+    overlay: &mut Overlay,
+) -> Result<(), String> {
+    for wpt in root.children().filter(|c| c.has_tag_name("wpt")) {
+        let (lat, lon) = position(wpt)?;
+        overlay.add_waypoint(child_text(wpt, "name").unwrap_or(""), lat, lon);
+    }
+
+    // Prefer a planned route; fall back to a recorded track
+    let (container, points): (_, Vec<_>) =
+        if let Some(rte) = root.children().find(|c| c.has_tag_name("rte")) {
+            let points = rte.children().filter(|c| c.has_tag_name("rtept")).collect();
+            (rte, points)
+        } else if let Some(trk) = root.children().find(|c| c.has_tag_name("trk")) {
+            let points = trk
+                .descendants()
+                .filter(|c| c.has_tag_name("trkpt"))
+                .collect();
+            (trk, points)
+        } else {
+            return Ok(());
+        };
+
+    let points = points
+        .into_iter()
+        .map(position)
+        .collect::<Result<Vec<_>, _>>()?;
+    let name = child_text(container, "name").unwrap_or(fallback_name);
+    overlay.add_route(name, points);
+    Ok(())
+}
+
+fn read_rtz(
+    root: roxmltree::Node,
+    fallback_name: &str,
+    overlay: &mut Overlay,
+) -> Result<(), String> {
+    let name = root
+        .children()
+        .find(|c| c.has_tag_name("routeInfo"))
+        .and_then(|info| info.attribute("routeName"))
+        .unwrap_or(fallback_name);
+
+    let points = root
+        .descendants()
+        .filter(|c| c.has_tag_name("waypoint"))
+        .filter_map(|wp| wp.children().find(|c| c.has_tag_name("position")))
+        .map(position)
+        .collect::<Result<Vec<_>, _>>()?;
+    overlay.add_route(name, points);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_traits::ToPrimitive;
+
+    /// (lat, lon) of each position of an overlay object, as f64
+    fn points(object: &s57_interp::overlay::MarinerObject) -> Vec<(f64, f64)> {
+        let positions = &object.positions;
+        positions
+            .lat
+            .iter()
+            .zip(positions.lon.iter())
+            .map(|(lat, lon)| (lat.to_f64().unwrap(), lon.to_f64().unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_gpx_route() {
+        let overlay = parse_route(
+            r#"<?xml version="1.0"?>
+            <gpx version="1.1" xmlns="http://www.topografix.com/GPX/1/1">
+              <wpt lat="50.9" lon="-1.4"><name>Start</name></wpt>
+              <rte>
+                <name>Solent</name>
+                <rtept lat="50.8" lon="-1.3"/>
+                <rtept lat="50.75" lon="-1.1"/>
+              </rte>
+              <trk><trkseg><trkpt lat="0" lon="0"/></trkseg></trk>
+            </gpx>"#,
+            "fallback",
+        )
+        .unwrap();
+
+        let waypoint = overlay.of_kind(MarinerObjectKind::Waypoint).next().unwrap();
+        assert_eq!(waypoint.name, "Start");
+        assert_eq!(points(waypoint), [(50.9, -1.4)]);
+
+        // The planned route wins over the track
+        let routes: Vec<_> = overlay.of_kind(MarinerObjectKind::Route).collect();
+        assert_eq!(routes.len(), 1);
+        assert_eq!(routes[0].name, "Solent");
+        assert_eq!(points(routes[0]), [(50.8, -1.3), (50.75, -1.1)]);
+    }
+
+    #[test]
+    fn test_parse_gpx_track() {
+        let overlay = parse_route(
+            r#"<gpx version="1.1">
+              <trk>
+                <trkseg><trkpt lat="50.1" lon="-1.0"/><trkpt lat="50.2" lon="-1.0"/></trkseg>
+                <trkseg><trkpt lat="50.3" lon="-0.9"/></trkseg>
+              </trk>
+            </gpx>"#,
+            "passage",
+        )
+        .unwrap();
+
+        // Unnamed, so the file name is used; segments are joined
+        let route = overlay.of_kind(MarinerObjectKind::Route).next().unwrap();
+        assert_eq!(route.name, "passage");
+        assert_eq!(points(route), [(50.1, -1.0), (50.2, -1.0), (50.3, -0.9)]);
+    }
+
+    #[test]
+    fn test_parse_rtz() {
+        let overlay = parse_route(
+            r#"<route version="1.1" xmlns="http://www.cirm.org/RTZ/1/1">
+              <routeInfo routeName="Dover Strait"/>
+              <waypoints>
+                <waypoint id="1"><position lat="51.1" lon="1.3"/></waypoint>
+                <waypoint id="2"><position lat="50.95" lon="1.85"/></waypoint>
+              </waypoints>
+            </route>"#,
+            "fallback",
+        )
+        .unwrap();
+
+        let route = overlay.of_kind(MarinerObjectKind::Route).next().unwrap();
+        assert_eq!(route.name, "Dover Strait");
+        assert_eq!(points(route), [(51.1, 1.3), (50.95, 1.85)]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |text| parse_route(text, "route").unwrap_err();
+
+        // Not well-formed XML
+        assert!(!error("<gpx><rte></gpx>").is_empty());
+        assert_eq!(error("<kml/>"), "unsupported route file root element <kml>");
+        assert_eq!(
+            error(r#"<gpx><wpt lat="50" lon="-1"/></gpx>"#),
+            "no route found"
+        );
+        assert_eq!(
+            error(r#"<gpx><rte><rtept lat="north" lon="-1"/></rte></gpx>"#),
+            "<rtept> has no valid lat attribute"
+        );
+        assert_eq!(
+            error(
+                r#"<route><waypoints><waypoint><position lat="51"/></waypoint></waypoints></route>"#
+            ),
+            "<position> has no valid lon attribute"
+        );
+    }
+}
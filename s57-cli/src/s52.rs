@@ -0,0 +1,832 @@
+//! S-52 presentation: colour tables and look-up tables
+//!
+//! The `s52-day`, `s52-dusk` and `s52-night` palettes style features the way
+//! an ECDIS does. Each feature is looked up by object class and attributes in
+//! a Presentation Library look-up table (points, lines or areas, by PRIM),
+//! and the entry's symbology instructions give its colours:
+//! - `AC(colour[,transparency])` fills an area
+//! - `LS(SOLD|DASH|DOTT,width,colour)` strokes a line or area boundary
+//! - `LC(name)` strokes a complex line, drawn as a plain dashed line
+//! - `SY(name)` places a symbol, drawn as a dot in the feature's COLOUR
+//! - `CS(name)` runs a conditional symbology procedure; DEPARE, DEPCNT,
+//!   LIGHTS, OBSTRN, WRECKS and SOUNDG are approximated, others draw nothing
+//!
+//! Area patterns (AP) and text (TX, TE) are accepted but not drawn.
+//!
+//! Colours are S-52 colour tokens (CHBLK, DEPDW, ...) resolved in the day,
+//! dusk or night colour table. The values are sRGB approximations of the
+//! CIE coordinates in the Presentation Library.
+//!
+//! Look-up tables use the Presentation Library CSV layout, one entry per
+//! line: object class, attribute conditions, instructions, display priority,
+//! radar flag, display category and viewing group.
+//!
+//! ```text
+//! "BOYLAT","CATLAM1|COLOUR3,4,3","SY(BOYLAT13)","8","O","STANDARD","27010"
+//! ```
+//!
+//! An attribute condition is an acronym followed by the value to match, `?`
+//! for an unknown (empty or missing) value, or nothing for any value. Of the
+//! entries for a class whose conditions all hold, the one with the most
+//! conditions wins; `######` is the entry for classes not in the table.
+//!
+//! The built-in tables (`s52/*.csv`) cover the classes the presets draw; the
+//! Presentation Library's own tables parse the same way.
+
+use num_rational::BigRational;
+use s57_catalogue::{AttributeInfo, ObjectClass};
+use s57_interp::depth::{DepthBand, DepthThresholds};
+use s57_interp::ecs::{parse_decimal, FeatureAttributes};
+use std::collections::HashMap;
+
+/// COLOUR: Colour
+const ATTL_COLOUR: u16 = 75;
+/// DRVAL1: Depth range value 1
+const ATTL_DRVAL1: u16 = 87;
+/// DRVAL2: Depth range value 2
+const ATTL_DRVAL2: u16 = 88;
+
+/// Shallow contour splitting the shallow band into DEPVS and DEPMS (metres)
+const SHALLOW_CONTOUR: i64 = 2;
+/// Safety contour used when none is given, as on most ECDIS
+const DEFAULT_SAFETY_CONTOUR: i64 = 30;
+
+/// Dash patterns for LS line styles, in pixels
+const DASH: &str = "6,3";
+const DOT: &str = "1.5,3";
+
+/// ColourScheme: Which S-52 colour table to use
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ColourScheme {
+    Day,
+    Dusk,
+    Night,
+}
+
+/// S-52 colour tokens with their day, dusk and night colours
+const COLOURS: &[(&str, [&str; 3])] = &[
+    ("NODTA", ["#a3b4b7", "#414849", "#151818"]),
+    ("CHBLK", ["#070707", "#a3b4b7", "#292d2e"]),
+    ("CHGRD", ["#7d898c", "#7d898c", "#292d2e"]),
+    ("CHGRF", ["#a3b4b7", "#414849", "#141617"]),
+    ("CHRED", ["#f15469", "#bd4354", "#3c151a"]),
+    ("CHGRN", ["#68e456", "#52b344", "#1a3915"]),
+    ("CHYLW", ["#f4da48", "#c0ac39", "#3d3712"]),
+    ("CHMGD", ["#c545c3", "#9b369a", "#311131"]),
+    ("CHMGF", ["#d3a6e9", "#54425d", "#1a151d"]),
+    ("CHBRN", ["#b19139", "#645220", "#2c240e"]),
+    ("CHWHT", ["#d4eaee", "#a3b4b7", "#292d2e"]),
+    ("DEPDW", ["#d4eaee", "#000000", "#000000"]),
+    ("DEPMD", ["#bad5e1", "#0d1213", "#06090c"]),
+    ("DEPMS", ["#98c5f2", "#19242f", "#090e14"]),
+    ("DEPVS", ["#73b6ef", "#273a4d", "#0d151e"]),
+    ("DEPIT", ["#83b295", "#25342a", "#0c120e"]),
+    ("DEPSC", ["#525a5c", "#a3b4b7", "#292d2e"]),
+    ("DEPCN", ["#7d898c", "#525a5c", "#141617"]),
+    ("LANDA", ["#c9b97a", "#3c3725", "#1e1b0e"]),
+    ("LANDF", ["#8b661f", "#594114", "#2c200a"]),
+    ("CSTLN", ["#525a5c", "#a3b4b7", "#292d2e"]),
+    ("SNDG1", ["#7d898c", "#7d898c", "#141617"]),
+    ("SNDG2", ["#070707", "#a3b4b7", "#292d2e"]),
+    ("LITRD", ["#f15469", "#bd4354", "#3c151a"]),
+    ("LITGN", ["#68e456", "#52b344", "#1a3915"]),
+    ("LITYW", ["#f4da48", "#c0ac39", "#3d3712"]),
+    ("TRFCD", ["#c545c3", "#9b369a", "#311131"]),
+    ("TRFCF", ["#d3a6e9", "#54425d", "#1a151d"]),
+    ("RESBL", ["#3a78f0", "#2e5fbe", "#0f1e3c"]),
+    ("ISDNG", ["#c545c3", "#9b369a", "#311131"]),
+    ("DNGHL", ["#f15469", "#bd4354", "#3c151a"]),
+];
+
+/// Colour of a token in a scheme; None for tokens not in the table
+fn colour(token: &str, scheme: ColourScheme) -> Option<&'static str> {
+    let (_, colours) = COLOURS.iter().find(|(name, _)| *name == token)?;
+    Some(colours[scheme as usize])
+}
+
+/// Colour tokens for COLOUR attribute values 1-13 (white, black, red,
+/// green, blue, yellow, grey, brown, amber, violet, orange, magenta, pink)
+const SYMBOL_COLOURS: [&str; 13] = [
+    "CHWHT", "CHBLK", "CHRED", "CHGRN", "RESBL", "CHYLW", "CHGRD", "CHBRN", "CHYLW", "CHMGD",
+    "CHYLW", "CHMGD", "CHMGD",
+];
+
+/// LineStyle: LS line pattern
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum LineStyle {
+    Solid,
+    Dash,
+    Dot,
+}
+
+/// Instruction: One symbology instruction of a look-up entry
+#[derive(Clone, Debug, PartialEq)]
+enum Instruction {
+    /// SY: point symbol
+    Symbol,
+    /// LS: simple line style
+    Line {
+        style: LineStyle,
+        width: u8,
+        colour: &'static str,
+    },
+    /// LC: complex line style, by name
+    ComplexLine(String),
+    /// AC: area colour fill, transparency 0 (opaque) to 3 (75%)
+    AreaColour {
+        colour: &'static str,
+        transparency: u8,
+    },
+    /// AP: area pattern fill
+    AreaPattern,
+    /// TX, TE: text
+    Text,
+    /// CS: conditional symbology procedure, by name
+    Procedure(String),
+}
+
+/// Condition: What an attribute condition requires of the value
+#[derive(Clone, Debug, PartialEq)]
+enum Condition {
+    /// Present, with any value
+    Any,
+    /// Missing, or present with an empty value
+    Unknown,
+    /// Present with exactly this value
+    Equals(String),
+}
+
+/// LookupEntry: One line of a look-up table
+#[derive(Clone, Debug)]
+struct LookupEntry {
+    conditions: Vec<(u16, Condition)>,
+    instructions: Vec<Instruction>,
+    /// Display priority, 0-9
+    priority: u8,
+}
+
+impl LookupEntry {
+    fn matches(&self, attrs: Option<&FeatureAttributes>) -> bool {
+        self.conditions.iter().all(|(attl, condition)| {
+            let value = attrs.and_then(|a| a.get(*attl));
+            match condition {
+                Condition::Any => value.is_some(),
+                Condition::Unknown => value.is_none_or(|v| v.trim().is_empty()),
+                Condition::Equals(expected) => value.is_some_and(|v| v.trim() == expected),
+            }
+        })
+    }
+}
+
+/// LookupTable: Look-up entries by object class
+#[derive(Clone, Debug, Default)]
+pub struct LookupTable {
+    entries: HashMap<u16, Vec<LookupEntry>>,
+    /// The `######` entry, for classes without entries of their own
+    fallback: Option<LookupEntry>,
+}
+
+impl LookupTable {
+    /// Parse a look-up table in Presentation Library CSV layout; errors are
+    /// prefixed with the line number
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut table = LookupTable::default();
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let (class, entry) = parse_entry(line).map_err(|e| format!("{}: {}", i + 1, e))?;
+            match class {
+                Some(objl) => table.entries.entry(objl).or_default().push(entry),
+                None => table.fallback = Some(entry),
+            }
+        }
+        Ok(table)
+    }
+
+    /// The entry for a feature: the matching entry with the most conditions,
+    /// earliest in the table on ties
+    fn lookup(&self, objl: u16, attrs: Option<&FeatureAttributes>) -> Option<&LookupEntry> {
+        let Some(entries) = self.entries.get(&objl) else {
+            return self.fallback.as_ref();
+        };
+        entries
+            .iter()
+            .filter(|entry| entry.matches(attrs))
+            .rev()
+            .max_by_key(|entry| entry.conditions.len())
+    }
+}
+
+/// Split a CSV line into its quoted fields
+fn csv_fields(line: &str) -> Result<Vec<&str>, String> {
+    let mut fields = Vec::new();
+    let mut rest = line;
+    loop {
+        let inner = rest
+            .strip_prefix('"')
+            .ok_or_else(|| format!("expected a quoted field at '{}'", rest))?;
+        let end = inner.find('"').ok_or("unterminated quoted field")?;
+        fields.push(&inner[..end]);
+        rest = &inner[end + 1..];
+        match rest.strip_prefix(',') {
+            Some(next) => rest = next,
+            None if rest.is_empty() => return Ok(fields),
+            None => return Err(format!("expected ',' at '{}'", rest)),
+        }
+    }
+}
+
+/// Parse one look-up line; the class is None for the `######` entry
+fn parse_entry(line: &str) -> Result<(Option<u16>, LookupEntry), String> {
+    let fields = csv_fields(line)?;
+    let [class, conditions, instructions, priority, radar, category, group] = fields[..] else {
+        return Err(format!("expected 7 fields, found {}", fields.len()));
+    };
+
+    let class = match class {
+        "######" => None,
+        acronym => Some(
+            acronym
+                .parse::<ObjectClass>()
+                .map_err(|_| format!("unknown object class '{}'", acronym))?
+                .code(),
+        ),
+    };
+    let conditions = conditions
+        .split('|')
+        .filter(|c| !c.trim().is_empty())
+        .map(parse_condition)
+        .collect::<Result<_, _>>()?;
+    let instructions = split_instructions(instructions)
+        .into_iter()
+        .map(parse_instruction)
+        .collect::<Result<_, _>>()?;
+    let priority = priority
+        .parse()
+        .ok()
+        .filter(|p| *p <= 9)
+        .ok_or_else(|| format!("invalid display priority '{}'", priority))?;
+    if !matches!(radar, "O" | "S") {
+        return Err(format!("invalid radar flag '{}'", radar));
+    }
+    if !matches!(
+        category,
+        "DISPLAYBASE" | "STANDARD" | "OTHER" | "MARINERS_STANDARD" | "MARINERS_OTHER"
+    ) {
+        return Err(format!("invalid display category '{}'", category));
+    }
+    if group.parse::<u32>().is_err() {
+        return Err(format!("invalid viewing group '{}'", group));
+    }
+
+    Ok((
+        class,
+        LookupEntry {
+            conditions,
+            instructions,
+            priority,
+        },
+    ))
+}
+
+/// `ACRONYMvalue`, `ACRONYM?` or `ACRONYM`
+fn parse_condition(condition: &str) -> Result<(u16, Condition), String> {
+    let condition = condition.trim();
+    let split = condition.len().min(6);
+    let (acronym, value) = condition
+        .split_at_checked(split)
+        .ok_or_else(|| format!("invalid attribute condition '{}'", condition))?;
+    let attl = AttributeInfo::from_acronym(acronym)
+        .ok_or_else(|| format!("unknown attribute '{}'", acronym))?
+        .code;
+    let condition = match value {
+        "" => Condition::Any,
+        "?" => Condition::Unknown,
+        value => Condition::Equals(value.to_string()),
+    };
+    Ok((attl, condition))
+}
+
+/// Instructions separated by ';', ignoring any inside parentheses or quotes
+fn split_instructions(text: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let (mut depth, mut quoted, mut start) = (0, false, 0);
+    for (i, c) in text.char_indices() {
+        match c {
+            '\'' => quoted = !quoted,
+            '(' if !quoted => depth += 1,
+            ')' if !quoted => depth -= 1,
+            ';' if !quoted && depth == 0 => {
+                parts.push(&text[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&text[start..]);
+    parts.retain(|p| !p.trim().is_empty());
+    parts
+}
+
+fn parse_instruction(text: &str) -> Result<Instruction, String> {
+    let text = text.trim();
+    let (name, args) = text
+        .strip_suffix(')')
+        .and_then(|t| t.split_once('('))
+        .ok_or_else(|| format!("invalid instruction '{}'", text))?;
+    let args: Vec<&str> = args.split(',').map(str::trim).collect();
+    let token = |token: &str| {
+        COLOURS
+            .iter()
+            .find(|(name, _)| *name == token)
+            .map(|(name, _)| *name)
+            .ok_or_else(|| format!("unknown colour token '{}' in '{}'", token, text))
+    };
+    match (name, args.as_slice()) {
+        ("SY", [_, ..]) => Ok(Instruction::Symbol),
+        ("LS", [style, width, colour]) => Ok(Instruction::Line {
+            style: match *style {
+                "SOLD" => LineStyle::Solid,
+                "DASH" => LineStyle::Dash,
+                "DOTT" => LineStyle::Dot,
+                other => return Err(format!("unknown line style '{}'", other)),
+            },
+            width: width
+                .parse()
+                .map_err(|_| format!("invalid line width in '{}'", text))?,
+            colour: token(colour)?,
+        }),
+        ("LC", [name]) => Ok(Instruction::ComplexLine(name.to_string())),
+        ("AC", [colour]) => Ok(Instruction::AreaColour {
+            colour: token(colour)?,
+            transparency: 0,
+        }),
+        ("AC", [colour, transparency]) => Ok(Instruction::AreaColour {
+            colour: token(colour)?,
+            transparency: transparency
+                .parse()
+                .ok()
+                .filter(|t| *t <= 3)
+                .ok_or_else(|| format!("invalid transparency in '{}'", text))?,
+        }),
+        ("AP", [_, ..]) => Ok(Instruction::AreaPattern),
+        ("TX" | "TE", _) => Ok(Instruction::Text),
+        ("CS", [name]) => Ok(Instruction::Procedure(name.to_string())),
+        _ => Err(format!("invalid instruction '{}'", text)),
+    }
+}
+
+/// LineStroke: Colour, width and dash pattern of a line
+#[derive(Clone, Debug, PartialEq)]
+pub struct LineStroke {
+    pub colour: &'static str,
+    pub width: f64,
+    /// SVG stroke-dasharray; None for a solid line
+    pub dash: Option<&'static str>,
+}
+
+/// AreaStyle: Fill and boundary of an area
+#[derive(Clone, Debug, PartialEq)]
+pub struct AreaStyle {
+    /// SVG fill, "none" for no fill
+    pub fill: String,
+    pub boundary: Option<LineStroke>,
+}
+
+/// S52Style: Look-up tables and colour scheme for rendering
+pub struct S52Style {
+    scheme: ColourScheme,
+    points: LookupTable,
+    lines: LookupTable,
+    areas: LookupTable,
+    /// Safety and deep contours for depth shading (metres)
+    depth_thresholds: DepthThresholds,
+}
+
+impl S52Style {
+    /// The built-in look-up tables in a colour scheme
+    ///
+    /// Depth areas are shaded against `safety_contour`, or 30 m if None.
+    pub fn new(scheme: ColourScheme, safety_contour: Option<BigRational>) -> Self {
+        let table = |name, text| {
+            LookupTable::parse(text)
+                .unwrap_or_else(|e| panic!("built-in {} look-up table, line {}", name, e))
+        };
+        Self {
+            scheme,
+            points: table("point", POINT_LOOKUP),
+            lines: table("line", LINE_LOOKUP),
+            areas: table("area", AREA_LOOKUP),
+            depth_thresholds: DepthThresholds::new(
+                safety_contour
+                    .unwrap_or_else(|| BigRational::from_integer(DEFAULT_SAFETY_CONTOUR.into())),
+            ),
+        }
+    }
+
+    /// Colour for areas without data, used as the background
+    pub fn background(&self) -> &'static str {
+        self.colour("NODTA")
+    }
+
+    /// Display priority from the look-up table; None if there is no entry
+    pub fn priority(&self, objl: u16, prim: u8, attrs: Option<&FeatureAttributes>) -> Option<u8> {
+        self.entry(objl, prim, attrs).map(|entry| entry.priority)
+    }
+
+    /// Fill colour of a point feature's symbol
+    pub fn point(&self, objl: u16, attrs: Option<&FeatureAttributes>) -> &'static str {
+        let mut token = "CHBLK";
+        for instruction in self.instructions(objl, 1, attrs) {
+            match instruction {
+                Instruction::Symbol => token = symbol_colour(attrs),
+                Instruction::Procedure(name) => match procedure(name) {
+                    "LIGHTS" => token = light_colour(attrs),
+                    "SOUNDG" => token = "SNDG2",
+                    "OBSTRN" | "WRECKS" => token = "CHBLK",
+                    _ => {}
+                },
+                _ => {}
+            }
+        }
+        self.colour(token)
+    }
+
+    /// Stroke of a line feature; None if the table draws nothing
+    ///
+    /// The safety contour is always drawn, bold in DEPSC.
+    pub fn line(
+        &self,
+        objl: u16,
+        attrs: Option<&FeatureAttributes>,
+        safety_contour: bool,
+        safety_width: f64,
+    ) -> Option<LineStroke> {
+        if safety_contour {
+            return Some(LineStroke {
+                colour: self.colour("DEPSC"),
+                width: safety_width,
+                dash: None,
+            });
+        }
+        let mut stroke = None;
+        for instruction in self.instructions(objl, 2, attrs) {
+            if let Some(s) = self.stroke(instruction) {
+                stroke = Some(s);
+            }
+        }
+        stroke
+    }
+
+    /// Fill and boundary of an area feature
+    pub fn area(&self, objl: u16, attrs: Option<&FeatureAttributes>) -> AreaStyle {
+        let mut style = AreaStyle {
+            fill: "none".to_string(),
+            boundary: None,
+        };
+        for instruction in self.instructions(objl, 3, attrs) {
+            match instruction {
+                Instruction::AreaColour {
+                    colour,
+                    transparency,
+                } => style.fill = self.fill(colour, *transparency),
+                Instruction::Procedure(name) => match procedure(name) {
+                    "DEPARE" => style.fill = self.fill(self.depth_shade(attrs), 0),
+                    "OBSTRN" | "WRECKS" => {
+                        style.fill = self.fill("DEPVS", 0);
+                        style.boundary = Some(LineStroke {
+                            colour: self.colour("CHBLK"),
+                            width: 2.0,
+                            dash: Some(DOT),
+                        });
+                    }
+                    _ => {}
+                },
+                other => {
+                    if let Some(stroke) = self.stroke(other) {
+                        style.boundary = Some(stroke);
+                    }
+                }
+            }
+        }
+        style
+    }
+
+    fn entry(
+        &self,
+        objl: u16,
+        prim: u8,
+        attrs: Option<&FeatureAttributes>,
+    ) -> Option<&LookupEntry> {
+        let table = match prim {
+            1 => &self.points,
+            2 => &self.lines,
+            3 => &self.areas,
+            _ => return None,
+        };
+        table.lookup(objl, attrs)
+    }
+
+    fn instructions(
+        &self,
+        objl: u16,
+        prim: u8,
+        attrs: Option<&FeatureAttributes>,
+    ) -> &[Instruction] {
+        self.entry(objl, prim, attrs)
+            .map_or(&[], |entry| entry.instructions.as_slice())
+    }
+
+    /// Stroke for LS, LC and line-drawing procedures
+    fn stroke(&self, instruction: &Instruction) -> Option<LineStroke> {
+        match instruction {
+            Instruction::Line {
+                style,
+                width,
+                colour,
+            } => Some(LineStroke {
+                colour: self.colour(colour),
+                width: f64::from(*width),
+                dash: match style {
+                    LineStyle::Solid => None,
+                    LineStyle::Dash => Some(DASH),
+                    LineStyle::Dot => Some(DOT),
+                },
+            }),
+            Instruction::ComplexLine(name) => {
+                // Overhead cables and pipes are grey; other complex lines
+                // (submarine cables, limits) are magenta
+                let token = if name.starts_with("CBLOHD") || name.starts_with("PIPOHD") {
+                    "CHGRD"
+                } else {
+                    "CHMGD"
+                };
+                Some(LineStroke {
+                    colour: self.colour(token),
+                    width: 1.0,
+                    dash: Some(DASH),
+                })
+            }
+            Instruction::Procedure(name) => match procedure(name) {
+                "DEPCNT" => Some(LineStroke {
+                    colour: self.colour("DEPCN"),
+                    width: 1.0,
+                    dash: None,
+                }),
+                "OBSTRN" | "WRECKS" => Some(LineStroke {
+                    colour: self.colour("CHBLK"),
+                    width: 2.0,
+                    dash: Some(DOT),
+                }),
+                "QUAPOS" | "SLCONS" => Some(LineStroke {
+                    colour: self.colour("CSTLN"),
+                    width: 1.0,
+                    dash: None,
+                }),
+                _ => None,
+            },
+            Instruction::Symbol
+            | Instruction::AreaColour { .. }
+            | Instruction::AreaPattern
+            | Instruction::Text => None,
+        }
+    }
+
+    /// Four-shade depth colour of a depth area (S-52 DEPARE procedure)
+    fn depth_shade(&self, attrs: Option<&FeatureAttributes>) -> &'static str {
+        let depth = |attl| attrs.and_then(|a| a.get(attl)).and_then(parse_decimal);
+        let drval1 = depth(ATTL_DRVAL1);
+        let drval2 = depth(ATTL_DRVAL2);
+        match DepthBand::classify(drval1.as_ref(), drval2.as_ref(), &self.depth_thresholds) {
+            DepthBand::Deep => "DEPDW",
+            DepthBand::Safe => "DEPMD",
+            DepthBand::Shallow => {
+                let shallow = BigRational::from_integer(SHALLOW_CONTOUR.into());
+                let deeper =
+                    drval1.is_some_and(|d| d >= shallow) && drval2.is_none_or(|d| d > shallow);
+                if deeper {
+                    "DEPMS"
+                } else {
+                    "DEPVS"
+                }
+            }
+            DepthBand::Dry => "DEPIT",
+        }
+    }
+
+    /// An area fill, with transparency as an alpha channel
+    fn fill(&self, token: &str, transparency: u8) -> String {
+        let colour = self.colour(token);
+        match transparency {
+            0 => colour.to_string(),
+            t => format!("{}{:02x}", colour, 255 - 64 * u32::from(t)),
+        }
+    }
+
+    fn colour(&self, token: &str) -> &'static str {
+        // Tokens are checked when the table is parsed
+        colour(token, self.scheme).unwrap_or("#ff00ff")
+    }
+}
+
+/// Procedure name without its version number: DEPARE02 -> DEPARE
+fn procedure(name: &str) -> &str {
+    name.trim_end_matches(|c: char| c.is_ascii_digit())
+}
+
+/// Symbol colour token from the first value of COLOUR
+fn symbol_colour(attrs: Option<&FeatureAttributes>) -> &'static str {
+    first_colour(attrs)
+        .and_then(|id| SYMBOL_COLOURS.get(id.checked_sub(1)?))
+        .copied()
+        .unwrap_or("CHBLK")
+}
+
+/// Light colour token: red and green lights in their own colour, all other
+/// lights (white, yellow, orange) in LITYW as S-52 draws them
+fn light_colour(attrs: Option<&FeatureAttributes>) -> &'static str {
+    match first_colour(attrs) {
+        Some(3) => "LITRD",
+        Some(4) => "LITGN",
+        _ => "LITYW",
+    }
+}
+
+fn first_colour(attrs: Option<&FeatureAttributes>) -> Option<usize> {
+    attrs?
+        .get(ATTL_COLOUR)?
+        .split(',')
+        .next()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// Point look-up table (paper chart symbols)
+const POINT_LOOKUP: &str = include_str!("s52/points.csv");
+
+/// Line look-up table (simplified lines)
+const LINE_LOOKUP: &str = include_str!("s52/lines.csv");
+
+/// Area look-up table (plain boundaries)
+const AREA_LOOKUP: &str = include_str!("s52/areas.csv");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Look-up entries told apart by their display priority; `######` would
+    /// end a raw string, so the fallback line is escaped
+    const TABLE: &str = concat!(
+        r#"
+"BOYLAT","","SY(BOYLAT01)","1","O","STANDARD","27010"
+"BOYLAT","CATLAM1","SY(BOYLAT13)","2","O","STANDARD","27010"
+"BOYLAT","CATLAM1|COLOUR3","SY(BOYLAT14)","3","O","STANDARD","27010"
+"BOYLAT","CATLAM?","SY(BOYLAT23)","4","O","STANDARD","27010"
+"BOYLAT","OBJNAM","SY(BOYLAT24)","5","O","STANDARD","27010"
+"BOYCAR","CATCAM1","SY(BOYCAR01)","6","O","STANDARD","27010"
+"DEPARE","","AC(DEPDW);LS(SOLD,1,CHGRD)","7","S","DISPLAYBASE","13030"
+"#,
+        "\"######\",\"\",\"SY(QUESMRK1)\",\"8\",\"O\",\"OTHER\",\"21010\"\n"
+    );
+
+    fn code(class: &str) -> u16 {
+        class.parse::<ObjectClass>().unwrap().code()
+    }
+
+    fn attl(acronym: &str) -> u16 {
+        AttributeInfo::from_acronym(acronym).unwrap().code
+    }
+
+    fn attrs(attributes: &[(&str, &str)]) -> FeatureAttributes {
+        FeatureAttributes {
+            attf: attributes
+                .iter()
+                .map(|(acronym, value)| (attl(acronym), value.to_string()))
+                .collect(),
+            natf: Vec::new(),
+        }
+    }
+
+    /// Priority of the entry chosen for `class` with `attributes`
+    fn chosen(table: &LookupTable, class: &str, attributes: &[(&str, &str)]) -> Option<u8> {
+        table
+            .lookup(code(class), Some(&attrs(attributes)))
+            .map(|entry| entry.priority)
+    }
+
+    #[test]
+    fn test_most_specific_entry_wins() {
+        let table = LookupTable::parse(TABLE).unwrap();
+        assert_eq!(chosen(&table, "BOYLAT", &[("CATLAM", "3")]), Some(1));
+        assert_eq!(chosen(&table, "BOYLAT", &[("CATLAM", "1")]), Some(2));
+        assert_eq!(
+            chosen(&table, "BOYLAT", &[("CATLAM", "1"), ("COLOUR", "3")]),
+            Some(3)
+        );
+        // A condition on another value doesn't hold
+        assert_eq!(
+            chosen(&table, "BOYLAT", &[("CATLAM", "1"), ("COLOUR", "4")]),
+            Some(2)
+        );
+        // Ties go to the earliest entry
+        assert_eq!(
+            chosen(&table, "BOYLAT", &[("CATLAM", "1"), ("OBJNAM", "Red")]),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn test_empty_and_wildcard_values() {
+        let table = LookupTable::parse(TABLE).unwrap();
+        // `?` holds for a missing or empty value, but not for any other
+        assert_eq!(chosen(&table, "BOYLAT", &[]), Some(4));
+        assert_eq!(table.lookup(code("BOYLAT"), None).unwrap().priority, 4);
+        assert_eq!(chosen(&table, "BOYLAT", &[("CATLAM", " ")]), Some(4));
+        assert_eq!(chosen(&table, "BOYLAT", &[("CATLAM", "2")]), Some(1));
+        // A bare acronym holds for any value of a present attribute
+        assert_eq!(
+            chosen(&table, "BOYLAT", &[("CATLAM", "2"), ("OBJNAM", "No 7")]),
+            Some(5)
+        );
+
+        let entry = &table.entries[&code("BOYLAT")][4];
+        assert!(entry.matches(Some(&attrs(&[("OBJNAM", "Red")]))));
+        assert!(!entry.matches(Some(&attrs(&[("CATLAM", "1")]))));
+        assert!(!entry.matches(None));
+    }
+
+    #[test]
+    fn test_fallback_entry() {
+        let table = LookupTable::parse(TABLE).unwrap();
+        // Classes without entries get `######`, classes whose entries all
+        // fail get nothing
+        assert_eq!(chosen(&table, "LIGHTS", &[("COLOUR", "1")]), Some(8));
+        assert_eq!(chosen(&table, "BOYCAR", &[("CATCAM", "1")]), Some(6));
+        assert_eq!(chosen(&table, "BOYCAR", &[("CATCAM", "2")]), None);
+
+        let without = LookupTable::parse(TABLE.replace("\"######\"", "\"BOYSPP\"").as_str());
+        assert_eq!(chosen(&without.unwrap(), "LIGHTS", &[]), None);
+    }
+
+    #[test]
+    fn test_parse_instructions() {
+        let table = LookupTable::parse(TABLE).unwrap();
+        let depare = &table.entries[&code("DEPARE")][0];
+        assert!(depare.conditions.is_empty());
+        assert_eq!(
+            depare.instructions,
+            [
+                Instruction::AreaColour {
+                    colour: "DEPDW",
+                    transparency: 0
+                },
+                Instruction::Line {
+                    style: LineStyle::Solid,
+                    width: 1,
+                    colour: "CHGRD"
+                }
+            ]
+        );
+        let boylat = &table.entries[&code("BOYLAT")][2];
+        assert_eq!(
+            boylat.conditions,
+            [
+                (attl("CATLAM"), Condition::Equals("1".to_string())),
+                (attl("COLOUR"), Condition::Equals("3".to_string()))
+            ]
+        );
+        assert_eq!(boylat.instructions, [Instruction::Symbol]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |text: &str| LookupTable::parse(text).unwrap_err();
+        assert_eq!(
+            error("\n\"BOYLAT\",\"\",\"SY(A)\",\"12\",\"O\",\"STANDARD\",\"1\""),
+            "2: invalid display priority '12'"
+        );
+        assert_eq!(
+            error(r#""BOYXXX","","SY(A)","1","O","STANDARD","1""#),
+            "1: unknown object class 'BOYXXX'"
+        );
+        assert_eq!(
+            error(r#""BOYLAT","CATXXX1","SY(A)","1","O","STANDARD","1""#),
+            "1: unknown attribute 'CATXXX'"
+        );
+        assert_eq!(
+            error(r#""BOYLAT","","AC(NOSUCH)","1","O","STANDARD","1""#),
+            "1: unknown colour token 'NOSUCH' in 'AC(NOSUCH)'"
+        );
+        assert_eq!(
+            error(r#""BOYLAT","","SY(A)","1","O","STANDARD""#),
+            "1: expected 7 fields, found 6"
+        );
+        assert_eq!(
+            error(r#""BOYLAT","","SY(A)"#),
+            "1: unterminated quoted field"
+        );
+    }
+}
@@ -0,0 +1,116 @@
+//! `schema` and `schema-diff`: the field definitions a cell's DDR declares
+//!
+//! Producers don't always define fields as S-57 does, and a definition the
+//! parser can't use is quietly replaced by the built-in one. The schema dump
+//! shows each field as it will be decoded and whether it matches the
+//! standard; `schema-diff` compares two cells' DDRs directly.
+
+use crate::export::read_cell;
+use s57_parse::ddr::DDR;
+use s57_parse::ddr_diff::DdrDifference;
+use s57_parse::S57File;
+use serde_json::{json, Value};
+use std::collections::BTreeSet;
+use std::path::Path;
+
+/// Print FILE's DDR field and subfield definitions as JSON
+///
+/// A cell without a usable DDR is described by the built-in definitions,
+/// with `"ddr": false`.
+pub fn print_schema(path: &Path, file: &S57File) {
+    let (ddr, from_file) = match load_ddr(file) {
+        Some(ddr) => (ddr, true),
+        None => (DDR::canonical(), false),
+    };
+
+    // Fields that decode differently from the built-in definitions; being
+    // built in rather than defined doesn't count
+    let canonical = DDR::canonical();
+    let nonstandard: BTreeSet<String> = canonical
+        .diff(&ddr)
+        .iter()
+        .filter(|d| !matches!(d, DdrDifference::BuiltIn { .. }))
+        .map(|d| d.tag().to_string())
+        .collect();
+
+    let mut fields: Vec<_> = ddr
+        .field_defs()
+        .iter()
+        .filter(|(tag, _)| !tag.starts_with('0'))
+        .collect();
+    fields.sort_by(|a, b| a.0.cmp(b.0));
+    let fields: Vec<Value> = fields
+        .into_iter()
+        .map(|(tag, def)| {
+            let standard = canonical
+                .get_field_def(tag)
+                .map(|_| !nonstandard.contains(tag.as_str()));
+            let subfields: Vec<Value> = def
+                .subfields
+                .iter()
+                .map(|subfield| {
+                    json!({
+                        "label": subfield.label,
+                        "format": subfield.format_spec(),
+                        "width": subfield.width,
+                    })
+                })
+                .collect();
+            json!({
+                "tag": tag,
+                "name": def.name,
+                "repeating": def.is_repeating,
+                "built_in": ddr.is_fallback(tag),
+                "standard": standard,
+                "array_descriptor": def.array_descriptor,
+                "format_controls": def.format_controls,
+                "subfields": subfields,
+            })
+        })
+        .collect();
+
+    let schema = json!({
+        "file": path.display().to_string(),
+        "ddr": from_file,
+        "fields": fields,
+    });
+    println!("{}", serde_json::to_string_pretty(&schema).unwrap());
+}
+
+/// Print how the DDRs of two cells define their fields differently
+///
+/// A cell without a usable DDR is compared by the built-in definitions.
+pub fn schema_diff(first: &Path, second: &Path) {
+    let ddr = |path: &Path| {
+        load_ddr(&read_cell(path)).unwrap_or_else(|| {
+            eprintln!(
+                "Warning: {} has no usable DDR, comparing the built-in definitions",
+                path.display()
+            );
+            DDR::canonical()
+        })
+    };
+    let differences = ddr(first).diff(&ddr(second));
+
+    println!("{} vs {}", first.display(), second.display());
+    if differences.is_empty() {
+        println!("Field definitions are the same");
+        return;
+    }
+    for difference in &differences {
+        println!("  {}", difference);
+    }
+    println!("{} difference(s)", differences.len());
+}
+
+/// The cell's DDR, warning if it has one that doesn't parse
+fn load_ddr(file: &S57File) -> Option<DDR> {
+    let record = file.records().first().filter(|r| r.leader.is_ddr())?;
+    match DDR::parse(record) {
+        Ok(ddr) => Some(ddr),
+        Err(e) => {
+            eprintln!("Warning: Failed to parse DDR: {}", e);
+            None
+        }
+    }
+}
@@ -0,0 +1,191 @@
+//! Object class selection for --classes
+//!
+//! Each comma-separated item is one of:
+//! - an acronym from the object catalogue (`DEPARE`)
+//! - a numeric OBJL code (`42`), which also covers codes not in the catalogue
+//! - an acronym pattern with `*` and `?` wildcards (`BOY*`, `M_*`)
+//! - a named group (`aids-to-navigation`, `dangers`, `depths`, `land`, `meta`)
+//!
+//! Matching is case-insensitive.
+
+use log::warn;
+use s57_catalogue::decode_object;
+use std::collections::HashSet;
+
+/// Named groups and the items they stand for
+const GROUPS: &[(&str, &[&str])] = &[
+    (
+        "aids-to-navigation",
+        &[
+            "BCN*", "BOY*", "LIGHTS", "LITFLT", "LITVES", "DAYMAR", "FOGSIG", "TOPMAR", "RTPBCN",
+        ],
+    ),
+    ("dangers", &["UWTROC", "WRECKS", "OBSTRN"]),
+    ("depths", &["DEPARE", "DEPCNT", "DRGARE", "SOUNDG"]),
+    ("land", &["COALNE", "LNDARE", "LNDRGN", "SLCONS"]),
+    ("meta", &["M_*"]),
+];
+
+/// ClassSelector: A set of object classes chosen on the command line
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClassSelector {
+    codes: HashSet<u16>,
+}
+
+impl ClassSelector {
+    /// Resolve selector items into object class codes
+    ///
+    /// Items that select nothing are logged and skipped. Fails only if no
+    /// item selects anything.
+    pub fn parse<S: AsRef<str>>(items: &[S]) -> Result<Self, String> {
+        let mut selector = ClassSelector::default();
+        for item in items {
+            let item = item.as_ref().trim();
+            if item.is_empty() {
+                continue;
+            }
+            if !selector.add(item) {
+                warn!("'{}' matches no object class, skipping", item);
+            }
+        }
+        if selector.codes.is_empty() {
+            return Err("No valid object classes specified".to_string());
+        }
+        Ok(selector)
+    }
+
+    /// Consume the selector, returning its OBJL codes
+    pub fn into_codes(self) -> HashSet<u16> {
+        self.codes
+    }
+
+    /// Add whatever one item selects; false if it selects nothing
+    fn add(&mut self, item: &str) -> bool {
+        if let Ok(code) = item.parse::<u16>() {
+            self.codes.insert(code);
+            return true;
+        }
+        if let Some((_, members)) = GROUPS
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(item))
+        {
+            // Evaluate every member, even once one has matched
+            let mut matched = false;
+            for member in *members {
+                matched |= self.add(member);
+            }
+            return matched;
+        }
+
+        let pattern = item.to_ascii_uppercase();
+        let mut matched = false;
+        for code in 1..=u16::MAX {
+            let Some(class) = decode_object(code) else {
+                continue;
+            };
+            if wildcard_match(pattern.as_bytes(), class.to_string().as_bytes()) {
+                self.codes.insert(code);
+                matched = true;
+            }
+        }
+        matched
+    }
+}
+
+/// Glob match with `*` (any run) and `?` (any one character)
+fn wildcard_match(pattern: &[u8], text: &[u8]) -> bool {
+    match (pattern.first(), text.first()) {
+        (None, None) => true,
+        (Some(b'*'), _) => {
+            wildcard_match(&pattern[1..], text)
+                || (!text.is_empty() && wildcard_match(pattern, &text[1..]))
+        }
+        (Some(b'?'), Some(_)) => wildcard_match(&pattern[1..], &text[1..]),
+        (Some(p), Some(t)) if p == t => wildcard_match(&pattern[1..], &text[1..]),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use s57_catalogue::ObjectClass;
+
+    /// Acronyms of the classes `items` select, sorted
+    fn classes(items: &[&str]) -> Vec<String> {
+        let mut acronyms: Vec<String> = ClassSelector::parse(items)
+            .unwrap()
+            .into_codes()
+            .into_iter()
+            .map(|code| match decode_object(code) {
+                Some(class) => class.to_string(),
+                None => code.to_string(),
+            })
+            .collect();
+        acronyms.sort();
+        acronyms
+    }
+
+    #[test]
+    fn test_acronyms_and_codes() {
+        assert_eq!(classes(&["DEPARE"]), ["DEPARE"]);
+        assert_eq!(classes(&["lights", " Wrecks "]), ["LIGHTS", "WRECKS"]);
+        let depare = "DEPARE".parse::<ObjectClass>().unwrap().code();
+        assert_eq!(classes(&[&depare.to_string()]), ["DEPARE"]);
+        // Codes outside the catalogue are kept as they are
+        assert_eq!(classes(&["9999"]), ["9999"]);
+    }
+
+    #[test]
+    fn test_wildcards() {
+        assert_eq!(
+            classes(&["boy*"]),
+            ["BOYCAR", "BOYINB", "BOYISD", "BOYLAT", "BOYSAW", "BOYSPP"]
+        );
+        assert_eq!(classes(&["BOYLA?"]), ["BOYLAT"]);
+        assert_eq!(classes(&["*CNT"]), ["DEPCNT"]);
+        let meta = classes(&["M_*"]);
+        assert!(meta.contains(&"M_COVR".to_string()));
+        assert!(meta.iter().all(|acronym| acronym.starts_with("M_")));
+    }
+
+    #[test]
+    fn test_groups() {
+        assert_eq!(classes(&["Dangers"]), ["OBSTRN", "UWTROC", "WRECKS"]);
+        assert_eq!(
+            classes(&["depths", "land", "DEPARE"]),
+            ["COALNE", "DEPARE", "DEPCNT", "DRGARE", "LNDARE", "LNDRGN", "SLCONS", "SOUNDG"]
+        );
+        let aids = classes(&["aids-to-navigation"]);
+        assert!(aids.contains(&"BCNCAR".to_string()));
+        assert!(aids.contains(&"BOYLAT".to_string()));
+        assert!(aids.contains(&"LIGHTS".to_string()));
+        assert_eq!(classes(&["meta"]), classes(&["M_*"]));
+    }
+
+    #[test]
+    fn test_unmatched_items() {
+        // Items that match nothing are skipped while others select
+        assert_eq!(classes(&["NOSUCH", "", "LIGHTS", "Q*"]), ["LIGHTS"]);
+        assert_eq!(
+            ClassSelector::parse(&["NOSUCH", "X?"]),
+            Err("No valid object classes specified".to_string())
+        );
+        assert!(ClassSelector::parse::<&str>(&[]).is_err());
+        assert!(ClassSelector::parse(&[" ", "70000"]).is_err());
+    }
+
+    #[test]
+    fn test_wildcard_match() {
+        let matches =
+            |pattern: &str, text: &str| wildcard_match(pattern.as_bytes(), text.as_bytes());
+        assert!(matches("BOYLAT", "BOYLAT"));
+        assert!(!matches("BOYLAT", "BOYLA"));
+        assert!(matches("*", ""));
+        assert!(matches("B*T", "BOYLAT"));
+        assert!(matches("**AT", "BOYLAT"));
+        assert!(!matches("?", ""));
+        assert!(matches("??Y*", "BOYLAT"));
+        assert!(!matches("B*X", "BOYLAT"));
+    }
+}
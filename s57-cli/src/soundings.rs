@@ -0,0 +1,87 @@
+//! Dumping a cell's soundings as text for hydrographic post-processing
+
+use crate::SoundingFormat;
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::Signed;
+use s57_interp::ParseOptions;
+use s57_parse::S57File;
+use std::io::Write;
+use std::path::Path;
+
+/// SOUNDG: Sounding
+const OBJL_SOUNDG: u16 = 129;
+
+/// Write every sounding to `output` (stdout if None)
+///
+/// Values are printed as exact decimals with as many places as COMF and
+/// SOMF need (7 and 1 for the usual 10^7 and 10), so nothing is lost.
+pub fn soundings(file: &S57File, format: SoundingFormat, output: Option<&Path>) {
+    // Only SOUNDG features and their vectors are needed
+    let options = ParseOptions::new().only_classes([OBJL_SOUNDG]);
+    let world = match s57_interp::build_world_with_options(file, &options) {
+        Ok(world) => world,
+        Err(e) => {
+            eprintln!("Error building world: {}", e);
+            std::process::exit(1);
+        }
+    };
+    let (position_places, depth_places) = world
+        .dataset_params
+        .as_ref()
+        .map(|params| (places(&params.comf), places(&params.somf)))
+        .unwrap_or((7, 1));
+
+    let mut text = String::new();
+    if format == SoundingFormat::Csv {
+        text.push_str("lat,lon,depth\n");
+    }
+    let mut count = 0;
+    for sounding in world.soundings() {
+        let lat = decimal(&sounding.lat, position_places);
+        let lon = decimal(&sounding.lon, position_places);
+        let depth = decimal(&sounding.depth, depth_places);
+        match format {
+            SoundingFormat::Csv => text.push_str(&format!("{},{},{}\n", lat, lon, depth)),
+            SoundingFormat::Xyz => text.push_str(&format!("{} {} {}\n", lon, lat, depth)),
+        }
+        count += 1;
+    }
+
+    let result = match output {
+        Some(path) => std::fs::write(path, text),
+        None => std::io::stdout().lock().write_all(text.as_bytes()),
+    };
+    if let Err(e) = result {
+        eprintln!("Error writing soundings: {}", e);
+        std::process::exit(1);
+    }
+    if let Some(path) = output {
+        println!("{} soundings written to: {}", count, path.display());
+    }
+}
+
+/// Decimal places that resolve 1/factor (e.g. 10^7 -> 7)
+fn places(factor: &BigInt) -> usize {
+    let mut places = 0;
+    let mut scale = BigInt::from(1);
+    while scale < *factor {
+        scale *= 10;
+        places += 1;
+    }
+    places
+}
+
+/// `value` with `places` decimals, exact when the factor is a power of ten
+/// and rounded half away from zero otherwise
+fn decimal(value: &BigRational, places: usize) -> String {
+    let scale = BigRational::from_integer(num_traits::pow(BigInt::from(10), places));
+    let scaled = (value * &scale).round().to_integer();
+    let sign = if scaled.is_negative() { "-" } else { "" };
+    let digits = format!("{:0>width$}", scaled.abs(), width = places + 1);
+    if places == 0 {
+        return format!("{}{}", sign, digits);
+    }
+    let (whole, fraction) = digits.split_at(digits.len() - places);
+    format!("{}{}.{}", sign, whole, fraction)
+}
@@ -0,0 +1,202 @@
+//! Render stylesheet: the order features are drawn in, and optional layers
+//!
+//! Every object class belongs to a draw-order layer, and lower layers are
+//! drawn first: land, then depth areas, other areas, lines, points, and
+//! text on top. Within a layer, features are drawn by display priority and
+//! then in file order.
+//!
+//! A stylesheet file overrides the layer of individual classes, one
+//! `CLASSES = ORDER` per line:
+//!
+//! ```text
+//! # Draw restricted areas over everything but points
+//! RESARE = 45
+//! dangers = text
+//! ```
+//!
+//! CLASSES is anything `--classes` accepts (acronym, OBJL code, wildcard or
+//! group). ORDER is a layer name or a number; the layers are 10 (land), 20
+//! (depth-areas), 30 (areas), 40 (lines), 50 (points) and 60 (text), so a
+//! number in between slots a class between two layers.
+//!
+//! Lines starting with '@' switch optional layers on or off:
+//!
+//! ```text
+//! @quality = on   # hatch areas by zone of confidence (M_QUAL CATZOC)
+//! ```
+//!
+//! Everything after a '#' is a comment, and blank lines are ignored.
+
+use crate::selector::ClassSelector;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Named draw-order layers, lowest (drawn first) to highest
+const LAYERS: &[(&str, u8)] = &[
+    ("land", 10),
+    ("depth-areas", 20),
+    ("areas", 30),
+    ("lines", 40),
+    ("points", 50),
+    ("text", 60),
+];
+
+/// Default draw order of a feature of class `objl` with primitive `prim`
+/// (1 = point, 2 = line, 3 = area)
+pub fn default_draw_order(objl: u16, prim: u8) -> u8 {
+    match (objl, prim) {
+        // LNDARE, LNDRGN
+        (71 | 73, 3) => 10,
+        // DEPARE, DRGARE
+        (42 | 46, 3) => 20,
+        (_, 3) => 30,
+        (_, 2) => 40,
+        _ => 50,
+    }
+}
+
+/// Stylesheet: Per-class draw-order overrides and optional layers
+#[derive(Debug, Clone, Default)]
+pub struct Stylesheet {
+    draw_order: HashMap<u16, u8>,
+    /// Hatch areas by zone of confidence
+    pub quality_hatching: bool,
+}
+
+impl Stylesheet {
+    /// Read a stylesheet file
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text =
+            std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        Self::parse(&text).map_err(|e| format!("{}:{}", path.display(), e))
+    }
+
+    /// Parse stylesheet text; errors are prefixed with the line number
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut stylesheet = Stylesheet::default();
+        for (i, line) in text.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(setting) = line.strip_prefix('@') {
+                stylesheet
+                    .set(setting)
+                    .map_err(|e| format!("{}: {}", i + 1, e))?;
+                continue;
+            }
+            let (classes, order) = line
+                .split_once('=')
+                .ok_or_else(|| format!("{}: expected CLASSES = ORDER", i + 1))?;
+            let order = parse_order(order.trim())
+                .ok_or_else(|| format!("{}: invalid draw order '{}'", i + 1, order.trim()))?;
+            let items: Vec<&str> = classes.split(',').collect();
+            let selector = ClassSelector::parse(&items)
+                .map_err(|_| format!("{}: '{}' matches no object class", i + 1, classes.trim()))?;
+            for code in selector.into_codes() {
+                stylesheet.draw_order.insert(code, order);
+            }
+        }
+        Ok(stylesheet)
+    }
+
+    /// Apply an `@name = value` layer switch (without the '@')
+    fn set(&mut self, setting: &str) -> Result<(), String> {
+        let (name, value) = setting.split_once('=').ok_or("expected @NAME = on|off")?;
+        let on = match value.trim().to_ascii_lowercase().as_str() {
+            "on" | "true" | "yes" => true,
+            "off" | "false" | "no" => false,
+            other => return Err(format!("expected on or off, got '{}'", other)),
+        };
+        match name.trim() {
+            "quality" => self.quality_hatching = on,
+            other => return Err(format!("unknown layer '@{}'", other)),
+        }
+        Ok(())
+    }
+
+    /// Draw order of a feature, with this stylesheet's overrides applied
+    pub fn draw_order(&self, objl: u16, prim: u8) -> u8 {
+        self.draw_order
+            .get(&objl)
+            .copied()
+            .unwrap_or_else(|| default_draw_order(objl, prim))
+    }
+}
+
+/// A layer name or a number
+fn parse_order(value: &str) -> Option<u8> {
+    LAYERS
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(value))
+        .map(|(_, order)| *order)
+        .or_else(|| value.parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_draw_order() {
+        let stylesheet = Stylesheet::parse(
+            "# Draw restricted areas over everything but points\n\
+             RESARE = 45\n\
+             \n\
+             dangers = text   # rocks, wrecks and obstructions on top\n\
+             LNDARE, 42 = Areas\n",
+        )
+        .unwrap();
+
+        // RESARE
+        assert_eq!(stylesheet.draw_order(112, 3), 45);
+        // UWTROC, WRECKS and OBSTRN from the group
+        assert_eq!(stylesheet.draw_order(153, 1), 60);
+        assert_eq!(stylesheet.draw_order(159, 1), 60);
+        assert_eq!(stylesheet.draw_order(86, 3), 60);
+        // Layer names are case-insensitive; DEPARE by OBJL code
+        assert_eq!(stylesheet.draw_order(71, 3), 30);
+        assert_eq!(stylesheet.draw_order(42, 3), 30);
+        // Unlisted classes keep their default layer
+        assert_eq!(stylesheet.draw_order(46, 3), 20);
+        assert_eq!(stylesheet.draw_order(75, 1), 50);
+        assert!(!stylesheet.quality_hatching);
+    }
+
+    #[test]
+    fn test_parse_layer_switches() {
+        assert!(Stylesheet::parse("@quality = on").unwrap().quality_hatching);
+        assert!(
+            Stylesheet::parse("@quality=YES # hatch")
+                .unwrap()
+                .quality_hatching
+        );
+        assert!(
+            !Stylesheet::parse("@quality = on\n@quality = off")
+                .unwrap()
+                .quality_hatching
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |text| Stylesheet::parse(text).unwrap_err();
+
+        assert_eq!(error("RESARE 45"), "1: expected CLASSES = ORDER");
+        assert_eq!(
+            error("RESARE = 45\n\n# comment\nRESARE = top"),
+            "4: invalid draw order 'top'"
+        );
+        assert_eq!(error("RESARE = 300"), "1: invalid draw order '300'");
+        assert_eq!(
+            error("\nNOSUCH = 45"),
+            "2: 'NOSUCH' matches no object class"
+        );
+        assert_eq!(error("@quality"), "1: expected @NAME = on|off");
+        assert_eq!(
+            error("RESARE = 45\n@quality = maybe"),
+            "2: expected on or off, got 'maybe'"
+        );
+        assert_eq!(error("@soundings = on"), "1: unknown layer '@soundings'");
+    }
+}
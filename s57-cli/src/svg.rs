@@ -1,70 +1,61 @@
 //! SVG renderer for S-57 chart data
 //!
-//! Renders lat/lon coordinates to SVG format with automatic bounding box
-//! calculation and coordinate normalization.
+//! Renders lat/lon coordinates to SVG in a selectable projection. The view bounds are fixed before the
+//! first element is drawn, so each element is transformed and written to
+//! the output as soon as it is added; nothing is buffered, and memory use
+//! stays flat however many points a cell has.
+//!
+//! Drawing goes through a `Canvas`, so other formats (see `png`) share the
+//! view, projection, simplification and element budget.
 
+use crate::OutputProjection;
+use s57_interp::projection::Projection;
+use s57_interp::region::{covering_lon_range, BoundingBox};
+use s57_interp::targets::{Target, TargetSource};
 use std::io::Write;
 
-/// Drawing primitive for SVG rendering
-#[derive(Debug, Clone)]
-pub enum Primitive {
-    /// Polyline (open path)
-    Polyline {
-        points: Vec<(f64, f64)>,
-        stroke: String,
-        stroke_width: f64,
-        id: Option<String>,
-    },
-    /// Polygon (closed path)
-    Polygon {
-        points: Vec<(f64, f64)>,
-        fill: String,
-        stroke: String,
-        stroke_width: f64,
-        id: Option<String>,
-    },
-    /// Polygon with holes (exterior ring + interior holes)
-    PolygonWithHoles {
-        rings: Vec<Vec<(f64, f64)>>, // First ring is exterior, rest are holes
-        fill: String,
-        stroke: String,
-        stroke_width: f64,
-        id: Option<String>,
-    },
-    /// Point marker
-    Point {
-        lat: f64,
-        lon: f64,
-        radius: f64,
-        fill: String,
-        title: Option<String>,
-        id: Option<String>,
-    },
-}
-
-/// SVG renderer with bounding box tracking
+/// SVG renderer settings: canvas, view bounds and dynamic targets
 pub struct SvgRenderer {
-    /// Drawing primitives to render
-    primitives: Vec<Primitive>,
-    /// Bounding box: (min_lat, min_lon, max_lat, max_lon)
-    bbox: Option<(f64, f64, f64, f64)>,
+    /// Dynamic target sources, drawn above all chart elements
+    target_sources: Vec<Box<dyn TargetSource>>,
+    /// Latitude range of everything included: (min_lat, max_lat)
+    lat_range: Option<(f64, f64)>,
+    /// Longitude ranges of everything included, (west, east); west > east
+    /// crosses the antimeridian
+    lon_ranges: Vec<(f64, f64)>,
+    /// View fixed by `begin`
+    view: Option<View>,
     /// Canvas width in pixels
     width: u32,
     /// Canvas height in pixels
     height: u32,
     /// Padding around the content (in pixels)
     padding: f64,
+    /// Most points written per path or ring; longer ones are simplified
+    max_path_points: Option<usize>,
+    /// Most chart elements written; later ones are dropped
+    max_elements: Option<usize>,
+    /// Map projection of the view
+    projection: OutputProjection,
+    /// Canvas fill behind the chart
+    background: String,
 }
 
 impl SvgRenderer {
     /// Create a new SVG renderer with default canvas size
     pub fn new() -> Self {
         Self {
-            primitives: Vec::new(),
-            bbox: None,
+            target_sources: Vec::new(),
+            lat_range: None,
+            lon_ranges: Vec::new(),
+            view: None,
             width: 800,
             height: 600,
             padding: 20.0,
+            max_path_points: None,
+            max_elements: None,
+            projection: OutputProjection::default(),
+            background: "#e8f4f8".to_string(),
         }
     }
 
@@ -75,136 +66,143 @@ impl SvgRenderer {
         self
     }
 
-    /// Set padding around content
-    pub fn with_padding(mut self, padding: f64) -> Self {
-        self.padding = padding;
+    /// Cap the points written per path (per ring, for polygons)
+    ///
+    /// Longer paths are simplified (Douglas-Peucker, in pixels) with the
+    /// smallest tolerance that brings them under the cap. Values below 2
+    /// are treated as 2.
+    pub fn with_max_path_points(mut self, max: Option<usize>) -> Self {
+        self.max_path_points = max.map(|m| m.max(2));
         self
     }
 
-    /// Add a polyline to the renderer
-    pub fn add_polyline(
-        &mut self,
-        points: impl IntoIterator<Item = (f64, f64)>,
-        stroke: String,
-        stroke_width: f64,
-        id: Option<String>,
-    ) {
-        let points: Vec<_> = points.into_iter().collect();
-        self.update_bbox(points.iter().copied());
-        self.primitives.push(Primitive::Polyline {
-            points,
-            stroke,
-            stroke_width,
-            id,
-        });
+    /// Cap the number of chart elements written
+    ///
+    /// Elements beyond the cap are dropped and counted; dynamic targets
+    /// don't count towards it.
+    pub fn with_max_elements(mut self, max: Option<usize>) -> Self {
+        self.max_elements = max;
+        self
     }
 
-    /// Add a polygon to the renderer
-    pub fn add_polygon(
-        &mut self,
-        points: impl IntoIterator<Item = (f64, f64)>,
-        fill: String,
-        stroke: String,
-        stroke_width: f64,
-        id: Option<String>,
-    ) {
-        let points: Vec<_> = points.into_iter().collect();
-        self.update_bbox(points.iter().copied());
-        self.primitives.push(Primitive::Polygon {
-            points,
-            fill,
-            stroke,
-            stroke_width,
-            id,
-        });
+    /// Set the map projection
+    ///
+    /// Transverse Mercator is centred on the view.
+    pub fn with_projection(mut self, projection: OutputProjection) -> Self {
+        self.projection = projection;
+        self
     }
 
-    /// Add a polygon with holes to the renderer
-    /// First ring is the exterior boundary, remaining rings are interior holes
-    pub fn add_polygon_with_holes(
-        &mut self,
-        rings: Vec<Vec<(f64, f64)>>,
-        fill: String,
-        stroke: String,
-        stroke_width: f64,
-        id: Option<String>,
-    ) {
-        // Update bbox with all rings
-        for ring in &rings {
-            self.update_bbox(ring.iter().copied());
-        }
-        self.primitives.push(Primitive::PolygonWithHoles {
-            rings,
-            fill,
-            stroke,
-            stroke_width,
-            id,
-        });
+    /// Set the canvas fill behind the chart
+    pub fn with_background(mut self, colour: &str) -> Self {
+        self.background = colour.to_string();
+        self
     }
 
-    /// Add a point marker to the renderer
-    pub fn add_point(
-        &mut self,
-        lat: f64,
-        lon: f64,
-        radius: f64,
-        fill: String,
-        title: Option<String>,
-        id: Option<String>,
-    ) {
-        self.update_bbox(std::iter::once((lat, lon)));
-        self.primitives.push(Primitive::Point {
-            lat,
-            lon,
-            radius,
-            fill,
-            title,
-            id,
-        });
+    /// The element cap, if any
+    pub fn max_elements(&self) -> Option<usize> {
+        self.max_elements
     }
 
-    /// Update bounding box with new points
-    fn update_bbox(&mut self, points: impl IntoIterator<Item = (f64, f64)>) {
+    /// Add a source of dynamic targets
+    ///
+    /// Targets don't affect the bounding box: the chart sets the view and
+    /// targets outside it are clipped.
+    pub fn with_target_source(mut self, source: impl TargetSource + 'static) -> Self {
+        self.target_sources.push(Box::new(source));
+        self
+    }
+
+    /// Grow the view bounds to include points
+    ///
+    /// Call for everything that will be drawn before `begin`; the bounds
+    /// can't change once output has started.
+    pub fn include(&mut self, points: impl IntoIterator<Item = (f64, f64)>) {
         for (lat, lon) in points {
-            if let Some((min_lat, min_lon, max_lat, max_lon)) = self.bbox {
-                self.bbox = Some((
-                    min_lat.min(lat),
-                    min_lon.min(lon),
-                    max_lat.max(lat),
-                    max_lon.max(lon),
-                ));
-            } else {
-                self.bbox = Some((lat, lon, lat, lon));
-            }
+            self.include_box(lat, lon, lat, lon);
         }
     }
 
-    /// Transform lat/lon to SVG coordinates
+    /// Grow the view bounds to include a box
+    ///
+    /// A box with `min_lon > max_lon` crosses the antimeridian.
+    pub fn include_box(&mut self, min_lat: f64, min_lon: f64, max_lat: f64, max_lon: f64) {
+        self.lat_range = Some(match self.lat_range {
+            Some((lo, hi)) => (lo.min(min_lat), hi.max(max_lat)),
+            None => (min_lat, max_lat),
+        });
+        self.lon_ranges.push((min_lon, max_lon));
+    }
+
+    /// Start writing an SVG document to `writer`
+    ///
+    /// With no bounds (nothing to draw) a placeholder document is written
+    /// and elements added to the stream are ignored.
+    pub fn begin<W: Write>(self, writer: W) -> SvgStream<W> {
+        self.begin_on(SvgCanvas::new(writer))
+    }
+
+    /// Start drawing on any canvas
+    ///
+    /// The view, projection and budgets are the same whatever the canvas;
+    /// only the drawing differs.
+    pub fn begin_on<C: Canvas>(mut self, canvas: C) -> ChartStream<C> {
+        self.view = self.lat_range.and_then(|(min_lat, max_lat)| {
+            let (west, east) = covering_lon_range(self.lon_ranges.drain(..))?;
+            let lon_span = if west > east {
+                east - west + 360.0
+            } else {
+                east - west
+            };
+            let projection = self
+                .projection
+                .around(&BoundingBox::from_degrees(min_lat, west, max_lat, east)?);
+            Some(View::new(projection, min_lat, max_lat, west, lon_span))
+        });
+        let mut stream = ChartStream {
+            renderer: self,
+            canvas,
+            error: None,
+            elements: 0,
+            dropped_elements: 0,
+            simplified_paths: 0,
+        };
+        let renderer = &stream.renderer;
+        let background = renderer.view.as_ref().map(|_| renderer.background.as_str());
+        let result = stream
+            .canvas
+            .begin(renderer.width, renderer.height, background);
+        stream.record(result);
+        stream
+    }
+
+    /// Transform lat/lon to canvas pixels
     fn transform(&self, lat: f64, lon: f64) -> (f64, f64) {
-        let (min_lat, min_lon, max_lat, max_lon) = self.bbox.unwrap();
+        let view = self.view.as_ref().unwrap();
+        let (x, y) = view.plane(lat, lon);
 
         // Calculate available drawing area
         let draw_width = self.width as f64 - 2.0 * self.padding;
         let draw_height = self.height as f64 - 2.0 * self.padding;
 
         // Calculate scale factors
-        let lat_range = max_lat - min_lat;
-        let lon_range = max_lon - min_lon;
+        let x_range = view.max_x - view.min_x;
+        let y_range = view.max_y - view.min_y;
 
         // Handle degenerate cases
-        if lat_range == 0.0 && lon_range == 0.0 {
+        if x_range == 0.0 && y_range == 0.0 {
             // Single point - center it
             return (self.width as f64 / 2.0, self.height as f64 / 2.0);
         }
 
-        let scale_x = if lon_range > 0.0 {
-            draw_width / lon_range
+        let scale_x = if x_range > 0.0 {
+            draw_width / x_range
         } else {
             1.0
         };
 
-        let scale_y = if lat_range > 0.0 {
-            draw_height / lat_range
+        let scale_y = if y_range > 0.0 {
+            draw_height / y_range
         } else {
             1.0
         };
@@ -213,185 +211,599 @@ impl SvgRenderer {
         let scale = scale_x.min(scale_y);
 
         // Center the content
-        let content_width = lon_range * scale;
-        let content_height = lat_range * scale;
+        let content_width = x_range * scale;
+        let content_height = y_range * scale;
         let offset_x = self.padding + (draw_width - content_width) / 2.0;
         let offset_y = self.padding + (draw_height - content_height) / 2.0;
 
-        // Transform coordinates
-        // Note: SVG Y axis goes down, so we flip latitude
-        let x = offset_x + (lon - min_lon) * scale;
-        let y = offset_y + (max_lat - lat) * scale;
+        // Note: SVG Y axis goes down, so we flip y
+        (
+            offset_x + (x - view.min_x) * scale,
+            offset_y + (view.max_y - y) * scale,
+        )
+    }
+}
+
+/// The area shown, fixed once writing starts
+///
+/// Longitudes are unwrapped around the view so a view across the
+/// antimeridian is continuous. In plate carrée they are also scaled by the
+/// cosine of the middle latitude so shapes keep their proportions away from
+/// the equator.
+struct View {
+    projection: Projection,
+    /// Longitude points are unwrapped around (degrees)
+    centre_lon: f64,
+    /// Plate carrée degrees of longitude to plane units
+    lon_scale: f64,
+    /// Extent in plane units
+    min_x: f64,
+    min_y: f64,
+    max_x: f64,
+    max_y: f64,
+}
+
+impl View {
+    /// Steps along each edge of the box when finding the projected extent
+    const EDGE_STEPS: u32 = 16;
+
+    fn new(projection: Projection, min_lat: f64, max_lat: f64, west: f64, lon_span: f64) -> Self {
+        // Keep a sliver of width for views reaching a pole
+        let mid_lat = (min_lat + max_lat) / 2.0;
+        let lon_scale = if projection.is_geographic() {
+            mid_lat.to_radians().cos().max(0.01)
+        } else {
+            1.0
+        };
+        let mut view = Self {
+            projection,
+            centre_lon: west + lon_span / 2.0,
+            lon_scale,
+            min_x: f64::INFINITY,
+            min_y: f64::INFINITY,
+            max_x: f64::NEG_INFINITY,
+            max_y: f64::NEG_INFINITY,
+        };
 
-        (x, y)
+        // Edges needn't be straight once projected, so walk along them
+        for i in 0..=Self::EDGE_STEPS {
+            let t = i as f64 / Self::EDGE_STEPS as f64;
+            let lon = west + lon_span * t;
+            let lat = min_lat + (max_lat - min_lat) * t;
+            for (lat, lon) in [
+                (min_lat, lon),
+                (max_lat, lon),
+                (lat, west),
+                (lat, west + lon_span),
+            ] {
+                let (x, y) = view.plane(lat, lon);
+                view.min_x = view.min_x.min(x);
+                view.min_y = view.min_y.min(y);
+                view.max_x = view.max_x.max(x);
+                view.max_y = view.max_y.max(y);
+            }
+        }
+        view
     }
 
-    /// Render all primitives to SVG
-    pub fn render<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        if self.bbox.is_none() {
-            writeln!(
-                writer,
-                "<svg width=\"{}\" height=\"{}\" xmlns=\"http://www.w3.org/2000/svg\">",
-                self.width, self.height
-            )?;
-            writeln!(
-                writer,
-                "  <text x=\"{}\" y=\"{}\" font-family=\"Arial\" font-size=\"16\">No data to render</text>",
-                self.width / 2, self.height / 2
-            )?;
-            writeln!(writer, "</svg>")?;
-            return Ok(());
+    /// (x, y) in plane units
+    fn plane(&self, lat: f64, lon: f64) -> (f64, f64) {
+        // Unwrap to within half a turn of the view's centre
+        let lon = (lon - self.centre_lon + 180.0).rem_euclid(360.0) - 180.0 + self.centre_lon;
+        if self.projection.is_geographic() {
+            ((lon - self.centre_lon) * self.lon_scale, lat)
+        } else {
+            self.projection.project(lat, lon)
         }
+    }
+}
 
-        // Write SVG header
-        writeln!(
+/// Chart output being drawn element by element
+///
+/// The stream projects and simplifies; the canvas only sees pixels. The
+/// first error is kept and returned by `finish`; later elements are
+/// skipped.
+pub struct ChartStream<C: Canvas> {
+    renderer: SvgRenderer,
+    canvas: C,
+    error: Option<std::io::Error>,
+    /// Elements written so far
+    elements: usize,
+    /// Elements skipped because of the element cap
+    dropped_elements: usize,
+    /// Paths or rings simplified to fit the point cap
+    simplified_paths: usize,
+}
+
+/// SVG document being written element by element
+pub type SvgStream<W> = ChartStream<SvgCanvas<W>>;
+
+impl<C: Canvas> ChartStream<C> {
+    /// Draw a polyline (open path)
+    pub fn add_polyline(
+        &mut self,
+        points: impl IntoIterator<Item = (f64, f64)>,
+        stroke: &str,
+        stroke_width: f64,
+        id: Option<&str>,
+    ) {
+        self.add_dashed_polyline(points, stroke, stroke_width, None, id);
+    }
+
+    /// Draw a polyline with an optional dash pattern (SVG stroke-dasharray)
+    pub fn add_dashed_polyline(
+        &mut self,
+        points: impl IntoIterator<Item = (f64, f64)>,
+        stroke: &str,
+        stroke_width: f64,
+        dash: Option<&str>,
+        id: Option<&str>,
+    ) {
+        if !self.admit() {
+            return;
+        }
+        let points = self.path_points(points);
+        let result = self
+            .canvas
+            .polyline(&points, stroke, stroke_width, dash, id);
+        self.record(result);
+    }
+
+    /// Draw a polygon (closed path)
+    pub fn add_polygon(
+        &mut self,
+        points: impl IntoIterator<Item = (f64, f64)>,
+        fill: &str,
+        stroke: &str,
+        stroke_width: f64,
+        id: Option<&str>,
+    ) {
+        if !self.admit() {
+            return;
+        }
+        let points = self.path_points(points);
+        let result = self.canvas.polygon(&points, fill, stroke, stroke_width, id);
+        self.record(result);
+    }
+
+    /// Draw a polygon with holes
+    /// First ring is the exterior boundary, remaining rings are interior holes
+    pub fn add_polygon_with_holes<R>(
+        &mut self,
+        rings: impl IntoIterator<Item = R>,
+        fill: &str,
+        stroke: &str,
+        stroke_width: f64,
+        id: Option<&str>,
+    ) where
+        R: IntoIterator<Item = (f64, f64)>,
+    {
+        if !self.admit() {
+            return;
+        }
+        let rings: Vec<_> = rings
+            .into_iter()
+            .map(|ring| self.path_points(ring))
+            .collect();
+        let result = self
+            .canvas
+            .polygon_with_holes(&rings, fill, stroke, stroke_width, id);
+        self.record(result);
+    }
+
+    /// Define a hatch pattern for use as a fill, `url(#id)`
+    ///
+    /// Lines `spacing` pixels apart at `angle` degrees, optionally crossed
+    /// by a second set at right angles. Definitions don't count towards
+    /// the element cap.
+    pub fn add_hatch_pattern(
+        &mut self,
+        id: &str,
+        spacing: f64,
+        angle: f64,
+        colour: &str,
+        crossed: bool,
+    ) {
+        if !self.ready() {
+            return;
+        }
+        let result = self
+            .canvas
+            .hatch_pattern(id, spacing, angle, colour, crossed);
+        self.record(result);
+    }
+
+    /// Draw a point marker
+    pub fn add_point(
+        &mut self,
+        lat: f64,
+        lon: f64,
+        radius: f64,
+        fill: &str,
+        title: Option<&str>,
+        id: Option<&str>,
+    ) {
+        if !self.admit() {
+            return;
+        }
+        let (x, y) = self.renderer.transform(lat, lon);
+        let result = self.canvas.point(x, y, radius, fill, title, id);
+        self.record(result);
+    }
+
+    /// Draw the dynamic targets, close the output and flush it
+    pub fn finish(mut self) -> std::io::Result<C::Output> {
+        if self.ready() {
+            let result = self.draw_targets();
+            self.record(result);
+        }
+        if let Some(e) = self.error {
+            return Err(e);
+        }
+        self.canvas.finish()
+    }
+
+    /// Elements written so far
+    pub fn elements(&self) -> usize {
+        self.elements
+    }
+
+    /// Elements dropped because the element cap was reached
+    pub fn dropped_elements(&self) -> usize {
+        self.dropped_elements
+    }
+
+    /// Paths or rings that were simplified to fit the point cap
+    pub fn simplified_paths(&self) -> usize {
+        self.simplified_paths
+    }
+
+    /// True if elements should be written: bounds set and no error so far
+    fn ready(&self) -> bool {
+        self.error.is_none() && self.renderer.view.is_some()
+    }
+
+    /// Check the element cap before writing an element, counting it
+    fn admit(&mut self) -> bool {
+        if !self.ready() {
+            return false;
+        }
+        if self
+            .renderer
+            .max_elements
+            .is_some_and(|max| self.elements >= max)
+        {
+            self.dropped_elements += 1;
+            return false;
+        }
+        self.elements += 1;
+        true
+    }
+
+    /// Transform a path to pixels, simplifying it if over the point cap
+    fn path_points(&mut self, points: impl IntoIterator<Item = (f64, f64)>) -> Vec<(f64, f64)> {
+        let points: Vec<_> = points
+            .into_iter()
+            .map(|(lat, lon)| self.renderer.transform(lat, lon))
+            .collect();
+        match self.renderer.max_path_points {
+            Some(max) if points.len() > max => {
+                self.simplified_paths += 1;
+                simplify_to(&points, max)
+            }
+            _ => points,
+        }
+    }
+
+    /// Keep the first error
+    fn record(&mut self, result: std::io::Result<()>) {
+        if let Err(e) = result {
+            self.error.get_or_insert(e);
+        }
+    }
+
+    fn draw_targets(&mut self) -> std::io::Result<()> {
+        for source in &self.renderer.target_sources {
+            for target in source.targets() {
+                let (x, y) = self.renderer.transform(target.lat, target.lon);
+                self.canvas.target(x, y, &target)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Output format a chart is drawn on, in pixels
+///
+/// Colours are SVG colour strings: `#rrggbb`, `#rrggbbaa`, `none`, or
+/// `url(#id)` for a hatch pattern defined earlier.
+pub trait Canvas {
+    /// What `finish` returns
+    type Output;
+
+    /// Start the image; `background` is None when there is nothing to draw
+    fn begin(&mut self, width: u32, height: u32, background: Option<&str>) -> std::io::Result<()>;
+
+    /// Open path
+    fn polyline(
+        &mut self,
+        points: &[(f64, f64)],
+        stroke: &str,
+        stroke_width: f64,
+        dash: Option<&str>,
+        id: Option<&str>,
+    ) -> std::io::Result<()>;
+
+    /// Closed path
+    fn polygon(
+        &mut self,
+        points: &[(f64, f64)],
+        fill: &str,
+        stroke: &str,
+        stroke_width: f64,
+        id: Option<&str>,
+    ) -> std::io::Result<()>;
+
+    /// Exterior ring followed by holes, filled even-odd
+    fn polygon_with_holes(
+        &mut self,
+        rings: &[Vec<(f64, f64)>],
+        fill: &str,
+        stroke: &str,
+        stroke_width: f64,
+        id: Option<&str>,
+    ) -> std::io::Result<()>;
+
+    /// Define a hatch pattern, as `ChartStream::add_hatch_pattern`
+    fn hatch_pattern(
+        &mut self,
+        id: &str,
+        spacing: f64,
+        angle: f64,
+        colour: &str,
+        crossed: bool,
+    ) -> std::io::Result<()>;
+
+    /// Point marker, with a hover title where the format has them
+    fn point(
+        &mut self,
+        x: f64,
+        y: f64,
+        radius: f64,
+        fill: &str,
+        title: Option<&str>,
+        id: Option<&str>,
+    ) -> std::io::Result<()>;
+
+    /// Dynamic target: a triangle pointing along its heading, or a circle
+    /// if the heading is unknown, plus its label
+    fn target(&mut self, x: f64, y: f64, target: &Target) -> std::io::Result<()>;
+
+    /// Close the image and flush it
+    fn finish(self) -> std::io::Result<Self::Output>;
+}
+
+/// SVG elements written straight to `W` as they are drawn
+pub struct SvgCanvas<W: Write> {
+    writer: W,
+    /// True once the closing tag is written
+    closed: bool,
+}
+
+impl<W: Write> SvgCanvas<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
             writer,
+            closed: false,
+        }
+    }
+
+    /// Space-separated x,y pairs
+    fn write_points(&mut self, points: &[(f64, f64)]) -> std::io::Result<()> {
+        for (i, (x, y)) in points.iter().enumerate() {
+            if i > 0 {
+                write!(self.writer, " ")?;
+            }
+            write!(self.writer, "{:.2},{:.2}", x, y)?;
+        }
+        Ok(())
+    }
+
+    fn write_id(&mut self, id: Option<&str>) -> std::io::Result<()> {
+        match id {
+            Some(id_val) => write!(self.writer, " data-feature-id=\"{}\"", escape_xml(id_val)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<W: Write> Canvas for SvgCanvas<W> {
+    type Output = W;
+
+    fn begin(&mut self, width: u32, height: u32, background: Option<&str>) -> std::io::Result<()> {
+        writeln!(
+            self.writer,
             "<svg width=\"{}\" height=\"{}\" xmlns=\"http://www.w3.org/2000/svg\">",
-            self.width, self.height
+            width, height
         )?;
+        let Some(background) = background else {
+            writeln!(
+                self.writer,
+                "  <text x=\"{}\" y=\"{}\" font-family=\"Arial\" font-size=\"16\">No data to render</text>",
+                width / 2, height / 2
+            )?;
+            self.closed = true;
+            return writeln!(self.writer, "</svg>");
+        };
 
         // Add background
         writeln!(
-            writer,
-            "  <rect width=\"{}\" height=\"{}\" fill=\"#e8f4f8\"/>",
-            self.width, self.height
+            self.writer,
+            "  <rect width=\"{}\" height=\"{}\" fill=\"{}\"/>",
+            width, height, background
+        )
+    }
+
+    fn polyline(
+        &mut self,
+        points: &[(f64, f64)],
+        stroke: &str,
+        stroke_width: f64,
+        dash: Option<&str>,
+        id: Option<&str>,
+    ) -> std::io::Result<()> {
+        write!(self.writer, "  <polyline points=\"")?;
+        self.write_points(points)?;
+        write!(
+            self.writer,
+            "\" fill=\"none\" stroke=\"{}\" stroke-width=\"{}\"",
+            stroke, stroke_width
+        )?;
+        if let Some(dash) = dash {
+            write!(self.writer, " stroke-dasharray=\"{}\"", dash)?;
+        }
+        self.write_id(id)?;
+        writeln!(self.writer, "/>")
+    }
+
+    fn polygon(
+        &mut self,
+        points: &[(f64, f64)],
+        fill: &str,
+        stroke: &str,
+        stroke_width: f64,
+        id: Option<&str>,
+    ) -> std::io::Result<()> {
+        write!(self.writer, "  <polygon points=\"")?;
+        self.write_points(points)?;
+        write!(
+            self.writer,
+            "\" fill=\"{}\" stroke=\"{}\" stroke-width=\"{}\"",
+            fill, stroke, stroke_width
+        )?;
+        self.write_id(id)?;
+        writeln!(self.writer, "/>")
+    }
+
+    fn polygon_with_holes(
+        &mut self,
+        rings: &[Vec<(f64, f64)>],
+        fill: &str,
+        stroke: &str,
+        stroke_width: f64,
+        id: Option<&str>,
+    ) -> std::io::Result<()> {
+        // Use SVG path element with fill-rule="evenodd" to handle holes
+        write!(self.writer, "  <path d=\"")?;
+        for ring in rings {
+            for (i, (x, y)) in ring.iter().enumerate() {
+                let command = if i == 0 { "M" } else { "L" };
+                write!(self.writer, "{} {:.2},{:.2} ", command, x, y)?;
+            }
+            // Close the ring, unless it was empty
+            if !ring.is_empty() {
+                write!(self.writer, "Z ")?;
+            }
+        }
+        write!(
+            self.writer,
+            "\" fill=\"{}\" stroke=\"{}\" stroke-width=\"{}\" fill-rule=\"evenodd\"",
+            fill, stroke, stroke_width
         )?;
+        self.write_id(id)?;
+        writeln!(self.writer, "/>")
+    }
 
-        // Render primitives
-        for primitive in &self.primitives {
-            match primitive {
-                Primitive::Polyline {
-                    points,
-                    stroke,
-                    stroke_width,
-                    id,
-                } => {
-                    write!(writer, "  <polyline points=\"")?;
-                    for (i, &(lat, lon)) in points.iter().enumerate() {
-                        let (x, y) = self.transform(lat, lon);
-                        if i > 0 {
-                            write!(writer, " ")?;
-                        }
-                        write!(writer, "{:.2},{:.2}", x, y)?;
-                    }
-                    write!(
-                        writer,
-                        "\" fill=\"none\" stroke=\"{}\" stroke-width=\"{}\"",
-                        stroke, stroke_width
-                    )?;
-                    if let Some(id_val) = id {
-                        write!(writer, " data-feature-id=\"{}\"", escape_xml(id_val))?;
-                    }
-                    writeln!(writer, "/>")?;
-                }
-                Primitive::Polygon {
-                    points,
-                    fill,
-                    stroke,
-                    stroke_width,
-                    id,
-                } => {
-                    write!(writer, "  <polygon points=\"")?;
-                    for (i, &(lat, lon)) in points.iter().enumerate() {
-                        let (x, y) = self.transform(lat, lon);
-                        if i > 0 {
-                            write!(writer, " ")?;
-                        }
-                        write!(writer, "{:.2},{:.2}", x, y)?;
-                    }
-                    write!(
-                        writer,
-                        "\" fill=\"{}\" stroke=\"{}\" stroke-width=\"{}\"",
-                        fill, stroke, stroke_width
-                    )?;
-                    if let Some(id_val) = id {
-                        write!(writer, " data-feature-id=\"{}\"", escape_xml(id_val))?;
-                    }
-                    writeln!(writer, "/>")?;
-                }
-                Primitive::PolygonWithHoles {
-                    rings,
-                    fill,
-                    stroke,
-                    stroke_width,
-                    id,
-                } => {
-                    // Use SVG path element with fill-rule="evenodd" to handle holes
-                    write!(writer, "  <path d=\"")?;
-
-                    for (ring_idx, ring) in rings.iter().enumerate() {
-                        if ring.is_empty() {
-                            continue;
-                        }
-
-                        // Move to first point
-                        let (lat, lon) = ring[0];
-                        let (x, y) = self.transform(lat, lon);
-                        write!(writer, "M {:.2},{:.2} ", x, y)?;
-
-                        // Line to subsequent points
-                        for &(lat, lon) in &ring[1..] {
-                            let (x, y) = self.transform(lat, lon);
-                            write!(writer, "L {:.2},{:.2} ", x, y)?;
-                        }
-
-                        // Close path
-                        write!(writer, "Z ")?;
-
-                        // Space between rings for readability
-                        if ring_idx < rings.len() - 1 {
-                            write!(writer, "")?;
-                        }
-                    }
-
-                    write!(
-                        writer,
-                        "\" fill=\"{}\" stroke=\"{}\" stroke-width=\"{}\" fill-rule=\"evenodd\"",
-                        fill, stroke, stroke_width
-                    )?;
-                    if let Some(id_val) = id {
-                        write!(writer, " data-feature-id=\"{}\"", escape_xml(id_val))?;
-                    }
-                    writeln!(writer, "/>")?;
-                }
-                Primitive::Point {
-                    lat,
-                    lon,
-                    radius,
-                    fill,
-                    title,
-                    id,
-                } => {
-                    let (x, y) = self.transform(*lat, *lon);
-                    let id_attr = id
-                        .as_ref()
-                        .map(|i| format!(" data-feature-id=\"{}\"", escape_xml(i)))
-                        .unwrap_or_default();
-
-                    if let Some(title_text) = title {
-                        writeln!(
-                            writer,
-                            "  <circle cx=\"{:.2}\" cy=\"{:.2}\" r=\"{}\" fill=\"{}\"{}>",
-                            x, y, radius, fill, id_attr
-                        )?;
-                        writeln!(writer, "    <title>{}</title>", escape_xml(title_text))?;
-                        writeln!(writer, "  </circle>")?;
-                    } else {
-                        writeln!(
-                            writer,
-                            "  <circle cx=\"{:.2}\" cy=\"{:.2}\" r=\"{}\" fill=\"{}\"{}//>",
-                            x, y, radius, fill, id_attr
-                        )?;
-                    }
-                }
+    fn hatch_pattern(
+        &mut self,
+        id: &str,
+        spacing: f64,
+        angle: f64,
+        colour: &str,
+        crossed: bool,
+    ) -> std::io::Result<()> {
+        write!(
+            self.writer,
+            "  <defs><pattern id=\"{}\" width=\"{}\" height=\"{}\" patternUnits=\"userSpaceOnUse\" patternTransform=\"rotate({})\">",
+            escape_xml(id),
+            spacing,
+            spacing,
+            angle
+        )?;
+        write!(
+            self.writer,
+            "<line x1=\"0\" y1=\"0\" x2=\"0\" y2=\"{}\" stroke=\"{}\" stroke-width=\"1\"/>",
+            spacing, colour
+        )?;
+        if crossed {
+            write!(
+                self.writer,
+                "<line x1=\"0\" y1=\"0\" x2=\"{}\" y2=\"0\" stroke=\"{}\" stroke-width=\"1\"/>",
+                spacing, colour
+            )?;
+        }
+        writeln!(self.writer, "</pattern></defs>")
+    }
+
+    fn point(
+        &mut self,
+        x: f64,
+        y: f64,
+        radius: f64,
+        fill: &str,
+        title: Option<&str>,
+        id: Option<&str>,
+    ) -> std::io::Result<()> {
+        write!(
+            self.writer,
+            "  <circle cx=\"{:.2}\" cy=\"{:.2}\" r=\"{}\" fill=\"{}\"",
+            x, y, radius, fill
+        )?;
+        self.write_id(id)?;
+        match title {
+            Some(title_text) => {
+                writeln!(self.writer, ">")?;
+                writeln!(self.writer, "    <title>{}</title>", escape_xml(title_text))?;
+                writeln!(self.writer, "  </circle>")
             }
+            None => writeln!(self.writer, "/>"),
         }
+    }
 
-        // Write SVG footer
-        writeln!(writer, "</svg>")?;
+    fn target(&mut self, x: f64, y: f64, target: &Target) -> std::io::Result<()> {
+        let writer = &mut self.writer;
+        match target.heading {
+            Some(heading) => writeln!(
+                writer,
+                "  <polygon points=\"0,-9 5,6 -5,6\" transform=\"translate({:.2},{:.2}) rotate({:.1})\" fill=\"#00a000\" stroke=\"#004000\" stroke-width=\"1\" class=\"target\"/>",
+                x, y, heading
+            )?,
+            None => writeln!(
+                writer,
+                "  <circle cx=\"{:.2}\" cy=\"{:.2}\" r=\"4\" fill=\"#00a000\" class=\"target\"/>",
+                x, y
+            )?,
+        }
 
+        if let Some(label) = &target.label {
+            writeln!(
+                writer,
+                "  <text x=\"{:.2}\" y=\"{:.2}\" font-family=\"Arial\" font-size=\"10\" fill=\"#004000\">{}</text>",
+                x + 8.0,
+                y - 8.0,
+                escape_xml(label)
+            )?;
+        }
         Ok(())
     }
+
+    fn finish(mut self) -> std::io::Result<W> {
+        if !self.closed {
+            writeln!(self.writer, "</svg>")?;
+        }
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
 }
 
 impl Default for SvgRenderer {
@@ -400,6 +812,61 @@ impl Default for SvgRenderer {
     }
 }
 
+/// Douglas-Peucker simplification to at most `max` points (`max` >= 2)
+///
+/// Doubles the tolerance from a quarter pixel until the path fits, so the
+/// path keeps as much detail as the cap allows. Endpoints are always kept.
+fn simplify_to(points: &[(f64, f64)], max: usize) -> Vec<(f64, f64)> {
+    let mut tolerance = 0.25;
+    loop {
+        let simplified = douglas_peucker(points, tolerance);
+        if simplified.len() <= max || !tolerance.is_finite() {
+            return simplified;
+        }
+        tolerance *= 2.0;
+    }
+}
+
+/// Douglas-Peucker with a stack instead of recursion (paths can be long)
+fn douglas_peucker(points: &[(f64, f64)], tolerance: f64) -> Vec<(f64, f64)> {
+    if points.len() <= 2 {
+        return points.to_vec();
+    }
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[points.len() - 1] = true;
+
+    let mut stack = vec![(0, points.len() - 1)];
+    while let Some((first, last)) = stack.pop() {
+        let (farthest, distance) = (first + 1..last)
+            .map(|i| (i, segment_distance(points[i], points[first], points[last])))
+            .fold((first, 0.0), |best, d| if d.1 > best.1 { d } else { best });
+        if distance > tolerance {
+            keep[farthest] = true;
+            stack.push((first, farthest));
+            stack.push((farthest, last));
+        }
+    }
+    points
+        .iter()
+        .zip(keep)
+        .filter_map(|(p, k)| k.then_some(*p))
+        .collect()
+}
+
+/// Distance from p to the segment a-b
+fn segment_distance(p: (f64, f64), a: (f64, f64), b: (f64, f64)) -> f64 {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let length2 = dx * dx + dy * dy;
+    let t = if length2 == 0.0 {
+        0.0
+    } else {
+        (((p.0 - a.0) * dx + (p.1 - a.1) * dy) / length2).clamp(0.0, 1.0)
+    };
+    let (cx, cy) = (a.0 + t * dx, a.1 + t * dy);
+    ((p.0 - cx).powi(2) + (p.1 - cy).powi(2)).sqrt()
+}
+
 /// Escape XML special characters for use in SVG
 fn escape_xml(s: &str) -> String {
     s.replace('&', "&amp;")
@@ -408,3 +875,227 @@ fn escape_xml(s: &str) -> String {
         .replace('"', "&quot;")
         .replace('\'', "&apos;")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /// Writer taking one byte per call into a buffer the test can read
+    /// while the stream still holds the writer
+    #[derive(Clone, Default)]
+    struct Trickle(Rc<RefCell<Vec<u8>>>);
+
+    impl Write for Trickle {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            let Some(byte) = buf.first() else {
+                return Ok(0);
+            };
+            self.0.borrow_mut().push(*byte);
+            Ok(1)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn renderer() -> SvgRenderer {
+        let mut renderer = SvgRenderer::new().with_dimensions(200, 100);
+        renderer.include_box(50.0, -1.0, 51.0, 1.0);
+        renderer.with_target_source(vec![Target {
+            lat: 50.5,
+            lon: 0.0,
+            heading: Some(90.0),
+            label: Some("A & B".to_string()),
+        }])
+    }
+
+    /// One of each element, with the stream's output so far after each
+    fn draw<W: Write>(
+        stream: &mut SvgStream<W>,
+        mut written: impl FnMut() -> String,
+    ) -> Vec<String> {
+        let mut snapshots = vec![written()];
+        stream.add_hatch_pattern("hatch", 4.0, 45.0, "#808080", true);
+        snapshots.push(written());
+        stream.add_polygon(
+            [(50.0, -1.0), (50.0, 1.0), (51.0, 1.0), (51.0, -1.0)],
+            "url(#hatch)",
+            "#000000",
+            1.0,
+            Some("1"),
+        );
+        snapshots.push(written());
+        stream.add_polygon_with_holes(
+            [
+                vec![(50.0, -1.0), (50.0, 0.0), (51.0, 0.0)],
+                vec![(50.2, -0.8), (50.2, -0.2), (50.8, -0.2)],
+            ],
+            "#c0c0c0",
+            "none",
+            0.0,
+            Some("2"),
+        );
+        snapshots.push(written());
+        stream.add_dashed_polyline(
+            [(50.0, 0.0), (51.0, 1.0)],
+            "#0000ff",
+            2.0,
+            Some("6,3"),
+            None,
+        );
+        snapshots.push(written());
+        stream.add_point(50.5, 0.5, 3.0, "#ff0000", Some("<Light>"), Some("4"));
+        snapshots.push(written());
+        snapshots
+    }
+
+    #[test]
+    fn test_streamed_output_matches_buffered() {
+        let mut buffered = renderer().begin(Vec::new());
+        draw(&mut buffered, String::new);
+        let buffered = String::from_utf8(buffered.finish().unwrap()).unwrap();
+
+        let trickle = Trickle::default();
+        let mut streamed = renderer().begin(trickle.clone());
+        let snapshots = draw(&mut streamed, || {
+            String::from_utf8(trickle.0.borrow().clone()).unwrap()
+        });
+        streamed.finish().unwrap();
+        let streamed = String::from_utf8(trickle.0.take()).unwrap();
+        assert_eq!(streamed, buffered);
+
+        // Each element reaches the writer as it is added, in order, and
+        // only the targets and closing tag wait for `finish`
+        assert!(snapshots[0].starts_with("<svg width=\"200\" height=\"100\""));
+        for (before, after) in snapshots.iter().zip(&snapshots[1..]) {
+            assert!(after.len() > before.len());
+            assert!(after.starts_with(before.as_str()));
+        }
+        assert!(snapshots[1].ends_with("</pattern></defs>\n"));
+        assert!(snapshots[2].ends_with("data-feature-id=\"1\"/>\n"));
+        assert!(snapshots[3].ends_with("fill-rule=\"evenodd\" data-feature-id=\"2\"/>\n"));
+        assert!(snapshots[4].ends_with("stroke-dasharray=\"6,3\"/>\n"));
+        assert!(snapshots[5].ends_with("<title>&lt;Light&gt;</title>\n  </circle>\n"));
+        let tail = &streamed[snapshots[5].len()..];
+        assert!(tail.contains("class=\"target\""));
+        assert!(tail.contains(">A &amp; B</text>"));
+        assert!(tail.ends_with("</svg>\n"));
+    }
+
+    /// Tags written for chart elements (not the background or targets)
+    fn elements(svg: &str) -> Vec<&str> {
+        svg.lines()
+            .map(str::trim)
+            .filter(|line| {
+                ["<polyline", "<polygon", "<path", "<circle"]
+                    .iter()
+                    .any(|tag| line.starts_with(tag))
+                    && !line.contains("class=\"target\"")
+            })
+            .collect()
+    }
+
+    /// Pairs in a `points="..."` attribute
+    fn points(element: &str) -> Vec<&str> {
+        let start = element.find("points=\"").unwrap() + "points=\"".len();
+        let end = start + element[start..].find('"').unwrap();
+        element[start..end].split(' ').collect()
+    }
+
+    #[test]
+    fn test_element_budget() {
+        let mut stream = renderer().with_max_elements(Some(3)).begin(Vec::new());
+        draw(&mut stream, String::new);
+        for lon in [-0.5, 0.0, 0.5] {
+            stream.add_point(50.5, lon, 2.0, "#000000", None, None);
+        }
+        // The pattern definition isn't an element, the targets don't count
+        assert_eq!(stream.elements(), 3);
+        assert_eq!(stream.dropped_elements(), 4);
+        let svg = String::from_utf8(stream.finish().unwrap()).unwrap();
+        let written = elements(&svg);
+        assert_eq!(written.len(), 3);
+        assert!(written[2].starts_with("<polyline"));
+        assert!(svg.contains("<defs><pattern id=\"hatch\""));
+        assert!(svg.contains("class=\"target\""));
+        assert!(svg.ends_with("</svg>\n"));
+
+        // Without a cap nothing is dropped
+        let mut stream = renderer().begin(Vec::new());
+        draw(&mut stream, String::new);
+        assert_eq!((stream.elements(), stream.dropped_elements()), (4, 0));
+        assert_eq!(
+            elements(&String::from_utf8(stream.finish().unwrap()).unwrap()).len(),
+            4
+        );
+    }
+
+    #[test]
+    fn test_path_point_budget() {
+        // 101 points zigzagging across the view
+        let sawtooth: Vec<(f64, f64)> = (0..=100)
+            .map(|i| (50.0 + 0.5 * (i % 2) as f64, -1.0 + i as f64 / 50.0))
+            .collect();
+        let mut stream = renderer().with_max_path_points(Some(10)).begin(Vec::new());
+        stream.add_polyline(sawtooth.iter().copied(), "#000000", 1.0, None);
+        stream.add_polygon(sawtooth.iter().copied(), "#ffffff", "none", 0.0, None);
+        stream.add_polyline([(50.0, -1.0), (51.0, 1.0)], "#000000", 1.0, None);
+        stream.add_polygon_with_holes(
+            [
+                sawtooth.clone(),
+                vec![(50.2, -0.5), (50.2, 0.5), (50.4, 0.0)],
+            ],
+            "#ffffff",
+            "none",
+            0.0,
+            None,
+        );
+        // Each ring over the cap is counted, the short ones aren't
+        assert_eq!(stream.simplified_paths(), 3);
+        assert_eq!(stream.dropped_elements(), 0);
+        let svg = String::from_utf8(stream.finish().unwrap()).unwrap();
+        let written = elements(&svg);
+        assert!((2..=10).contains(&points(written[0]).len()));
+        assert!((2..=10).contains(&points(written[1]).len()));
+        assert_eq!(points(written[2]).len(), 2);
+        let rings: Vec<usize> = written[3]
+            .split('Z')
+            .map(|ring| ring.matches(['M', 'L']).count())
+            .filter(|points| *points > 0)
+            .collect();
+        assert_eq!(rings.len(), 2);
+        assert!((2..=10).contains(&rings[0]));
+        assert_eq!(rings[1], 3);
+
+        // The ends of a simplified path stay where they were
+        let mut full = renderer().begin(Vec::new());
+        full.add_polyline(sawtooth.iter().copied(), "#000000", 1.0, None);
+        let full = String::from_utf8(full.finish().unwrap()).unwrap();
+        let full = points(elements(&full)[0]);
+        let simplified = points(written[0]);
+        assert_eq!(full.len(), 101);
+        assert_eq!(simplified.first(), full.first());
+        assert_eq!(simplified.last(), full.last());
+    }
+
+    #[test]
+    fn test_simplify_to() {
+        let zigzag: Vec<(f64, f64)> = (0..50).map(|i| (i as f64, (i % 2) as f64 * 10.0)).collect();
+        for max in [2, 3, 10, 49] {
+            let simplified = simplify_to(&zigzag, max);
+            assert!(simplified.len() <= max);
+            assert_eq!(simplified.first(), zigzag.first());
+            assert_eq!(simplified.last(), zigzag.last());
+        }
+        // Wiggles under a quarter pixel go even when the path fits, then the
+        // points nearest the line between their neighbours
+        let line = [(0.0, 0.0), (1.0, 0.1), (2.0, 0.0), (3.0, 5.0), (4.0, 0.0)];
+        let smooth = [(0.0, 0.0), (2.0, 0.0), (3.0, 5.0), (4.0, 0.0)];
+        assert_eq!(simplify_to(&line, 5), smooth);
+        assert_eq!(simplify_to(&line, 4), smooth);
+        assert_eq!(simplify_to(&line, 3), [(0.0, 0.0), (3.0, 5.0), (4.0, 0.0)]);
+    }
+}
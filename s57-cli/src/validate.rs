@@ -0,0 +1,51 @@
+//! S-58 style validation report
+
+use crate::ValidateFormat;
+use s57_interp::validate::{validate as run_checks, Severity};
+use s57_parse::S57File;
+use serde_json::json;
+
+/// Print the findings of every check; exits with status 1 on errors
+pub fn validate(file: &S57File, format: ValidateFormat) {
+    let findings = match run_checks(file) {
+        Ok(findings) => findings,
+        Err(e) => {
+            eprintln!("Error validating file: {}", e);
+            std::process::exit(1);
+        }
+    };
+    let errors = findings
+        .iter()
+        .filter(|f| f.severity() == Severity::Error)
+        .count();
+
+    match format {
+        ValidateFormat::Text => {
+            for finding in &findings {
+                println!("{}", finding);
+            }
+            println!(
+                "{} error(s), {} warning(s)",
+                errors,
+                findings.len() - errors
+            );
+        }
+        ValidateFormat::Json => {
+            let findings: Vec<_> = findings
+                .iter()
+                .map(|f| {
+                    json!({
+                        "record": f.record,
+                        "severity": f.severity().to_string(),
+                        "check": f.check.code(),
+                        "message": f.message,
+                    })
+                })
+                .collect();
+            println!("{}", serde_json::to_string_pretty(&findings).unwrap());
+        }
+    }
+    if errors > 0 {
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,247 @@
+//! Watch mode: re-run a command when the input cell or its updates change
+//!
+//! Uses modification-time polling rather than OS file notifications, which
+//! is plenty for a preview loop and behaves the same on every platform.
+
+use s57_parse::S57File;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// How often to check the watched files
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Files belonging to a cell: the base cell (.000) and its updates (.001-.999)
+///
+/// Updates are found next to the base cell by file stem. For any other file
+/// only the file itself is watched.
+fn cell_files(path: &Path) -> Vec<PathBuf> {
+    let mut files = vec![path.to_path_buf()];
+
+    let is_base_cell = path.extension().and_then(|e| e.to_str()) == Some("000");
+    let (Some(dir), Some(stem)) = (path.parent(), path.file_stem()) else {
+        return files;
+    };
+    if !is_base_cell {
+        return files;
+    }
+
+    let dir = if dir.as_os_str().is_empty() {
+        Path::new(".")
+    } else {
+        dir
+    };
+    if let Ok(entries) = std::fs::read_dir(dir) {
+        for entry in entries.flatten() {
+            let candidate = entry.path();
+            let is_update = candidate.file_stem() == Some(stem)
+                && candidate
+                    .extension()
+                    .and_then(|e| e.to_str())
+                    .is_some_and(|e| {
+                        e.len() == 3 && e != "000" && e.bytes().all(|b| b.is_ascii_digit())
+                    });
+            if is_update {
+                files.push(candidate);
+            }
+        }
+    }
+
+    files.sort();
+    files
+}
+
+/// Modification times of the watched files (None if a file is missing)
+fn snapshot(path: &Path) -> BTreeMap<PathBuf, Option<SystemTime>> {
+    cell_files(path)
+        .into_iter()
+        .map(|f| {
+            let mtime = std::fs::metadata(&f).and_then(|m| m.modified()).ok();
+            (f, mtime)
+        })
+        .collect()
+}
+
+/// Block forever, calling `run` with the reloaded cell whenever it changes
+///
+/// `load` reads the cell as the first run did, so the same options apply
+/// every time. A cell that fails to load (e.g. caught mid-write) is
+/// reported and retried on the next change instead of ending the watch.
+pub fn watch<L, F>(path: &Path, mut load: L, mut run: F)
+where
+    L: FnMut() -> Result<S57File, String>,
+    F: FnMut(&S57File),
+{
+    let mut watcher = Watcher::new(path);
+    println!(
+        "Watching {} for changes (Ctrl-C to stop)...",
+        path.display()
+    );
+
+    loop {
+        std::thread::sleep(POLL_INTERVAL);
+        watcher.poll(&mut load, &mut run);
+    }
+}
+
+/// Watcher: The watched files as last seen
+struct Watcher {
+    path: PathBuf,
+    last: BTreeMap<PathBuf, Option<SystemTime>>,
+}
+
+impl Watcher {
+    fn new(path: &Path) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            last: snapshot(path),
+        }
+    }
+
+    /// Reload and re-run if any watched file changed since the last poll;
+    /// true if it did
+    fn poll<L, F>(&mut self, load: &mut L, run: &mut F) -> bool
+    where
+        L: FnMut() -> Result<S57File, String>,
+        F: FnMut(&S57File),
+    {
+        let current = snapshot(&self.path);
+        if current == self.last {
+            return false;
+        }
+        for (file, mtime) in &current {
+            if self.last.get(file) != Some(mtime) {
+                log::info!("Changed: {}", file.display());
+            }
+        }
+        self.last = current;
+
+        match load() {
+            Ok(file) => run(&file),
+            Err(e) => eprintln!("{}", e),
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use s57_parse::iso8211::{write_file, Record};
+    use s57_parse::update::ConflictPolicy;
+
+    /// DSID and DSSI of edition 1 of GB100001, update `updn`
+    fn dsid(updn: u32) -> Record {
+        let expp = if updn == 0 { 1 } else { 2 };
+        let mut data = vec![10, 1, 0, 0, 0, expp, 1];
+        for text in ["GB100001.000", "1", &updn.to_string()] {
+            data.extend(text.bytes());
+            data.push(0x1F);
+        }
+        data.extend(b"2026010120260101");
+        data.extend(b"03.1");
+        data.extend([1, 0x1F, 0x1F, 1, 0x26, 0x02, 0x1F, 0x1E]);
+        let mut dssi = vec![2, 1, 1];
+        dssi.extend([0; 32]);
+        dssi.push(0x1E);
+        Record::from_fields([("DSID", data), ("DSSI", dssi)])
+    }
+
+    /// A LNDMRK without geometry, as inserted (RUIN 1)
+    fn landmark(rcid: u32) -> Record {
+        let mut frid = vec![100];
+        frid.extend(rcid.to_le_bytes());
+        frid.extend([255, 2]);
+        frid.extend(74u16.to_le_bytes());
+        frid.extend(1u16.to_le_bytes());
+        frid.extend([1, 0x1E]);
+        Record::from_fields([("FRID", frid)])
+    }
+
+    /// ISO 8211 bytes of a cell
+    fn cell(records: &[Record]) -> Vec<u8> {
+        write_file(records).unwrap()
+    }
+
+    /// Write a file with a set modification time, so changes show without
+    /// waiting out the file system's timestamp resolution
+    fn write(path: &Path, data: &[u8], mtime: SystemTime) {
+        std::fs::write(path, data).unwrap();
+        std::fs::File::options()
+            .write(true)
+            .open(path)
+            .unwrap()
+            .set_modified(mtime)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_changes_reload() {
+        let dir = std::env::temp_dir().join(format!("s57-watch-changes-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let base = dir.join("GB100001.000");
+        let update = dir.join("GB100001.001");
+        let other = dir.join("GB100002.001");
+        let mtime = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+        write(&base, b"base", mtime);
+        write(&update, b"update", mtime);
+
+        // A load that fails, as for a cell caught mid-write, is reported
+        // and doesn't stop the watch
+        let mut loads = 0;
+        let mut load = || {
+            loads += 1;
+            Err::<S57File, _>("Error parsing file: truncated".to_string())
+        };
+        let mut run = |_: &S57File| panic!("nothing loaded");
+        let mut watcher = Watcher::new(&base);
+        assert!(!watcher.poll(&mut load, &mut run));
+
+        // The base cell, its updates and new updates all reload; another
+        // cell's update doesn't
+        let mut changes = 0;
+        for changed in [&base, &update, &dir.join("GB100001.002"), &other] {
+            changes += 1;
+            write(changed, b"changed", mtime + Duration::from_secs(changes));
+            assert_eq!(watcher.poll(&mut load, &mut run), changed != &other);
+            assert!(!watcher.poll(&mut load, &mut run));
+        }
+        assert_eq!(loads, 3);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_update_change_reloads_merged_cell() {
+        let dir = std::env::temp_dir().join(format!("s57-watch-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let base = dir.join("GB100001.000");
+        let update = dir.join("GB100001.001");
+        let mtime = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+        write(&base, &cell(&[dsid(0), landmark(1)]), mtime);
+        write(&update, &cell(&[dsid(1), landmark(2)]), mtime);
+
+        let mut load =
+            || crate::load_cell(&base, false, Some(ConflictPolicy::Fail)).map(|(file, _)| file);
+        let mut features = Vec::new();
+        let mut run = |file: &S57File| {
+            let frids = file.records().iter().filter(|r| r.fields[0].tag == "FRID");
+            features.push(frids.count());
+        };
+        let mut watcher = Watcher::new(&base);
+        assert!(!watcher.poll(&mut load, &mut run));
+
+        // The update now inserts two features: the re-run sees them merged
+        // into the base cell's one
+        write(
+            &update,
+            &cell(&[dsid(1), landmark(2), landmark(3)]),
+            mtime + Duration::from_secs(1),
+        );
+        assert!(watcher.poll(&mut load, &mut run));
+        assert!(!watcher.poll(&mut load, &mut run));
+        assert_eq!(features, [3]);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,81 @@
+/*
+ * s57.h - C interface to the s57 parser and World
+ *
+ * Link against the s57_ffi shared or static library built by
+ * `cargo build -p s57-ffi --release`.
+ *
+ * Handles are opaque and owned by the caller; release each with its _free
+ * function (freeing NULL does nothing). Functions that fail return NULL or
+ * -1, and s57_last_error() then describes the failure. Features are indexed
+ * 0..s57_feature_count() in FOID order. Coordinates are x, y (longitude,
+ * latitude) in degrees, with the depth in metres as z for soundings.
+ */
+
+#ifndef S57_H
+#define S57_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct S57World S57World;
+typedef struct S57Geometry S57Geometry;
+typedef struct S57Attributes S57Attributes;
+
+/* Identity and class of a feature */
+typedef struct S57FeatureInfo {
+    uint16_t agen; /* producing agency (FOID AGEN) */
+    uint16_t fids; /* feature identification subdivision (FOID FIDS) */
+    uint32_t fidn; /* feature identification number (FOID FIDN) */
+    uint16_t objl; /* object class code */
+    uint8_t prim;  /* 1 point, 2 line, 3 area, 255 none */
+    uint8_t grup;  /* 1 skin of the earth, 2 other */
+} S57FeatureInfo;
+
+/* Part kinds */
+#define S57_PART_POINTS 0u   /* points of a point feature */
+#define S57_PART_LINE 1u     /* a line of a line feature */
+#define S57_PART_EXTERIOR 2u /* an exterior ring, counter-clockwise */
+#define S57_PART_INTERIOR 3u /* a hole, clockwise */
+
+/* One part of a geometry; coords stay valid until the geometry is freed */
+typedef struct S57GeometryPart {
+    const double *coords; /* point_count * dimensions doubles */
+    size_t point_count;
+    uint32_t dimensions; /* 2 (x, y), or 3 (x, y, depth) for soundings */
+    uint32_t kind;       /* S57_PART_* */
+} S57GeometryPart;
+
+/* Message of the last failed call on this thread, or NULL */
+const char *s57_last_error(void);
+
+/* Open a cell (.000), applying its update files when apply_updates != 0 */
+S57World *s57_open(const char *path, int apply_updates);
+void s57_world_free(S57World *world);
+
+size_t s57_feature_count(const S57World *world);
+int s57_feature_info(const S57World *world, size_t index, S57FeatureInfo *info);
+
+/* Geometry of a feature; a feature without any has no parts */
+S57Geometry *s57_feature_geometry(const S57World *world, size_t index);
+size_t s57_geometry_part_count(const S57Geometry *geometry);
+int s57_geometry_part(const S57Geometry *geometry, size_t index, S57GeometryPart *part);
+void s57_geometry_free(S57Geometry *geometry);
+
+/* Attributes of a feature: ATTF, then NATF, values as UTF-8 strings */
+S57Attributes *s57_feature_attributes(const S57World *world, size_t index);
+size_t s57_attributes_count(const S57Attributes *attributes);
+/* attl and national (1 for NATF) may be NULL; the string lives as long as
+ * the attributes handle */
+const char *s57_attributes_get(const S57Attributes *attributes, size_t index, uint16_t *attl,
+                               int *national);
+void s57_attributes_free(S57Attributes *attributes);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* S57_H */
@@ -0,0 +1,148 @@
+//! Feature attributes as strings
+//!
+//! ATTF attributes come first, then NATF ones, each in record order, with
+//! values as charted (already decoded from the cell's lexical level to
+//! UTF-8).
+
+use crate::{guard, handle, S57World};
+use std::ffi::{c_char, c_int, CString};
+
+/// S57Attributes: A feature's attribute codes and values
+#[derive(Debug, Clone, Default)]
+pub struct S57Attributes {
+    entries: Vec<Entry>,
+}
+
+/// Entry: One attribute, its value ready to hand out
+#[derive(Debug, Clone)]
+struct Entry {
+    attl: u16,
+    national: bool,
+    value: CString,
+}
+
+impl S57Attributes {
+    fn collect(world: &S57World, index: usize) -> Result<Self, String> {
+        let entity = world.feature(index)?;
+        let Some(attributes) = world.world.feature_attributes.get(&entity) else {
+            return Ok(Self::default());
+        };
+        let entry = |national: bool| {
+            move |(attl, value): &(u16, String)| Entry {
+                attl: *attl,
+                national,
+                // C strings end at the first NUL, so values can't hold one
+                value: CString::new(value.replace('\0', "")).unwrap_or_default(),
+            }
+        };
+        let entries = attributes
+            .attf
+            .iter()
+            .map(entry(false))
+            .chain(attributes.natf.iter().map(entry(true)))
+            .collect();
+        Ok(Self { entries })
+    }
+}
+
+/// Collect the attributes of the feature at `index`; NULL on failure
+///
+/// # Safety
+///
+/// `world` must be NULL or a live handle.
+#[no_mangle]
+pub unsafe extern "C" fn s57_feature_attributes(
+    world: *const S57World,
+    index: usize,
+) -> *mut S57Attributes {
+    guard(std::ptr::null_mut(), || {
+        let attributes = S57Attributes::collect(handle(world, "world")?, index)?;
+        Ok(Box::into_raw(Box::new(attributes)))
+    })
+}
+
+/// Number of attributes; 0 for NULL
+///
+/// # Safety
+///
+/// `attributes` must be NULL or a live handle.
+#[no_mangle]
+pub unsafe extern "C" fn s57_attributes_count(attributes: *const S57Attributes) -> usize {
+    attributes
+        .as_ref()
+        .map_or(0, |attributes| attributes.entries.len())
+}
+
+/// Value of the attribute at `index`, or NULL on failure
+///
+/// Its code is written to `attl` and, as 1 for NATF or 0 for ATTF, its
+/// kind to `national`; either may be NULL. The string is UTF-8 and stays
+/// valid until the attributes are freed.
+///
+/// # Safety
+///
+/// `attributes` must be NULL or a live handle; `attl` and `national` must
+/// each be NULL or writable.
+#[no_mangle]
+pub unsafe extern "C" fn s57_attributes_get(
+    attributes: *const S57Attributes,
+    index: usize,
+    attl: *mut u16,
+    national: *mut c_int,
+) -> *const c_char {
+    guard(std::ptr::null(), || {
+        let attributes = handle(attributes, "attributes")?;
+        let entry = attributes.entries.get(index).ok_or_else(|| {
+            format!(
+                "attribute index {} out of range ({} attributes)",
+                index,
+                attributes.entries.len()
+            )
+        })?;
+        if let Some(attl) = attl.as_mut() {
+            *attl = entry.attl;
+        }
+        if let Some(national) = national.as_mut() {
+            *national = c_int::from(entry.national);
+        }
+        Ok(entry.value.as_ptr())
+    })
+}
+
+/// Release an attributes handle
+///
+/// # Safety
+///
+/// `attributes` must be NULL or a handle from `s57_feature_attributes` not
+/// yet freed.
+#[no_mangle]
+pub unsafe extern "C" fn s57_attributes_free(attributes: *mut S57Attributes) {
+    if !attributes.is_null() {
+        drop(Box::from_raw(attributes));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::test_world;
+    use std::ffi::CStr;
+
+    #[test]
+    fn test_attributes() {
+        let world = test_world();
+        unsafe {
+            let attributes = s57_feature_attributes(&world, 1);
+            assert_eq!(s57_attributes_count(attributes), 1);
+            let (mut attl, mut national) = (0, -1);
+            let value = s57_attributes_get(attributes, 0, &mut attl, &mut national);
+            assert_eq!(CStr::from_ptr(value).to_str().unwrap(), "Mast");
+            assert_eq!((attl, national), (116, 0));
+
+            let missing =
+                s57_attributes_get(attributes, 1, std::ptr::null_mut(), std::ptr::null_mut());
+            assert!(missing.is_null());
+            s57_attributes_free(attributes);
+        }
+    }
+}
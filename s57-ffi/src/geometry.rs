@@ -0,0 +1,250 @@
+//! Feature geometry as arrays of doubles
+//!
+//! A geometry is a list of parts, each a run of points packed as x, y (and
+//! z) doubles: the points of a point feature, one part per line of a line
+//! feature, or an area's exterior rings followed by its holes. Rings are
+//! closed, exteriors counter-clockwise and holes clockwise.
+
+use crate::{guard, handle, S57World};
+use num_rational::BigRational;
+use num_traits::ToPrimitive;
+use s57_interp::ecs::EntityId;
+use s57_interp::geometry_cache::ResolvedGeometry;
+use s57_interp::topology::{ContinuityPolicy, CyclePolicy, TraversalContext};
+use std::ffi::c_int;
+
+/// Part kind: points of a point feature (soundings carry a depth)
+pub const S57_PART_POINTS: u32 = 0;
+/// Part kind: a line of a line feature
+pub const S57_PART_LINE: u32 = 1;
+/// Part kind: an exterior ring of an area
+pub const S57_PART_EXTERIOR: u32 = 2;
+/// Part kind: an interior ring (hole) of an area
+pub const S57_PART_INTERIOR: u32 = 3;
+
+/// S57Geometry: A feature's resolved geometry
+#[derive(Debug, Clone, Default)]
+pub struct S57Geometry {
+    coords: Vec<f64>,
+    parts: Vec<Part>,
+}
+
+/// Part: Where one part's points sit in the packed coordinates
+#[derive(Debug, Clone, Copy)]
+struct Part {
+    start: usize,
+    points: usize,
+    dimensions: u32,
+    kind: u32,
+}
+
+/// S57GeometryPart: One part of a geometry, borrowed from its handle
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct S57GeometryPart {
+    /// `point_count * dimensions` doubles: x, y[, z] per point
+    pub coords: *const f64,
+    /// Number of points
+    pub point_count: usize,
+    /// 2, or 3 when the points carry a depth
+    pub dimensions: u32,
+    /// One of the `S57_PART_*` kinds
+    pub kind: u32,
+}
+
+impl Default for S57GeometryPart {
+    fn default() -> Self {
+        Self {
+            coords: std::ptr::null(),
+            point_count: 0,
+            dimensions: 0,
+            kind: 0,
+        }
+    }
+}
+
+fn to_f64(value: &BigRational) -> f64 {
+    value.to_f64().unwrap_or(f64::NAN)
+}
+
+impl S57Geometry {
+    /// Resolve a feature's geometry; empty for features without any
+    fn resolve(world: &S57World, entity: EntityId) -> Result<Self, String> {
+        // Lenient like the renderer: mark gaps and tolerate shared nodes
+        let ctx = TraversalContext::new(&world.world)
+            .with_continuity_policy(ContinuityPolicy::InsertGapMarker)
+            .with_cycle_policy(CyclePolicy::AllowVisitCount(2));
+        let mut geometry = Self::default();
+        let prim = world.world.feature_meta.get(&entity).map(|meta| meta.prim);
+        if prim == Some(1) {
+            geometry.add_points(&world.world, entity);
+            return Ok(geometry);
+        }
+        let Some(resolved) = ctx.feature_geometry(entity).map_err(|e| e.to_string())? else {
+            return Ok(geometry);
+        };
+        match resolved.as_ref() {
+            ResolvedGeometry::Lines(lines) => {
+                for line in lines {
+                    geometry.add_part(S57_PART_LINE, line);
+                }
+            }
+            ResolvedGeometry::Area(boundary) => {
+                for ring in &boundary.exterior {
+                    geometry.add_part(S57_PART_EXTERIOR, ring);
+                }
+                for ring in &boundary.interior {
+                    geometry.add_part(S57_PART_INTERIOR, ring);
+                }
+            }
+        }
+        Ok(geometry)
+    }
+
+    /// One part per node a point feature references, 3D for soundings
+    fn add_points(&mut self, world: &s57_interp::ecs::World, entity: EntityId) {
+        let Some(pointers) = world.feature_pointers.get(&entity) else {
+            return;
+        };
+        for sref in &pointers.spatial_refs {
+            let Some(positions) = world.exact_positions.get(&sref.entity) else {
+                continue;
+            };
+            let depths = world.exact_depths.get(&sref.entity);
+            let start = self.coords.len();
+            for (i, (lat, lon)) in positions.lat.iter().zip(&positions.lon).enumerate() {
+                self.coords.extend([to_f64(lon), to_f64(lat)]);
+                if let Some(depths) = depths {
+                    self.coords
+                        .push(depths.depth.get(i).map_or(f64::NAN, to_f64));
+                }
+            }
+            self.parts.push(Part {
+                start,
+                points: positions.lat.len().min(positions.lon.len()),
+                dimensions: if depths.is_some() { 3 } else { 2 },
+                kind: S57_PART_POINTS,
+            });
+        }
+    }
+
+    fn add_part(&mut self, kind: u32, points: &[(BigRational, BigRational)]) {
+        if points.is_empty() {
+            return;
+        }
+        let start = self.coords.len();
+        for (lat, lon) in points {
+            self.coords.extend([to_f64(lon), to_f64(lat)]);
+        }
+        self.parts.push(Part {
+            start,
+            points: points.len(),
+            dimensions: 2,
+            kind,
+        });
+    }
+}
+
+/// Resolve the geometry of the feature at `index`; NULL on failure
+///
+/// A feature without geometry gives a geometry with no parts.
+///
+/// # Safety
+///
+/// `world` must be NULL or a live handle.
+#[no_mangle]
+pub unsafe extern "C" fn s57_feature_geometry(
+    world: *const S57World,
+    index: usize,
+) -> *mut S57Geometry {
+    guard(std::ptr::null_mut(), || {
+        let world = handle(world, "world")?;
+        let geometry = S57Geometry::resolve(world, world.feature(index)?)?;
+        Ok(Box::into_raw(Box::new(geometry)))
+    })
+}
+
+/// Number of parts; 0 for NULL
+///
+/// # Safety
+///
+/// `geometry` must be NULL or a live handle.
+#[no_mangle]
+pub unsafe extern "C" fn s57_geometry_part_count(geometry: *const S57Geometry) -> usize {
+    geometry.as_ref().map_or(0, |geometry| geometry.parts.len())
+}
+
+/// Fill `part` with the part at `index`; 0 on success, -1 on failure
+///
+/// The coordinates stay valid until the geometry is freed.
+///
+/// # Safety
+///
+/// `geometry` must be NULL or a live handle; `part` must be NULL or point
+/// to writable memory for an `S57GeometryPart`.
+#[no_mangle]
+pub unsafe extern "C" fn s57_geometry_part(
+    geometry: *const S57Geometry,
+    index: usize,
+    part: *mut S57GeometryPart,
+) -> c_int {
+    guard(-1, || {
+        let geometry = handle(geometry, "geometry")?;
+        let out = part.as_mut().ok_or("part is NULL")?;
+        let part = geometry.parts.get(index).ok_or_else(|| {
+            format!(
+                "part index {} out of range ({} parts)",
+                index,
+                geometry.parts.len()
+            )
+        })?;
+        *out = S57GeometryPart {
+            coords: geometry.coords[part.start..].as_ptr(),
+            point_count: part.points,
+            dimensions: part.dimensions,
+            kind: part.kind,
+        };
+        Ok(0)
+    })
+}
+
+/// Release a geometry handle
+///
+/// # Safety
+///
+/// `geometry` must be NULL or a handle from `s57_feature_geometry` not yet
+/// freed.
+#[no_mangle]
+pub unsafe extern "C" fn s57_geometry_free(geometry: *mut S57Geometry) {
+    if !geometry.is_null() {
+        drop(Box::from_raw(geometry));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::test_world;
+
+    #[test]
+    fn test_point_geometry() {
+        let world = test_world();
+        unsafe {
+            let geometry = s57_feature_geometry(&world, 0);
+            assert_eq!(s57_geometry_part_count(geometry), 1);
+            let mut part = S57GeometryPart::default();
+            assert_eq!(s57_geometry_part(geometry, 0, &mut part), 0);
+            assert_eq!(
+                (part.point_count, part.dimensions, part.kind),
+                (1, 2, S57_PART_POINTS)
+            );
+            let coords = std::slice::from_raw_parts(part.coords, 2);
+            assert!((coords[0] - -70.2).abs() < 1e-9, "{:?}", coords);
+            assert!((coords[1] - 43.6).abs() < 1e-9, "{:?}", coords);
+
+            assert_eq!(s57_geometry_part(geometry, 1, &mut part), -1);
+            s57_geometry_free(geometry);
+            assert!(s57_feature_geometry(&world, 5).is_null());
+        }
+    }
+}
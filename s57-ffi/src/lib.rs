@@ -0,0 +1,383 @@
+//! C ABI for the S-57 parser and World
+//!
+//! A C or C++ program opens a cell, walks its features by index, and reads
+//! each feature's identity, geometry (as arrays of doubles) and attributes
+//! (as strings), without going through GDAL. The declarations are in
+//! `include/s57.h`.
+//!
+//! # Conventions
+//!
+//! - Handles (`S57World`, `S57Geometry`, `S57Attributes`) are opaque and
+//!   owned by the caller, who releases each with its `_free` function.
+//!   Freeing NULL does nothing.
+//! - Functions that can fail return NULL or -1 and leave a message for
+//!   `s57_last_error`, kept per thread until the next failure.
+//! - Features are indexed 0..`s57_feature_count` in FOID order, which is
+//!   stable for a given file.
+//! - Coordinates are x, y (longitude, latitude) in degrees, with the depth
+//!   in metres as z for soundings.
+//! - A panic inside the library is caught and reported as an error rather
+//!   than unwinding into the caller.
+
+mod attributes;
+mod geometry;
+
+pub use attributes::*;
+pub use geometry::*;
+
+use s57_interp::ecs::{EntityId, World};
+use s57_parse::S57File;
+use std::cell::RefCell;
+use std::ffi::{c_char, c_int, CStr, CString};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::path::Path;
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// S57World: An opened cell and its features in FOID order
+pub struct S57World {
+    world: World,
+    features: Vec<EntityId>,
+}
+
+impl S57World {
+    /// Wrap a built World, indexing its features by FOID
+    ///
+    /// Features sharing a FOID keep the order of their records in the
+    /// cell, so the indices are the same on every open.
+    pub fn new(world: World) -> Self {
+        let mut features: Vec<_> = world
+            .feature_meta
+            .iter()
+            .map(|(entity, meta)| {
+                let record = world.record_sources.get(entity).map(|source| source.record);
+                (
+                    (meta.foid.agen, meta.foid.fidn, meta.foid.fids, record),
+                    *entity,
+                )
+            })
+            .collect();
+        features.sort();
+        Self {
+            features: features.into_iter().map(|(_, entity)| entity).collect(),
+            world,
+        }
+    }
+
+    /// Read, parse and build a cell, with its update files when asked
+    pub fn open(path: &Path, apply_updates: bool) -> Result<Self, String> {
+        let read = |path: &Path| -> Result<S57File, String> {
+            let data = std::fs::read(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+            S57File::from_bytes(&data).map_err(|e| format!("{}: {}", path.display(), e))
+        };
+        let mut file = read(path)?;
+        if apply_updates {
+            for update_path in s57_parse::update::update_paths(path) {
+                file.apply_update(&read(&update_path)?)
+                    .map_err(|e| format!("{}: {}", update_path.display(), e))?;
+            }
+        }
+        let world = s57_interp::build_world(&file).map_err(|e| e.to_string())?;
+        Ok(Self::new(world))
+    }
+
+    /// The World the handle wraps
+    pub fn world(&self) -> &World {
+        &self.world
+    }
+
+    /// Entity of the feature at `index`
+    fn feature(&self, index: usize) -> Result<EntityId, String> {
+        self.features.get(index).copied().ok_or_else(|| {
+            format!(
+                "feature index {} out of range ({} features)",
+                index,
+                self.features.len()
+            )
+        })
+    }
+}
+
+/// S57FeatureInfo: Identity and class of a feature
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct S57FeatureInfo {
+    /// Producing agency (FOID AGEN)
+    pub agen: u16,
+    /// Feature identification subdivision (FOID FIDS)
+    pub fids: u16,
+    /// Feature identification number (FOID FIDN)
+    pub fidn: u32,
+    /// Object class code (OBJL)
+    pub objl: u16,
+    /// Geometric primitive: 1 point, 2 line, 3 area, 255 none
+    pub prim: u8,
+    /// Group: 1 skin of the earth, 2 other
+    pub grup: u8,
+}
+
+/// Record `message` as this thread's last error
+fn set_error(message: impl Into<Vec<u8>>) {
+    let mut message = message.into();
+    message.retain(|&b| b != 0);
+    let message = CString::new(message).unwrap_or_default();
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(message));
+}
+
+/// Run `f`, turning an error or a panic into `fallback` and a last error
+fn guard<T>(fallback: T, f: impl FnOnce() -> Result<T, String>) -> T {
+    match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(value)) => value,
+        Ok(Err(message)) => {
+            set_error(message);
+            fallback
+        }
+        Err(panic) => {
+            let detail = panic
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| panic.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            set_error(format!("internal error: {}", detail));
+            fallback
+        }
+    }
+}
+
+/// A handle argument, or an error naming it when NULL
+///
+/// # Safety
+///
+/// `ptr` must be NULL or point to a live `T`.
+unsafe fn handle<'a, T>(ptr: *const T, name: &str) -> Result<&'a T, String> {
+    ptr.as_ref().ok_or_else(|| format!("{} is NULL", name))
+}
+
+/// Message of the last failed call on this thread, or NULL if none failed
+///
+/// The string stays valid until the next failure on the same thread.
+#[no_mangle]
+pub extern "C" fn s57_last_error() -> *const c_char {
+    LAST_ERROR.with(|last| {
+        last.borrow()
+            .as_ref()
+            .map_or(std::ptr::null(), |message| message.as_ptr())
+    })
+}
+
+/// Open a cell (`.000`); with `apply_updates` nonzero, the update files
+/// next to it (`.001`, `.002`, ...) are applied in order
+///
+/// Returns NULL on failure.
+///
+/// # Safety
+///
+/// `path` must be NULL or a NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn s57_open(path: *const c_char, apply_updates: c_int) -> *mut S57World {
+    guard(std::ptr::null_mut(), || {
+        if path.is_null() {
+            return Err("path is NULL".to_string());
+        }
+        let path = CStr::from_ptr(path)
+            .to_str()
+            .map_err(|_| "path is not valid UTF-8".to_string())?;
+        let world = S57World::open(Path::new(path), apply_updates != 0)?;
+        Ok(Box::into_raw(Box::new(world)))
+    })
+}
+
+/// Release a World handle and everything it holds
+///
+/// # Safety
+///
+/// `world` must be NULL or a handle from `s57_open` not yet freed.
+#[no_mangle]
+pub unsafe extern "C" fn s57_world_free(world: *mut S57World) {
+    if !world.is_null() {
+        drop(Box::from_raw(world));
+    }
+}
+
+/// Number of features; 0 for NULL
+///
+/// # Safety
+///
+/// `world` must be NULL or a live handle.
+#[no_mangle]
+pub unsafe extern "C" fn s57_feature_count(world: *const S57World) -> usize {
+    world.as_ref().map_or(0, |world| world.features.len())
+}
+
+/// Fill `info` for the feature at `index`; 0 on success, -1 on failure
+///
+/// # Safety
+///
+/// `world` must be NULL or a live handle; `info` must be NULL or point to
+/// writable memory for an `S57FeatureInfo`.
+#[no_mangle]
+pub unsafe extern "C" fn s57_feature_info(
+    world: *const S57World,
+    index: usize,
+    info: *mut S57FeatureInfo,
+) -> c_int {
+    guard(-1, || {
+        let world = handle(world, "world")?;
+        let info = info.as_mut().ok_or("info is NULL")?;
+        let entity = world.feature(index)?;
+        let meta = world
+            .world
+            .feature_meta
+            .get(&entity)
+            .ok_or("feature has no FRID")?;
+        *info = S57FeatureInfo {
+            agen: meta.foid.agen,
+            fids: meta.foid.fids,
+            fidn: meta.foid.fidn,
+            objl: meta.objl,
+            prim: meta.prim,
+            grup: meta.grup,
+        };
+        Ok(0)
+    })
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use s57_parse::iso8211::{write_file, Field, Record};
+
+    const UNIT_TERMINATOR: u8 = 0x1F;
+    const FIELD_TERMINATOR: u8 = 0x1E;
+
+    /// Dataset parameters: COMF 10^7, SOMF 10
+    fn dspm() -> Record {
+        let mut dspm = vec![20];
+        dspm.extend(1u32.to_le_bytes());
+        dspm.extend([2, 3, 23]);
+        dspm.extend(22000u32.to_le_bytes());
+        dspm.extend([1, 1, 1, 1]);
+        dspm.extend(10_000_000u32.to_le_bytes());
+        dspm.extend(10u32.to_le_bytes());
+        dspm.extend([UNIT_TERMINATOR, FIELD_TERMINATOR]);
+        Record::from_fields(vec![Field::new("DSPM".to_string(), dspm)])
+    }
+
+    /// An isolated node at (lat, lon) in units of 1e-7 degrees
+    fn node(rcid: u32, lat: i32, lon: i32) -> Record {
+        let mut vrid = vec![110];
+        vrid.extend(rcid.to_le_bytes());
+        vrid.extend(1u16.to_le_bytes());
+        vrid.extend([1, FIELD_TERMINATOR]);
+        let mut sg2d = lat.to_le_bytes().to_vec();
+        sg2d.extend(lon.to_le_bytes());
+        sg2d.push(FIELD_TERMINATOR);
+        Record::from_fields(vec![
+            Field::new("VRID".to_string(), vrid),
+            Field::new("SG2D".to_string(), sg2d),
+        ])
+    }
+
+    /// A LNDMRK point feature on node `node` with OBJNAM
+    fn landmark(fidn: u32, node: u32, objnam: &str) -> Record {
+        let mut frid = vec![100];
+        frid.extend(fidn.to_le_bytes());
+        frid.extend([1, 2]);
+        frid.extend(74u16.to_le_bytes());
+        frid.extend(1u16.to_le_bytes());
+        frid.extend([1, FIELD_TERMINATOR]);
+
+        let mut foid = 550u16.to_le_bytes().to_vec();
+        foid.extend(fidn.to_le_bytes());
+        foid.extend(1u16.to_le_bytes());
+        foid.push(FIELD_TERMINATOR);
+
+        let mut attf = 116u16.to_le_bytes().to_vec();
+        attf.extend(objnam.bytes());
+        attf.extend([UNIT_TERMINATOR, FIELD_TERMINATOR]);
+
+        let mut fspt = vec![110];
+        fspt.extend(node.to_le_bytes());
+        fspt.extend([255, 255, 255, FIELD_TERMINATOR]);
+
+        Record::from_fields(vec![
+            Field::new("FRID".to_string(), frid),
+            Field::new("FOID".to_string(), foid),
+            Field::new("ATTF".to_string(), attf),
+            Field::new("FSPT".to_string(), fspt),
+        ])
+    }
+
+    /// A cell of two landmarks, written out in FOID order 2, 1
+    pub(crate) fn test_world() -> S57World {
+        let records = vec![
+            dspm(),
+            node(1, 436_000_000, -702_000_000),
+            node(2, 437_000_000, -703_000_000),
+            landmark(2, 2, "Mast"),
+            landmark(1, 1, "Tower"),
+        ];
+        let data = write_file(&records).unwrap();
+        let file = S57File::from_bytes(&data).unwrap();
+        S57World::new(s57_interp::build_world(&file).unwrap())
+    }
+
+    #[test]
+    fn test_features_in_foid_order() {
+        let world = test_world();
+        unsafe {
+            assert_eq!(s57_feature_count(&world), 2);
+            let mut info = S57FeatureInfo::default();
+            assert_eq!(s57_feature_info(&world, 0, &mut info), 0);
+            assert_eq!((info.fidn, info.objl, info.prim), (1, 74, 1));
+            assert_eq!(s57_feature_info(&world, 1, &mut info), 0);
+            assert_eq!(info.fidn, 2);
+
+            assert_eq!(s57_feature_info(&world, 2, &mut info), -1);
+            let error = CStr::from_ptr(s57_last_error()).to_str().unwrap();
+            assert!(error.contains("out of range"), "{}", error);
+        }
+    }
+
+    #[test]
+    fn test_repeated_foid_in_record_order() {
+        // Give the Mast (record 3) the Tower's (record 4) FOID, as an edit
+        // or a merge could; the earlier record comes first every time
+        for _ in 0..4 {
+            let mut world = test_world().world;
+            let mast = world
+                .feature_meta
+                .iter()
+                .find(|(_, meta)| meta.foid.fidn == 2)
+                .map(|(&entity, _)| entity)
+                .unwrap();
+            world.feature_meta.get_mut(&mast).unwrap().foid.fidn = 1;
+            let world = S57World::new(world);
+            let names: Vec<_> = world
+                .features
+                .iter()
+                .map(|entity| world.world.feature_attributes[entity].attf[0].1.as_str())
+                .collect();
+            assert_eq!(names, ["Mast", "Tower"]);
+        }
+    }
+
+    #[test]
+    fn test_open_errors() {
+        unsafe {
+            assert!(s57_open(std::ptr::null(), 0).is_null());
+            let error = CStr::from_ptr(s57_last_error()).to_str().unwrap();
+            assert_eq!(error, "path is NULL");
+
+            let missing = CString::new("/nonexistent/CELL.000").unwrap();
+            assert!(s57_open(missing.as_ptr(), 1).is_null());
+            let error = CStr::from_ptr(s57_last_error()).to_str().unwrap();
+            assert!(error.starts_with("/nonexistent/CELL.000: "), "{}", error);
+
+            assert_eq!(s57_feature_count(std::ptr::null()), 0);
+            s57_world_free(std::ptr::null_mut());
+        }
+    }
+}
@@ -0,0 +1,270 @@
+//! f64 coordinates for the fast geometry path
+//!
+//! Exact positions are what validation and the planar predicates need, but
+//! resolving and converting BigRationals for every vertex makes drawing a
+//! large cell slow. `ApproxPositions` and `ApproxDepths` hold the same
+//! positions as f64, alongside the exact (or fixed-point) components rather
+//! than in place of them, so a World can serve both.
+//!
+//! They are filled during `build_world` with `ParseOptions::approx_positions`,
+//! or afterwards by `World::compute_approx`. The topology walker and cursors
+//! resolve into `ApproxPoint`s (`EdgeWalker::resolve_line_approx`,
+//! `FeatureBoundaryCursor::resolve_boundary_approx`,
+//! `TraversalContext::feature_geometry_approx`) and read these components
+//! first, so a render never touches a BigRational. Without them the f64 path
+//! still works, converting fixed-point or exact positions as it reads them.
+
+use crate::ecs::{EntityId, ExactDepths, ExactPositions, World, INLINE_COORDINATES};
+use crate::fixed::{FixedDepths, FixedPositions};
+use crate::topology::ApproxPoint;
+use num_traits::ToPrimitive;
+use smallvec::SmallVec;
+
+/// ApproxCoordinates: f64 values for one vector, inline for points and short lines
+pub type ApproxCoordinates = SmallVec<[f64; INLINE_COORDINATES]>;
+
+/// ApproxPositions: Latitude and longitude in degrees, as f64
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ApproxPositions {
+    pub lat: ApproxCoordinates,
+    pub lon: ApproxCoordinates,
+}
+
+impl ApproxPositions {
+    /// Number of points
+    pub fn len(&self) -> usize {
+        self.lat.len().min(self.lon.len())
+    }
+
+    /// True if there are no points
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// (lat, lon) of each point
+    pub fn points(&self) -> impl Iterator<Item = ApproxPoint> + '_ {
+        self.lat.iter().copied().zip(self.lon.iter().copied())
+    }
+}
+
+impl From<&ExactPositions> for ApproxPositions {
+    fn from(exact: &ExactPositions) -> Self {
+        let to_f64 = |v: &num_rational::BigRational| v.to_f64().unwrap_or(0.0);
+        Self {
+            lat: exact.lat.iter().map(to_f64).collect(),
+            lon: exact.lon.iter().map(to_f64).collect(),
+        }
+    }
+}
+
+impl From<&FixedPositions> for ApproxPositions {
+    fn from(fixed: &FixedPositions) -> Self {
+        let comf = fixed.comf as f64;
+        Self {
+            lat: fixed.y.iter().map(|&y| y as f64 / comf).collect(),
+            lon: fixed.x.iter().map(|&x| x as f64 / comf).collect(),
+        }
+    }
+}
+
+/// ApproxDepths: Sounding depths as f64, in DUNI units
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ApproxDepths {
+    pub depth: ApproxCoordinates,
+    /// Units (from DUNI)
+    pub units: u16,
+}
+
+impl From<&ExactDepths> for ApproxDepths {
+    fn from(exact: &ExactDepths) -> Self {
+        Self {
+            depth: exact
+                .depth
+                .iter()
+                .map(|d| d.to_f64().unwrap_or(0.0))
+                .collect(),
+            units: exact.units,
+        }
+    }
+}
+
+impl From<&FixedDepths> for ApproxDepths {
+    fn from(fixed: &FixedDepths) -> Self {
+        let somf = fixed.somf as f64;
+        Self {
+            depth: fixed.z.iter().map(|&z| z as f64 / somf).collect(),
+            units: fixed.units,
+        }
+    }
+}
+
+/// ApproxBox: Extent in f64 degrees
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ApproxBox {
+    pub min_lat: f64,
+    pub min_lon: f64,
+    pub max_lat: f64,
+    pub max_lon: f64,
+}
+
+impl ApproxBox {
+    /// Smallest box holding every point; None if there are none
+    pub fn from_points(points: impl IntoIterator<Item = ApproxPoint>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let (lat, lon) = points.next()?;
+        let mut bbox = Self {
+            min_lat: lat,
+            min_lon: lon,
+            max_lat: lat,
+            max_lon: lon,
+        };
+        for (lat, lon) in points {
+            bbox.min_lat = bbox.min_lat.min(lat);
+            bbox.min_lon = bbox.min_lon.min(lon);
+            bbox.max_lat = bbox.max_lat.max(lat);
+            bbox.max_lon = bbox.max_lon.max(lon);
+        }
+        Some(bbox)
+    }
+}
+
+impl World {
+    /// Fill `approx_positions`/`approx_depths` for every positioned vector
+    ///
+    /// Reads fixed-point values where the World has them, else exact ones,
+    /// replacing any f64 copies already there. Returns the number of
+    /// vectors with positions.
+    pub fn compute_approx(&mut self) -> usize {
+        self.approx_positions = self
+            .exact_positions
+            .iter()
+            .map(|(&entity, exact)| (entity, exact.into()))
+            .chain(
+                self.fixed_positions
+                    .iter()
+                    .map(|(&entity, fixed)| (entity, fixed.into())),
+            )
+            .collect();
+        self.approx_depths = self
+            .exact_depths
+            .iter()
+            .map(|(&entity, exact)| (entity, exact.into()))
+            .chain(
+                self.fixed_depths
+                    .iter()
+                    .map(|(&entity, fixed)| (entity, fixed.into())),
+            )
+            .collect();
+        self.approx_positions.len()
+    }
+
+    /// Bring a vector's f64 copies back in line after its positions changed
+    /// (in either backend); vectors without copies are left without
+    pub(crate) fn refresh_approx(&mut self, vector: EntityId) {
+        if self.approx_positions.contains_key(&vector) {
+            match (
+                self.fixed_positions.get(&vector),
+                self.exact_positions.get(&vector),
+            ) {
+                (Some(fixed), _) => self.approx_positions.insert(vector, fixed.into()),
+                (None, Some(exact)) => self.approx_positions.insert(vector, exact.into()),
+                (None, None) => self.approx_positions.remove(&vector),
+            };
+        }
+        if self.approx_depths.contains_key(&vector) {
+            match (
+                self.fixed_depths.get(&vector),
+                self.exact_depths.get(&vector),
+            ) {
+                (Some(fixed), _) => self.approx_depths.insert(vector, fixed.into()),
+                (None, Some(exact)) => self.approx_depths.insert(vector, exact.into()),
+                (None, None) => self.approx_depths.remove(&vector),
+            };
+        }
+    }
+
+    /// f64 (lat, lon) points of a vector, from whichever component holds them
+    pub fn approx_points(&self, entity: EntityId) -> Option<ApproxPositions> {
+        if let Some(approx) = self.approx_positions.get(&entity) {
+            return Some(approx.clone());
+        }
+        match self.fixed_positions.get(&entity) {
+            Some(fixed) => Some(fixed.into()),
+            None => self.exact_positions.get(&entity).map(Into::into),
+        }
+    }
+
+    /// Extent of a feature in f64, as `feature_bbox` but from any backend
+    pub fn feature_bbox_approx(&self, feature: EntityId) -> Option<ApproxBox> {
+        let pointers = self.feature_pointers.get(&feature)?;
+        let mut vectors = Vec::new();
+        for sref in &pointers.spatial_refs {
+            vectors.extend(self.approx_points(sref.entity));
+            if let Some(topology) = self.vector_topology.get(&sref.entity) {
+                for neighbor in &topology.neighbors {
+                    vectors.extend(self.approx_points(neighbor.entity));
+                }
+            }
+        }
+        ApproxBox::from_points(vectors.iter().flat_map(ApproxPositions::points))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::EntityType;
+    use num_bigint::BigInt;
+    use num_rational::BigRational;
+    use smallvec::smallvec;
+
+    #[test]
+    fn test_compute_approx() {
+        let mut world = World::new();
+        let exact = world.create_entity(EntityType::Vector);
+        let fixed = world.create_entity(EntityType::Vector);
+        let half = BigRational::new(BigInt::from(1), BigInt::from(2));
+        world.exact_positions.insert(
+            exact,
+            ExactPositions {
+                lat: smallvec![half.clone()],
+                lon: smallvec![-half.clone()],
+            },
+        );
+        world.exact_depths.insert(
+            exact,
+            ExactDepths {
+                depth: smallvec![half.clone()],
+                units: 1,
+            },
+        );
+        world.fixed_positions.insert(
+            fixed,
+            FixedPositions {
+                y: smallvec![15, 25],
+                x: smallvec![-5, 5],
+                comf: 10,
+            },
+        );
+
+        assert_eq!(world.compute_approx(), 2);
+        let points: Vec<_> = world.approx_positions[&exact].points().collect();
+        assert_eq!(points, [(0.5, -0.5)]);
+        assert_eq!(world.approx_depths[&exact].depth.as_slice(), [0.5]);
+        assert_eq!(world.approx_positions[&fixed].lat.as_slice(), [1.5, 2.5]);
+        assert_eq!(
+            world.positions_f64(fixed),
+            Some((vec![1.5, 2.5], vec![-0.5, 0.5]))
+        );
+
+        // Edits keep the copies current
+        world.exact_positions.get_mut(&exact).unwrap().lat[0] = BigInt::from(3).into();
+        world.refresh_approx(exact);
+        assert_eq!(world.approx_positions[&exact].lat.as_slice(), [3.0]);
+
+        world.remove_entity(fixed);
+        assert!(!world.approx_positions.contains_key(&fixed));
+    }
+}
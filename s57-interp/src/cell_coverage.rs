@@ -0,0 +1,274 @@
+//! Coverage gaps and overlaps between neighbouring cells
+//!
+//! Each cell declares the area it covers with M_COVR features (CATCOV=1).
+//! Cells of the same navigational purpose (usage band, DSID INTU) should
+//! tile without overlapping and without leaving slivers between them. This
+//! compares the M_COVR polygons of a set of cells, band by band, and reports
+//! where two cells both claim an area and where a gap is enclosed by
+//! coverage on all sides.
+//!
+//! Only gaps fully surrounded by coverage are found: the outer edge of a
+//! band's coverage can't be told apart from the edge of the charted area.
+
+use crate::clip::{intersection, union};
+use crate::ecs::World;
+use crate::geometry::{locate_in_ring, PointLocation};
+use crate::geometry_cache::ResolvedGeometry;
+use crate::region::BoundingBox;
+use crate::topology::{AreaBoundary, Ring, TraversalContext};
+use num_traits::ToPrimitive;
+use std::fmt;
+
+/// M_COVR: Coverage
+const OBJL_M_COVR: u16 = 302;
+/// CATCOV: Category of coverage (1 = coverage available)
+const ATTL_CATCOV: u16 = 18;
+
+/// CellCoverage: The area one cell covers, and its usage band
+#[derive(Debug, Clone)]
+pub struct CellCoverage {
+    /// Cell name, e.g. "US5MA1AA"
+    pub name: String,
+    /// Navigational purpose (1=overview .. 6=berthing; 0 if unknown)
+    pub band: u8,
+    /// Union of the cell's CATCOV=1 M_COVR areas
+    pub area: AreaBoundary,
+}
+
+impl CellCoverage {
+    /// Coverage of a loaded cell
+    ///
+    /// The band comes from DSID INTU, or failing that from the third
+    /// character of the cell name (the ENC naming convention).
+    pub fn from_world(name: impl Into<String>, world: &World) -> Self {
+        let name = name.into();
+        let band = world
+            .dataset_id
+            .as_ref()
+            .map(|id| id.intu)
+            .filter(|intu| *intu != 0)
+            .or_else(|| name.chars().nth(2)?.to_digit(10).map(|d| d as u8))
+            .unwrap_or(0);
+        CellCoverage {
+            name,
+            band,
+            area: world.covered_area(),
+        }
+    }
+}
+
+/// CoverageIssueKind: What is wrong between cells of a band
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoverageIssueKind {
+    /// Area covered by no cell, enclosed by coverage
+    Gap,
+    /// Area covered by two cells at once
+    Overlap,
+}
+
+/// CoverageIssue: A gap or overlap found between cells of one band
+#[derive(Debug, Clone)]
+pub struct CoverageIssue {
+    pub kind: CoverageIssueKind,
+    /// Usage band the cells belong to
+    pub band: u8,
+    /// Names of the cells involved
+    pub cells: Vec<String>,
+    /// The uncovered or doubly covered area
+    pub area: AreaBoundary,
+}
+
+impl fmt::Display for CoverageIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let what = match self.kind {
+            CoverageIssueKind::Gap => "gap next to",
+            CoverageIssueKind::Overlap => "overlap between",
+        };
+        write!(
+            f,
+            "Band {}: {} {} ({:.3e} sq deg",
+            self.band,
+            what,
+            self.cells.join(", "),
+            self.area.area().to_f64().unwrap_or(f64::NAN)
+        )?;
+        if let Some(bbox) = bounds(&self.area) {
+            let deg = |v: &num_rational::BigRational| v.to_f64().unwrap_or(f64::NAN);
+            write!(
+                f,
+                " within {:.6},{:.6} .. {:.6},{:.6}",
+                deg(&bbox.min_lat),
+                deg(&bbox.min_lon),
+                deg(&bbox.max_lat),
+                deg(&bbox.max_lon)
+            )?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl World {
+    /// Area covered by data: the union of CATCOV=1 M_COVR features
+    ///
+    /// M_COVR areas whose boundary can't be resolved are skipped.
+    pub fn covered_area(&self) -> AreaBoundary {
+        let ctx = TraversalContext::new(self);
+        let mut covered = AreaBoundary::default();
+        for (feature, meta) in &self.feature_meta {
+            if meta.objl != OBJL_M_COVR {
+                continue;
+            }
+            let catcov = self
+                .feature_attributes
+                .get(feature)
+                .and_then(|a| a.get(ATTL_CATCOV));
+            if catcov.map(str::trim) != Some("1") {
+                continue;
+            }
+            match ctx.feature_geometry(*feature) {
+                Ok(Some(geometry)) => {
+                    if let ResolvedGeometry::Area(boundary) = geometry.as_ref() {
+                        covered = union(&covered, boundary);
+                    }
+                }
+                Ok(None) => {}
+                Err(e) => crate::diag::debug!("Skipping M_COVR {:?}: {}", meta.foid, e),
+            }
+        }
+        covered
+    }
+}
+
+/// Compare the coverage of cells band by band
+///
+/// Reports every pair of same-band cells whose coverage overlaps, and every
+/// hole in a band's combined coverage. Cells that merely share an edge are
+/// fine. Issues are ordered by band.
+pub fn coverage_issues(cells: &[CellCoverage]) -> Vec<CoverageIssue> {
+    let mut bands: Vec<u8> = cells.iter().map(|c| c.band).collect();
+    bands.sort_unstable();
+    bands.dedup();
+
+    let mut issues = Vec::new();
+    for band in bands {
+        let members: Vec<(&CellCoverage, Option<BoundingBox>)> = cells
+            .iter()
+            .filter(|c| c.band == band)
+            .map(|c| (c, bounds(&c.area)))
+            .collect();
+
+        for (i, (a, a_box)) in members.iter().enumerate() {
+            for (b, b_box) in &members[i + 1..] {
+                let (Some(a_box), Some(b_box)) = (a_box, b_box) else {
+                    continue;
+                };
+                if !a_box.intersects(b_box) {
+                    continue;
+                }
+                let both = intersection(&a.area, &b.area);
+                if !both.exterior.is_empty() {
+                    issues.push(CoverageIssue {
+                        kind: CoverageIssueKind::Overlap,
+                        band,
+                        cells: vec![a.name.clone(), b.name.clone()],
+                        area: both,
+                    });
+                }
+            }
+        }
+
+        let combined = members
+            .iter()
+            .fold(AreaBoundary::default(), |acc, (c, _)| union(&acc, &c.area));
+        for hole in combined.interior {
+            let cells = members
+                .iter()
+                .filter(|(c, _)| touches(&c.area, &hole))
+                .map(|(c, _)| c.name.clone())
+                .collect();
+            let mut exterior = hole;
+            exterior.reverse();
+            issues.push(CoverageIssue {
+                kind: CoverageIssueKind::Gap,
+                band,
+                cells,
+                area: AreaBoundary {
+                    exterior: vec![exterior],
+                    interior: Vec::new(),
+                },
+            });
+        }
+    }
+    issues
+}
+
+/// True if any vertex of `ring` lies on the boundary of `area`
+fn touches(area: &AreaBoundary, ring: &Ring) -> bool {
+    ring.iter().any(|point| {
+        area.exterior
+            .iter()
+            .chain(&area.interior)
+            .any(|r| locate_in_ring(r, point) == PointLocation::Boundary)
+    })
+}
+
+/// Bounding box of an area's exterior rings
+fn bounds(area: &AreaBoundary) -> Option<BoundingBox> {
+    BoundingBox::from_points(area.exterior.iter().flatten().map(|(lat, lon)| (lat, lon)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_bigint::BigInt;
+    use num_rational::BigRational;
+
+    fn cell(name: &str, band: u8, min_lon: i64, max_lon: i64) -> CellCoverage {
+        let int = |v: i64| BigRational::from(BigInt::from(v));
+        CellCoverage {
+            name: name.to_string(),
+            band,
+            area: AreaBoundary::rectangle(int(0), int(min_lon), int(2), int(max_lon)),
+        }
+    }
+
+    #[test]
+    fn test_coverage_issues() {
+        // Band 5 tiles cleanly; band 4 overlaps by one unit
+        let cells = [
+            cell("A5", 5, 0, 2),
+            cell("B5", 5, 2, 4),
+            cell("A4", 4, 0, 3),
+            cell("B4", 4, 2, 5),
+        ];
+        let issues = coverage_issues(&cells);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].kind, CoverageIssueKind::Overlap);
+        assert_eq!(issues[0].band, 4);
+        assert_eq!(issues[0].cells, ["A4", "B4"]);
+        assert_eq!(issues[0].area.area(), BigRational::from(BigInt::from(2)));
+
+        // A ring of four cells around an uncovered square
+        let int = |v: i64| BigRational::from(BigInt::from(v));
+        let rect = |a, b, c, d| AreaBoundary::rectangle(int(a), int(b), int(c), int(d));
+        let ring = [
+            ("S", rect(0, 0, 1, 3)),
+            ("N", rect(2, 0, 3, 3)),
+            ("W", rect(1, 0, 2, 1)),
+            ("E", rect(1, 2, 2, 3)),
+        ]
+        .map(|(name, area)| CellCoverage {
+            name: name.to_string(),
+            band: 5,
+            area,
+        });
+        let issues = coverage_issues(&ring);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].kind, CoverageIssueKind::Gap);
+        assert_eq!(issues[0].cells.len(), 4);
+        assert_eq!(issues[0].area.area(), int(1));
+        assert!(issues[0]
+            .to_string()
+            .starts_with("Band 5: gap next to S, N, W, E"));
+    }
+}
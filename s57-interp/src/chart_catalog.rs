@@ -0,0 +1,314 @@
+//! ChartCatalog: cells of an exchange set on disk
+//!
+//! Wraps the CATALOG.031 ExchangeSet with the directory it lives in, so cells
+//! can be selected by coverage and loaded into Worlds, and the files on disk
+//! checked against the catalogue.
+
+use crate::ecs::World;
+use crate::region::BoundingBox;
+use crate::{build_world_with_options, ParseOptions};
+use s57_parse::catalog::{crc32, CatalogueEntry, ExchangeSet};
+use s57_parse::{ParseError, ParseErrorKind, Result, S57File};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// File name of an exchange set catalogue
+const CATALOG_FILE: &str = "CATALOG.031";
+
+/// MemberStatus: How a catalogue entry compares with the file on disk
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MemberStatus {
+    /// Present, with the catalogued CRC
+    Ok,
+    /// Present; the catalogue gives no CRC to check it against
+    Unchecked,
+    /// Present, but its CRC-32 differs from CATD CRCS
+    CrcMismatch { expected: u32, actual: u32 },
+    /// Not found on disk
+    Missing,
+    /// Found but couldn't be read
+    Unreadable(String),
+}
+
+impl MemberStatus {
+    /// True for files that are present and not known to be corrupt
+    pub fn is_ok(&self) -> bool {
+        matches!(self, MemberStatus::Ok | MemberStatus::Unchecked)
+    }
+}
+
+impl fmt::Display for MemberStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MemberStatus::Ok => write!(f, "ok"),
+            MemberStatus::Unchecked => write!(f, "present (no CRC)"),
+            MemberStatus::CrcMismatch { expected, actual } => {
+                write!(
+                    f,
+                    "CRC mismatch: catalogue {:08X}, file {:08X}",
+                    expected, actual
+                )
+            }
+            MemberStatus::Missing => write!(f, "missing"),
+            MemberStatus::Unreadable(e) => write!(f, "unreadable: {}", e),
+        }
+    }
+}
+
+/// MemberCheck: One catalogue entry and the state of its file
+#[derive(Debug, Clone)]
+pub struct MemberCheck<'a> {
+    pub entry: &'a CatalogueEntry,
+    pub path: PathBuf,
+    pub status: MemberStatus,
+}
+
+/// ExchangeSetCheck: Catalogue entries checked against the files on disk
+#[derive(Debug, Clone)]
+pub struct ExchangeSetCheck<'a> {
+    /// Every catalogue entry, in catalogue order
+    pub members: Vec<MemberCheck<'a>>,
+    /// Files under the root that the catalogue doesn't list, sorted
+    pub extra: Vec<PathBuf>,
+}
+
+impl ExchangeSetCheck<'_> {
+    /// True if every entry is present and intact and no file is unlisted
+    pub fn is_ok(&self) -> bool {
+        self.extra.is_empty() && self.members.iter().all(|m| m.status.is_ok())
+    }
+}
+
+/// ChartCatalog: Exchange set catalogue rooted at a directory
+#[derive(Debug, Clone)]
+pub struct ChartCatalog {
+    /// Directory that CATD FILE paths are relative to
+    root: PathBuf,
+    /// Parsed catalogue
+    exchange_set: ExchangeSet,
+}
+
+impl ChartCatalog {
+    /// Create a catalogue from an already parsed ExchangeSet
+    pub fn new(root: impl Into<PathBuf>, exchange_set: ExchangeSet) -> Self {
+        Self {
+            root: root.into(),
+            exchange_set,
+        }
+    }
+
+    /// Open a CATALOG.031 file; cell paths resolve relative to its directory
+    pub fn open(catalog_path: &Path) -> Result<Self> {
+        let data = std::fs::read(catalog_path).map_err(|e| {
+            ParseError::at(
+                ParseErrorKind::Other(format!("{}: {}", catalog_path.display(), e)),
+                0,
+            )
+        })?;
+        let exchange_set = ExchangeSet::from_bytes(&data)?;
+        let root = catalog_path
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+        Ok(Self::new(root, exchange_set))
+    }
+
+    /// The underlying exchange set
+    pub fn exchange_set(&self) -> &ExchangeSet {
+        &self.exchange_set
+    }
+
+    /// Path of a catalogue entry on disk
+    ///
+    /// CATD paths use '\' separators in many exchange sets; they are
+    /// normalised to the platform separator.
+    pub fn path_of(&self, entry: &CatalogueEntry) -> PathBuf {
+        entry
+            .file
+            .split(['/', '\\'])
+            .filter(|part| !part.is_empty())
+            .fold(self.root.clone(), |path, part| path.join(part))
+    }
+
+    /// All base cells (.000), without their update files
+    pub fn base_cells(&self) -> impl Iterator<Item = &CatalogueEntry> {
+        self.exchange_set
+            .cells()
+            .filter(|e| e.file_name().to_ascii_uppercase().ends_with(".000"))
+    }
+
+    /// Base cells (.000) whose catalogue coverage touches `bbox`
+    ///
+    /// Cells without coverage in the catalogue are included, since they
+    /// can't be ruled out without loading them.
+    pub fn cells_in(&self, bbox: &BoundingBox) -> Vec<&CatalogueEntry> {
+        self.base_cells()
+            .filter(|e| match e.bounds() {
+                Some((s, w, n, east)) => BoundingBox::from_degrees(s, w, n, east)
+                    .map(|cell| cell.intersects(bbox))
+                    .unwrap_or(true),
+                None => true,
+            })
+            .collect()
+    }
+
+    /// Check every catalogue entry against the file on disk
+    ///
+    /// Files are matched case-insensitively, since exchange sets are often
+    /// copied between file systems that disagree on case. Any file under the
+    /// root that no entry names is reported as extra, except the catalogue
+    /// itself.
+    pub fn verify(&self) -> Result<ExchangeSetCheck<'_>> {
+        let mut on_disk = Vec::new();
+        find_files(&self.root, &mut on_disk).map_err(|e| {
+            ParseError::at(
+                ParseErrorKind::Other(format!("{}: {}", self.root.display(), e)),
+                0,
+            )
+        })?;
+        let key = |path: &Path| path.to_string_lossy().to_lowercase();
+        let disk_paths: HashMap<_, _> = on_disk.iter().map(|p| (key(p), p)).collect();
+
+        let mut listed = HashSet::new();
+        let members = self
+            .exchange_set
+            .entries
+            .iter()
+            .map(|entry| {
+                let catalogued = self.path_of(entry);
+                listed.insert(key(&catalogued));
+                let (path, status) = match disk_paths.get(&key(&catalogued)) {
+                    Some(path) => ((*path).clone(), check_file(entry, path)),
+                    None => (catalogued, MemberStatus::Missing),
+                };
+                MemberCheck {
+                    entry,
+                    path,
+                    status,
+                }
+            })
+            .collect();
+
+        let mut extra: Vec<_> = on_disk
+            .into_iter()
+            .filter(|p| !listed.contains(&key(p)))
+            .filter(|p| {
+                !p.file_name()
+                    .is_some_and(|n| n.eq_ignore_ascii_case(CATALOG_FILE))
+            })
+            .collect();
+        extra.sort();
+        Ok(ExchangeSetCheck { members, extra })
+    }
+
+    /// Read, parse and build the World for a catalogue entry
+    pub fn load_world(&self, entry: &CatalogueEntry, options: &ParseOptions) -> Result<World> {
+        let path = self.path_of(entry);
+        let data = std::fs::read(&path).map_err(|e| {
+            ParseError::at(
+                ParseErrorKind::Other(format!("{}: {}", path.display(), e)),
+                0,
+            )
+        })?;
+        let file = S57File::from_bytes(&data)?;
+        build_world_with_options(&file, options)
+    }
+}
+
+/// Compare a file's CRC-32 with its catalogue entry
+fn check_file(entry: &CatalogueEntry, path: &Path) -> MemberStatus {
+    let data = match std::fs::read(path) {
+        Ok(data) => data,
+        Err(e) => return MemberStatus::Unreadable(e.to_string()),
+    };
+    let Some(expected) = entry.crc() else {
+        return MemberStatus::Unchecked;
+    };
+    let actual = crc32(&data);
+    if actual == expected {
+        MemberStatus::Ok
+    } else {
+        MemberStatus::CrcMismatch { expected, actual }
+    }
+}
+
+/// Every file under `dir`, recursively
+fn find_files(dir: &Path, out: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            find_files(&path, out)?;
+        } else {
+            out.push(path);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use s57_parse::ddr::SubfieldValue;
+
+    fn entry(rcid: u32, file: &str, crcs: &str) -> CatalogueEntry {
+        let text = |v: &str| SubfieldValue::String(v.to_string().into());
+        let group = [
+            ("RCNM", text("CD")),
+            ("RCID", text(&rcid.to_string())),
+            ("FILE", text(file)),
+            ("IMPL", text("BIN")),
+            ("CRCS", text(crcs)),
+        ];
+        CatalogueEntry::from_group(&group).unwrap()
+    }
+
+    #[test]
+    fn test_verify_exchange_set() {
+        let root = std::env::temp_dir().join(format!("s57-exchange-set-{}", std::process::id()));
+        let write = |path: &str, data: &[u8]| {
+            let path = root.join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, data).unwrap();
+        };
+        write("CATALOG.031", b"catalogue");
+        write("GB100001/GB100001.000", b"cell");
+        write("GB100001/gb100001.001", b"update");
+        write("GB100002/GB100002.000", b"corrupted");
+        write("README.TXT", b"not catalogued");
+
+        let crc = |data: &[u8]| format!("{:08X}", crc32(data));
+        let set = ExchangeSet {
+            entries: vec![
+                entry(1, "CATALOG.031", ""),
+                entry(2, "GB100001\\GB100001.000", &crc(b"cell")),
+                entry(3, "GB100001\\GB100001.001", &crc(b"update")),
+                entry(4, "GB100002\\GB100002.000", &crc(b"cell")),
+                entry(5, "GB100003\\GB100003.000", &crc(b"cell")),
+            ],
+            cross_references: Vec::new(),
+        };
+        let catalog = ChartCatalog::new(&root, set);
+        let check = catalog.verify().unwrap();
+        std::fs::remove_dir_all(&root).unwrap();
+
+        let statuses: Vec<_> = check.members.iter().map(|m| m.status.clone()).collect();
+        assert_eq!(
+            statuses,
+            [
+                MemberStatus::Unchecked,
+                MemberStatus::Ok,
+                MemberStatus::Ok,
+                MemberStatus::CrcMismatch {
+                    expected: crc32(b"cell"),
+                    actual: crc32(b"corrupted"),
+                },
+                MemberStatus::Missing,
+            ]
+        );
+        // The update was found under its on-disk (lower case) name
+        assert!(check.members[2].path.ends_with("gb100001.001"));
+        assert_eq!(check.extra, [root.join("README.TXT")]);
+        assert!(!check.is_ok());
+    }
+}
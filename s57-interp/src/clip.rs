@@ -0,0 +1,493 @@
+//! Boolean operations on area boundaries
+//!
+//! Intersection, difference and union of two `AreaBoundary`s on the exact
+//! BigRational coordinates, for clipping cells to a region, finding gaps and
+//! overlaps between coverage polygons, and building quilting masks.
+//! `clip_line` cuts a polyline to an area the same way.
+//!
+//! The method is a plain edge overlay: every ring edge of both operands is
+//! split wherever it meets an edge of the other, each piece is kept or
+//! dropped depending on which side of the other operand it lies, and the
+//! kept pieces are stitched back into rings. Because all intersection points
+//! are exact, pieces that coincide (shared chart boundaries, a cell clipped
+//! along its own edge) compare equal and need no tolerance.
+
+use crate::geometry::{cross, orientation, PointLocation, RingOrientation};
+use crate::topology::{AreaBoundary, Ring};
+use num_rational::BigRational;
+use num_traits::{One, Signed, Zero};
+use std::collections::{HashMap, HashSet};
+
+type Point = (BigRational, BigRational);
+/// Directed edge, start to end
+pub(crate) type Edge = (Point, Point);
+
+/// BooleanOp: Which parts of the two operands to keep
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BooleanOp {
+    /// Area covered by both
+    Intersection,
+    /// Area covered by the first but not the second
+    Difference,
+    /// Area covered by either
+    Union,
+}
+
+/// Area covered by both `a` and `b`
+pub fn intersection(a: &AreaBoundary, b: &AreaBoundary) -> AreaBoundary {
+    overlay(a, b, BooleanOp::Intersection)
+}
+
+/// Area covered by `a` but not by `b`
+pub fn difference(a: &AreaBoundary, b: &AreaBoundary) -> AreaBoundary {
+    overlay(a, b, BooleanOp::Difference)
+}
+
+/// Area covered by `a` or `b`
+pub fn union(a: &AreaBoundary, b: &AreaBoundary) -> AreaBoundary {
+    overlay(a, b, BooleanOp::Union)
+}
+
+/// Apply a boolean operation to two areas
+///
+/// Operands may be wound either way; the result is wound exterior
+/// counter-clockwise, holes clockwise. Each operand must be a valid area
+/// (rings don't cross themselves or each other). Empty results have no
+/// rings.
+pub fn overlay(a: &AreaBoundary, b: &AreaBoundary, op: BooleanOp) -> AreaBoundary {
+    let mut a = a.clone();
+    let mut b = b.clone();
+    a.normalize_winding();
+    b.normalize_winding();
+
+    let (a_pieces, b_pieces) = split_edges(&directed_edges(&a), &directed_edges(&b));
+    let b_set: HashSet<&Edge> = b_pieces.iter().collect();
+
+    let mut kept = Vec::new();
+    for (p, q) in &a_pieces {
+        let keep = match b.locate(&midpoint(p, q)) {
+            PointLocation::Inside => op == BooleanOp::Intersection,
+            PointLocation::Outside => op != BooleanOp::Intersection,
+            // Shared edge: kept once, from `a`, if the areas lie on the
+            // same side of it (intersection, union) or opposite sides
+            // (difference)
+            PointLocation::Boundary => {
+                let same = b_set.contains(&(p.clone(), q.clone()));
+                same != (op == BooleanOp::Difference)
+            }
+        };
+        if keep {
+            kept.push((p.clone(), q.clone()));
+        }
+    }
+    for (p, q) in b_pieces {
+        match (a.locate(&midpoint(&p, &q)), op) {
+            (PointLocation::Inside, BooleanOp::Intersection)
+            | (PointLocation::Outside, BooleanOp::Union) => kept.push((p, q)),
+            // Inside `a`, `b`'s edge bounds the difference with `b` on the
+            // other side, so it runs the other way
+            (PointLocation::Inside, BooleanOp::Difference) => kept.push((q, p)),
+            _ => {}
+        }
+    }
+
+    let mut result = AreaBoundary::default();
+    for mut ring in stitch(kept) {
+        drop_collinear(&mut ring);
+        match orientation(&ring) {
+            RingOrientation::CounterClockwise => result.exterior.push(ring),
+            RingOrientation::Clockwise => result.interior.push(ring),
+            RingOrientation::Degenerate => {}
+        }
+    }
+    result
+}
+
+/// Parts of a polyline inside `area`, its boundary included
+///
+/// The line is cut wherever it meets a ring edge, and consecutive pieces
+/// that lie inside are joined again, so a line entering and leaving once
+/// comes back as one polyline.
+pub fn clip_line(line: &[Point], area: &AreaBoundary) -> Vec<Vec<Point>> {
+    let segments: Vec<Edge> = line
+        .windows(2)
+        .filter(|pair| pair[0] != pair[1])
+        .map(|pair| (pair[0].clone(), pair[1].clone()))
+        .collect();
+    let (pieces, _) = split_edges(&segments, &directed_edges(area));
+
+    let mut lines = Vec::new();
+    let mut current: Vec<Point> = Vec::new();
+    for (p, q) in pieces {
+        let inside = area.locate(&midpoint(&p, &q)) != PointLocation::Outside;
+        if !inside || current.last() != Some(&p) {
+            if current.len() > 1 {
+                lines.push(std::mem::take(&mut current));
+            }
+            current.clear();
+        }
+        if inside {
+            if current.is_empty() {
+                current.push(p);
+            }
+            current.push(q);
+        }
+    }
+    if current.len() > 1 {
+        lines.push(current);
+    }
+    lines
+}
+
+impl AreaBoundary {
+    /// Axis-aligned rectangle, e.g. a cell's or a tile's extent
+    pub fn rectangle(
+        min_lat: BigRational,
+        min_lon: BigRational,
+        max_lat: BigRational,
+        max_lon: BigRational,
+    ) -> Self {
+        let ring = vec![
+            (min_lat.clone(), min_lon.clone()),
+            (min_lat.clone(), max_lon.clone()),
+            (max_lat.clone(), max_lon),
+            (max_lat, min_lon.clone()),
+            (min_lat, min_lon),
+        ];
+        AreaBoundary {
+            exterior: vec![ring],
+            interior: Vec::new(),
+        }
+    }
+
+    /// True if the area has no rings
+    pub fn is_empty(&self) -> bool {
+        self.exterior.is_empty() && self.interior.is_empty()
+    }
+}
+
+/// Every ring edge of an area as a (start, end) pair, zero-length ones dropped
+fn directed_edges(area: &AreaBoundary) -> Vec<Edge> {
+    let mut edges = Vec::new();
+    for ring in area.exterior.iter().chain(&area.interior) {
+        for (i, p) in ring.iter().enumerate() {
+            let q = &ring[(i + 1) % ring.len()];
+            if p != q {
+                edges.push((p.clone(), q.clone()));
+            }
+        }
+    }
+    edges
+}
+
+/// Split both edge sets at every point where an edge of one meets the other
+fn split_edges(a: &[Edge], b: &[Edge]) -> (Vec<Edge>, Vec<Edge>) {
+    // Split parameters (0..1 along the edge) found per edge
+    let mut a_params: Vec<Vec<BigRational>> = vec![Vec::new(); a.len()];
+    let mut b_params: Vec<Vec<BigRational>> = vec![Vec::new(); b.len()];
+
+    for (i, p) in a.iter().enumerate() {
+        for (j, q) in b.iter().enumerate() {
+            let (t, u) = meeting_params(p, q);
+            a_params[i].extend(t);
+            b_params[j].extend(u);
+        }
+    }
+
+    (
+        split_at(a, a_params).into_iter().flatten().collect(),
+        split_at(b, b_params).into_iter().flatten().collect(),
+    )
+}
+
+/// Split segments at every point where they meet one another
+///
+/// Returns each segment's pieces, in order along it.
+pub(crate) fn split_segments(segments: &[Edge]) -> Vec<Vec<Edge>> {
+    let mut params: Vec<Vec<BigRational>> = vec![Vec::new(); segments.len()];
+    for (i, p) in segments.iter().enumerate() {
+        for (j, q) in segments.iter().enumerate().skip(i + 1) {
+            let (t, u) = meeting_params(p, q);
+            params[i].extend(t);
+            params[j].extend(u);
+        }
+    }
+    split_at(segments, params)
+}
+
+/// Parameters (0..1) along each of two edges where they meet
+fn meeting_params((p, p2): &Edge, (q, q2): &Edge) -> (Vec<BigRational>, Vec<BigRational>) {
+    let mut t_params = Vec::new();
+    let mut u_params = Vec::new();
+    if !boxes_overlap(p, p2, q, q2) {
+        return (t_params, u_params);
+    }
+    let r = sub(p2, p);
+    let s = sub(q2, q);
+    let qp = sub(q, p);
+    let denom = cross2(&r, &s);
+    if !denom.is_zero() {
+        let t = cross2(&qp, &s) / &denom;
+        let u = cross2(&qp, &r) / &denom;
+        if in_unit(&t) && in_unit(&u) {
+            t_params.push(t);
+            u_params.push(u);
+        }
+    } else if cross2(&qp, &r).is_zero() {
+        // Collinear: each edge is split at the other's endpoints
+        for end in [q, q2] {
+            let t = project(p, &r, end);
+            if in_unit(&t) {
+                t_params.push(t);
+            }
+        }
+        for end in [p, p2] {
+            let u = project(q, &s, end);
+            if in_unit(&u) {
+                u_params.push(u);
+            }
+        }
+    }
+    (t_params, u_params)
+}
+
+/// Cut each edge at its parameters, in order along the edge
+fn split_at(edges: &[Edge], params: Vec<Vec<BigRational>>) -> Vec<Vec<Edge>> {
+    let mut split = Vec::new();
+    for ((p, q), mut ts) in edges.iter().zip(params) {
+        let mut pieces = Vec::new();
+        ts.sort();
+        ts.dedup();
+        let r = sub(q, p);
+        let mut start = p.clone();
+        for t in ts {
+            if t.is_zero() || t.is_one() {
+                continue;
+            }
+            let point = (&p.0 + &r.0 * &t, &p.1 + &r.1 * &t);
+            pieces.push((start, point.clone()));
+            start = point;
+        }
+        pieces.push((start, q.clone()));
+        split.push(pieces);
+    }
+    split
+}
+
+/// Join directed edges end to start into closed rings
+///
+/// Where several kept edges leave the same vertex (two result rings touch
+/// there), the sharpest left turn is taken, which keeps each ring around
+/// a single face. Chains that don't close are dropped.
+pub(crate) fn stitch(edges: Vec<Edge>) -> Vec<Ring> {
+    let mut outgoing: HashMap<&Point, Vec<usize>> = HashMap::new();
+    for (i, (p, _)) in edges.iter().enumerate() {
+        outgoing.entry(p).or_default().push(i);
+    }
+    let mut used = vec![false; edges.len()];
+    let mut rings = Vec::new();
+
+    for first in 0..edges.len() {
+        if used[first] {
+            continue;
+        }
+        used[first] = true;
+        let start = &edges[first].0;
+        let mut ring = vec![start.clone()];
+        let mut current = first;
+        loop {
+            let (from, to) = &edges[current];
+            ring.push(to.clone());
+            if to == start {
+                rings.push(ring);
+                break;
+            }
+            let back = sub(from, to);
+            let next = outgoing
+                .get(to)
+                .into_iter()
+                .flatten()
+                .copied()
+                .filter(|&e| !used[e])
+                .min_by(|&x, &y| {
+                    let dx = sub(&edges[x].1, to);
+                    let dy = sub(&edges[y].1, to);
+                    clockwise_from(&back, &dx, &dy)
+                });
+            match next {
+                Some(e) => {
+                    used[e] = true;
+                    current = e;
+                }
+                None => {
+                    crate::diag::debug!("Dropping unclosed chain of {} points", ring.len());
+                    break;
+                }
+            }
+        }
+    }
+    rings
+}
+
+/// Order two directions by clockwise angle from `reference`
+///
+/// A direction equal to `reference` (straight back along the incoming
+/// edge) sorts last.
+fn clockwise_from(reference: &Point, a: &Point, b: &Point) -> std::cmp::Ordering {
+    // 0: clockwise of the reference, 1: opposite it, 2: counter-clockwise
+    // of it, 3: the reference direction itself
+    let half = |d: &Point| {
+        let side = cross2(reference, d);
+        if side.is_negative() {
+            0
+        } else if side.is_positive() {
+            2
+        } else if dot(reference, d).is_negative() {
+            1
+        } else {
+            3
+        }
+    };
+    // Within a half, `a` comes first if `b` is clockwise of it
+    half(a)
+        .cmp(&half(b))
+        .then_with(|| cross2(a, b).cmp(&BigRational::zero()))
+}
+
+/// Remove vertices lying on the straight line between their neighbours
+pub(crate) fn drop_collinear(ring: &mut Ring) {
+    // Work on the open ring, then close it again
+    if ring.len() > 1 && ring.first() == ring.last() {
+        ring.pop();
+    }
+    let mut i = 0;
+    while ring.len() >= 3 && i < ring.len() {
+        let n = ring.len();
+        let prev = &ring[(i + n - 1) % n];
+        let next = &ring[(i + 1) % n];
+        if cross(prev, next, &ring[i]).is_zero() {
+            ring.remove(i);
+            i = i.saturating_sub(1);
+        } else {
+            i += 1;
+        }
+    }
+    if let Some(first) = ring.first().cloned() {
+        ring.push(first);
+    }
+}
+
+pub(crate) fn midpoint(p: &Point, q: &Point) -> Point {
+    let two = BigRational::from_integer(2.into());
+    ((&p.0 + &q.0) / &two, (&p.1 + &q.1) / &two)
+}
+
+fn sub(a: &Point, b: &Point) -> Point {
+    (&a.0 - &b.0, &a.1 - &b.1)
+}
+
+/// Cross product of two (lat, lon) vectors, lon as x
+fn cross2(a: &Point, b: &Point) -> BigRational {
+    &a.1 * &b.0 - &a.0 * &b.1
+}
+
+fn dot(a: &Point, b: &Point) -> BigRational {
+    &a.0 * &b.0 + &a.1 * &b.1
+}
+
+/// Parameter of `point` projected onto the line p + t r
+fn project(p: &Point, r: &Point, point: &Point) -> BigRational {
+    dot(&sub(point, p), r) / dot(r, r)
+}
+
+fn in_unit(t: &BigRational) -> bool {
+    !t.is_negative() && *t <= BigRational::one()
+}
+
+fn boxes_overlap(p: &Point, p2: &Point, q: &Point, q2: &Point) -> bool {
+    let overlap = |a1: &BigRational, a2: &BigRational, b1: &BigRational, b2: &BigRational| {
+        a1.max(a2) >= b1.min(b2) && b1.max(b2) >= a1.min(a2)
+    };
+    overlap(&p.0, &p2.0, &q.0, &q2.0) && overlap(&p.1, &p2.1, &q.1, &q2.1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_bigint::BigInt;
+
+    fn int(v: i64) -> BigRational {
+        BigInt::from(v).into()
+    }
+
+    fn square(min_lat: i64, min_lon: i64, max_lat: i64, max_lon: i64) -> AreaBoundary {
+        AreaBoundary::rectangle(int(min_lat), int(min_lon), int(max_lat), int(max_lon))
+    }
+
+    #[test]
+    fn test_overlapping_squares() {
+        let a = square(0, 0, 4, 4);
+        let b = square(2, 2, 6, 6);
+
+        let both = intersection(&a, &b);
+        assert_eq!(both.exterior.len(), 1);
+        assert_eq!(both.exterior[0].len(), 5);
+        assert_eq!(both.area(), int(4));
+
+        assert_eq!(difference(&a, &b).area(), int(12));
+        assert_eq!(union(&a, &b).area(), int(28));
+    }
+
+    #[test]
+    fn test_shared_edges_and_holes() {
+        // Side by side: nothing in common, union is one rectangle
+        let left = square(0, 0, 2, 2);
+        let right = square(0, 2, 2, 4);
+        assert!(intersection(&left, &right).is_empty());
+        let joined = union(&left, &right);
+        assert_eq!(joined.exterior, square(0, 0, 2, 4).exterior);
+
+        // Clipping to its own extent changes nothing
+        assert_eq!(intersection(&left, &left).area(), int(4));
+        assert!(difference(&left, &left).is_empty());
+
+        // Cutting a hole, then filling it back in
+        let outer = square(0, 0, 4, 4);
+        let inner = square(1, 1, 3, 3);
+        let ring = difference(&outer, &inner);
+        assert_eq!((ring.exterior.len(), ring.interior.len()), (1, 1));
+        assert_eq!(ring.area(), int(12));
+        assert_eq!(union(&ring, &inner).area(), int(16));
+        assert!(intersection(&ring, &inner).is_empty());
+
+        // Touching at a single corner: two separate rings
+        let corner = union(&square(0, 0, 1, 1), &square(1, 1, 2, 2));
+        assert_eq!(corner.exterior.len(), 2);
+        assert_eq!(corner.area(), int(2));
+    }
+
+    #[test]
+    fn test_clip_line() {
+        let point = |lat: i64, lon: i64| (int(lat), int(lon));
+        let area = square(0, 0, 4, 4);
+
+        // In, out and back in: two pieces, cut at the boundary
+        let line = [point(2, -2), point(2, 6), point(3, 6), point(3, 2)];
+        assert_eq!(
+            clip_line(&line, &area),
+            [
+                vec![point(2, 0), point(2, 4)],
+                vec![point(3, 4), point(3, 2)],
+            ]
+        );
+
+        // Wholly inside stays whole; along the boundary counts as inside
+        let inside = [point(1, 1), point(1, 3), point(3, 3)];
+        assert_eq!(clip_line(&inside, &area), [inside.to_vec()]);
+        let edge = [point(0, 0), point(0, 4)];
+        assert_eq!(clip_line(&edge, &area), [edge.to_vec()]);
+
+        // Wholly outside leaves nothing
+        assert!(clip_line(&[point(5, 5), point(6, 6)], &area).is_empty());
+    }
+}
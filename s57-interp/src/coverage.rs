@@ -0,0 +1,193 @@
+//! Attribute coverage per object class
+//!
+//! For each object class in a World, counts how many of its features carry
+//! each attribute (ATTF or NATF, with a non-blank value). Useful as a quick
+//! data-quality overview: a DEPARE without DRVAL1, or lights missing COLOUR,
+//! show up as less than 100% coverage.
+
+use crate::ecs::World;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// AttributeCount: How many features of a class carry one attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AttributeCount {
+    /// Attribute code (ATTL)
+    pub attl: u16,
+    /// Number of features with a non-blank value for it
+    pub features: usize,
+}
+
+/// ClassCoverage: Attribute usage for one object class
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClassCoverage {
+    /// Object class code (OBJL)
+    pub objl: u16,
+    /// Number of features of this class
+    pub features: usize,
+    /// Populated attributes, sorted by ATTL
+    pub attributes: Vec<AttributeCount>,
+}
+
+impl ClassCoverage {
+    /// Fraction (0.0..=1.0) of this class's features that carry `attl`
+    pub fn fraction(&self, attl: u16) -> f64 {
+        let count = self
+            .attributes
+            .iter()
+            .find(|a| a.attl == attl)
+            .map_or(0, |a| a.features);
+        if self.features == 0 {
+            0.0
+        } else {
+            count as f64 / self.features as f64
+        }
+    }
+}
+
+/// AttributeCoverage: Per-class attribute usage across a World
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AttributeCoverage {
+    /// One entry per object class present, sorted by OBJL
+    pub classes: Vec<ClassCoverage>,
+}
+
+impl AttributeCoverage {
+    /// Look up a class by OBJL code
+    pub fn class(&self, objl: u16) -> Option<&ClassCoverage> {
+        self.classes.iter().find(|c| c.objl == objl)
+    }
+}
+
+impl fmt::Display for AttributeCoverage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, class) in self.classes.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            let acronym = s57_catalogue::decode_object(class.objl)
+                .map(|c| c.to_string())
+                .unwrap_or_else(|| class.objl.to_string());
+            write!(f, "{} ({} features)", acronym, class.features)?;
+            for attr in &class.attributes {
+                let name = s57_catalogue::AttributeInfo::from_code(attr.attl)
+                    .map(|info| info.acronym.to_string())
+                    .unwrap_or_else(|| attr.attl.to_string());
+                write!(
+                    f,
+                    "\n  {:<8} {:>8} {:>5.1}%",
+                    name,
+                    attr.features,
+                    100.0 * class.fraction(attr.attl)
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl World {
+    /// Report which attributes each object class uses, and how often
+    ///
+    /// A feature counts towards an attribute once even if it appears in both
+    /// ATTF and NATF; blank values (the S-57 "unknown" encoding) are ignored.
+    pub fn attribute_coverage(&self) -> AttributeCoverage {
+        // objl -> (feature count, attl -> features with it)
+        let mut classes: BTreeMap<u16, (usize, BTreeMap<u16, usize>)> = BTreeMap::new();
+
+        for (feature, meta) in &self.feature_meta {
+            let (count, attributes) = classes.entry(meta.objl).or_default();
+            *count += 1;
+
+            let Some(attrs) = self.feature_attributes.get(feature) else {
+                continue;
+            };
+            let mut populated: Vec<u16> = attrs
+                .attf
+                .iter()
+                .chain(&attrs.natf)
+                .filter(|(_, value)| !value.trim().is_empty())
+                .map(|(attl, _)| *attl)
+                .collect();
+            populated.sort_unstable();
+            populated.dedup();
+            for attl in populated {
+                *attributes.entry(attl).or_default() += 1;
+            }
+        }
+
+        AttributeCoverage {
+            classes: classes
+                .into_iter()
+                .map(|(objl, (features, attributes))| ClassCoverage {
+                    objl,
+                    features,
+                    attributes: attributes
+                        .into_iter()
+                        .map(|(attl, features)| AttributeCount { attl, features })
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::{EntityType, FeatureAttributes, FeatureMeta};
+    use s57_parse::bitstring::FoidKey;
+
+    fn add_feature(world: &mut World, fidn: u32, objl: u16, attf: &[(u16, &str)]) {
+        let feature = world.create_entity(EntityType::Feature);
+        world.feature_meta.insert(
+            feature,
+            FeatureMeta {
+                foid: FoidKey {
+                    agen: 550,
+                    fidn,
+                    fids: 1,
+                },
+                prim: 3,
+                grup: 1,
+                objl,
+                rver: 1,
+                ruin: 1,
+            },
+        );
+        world.feature_attributes.insert(
+            feature,
+            FeatureAttributes {
+                attf: attf.iter().map(|(a, v)| (*a, v.to_string())).collect(),
+                natf: Vec::new(),
+            },
+        );
+    }
+
+    #[test]
+    fn test_attribute_coverage() {
+        let mut world = World::new();
+        // DEPARE (42) with DRVAL1 (87) on both, DRVAL2 (88) on one
+        add_feature(&mut world, 1, 42, &[(87, "0"), (88, "5")]);
+        add_feature(&mut world, 2, 42, &[(87, "5"), (88, " ")]);
+        // LNDARE (71) without attributes
+        add_feature(&mut world, 3, 71, &[]);
+
+        let coverage = world.attribute_coverage();
+        assert_eq!(coverage.classes.len(), 2);
+
+        let depare = coverage.class(42).unwrap();
+        assert_eq!(depare.features, 2);
+        assert_eq!(depare.fraction(87), 1.0);
+        assert_eq!(depare.fraction(88), 0.5);
+        assert_eq!(depare.fraction(116), 0.0);
+
+        let lndare = coverage.class(71).unwrap();
+        assert_eq!(lndare.features, 1);
+        assert!(lndare.attributes.is_empty());
+
+        let text = coverage.to_string();
+        assert!(text.starts_with("DEPARE (2 features)"));
+        assert!(text.contains("DRVAL2          1  50.0%"));
+    }
+}
@@ -0,0 +1,255 @@
+//! Curve densification for ARCC geometry
+//!
+//! S-57 can encode edges as arcs, ellipses or curves (ARCC + AR2D/EL2D/CT2D)
+//! instead of SG2D point lists. Renderers only understand polylines, so these
+//! helpers turn the construction points into interpolated vertices.
+//!
+//! Trigonometry needs floating point, so interpolation is done in f64 on a
+//! local equirectangular plane (x = lon * cos(lat0), y = lat) and callers snap
+//! the results back onto the dataset's COMF grid. Construction points are
+//! returned unchanged so edge endpoints still match their connected nodes.
+
+use std::f64::consts::{PI, TAU};
+
+/// Default angular step between interpolated points (5 degrees)
+pub const DEFAULT_ANGLE_STEP: f64 = PI / 36.0;
+
+/// Upper bound on interpolated segments per curve
+pub const MAX_SEGMENTS: usize = 720;
+
+/// Arc/curve type from the ARCC ATYP subfield
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArcType {
+    /// 1 = arc through start/end points around a centre (AR2D)
+    ArcCentre,
+    /// 2 = elliptical arc (EL2D)
+    EllipticalArc,
+    /// 3 = uniform B-spline (CT2D)
+    UniformBSpline,
+    /// 4 = piecewise Bézier (CT2D)
+    PiecewiseBezier,
+    /// 5 = non-uniform rational B-spline (CT2D)
+    NonUniformRationalBSpline,
+}
+
+impl ArcType {
+    /// Decode an ATYP code
+    pub fn from_code(code: u8) -> Option<Self> {
+        match code {
+            1 => Some(ArcType::ArcCentre),
+            2 => Some(ArcType::EllipticalArc),
+            3 => Some(ArcType::UniformBSpline),
+            4 => Some(ArcType::PiecewiseBezier),
+            5 => Some(ArcType::NonUniformRationalBSpline),
+            _ => None,
+        }
+    }
+}
+
+/// Planar point (x, y) in interpolation space
+pub type Point = (f64, f64);
+
+/// Number of segments for a sweep of `sweep` radians
+///
+/// With a resolution (maximum chord length, same units as the points) the
+/// segment count follows the arc length; otherwise DEFAULT_ANGLE_STEP is used.
+fn segment_count(sweep: f64, radius: f64, resolution: Option<f64>) -> usize {
+    let n = match resolution {
+        Some(reso) if reso > 0.0 && radius > 0.0 => sweep.abs() * radius / reso,
+        _ => sweep.abs() / DEFAULT_ANGLE_STEP,
+    };
+    // Tolerate rounding noise so exact multiples don't gain a segment
+    ((n - 1e-9).ceil() as usize).clamp(1, MAX_SEGMENTS)
+}
+
+/// Clockwise sweep (negative radians) from angle `a0` to `a1`
+///
+/// Coincident start and end angles describe a full circle.
+fn clockwise_sweep(a0: f64, a1: f64) -> f64 {
+    let mut sweep = a1 - a0;
+    while sweep >= 0.0 {
+        sweep -= TAU;
+    }
+    while sweep < -TAU {
+        sweep += TAU;
+    }
+    sweep
+}
+
+/// Densify a circular arc from `start` to `end` around `centre`
+///
+/// S-57 arcs run clockwise from the start point to the end point. The radius
+/// is taken from the start point; the end point is emitted verbatim.
+pub fn densify_arc(start: Point, centre: Point, end: Point, resolution: Option<f64>) -> Vec<Point> {
+    let radius = (start.0 - centre.0).hypot(start.1 - centre.1);
+    if radius == 0.0 {
+        return vec![start, end];
+    }
+
+    let a0 = (start.1 - centre.1).atan2(start.0 - centre.0);
+    let a1 = (end.1 - centre.1).atan2(end.0 - centre.0);
+    let sweep = clockwise_sweep(a0, a1);
+    let n = segment_count(sweep, radius, resolution);
+
+    let mut points = Vec::with_capacity(n + 1);
+    points.push(start);
+    for i in 1..n {
+        let a = a0 + sweep * (i as f64) / (n as f64);
+        points.push((centre.0 + radius * a.cos(), centre.1 + radius * a.sin()));
+    }
+    points.push(end);
+    points
+}
+
+/// Densify an elliptical arc from `start` to `end`
+///
+/// The ellipse is P(t) = centre + u·cos(t) + v·sin(t), where u and v are the
+/// conjugate diameter vectors to `major` and `minor`. Like circular arcs the
+/// sweep is clockwise. Returns just the endpoints if the axes are degenerate.
+pub fn densify_ellipse(
+    start: Point,
+    centre: Point,
+    end: Point,
+    major: Point,
+    minor: Point,
+    resolution: Option<f64>,
+) -> Vec<Point> {
+    let u = (major.0 - centre.0, major.1 - centre.1);
+    let v = (minor.0 - centre.0, minor.1 - centre.1);
+    let det = u.0 * v.1 - u.1 * v.0;
+    if det == 0.0 {
+        return vec![start, end];
+    }
+
+    // Solve [u v]·(cos t, sin t) = p - centre for the parameter t
+    let param = |p: Point| {
+        let (dx, dy) = (p.0 - centre.0, p.1 - centre.1);
+        let c = (dx * v.1 - dy * v.0) / det;
+        let s = (u.0 * dy - u.1 * dx) / det;
+        s.atan2(c)
+    };
+
+    // Increasing t is counter-clockwise when (u, v) is right-handed
+    let t0 = param(start);
+    let t1 = param(end);
+    let sweep = if det > 0.0 {
+        clockwise_sweep(t0, t1)
+    } else {
+        -clockwise_sweep(-t0, -t1)
+    };
+    let radius = (u.0.hypot(u.1)).max(v.0.hypot(v.1));
+    let n = segment_count(sweep, radius, resolution);
+
+    let mut points = Vec::with_capacity(n + 1);
+    points.push(start);
+    for i in 1..n {
+        let t = t0 + sweep * (i as f64) / (n as f64);
+        let (c, s) = (t.cos(), t.sin());
+        points.push((centre.0 + u.0 * c + v.0 * s, centre.1 + u.1 * c + v.1 * s));
+    }
+    points.push(end);
+    points
+}
+
+/// Evaluate a piecewise Bézier curve of the given order
+///
+/// Control points are consumed in pieces of `order + 1`, sharing the last
+/// point of one piece with the first of the next. Leftover points that do not
+/// complete a piece are appended as straight segments.
+pub fn densify_bezier(control: &[Point], order: usize, segments_per_piece: usize) -> Vec<Point> {
+    if control.len() < 2 || order == 0 {
+        return control.to_vec();
+    }
+    let segments = segments_per_piece.clamp(1, MAX_SEGMENTS);
+
+    let mut points = vec![control[0]];
+    let mut i = 0;
+    while i + order < control.len() {
+        let piece = &control[i..=i + order];
+        for s in 1..=segments {
+            points.push(de_casteljau(piece, s as f64 / segments as f64));
+        }
+        i += order;
+    }
+    points.extend_from_slice(&control[i + 1..]);
+    points
+}
+
+/// Evaluate a single Bézier piece at parameter t (0..=1)
+fn de_casteljau(piece: &[Point], t: f64) -> Point {
+    let mut work = piece.to_vec();
+    for level in 1..work.len() {
+        for j in 0..work.len() - level {
+            work[j] = (
+                work[j].0 + (work[j + 1].0 - work[j].0) * t,
+                work[j].1 + (work[j + 1].1 - work[j].1) * t,
+            );
+        }
+    }
+    work[0]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: Point, b: Point) -> bool {
+        (a.0 - b.0).abs() < 1e-9 && (a.1 - b.1).abs() < 1e-9
+    }
+
+    #[test]
+    fn test_arc_quarter_clockwise() {
+        // From (0,1) clockwise to (1,0) around the origin: a quarter circle
+        let points = densify_arc((0.0, 1.0), (0.0, 0.0), (1.0, 0.0), None);
+
+        assert_eq!(points.len(), 19); // 90° / 5° = 18 segments
+        assert!(close(points[0], (0.0, 1.0)));
+        assert!(close(*points.last().unwrap(), (1.0, 0.0)));
+        for p in &points {
+            assert!((p.0.hypot(p.1) - 1.0).abs() < 1e-9);
+            assert!(
+                p.0 >= -1e-9 && p.1 >= -1e-9,
+                "left the first quadrant: {:?}",
+                p
+            );
+        }
+    }
+
+    #[test]
+    fn test_arc_resolution() {
+        // Half circle of radius 10 with 1-unit chords: ceil(10π) segments
+        let points = densify_arc((-10.0, 0.0), (0.0, 0.0), (10.0, 0.0), Some(1.0));
+        assert_eq!(points.len(), 33);
+        // Clockwise from west to east passes through north
+        assert!(points[16].1 > 9.9);
+    }
+
+    #[test]
+    fn test_ellipse_matches_circle() {
+        // A "circular" ellipse must agree with densify_arc
+        let arc = densify_arc((0.0, 1.0), (0.0, 0.0), (1.0, 0.0), None);
+        let ellipse = densify_ellipse(
+            (0.0, 1.0),
+            (0.0, 0.0),
+            (1.0, 0.0),
+            (1.0, 0.0),
+            (0.0, 1.0),
+            None,
+        );
+        assert_eq!(arc.len(), ellipse.len());
+        for (a, e) in arc.iter().zip(ellipse.iter()) {
+            assert!(close(*a, *e), "{:?} != {:?}", a, e);
+        }
+    }
+
+    #[test]
+    fn test_quadratic_bezier() {
+        let control = [(0.0, 0.0), (1.0, 2.0), (2.0, 0.0)];
+        let points = densify_bezier(&control, 2, 4);
+
+        assert_eq!(points.len(), 5);
+        assert!(close(points[0], (0.0, 0.0)));
+        assert!(close(points[2], (1.0, 1.0)));
+        assert!(close(points[4], (2.0, 0.0)));
+    }
+}
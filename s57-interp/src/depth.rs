@@ -0,0 +1,281 @@
+//! Depth contours classified against the mariner's safety depth
+//!
+//! An ECDIS emphasises one depth contour, the safety contour: the contour
+//! at the depth the mariner chose, or, when the cell has no contour at
+//! exactly that depth, the next deeper one. Contours shallower than the
+//! chosen depth never qualify, since water inside them may be too shallow.
+//!
+//! Depth areas (DEPARE, DRGARE) are classified into bands against the safety
+//! and deep contours, as the S-52 DEPARE procedure shades them: an area is in
+//! a band only if its whole DRVAL1..DRVAL2 range is.
+
+use crate::ecs::{parse_decimal, EntityId, EntityType, World};
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::Zero;
+use std::collections::HashMap;
+
+/// DEPARE: Depth area
+const OBJL_DEPARE: u16 = 42;
+/// DEPCNT: Depth contour
+const OBJL_DEPCNT: u16 = 43;
+/// DRGARE: Dredged area
+const OBJL_DRGARE: u16 = 46;
+/// DRVAL1: Depth range value 1
+const ATTL_DRVAL1: u16 = 87;
+/// DRVAL2: Depth range value 2
+const ATTL_DRVAL2: u16 = 88;
+/// VALDCO: Value of depth contour
+const ATTL_VALDCO: u16 = 174;
+
+/// Deep contour when none is given (metres), the S-52 default
+const DEFAULT_DEEP_CONTOUR: i64 = 30;
+
+/// DepthBand: Band of a depth area relative to the safety and deep contours
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DepthBand {
+    /// Dries at chart datum (DRVAL1 negative or missing)
+    Dry,
+    /// Shallower than the safety contour somewhere
+    Shallow,
+    /// At least the safety contour, shallower than the deep contour
+    Safe,
+    /// At least the deep contour
+    Deep,
+}
+
+/// DepthThresholds: Contours that separate the depth bands
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DepthThresholds {
+    /// Safety contour
+    pub safety: BigRational,
+    /// Deep contour; never shallower than the safety contour
+    pub deep: BigRational,
+}
+
+impl DepthThresholds {
+    /// Thresholds for a safety contour, with the 30 m deep contour
+    pub fn new(safety: BigRational) -> Self {
+        Self::new_with_deep(
+            safety,
+            BigRational::from(BigInt::from(DEFAULT_DEEP_CONTOUR)),
+        )
+    }
+
+    /// Thresholds for a safety and a deep contour
+    ///
+    /// A deep contour shallower than the safety contour is raised to it.
+    pub fn new_with_deep(safety: BigRational, deep: BigRational) -> Self {
+        let deep = deep.max(safety.clone());
+        Self { safety, deep }
+    }
+}
+
+impl DepthBand {
+    /// Band of a DRVAL1..DRVAL2 depth range
+    ///
+    /// An area reaches a contour if DRVAL1 is at least the contour and
+    /// DRVAL2, when given, is deeper than it. Without DRVAL1 the area is
+    /// taken to dry.
+    pub fn classify(
+        drval1: Option<&BigRational>,
+        drval2: Option<&BigRational>,
+        thresholds: &DepthThresholds,
+    ) -> Self {
+        let Some(drval1) = drval1 else {
+            return DepthBand::Dry;
+        };
+        let reaches =
+            |contour: &BigRational| drval1 >= contour && drval2.is_none_or(|d| d > contour);
+        if reaches(&thresholds.deep) {
+            DepthBand::Deep
+        } else if reaches(&thresholds.safety) {
+            DepthBand::Safe
+        } else if reaches(&BigRational::zero()) {
+            DepthBand::Shallow
+        } else {
+            DepthBand::Dry
+        }
+    }
+}
+
+impl World {
+    /// Depth (VALDCO) of a DEPCNT feature, or None for other features
+    pub fn contour_depth(&self, feature: EntityId) -> Option<BigRational> {
+        if self.feature_meta.get(&feature)?.objl != OBJL_DEPCNT {
+            return None;
+        }
+        parse_decimal(self.feature_attributes.get(&feature)?.get(ATTL_VALDCO)?)
+    }
+
+    /// Depths of every charted contour, shallowest first, without repeats
+    pub fn contour_depths(&self) -> Vec<BigRational> {
+        let mut depths: Vec<BigRational> = self
+            .entities_of_type(EntityType::Feature)
+            .into_iter()
+            .filter_map(|feature| self.contour_depth(feature))
+            .collect();
+        depths.sort();
+        depths.dedup();
+        depths
+    }
+
+    /// Depth of the safety contour for a chosen safety depth
+    ///
+    /// The shallowest contour at or deeper than `requested`; None if every
+    /// contour is shallower.
+    pub fn safety_contour(&self, requested: &BigRational) -> Option<BigRational> {
+        self.contour_depths()
+            .into_iter()
+            .find(|depth| depth >= requested)
+    }
+
+    /// DEPCNT features at exactly `depth`
+    pub fn contours_at(&self, depth: &BigRational) -> Vec<EntityId> {
+        self.entities_of_type(EntityType::Feature)
+            .into_iter()
+            .filter(|feature| self.contour_depth(*feature).as_ref() == Some(depth))
+            .collect()
+    }
+
+    /// Depth band of a DEPARE or DRGARE feature, or None for other features
+    pub fn depth_band(&self, feature: EntityId, thresholds: &DepthThresholds) -> Option<DepthBand> {
+        let objl = self.feature_meta.get(&feature)?.objl;
+        if objl != OBJL_DEPARE && objl != OBJL_DRGARE {
+            return None;
+        }
+        let attrs = self.feature_attributes.get(&feature);
+        let depth = |attl| attrs.and_then(|a| a.get(attl)).and_then(parse_decimal);
+        Some(DepthBand::classify(
+            depth(ATTL_DRVAL1).as_ref(),
+            depth(ATTL_DRVAL2).as_ref(),
+            thresholds,
+        ))
+    }
+
+    /// Depth band of every DEPARE and DRGARE feature
+    pub fn depth_bands(&self, thresholds: &DepthThresholds) -> HashMap<EntityId, DepthBand> {
+        self.entities_of_type(EntityType::Feature)
+            .into_iter()
+            .filter_map(|feature| Some((feature, self.depth_band(feature, thresholds)?)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::{FeatureAttributes, FeatureMeta};
+    use num_bigint::BigInt;
+    use s57_parse::bitstring::FoidKey;
+
+    fn add_feature(world: &mut World, fidn: u32, objl: u16, valdco: &str) -> EntityId {
+        let feature = world.create_entity(EntityType::Feature);
+        let foid = FoidKey {
+            agen: 550,
+            fidn,
+            fids: 1,
+        };
+        world.foid_index.insert(foid, feature);
+        world.feature_meta.insert(
+            feature,
+            FeatureMeta {
+                foid,
+                prim: 2,
+                grup: 1,
+                objl,
+                rver: 1,
+                ruin: 1,
+            },
+        );
+        world.feature_attributes.insert(
+            feature,
+            FeatureAttributes {
+                attf: vec![(ATTL_VALDCO, valdco.to_string())],
+                natf: Vec::new(),
+            },
+        );
+        feature
+    }
+
+    #[test]
+    fn test_safety_contour() {
+        let depth = |v: &str| parse_decimal(v).unwrap();
+        let mut world = World::new();
+        add_feature(&mut world, 1, OBJL_DEPCNT, "5");
+        let ten = add_feature(&mut world, 2, OBJL_DEPCNT, "10");
+        let ten_again = add_feature(&mut world, 3, OBJL_DEPCNT, "10.0");
+        add_feature(&mut world, 4, OBJL_DEPCNT, "20");
+        // Not a contour, though it carries a VALDCO
+        add_feature(&mut world, 5, 30, "8");
+
+        assert_eq!(
+            world.contour_depths(),
+            vec![depth("5"), depth("10"), depth("20")]
+        );
+        // No 8 m contour: the next deeper one is used
+        assert_eq!(world.safety_contour(&depth("8")), Some(depth("10")));
+        assert_eq!(world.safety_contour(&depth("10")), Some(depth("10")));
+        assert_eq!(world.safety_contour(&depth("25")), None);
+
+        let mut at_ten = world.contours_at(&depth("10"));
+        at_ten.sort();
+        let mut expected = vec![ten, ten_again];
+        expected.sort();
+        assert_eq!(at_ten, expected);
+        assert_eq!(
+            world.contour_depth(ten),
+            Some(BigRational::from(BigInt::from(10)))
+        );
+    }
+
+    #[test]
+    fn test_depth_bands() {
+        let depth = |v: &str| parse_decimal(v).unwrap();
+        let mut world = World::new();
+        let mut area = |fidn, objl, drval: &[&str]| {
+            let feature = add_feature(&mut world, fidn, objl, "0");
+            let attf = [ATTL_DRVAL1, ATTL_DRVAL2]
+                .into_iter()
+                .zip(drval.iter().map(|v| v.to_string()))
+                .collect();
+            world.feature_attributes.insert(
+                feature,
+                FeatureAttributes {
+                    attf,
+                    natf: Vec::new(),
+                },
+            );
+            feature
+        };
+        let drying = area(1, OBJL_DEPARE, &["-2", "0"]);
+        let shallow = area(2, OBJL_DEPARE, &["5", "10"]);
+        let safe = area(3, OBJL_DEPARE, &["10", "20"]);
+        let dredged = area(4, OBJL_DRGARE, &["30"]);
+        let unknown = area(5, OBJL_DEPARE, &[]);
+        let contour = area(6, OBJL_DEPCNT, &["10"]);
+
+        let thresholds = DepthThresholds::new(depth("10"));
+        assert_eq!(thresholds.deep, depth("30"));
+        let bands = world.depth_bands(&thresholds);
+        assert_eq!(bands.len(), 5);
+        assert_eq!(bands[&drying], DepthBand::Dry);
+        assert_eq!(bands[&shallow], DepthBand::Shallow);
+        assert_eq!(bands[&safe], DepthBand::Safe);
+        assert_eq!(bands[&dredged], DepthBand::Deep);
+        assert_eq!(bands[&unknown], DepthBand::Dry);
+        assert_eq!(world.depth_band(contour, &thresholds), None);
+
+        // A deep contour shallower than the safety contour is raised to it
+        let thresholds = DepthThresholds::new_with_deep(depth("12"), depth("5"));
+        assert_eq!(thresholds.deep, depth("12"));
+        assert_eq!(
+            world.depth_band(safe, &thresholds),
+            Some(DepthBand::Shallow)
+        );
+        assert_eq!(
+            world.feature(dredged).unwrap().depth_band(&thresholds),
+            Some(DepthBand::Deep)
+        );
+    }
+}
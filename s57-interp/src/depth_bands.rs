@@ -0,0 +1,661 @@
+//! Depth areas reconstructed from depth contours
+//!
+//! Every point of a cell's coverage should lie in exactly one skin-of-earth
+//! area (DEPARE, DRGARE, LNDARE, UNSARE, ...), but some producers leave
+//! stretches of water without DEPARE while still charting the DEPCNT lines
+//! through them. Rendered as is, those stretches show no depth shading at all.
+//!
+//! `TraversalContext::derived_depth_areas` rebuilds the missing depth bands.
+//! The gap in skin-of-earth coverage (inside M_COVR, or the data extent when
+//! there is none) is cut along every contour and coastline crossing it, and
+//! each piece takes its depth range from:
+//! - a charted depth area it continues across the edge of the gap, if any
+//!   (no contour separates them, so the band carries on)
+//! - otherwise the contours around it, with coastline or land as depth 0
+//!
+//! A piece touching a single contour and nothing else could lie on either
+//! side of it, so it is returned without a range.
+//!
+//! The results are never added to the World: they are derived, not charted,
+//! and callers must keep them apart from chart features (GeoJSON export marks
+//! them with a `derived` property).
+
+use crate::clip::{difference, drop_collinear, midpoint, split_segments, stitch, Edge};
+use crate::ecs::{parse_decimal, EntityId, EntityType};
+use crate::geometry::{locate_in_ring, orientation, signed_area, PointLocation, RingOrientation};
+use crate::geometry_cache::ResolvedGeometry;
+use crate::topology::{AreaBoundary, Ring, TraversalContext};
+use num_rational::BigRational;
+use num_traits::{Signed, Zero};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+type Point = (BigRational, BigRational);
+
+/// COALNE: Coastline
+const OBJL_COALNE: u16 = 30;
+/// DEPARE: Depth area
+const OBJL_DEPARE: u16 = 42;
+/// DEPCNT: Depth contour
+const OBJL_DEPCNT: u16 = 43;
+/// DRGARE: Dredged area
+const OBJL_DRGARE: u16 = 46;
+/// LNDARE: Land area
+const OBJL_LNDARE: u16 = 71;
+/// Group 1 (skin of the earth) classes, which together tile the coverage
+const SKIN_OF_EARTH: [u16; 7] = [OBJL_DEPARE, OBJL_DRGARE, 57, 65, OBJL_LNDARE, 95, 154];
+/// DRVAL1 / DRVAL2: Depth range values
+const ATTL_DRVAL1: u16 = 87;
+const ATTL_DRVAL2: u16 = 88;
+
+/// DepthSource: Where a derived area's depth range came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DepthSource {
+    /// Continues this charted DEPARE/DRGARE across the edge of the gap
+    Adjacent(EntityId),
+    /// The contours (and coastline) bounding the area
+    Contours,
+    /// A single contour and nothing else: the side is unknown
+    Unresolved,
+}
+
+/// DerivedDepthArea: A depth band the chart leaves out, rebuilt from contours
+#[derive(Debug, Clone, PartialEq)]
+pub struct DerivedDepthArea {
+    /// One exterior ring (counter-clockwise) and its holes (clockwise)
+    pub area: AreaBoundary,
+    /// Shoalest depth of the band
+    pub drval1: Option<BigRational>,
+    /// Deepest depth of the band
+    pub drval2: Option<BigRational>,
+    pub source: DepthSource,
+    /// DEPCNT features along the area's boundary, sorted
+    pub contours: Vec<EntityId>,
+}
+
+/// What a piece of the cut-up gap's edge network came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Line {
+    /// Edge of the gap itself
+    Gap,
+    Contour(EntityId),
+    Coast,
+}
+
+/// A charted skin-of-earth area that may border the gap
+struct Neighbour {
+    feature: EntityId,
+    area: AreaBoundary,
+    land: bool,
+    drval1: Option<BigRational>,
+    drval2: Option<BigRational>,
+}
+
+impl TraversalContext<'_> {
+    /// Depth bands reconstructed where skin-of-earth coverage has gaps
+    ///
+    /// Empty when the chart's own coverage is complete. The context's
+    /// transform, if any, is applied to the results only: the work is done
+    /// on charted positions.
+    pub fn derived_depth_areas(&self) -> Vec<DerivedDepthArea> {
+        let charted = TraversalContext {
+            world: self.world,
+            cycle_policy: self.cycle_policy,
+            continuity_policy: self.continuity_policy,
+            transform: None,
+        };
+        let mut areas = charted.reconstruct_depth_areas();
+        if self.transform.is_some() {
+            for derived in &mut areas {
+                let area = &mut derived.area;
+                for ring in area.exterior.iter_mut().chain(area.interior.iter_mut()) {
+                    for (lat, lon) in ring.iter_mut() {
+                        (*lat, *lon) = self.transform_point(lat, lon);
+                    }
+                }
+            }
+        }
+        areas
+    }
+
+    fn reconstruct_depth_areas(&self) -> Vec<DerivedDepthArea> {
+        let world = self.world;
+        let mut gap = world.covered_area();
+        if gap.is_empty() {
+            let Some(extent) = world.extent() else {
+                return Vec::new();
+            };
+            gap = AreaBoundary::rectangle(
+                extent.min_lat,
+                extent.min_lon,
+                extent.max_lat,
+                extent.max_lon,
+            );
+        }
+
+        // Cut away everything the chart covers itself
+        let neighbours = self.skin_of_earth();
+        for neighbour in &neighbours {
+            gap = difference(&gap, &neighbour.area);
+            if gap.is_empty() {
+                return Vec::new();
+            }
+        }
+        crate::diag::debug!(
+            "Skin-of-earth coverage has a gap of {} ring(s)",
+            gap.exterior.len() + gap.interior.len()
+        );
+
+        // The gap's edges, and the contour and coastline segments near it
+        let mut segments: Vec<Edge> = Vec::new();
+        let mut kinds: Vec<Line> = Vec::new();
+        for ring in gap.exterior.iter().chain(&gap.interior) {
+            for pair in ring.windows(2) {
+                if pair[0] != pair[1] {
+                    segments.push((pair[0].clone(), pair[1].clone()));
+                    kinds.push(Line::Gap);
+                }
+            }
+        }
+        let bounds = ring_bounds(gap.exterior.iter().flatten());
+        for feature in world.entities_of_type(EntityType::Feature) {
+            let kind = match world.feature_meta.get(&feature).map(|m| m.objl) {
+                Some(OBJL_DEPCNT) => Line::Contour(feature),
+                Some(OBJL_COALNE) => Line::Coast,
+                _ => continue,
+            };
+            let Ok(Some(geometry)) = self.feature_geometry(feature) else {
+                continue;
+            };
+            let ResolvedGeometry::Lines(lines) = geometry.as_ref() else {
+                continue;
+            };
+            for pair in lines.iter().flat_map(|line| line.windows(2)) {
+                if pair[0] != pair[1] && bounds.as_ref().is_some_and(|b| b.touches(pair)) {
+                    segments.push((pair[0].clone(), pair[1].clone()));
+                    kinds.push(kind);
+                }
+            }
+        }
+
+        // Split everything where it meets, keeping only what lies in the
+        // gap; pieces that coincide (a contour along the gap's edge) merge
+        let mut pieces: BTreeMap<Edge, BTreeSet<Line>> = BTreeMap::new();
+        let mut gap_edges: HashSet<Edge> = HashSet::new();
+        for (split, kind) in split_segments(&segments).into_iter().zip(kinds) {
+            for (p, q) in split {
+                if kind == Line::Gap {
+                    gap_edges.insert((p.clone(), q.clone()));
+                } else if gap.locate(&midpoint(&p, &q)) == PointLocation::Outside {
+                    continue;
+                }
+                let key = if p < q { (p, q) } else { (q, p) };
+                pieces.entry(key).or_default().insert(kind);
+            }
+        }
+
+        // Trace the faces of the network: counter-clockwise rings are faces,
+        // clockwise ones bound a separate piece of network from outside
+        let directed: Vec<Edge> = pieces
+            .keys()
+            .flat_map(|(p, q)| [(p.clone(), q.clone()), (q.clone(), p.clone())])
+            .collect();
+        let mut faces: Vec<(Ring, BTreeSet<Line>)> = Vec::new();
+        let mut holes: Vec<(Ring, BTreeSet<Line>)> = Vec::new();
+        for ring in stitch(directed) {
+            // Only rings with the gap on their left are part of it
+            let first = (ring[0].clone(), ring[1].clone());
+            let on_gap_edge = edge_lines(&pieces, &first).contains(&Line::Gap);
+            if on_gap_edge && !gap_edges.contains(&first) {
+                continue;
+            }
+            let mut lines = BTreeSet::new();
+            for pair in ring.windows(2) {
+                lines.extend(edge_lines(&pieces, &(pair[0].clone(), pair[1].clone())));
+            }
+            match orientation(&ring) {
+                RingOrientation::CounterClockwise => faces.push((ring, lines)),
+                RingOrientation::Clockwise => holes.push((ring, lines)),
+                RingOrientation::Degenerate => {}
+            }
+        }
+
+        let mut areas: Vec<(AreaBoundary, BTreeSet<Line>)> = faces
+            .into_iter()
+            .map(|(ring, lines)| {
+                let area = AreaBoundary {
+                    exterior: vec![ring],
+                    interior: Vec::new(),
+                };
+                (area, lines)
+            })
+            .collect();
+        for (hole, lines) in holes {
+            // The smallest face around the hole owns it
+            let owner = areas
+                .iter()
+                .enumerate()
+                .filter(|(_, (area, _))| {
+                    locate_in_ring(&area.exterior[0], &hole[0]) == PointLocation::Inside
+                })
+                .min_by_key(|(_, (area, _))| signed_area(&area.exterior[0]).abs())
+                .map(|(i, _)| i);
+            if let Some(owner) = owner {
+                areas[owner].0.interior.push(hole);
+                areas[owner].1.extend(lines);
+            }
+        }
+
+        areas
+            .into_iter()
+            .map(|(mut area, lines)| {
+                let derived = self.depth_band(&area, &lines, &pieces, &neighbours);
+                for ring in area.exterior.iter_mut().chain(area.interior.iter_mut()) {
+                    drop_collinear(ring);
+                }
+                DerivedDepthArea { area, ..derived }
+            })
+            .collect()
+    }
+
+    /// Charted skin-of-earth areas, with their depth ranges
+    fn skin_of_earth(&self) -> Vec<Neighbour> {
+        let world = self.world;
+        let mut areas = Vec::new();
+        for feature in world.entities_of_type(EntityType::Feature) {
+            let Some(meta) = world.feature_meta.get(&feature) else {
+                continue;
+            };
+            if !SKIN_OF_EARTH.contains(&meta.objl) {
+                continue;
+            }
+            let geometry = match self.feature_geometry(feature) {
+                Ok(Some(geometry)) => geometry,
+                Ok(None) => continue,
+                Err(e) => {
+                    crate::diag::debug!("Skipping area {:?}: {}", meta.foid, e);
+                    continue;
+                }
+            };
+            let ResolvedGeometry::Area(area) = geometry.as_ref() else {
+                continue;
+            };
+            let attrs = world.feature_attributes.get(&feature);
+            let value = |attl| attrs.and_then(|a| a.get(attl)).and_then(parse_decimal);
+            let depth_area = matches!(meta.objl, OBJL_DEPARE | OBJL_DRGARE);
+            areas.push(Neighbour {
+                feature,
+                area: area.clone(),
+                land: meta.objl == OBJL_LNDARE,
+                drval1: value(ATTL_DRVAL1).filter(|_| depth_area),
+                drval2: value(ATTL_DRVAL2).filter(|_| depth_area),
+            });
+        }
+        areas
+    }
+
+    /// Depth range of one face from what lies around it
+    fn depth_band(
+        &self,
+        area: &AreaBoundary,
+        lines: &BTreeSet<Line>,
+        pieces: &BTreeMap<Edge, BTreeSet<Line>>,
+        neighbours: &[Neighbour],
+    ) -> DerivedDepthArea {
+        let contours: Vec<EntityId> = lines
+            .iter()
+            .filter_map(|line| match line {
+                Line::Contour(feature) => Some(*feature),
+                _ => None,
+            })
+            .collect();
+
+        // Charted areas across the face's stretches of gap edge; across a
+        // contour the band changes, so only land counts there
+        let mut adjacent: Option<&Neighbour> = None;
+        let mut coast = lines.contains(&Line::Coast);
+        for ring in area.exterior.iter().chain(&area.interior) {
+            for pair in ring.windows(2) {
+                let edge = (pair[0].clone(), pair[1].clone());
+                let kinds = edge_lines(pieces, &edge);
+                if !kinds.contains(&Line::Gap) {
+                    continue;
+                }
+                let across_contour = kinds.iter().any(|k| matches!(k, Line::Contour(_)));
+                let point = midpoint(&pair[0], &pair[1]);
+                for neighbour in neighbours {
+                    if neighbour.area.locate(&point) != PointLocation::Boundary {
+                        continue;
+                    }
+                    coast |= neighbour.land;
+                    if across_contour || (neighbour.drval1.is_none() && neighbour.drval2.is_none())
+                    {
+                        continue;
+                    }
+                    // Shoalest wins, as in `depth_at`
+                    let shoaler =
+                        adjacent.is_none_or(|best| match (&neighbour.drval1, &best.drval1) {
+                            (Some(a), Some(b)) => a < b,
+                            (Some(_), None) => true,
+                            _ => false,
+                        });
+                    if shoaler {
+                        adjacent = Some(neighbour);
+                    }
+                }
+            }
+        }
+
+        let (drval1, drval2, source) = if let Some(neighbour) = adjacent {
+            (
+                neighbour.drval1.clone(),
+                neighbour.drval2.clone(),
+                DepthSource::Adjacent(neighbour.feature),
+            )
+        } else {
+            let mut depths: Vec<BigRational> = contours
+                .iter()
+                .filter_map(|feature| self.world.contour_depth(*feature))
+                .collect();
+            if coast {
+                depths.push(BigRational::zero());
+            }
+            depths.sort();
+            depths.dedup();
+            match depths.len() {
+                0 => (None, None, DepthSource::Unresolved),
+                1 if !coast => (None, None, DepthSource::Unresolved),
+                1 => (depths.pop(), None, DepthSource::Contours),
+                _ => (
+                    depths.first().cloned(),
+                    depths.last().cloned(),
+                    DepthSource::Contours,
+                ),
+            }
+        };
+
+        DerivedDepthArea {
+            area: AreaBoundary::default(),
+            drval1,
+            drval2,
+            source,
+            contours,
+        }
+    }
+}
+
+/// Lines a piece of the network came from, whichever way it is walked
+fn edge_lines(pieces: &BTreeMap<Edge, BTreeSet<Line>>, (p, q): &Edge) -> BTreeSet<Line> {
+    let key = if p < q {
+        (p.clone(), q.clone())
+    } else {
+        (q.clone(), p.clone())
+    };
+    pieces.get(&key).cloned().unwrap_or_default()
+}
+
+/// Axis-aligned bounds of some points, for cheap rejection of segments
+struct Bounds {
+    min: Point,
+    max: Point,
+}
+
+impl Bounds {
+    fn touches(&self, segment: &[Point]) -> bool {
+        let (a, b) = (&segment[0], &segment[1]);
+        a.0.clone().max(b.0.clone()) >= self.min.0
+            && a.0.clone().min(b.0.clone()) <= self.max.0
+            && a.1.clone().max(b.1.clone()) >= self.min.1
+            && a.1.clone().min(b.1.clone()) <= self.max.1
+    }
+}
+
+fn ring_bounds<'a>(mut points: impl Iterator<Item = &'a Point>) -> Option<Bounds> {
+    let first = points.next()?;
+    let mut bounds = Bounds {
+        min: first.clone(),
+        max: first.clone(),
+    };
+    for (lat, lon) in points {
+        if *lat < bounds.min.0 {
+            bounds.min.0 = lat.clone();
+        }
+        if *lat > bounds.max.0 {
+            bounds.max.0 = lat.clone();
+        }
+        if *lon < bounds.min.1 {
+            bounds.min.1 = lon.clone();
+        }
+        if *lon > bounds.max.1 {
+            bounds.max.1 = lon.clone();
+        }
+    }
+    Some(bounds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::{
+        ExactPositions, FeatureAttributes, FeatureMeta, FeaturePointers, SpatialRef, VectorMeta,
+        World,
+    };
+    use crate::geojson::{to_geojson, GeoJsonOptions};
+    use crate::transform::Shift;
+    use num_bigint::BigInt;
+    use s57_parse::bitstring::{FoidKey, NameKey};
+
+    /// M_COVR: Coverage
+    const OBJL_M_COVR: u16 = 302;
+    /// CATCOV: Category of coverage
+    const ATTL_CATCOV: u16 = 18;
+    /// VALDCO: Value of depth contour
+    const ATTL_VALDCO: u16 = 174;
+
+    fn int(v: i64) -> BigRational {
+        BigInt::from(v).into()
+    }
+
+    /// Line (prim 2) or area (prim 3) feature over one edge through `points`
+    fn add_feature(
+        world: &mut World,
+        id: u32,
+        objl: u16,
+        prim: u8,
+        points: &[(i64, i64)],
+        attf: &[(u16, &str)],
+    ) -> EntityId {
+        let edge = world.create_entity(EntityType::Vector);
+        let name = NameKey {
+            rcnm: 130,
+            rcid: id,
+        };
+        world.name_index.insert(name, edge);
+        world.vector_meta.insert(
+            edge,
+            VectorMeta {
+                name,
+                rver: 1,
+                ruin: 1,
+            },
+        );
+        world.exact_positions.insert(
+            edge,
+            ExactPositions {
+                lat: points.iter().map(|p| int(p.0)).collect(),
+                lon: points.iter().map(|p| int(p.1)).collect(),
+            },
+        );
+
+        let feature = world.create_entity(EntityType::Feature);
+        let foid = FoidKey {
+            agen: 550,
+            fidn: id,
+            fids: 1,
+        };
+        world.foid_index.insert(foid, feature);
+        world.feature_meta.insert(
+            feature,
+            FeatureMeta {
+                foid,
+                prim,
+                grup: 1,
+                objl,
+                rver: 1,
+                ruin: 1,
+            },
+        );
+        world.feature_attributes.insert(
+            feature,
+            FeatureAttributes {
+                attf: attf.iter().map(|(a, v)| (*a, v.to_string())).collect(),
+                natf: Vec::new(),
+            },
+        );
+        world.feature_pointers.insert(
+            feature,
+            FeaturePointers {
+                related_features: vec![],
+                spatial_refs: vec![SpatialRef {
+                    entity: edge,
+                    ornt: 1,
+                    usag: if prim == 3 { 1 } else { 255 },
+                    mask: 255,
+                }],
+            },
+        );
+        feature
+    }
+
+    fn rect(min_lon: i64, max_lon: i64) -> Vec<(i64, i64)> {
+        vec![
+            (0, min_lon),
+            (0, max_lon),
+            (4, max_lon),
+            (4, min_lon),
+            (0, min_lon),
+        ]
+    }
+
+    /// Coverage over lon 0..20: land up to 4, a charted 20-50 m DEPARE from
+    /// 16, and contours at 8 (5 m) and 12 (10 m) in the uncharted water
+    /// between, plus a 10 m pool at lon 13..15
+    fn chart(contour_at_depare: bool) -> (World, EntityId) {
+        let mut world = World::new();
+        add_feature(
+            &mut world,
+            1,
+            OBJL_M_COVR,
+            3,
+            &rect(0, 20),
+            &[(ATTL_CATCOV, "1")],
+        );
+        add_feature(&mut world, 2, OBJL_LNDARE, 3, &rect(0, 4), &[]);
+        let depare = add_feature(
+            &mut world,
+            3,
+            OBJL_DEPARE,
+            3,
+            &rect(16, 20),
+            &[(ATTL_DRVAL1, "20"), (ATTL_DRVAL2, "50")],
+        );
+        let contour = |lon| [(0, lon), (4, lon)];
+        add_feature(
+            &mut world,
+            4,
+            OBJL_DEPCNT,
+            2,
+            &contour(8),
+            &[(ATTL_VALDCO, "5")],
+        );
+        add_feature(
+            &mut world,
+            5,
+            OBJL_DEPCNT,
+            2,
+            &contour(12),
+            &[(ATTL_VALDCO, "10")],
+        );
+        let pool = [(1, 13), (1, 15), (3, 15), (3, 13), (1, 13)];
+        add_feature(&mut world, 6, OBJL_DEPCNT, 2, &pool, &[(ATTL_VALDCO, "10")]);
+        if contour_at_depare {
+            add_feature(
+                &mut world,
+                7,
+                OBJL_DEPCNT,
+                2,
+                &contour(16),
+                &[(ATTL_VALDCO, "20")],
+            );
+        }
+        (world, depare)
+    }
+
+    /// Derived areas sorted by the western edge of their exterior ring
+    fn bands(world: &World) -> Vec<DerivedDepthArea> {
+        let mut areas = TraversalContext::new(world).derived_depth_areas();
+        let west = |a: &DerivedDepthArea| a.area.exterior[0].iter().map(|p| p.1.clone()).min();
+        areas.sort_by_key(|a| (west(a), a.area.area()));
+        areas
+    }
+
+    #[test]
+    fn test_derived_depth_areas() {
+        let (world, depare) = chart(true);
+        let areas = bands(&world);
+        let ranges: Vec<_> = areas
+            .iter()
+            .map(|a| (a.drval1.clone(), a.drval2.clone(), a.source, a.area.area()))
+            .collect();
+        assert_eq!(
+            ranges,
+            vec![
+                // Coastline to the 5 m contour
+                (Some(int(0)), Some(int(5)), DepthSource::Contours, int(16)),
+                (Some(int(5)), Some(int(10)), DepthSource::Contours, int(16)),
+                // 10 to 20 m, less the pool
+                (Some(int(10)), Some(int(20)), DepthSource::Contours, int(12)),
+                // Inside a lone contour: shoal or deep, who knows
+                (None, None, DepthSource::Unresolved, int(4)),
+            ]
+        );
+        assert_eq!(areas[2].area.interior.len(), 1);
+        assert_eq!(areas[2].contours.len(), 3);
+
+        // Without the 20 m contour the band carries on from the DEPARE
+        let (world, _) = chart(false);
+        let areas = bands(&world);
+        assert_eq!(areas[2].source, DepthSource::Adjacent(depare));
+        assert_eq!(areas[2].drval1, Some(int(20)));
+
+        // Full coverage: nothing to derive
+        let mut covered = World::new();
+        add_feature(
+            &mut covered,
+            1,
+            OBJL_M_COVR,
+            3,
+            &rect(0, 4),
+            &[(ATTL_CATCOV, "1")],
+        );
+        add_feature(&mut covered, 2, OBJL_DEPARE, 3, &rect(0, 4), &[]);
+        assert!(bands(&covered).is_empty());
+
+        // Transformed with the context, and exported only on request
+        let (world, _) = chart(true);
+        let shifted = TraversalContext::new(&world).with_transform(Shift {
+            lat: int(10),
+            lon: int(0),
+        });
+        let shifted = shifted.derived_depth_areas();
+        assert!(shifted
+            .iter()
+            .all(|a| a.area.exterior[0].iter().all(|p| p.0 >= int(10))));
+        let plain = to_geojson(&world, &GeoJsonOptions::new());
+        let with_derived = to_geojson(&world, &GeoJsonOptions::new().derived_depth_areas(true));
+        let count = |v: &serde_json::Value| v["features"].as_array().unwrap().len();
+        assert_eq!(count(&with_derived), count(&plain) + 4);
+        let derived = &with_derived["features"][count(&plain)];
+        assert_eq!(derived["properties"]["derived"], true);
+        assert_eq!(derived["properties"]["OBJL"], "DEPARE");
+    }
+}
@@ -0,0 +1,34 @@
+//! Diagnostic macros
+//!
+//! As in `s57_parse`: `tracing` events and spans with the `tracing`
+//! feature, plain `log` records (and no spans) without it.
+
+/// A debug-level diagnostic
+macro_rules! debug {
+    ($($arg:tt)+) => {{
+        #[cfg(feature = "tracing")]
+        tracing::debug!($($arg)+);
+        #[cfg(not(feature = "tracing"))]
+        log::debug!($($arg)+);
+    }};
+}
+
+/// A warning
+macro_rules! warning {
+    ($($arg:tt)+) => {{
+        #[cfg(feature = "tracing")]
+        tracing::warn!($($arg)+);
+        #[cfg(not(feature = "tracing"))]
+        log::warn!($($arg)+);
+    }};
+}
+
+/// Enter a trace-level span for the rest of the enclosing block
+macro_rules! span {
+    ($name:literal $(, $($field:tt)*)?) => {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::trace_span!($name $(, $($field)*)?).entered();
+    };
+}
+
+pub(crate) use {debug, span, warning};
@@ -0,0 +1,168 @@
+//! Feature-level differences between two Worlds
+//!
+//! Features are matched by FOID. A feature present in both counts as
+//! modified when its content digest (the per-feature hash behind
+//! `World::fingerprint`) differs: class, attributes, related features or
+//! exact geometry. Record versions and vector RCIDs don't count, so a cell
+//! and the same cell rewritten compare equal.
+
+use crate::ecs::{EntityType, World};
+use s57_parse::bitstring::FoidKey;
+use std::collections::HashMap;
+use std::fmt;
+
+/// ChangeKind: How a feature differs between two Worlds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ChangeKind {
+    /// Only in the newer World
+    Added,
+    /// Only in the older World
+    Removed,
+    /// In both, with different content
+    Modified,
+}
+
+/// FeatureChange: One feature that differs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeatureChange {
+    pub foid: FoidKey,
+    /// Object class code, from the newer World unless removed
+    pub objl: u16,
+    pub kind: ChangeKind,
+}
+
+/// WorldDiff: Every feature that differs between two Worlds, by FOID
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WorldDiff {
+    pub changes: Vec<FeatureChange>,
+}
+
+impl WorldDiff {
+    /// True if both Worlds hold the same features
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Number of changes of one kind
+    pub fn count(&self, kind: ChangeKind) -> usize {
+        self.changes.iter().filter(|c| c.kind == kind).count()
+    }
+}
+
+impl fmt::Display for WorldDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} added, {} removed, {} modified",
+            self.count(ChangeKind::Added),
+            self.count(ChangeKind::Removed),
+            self.count(ChangeKind::Modified)
+        )
+    }
+}
+
+impl World {
+    /// Features added, removed or modified going from `self` to `newer`
+    ///
+    /// Changes are ordered by FOID. Features without FeatureMeta are ignored.
+    pub fn diff(&self, newer: &World) -> WorldDiff {
+        let old = self.feature_digests();
+        let new = newer.feature_digests();
+
+        let mut changes = Vec::new();
+        for (foid, (objl, digest)) in &new {
+            let kind = match old.get(foid) {
+                None => ChangeKind::Added,
+                Some((_, before)) if before != digest => ChangeKind::Modified,
+                Some(_) => continue,
+            };
+            changes.push(FeatureChange {
+                foid: *foid,
+                objl: *objl,
+                kind,
+            });
+        }
+        for (foid, (objl, _)) in &old {
+            if !new.contains_key(foid) {
+                changes.push(FeatureChange {
+                    foid: *foid,
+                    objl: *objl,
+                    kind: ChangeKind::Removed,
+                });
+            }
+        }
+        changes.sort_by_key(|c| (c.foid.agen, c.foid.fidn, c.foid.fids));
+        WorldDiff { changes }
+    }
+
+    /// FOID → (OBJL, content digest) of every feature
+    fn feature_digests(&self) -> HashMap<FoidKey, (u16, [u8; 32])> {
+        self.entities_of_type(EntityType::Feature)
+            .into_iter()
+            .filter_map(|feature| {
+                let meta = self.feature_meta.get(&feature)?;
+                Some((meta.foid, (meta.objl, self.feature_digest(feature)?)))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::{FeatureAttributes, FeatureMeta};
+
+    fn add(world: &mut World, fidn: u32, objl: u16, objnam: &str) {
+        let feature = world.create_entity(EntityType::Feature);
+        let foid = FoidKey {
+            agen: 550,
+            fidn,
+            fids: 1,
+        };
+        world.foid_index.insert(foid, feature);
+        world.feature_meta.insert(
+            feature,
+            FeatureMeta {
+                foid,
+                prim: 1,
+                grup: 2,
+                objl,
+                rver: 1,
+                ruin: 1,
+            },
+        );
+        world.feature_attributes.insert(
+            feature,
+            FeatureAttributes {
+                attf: vec![(116, objnam.to_string())],
+                natf: Vec::new(),
+            },
+        );
+    }
+
+    #[test]
+    fn test_world_diff() {
+        let mut old = World::new();
+        add(&mut old, 1, 75, "Light");
+        add(&mut old, 2, 17, "Buoy");
+        add(&mut old, 3, 17, "Wreck buoy");
+        let mut new = World::new();
+        add(&mut new, 3, 17, "Wreck buoy");
+        add(&mut new, 2, 17, "Buoy 2");
+        add(&mut new, 4, 159, "Wreck");
+
+        let diff = old.diff(&new);
+        let kinds: Vec<_> = diff.changes.iter().map(|c| (c.foid.fidn, c.kind)).collect();
+        assert_eq!(
+            kinds,
+            [
+                (1, ChangeKind::Removed),
+                (2, ChangeKind::Modified),
+                (4, ChangeKind::Added)
+            ]
+        );
+        assert_eq!(diff.changes[0].objl, 75);
+        assert_eq!(diff.to_string(), "1 added, 1 removed, 1 modified");
+        assert!(new.diff(&new).is_empty());
+    }
+}
@@ -9,11 +9,16 @@
 //! - Sparse storage: HashMap<EntityId, ComponentData> for optional components
 //! - Systems: pure functions that operate on component slices
 
+use crate::approx::{ApproxDepths, ApproxPositions};
+use crate::fixed::{FixedDepths, FixedPositions};
+use crate::geometry_cache::GeometryCache;
+use crate::overlay::Overlay;
 use num_bigint::BigInt;
 use num_rational::BigRational;
 use num_traits::ToPrimitive;
 use s57_parse::bitstring::{FoidKey, NameKey};
 use slotmap::{new_key_type, SlotMap};
+use smallvec::SmallVec;
 use std::collections::HashMap;
 
 // Define EntityId as a slotmap key type (generational index)
@@ -31,28 +36,77 @@ new_key_type! {
 /// - entities: SlotMap for entity lifecycle management
 /// - components: Separate storage for each component type
 /// - indices: Fast lookup from S-57 keys to EntityId
+///
+/// With feature "serde" a World serializes with every component, so it can
+/// be saved (e.g. as JSON or bincode) and loaded without re-parsing the cell;
+/// exact values are written as decimal strings (see `serde_exact`).
 #[derive(Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct World {
     /// Entity allocator (slotmap handles generation/reuse)
     entities: SlotMap<EntityId, EntityMeta>,
 
     /// Fast lookups from S-57 keys to entities
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_exact::pairs"))]
     pub name_index: HashMap<NameKey, EntityId>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_exact::pairs"))]
     pub foid_index: HashMap<FoidKey, EntityId>,
 
     /// Component storage (sparse - not all entities have all components)
     pub dataset_params: Option<DatasetParams>,
+    pub dataset_structure: Option<DatasetStructure>,
+    pub dataset_id: Option<DatasetIdentification>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_exact::pairs"))]
     pub vector_meta: HashMap<EntityId, VectorMeta>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_exact::pairs"))]
     pub vector_topology: HashMap<EntityId, VectorTopology>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_exact::pairs"))]
     pub feature_meta: HashMap<EntityId, FeatureMeta>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_exact::pairs"))]
     pub feature_attributes: HashMap<EntityId, FeatureAttributes>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_exact::pairs"))]
     pub feature_pointers: HashMap<EntityId, FeaturePointers>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_exact::pairs"))]
+    pub vector_attributes: HashMap<EntityId, VectorAttributes>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_exact::pairs"))]
+    pub update_controls: HashMap<EntityId, UpdateControls>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_exact::pairs"))]
+    pub raw_fields: HashMap<EntityId, Vec<RawField>>,
+    /// Where each entity built from a cell came from
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_exact::pairs"))]
+    pub record_sources: HashMap<EntityId, RecordSource>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_exact::pairs"))]
     pub exact_positions: HashMap<EntityId, ExactPositions>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_exact::pairs"))]
     pub exact_depths: HashMap<EntityId, ExactDepths>,
+    /// Raw SG2D/SG3D values, in place of the exact components when built
+    /// with `CoordinateBackend::Fixed`
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_exact::pairs"))]
+    pub fixed_positions: HashMap<EntityId, FixedPositions>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_exact::pairs"))]
+    pub fixed_depths: HashMap<EntityId, FixedDepths>,
+    /// f64 copies of the positions above, for the fast geometry path
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_exact::pairs"))]
+    pub approx_positions: HashMap<EntityId, ApproxPositions>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_exact::pairs"))]
+    pub approx_depths: HashMap<EntityId, ApproxDepths>,
+
+    /// Non-standard fields from records that produced no entity (e.g. DSID records)
+    pub unattached_raw_fields: Vec<RawField>,
+
+    /// User-created mariner objects (not chart data, never entities)
+    pub overlay: Overlay,
+
+    /// Resolved line/area geometry per feature (None = caching disabled)
+    ///
+    /// Not serialized: a deserialized World starts with caching disabled.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub geometry_cache: Option<GeometryCache>,
 }
 
 /// EntityMeta: Minimal metadata stored in slotmap
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EntityMeta {
     /// Entity type tag (for debugging/filtering)
     pub entity_type: EntityType,
@@ -60,6 +114,7 @@ pub struct EntityMeta {
 
 /// EntityType: Categorizes entities for filtering/debugging
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EntityType {
     /// Vector record (spatial geometry)
     Vector,
@@ -86,8 +141,22 @@ impl World {
         self.feature_meta.remove(&entity);
         self.feature_attributes.remove(&entity);
         self.feature_pointers.remove(&entity);
+        self.vector_attributes.remove(&entity);
+        self.update_controls.remove(&entity);
+        self.raw_fields.remove(&entity);
+        self.record_sources.remove(&entity);
         self.exact_positions.remove(&entity);
         self.exact_depths.remove(&entity);
+        self.fixed_positions.remove(&entity);
+        self.fixed_depths.remove(&entity);
+        self.approx_positions.remove(&entity);
+        self.approx_depths.remove(&entity);
+        self.invalidate_feature_geometry(entity);
+    }
+
+    /// Number of live entities
+    pub fn entity_count(&self) -> usize {
+        self.entities.len()
     }
 
     /// Check if an entity exists (not deleted)
@@ -108,6 +177,50 @@ impl World {
             .map(|(id, _)| id)
             .collect()
     }
+
+    /// Get a feature's object name according to a language preference
+    pub fn feature_name(&self, feature: EntityId, preference: NamePreference) -> Option<&str> {
+        self.feature_attributes.get(&feature)?.name(preference)
+    }
+
+    /// Get positional quality of the vectors a feature is built from
+    ///
+    /// Follows the feature's FSPT spatial references and, for edges, the
+    /// VRPT nodes they connect. Returns (vector entity, quality) for every
+    /// vector carrying QUAPOS and/or POSACC in its ATTV field.
+    pub fn feature_positional_quality(
+        &self,
+        feature: EntityId,
+    ) -> Vec<(EntityId, PositionalQuality)> {
+        let mut result = Vec::new();
+        let Some(pointers) = self.feature_pointers.get(&feature) else {
+            return result;
+        };
+
+        let visit = |vector: EntityId, result: &mut Vec<(EntityId, PositionalQuality)>| {
+            if result.iter().any(|(e, _)| *e == vector) {
+                return;
+            }
+            if let Some(quality) = self
+                .vector_attributes
+                .get(&vector)
+                .and_then(VectorAttributes::positional_quality)
+            {
+                result.push((vector, quality));
+            }
+        };
+
+        for sref in &pointers.spatial_refs {
+            visit(sref.entity, &mut result);
+            if let Some(topology) = self.vector_topology.get(&sref.entity) {
+                for neighbor in &topology.neighbors {
+                    visit(neighbor.entity, &mut result);
+                }
+            }
+        }
+
+        result
+    }
 }
 
 //
@@ -119,10 +232,13 @@ impl World {
 /// These define the scaling factors for converting raw integer coordinates
 /// to exact lat/lon and depth values.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DatasetParams {
     /// Coordinate multiplication factor (e.g., 10,000,000 for 7 decimal places)
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_exact::bigint"))]
     pub comf: BigInt,
     /// Sounding (depth) multiplication factor
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_exact::bigint"))]
     pub somf: BigInt,
     /// Units of depth (1=metres, 2=fathoms/feet, 3=feet, 4=fathoms/fractions)
     pub duni: u16,
@@ -140,8 +256,115 @@ pub struct DatasetParams {
     pub cscl: u32,
 }
 
+/// DatasetIdentification: Identification of the data set from the DSID field
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DatasetIdentification {
+    /// Data set name, usually the cell file name without extension
+    pub dsnm: String,
+    /// Edition number
+    pub edtn: String,
+    /// Update number
+    pub updn: String,
+    /// Update application date (CCYYMMDD)
+    pub uadt: String,
+    /// Issue date (CCYYMMDD)
+    pub isdt: String,
+    /// Intended usage, i.e. navigational purpose (1=overview .. 6=berthing)
+    pub intu: u8,
+    /// Exchange purpose (1=new data set, 2=revision)
+    pub expp: u8,
+    /// S-57 edition the data follows, e.g. "03.1"
+    pub sted: String,
+    /// Product specification (1=ENC, 2=ODD)
+    pub prsp: u8,
+    /// Product specification description, for other product specifications
+    pub psdn: String,
+    /// Product specification edition, e.g. "2.0"
+    pub pred: String,
+    /// Application profile (1=ENC new, 2=ENC revision, 3=IHO data dictionary)
+    pub prof: u8,
+    /// Producing agency code (IHO S-62); 0 if not given
+    pub agen: u16,
+    /// Comment
+    pub comt: String,
+}
+
+/// DatasetStructure: Data set structure information from the DSSI field
+///
+/// Declares the topology level and how many records of each kind the file
+/// contains; see `World::check_record_counts` and
+/// `World::validate_structure`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DatasetStructure {
+    /// Data structure (1=cartographic spaghetti, 2=chain-node, 3=planar graph,
+    /// 4=full topology, 255=topology not relevant)
+    pub dstr: u8,
+    /// ATTF lexical level (0=ASCII, 1=Latin-1, 2=UCS-2)
+    pub aall: u8,
+    /// NATF lexical level
+    pub nall: u8,
+    /// Number of meta records
+    pub nomr: u32,
+    /// Number of cartographic records
+    pub nocr: u32,
+    /// Number of geo records
+    pub nogr: u32,
+    /// Number of collection records
+    pub nolr: u32,
+    /// Number of isolated node records
+    pub noin: u32,
+    /// Number of connected node records
+    pub nocn: u32,
+    /// Number of edge records
+    pub noed: u32,
+    /// Number of face records
+    pub nofa: u32,
+}
+
+impl DatasetStructure {
+    /// Topology level declared by DSTR, or None for an unknown value
+    pub fn topology(&self) -> Option<TopologyLevel> {
+        match self.dstr {
+            1 => Some(TopologyLevel::CartographicSpaghetti),
+            2 => Some(TopologyLevel::ChainNode),
+            3 => Some(TopologyLevel::PlanarGraph),
+            4 => Some(TopologyLevel::FullTopology),
+            255 => Some(TopologyLevel::NotRelevant),
+            _ => None,
+        }
+    }
+}
+
+/// TopologyLevel: Data structure of a file (DSSI DSTR)
+///
+/// Area features reference edges directly in the spaghetti, chain-node and
+/// planar graph levels, and face records (RCNM=140) in full topology.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TopologyLevel {
+    CartographicSpaghetti,
+    ChainNode,
+    PlanarGraph,
+    FullTopology,
+    NotRelevant,
+}
+
+impl std::fmt::Display for TopologyLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            TopologyLevel::CartographicSpaghetti => "cartographic spaghetti",
+            TopologyLevel::ChainNode => "chain-node",
+            TopologyLevel::PlanarGraph => "planar graph",
+            TopologyLevel::FullTopology => "full topology",
+            TopologyLevel::NotRelevant => "topology not relevant",
+        })
+    }
+}
+
 /// VectorMeta: Metadata for vector (spatial) records
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VectorMeta {
     /// NAME key (rcnm, rcid) for cross-references
     pub name: NameKey,
@@ -156,6 +379,7 @@ pub struct VectorMeta {
 /// Defines how vectors connect to form edges, faces, etc.
 /// All references are EntityIds for fast ECS lookups.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VectorTopology {
     /// Neighboring vector entities with topology flags
     pub neighbors: Vec<VectorNeighbor>,
@@ -163,6 +387,7 @@ pub struct VectorTopology {
 
 /// VectorNeighbor: Single neighbor relationship from VRPT
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VectorNeighbor {
     /// EntityId of neighboring vector (resolved from NAME via name_index)
     pub entity: EntityId,
@@ -178,6 +403,7 @@ pub struct VectorNeighbor {
 
 /// FeatureMeta: Metadata for feature (semantic object) records
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FeatureMeta {
     /// Feature object identifier (agen, fidn, fids)
     pub foid: FoidKey,
@@ -197,6 +423,7 @@ pub struct FeatureMeta {
 ///
 /// Stores attribute label (ATTL) and value (ATVL) pairs.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FeatureAttributes {
     /// Feature record attributes (ATTF)
     pub attf: Vec<(u16, String)>,
@@ -204,20 +431,322 @@ pub struct FeatureAttributes {
     pub natf: Vec<(u16, String)>,
 }
 
+/// ATTL code of OBJNAM (Object name, ATTF)
+const ATTL_OBJNAM: u16 = 116;
+/// ATTL code of NOBJNM (Object name in national language, NATF)
+const ATTL_NOBJNM: u16 = 301;
+
+/// NamePreference: Which object name to prefer when both are present
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NamePreference {
+    /// OBJNAM (international), falling back to NOBJNM
+    #[default]
+    International,
+    /// NOBJNM (national language), falling back to OBJNAM
+    National,
+}
+
+impl FeatureAttributes {
+    /// Get raw ATTF attribute value by attribute code
+    pub fn get(&self, attl: u16) -> Option<&str> {
+        self.attf
+            .iter()
+            .find(|(code, _)| *code == attl)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Get raw NATF attribute value by attribute code
+    pub fn get_national(&self, attl: u16) -> Option<&str> {
+        self.natf
+            .iter()
+            .find(|(code, _)| *code == attl)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Object name (OBJNAM / NOBJNM) according to a preference
+    ///
+    /// Empty values count as missing, so a blank NOBJNM falls back to OBJNAM.
+    pub fn name(&self, preference: NamePreference) -> Option<&str> {
+        let international = self.get(ATTL_OBJNAM).filter(|n| !n.trim().is_empty());
+        let national = self
+            .get_national(ATTL_NOBJNM)
+            .filter(|n| !n.trim().is_empty());
+        match preference {
+            NamePreference::International => international.or(national),
+            NamePreference::National => national.or(international),
+        }
+    }
+}
+
+/// VectorAttributes: Spatial attributes from ATTV field
+///
+/// Stores attribute label (ATTL) and value (ATVL) pairs attached to vector
+/// records. In ENCs these carry spatial quality (QUAPOS, POSACC).
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VectorAttributes {
+    /// Vector record attributes (ATTV)
+    pub attv: Vec<(u16, String)>,
+}
+
+/// ATTL code of POSACC (Positional accuracy)
+const ATTL_POSACC: u16 = 401;
+/// ATTL code of QUAPOS (Quality of position)
+const ATTL_QUAPOS: u16 = 402;
+
+impl VectorAttributes {
+    /// Get raw attribute value (ATVL) by attribute code
+    pub fn get(&self, attl: u16) -> Option<&str> {
+        self.attv
+            .iter()
+            .find(|(code, _)| *code == attl)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Decode QUAPOS/POSACC into a PositionalQuality
+    ///
+    /// Returns None if neither attribute is present with a parseable value.
+    pub fn positional_quality(&self) -> Option<PositionalQuality> {
+        let quapos = self
+            .get(ATTL_QUAPOS)
+            .and_then(|v| v.trim().parse::<u8>().ok());
+        let posacc = self.get(ATTL_POSACC).and_then(parse_decimal);
+
+        if quapos.is_none() && posacc.is_none() {
+            return None;
+        }
+
+        Some(PositionalQuality { quapos, posacc })
+    }
+}
+
+/// PositionalQuality: Decoded QUAPOS/POSACC spatial attributes
+#[derive(Debug, Clone, PartialEq)]
+pub struct PositionalQuality {
+    /// Quality of position (1=surveyed, 2=unsurveyed, 4=approximated, etc.)
+    pub quapos: Option<u8>,
+    /// Positional accuracy in PUNI units (exact rational, parsed from decimal ATVL)
+    pub posacc: Option<BigRational>,
+}
+
+/// Parse a decimal ATVL string (e.g. "12.5", "-0.25") into an exact rational
+pub fn parse_decimal(s: &str) -> Option<BigRational> {
+    let s = s.trim();
+    let (negative, digits) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+
+    let (int_part, frac_part) = digits.split_once('.').unwrap_or((digits, ""));
+    if int_part.is_empty() && frac_part.is_empty() {
+        return None;
+    }
+    if !int_part
+        .chars()
+        .chain(frac_part.chars())
+        .all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let numer_str = format!("{}{}", int_part, frac_part);
+    let mut numer: BigInt = numer_str.parse().ok()?;
+    if negative {
+        numer = -numer;
+    }
+    let denom = num_traits::pow(BigInt::from(10), frac_part.len());
+
+    Some(BigRational::new(numer, denom))
+}
+
+/// UpdateInstruction: Update instruction code used by RUIN and the *UI
+/// subfields of update control fields (VPUI, CCUI, FFUI, FSUI)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UpdateInstruction {
+    /// 1 = insert
+    Insert,
+    /// 2 = delete
+    Delete,
+    /// 3 = modify
+    Modify,
+}
+
+impl UpdateInstruction {
+    /// Decode an update instruction code (1=insert, 2=delete, 3=modify)
+    pub fn from_code(code: u8) -> Option<Self> {
+        match code {
+            1 => Some(UpdateInstruction::Insert),
+            2 => Some(UpdateInstruction::Delete),
+            3 => Some(UpdateInstruction::Modify),
+            _ => None,
+        }
+    }
+}
+
+/// UpdateControl: Decoded pointer/coordinate control field
+///
+/// All four update control fields share the same layout:
+/// - VRPC: VPUI / VPIX / NVPT (VRPT pointers)
+/// - SGCC: CCUI / CCIX / CCNC (SG2D/SG3D coordinates)
+/// - FFPC: FFUI / FFIX / NFPT (FFPT pointers)
+/// - FSPC: FSUI / FSIX / NSPT (FSPT pointers)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UpdateControl {
+    /// Update instruction applied to the target field
+    pub instruction: UpdateInstruction,
+    /// 1-based index of the first pointer/coordinate affected
+    pub index: u16,
+    /// Number of pointers/coordinates affected
+    pub count: u16,
+}
+
+/// UpdateControls: Control fields carried by records in update datasets
+///
+/// Base cells (EDTN/UPDN = 0) never carry these; each is None unless the
+/// corresponding field was present on the record.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UpdateControls {
+    /// Vector record pointer control (VRPC)
+    pub vrpc: Option<UpdateControl>,
+    /// Coordinate control (SGCC)
+    pub sgcc: Option<UpdateControl>,
+    /// Feature to feature pointer control (FFPC)
+    pub ffpc: Option<UpdateControl>,
+    /// Feature to spatial pointer control (FSPC)
+    pub fspc: Option<UpdateControl>,
+}
+
+/// RawField: Undecoded field with a non-standard tag
+///
+/// Producer-specific extension fields are kept byte-for-byte so they can be
+/// inspected or written back out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RawField {
+    /// 1-based data record number the field came from
+    pub record: usize,
+    /// Field tag
+    pub tag: String,
+    /// Field data as read (including the field terminator)
+    pub data: Vec<u8>,
+}
+
+/// RecordSource: The record an entity was built from
+///
+/// Lets a feature be traced back to the bytes that define it, e.g. to
+/// check a suspicious attribute against the cell in a hex viewer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RecordSource {
+    /// Record number in the file, numbered like `RawField::record`
+    pub record: usize,
+    /// UPDN of the update file that inserted or last changed the record;
+    /// None if it is as issued in the base cell
+    pub update: Option<u32>,
+    /// Byte offset of the record in the file it was read from: the base
+    /// cell, or for a record an update inserted, that update file. None
+    /// once an update has changed the record
+    pub offset: Option<usize>,
+}
+
+impl RecordSource {
+    /// Source of a record parsed from a cell
+    pub fn new(record: usize, parsed: &s57_parse::iso8211::Record) -> Self {
+        Self {
+            record,
+            update: parsed.update,
+            offset: parsed.span().map(|span| span.offset),
+        }
+    }
+}
+
+impl std::fmt::Display for RecordSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "record {}", self.record)?;
+        if let Some(updn) = self.update {
+            write!(f, " (update {})", updn)?;
+        }
+        if let Some(offset) = self.offset {
+            write!(f, " at byte {}", offset)?;
+        }
+        Ok(())
+    }
+}
+
 /// FeaturePointers: Cross-references from FFPT/FSPT fields
 ///
 /// Links features to other features (FFPT) and to spatial vectors (FSPT).
 /// All references are EntityIds for fast ECS lookups.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FeaturePointers {
     /// Feature-to-feature pointers (FFPT): related feature entities (resolved from LNAM)
-    pub related_features: Vec<EntityId>,
+    pub related_features: Vec<FeatureRef>,
     /// Feature-to-spatial pointers (FSPT): spatial vector entities with flags
     pub spatial_refs: Vec<SpatialRef>,
 }
 
+/// FeatureRef: Single feature reference from FFPT
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FeatureRef {
+    /// EntityId of the referenced feature (resolved from LNAM via foid_index)
+    pub entity: EntityId,
+    /// Role of the referenced feature (RIND); None if absent or not 1-3
+    pub rind: Option<Relationship>,
+}
+
+/// Relationship: FFPT relationship indicator (RIND)
+///
+/// Gives the role of the referenced feature: a buoy points at its light and
+/// topmark as slaves, and a slave may point back at its master.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Relationship {
+    /// 1 = master
+    Master,
+    /// 2 = slave
+    Slave,
+    /// 3 = peer
+    Peer,
+}
+
+impl Relationship {
+    /// Decode a RIND code (1=master, 2=slave, 3=peer)
+    pub fn from_code(code: u8) -> Option<Self> {
+        match code {
+            1 => Some(Relationship::Master),
+            2 => Some(Relationship::Slave),
+            3 => Some(Relationship::Peer),
+            _ => None,
+        }
+    }
+
+    /// RIND code
+    pub fn code(self) -> u8 {
+        match self {
+            Relationship::Master => 1,
+            Relationship::Slave => 2,
+            Relationship::Peer => 3,
+        }
+    }
+
+    /// The same relationship seen from the other feature
+    pub fn inverse(self) -> Self {
+        match self {
+            Relationship::Master => Relationship::Slave,
+            Relationship::Slave => Relationship::Master,
+            Relationship::Peer => Relationship::Peer,
+        }
+    }
+}
+
 /// SpatialRef: Single spatial reference from FSPT
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SpatialRef {
     /// EntityId of referenced vector (resolved from NAME via name_index)
     pub entity: EntityId,
@@ -229,6 +758,15 @@ pub struct SpatialRef {
     pub mask: u8,
 }
 
+/// Number of coordinates stored inline before spilling to the heap
+///
+/// Isolated and connected nodes hold one coordinate and many edges only a
+/// couple of intermediate points, so these avoid a separate allocation.
+pub const INLINE_COORDINATES: usize = 2;
+
+/// Coordinates: Exact values for one vector, inline for points and short lines
+pub type Coordinates = SmallVec<[BigRational; INLINE_COORDINATES]>;
+
 /// ExactPositions: Exact lat/lon coordinates (BigRational)
 ///
 /// Computed from SG2D/SG3D fields by dividing by COMF:
@@ -236,11 +774,14 @@ pub struct SpatialRef {
 ///
 /// All processing should use exact math. Convert to f64 only at render time.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExactPositions {
     /// Latitude in degrees (exact rational)
-    pub lat: Vec<BigRational>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_exact::coordinates"))]
+    pub lat: Coordinates,
     /// Longitude in degrees (exact rational)
-    pub lon: Vec<BigRational>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_exact::coordinates"))]
+    pub lon: Coordinates,
 }
 
 impl ExactPositions {
@@ -261,9 +802,11 @@ impl ExactPositions {
 ///
 /// All processing should use exact math. Convert to f64 only at render time.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExactDepths {
     /// Depth values (exact rational, positive down)
-    pub depth: Vec<BigRational>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_exact::coordinates"))]
+    pub depth: Coordinates,
     /// Units (from DUNI: 1=metres, 2=fathoms/feet, etc.)
     pub units: u16,
 }
@@ -285,6 +828,33 @@ impl ExactDepths {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_feature_name_preference() {
+        let attrs = FeatureAttributes {
+            attf: vec![(ATTL_OBJNAM, "Harbour Light".to_string())],
+            natf: vec![(ATTL_NOBJNM, "Hafenfeuer".to_string())],
+        };
+        assert_eq!(
+            attrs.name(NamePreference::International),
+            Some("Harbour Light")
+        );
+        assert_eq!(attrs.name(NamePreference::National), Some("Hafenfeuer"));
+
+        // Missing or blank names fall back to the other one
+        let international_only = FeatureAttributes {
+            attf: vec![(ATTL_OBJNAM, "Harbour Light".to_string())],
+            natf: vec![(ATTL_NOBJNM, " ".to_string())],
+        };
+        assert_eq!(
+            international_only.name(NamePreference::National),
+            Some("Harbour Light")
+        );
+        assert_eq!(
+            FeatureAttributes::default().name(NamePreference::National),
+            None
+        );
+    }
+
     #[test]
     fn test_world_create_entity() {
         let mut world = World::new();
@@ -313,4 +883,78 @@ mod tests {
         assert!(vectors.contains(&v1));
         assert!(vectors.contains(&v2));
     }
+
+    #[test]
+    fn test_parse_decimal() {
+        assert_eq!(
+            parse_decimal("12.5"),
+            Some(BigRational::new(BigInt::from(25), BigInt::from(2)))
+        );
+        assert_eq!(
+            parse_decimal("-0.25"),
+            Some(BigRational::new(BigInt::from(-1), BigInt::from(4)))
+        );
+        assert_eq!(
+            parse_decimal("7"),
+            Some(BigRational::from_integer(7.into()))
+        );
+        assert_eq!(parse_decimal(""), None);
+        assert_eq!(parse_decimal("abc"), None);
+    }
+
+    #[test]
+    fn test_feature_positional_quality() {
+        let mut world = World::new();
+        let node = world.create_entity(EntityType::Vector);
+        let edge = world.create_entity(EntityType::Vector);
+        let feature = world.create_entity(EntityType::Feature);
+
+        world.vector_attributes.insert(
+            edge,
+            VectorAttributes {
+                attv: vec![(402, "4".to_string())],
+            },
+        );
+        world.vector_attributes.insert(
+            node,
+            VectorAttributes {
+                attv: vec![(401, "10.5".to_string())],
+            },
+        );
+        world.vector_topology.insert(
+            edge,
+            VectorTopology {
+                neighbors: vec![VectorNeighbor {
+                    entity: node,
+                    ornt: 255,
+                    usag: 255,
+                    topi: 1,
+                    mask: 255,
+                }],
+            },
+        );
+        world.feature_pointers.insert(
+            feature,
+            FeaturePointers {
+                related_features: vec![],
+                spatial_refs: vec![SpatialRef {
+                    entity: edge,
+                    ornt: 1,
+                    usag: 255,
+                    mask: 255,
+                }],
+            },
+        );
+
+        let quality = world.feature_positional_quality(feature);
+        assert_eq!(quality.len(), 2);
+        assert_eq!(quality[0].0, edge);
+        assert_eq!(quality[0].1.quapos, Some(4));
+        assert_eq!(quality[0].1.posacc, None);
+        assert_eq!(quality[1].0, node);
+        assert_eq!(
+            quality[1].1.posacc,
+            Some(BigRational::new(BigInt::from(21), BigInt::from(2)))
+        );
+    }
 }
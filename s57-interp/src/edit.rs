@@ -0,0 +1,837 @@
+//! Feature editing on a World
+//!
+//! Small mutators for chart corrections and annotations. Each keeps the
+//! name/foid indexes consistent and bumps RVER on every record it touches,
+//! so edited records can be told apart from the ones read from the cell.
+
+use crate::ecs::{
+    EntityId, EntityType, ExactPositions, FeatureRef, UpdateControl, UpdateInstruction,
+    VectorNeighbor, VectorTopology, World,
+};
+use crate::fixed::unscaled;
+use num_rational::BigRational;
+use s57_parse::{ParseError, ParseErrorKind, Result};
+use smallvec::smallvec;
+use std::ops::Range;
+
+/// PRIM value of point features
+const PRIM_POINT: u8 = 1;
+/// RCNM of connected nodes and edges
+const RCNM_CONNECTED_NODE: u8 = 120;
+const RCNM_EDGE: u8 = 130;
+
+/// Error for an edit that can't be applied
+fn edit_error(message: impl Into<String>) -> ParseError {
+    ParseError::at(ParseErrorKind::Other(message.into()), 0)
+}
+
+/// Raw fixed-point values of exact ones over `factor` (COMF or SOMF)
+fn fixed_values(values: &[BigRational], factor: i64, name: &str) -> Result<Vec<i64>> {
+    values
+        .iter()
+        .map(|value| {
+            unscaled(value, factor).ok_or_else(|| {
+                edit_error(format!(
+                    "{} can't be held in fixed point with {} {}",
+                    value, name, factor
+                ))
+            })
+        })
+        .collect()
+}
+
+/// Raw (y, x) fixed-point values of exact (lat, lon) coordinates
+fn fixed_coordinates(
+    coordinates: &[(BigRational, BigRational)],
+    comf: i64,
+) -> Result<Vec<(i64, i64)>> {
+    let (lat, lon): (Vec<_>, Vec<_>) = coordinates.iter().cloned().unzip();
+    let y = fixed_values(&lat, comf, "COMF")?;
+    let x = fixed_values(&lon, comf, "COMF")?;
+    Ok(y.into_iter().zip(x).collect())
+}
+
+/// Range of a `len`-item list that an update control replaces, and how
+/// many of the `given` new items go in its place
+///
+/// Insert puts `count` items before the 1-based index (one past the end
+/// appends), delete removes `count` items from it, and modify replaces them.
+fn control_range(
+    control: UpdateControl,
+    len: usize,
+    given: usize,
+    field: &str,
+    items: &str,
+) -> Result<(Range<usize>, usize)> {
+    let count = control.count as usize;
+    let start = (control.index as usize)
+        .checked_sub(1)
+        .ok_or_else(|| edit_error(format!("{} index must start at 1", field)))?;
+    let take = match control.instruction {
+        UpdateInstruction::Delete => 0,
+        UpdateInstruction::Insert | UpdateInstruction::Modify if given >= count => count,
+        UpdateInstruction::Insert | UpdateInstruction::Modify => {
+            return Err(edit_error(format!(
+                "{} needs {} {}, {} given",
+                field, count, items, given
+            )))
+        }
+    };
+    match control.instruction {
+        UpdateInstruction::Insert if start <= len => Ok((start..start, take)),
+        UpdateInstruction::Delete | UpdateInstruction::Modify if start + count <= len => {
+            Ok((start..start + count, take))
+        }
+        _ => Err(edit_error(format!(
+            "{} {} {}..{} out of range for {} {}",
+            field,
+            items,
+            control.index,
+            start + count + 1,
+            len,
+            items
+        ))),
+    }
+}
+
+impl World {
+    /// Set (or replace) an ATTF attribute on a feature
+    pub fn set_feature_attribute(
+        &mut self,
+        feature: EntityId,
+        attl: u16,
+        value: &str,
+    ) -> Result<()> {
+        self.require_feature(feature)?;
+
+        let attrs = self.feature_attributes.entry(feature).or_default();
+        match attrs.attf.iter_mut().find(|(code, _)| *code == attl) {
+            Some((_, existing)) => *existing = value.to_string(),
+            None => attrs.attf.push((attl, value.to_string())),
+        }
+
+        self.bump_feature_rver(feature);
+        Ok(())
+    }
+
+    /// Remove an ATTF attribute from a feature
+    ///
+    /// Returns false (and leaves RVER alone) if the attribute wasn't set.
+    pub fn remove_feature_attribute(&mut self, feature: EntityId, attl: u16) -> Result<bool> {
+        self.require_feature(feature)?;
+
+        let Some(attrs) = self.feature_attributes.get_mut(&feature) else {
+            return Ok(false);
+        };
+        let before = attrs.attf.len();
+        attrs.attf.retain(|(code, _)| *code != attl);
+        if attrs.attf.len() == before {
+            return Ok(false);
+        }
+
+        self.bump_feature_rver(feature);
+        Ok(true)
+    }
+
+    /// Move a point feature to a new exact position
+    ///
+    /// The feature's node is moved in place, so any other feature sharing
+    /// that node (e.g. a light on a buoy) moves with it. Depths of sounding
+    /// nodes are kept. A node held in fixed point stays so, and only takes
+    /// a position its COMF can represent.
+    pub fn move_point_feature(
+        &mut self,
+        feature: EntityId,
+        lat: BigRational,
+        lon: BigRational,
+    ) -> Result<()> {
+        self.require_feature(feature)?;
+
+        let prim = self.feature_meta.get(&feature).map(|m| m.prim);
+        if prim != Some(PRIM_POINT) {
+            return Err(edit_error(format!(
+                "feature is not a point feature (PRIM={:?})",
+                prim
+            )));
+        }
+
+        let node = match self.feature_pointers.get(&feature) {
+            Some(pointers) if pointers.spatial_refs.len() == 1 => pointers.spatial_refs[0].entity,
+            _ => return Err(edit_error("point feature must reference exactly one node")),
+        };
+        let count = self
+            .exact_positions_of(node)
+            .ok_or_else(|| edit_error("point feature node has no position"))?
+            .lat
+            .len();
+        if count != 1 {
+            return Err(edit_error(format!(
+                "point feature node has {} coordinates",
+                count
+            )));
+        }
+
+        if let Some(fixed) = self.fixed_positions.get_mut(&node) {
+            let (y, x) = fixed_coordinates(&[(lat, lon)], fixed.comf)?[0];
+            fixed.y = smallvec![y];
+            fixed.x = smallvec![x];
+        } else if let Some(positions) = self.exact_positions.get_mut(&node) {
+            positions.lat = smallvec![lat];
+            positions.lon = smallvec![lon];
+        }
+
+        if let Some(meta) = self.vector_meta.get_mut(&node) {
+            meta.rver = meta.rver.saturating_add(1);
+        }
+        self.bump_feature_rver(feature);
+        self.refresh_approx(node);
+        self.invalidate_geometry();
+        Ok(())
+    }
+
+    /// Edit a feature's FFPT pointers as an FFPC control field would
+    ///
+    /// Inserts `pointers` before the 1-based `control.index`, or deletes or
+    /// replaces `control.count` pointers from it; an insert at one past the
+    /// end appends. Insert and modify take the first `control.count` of
+    /// `pointers`. Every new pointer must name another live feature, so the
+    /// relationship graph never points at nothing.
+    pub fn update_feature_pointers(
+        &mut self,
+        feature: EntityId,
+        control: UpdateControl,
+        pointers: &[FeatureRef],
+    ) -> Result<()> {
+        self.require_feature(feature)?;
+
+        let current = self
+            .feature_pointers
+            .get(&feature)
+            .map_or(0, |p| p.related_features.len());
+        let (range, take) = control_range(control, current, pointers.len(), "FFPC", "pointers")?;
+        let replacement = &pointers[..take];
+        for pointer in replacement {
+            if pointer.entity == feature {
+                return Err(edit_error("feature cannot point at itself"));
+            }
+            self.require_feature(pointer.entity)?;
+        }
+
+        self.feature_pointers
+            .entry(feature)
+            .or_default()
+            .related_features
+            .splice(range, replacement.iter().copied());
+
+        self.bump_feature_rver(feature);
+        Ok(())
+    }
+
+    /// Edit a vector's coordinates as an SGCC control field would
+    ///
+    /// `coordinates` are (lat, lon) pairs, spliced in by the same rules as
+    /// `update_feature_pointers`. A vector with depths (SG3D soundings)
+    /// needs one in `depths` for each new coordinate; a vector without
+    /// takes none. Shared edges and nodes move for every feature using them.
+    /// A vector held in fixed point stays so, and rejects values its
+    /// COMF/SOMF can't represent.
+    pub fn update_vector_coordinates(
+        &mut self,
+        vector: EntityId,
+        control: UpdateControl,
+        coordinates: &[(BigRational, BigRational)],
+        depths: &[BigRational],
+    ) -> Result<()> {
+        self.require_vector(vector)?;
+
+        let current = self.exact_positions_of(vector).map_or(0, |p| p.lat.len());
+        let (range, take) =
+            control_range(control, current, coordinates.len(), "SGCC", "coordinates")?;
+        let has_depths =
+            self.exact_depths.contains_key(&vector) || self.fixed_depths.contains_key(&vector);
+        if has_depths && depths.len() < take {
+            return Err(edit_error(format!(
+                "SGCC needs {} depths, {} given",
+                take,
+                depths.len()
+            )));
+        }
+        if !has_depths && !depths.is_empty() {
+            return Err(edit_error("vector has no depths to update"));
+        }
+        let new = &coordinates[..take];
+        let new_depths = &depths[..take.min(depths.len())];
+
+        if let Some(fixed) = self.fixed_positions.get(&vector) {
+            // Convert everything first, so a value that doesn't fit leaves
+            // the vector as it was
+            let raw = fixed_coordinates(new, fixed.comf)?;
+            let raw_depths = match self.fixed_depths.get(&vector) {
+                Some(fixed) => fixed_values(new_depths, fixed.somf, "SOMF")?,
+                None => Vec::new(),
+            };
+            if let Some(fixed) = self.fixed_positions.get_mut(&vector) {
+                fixed.y.drain(range.clone());
+                fixed.x.drain(range.clone());
+                fixed
+                    .y
+                    .insert_many(range.start, raw.iter().map(|(y, _)| *y));
+                fixed
+                    .x
+                    .insert_many(range.start, raw.iter().map(|(_, x)| *x));
+            }
+            if let Some(fixed) = self.fixed_depths.get_mut(&vector) {
+                fixed.z.drain(range.clone());
+                fixed.z.insert_many(range.start, raw_depths);
+            }
+        } else {
+            let positions = self
+                .exact_positions
+                .entry(vector)
+                .or_insert_with(|| ExactPositions {
+                    lat: smallvec![],
+                    lon: smallvec![],
+                });
+            positions.lat.drain(range.clone());
+            positions.lon.drain(range.clone());
+            positions
+                .lat
+                .insert_many(range.start, new.iter().map(|(lat, _)| lat.clone()));
+            positions
+                .lon
+                .insert_many(range.start, new.iter().map(|(_, lon)| lon.clone()));
+            if let Some(existing) = self.exact_depths.get_mut(&vector) {
+                existing.depth.drain(range.clone());
+                existing
+                    .depth
+                    .insert_many(range.start, new_depths.iter().cloned());
+            }
+        }
+
+        if let Some(meta) = self.vector_meta.get_mut(&vector) {
+            meta.rver = meta.rver.saturating_add(1);
+        }
+        self.refresh_approx(vector);
+        self.invalidate_geometry();
+        Ok(())
+    }
+
+    /// Edit a vector's VRPT pointers as a VRPC control field would
+    ///
+    /// `neighbors` are spliced in by the same rules as
+    /// `update_feature_pointers`, and each must name another live vector.
+    /// An edge has to keep exactly one beginning (TOPI 1) and one end
+    /// (TOPI 2) node, both connected nodes; an edit that would break that
+    /// is rejected and leaves the edge as it was.
+    pub fn update_vector_pointers(
+        &mut self,
+        vector: EntityId,
+        control: UpdateControl,
+        neighbors: &[VectorNeighbor],
+    ) -> Result<()> {
+        self.require_vector(vector)?;
+
+        let mut updated = self
+            .vector_topology
+            .get(&vector)
+            .map(|t| t.neighbors.clone())
+            .unwrap_or_default();
+        let (range, take) =
+            control_range(control, updated.len(), neighbors.len(), "VRPC", "pointers")?;
+        let replacement = &neighbors[..take];
+        for neighbor in replacement {
+            if neighbor.entity == vector {
+                return Err(edit_error("vector cannot point at itself"));
+            }
+            self.require_vector(neighbor.entity)?;
+        }
+        updated.splice(range, replacement.iter().copied());
+        if self.rcnm(vector) == Some(RCNM_EDGE) {
+            for (topi, end) in [(1, "beginning"), (2, "end")] {
+                let nodes: Vec<_> = updated.iter().filter(|n| n.topi == topi).collect();
+                match nodes[..] {
+                    [node] if self.rcnm(node.entity) == Some(RCNM_CONNECTED_NODE) => {}
+                    [_] => {
+                        return Err(edit_error(format!(
+                            "edge {} node must be a connected node",
+                            end
+                        )))
+                    }
+                    _ => {
+                        return Err(edit_error(format!(
+                            "edge would have {} {} nodes",
+                            nodes.len(),
+                            end
+                        )))
+                    }
+                }
+            }
+        }
+
+        self.vector_topology
+            .insert(vector, VectorTopology { neighbors: updated });
+        if let Some(meta) = self.vector_meta.get_mut(&vector) {
+            meta.rver = meta.rver.saturating_add(1);
+        }
+        self.invalidate_geometry();
+        Ok(())
+    }
+
+    /// Delete a feature
+    ///
+    /// Removes it from the FOID index and from every other feature's FFPT
+    /// pointers. The vectors it referenced are left in place, since other
+    /// features may still be built from them.
+    pub fn delete_feature(&mut self, feature: EntityId) -> Result<()> {
+        self.require_feature(feature)?;
+
+        if let Some(meta) = self.feature_meta.get(&feature) {
+            self.foid_index.remove(&meta.foid);
+        }
+        for (other, pointers) in self.feature_pointers.iter_mut() {
+            if *other != feature
+                && pointers
+                    .related_features
+                    .iter()
+                    .any(|r| r.entity == feature)
+            {
+                pointers.related_features.retain(|r| r.entity != feature);
+                if let Some(meta) = self.feature_meta.get_mut(other) {
+                    meta.rver = meta.rver.saturating_add(1);
+                }
+            }
+        }
+
+        self.remove_entity(feature);
+        Ok(())
+    }
+
+    /// Check that an entity is a live feature
+    fn require_feature(&self, entity: EntityId) -> Result<()> {
+        match self.entity_type(entity) {
+            Some(EntityType::Feature) => Ok(()),
+            Some(EntityType::Vector) => Err(edit_error("entity is a vector, not a feature")),
+            None => Err(edit_error("entity does not exist")),
+        }
+    }
+
+    /// Check that an entity is a live vector
+    fn require_vector(&self, entity: EntityId) -> Result<()> {
+        match self.entity_type(entity) {
+            Some(EntityType::Vector) => Ok(()),
+            Some(EntityType::Feature) => Err(edit_error("entity is a feature, not a vector")),
+            None => Err(edit_error("entity does not exist")),
+        }
+    }
+
+    /// RCNM of a vector (isolated or connected node, edge or face)
+    fn rcnm(&self, vector: EntityId) -> Option<u8> {
+        self.vector_meta.get(&vector).map(|meta| meta.name.rcnm)
+    }
+
+    /// Increment a feature's record version
+    fn bump_feature_rver(&mut self, feature: EntityId) {
+        if let Some(meta) = self.feature_meta.get_mut(&feature) {
+            meta.rver = meta.rver.saturating_add(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::{
+        ExactDepths, FeatureMeta, FeaturePointers, Relationship, SpatialRef, VectorMeta,
+    };
+    use crate::fixed::{FixedDepths, FixedPositions};
+    use num_bigint::BigInt;
+    use s57_parse::bitstring::{FoidKey, NameKey};
+
+    fn deg(v: i64) -> BigRational {
+        BigRational::from_integer(BigInt::from(v))
+    }
+
+    /// World with a point feature on an isolated node, plus a second feature
+    /// pointing at it via FFPT
+    fn world_with_point() -> (World, EntityId, EntityId, EntityId) {
+        let mut world = World::new();
+        let node = world.create_entity(EntityType::Vector);
+        let name = NameKey { rcnm: 110, rcid: 1 };
+        world.name_index.insert(name, node);
+        world.vector_meta.insert(
+            node,
+            VectorMeta {
+                name,
+                rver: 1,
+                ruin: 1,
+            },
+        );
+        world.exact_positions.insert(
+            node,
+            ExactPositions {
+                lat: smallvec![deg(10)],
+                lon: smallvec![deg(20)],
+            },
+        );
+
+        let add_feature = |world: &mut World, fidn: u32, related: Vec<FeatureRef>| {
+            let feature = world.create_entity(EntityType::Feature);
+            let foid = FoidKey {
+                agen: 550,
+                fidn,
+                fids: 1,
+            };
+            world.foid_index.insert(foid, feature);
+            world.feature_meta.insert(
+                feature,
+                FeatureMeta {
+                    foid,
+                    prim: PRIM_POINT,
+                    grup: 2,
+                    objl: 17,
+                    rver: 1,
+                    ruin: 1,
+                },
+            );
+            world.feature_pointers.insert(
+                feature,
+                FeaturePointers {
+                    related_features: related,
+                    spatial_refs: vec![SpatialRef {
+                        entity: node,
+                        ornt: 255,
+                        usag: 255,
+                        mask: 255,
+                    }],
+                },
+            );
+            feature
+        };
+        let buoy = add_feature(&mut world, 1, Vec::new());
+        let light = add_feature(
+            &mut world,
+            2,
+            vec![FeatureRef {
+                entity: buoy,
+                rind: Some(Relationship::Master),
+            }],
+        );
+
+        (world, node, buoy, light)
+    }
+
+    #[test]
+    fn test_set_and_remove_attribute() {
+        let (mut world, _, buoy, _) = world_with_point();
+
+        world.set_feature_attribute(buoy, 75, "3").unwrap();
+        world.set_feature_attribute(buoy, 75, "4").unwrap();
+        assert_eq!(
+            world.feature_attributes[&buoy].attf,
+            vec![(75, "4".to_string())]
+        );
+        assert_eq!(world.feature_meta[&buoy].rver, 3);
+
+        assert!(!world.remove_feature_attribute(buoy, 116).unwrap());
+        assert!(world.remove_feature_attribute(buoy, 75).unwrap());
+        assert!(world.feature_attributes[&buoy].attf.is_empty());
+        assert_eq!(world.feature_meta[&buoy].rver, 4);
+    }
+
+    #[test]
+    fn test_update_vector_coordinates() {
+        let (mut world, node, buoy, _) = world_with_point();
+        let control = |instruction, index, count| UpdateControl {
+            instruction,
+            index,
+            count,
+        };
+        let coordinates = |world: &World| -> Vec<(BigRational, BigRational)> {
+            let positions = &world.exact_positions[&node];
+            positions
+                .lat
+                .iter()
+                .cloned()
+                .zip(positions.lon.iter().cloned())
+                .collect()
+        };
+
+        // Insert two after the first, replace the last, delete the first
+        let new = [(deg(11), deg(21)), (deg(12), deg(22))];
+        world
+            .update_vector_coordinates(node, control(UpdateInstruction::Insert, 2, 2), &new, &[])
+            .unwrap();
+        world
+            .update_vector_coordinates(
+                node,
+                control(UpdateInstruction::Modify, 3, 1),
+                &[(deg(13), deg(23))],
+                &[],
+            )
+            .unwrap();
+        world
+            .update_vector_coordinates(node, control(UpdateInstruction::Delete, 1, 1), &[], &[])
+            .unwrap();
+        assert_eq!(
+            coordinates(&world),
+            [(deg(11), deg(21)), (deg(13), deg(23))]
+        );
+        assert_eq!(world.vector_meta[&node].rver, 4);
+
+        // Depths go only where there are depths, one per coordinate
+        let modify = control(UpdateInstruction::Modify, 1, 1);
+        let point = [(deg(10), deg(20))];
+        assert!(world
+            .update_vector_coordinates(node, modify, &point, &[deg(5)])
+            .is_err());
+        world.exact_depths.insert(
+            node,
+            ExactDepths {
+                depth: smallvec![deg(7), deg(8)],
+                units: 1,
+            },
+        );
+        assert!(world
+            .update_vector_coordinates(node, modify, &point, &[])
+            .is_err());
+        world
+            .update_vector_coordinates(node, modify, &point, &[deg(5)])
+            .unwrap();
+        assert_eq!(world.exact_depths[&node].depth.as_slice(), [deg(5), deg(8)]);
+
+        // Out of range, and features aren't vectors
+        let delete = control(UpdateInstruction::Delete, 2, 2);
+        assert!(world
+            .update_vector_coordinates(node, delete, &[], &[])
+            .is_err());
+        assert!(world
+            .update_vector_coordinates(buoy, modify, &point, &[])
+            .is_err());
+        assert_eq!(world.vector_meta[&node].rver, 5);
+    }
+
+    #[test]
+    fn test_update_vector_pointers() {
+        let mut world = World::new();
+        let mut vector = |rcnm, rcid| {
+            let entity = world.create_entity(EntityType::Vector);
+            let name = NameKey { rcnm, rcid };
+            world.name_index.insert(name, entity);
+            world.vector_meta.insert(
+                entity,
+                VectorMeta {
+                    name,
+                    rver: 1,
+                    ruin: 1,
+                },
+            );
+            entity
+        };
+        let [a, b, c] = [1, 2, 3].map(|rcid| vector(RCNM_CONNECTED_NODE, rcid));
+        let isolated = vector(110, 4);
+        let edge = vector(RCNM_EDGE, 10);
+        let pointer = |entity, topi| VectorNeighbor {
+            entity,
+            ornt: 255,
+            usag: 255,
+            topi,
+            mask: 255,
+        };
+        let control = |instruction, index, count| UpdateControl {
+            instruction,
+            index,
+            count,
+        };
+        let ends = |world: &World| -> Vec<_> {
+            world.vector_topology[&edge]
+                .neighbors
+                .iter()
+                .map(|n| (n.entity, n.topi))
+                .collect()
+        };
+
+        // Give the edge its nodes, then move its end from b to c
+        world
+            .update_vector_pointers(
+                edge,
+                control(UpdateInstruction::Insert, 1, 2),
+                &[pointer(a, 1), pointer(b, 2)],
+            )
+            .unwrap();
+        world
+            .update_vector_pointers(
+                edge,
+                control(UpdateInstruction::Modify, 2, 1),
+                &[pointer(c, 2)],
+            )
+            .unwrap();
+        assert_eq!(ends(&world), [(a, 1), (c, 2)]);
+        assert_eq!(world.vector_meta[&edge].rver, 3);
+
+        // Dropping an end, adding a second beginning, ending on an isolated
+        // node or on the edge itself all leave it alone
+        for (instruction, index, new) in [
+            (UpdateInstruction::Delete, 2, vec![]),
+            (UpdateInstruction::Insert, 1, vec![pointer(b, 1)]),
+            (UpdateInstruction::Modify, 2, vec![pointer(isolated, 2)]),
+            (UpdateInstruction::Modify, 2, vec![pointer(edge, 2)]),
+        ] {
+            assert!(world
+                .update_vector_pointers(edge, control(instruction, index, 1), &new)
+                .is_err());
+        }
+        assert_eq!(ends(&world), [(a, 1), (c, 2)]);
+        assert_eq!(world.vector_meta[&edge].rver, 3);
+    }
+
+    #[test]
+    fn test_move_point_feature() {
+        let (mut world, node, buoy, _) = world_with_point();
+
+        world.move_point_feature(buoy, deg(11), deg(21)).unwrap();
+        assert_eq!(world.exact_positions[&node].lat[0], deg(11));
+        assert_eq!(world.exact_positions[&node].lon[0], deg(21));
+        assert_eq!(world.vector_meta[&node].rver, 2);
+        assert_eq!(world.feature_meta[&buoy].rver, 2);
+
+        // Vectors are not features
+        assert!(world.move_point_feature(node, deg(0), deg(0)).is_err());
+    }
+
+    #[test]
+    fn test_edit_fixed_point_vector() {
+        // The node held in fixed point, COMF 10 and SOMF 10
+        let (mut world, node, buoy, _) = world_with_point();
+        world.exact_positions.remove(&node);
+        world.fixed_positions.insert(
+            node,
+            FixedPositions {
+                y: smallvec![100],
+                x: smallvec![200],
+                comf: 10,
+            },
+        );
+        world.fixed_depths.insert(
+            node,
+            FixedDepths {
+                z: smallvec![55],
+                somf: 10,
+                units: 1,
+            },
+        );
+        let tenths = |v: i64| BigRational::new(BigInt::from(v), BigInt::from(10));
+
+        world
+            .move_point_feature(buoy, tenths(115), deg(21))
+            .unwrap();
+        assert_eq!(world.fixed_positions[&node].y.as_slice(), [115]);
+        assert_eq!(world.fixed_positions[&node].x.as_slice(), [210]);
+        assert!(!world.exact_positions.contains_key(&node));
+
+        let append = UpdateControl {
+            instruction: UpdateInstruction::Insert,
+            index: 2,
+            count: 1,
+        };
+        world
+            .update_vector_coordinates(node, append, &[(deg(12), tenths(225))], &[tenths(61)])
+            .unwrap();
+        assert_eq!(world.fixed_positions[&node].y.as_slice(), [115, 120]);
+        assert_eq!(world.fixed_positions[&node].x.as_slice(), [210, 225]);
+        assert_eq!(world.fixed_depths[&node].z.as_slice(), [55, 61]);
+
+        // Finer than 1/COMF or 1/SOMF is rejected and changes nothing
+        let hundredth = BigRational::new(BigInt::from(1), BigInt::from(100));
+        assert!(world
+            .update_vector_coordinates(node, append, &[(hundredth.clone(), deg(0))], &[deg(1)])
+            .is_err());
+        assert!(world
+            .update_vector_coordinates(node, append, &[(deg(0), deg(0))], &[hundredth])
+            .is_err());
+        assert_eq!(world.fixed_positions[&node].len(), 2);
+        assert_eq!(world.fixed_depths[&node].z.len(), 2);
+        assert_eq!(world.vector_meta[&node].rver, 3);
+    }
+
+    #[test]
+    fn test_update_feature_pointers() {
+        let (mut world, node, buoy, light) = world_with_point();
+        let control = |instruction, index, count| UpdateControl {
+            instruction,
+            index,
+            count,
+        };
+        let slave = |entity| FeatureRef {
+            entity,
+            rind: Some(Relationship::Slave),
+        };
+        let targets = |world: &World| -> Vec<_> {
+            world.feature_pointers[&buoy]
+                .related_features
+                .iter()
+                .map(|r| r.entity)
+                .collect()
+        };
+
+        // Append the light to the buoy, then replace it with itself as peer
+        world
+            .update_feature_pointers(
+                buoy,
+                control(UpdateInstruction::Insert, 1, 1),
+                &[slave(light)],
+            )
+            .unwrap();
+        assert_eq!(targets(&world), [light]);
+        assert_eq!(world.slaves_of(buoy), [light]);
+        let peer = FeatureRef {
+            entity: light,
+            rind: Some(Relationship::Peer),
+        };
+        world
+            .update_feature_pointers(buoy, control(UpdateInstruction::Modify, 1, 1), &[peer])
+            .unwrap();
+        assert_eq!(world.peers_of(buoy), [light]);
+        assert_eq!(world.feature_meta[&buoy].rver, 3);
+
+        // Out of range, too few pointers, and pointers at non-features
+        let bad = [
+            control(UpdateInstruction::Insert, 3, 1),
+            control(UpdateInstruction::Delete, 1, 2),
+            control(UpdateInstruction::Modify, 0, 1),
+        ];
+        for c in bad {
+            assert!(world
+                .update_feature_pointers(buoy, c, &[slave(light)])
+                .is_err());
+        }
+        let insert = control(UpdateInstruction::Insert, 1, 1);
+        assert!(world.update_feature_pointers(buoy, insert, &[]).is_err());
+        assert!(world
+            .update_feature_pointers(buoy, insert, &[slave(node)])
+            .is_err());
+        assert!(world
+            .update_feature_pointers(buoy, insert, &[slave(buoy)])
+            .is_err());
+        assert_eq!(world.feature_meta[&buoy].rver, 3);
+
+        world
+            .update_feature_pointers(buoy, control(UpdateInstruction::Delete, 1, 1), &[])
+            .unwrap();
+        assert!(targets(&world).is_empty());
+    }
+
+    #[test]
+    fn test_delete_feature_updates_indexes() {
+        let (mut world, node, buoy, light) = world_with_point();
+
+        world.delete_feature(buoy).unwrap();
+        assert!(!world.is_valid(buoy));
+        assert!(!world.foid_index.values().any(|e| *e == buoy));
+        assert!(world.feature_pointers[&light].related_features.is_empty());
+        assert_eq!(world.feature_meta[&light].rver, 2);
+        // Shared node is kept for the remaining feature
+        assert!(world.is_valid(node));
+
+        assert!(world.delete_feature(buoy).is_err());
+    }
+}
@@ -0,0 +1,317 @@
+//! Typed feature access
+//!
+//! The World stores a feature as separate components: FeatureMeta for the
+//! FRID/FOID fields, FeatureAttributes for raw (ATTL, value) pairs, and
+//! FeaturePointers for its geometry. `Feature` gathers them behind one
+//! borrowed view and decodes what it returns through the s57-catalogue: the
+//! object class from OBJL and attribute values by their catalogue type.
+
+use crate::depth::{DepthBand, DepthThresholds};
+use crate::ecs::{EntityId, EntityType, FeatureMeta, NamePreference, World};
+use crate::geometry_cache::ResolvedGeometry;
+use crate::topology::{TopologyResult, TraversalContext};
+use num_rational::BigRational;
+use s57_catalogue::{AttributeInfo, AttributeValue, ObjectClass};
+use s57_parse::bitstring::FoidKey;
+use std::sync::Arc;
+
+/// PRIM value of point features
+const PRIM_POINT: u8 = 1;
+
+/// AttributeKey: Anything that names an attribute
+///
+/// Implemented for ATTL codes, acronyms ("CATLAM") and catalogue entries.
+pub trait AttributeKey {
+    /// ATTL code, or None if the key names no known attribute
+    fn attl(&self) -> Option<u16>;
+}
+
+impl AttributeKey for u16 {
+    fn attl(&self) -> Option<u16> {
+        Some(*self)
+    }
+}
+
+impl AttributeKey for &str {
+    fn attl(&self) -> Option<u16> {
+        AttributeInfo::from_acronym(self).map(|info| info.code)
+    }
+}
+
+impl AttributeKey for &AttributeInfo {
+    fn attl(&self) -> Option<u16> {
+        Some(self.code)
+    }
+}
+
+/// FeatureGeometry: Positions of a feature
+#[derive(Debug, Clone, PartialEq)]
+pub enum FeatureGeometry {
+    /// Node positions (lat, lon) of a point feature; several for SOUNDG
+    Points(Vec<(BigRational, BigRational)>),
+    /// Line or area geometry after topology resolution
+    Resolved(Arc<ResolvedGeometry>),
+}
+
+/// Feature: Read-only view of one feature in a World
+#[derive(Debug, Clone, Copy)]
+pub struct Feature<'a> {
+    world: &'a World,
+    id: EntityId,
+    meta: &'a FeatureMeta,
+}
+
+impl World {
+    /// Every feature in the World
+    pub fn features(&self) -> impl Iterator<Item = Feature<'_>> {
+        self.entities_of_type(EntityType::Feature)
+            .into_iter()
+            .filter_map(|id| self.feature(id))
+    }
+
+    /// View of a feature entity; None for vectors and removed entities
+    pub fn feature(&self, id: EntityId) -> Option<Feature<'_>> {
+        let meta = self.feature_meta.get(&id)?;
+        Some(Feature {
+            world: self,
+            id,
+            meta,
+        })
+    }
+
+    /// View of the feature with a FOID
+    pub fn feature_by_foid(&self, foid: &FoidKey) -> Option<Feature<'_>> {
+        self.feature(*self.foid_index.get(foid)?)
+    }
+}
+
+impl<'a> Feature<'a> {
+    /// Entity id in the World
+    pub fn id(&self) -> EntityId {
+        self.id
+    }
+
+    /// Feature object identifier
+    pub fn foid(&self) -> FoidKey {
+        self.meta.foid
+    }
+
+    /// FRID fields (OBJL, PRIM, GRUP, RVER, RUIN)
+    pub fn meta(&self) -> &'a FeatureMeta {
+        self.meta
+    }
+
+    /// Object class from OBJL; None for codes outside the catalogue
+    pub fn object_class(&self) -> Option<ObjectClass> {
+        ObjectClass::from_code(self.meta.objl)
+    }
+
+    /// Raw ATTF or NATF value of an attribute
+    pub fn raw_attribute(&self, key: impl AttributeKey) -> Option<&'a str> {
+        let attl = key.attl()?;
+        let attrs = self.world.feature_attributes.get(&self.id)?;
+        attrs.get(attl).or_else(|| attrs.get_national(attl))
+    }
+
+    /// Attribute value decoded by its catalogue type
+    ///
+    /// None if the feature doesn't carry the attribute. Values that don't
+    /// decode (unknown ATTL, malformed numbers) come back as `Text`.
+    pub fn attribute(&self, key: impl AttributeKey) -> Option<AttributeValue<'a>> {
+        let attl = key.attl()?;
+        self.raw_attribute(attl).map(|raw| decode(attl, raw))
+    }
+
+    /// Every ATTF and NATF attribute, decoded as by `attribute`
+    pub fn attributes(&self) -> impl Iterator<Item = (u16, AttributeValue<'a>)> {
+        self.world
+            .feature_attributes
+            .get(&self.id)
+            .into_iter()
+            .flat_map(|attrs| attrs.attf.iter().chain(&attrs.natf))
+            .map(|(attl, raw)| (*attl, decode(*attl, raw)))
+    }
+
+    /// Object name (OBJNAM / NOBJNM) according to a preference
+    pub fn name(&self, preference: NamePreference) -> Option<&'a str> {
+        self.world.feature_name(self.id, preference)
+    }
+
+    /// Depth band of a DEPARE or DRGARE feature, as `World::depth_band`
+    pub fn depth_band(&self, thresholds: &DepthThresholds) -> Option<DepthBand> {
+        self.world.depth_band(self.id, thresholds)
+    }
+
+    /// Geometry, resolved with the default traversal policies
+    ///
+    /// Use `geometry_with` for other policies or a coordinate transform.
+    pub fn geometry(&self) -> TopologyResult<Option<FeatureGeometry>> {
+        self.geometry_with(&TraversalContext::new(self.world))
+    }
+
+    /// Geometry, resolved through a traversal context
+    ///
+    /// Ok(None) for features without geometry (PRIM 255, or no positioned
+    /// vectors).
+    pub fn geometry_with(&self, ctx: &TraversalContext) -> TopologyResult<Option<FeatureGeometry>> {
+        if self.meta.prim != PRIM_POINT {
+            return Ok(ctx
+                .feature_geometry(self.id)?
+                .map(FeatureGeometry::Resolved));
+        }
+        let points: Vec<_> = self
+            .world
+            .feature_pointers
+            .get(&self.id)
+            .into_iter()
+            .flat_map(|p| &p.spatial_refs)
+            .filter_map(|sref| self.world.exact_positions.get(&sref.entity))
+            .flat_map(|positions| positions.lat.iter().zip(positions.lon.iter()))
+            .map(|(lat, lon)| ctx.transform_point(lat, lon))
+            .collect();
+        Ok((!points.is_empty()).then_some(FeatureGeometry::Points(points)))
+    }
+}
+
+fn decode(attl: u16, raw: &str) -> AttributeValue<'_> {
+    AttributeInfo::decode_value(attl, raw).unwrap_or(AttributeValue::Text(raw))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::{ExactPositions, FeatureAttributes, FeaturePointers, SpatialRef, VectorMeta};
+    use num_bigint::BigInt;
+    use s57_catalogue::EnumValue;
+    use s57_parse::bitstring::NameKey;
+    use smallvec::smallvec;
+
+    fn int(v: i64) -> BigRational {
+        BigInt::from(v).into()
+    }
+
+    /// BOYLAT at (50, -1) with CATLAM, COLOUR and OBJNAM
+    fn buoy_world() -> (World, EntityId) {
+        let mut world = World::new();
+        let node = world.create_entity(EntityType::Vector);
+        let name = NameKey { rcnm: 110, rcid: 1 };
+        world.name_index.insert(name, node);
+        world.vector_meta.insert(
+            node,
+            VectorMeta {
+                name,
+                rver: 1,
+                ruin: 1,
+            },
+        );
+        world.exact_positions.insert(
+            node,
+            ExactPositions {
+                lat: smallvec![int(50)],
+                lon: smallvec![int(-1)],
+            },
+        );
+
+        let buoy = world.create_entity(EntityType::Feature);
+        let foid = FoidKey {
+            agen: 550,
+            fidn: 7,
+            fids: 1,
+        };
+        world.foid_index.insert(foid, buoy);
+        world.feature_meta.insert(
+            buoy,
+            FeatureMeta {
+                foid,
+                prim: 1,
+                grup: 1,
+                objl: 17,
+                rver: 1,
+                ruin: 1,
+            },
+        );
+        world.feature_attributes.insert(
+            buoy,
+            FeatureAttributes {
+                attf: vec![
+                    (36, "1".to_string()),
+                    (75, "3,1,3".to_string()),
+                    (116, "Outer".to_string()),
+                    (133, "many".to_string()),
+                ],
+                natf: vec![(301, "Äußere".to_string())],
+            },
+        );
+        world.feature_pointers.insert(
+            buoy,
+            FeaturePointers {
+                related_features: Vec::new(),
+                spatial_refs: vec![SpatialRef {
+                    entity: node,
+                    ornt: 255,
+                    usag: 255,
+                    mask: 255,
+                }],
+            },
+        );
+        (world, buoy)
+    }
+
+    #[test]
+    fn test_feature_view() {
+        let (world, buoy) = buoy_world();
+        assert_eq!(world.features().count(), 1);
+        let feature = world
+            .feature_by_foid(&FoidKey {
+                agen: 550,
+                fidn: 7,
+                fids: 1,
+            })
+            .unwrap();
+        assert_eq!(feature.id(), buoy);
+        assert_eq!(feature.foid().fidn, 7);
+        assert_eq!(feature.object_class(), Some(ObjectClass::BuoyLateral));
+
+        // By code, acronym or catalogue entry
+        let port = AttributeValue::Enum(EnumValue {
+            id: 1,
+            meaning: Some("port-hand lateral mark"),
+        });
+        assert_eq!(feature.attribute(36u16), Some(port.clone()));
+        assert_eq!(feature.attribute("CATLAM"), Some(port));
+        let colour = AttributeInfo::from_code(75).unwrap();
+        let Some(AttributeValue::List(colours)) = feature.attribute(&colour) else {
+            panic!("COLOUR is a list");
+        };
+        let colours: Vec<_> = colours.map(|c| c.to_string()).collect();
+        assert_eq!(colours, ["red", "white", "red"]);
+
+        // Absent, national, and undecodable attributes
+        assert_eq!(feature.attribute("DRVAL1"), None);
+        assert_eq!(feature.raw_attribute("NOBJNM"), Some("Äußere"));
+        assert_eq!(
+            feature.attribute(133u16),
+            Some(AttributeValue::Text("many"))
+        );
+        assert_eq!(feature.attributes().count(), 5);
+        assert_eq!(feature.name(NamePreference::National), Some("Äußere"));
+
+        assert_eq!(
+            feature.geometry().unwrap(),
+            Some(FeatureGeometry::Points(vec![(int(50), int(-1))]))
+        );
+
+        // Vectors aren't features
+        let node = world.name_index[&NameKey { rcnm: 110, rcid: 1 }];
+        assert!(world.feature(node).is_none());
+
+        // Unknown (empty) and removed (update delete marker) values
+        let mut world = world;
+        let attrs = world.feature_attributes.get_mut(&buoy).unwrap();
+        attrs.attf.push((87, String::new()));
+        attrs.attf.push((88, "\u{7f}".to_string()));
+        let feature = world.feature(buoy).unwrap();
+        assert_eq!(feature.attribute("DRVAL1"), Some(AttributeValue::Unknown));
+        assert_eq!(feature.attribute("DRVAL2"), Some(AttributeValue::Removed));
+    }
+}
@@ -0,0 +1,256 @@
+//! Content fingerprint of a World
+//!
+//! Two cells get the same fingerprint when they describe the same features
+//! with the same attributes and exact geometry, regardless of record order,
+//! vector RCIDs or record versions. Each feature is digested on its own
+//! (FOID, class, attributes and the exact coordinates it is built from), and
+//! the sorted digests are hashed together.
+
+use crate::ecs::{EntityId, EntityType, World};
+use num_rational::BigRational;
+use sha2::{Digest, Sha256};
+use std::fmt;
+
+/// Fingerprint: SHA-256 over a World's semantic content
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Fingerprint(pub [u8; 32]);
+
+impl fmt::Display for Fingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in &self.0 {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl World {
+    /// Compute a content hash that ignores record ordering and identifiers
+    ///
+    /// Covers every feature's FOID, PRIM/GRUP/OBJL, ATTF/NATF attributes
+    /// (order-independent), FFPT targets (by FOID) and the exact coordinates
+    /// and depths of the vectors it references, in FSPT order with their
+    /// orientation/usage/mask flags. Dataset parameters, RVER/RUIN and raw
+    /// non-standard fields are not included.
+    pub fn fingerprint(&self) -> Fingerprint {
+        let mut digests: Vec<[u8; 32]> = self
+            .entities_of_type(EntityType::Feature)
+            .into_iter()
+            .filter_map(|feature| self.feature_digest(feature))
+            .collect();
+        digests.sort_unstable();
+
+        let mut hasher = Sha256::new();
+        hasher.update((digests.len() as u64).to_be_bytes());
+        for digest in &digests {
+            hasher.update(digest);
+        }
+        Fingerprint(hasher.finalize().into())
+    }
+
+    /// Digest of one feature, or None for entities without FeatureMeta
+    pub(crate) fn feature_digest(&self, feature: EntityId) -> Option<[u8; 32]> {
+        let meta = self.feature_meta.get(&feature)?;
+        let mut h = Sha256::new();
+
+        h.update(b"FOID");
+        h.update(meta.foid.agen.to_be_bytes());
+        h.update(meta.foid.fidn.to_be_bytes());
+        h.update(meta.foid.fids.to_be_bytes());
+        h.update([meta.prim, meta.grup]);
+        h.update(meta.objl.to_be_bytes());
+
+        if let Some(attrs) = self.feature_attributes.get(&feature) {
+            for (tag, list) in [(b"ATTF", &attrs.attf), (b"NATF", &attrs.natf)] {
+                let mut sorted: Vec<_> = list.iter().collect();
+                sorted.sort();
+                h.update(tag);
+                h.update((sorted.len() as u64).to_be_bytes());
+                for (attl, value) in sorted {
+                    h.update(attl.to_be_bytes());
+                    hash_bytes(&mut h, value.as_bytes());
+                }
+            }
+        }
+
+        if let Some(pointers) = self.feature_pointers.get(&feature) {
+            let mut related: Vec<_> = pointers
+                .related_features
+                .iter()
+                .filter_map(|r| {
+                    let m = self.feature_meta.get(&r.entity)?;
+                    Some((
+                        m.foid.agen,
+                        m.foid.fidn,
+                        m.foid.fids,
+                        r.rind.map_or(0, |k| k.code()),
+                    ))
+                })
+                .collect();
+            related.sort_unstable();
+            h.update(b"FFPT");
+            h.update((related.len() as u64).to_be_bytes());
+            for (agen, fidn, fids, rind) in related {
+                h.update(agen.to_be_bytes());
+                h.update(fidn.to_be_bytes());
+                h.update(fids.to_be_bytes());
+                h.update([rind]);
+            }
+
+            h.update(b"FSPT");
+            h.update((pointers.spatial_refs.len() as u64).to_be_bytes());
+            for sref in &pointers.spatial_refs {
+                h.update([sref.ornt, sref.usag, sref.mask]);
+                self.hash_vector(&mut h, sref.entity);
+                if let Some(topology) = self.vector_topology.get(&sref.entity) {
+                    h.update((topology.neighbors.len() as u64).to_be_bytes());
+                    for neighbor in &topology.neighbors {
+                        h.update([neighbor.ornt, neighbor.usag, neighbor.topi, neighbor.mask]);
+                        self.hash_vector(&mut h, neighbor.entity);
+                    }
+                }
+            }
+        }
+
+        Some(h.finalize().into())
+    }
+
+    /// Hash a vector's exact coordinates and depths
+    fn hash_vector(&self, h: &mut Sha256, vector: EntityId) {
+        match self.exact_positions.get(&vector) {
+            Some(positions) => {
+                h.update((positions.lat.len() as u64).to_be_bytes());
+                for (lat, lon) in positions.lat.iter().zip(positions.lon.iter()) {
+                    hash_rational(h, lat);
+                    hash_rational(h, lon);
+                }
+            }
+            None => h.update(0u64.to_be_bytes()),
+        }
+        match self.exact_depths.get(&vector) {
+            Some(depths) => {
+                h.update((depths.depth.len() as u64).to_be_bytes());
+                for depth in &depths.depth {
+                    hash_rational(h, depth);
+                }
+            }
+            None => h.update(0u64.to_be_bytes()),
+        }
+    }
+}
+
+/// Hash length-prefixed bytes so adjacent values can't run together
+fn hash_bytes(h: &mut Sha256, bytes: &[u8]) {
+    h.update((bytes.len() as u64).to_be_bytes());
+    h.update(bytes);
+}
+
+/// Hash a rational in lowest terms (BigRational is always normalised)
+fn hash_rational(h: &mut Sha256, value: &BigRational) {
+    hash_bytes(h, &value.numer().to_signed_bytes_be());
+    hash_bytes(h, &value.denom().to_signed_bytes_be());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::{
+        ExactPositions, FeatureAttributes, FeatureMeta, FeaturePointers, SpatialRef, VectorMeta,
+    };
+    use num_bigint::BigInt;
+    use s57_parse::bitstring::{FoidKey, NameKey};
+    use smallvec::smallvec;
+
+    /// Build a World from (fidn, rcid, lat, lon) point features, in order
+    fn world_from(points: &[(u32, u32, i64, i64)]) -> World {
+        let mut world = World::new();
+        for (fidn, rcid, lat, lon) in points {
+            let node = world.create_entity(EntityType::Vector);
+            let name = NameKey {
+                rcnm: 110,
+                rcid: *rcid,
+            };
+            world.name_index.insert(name, node);
+            world.vector_meta.insert(
+                node,
+                VectorMeta {
+                    name,
+                    rver: 1,
+                    ruin: 1,
+                },
+            );
+            world.exact_positions.insert(
+                node,
+                ExactPositions {
+                    lat: smallvec![BigRational::new(BigInt::from(*lat), BigInt::from(100))],
+                    lon: smallvec![BigRational::new(BigInt::from(*lon), BigInt::from(100))],
+                },
+            );
+
+            let feature = world.create_entity(EntityType::Feature);
+            let foid = FoidKey {
+                agen: 550,
+                fidn: *fidn,
+                fids: 1,
+            };
+            world.foid_index.insert(foid, feature);
+            world.feature_meta.insert(
+                feature,
+                FeatureMeta {
+                    foid,
+                    prim: 1,
+                    grup: 2,
+                    objl: 75,
+                    rver: 1,
+                    ruin: 1,
+                },
+            );
+            world.feature_attributes.insert(
+                feature,
+                FeatureAttributes {
+                    attf: vec![(116, format!("Light {}", fidn)), (75, "1".to_string())],
+                    natf: Vec::new(),
+                },
+            );
+            world.feature_pointers.insert(
+                feature,
+                FeaturePointers {
+                    related_features: Vec::new(),
+                    spatial_refs: vec![SpatialRef {
+                        entity: node,
+                        ornt: 255,
+                        usag: 255,
+                        mask: 255,
+                    }],
+                },
+            );
+        }
+        world
+    }
+
+    #[test]
+    fn test_fingerprint_ignores_order_and_rcids() {
+        let a = world_from(&[(1, 1, 1000, 2000), (2, 2, 1100, 2100)]);
+        let b = world_from(&[(2, 7, 1100, 2100), (1, 9, 1000, 2000)]);
+        assert_eq!(a.fingerprint(), b.fingerprint());
+        assert_eq!(a.fingerprint().to_string().len(), 64);
+
+        // Moving one point changes it
+        let c = world_from(&[(1, 1, 1000, 2000), (2, 2, 1100, 2101)]);
+        assert_ne!(a.fingerprint(), c.fingerprint());
+
+        // So does an attribute value, but not attribute order
+        let mut d = world_from(&[(1, 1, 1000, 2000), (2, 2, 1100, 2100)]);
+        for attrs in d.feature_attributes.values_mut() {
+            attrs.attf.reverse();
+        }
+        assert_eq!(a.fingerprint(), d.fingerprint());
+        let feature = d.foid_index[&FoidKey {
+            agen: 550,
+            fidn: 1,
+            fids: 1,
+        }];
+        d.set_feature_attribute(feature, 75, "3").unwrap();
+        assert_ne!(a.fingerprint(), d.fingerprint());
+    }
+}
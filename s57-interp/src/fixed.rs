@@ -0,0 +1,308 @@
+//! Fixed-point coordinates
+//!
+//! SG2D/SG3D values are integers scaled by the dataset's COMF and SOMF, so
+//! keeping the raw integers next to their factor loses nothing: the exact
+//! value is always `raw / factor`. `FixedPositions` and `FixedDepths` store
+//! them as i64, one machine word per value instead of a BigRational's two
+//! heap-allocated BigInts.
+//!
+//! A World built with `CoordinateBackend::Fixed` holds these components
+//! instead of `exact_positions`/`exact_depths` for SG2D/SG3D vectors (ARCC
+//! curves are densified and stay exact). Geometry resolution, extents,
+//! region extraction and edits read and write either backend; other exact
+//! consumers read the exact components only, so call `World::promote_fixed`
+//! before using them.
+
+use crate::ecs::{EntityId, ExactDepths, ExactPositions, World, INLINE_COORDINATES};
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::ToPrimitive;
+use smallvec::SmallVec;
+use std::borrow::Cow;
+
+/// FixedCoordinates: Raw values for one vector, inline for points and short lines
+pub type FixedCoordinates = SmallVec<[i64; INLINE_COORDINATES]>;
+
+/// FixedPositions: Raw XCOO/YCOO values and the COMF they are scaled by
+///
+/// lat = y / comf, lon = x / comf (degrees)
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FixedPositions {
+    /// YCOO values
+    pub y: FixedCoordinates,
+    /// XCOO values
+    pub x: FixedCoordinates,
+    /// Coordinate multiplication factor
+    pub comf: i64,
+}
+
+impl FixedPositions {
+    /// Number of points
+    pub fn len(&self) -> usize {
+        self.y.len()
+    }
+
+    /// True if there are no points
+    pub fn is_empty(&self) -> bool {
+        self.y.is_empty()
+    }
+
+    /// Exact latitude and longitude of point `i`
+    pub fn exact(&self, i: usize) -> Option<(BigRational, BigRational)> {
+        Some((
+            scaled(*self.y.get(i)?, self.comf),
+            scaled(*self.x.get(i)?, self.comf),
+        ))
+    }
+
+    /// Exact positions with the same values
+    pub fn to_exact(&self) -> ExactPositions {
+        ExactPositions {
+            lat: self.y.iter().map(|&y| scaled(y, self.comf)).collect(),
+            lon: self.x.iter().map(|&x| scaled(x, self.comf)).collect(),
+        }
+    }
+
+    /// Convert to f64 (lat, lon) vectors, as `ExactPositions::to_f64`
+    pub fn to_f64(&self) -> (Vec<f64>, Vec<f64>) {
+        let comf = self.comf as f64;
+        (
+            self.y.iter().map(|&y| y as f64 / comf).collect(),
+            self.x.iter().map(|&x| x as f64 / comf).collect(),
+        )
+    }
+}
+
+/// FixedDepths: Raw VE3D values and the SOMF they are scaled by
+///
+/// depth = z / somf (in DUNI units)
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FixedDepths {
+    /// VE3D values
+    pub z: FixedCoordinates,
+    /// Sounding multiplication factor
+    pub somf: i64,
+    /// Units (from DUNI)
+    pub units: u16,
+}
+
+impl FixedDepths {
+    /// Exact depths with the same values
+    pub fn to_exact(&self) -> ExactDepths {
+        ExactDepths {
+            depth: self.z.iter().map(|&z| scaled(z, self.somf)).collect(),
+            units: self.units,
+        }
+    }
+
+    /// Convert to f64, as `ExactDepths::to_f64`
+    pub fn to_f64(&self) -> Vec<f64> {
+        let somf = self.somf as f64;
+        self.z.iter().map(|&z| z as f64 / somf).collect()
+    }
+}
+
+/// Exact value of a raw integer over its factor
+fn scaled(raw: i64, factor: i64) -> BigRational {
+    BigRational::new(BigInt::from(raw), BigInt::from(factor))
+}
+
+/// Raw integer of an exact value over `factor`
+///
+/// None unless the value is a whole multiple of 1/factor that fits in i64.
+pub(crate) fn unscaled(value: &BigRational, factor: i64) -> Option<i64> {
+    let raw = value * BigInt::from(factor);
+    if raw.is_integer() {
+        raw.to_integer().to_i64()
+    } else {
+        None
+    }
+}
+
+impl World {
+    /// Replace every fixed-point component with its exact equivalent
+    ///
+    /// Needed before geometry resolution, rendering or export of a World
+    /// built with `CoordinateBackend::Fixed`. Returns the number of vectors
+    /// converted.
+    pub fn promote_fixed(&mut self) -> usize {
+        let positions: Vec<(EntityId, FixedPositions)> = self.fixed_positions.drain().collect();
+        let count = positions.len();
+        for (entity, fixed) in positions {
+            self.exact_positions.insert(entity, fixed.to_exact());
+        }
+        for (entity, fixed) in std::mem::take(&mut self.fixed_depths) {
+            self.exact_depths.insert(entity, fixed.to_exact());
+        }
+        self.invalidate_geometry();
+        count
+    }
+
+    /// Exact positions of a vector from whichever backend holds it
+    pub fn exact_positions_of(&self, entity: EntityId) -> Option<Cow<'_, ExactPositions>> {
+        match self.fixed_positions.get(&entity) {
+            Some(fixed) => Some(Cow::Owned(fixed.to_exact())),
+            None => self.exact_positions.get(&entity).map(Cow::Borrowed),
+        }
+    }
+
+    /// Exact depths of a vector from whichever backend holds them
+    pub fn exact_depths_of(&self, entity: EntityId) -> Option<Cow<'_, ExactDepths>> {
+        match self.fixed_depths.get(&entity) {
+            Some(fixed) => Some(Cow::Owned(fixed.to_exact())),
+            None => self.exact_depths.get(&entity).map(Cow::Borrowed),
+        }
+    }
+
+    /// f64 (lat, lon) of a vector from whichever backend holds it
+    ///
+    /// Taken from `approx_positions` when the World has them.
+    pub fn positions_f64(&self, entity: EntityId) -> Option<(Vec<f64>, Vec<f64>)> {
+        if let Some(approx) = self.approx_positions.get(&entity) {
+            return Some((approx.lat.to_vec(), approx.lon.to_vec()));
+        }
+        match self.fixed_positions.get(&entity) {
+            Some(fixed) => Some(fixed.to_f64()),
+            None => self
+                .exact_positions
+                .get(&entity)
+                .map(ExactPositions::to_f64),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::EntityType;
+    use crate::{build_world, build_world_with_options, NameKey, ParseOptions};
+    use s57_parse::iso8211::{write_file, Record};
+    use s57_parse::S57File;
+    use smallvec::smallvec;
+
+    fn vrid(rcnm: u8, rcid: u32) -> Vec<u8> {
+        let mut data = vec![rcnm];
+        data.extend(rcid.to_le_bytes());
+        data.extend([1, 0, 1, 0x1E]);
+        data
+    }
+
+    /// SG2D or SG3D from (YCOO, XCOO[, VE3D]) tuples
+    fn coordinates(values: &[&[i32]]) -> Vec<u8> {
+        let mut data: Vec<u8> = values
+            .iter()
+            .flat_map(|point| point.iter().flat_map(|v| v.to_le_bytes()))
+            .collect();
+        data.push(0x1E);
+        data
+    }
+
+    #[test]
+    fn test_fixed_positions_are_lossless() {
+        let fixed = FixedPositions {
+            y: smallvec![515_000_001, -1],
+            x: smallvec![-12_345_678, 0],
+            comf: 10_000_000,
+        };
+        let exact = fixed.to_exact();
+        assert_eq!(
+            exact.lat[0],
+            BigRational::new(BigInt::from(515_000_001), BigInt::from(10_000_000))
+        );
+        assert_eq!(
+            fixed.exact(1),
+            Some((exact.lat[1].clone(), exact.lon[1].clone()))
+        );
+        assert_eq!(fixed.exact(2), None);
+        assert_eq!(fixed.to_f64(), exact.to_f64());
+
+        let mut world = World::new();
+        let node = world.create_entity(EntityType::Vector);
+        world.fixed_positions.insert(node, fixed.clone());
+        world.fixed_depths.insert(
+            node,
+            FixedDepths {
+                z: smallvec![125, 3],
+                somf: 10,
+                units: 1,
+            },
+        );
+        assert_eq!(world.positions_f64(node), Some(fixed.to_f64()));
+
+        assert_eq!(world.promote_fixed(), 1);
+        assert!(world.fixed_positions.is_empty() && world.fixed_depths.is_empty());
+        assert_eq!(world.exact_positions[&node].lat, exact.lat);
+        assert_eq!(world.exact_depths[&node].to_f64(), [12.5, 0.3]);
+        assert_eq!(world.positions_f64(node), Some(fixed.to_f64()));
+    }
+
+    #[test]
+    fn test_fixed_backend_matches_exact() {
+        // COMF 10^7, SOMF 10
+        let mut dspm = vec![20];
+        dspm.extend(1u32.to_le_bytes());
+        dspm.extend([2, 3, 23]);
+        dspm.extend(22000u32.to_le_bytes());
+        dspm.extend([1, 1, 1, 1]);
+        dspm.extend(10_000_000u32.to_le_bytes());
+        dspm.extend(10u32.to_le_bytes());
+        dspm.extend([0x1F, 0x1E]);
+        let records = vec![
+            Record::from_fields([("DSPM", dspm)]),
+            Record::from_fields([
+                ("VRID", vrid(130, 1)),
+                (
+                    "SG2D",
+                    coordinates(&[&[436_000_001, -703_000_000], &[437_000_000, -701_000_003]]),
+                ),
+            ]),
+            Record::from_fields([
+                ("VRID", vrid(110, 2)),
+                ("SG3D", coordinates(&[&[436_500_000, -702_000_000, 57]])),
+            ]),
+        ];
+        let file = S57File::from_bytes(&write_file(&records).unwrap()).unwrap();
+        let exact = build_world(&file).unwrap();
+        let mut fixed =
+            build_world_with_options(&file, &ParseOptions::new().fixed_point()).unwrap();
+        assert!(fixed.exact_positions.is_empty() && fixed.exact_depths.is_empty());
+        assert_eq!(fixed.fixed_positions.len(), 2);
+
+        let sounding = fixed.name_index[&NameKey { rcnm: 110, rcid: 2 }];
+        assert_eq!(fixed.fixed_depths[&sounding].z.as_slice(), [57]);
+        assert_eq!(fixed.fixed_depths[&sounding].somf, 10);
+
+        let options = ParseOptions::new().fixed_point().approx_positions();
+        let approx = build_world_with_options(&file, &options).unwrap();
+        for (name, entity) in &exact.name_index {
+            let (lat, lon) = exact.exact_positions[entity].to_f64();
+            let positions = &approx.approx_positions[&approx.name_index[name]];
+            assert_eq!((positions.lat.to_vec(), positions.lon.to_vec()), (lat, lon));
+        }
+        let sounding_approx = approx.name_index[&NameKey { rcnm: 110, rcid: 2 }];
+        assert_eq!(
+            approx.approx_depths[&sounding_approx].depth.as_slice(),
+            [5.7]
+        );
+
+        assert_eq!(fixed.promote_fixed(), 2);
+        for (name, entity) in &exact.name_index {
+            let promoted = fixed.name_index[name];
+            assert_eq!(
+                fixed.exact_positions[&promoted].lat,
+                exact.exact_positions[entity].lat
+            );
+            assert_eq!(
+                fixed.exact_positions[&promoted].lon,
+                exact.exact_positions[entity].lon
+            );
+        }
+        let sounding_exact = exact.name_index[&NameKey { rcnm: 110, rcid: 2 }];
+        assert_eq!(
+            fixed.exact_depths[&sounding].depth,
+            exact.exact_depths[&sounding_exact].depth
+        );
+    }
+}
@@ -0,0 +1,179 @@
+//! Feature identity across cells
+//!
+//! A real-world object charted in several cells (overlapping cells of
+//! different usage bands, or an object split at a cell boundary) carries the
+//! same FOID in each. `FoidRegistry` indexes every feature of a set of
+//! Worlds by FOID so those copies can be found together, and picks the copy
+//! to use by compilation scale (DSPM CSCL).
+
+use crate::ecs::{EntityId, EntityType, World};
+use s57_parse::bitstring::FoidKey;
+use std::collections::HashMap;
+
+/// FoidEntry: One copy of a feature, in one of the registered Worlds
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FoidEntry {
+    /// Index of the World in the slice the registry was built from
+    pub cell: usize,
+    /// The feature entity in that World
+    pub entity: EntityId,
+    /// Compilation scale denominator of the cell; None if it has no DSPM
+    pub cscl: Option<u32>,
+}
+
+/// FoidRegistry: FOID → feature index over several Worlds
+#[derive(Debug, Clone, Default)]
+pub struct FoidRegistry {
+    entries: HashMap<FoidKey, Vec<FoidEntry>>,
+}
+
+impl FoidRegistry {
+    /// Index every feature of `worlds`
+    ///
+    /// Entries for a FOID are kept in `worlds` order.
+    pub fn build(worlds: &[&World]) -> Self {
+        let mut entries: HashMap<FoidKey, Vec<FoidEntry>> = HashMap::new();
+        for (cell, world) in worlds.iter().enumerate() {
+            let cscl = world.dataset_params.as_ref().map(|p| p.cscl);
+            for entity in world.entities_of_type(EntityType::Feature) {
+                if let Some(meta) = world.feature_meta.get(&entity) {
+                    entries
+                        .entry(meta.foid)
+                        .or_default()
+                        .push(FoidEntry { cell, entity, cscl });
+                }
+            }
+        }
+        Self { entries }
+    }
+
+    /// Number of distinct FOIDs
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// True if no features were registered
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Every copy of a feature; empty if the FOID is unknown
+    pub fn get(&self, foid: &FoidKey) -> &[FoidEntry] {
+        self.entries.get(foid).map_or(&[], Vec::as_slice)
+    }
+
+    /// FOIDs that appear in more than one cell, with their copies
+    pub fn shared(&self) -> impl Iterator<Item = (&FoidKey, &[FoidEntry])> {
+        self.entries
+            .iter()
+            .filter(|(_, copies)| copies.len() > 1)
+            .map(|(foid, copies)| (foid, copies.as_slice()))
+    }
+
+    /// The most detailed copy of a feature: the one with the smallest
+    /// compilation scale denominator
+    ///
+    /// Cells without a CSCL rank last; ties go to the earlier cell.
+    pub fn best(&self, foid: &FoidKey) -> Option<&FoidEntry> {
+        self.get(foid)
+            .iter()
+            .min_by_key(|e| e.cscl.unwrap_or(u32::MAX))
+    }
+
+    /// The copy to show at a display scale of 1:`scale`
+    ///
+    /// Prefers the coarsest copy compiled at or larger than the display
+    /// scale (CSCL <= `scale`), which is detailed enough without being
+    /// cluttered. If every copy would be overscaled, falls back to `best`.
+    pub fn best_for_scale(&self, foid: &FoidKey, scale: u32) -> Option<&FoidEntry> {
+        let copies = self.get(foid);
+        copies
+            .iter()
+            .filter(|e| e.cscl.is_some_and(|cscl| cscl <= scale))
+            // max_by_key keeps the last maximum; reverse so ties go to the earlier cell
+            .rev()
+            .max_by_key(|e| e.cscl)
+            .or_else(|| self.best(foid))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::{DatasetParams, FeatureMeta};
+    use num_bigint::BigInt;
+
+    fn cell(cscl: Option<u32>, fidns: &[u32]) -> World {
+        let mut world = World::new();
+        world.dataset_params = cscl.map(|cscl| DatasetParams {
+            comf: BigInt::from(10_000_000),
+            somf: BigInt::from(10),
+            duni: 1,
+            huni: 1,
+            puni: 1,
+            hdat: 2,
+            vdat: 0,
+            sdat: 0,
+            cscl,
+        });
+        for fidn in fidns {
+            let feature = world.create_entity(EntityType::Feature);
+            let foid = FoidKey {
+                agen: 550,
+                fidn: *fidn,
+                fids: 1,
+            };
+            world.foid_index.insert(foid, feature);
+            world.feature_meta.insert(
+                feature,
+                FeatureMeta {
+                    foid,
+                    prim: 1,
+                    grup: 2,
+                    objl: 75,
+                    rver: 1,
+                    ruin: 1,
+                },
+            );
+        }
+        world
+    }
+
+    fn foid(fidn: u32) -> FoidKey {
+        FoidKey {
+            agen: 550,
+            fidn,
+            fids: 1,
+        }
+    }
+
+    #[test]
+    fn test_foid_registry() {
+        // Overview, approach and harbour cells; FOID 1 is in all three
+        let overview = cell(Some(350_000), &[1, 2]);
+        let approach = cell(Some(45_000), &[1, 3]);
+        let harbour = cell(Some(12_000), &[1]);
+        let unscaled = cell(None, &[2]);
+        let registry = FoidRegistry::build(&[&overview, &approach, &harbour, &unscaled]);
+
+        assert_eq!(registry.len(), 3);
+        assert_eq!(registry.get(&foid(1)).len(), 3);
+        assert!(registry.get(&foid(9)).is_empty());
+        let mut shared: Vec<u32> = registry.shared().map(|(f, _)| f.fidn).collect();
+        shared.sort();
+        assert_eq!(shared, [1, 2]);
+
+        // Most detailed copy, and the one that fits the display scale
+        assert_eq!(registry.best(&foid(1)).unwrap().cell, 2);
+        assert_eq!(registry.best_for_scale(&foid(1), 50_000).unwrap().cell, 1);
+        assert_eq!(
+            registry.best_for_scale(&foid(1), 1_000_000).unwrap().cell,
+            0
+        );
+        assert_eq!(registry.best_for_scale(&foid(1), 5_000).unwrap().cell, 2);
+
+        // A cell without CSCL loses to one with it
+        assert_eq!(registry.best(&foid(2)).unwrap().cell, 0);
+        assert_eq!(registry.best_for_scale(&foid(3), 10).unwrap().cell, 1);
+    }
+}
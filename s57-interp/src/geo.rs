@@ -0,0 +1,327 @@
+//! Feature geometry as geo-types, and the World as a geozero datasource
+//!
+//! With the `geo` feature, resolved feature geometry converts to
+//! `geo_types::Geometry<f64>`, x being longitude and y latitude in degrees,
+//! for use with the `geo` algorithms. With `geozero`, a World is a
+//! `GeozeroDatasource`: `world.process(&mut writer)` streams its features,
+//! with the same properties as the GeoJSON export, into any geozero sink
+//! (FlatGeobuf, PostGIS, WKT, ...).
+
+use crate::feature::{Feature, FeatureGeometry};
+use crate::geometry::{locate_in_ring, PointLocation};
+use crate::geometry_cache::ResolvedGeometry;
+use crate::topology::{Ring, TopologyResult};
+use geo_types::{
+    Coord, Geometry, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon,
+};
+use num_rational::BigRational;
+use num_traits::ToPrimitive;
+
+impl FeatureGeometry {
+    /// The geometry in geo-types
+    ///
+    /// Points become a Point (a MultiPoint where there are several, as for
+    /// SOUNDG), edges a LineString or MultiLineString, and an area a Polygon,
+    /// or a MultiPolygon with each hole going to the exterior ring that
+    /// contains it. Lines of fewer than 2 points and rings of fewer than 4
+    /// are dropped; None if nothing is left.
+    pub fn to_geo(&self) -> Option<Geometry<f64>> {
+        match self {
+            Self::Points(points) => {
+                let mut points: Vec<Point<f64>> =
+                    points.iter().map(|point| coord(point).into()).collect();
+                match points.len() {
+                    0 => None,
+                    1 => points.pop().map(Geometry::Point),
+                    _ => Some(MultiPoint(points).into()),
+                }
+            }
+            Self::Resolved(resolved) => match resolved.as_ref() {
+                ResolvedGeometry::Lines(edges) => {
+                    let mut lines: Vec<LineString<f64>> = edges
+                        .iter()
+                        .filter(|edge| edge.len() >= 2)
+                        .map(|edge| line(edge))
+                        .collect();
+                    match lines.len() {
+                        0 => None,
+                        1 => lines.pop().map(Geometry::LineString),
+                        _ => Some(MultiLineString(lines).into()),
+                    }
+                }
+                ResolvedGeometry::Area(boundary) => {
+                    let mut polygons: Vec<(&Ring, Vec<LineString<f64>>)> = boundary
+                        .exterior
+                        .iter()
+                        .filter(|ring| ring.len() >= 4)
+                        .map(|ring| (ring, Vec::new()))
+                        .collect();
+                    for hole in boundary.interior.iter().filter(|ring| ring.len() >= 4) {
+                        let owner = polygons
+                            .iter()
+                            .position(|(exterior, _)| {
+                                locate_in_ring(exterior, &hole[0]) != PointLocation::Outside
+                            })
+                            .unwrap_or(0);
+                        if let Some((_, holes)) = polygons.get_mut(owner) {
+                            holes.push(line(hole));
+                        }
+                    }
+                    let mut polygons: Vec<Polygon<f64>> = polygons
+                        .into_iter()
+                        .map(|(exterior, holes)| Polygon::new(line(exterior), holes))
+                        .collect();
+                    match polygons.len() {
+                        0 => None,
+                        1 => polygons.pop().map(Geometry::Polygon),
+                        _ => Some(MultiPolygon(polygons).into()),
+                    }
+                }
+            },
+        }
+    }
+}
+
+impl Feature<'_> {
+    /// Geometry in geo-types, resolved as by `geometry`
+    pub fn geo_geometry(&self) -> TopologyResult<Option<Geometry<f64>>> {
+        Ok(self.geometry()?.and_then(|geometry| geometry.to_geo()))
+    }
+}
+
+/// A (lat, lon) point as a geo-types coordinate
+fn coord((lat, lon): &(BigRational, BigRational)) -> Coord<f64> {
+    Coord {
+        x: lon.to_f64().unwrap_or(0.0),
+        y: lat.to_f64().unwrap_or(0.0),
+    }
+}
+
+/// (lat, lon) points as a LineString
+fn line(points: &[(BigRational, BigRational)]) -> LineString<f64> {
+    points.iter().map(coord).collect()
+}
+
+#[cfg(feature = "geozero")]
+mod datasource {
+    use crate::ecs::World;
+    use geozero::error::Result;
+    use geozero::{ColumnValue, FeatureProcessor, GeozeroDatasource, GeozeroGeometry};
+    use s57_catalogue::AttributeInfo;
+
+    /// Each feature with OBJL (acronym, or code if unknown), FOID
+    /// ("agen:fidn:fids") and its attributes by acronym as properties. The
+    /// dataset is named after DSNM. Features whose geometry doesn't resolve
+    /// are streamed without one.
+    impl GeozeroDatasource for World {
+        fn process<P: FeatureProcessor>(&mut self, processor: &mut P) -> Result<()> {
+            processor.dataset_begin(self.dataset_id.as_ref().map(|id| id.dsnm.as_str()))?;
+            for (index, feature) in self.features().enumerate() {
+                let index = index as u64;
+                processor.feature_begin(index)?;
+
+                processor.properties_begin()?;
+                let meta = feature.meta();
+                let class = feature.object_class().map(|class| class.to_string());
+                let objl = match &class {
+                    Some(acronym) => ColumnValue::String(acronym),
+                    None => ColumnValue::UShort(meta.objl),
+                };
+                processor.property(0, "OBJL", &objl)?;
+                let foid = format!("{}:{}:{}", meta.foid.agen, meta.foid.fidn, meta.foid.fids);
+                processor.property(1, "FOID", &ColumnValue::String(&foid))?;
+                let attributes = self.feature_attributes.get(&feature.id());
+                for (i, (attl, value)) in attributes
+                    .into_iter()
+                    .flat_map(|attrs| attrs.attf.iter().chain(&attrs.natf))
+                    .enumerate()
+                {
+                    let name = AttributeInfo::from_code(*attl)
+                        .map(|info| info.acronym.to_string())
+                        .unwrap_or_else(|| attl.to_string());
+                    processor.property(i + 2, &name, &ColumnValue::String(value))?;
+                }
+                processor.properties_end()?;
+
+                match feature.geo_geometry() {
+                    Ok(Some(geometry)) => {
+                        processor.geometry_begin()?;
+                        geometry.process_geom(processor)?;
+                        processor.geometry_end()?;
+                    }
+                    Ok(None) => {}
+                    Err(e) => crate::diag::debug!("Skipping geometry of {:?}: {}", feature.id(), e),
+                }
+                processor.feature_end(index)?;
+            }
+            processor.dataset_end()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::topology::AreaBoundary;
+    use num_bigint::BigInt;
+    use std::sync::Arc;
+
+    fn point(lat: i64, lon: i64) -> (BigRational, BigRational) {
+        (BigInt::from(lat).into(), BigInt::from(lon).into())
+    }
+
+    /// Closed square ring with its lower left corner at (lat, lon)
+    fn square(lat: i64, lon: i64, size: i64) -> Ring {
+        vec![
+            point(lat, lon),
+            point(lat, lon + size),
+            point(lat + size, lon + size),
+            point(lat + size, lon),
+            point(lat, lon),
+        ]
+    }
+
+    #[test]
+    fn test_to_geo() {
+        let sounding = FeatureGeometry::Points(vec![point(50, -1)]);
+        assert_eq!(
+            sounding.to_geo(),
+            Some(Geometry::Point(Point::new(-1.0, 50.0)))
+        );
+        let soundings = FeatureGeometry::Points(vec![point(50, -1), point(51, -2)]);
+        assert!(matches!(soundings.to_geo(), Some(Geometry::MultiPoint(p)) if p.0.len() == 2));
+
+        // The single-point edge is dropped
+        let lines =
+            ResolvedGeometry::Lines(vec![vec![point(0, 0), point(1, 1)], vec![point(2, 2)]]);
+        let Some(Geometry::LineString(line)) = FeatureGeometry::Resolved(Arc::new(lines)).to_geo()
+        else {
+            panic!("expected a LineString");
+        };
+        assert_eq!(line.0, [Coord { x: 0.0, y: 0.0 }, Coord { x: 1.0, y: 1.0 }]);
+
+        // Two islands, the hole going to the one that holds it
+        let area = ResolvedGeometry::Area(AreaBoundary {
+            exterior: vec![square(0, 0, 10), square(20, 20, 10)],
+            interior: vec![square(22, 22, 2)],
+        });
+        let Some(Geometry::MultiPolygon(polygons)) =
+            FeatureGeometry::Resolved(Arc::new(area)).to_geo()
+        else {
+            panic!("expected a MultiPolygon");
+        };
+        assert!(polygons.0[0].interiors().is_empty());
+        assert_eq!(polygons.0[1].interiors().len(), 1);
+        assert_eq!(
+            polygons.0[1].interiors()[0].0[0],
+            Coord { x: 22.0, y: 22.0 }
+        );
+
+        let empty = ResolvedGeometry::Area(AreaBoundary {
+            exterior: Vec::new(),
+            interior: Vec::new(),
+        });
+        assert_eq!(FeatureGeometry::Resolved(Arc::new(empty)).to_geo(), None);
+    }
+
+    #[cfg(feature = "geozero")]
+    #[test]
+    fn test_geozero_datasource() {
+        use crate::ecs::{
+            EntityType, ExactPositions, FeatureAttributes, FeatureMeta, FeaturePointers,
+            SpatialRef, World,
+        };
+        use geozero::error::Result;
+        use geozero::{
+            ColumnValue, FeatureProcessor, GeomProcessor, GeozeroDatasource, PropertyProcessor,
+        };
+        use s57_parse::bitstring::FoidKey;
+        use smallvec::smallvec;
+
+        /// Properties as text and the coordinates of each point
+        #[derive(Default)]
+        struct Collect {
+            properties: Vec<(String, String)>,
+            points: Vec<(f64, f64)>,
+            features: usize,
+        }
+        impl GeomProcessor for Collect {
+            fn xy(&mut self, x: f64, y: f64, _idx: usize) -> Result<()> {
+                self.points.push((x, y));
+                Ok(())
+            }
+        }
+        impl PropertyProcessor for Collect {
+            fn property(&mut self, _idx: usize, name: &str, value: &ColumnValue) -> Result<bool> {
+                self.properties.push((name.to_string(), value.to_string()));
+                Ok(false)
+            }
+        }
+        impl FeatureProcessor for Collect {
+            fn feature_end(&mut self, _idx: u64) -> Result<()> {
+                self.features += 1;
+                Ok(())
+            }
+        }
+
+        // A LIGHTS point feature at (50, -1.5)
+        let mut world = World::new();
+        let node = world.create_entity(EntityType::Vector);
+        world.exact_positions.insert(
+            node,
+            ExactPositions {
+                lat: smallvec![BigInt::from(50).into()],
+                lon: smallvec![BigRational::new(BigInt::from(-3), BigInt::from(2))],
+            },
+        );
+        let light = world.create_entity(EntityType::Feature);
+        world.feature_meta.insert(
+            light,
+            FeatureMeta {
+                foid: FoidKey {
+                    agen: 550,
+                    fidn: 7,
+                    fids: 1,
+                },
+                prim: 1,
+                grup: 2,
+                objl: 75,
+                rver: 1,
+                ruin: 1,
+            },
+        );
+        world.feature_attributes.insert(
+            light,
+            FeatureAttributes {
+                attf: vec![(75, "1".to_string())],
+                natf: Vec::new(),
+            },
+        );
+        world.feature_pointers.insert(
+            light,
+            FeaturePointers {
+                related_features: Vec::new(),
+                spatial_refs: vec![SpatialRef {
+                    entity: node,
+                    ornt: 255,
+                    usag: 255,
+                    mask: 255,
+                }],
+            },
+        );
+
+        let mut sink = Collect::default();
+        world.process(&mut sink).unwrap();
+        assert_eq!(sink.features, 1);
+        assert_eq!(sink.points, [(-1.5, 50.0)]);
+        let text = |name: &str, value: &str| (name.to_string(), value.to_string());
+        assert_eq!(
+            sink.properties,
+            [
+                text("OBJL", "LIGHTS"),
+                text("FOID", "550:7:1"),
+                text("COLOUR", "1")
+            ]
+        );
+    }
+}
@@ -0,0 +1,907 @@
+//! GeoJSON export of a World
+//!
+//! Resolves each feature's geometry through the topology walker and emits a
+//! FeatureCollection. Two flavours are supported:
+//! - RFC 7946 GeoJSON (default): WGS84 lon/lat, no CRS member
+//! - OGC JSON-FG: adds `conformsTo`, an explicit `coordRefSys`, and per
+//!   feature `featureType`, `time` (from DATSTA/DATEND) and `place`, which
+//!   holds the geometry in `GeoJsonOptions::projection` when that isn't CRS84
+//!
+//! Each feature carries a `name` property with its OBJNAM or NOBJNM, picked
+//! by `GeoJsonOptions::names`, alongside the raw attributes.
+//!
+//! Coordinates are [lon, lat], with sounding depth as a third value for
+//! SG3D points. By default they are written at full f64 precision;
+//! `CoordinatePrecision` rounds them to a fixed number of decimals or to the
+//! dataset's own COMF/SOMF resolution so output stays short and diffable.
+//! Mariner overlay objects are exported after the chart features, marked
+//! with an `overlay` property. Depth bands reconstructed from contours
+//! (`GeoJsonOptions::derived_depth_areas`) sit between the two, marked with
+//! a `derived` property.
+//!
+//! A differential export (`GeoJsonOptions::changes`) writes only the
+//! features a `WorldDiff` reports as added or modified, each with a `change`
+//! property, followed by a geometry-less stub for every removed feature, so
+//! a downstream store can be brought up to date without a full reload.
+
+use crate::depth_bands::DerivedDepthArea;
+use crate::diff::{ChangeKind, FeatureChange, WorldDiff};
+use crate::ecs::{EntityId, EntityType, NamePreference, World};
+use crate::geometry::{locate_in_ring, PointLocation};
+use crate::geometry_cache::ResolvedGeometry;
+use crate::overlay::{MarinerObject, MarinerObjectKind};
+use crate::projection::Projection;
+use crate::topology::{AreaBoundary, Ring, TraversalContext};
+use crate::transform::CoordinateTransform;
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{ToPrimitive, Zero};
+use s57_parse::bitstring::FoidKey;
+use serde_json::{json, Map, Value};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// JSON-FG core conformance class
+const JSON_FG_CORE: &str = "http://www.opengis.net/spec/json-fg-1/0.2/conf/core";
+
+/// CRS84: WGS84 with lon/lat axis order
+const CRS84: &str = "http://www.opengis.net/def/crs/OGC/1.3/CRS84";
+
+/// ATTL codes of the temporal attributes mapped to JSON-FG `time`
+const ATTL_DATEND: u16 = 85;
+const ATTL_DATSTA: u16 = 86;
+
+/// GeoJsonFlavor: Which standard the output follows
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GeoJsonFlavor {
+    /// RFC 7946 GeoJSON
+    #[default]
+    Rfc7946,
+    /// OGC Features and Geometries JSON (JSON-FG)
+    JsonFg,
+}
+
+/// CoordinatePrecision: How output coordinates and depths are rounded
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CoordinatePrecision {
+    /// Nearest f64 to the exact value
+    #[default]
+    Full,
+    /// Fixed number of decimal places for coordinates and depths
+    Decimals(u32),
+    /// Decimal places implied by the dataset's COMF (coordinates) and SOMF
+    /// (depths), e.g. COMF=10,000,000 gives 7 decimals. Falls back to Full
+    /// if the World has no DSPM.
+    Dataset,
+}
+
+/// GeoJsonOptions: Configuration for `to_geojson`
+#[derive(Debug, Clone, Default)]
+pub struct GeoJsonOptions {
+    /// Output flavour
+    pub flavor: GeoJsonFlavor,
+    /// Coordinate rounding
+    pub precision: CoordinatePrecision,
+    /// Language of the `name` property (OBJNAM vs NOBJNM)
+    pub names: NamePreference,
+    /// Object classes to export (None = all); overlay objects are always kept
+    pub classes: Option<HashSet<u16>>,
+    /// Projection for JSON-FG `place`; `geometry` always stays in CRS84, and
+    /// RFC 7946 output ignores this
+    pub projection: Projection,
+    /// Applied to every position before it is written (None = as charted);
+    /// `coordRefSys` is not adjusted to match
+    pub transform: Option<Arc<dyn CoordinateTransform>>,
+    /// Also export depth bands reconstructed from contours where the chart
+    /// lacks DEPARE coverage, marked with a `derived` property
+    pub derived_depth_areas: bool,
+    /// Export only what this diff against an older World reports (None =
+    /// every feature); derived depth areas and overlay objects are left out
+    pub changes: Option<WorldDiff>,
+    /// Add a `source` property naming the record (and update) each feature
+    /// was built from
+    pub provenance: bool,
+}
+
+impl GeoJsonOptions {
+    /// Create options for plain RFC 7946 output
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the output flavour
+    pub fn flavor(mut self, flavor: GeoJsonFlavor) -> Self {
+        self.flavor = flavor;
+        self
+    }
+
+    /// Set the coordinate rounding
+    pub fn precision(mut self, precision: CoordinatePrecision) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Set which object name fills the `name` property
+    pub fn names(mut self, names: NamePreference) -> Self {
+        self.names = names;
+        self
+    }
+
+    /// Also write each feature's geometry projected, as JSON-FG `place`
+    pub fn projection(mut self, projection: Projection) -> Self {
+        self.projection = projection;
+        self
+    }
+
+    /// Pass every position through a coordinate transform
+    pub fn transform(mut self, transform: impl CoordinateTransform + 'static) -> Self {
+        self.transform = Some(Arc::new(transform));
+        self
+    }
+
+    /// Include depth areas reconstructed from contours
+    pub fn derived_depth_areas(mut self, derived: bool) -> Self {
+        self.derived_depth_areas = derived;
+        self
+    }
+
+    /// Export only features of these object classes
+    pub fn classes(mut self, classes: HashSet<u16>) -> Self {
+        self.classes = Some(classes);
+        self
+    }
+
+    /// Export only the features added, modified or removed in `diff`
+    pub fn changes(mut self, diff: WorldDiff) -> Self {
+        self.changes = Some(diff);
+        self
+    }
+
+    /// Add the `source` property to each feature
+    pub fn provenance(mut self, provenance: bool) -> Self {
+        self.provenance = provenance;
+        self
+    }
+}
+
+/// Decimal places applied to coordinates and depths (None = full precision)
+struct Rounding {
+    coordinate: Option<u32>,
+    depth: Option<u32>,
+}
+
+impl Rounding {
+    fn new(world: &World, precision: CoordinatePrecision) -> Self {
+        match precision {
+            CoordinatePrecision::Full => Self {
+                coordinate: None,
+                depth: None,
+            },
+            CoordinatePrecision::Decimals(n) => Self {
+                coordinate: Some(n),
+                depth: Some(n),
+            },
+            CoordinatePrecision::Dataset => {
+                let params = world.dataset_params.as_ref();
+                Self {
+                    coordinate: params.map(|p| decimals_for(&p.comf)),
+                    depth: params.map(|p| decimals_for(&p.somf)),
+                }
+            }
+        }
+    }
+
+    fn coordinate(&self, value: &BigRational) -> Option<f64> {
+        round(value, self.coordinate)
+    }
+
+    fn depth(&self, value: &BigRational) -> Option<f64> {
+        round(value, self.depth)
+    }
+}
+
+/// Smallest number of decimals that resolves 1/factor (e.g. 10^7 -> 7)
+fn decimals_for(factor: &BigInt) -> u32 {
+    let mut decimals = 0;
+    let mut scale = BigInt::from(1);
+    while scale < *factor {
+        scale *= 10;
+        decimals += 1;
+    }
+    decimals
+}
+
+/// Round exactly to `decimals` places (half away from zero), then convert
+///
+/// Rounding the rational first means the f64 written is the one closest to
+/// a short decimal, which serializes without trailing noise digits.
+fn round(value: &BigRational, decimals: Option<u32>) -> Option<f64> {
+    let Some(decimals) = decimals else {
+        return value.to_f64();
+    };
+    let scale = BigRational::from_integer(num_traits::pow(BigInt::from(10), decimals as usize));
+    let scaled = (value * &scale).round();
+    if scaled.is_zero() {
+        return Some(0.0);
+    }
+    (scaled / scale).to_f64()
+}
+
+/// Export every feature of a World as a FeatureCollection
+///
+/// Features whose geometry can't be resolved are exported with a null
+/// geometry rather than dropped, so attribute-only consumers still see them.
+pub fn to_geojson(world: &World, options: &GeoJsonOptions) -> Value {
+    let mut ctx = TraversalContext::new(world);
+    ctx.transform = options.transform.clone();
+    let rounding = Rounding::new(world, options.precision);
+    let changed: Option<HashMap<FoidKey, ChangeKind>> = options
+        .changes
+        .as_ref()
+        .map(|diff| diff.changes.iter().map(|c| (c.foid, c.kind)).collect());
+    let mut features: Vec<Value> = world
+        .entities_of_type(EntityType::Feature)
+        .into_iter()
+        .filter_map(|entity| {
+            let change = match &changed {
+                Some(changed) => Some(*changed.get(&world.feature_meta.get(&entity)?.foid)?),
+                None => None,
+            };
+            let mut feature = feature_to_json(world, &ctx, entity, options, &rounding)?;
+            if let Some(change) = change {
+                feature["properties"]["change"] = json!(change_name(change));
+            }
+            Some(feature)
+        })
+        .collect();
+
+    if let Some(diff) = &options.changes {
+        features.extend(
+            diff.changes
+                .iter()
+                .filter(|change| change.kind == ChangeKind::Removed)
+                .filter(|change| {
+                    options
+                        .classes
+                        .as_ref()
+                        .is_none_or(|classes| classes.contains(&change.objl))
+                })
+                .map(|change| removed_to_json(change, options)),
+        );
+    } else {
+        if options.derived_depth_areas {
+            features.extend(
+                ctx.derived_depth_areas()
+                    .iter()
+                    .enumerate()
+                    .map(|(i, area)| derived_depth_area_to_json(i, area, options, &rounding)),
+            );
+        }
+        features.extend(
+            world
+                .overlay
+                .objects
+                .iter()
+                .map(|object| overlay_to_json(&ctx, object, options, &rounding)),
+        );
+    }
+
+    let mut collection = Map::new();
+    collection.insert("type".into(), json!("FeatureCollection"));
+    if options.flavor == GeoJsonFlavor::JsonFg {
+        collection.insert("conformsTo".into(), json!([JSON_FG_CORE]));
+        let crs = if options.projection.is_geographic() {
+            CRS84.to_string()
+        } else {
+            options.projection.crs()
+        };
+        collection.insert("coordRefSys".into(), json!(crs));
+    }
+    collection.insert("features".into(), Value::Array(features));
+    Value::Object(collection)
+}
+
+/// Convert one chart feature
+fn feature_to_json(
+    world: &World,
+    ctx: &TraversalContext,
+    entity: EntityId,
+    options: &GeoJsonOptions,
+    rounding: &Rounding,
+) -> Option<Value> {
+    let meta = world.feature_meta.get(&entity)?;
+    if let Some(classes) = &options.classes {
+        if !classes.contains(&meta.objl) {
+            return None;
+        }
+    }
+    let acronym = s57_catalogue::decode_object(meta.objl).map(|c| c.to_string());
+
+    let mut properties = Map::new();
+    properties.insert(
+        "OBJL".into(),
+        acronym
+            .clone()
+            .map(Value::String)
+            .unwrap_or_else(|| json!(meta.objl)),
+    );
+    properties.insert(
+        "FOID".into(),
+        json!(format!(
+            "{}:{}:{}",
+            meta.foid.agen, meta.foid.fidn, meta.foid.fids
+        )),
+    );
+    if let Some(attrs) = world.feature_attributes.get(&entity) {
+        if let Some(name) = attrs.name(options.names) {
+            properties.insert("name".into(), json!(name));
+        }
+        for (attl, value) in attrs.attf.iter().chain(attrs.natf.iter()) {
+            properties.insert(attribute_key(*attl), json!(value));
+        }
+    }
+    if options.provenance {
+        if let Some(source) = world.record_sources.get(&entity) {
+            properties.insert(
+                "source".into(),
+                json!({
+                    "record": source.record,
+                    "update": source.update,
+                    "offset": source.offset,
+                }),
+            );
+        }
+    }
+
+    let geometry = match meta.prim {
+        1 => point_geometry(ctx, entity, rounding),
+        2 => line_geometry(ctx, entity, rounding),
+        3 => area_geometry(ctx, entity, rounding),
+        _ => None,
+    };
+
+    let mut feature = Map::new();
+    feature.insert("type".into(), json!("Feature"));
+    feature.insert("id".into(), properties["FOID"].clone());
+    if options.flavor == GeoJsonFlavor::JsonFg {
+        feature.insert(
+            "featureType".into(),
+            acronym.map(Value::String).unwrap_or(Value::Null),
+        );
+        feature.insert("time".into(), feature_time(world, entity));
+        feature.insert("place".into(), place(geometry.as_ref(), options.projection));
+    }
+    feature.insert("geometry".into(), geometry.unwrap_or(Value::Null));
+    feature.insert("properties".into(), Value::Object(properties));
+    Some(Value::Object(feature))
+}
+
+/// `change` property value of a differential export
+fn change_name(kind: ChangeKind) -> &'static str {
+    match kind {
+        ChangeKind::Added => "added",
+        ChangeKind::Removed => "removed",
+        ChangeKind::Modified => "modified",
+    }
+}
+
+/// Stub for a feature the newer World no longer has: its FOID and class,
+/// no geometry and no attributes
+fn removed_to_json(change: &FeatureChange, options: &GeoJsonOptions) -> Value {
+    let acronym = s57_catalogue::decode_object(change.objl).map(|c| c.to_string());
+    let foid = format!(
+        "{}:{}:{}",
+        change.foid.agen, change.foid.fidn, change.foid.fids
+    );
+    let mut feature = Map::new();
+    feature.insert("type".into(), json!("Feature"));
+    feature.insert("id".into(), json!(foid));
+    if options.flavor == GeoJsonFlavor::JsonFg {
+        feature.insert(
+            "featureType".into(),
+            acronym.clone().map(Value::String).unwrap_or(Value::Null),
+        );
+        feature.insert("time".into(), Value::Null);
+        feature.insert("place".into(), Value::Null);
+    }
+    feature.insert("geometry".into(), Value::Null);
+    feature.insert(
+        "properties".into(),
+        json!({
+            "OBJL": acronym.map(Value::String).unwrap_or_else(|| json!(change.objl)),
+            "FOID": foid,
+            "change": change_name(ChangeKind::Removed),
+        }),
+    );
+    Value::Object(feature)
+}
+
+/// JSON-FG `place`: the geometry projected, or null when the projection is
+/// CRS84 itself and `geometry` already says it all
+///
+/// Projected coordinates are rounded to the centimetre; depths are kept.
+fn place(geometry: Option<&Value>, projection: Projection) -> Value {
+    let Some(geometry) = geometry.filter(|_| !projection.is_geographic()) else {
+        return Value::Null;
+    };
+    let mut place = geometry.clone();
+    if let Some(coordinates) = place.get_mut("coordinates") {
+        *coordinates = project_coordinates(coordinates, projection);
+    }
+    place
+}
+
+/// Project every [lon, lat, ...] position in a coordinates array
+fn project_coordinates(value: &Value, projection: Projection) -> Value {
+    let Value::Array(items) = value else {
+        return value.clone();
+    };
+    match (
+        items.first().and_then(Value::as_f64),
+        items.get(1).and_then(Value::as_f64),
+    ) {
+        (Some(lon), Some(lat)) => {
+            let (x, y) = projection.project(lat, lon);
+            let centimetres = |v: f64| (v * 100.0).round() / 100.0;
+            let mut position = vec![json!(centimetres(x)), json!(centimetres(y))];
+            position.extend(items[2..].iter().cloned());
+            Value::Array(position)
+        }
+        _ => Value::Array(
+            items
+                .iter()
+                .map(|item| project_coordinates(item, projection))
+                .collect(),
+        ),
+    }
+}
+
+/// Property name for an attribute: its acronym, or the numeric code if unknown
+fn attribute_key(attl: u16) -> String {
+    s57_catalogue::AttributeInfo::from_code(attl)
+        .map(|info| info.acronym.to_string())
+        .unwrap_or_else(|| attl.to_string())
+}
+
+/// JSON-FG `time` from DATSTA/DATEND (CCYYMMDD), or null if neither is set
+fn feature_time(world: &World, entity: EntityId) -> Value {
+    let attr = |code: u16| {
+        world
+            .feature_attributes
+            .get(&entity)?
+            .attf
+            .iter()
+            .find(|(attl, _)| *attl == code)
+            .and_then(|(_, value)| iso_date(value))
+    };
+
+    match (attr(ATTL_DATSTA), attr(ATTL_DATEND)) {
+        (None, None) => Value::Null,
+        (Some(start), Some(end)) if start == end => json!({ "date": start }),
+        (start, end) => json!({
+            "interval": [
+                start.unwrap_or_else(|| "..".to_string()),
+                end.unwrap_or_else(|| "..".to_string()),
+            ]
+        }),
+    }
+}
+
+/// Convert an S-57 CCYYMMDD date to ISO 8601 (YYYY-MM-DD)
+fn iso_date(value: &str) -> Option<String> {
+    let value = value.trim();
+    if value.len() != 8 || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    Some(format!("{}-{}-{}", &value[..4], &value[4..6], &value[6..]))
+}
+
+/// [lon, lat] position
+fn position(lat: &BigRational, lon: &BigRational, rounding: &Rounding) -> Option<Value> {
+    Some(json!([
+        rounding.coordinate(lon)?,
+        rounding.coordinate(lat)?
+    ]))
+}
+
+/// Point or MultiPoint from the nodes a point feature references
+fn point_geometry(ctx: &TraversalContext, entity: EntityId, rounding: &Rounding) -> Option<Value> {
+    let world = ctx.world;
+    let pointers = world.feature_pointers.get(&entity)?;
+    let mut points = Vec::new();
+    for sref in &pointers.spatial_refs {
+        let Some(positions) = world.exact_positions.get(&sref.entity) else {
+            continue;
+        };
+        let depths = world.exact_depths.get(&sref.entity);
+        for (i, (lat, lon)) in positions.lat.iter().zip(positions.lon.iter()).enumerate() {
+            let (lat, lon) = ctx.transform_point(lat, lon);
+            let Some(mut point) = position(&lat, &lon, rounding) else {
+                continue;
+            };
+            if let Some(depth) = depths.and_then(|d| rounding.depth(d.depth.get(i)?)) {
+                point.as_array_mut()?.push(json!(depth));
+            }
+            points.push(point);
+        }
+    }
+
+    match points.len() {
+        0 => None,
+        1 => Some(json!({ "type": "Point", "coordinates": points.pop() })),
+        _ => Some(json!({ "type": "MultiPoint", "coordinates": points })),
+    }
+}
+
+/// LineString or MultiLineString from the edges a line feature references
+fn line_geometry(ctx: &TraversalContext, entity: EntityId, rounding: &Rounding) -> Option<Value> {
+    let geometry = ctx.feature_geometry(entity).ok()??;
+    let ResolvedGeometry::Lines(edges) = geometry.as_ref() else {
+        return None;
+    };
+    let mut lines: Vec<Value> = edges
+        .iter()
+        .map(|coords| {
+            coords
+                .iter()
+                .filter_map(|(lat, lon)| position(lat, lon, rounding))
+                .collect::<Vec<_>>()
+        })
+        .filter(|line| line.len() >= 2)
+        .map(Value::Array)
+        .collect();
+
+    match lines.len() {
+        0 => None,
+        1 => Some(json!({ "type": "LineString", "coordinates": lines.pop() })),
+        _ => Some(json!({ "type": "MultiLineString", "coordinates": lines })),
+    }
+}
+
+/// Polygon (exterior ring first, then holes) for an area feature
+///
+/// An area with several exterior rings becomes a MultiPolygon, each hole
+/// going to the exterior ring that contains it.
+fn area_geometry(ctx: &TraversalContext, entity: EntityId, rounding: &Rounding) -> Option<Value> {
+    let geometry = match ctx.feature_geometry(entity) {
+        Ok(geometry) => geometry?,
+        Err(e) => {
+            crate::diag::debug!("Skipping area {:?}: {}", entity, e);
+            return None;
+        }
+    };
+    let ResolvedGeometry::Area(boundary) = geometry.as_ref() else {
+        return None;
+    };
+    boundary_geometry(boundary, rounding)
+}
+
+/// Polygon or MultiPolygon for an area's rings
+fn boundary_geometry(boundary: &AreaBoundary, rounding: &Rounding) -> Option<Value> {
+    let mut polygons: Vec<(&Ring, Vec<Value>)> = boundary
+        .exterior
+        .iter()
+        .filter_map(|ring| Some((ring, vec![ring_to_json(ring, rounding)?])))
+        .collect();
+    for hole in &boundary.interior {
+        let Some(json) = ring_to_json(hole, rounding) else {
+            continue;
+        };
+        let owner = polygons
+            .iter()
+            .position(|(exterior, _)| locate_in_ring(exterior, &hole[0]) != PointLocation::Outside)
+            .unwrap_or(0);
+        if let Some((_, rings)) = polygons.get_mut(owner) {
+            rings.push(json);
+        }
+    }
+
+    match polygons.len() {
+        0 => None,
+        1 => Some(json!({ "type": "Polygon", "coordinates": polygons.pop()?.1 })),
+        _ => {
+            let coordinates: Vec<Vec<Value>> = polygons.into_iter().map(|(_, r)| r).collect();
+            Some(json!({ "type": "MultiPolygon", "coordinates": coordinates }))
+        }
+    }
+}
+
+/// GeoJSON positions of a ring, or None if it has fewer than 4 points
+fn ring_to_json(ring: &Ring, rounding: &Rounding) -> Option<Value> {
+    let positions: Vec<Value> = ring
+        .iter()
+        .filter_map(|(lat, lon)| position(lat, lon, rounding))
+        .collect();
+    (positions.len() >= 4).then_some(Value::Array(positions))
+}
+
+/// Convert a depth band reconstructed from contours
+///
+/// Exported as a DEPARE, but with a `derived` property and no FOID: it is
+/// not a feature of the chart.
+fn derived_depth_area_to_json(
+    index: usize,
+    derived: &DerivedDepthArea,
+    options: &GeoJsonOptions,
+    rounding: &Rounding,
+) -> Value {
+    let mut properties = Map::new();
+    properties.insert("OBJL".into(), json!("DEPARE"));
+    properties.insert("derived".into(), json!(true));
+    for (key, value) in [("DRVAL1", &derived.drval1), ("DRVAL2", &derived.drval2)] {
+        if let Some(depth) = value.as_ref().and_then(|v| rounding.depth(v)) {
+            properties.insert(key.into(), json!(depth));
+        }
+    }
+    let geometry = boundary_geometry(&derived.area, rounding);
+
+    let mut feature = Map::new();
+    feature.insert("type".into(), json!("Feature"));
+    feature.insert("id".into(), json!(format!("derived:{}", index)));
+    if options.flavor == GeoJsonFlavor::JsonFg {
+        feature.insert("featureType".into(), json!("DEPARE"));
+        feature.insert("time".into(), Value::Null);
+        feature.insert("place".into(), place(geometry.as_ref(), options.projection));
+    }
+    feature.insert("geometry".into(), geometry.unwrap_or(Value::Null));
+    feature.insert("properties".into(), Value::Object(properties));
+    Value::Object(feature)
+}
+
+/// Convert a mariner overlay object
+fn overlay_to_json(
+    ctx: &TraversalContext,
+    object: &MarinerObject,
+    options: &GeoJsonOptions,
+    rounding: &Rounding,
+) -> Value {
+    let coords: Vec<Value> = object
+        .positions
+        .lat
+        .iter()
+        .zip(object.positions.lon.iter())
+        .filter_map(|(lat, lon)| {
+            let (lat, lon) = ctx.transform_point(lat, lon);
+            position(&lat, &lon, rounding)
+        })
+        .collect();
+    let (kind, geometry) = match object.kind {
+        MarinerObjectKind::Route => (
+            "route",
+            json!({ "type": "LineString", "coordinates": coords }),
+        ),
+        MarinerObjectKind::Waypoint => (
+            "waypoint",
+            json!({ "type": "Point", "coordinates": coords.first() }),
+        ),
+        MarinerObjectKind::Note => (
+            "note",
+            json!({ "type": "Point", "coordinates": coords.first() }),
+        ),
+    };
+
+    let mut feature = Map::new();
+    feature.insert("type".into(), json!("Feature"));
+    if options.flavor == GeoJsonFlavor::JsonFg {
+        feature.insert("featureType".into(), json!("MarinerObject"));
+        feature.insert("time".into(), Value::Null);
+        feature.insert("place".into(), place(Some(&geometry), options.projection));
+    }
+    feature.insert("geometry".into(), geometry);
+    feature.insert(
+        "properties".into(),
+        json!({ "overlay": kind, "name": object.name, "text": object.text }),
+    );
+    Value::Object(feature)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::{
+        ExactPositions, FeatureAttributes, FeatureMeta, FeaturePointers, RecordSource, SpatialRef,
+    };
+    use num_bigint::BigInt;
+    use s57_parse::bitstring::FoidKey;
+    use smallvec::smallvec;
+
+    /// World with one LIGHTS point feature carrying DATSTA
+    fn world_with_light() -> World {
+        let mut world = World::new();
+        let node = world.create_entity(EntityType::Vector);
+        world.exact_positions.insert(
+            node,
+            ExactPositions {
+                lat: smallvec![BigRational::new(BigInt::from(1), BigInt::from(2))],
+                lon: smallvec![BigRational::new(BigInt::from(-3), BigInt::from(4))],
+            },
+        );
+        let feature = world.create_entity(EntityType::Feature);
+        let foid = FoidKey {
+            agen: 550,
+            fidn: 7,
+            fids: 1,
+        };
+        world.foid_index.insert(foid, feature);
+        world.feature_meta.insert(
+            feature,
+            FeatureMeta {
+                foid,
+                prim: 1,
+                grup: 2,
+                objl: 75,
+                rver: 1,
+                ruin: 1,
+            },
+        );
+        world.feature_attributes.insert(
+            feature,
+            FeatureAttributes {
+                attf: vec![(ATTL_DATSTA, "20240115".to_string())],
+                natf: Vec::new(),
+            },
+        );
+        world.feature_pointers.insert(
+            feature,
+            FeaturePointers {
+                related_features: Vec::new(),
+                spatial_refs: vec![SpatialRef {
+                    entity: node,
+                    ornt: 255,
+                    usag: 255,
+                    mask: 255,
+                }],
+            },
+        );
+        world
+    }
+
+    #[test]
+    fn test_geojson_rfc7946() {
+        let out = to_geojson(&world_with_light(), &GeoJsonOptions::new());
+        assert_eq!(out["type"], "FeatureCollection");
+        assert!(out.get("coordRefSys").is_none());
+
+        let feature = &out["features"][0];
+        assert_eq!(feature["id"], "550:7:1");
+        assert_eq!(feature["geometry"]["type"], "Point");
+        assert_eq!(feature["geometry"]["coordinates"], json!([-0.75, 0.5]));
+        assert_eq!(feature["properties"]["OBJL"], "LIGHTS");
+        assert_eq!(feature["properties"]["DATSTA"], "20240115");
+        assert!(feature.get("time").is_none());
+        assert!(feature["properties"].get("source").is_none());
+
+        let mut world = world_with_light();
+        let light = world.foid_index.values().copied().next().unwrap();
+        world.record_sources.insert(
+            light,
+            RecordSource {
+                record: 4,
+                update: Some(2),
+                offset: None,
+            },
+        );
+        let out = to_geojson(&world, &GeoJsonOptions::new().provenance(true));
+        assert_eq!(
+            out["features"][0]["properties"]["source"],
+            json!({ "record": 4, "update": 2, "offset": null })
+        );
+
+        // LIGHTS is OBJL 75
+        let only_depths = GeoJsonOptions::new().classes(HashSet::from([42]));
+        let out = to_geojson(&world_with_light(), &only_depths);
+        assert_eq!(out["features"], json!([]));
+        let only_lights = GeoJsonOptions::new().classes(HashSet::from([75]));
+        let out = to_geojson(&world_with_light(), &only_lights);
+        assert_eq!(out["features"].as_array().map(Vec::len), Some(1));
+    }
+
+    #[test]
+    fn test_geojson_json_fg() {
+        let options = GeoJsonOptions::new().flavor(GeoJsonFlavor::JsonFg);
+        let out = to_geojson(&world_with_light(), &options);
+        assert_eq!(out["conformsTo"], json!([JSON_FG_CORE]));
+        assert_eq!(out["coordRefSys"], CRS84);
+
+        let feature = &out["features"][0];
+        assert_eq!(feature["featureType"], "LIGHTS");
+        assert_eq!(feature["time"], json!({ "interval": ["2024-01-15", ".."] }));
+        assert_eq!(feature["place"], Value::Null);
+
+        let options = options.projection(Projection::WebMercator);
+        let out = to_geojson(&world_with_light(), &options);
+        assert_eq!(
+            out["coordRefSys"],
+            "http://www.opengis.net/def/crs/EPSG/0/3857"
+        );
+        let feature = &out["features"][0];
+        assert_eq!(feature["geometry"]["coordinates"], json!([-0.75, 0.5]));
+        assert_eq!(feature["place"]["type"], "Point");
+        assert_eq!(
+            feature["place"]["coordinates"],
+            json!([-83489.62, 55660.45])
+        );
+    }
+
+    #[test]
+    fn test_geojson_precision() {
+        let mut world = world_with_light();
+        // 1/3 degree has no short decimal representation
+        let third = BigRational::new(BigInt::from(1), BigInt::from(3));
+        for positions in world.exact_positions.values_mut() {
+            positions.lat[0] = third.clone();
+            positions.lon[0] = -third.clone();
+        }
+
+        let coords = |world: &World, precision| {
+            let options = GeoJsonOptions::new().precision(precision);
+            to_geojson(world, &options)["features"][0]["geometry"]["coordinates"].clone()
+        };
+
+        assert_eq!(
+            coords(&world, CoordinatePrecision::Decimals(3)),
+            json!([-0.333, 0.333])
+        );
+        // No DSPM: dataset precision falls back to full
+        assert_eq!(
+            coords(&world, CoordinatePrecision::Dataset),
+            coords(&world, CoordinatePrecision::Full)
+        );
+
+        world.dataset_params = Some(crate::ecs::DatasetParams {
+            comf: BigInt::from(10_000_000),
+            somf: BigInt::from(10),
+            duni: 1,
+            huni: 1,
+            puni: 1,
+            hdat: 2,
+            vdat: 0,
+            sdat: 0,
+            cscl: 22000,
+        });
+        assert_eq!(
+            coords(&world, CoordinatePrecision::Dataset),
+            json!([-0.3333333, 0.3333333])
+        );
+    }
+
+    #[test]
+    fn test_geojson_changes() {
+        let empty = World::new();
+        let light = world_with_light();
+        let mut relit = world_with_light();
+        for attrs in relit.feature_attributes.values_mut() {
+            attrs.attf[0].1 = "20250101".to_string();
+        }
+
+        let features = |old: &World, new: &World, options: GeoJsonOptions| {
+            to_geojson(new, &options.changes(old.diff(new)))["features"].clone()
+        };
+
+        // Unchanged: nothing to export
+        assert_eq!(
+            features(&light, &world_with_light(), GeoJsonOptions::new()),
+            json!([])
+        );
+
+        let added = features(&empty, &light, GeoJsonOptions::new());
+        assert_eq!(added.as_array().map(Vec::len), Some(1));
+        assert_eq!(added[0]["properties"]["change"], "added");
+        assert_eq!(added[0]["geometry"]["type"], "Point");
+
+        let modified = features(&light, &relit, GeoJsonOptions::new());
+        assert_eq!(modified[0]["properties"]["change"], "modified");
+        assert_eq!(modified[0]["properties"]["DATSTA"], "20250101");
+
+        // Removed features keep their identity but lose geometry
+        let removed = features(&light, &empty, GeoJsonOptions::new());
+        assert_eq!(removed[0]["id"], "550:7:1");
+        assert_eq!(removed[0]["geometry"], Value::Null);
+        assert_eq!(
+            removed[0]["properties"],
+            json!({ "OBJL": "LIGHTS", "FOID": "550:7:1", "change": "removed" })
+        );
+        let only_depths = GeoJsonOptions::new().classes(HashSet::from([42]));
+        assert_eq!(features(&light, &empty, only_depths), json!([]));
+    }
+}
@@ -0,0 +1,258 @@
+//! Exact planar predicates on rings
+//!
+//! Rings are closed (lat, lon) polylines as produced by topology resolution.
+//! Everything here treats longitude as x and latitude as y and works on the
+//! exact BigRational coordinates, so orientation never flips because of
+//! rounding on long, thin or nearly degenerate chart boundaries.
+
+use crate::topology::{AreaBoundary, Coordinate, Ring};
+use num_rational::BigRational;
+use num_traits::{Signed, Zero};
+use std::cmp::Ordering;
+
+/// RingOrientation: Winding direction of a ring (x = lon east, y = lat north)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RingOrientation {
+    CounterClockwise,
+    Clockwise,
+    /// Zero area (fewer than three distinct points, or all collinear)
+    Degenerate,
+}
+
+/// PointLocation: Where a point lies relative to a ring or area
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointLocation {
+    Inside,
+    Outside,
+    /// Exactly on an edge or vertex
+    Boundary,
+}
+
+/// Twice the signed area of a ring, in square degrees
+///
+/// Positive for counter-clockwise rings. Works on closed and unclosed
+/// rings alike; the closing segment is implied.
+pub fn signed_area2(ring: &[(BigRational, BigRational)]) -> BigRational {
+    let mut sum = BigRational::zero();
+    if ring.len() < 3 {
+        return sum;
+    }
+    for (i, (lat1, lon1)) in ring.iter().enumerate() {
+        let (lat2, lon2) = &ring[(i + 1) % ring.len()];
+        sum += lon1 * lat2 - lon2 * lat1;
+    }
+    sum
+}
+
+/// Signed area of a ring in square degrees (positive = counter-clockwise)
+pub fn signed_area(ring: &[(BigRational, BigRational)]) -> BigRational {
+    signed_area2(ring) / BigRational::from_integer(2.into())
+}
+
+/// Winding direction of a ring
+pub fn orientation(ring: &[(BigRational, BigRational)]) -> RingOrientation {
+    match signed_area2(ring).cmp(&BigRational::zero()) {
+        Ordering::Greater => RingOrientation::CounterClockwise,
+        Ordering::Less => RingOrientation::Clockwise,
+        Ordering::Equal => RingOrientation::Degenerate,
+    }
+}
+
+/// Reverse a ring if it doesn't already wind the requested way
+///
+/// Degenerate rings are left alone. f64 rings are judged in f64.
+pub fn orient<P: Coordinate>(ring: &mut Ring<P>, wanted: RingOrientation) {
+    let current = P::orientation(ring);
+    if current != RingOrientation::Degenerate && current != wanted {
+        ring.reverse();
+    }
+}
+
+/// Cross product of (b - a) x (p - a): > 0 if p is left of a->b
+pub(crate) fn cross(
+    a: &(BigRational, BigRational),
+    b: &(BigRational, BigRational),
+    p: &(BigRational, BigRational),
+) -> BigRational {
+    let (ay, ax) = a;
+    let (by, bx) = b;
+    let (py, px) = p;
+    (bx - ax) * (py - ay) - (by - ay) * (px - ax)
+}
+
+/// True if v lies in the closed interval spanned by a and b
+fn between(v: &BigRational, a: &BigRational, b: &BigRational) -> bool {
+    (a <= v && v <= b) || (b <= v && v <= a)
+}
+
+/// Locate a (lat, lon) point relative to a ring, exactly
+///
+/// Uses the winding number with exact cross products, so points on an edge
+/// or vertex are reported as Boundary rather than falling either way, and
+/// horizontal edges and vertices level with the point need no special
+/// casing. Works on closed and unclosed rings.
+pub fn locate_in_ring(
+    ring: &[(BigRational, BigRational)],
+    point: &(BigRational, BigRational),
+) -> PointLocation {
+    if ring.is_empty() {
+        return PointLocation::Outside;
+    }
+    let (py, px) = point;
+    let mut winding = 0i64;
+    for (i, a) in ring.iter().enumerate() {
+        let b = &ring[(i + 1) % ring.len()];
+        let side = cross(a, b, point);
+
+        // On the segment: collinear and within its bounding box
+        if side.is_zero() && between(px, &a.1, &b.1) && between(py, &a.0, &b.0) {
+            return PointLocation::Boundary;
+        }
+
+        if a.0 <= *py {
+            if b.0 > *py && side.is_positive() {
+                winding += 1;
+            }
+        } else if b.0 <= *py && side.is_negative() {
+            winding -= 1;
+        }
+    }
+    if winding == 0 {
+        PointLocation::Outside
+    } else {
+        PointLocation::Inside
+    }
+}
+
+impl<P: Coordinate> AreaBoundary<P> {
+    /// Normalize winding: exterior rings counter-clockwise, holes clockwise
+    ///
+    /// This is the RFC 7946 convention; S-57 itself winds exteriors
+    /// clockwise, so resolved areas are normalized before anyone sees them.
+    pub fn normalize_winding(&mut self) {
+        for ring in &mut self.exterior {
+            orient(ring, RingOrientation::CounterClockwise);
+        }
+        for ring in &mut self.interior {
+            orient(ring, RingOrientation::Clockwise);
+        }
+    }
+}
+
+impl AreaBoundary {
+    /// Locate a (lat, lon) point relative to the area, holes included
+    ///
+    /// A point on a hole's edge is on the area's boundary.
+    pub fn locate(&self, point: &(BigRational, BigRational)) -> PointLocation {
+        let mut inside = false;
+        for ring in &self.exterior {
+            match locate_in_ring(ring, point) {
+                PointLocation::Boundary => return PointLocation::Boundary,
+                PointLocation::Inside => inside = true,
+                PointLocation::Outside => {}
+            }
+        }
+        if !inside {
+            return PointLocation::Outside;
+        }
+        for hole in &self.interior {
+            match locate_in_ring(hole, point) {
+                PointLocation::Boundary => return PointLocation::Boundary,
+                PointLocation::Inside => return PointLocation::Outside,
+                PointLocation::Outside => {}
+            }
+        }
+        PointLocation::Inside
+    }
+
+    /// True if the point is inside the area or on its boundary
+    ///
+    /// Boundaries count as inside: a sounding on the edge shared by two
+    /// depth areas belongs to both, rather than to neither.
+    pub fn contains(&self, point: &(BigRational, BigRational)) -> bool {
+        self.locate(point) != PointLocation::Outside
+    }
+
+    /// Area enclosed, in square degrees: exteriors minus holes
+    pub fn area(&self) -> BigRational {
+        let total = |rings: &[Ring]| -> BigRational {
+            rings
+                .iter()
+                .map(|ring| signed_area(ring).abs())
+                .fold(BigRational::zero(), |acc, a| acc + a)
+        };
+        total(&self.exterior) - total(&self.interior)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_bigint::BigInt;
+
+    fn ring(points: &[(i64, i64)]) -> Ring {
+        points
+            .iter()
+            .map(|(lat, lon)| (BigInt::from(*lat).into(), BigInt::from(*lon).into()))
+            .collect()
+    }
+
+    #[test]
+    fn test_orientation_and_area() {
+        // (lat, lon): east along the bottom, then north = counter-clockwise
+        let ccw = ring(&[(0, 0), (0, 4), (4, 4), (4, 0), (0, 0)]);
+        assert_eq!(orientation(&ccw), RingOrientation::CounterClockwise);
+        assert_eq!(signed_area(&ccw), BigInt::from(16).into());
+
+        let mut cw = ccw.clone();
+        cw.reverse();
+        assert_eq!(orientation(&cw), RingOrientation::Clockwise);
+        assert_eq!(signed_area(&cw), BigInt::from(-16).into());
+
+        let line = ring(&[(0, 0), (1, 1), (2, 2), (0, 0)]);
+        assert_eq!(orientation(&line), RingOrientation::Degenerate);
+
+        let mut boundary = AreaBoundary {
+            exterior: vec![cw],
+            interior: vec![ring(&[(1, 1), (1, 2), (2, 2), (2, 1), (1, 1)])],
+        };
+        boundary.normalize_winding();
+        assert_eq!(boundary.exterior[0], ccw);
+        assert_eq!(
+            orientation(&boundary.interior[0]),
+            RingOrientation::Clockwise
+        );
+        assert_eq!(boundary.area(), BigInt::from(15).into());
+    }
+
+    #[test]
+    fn test_locate() {
+        let point = |lat: i64, lon: i64| (BigInt::from(lat).into(), BigInt::from(lon).into());
+        let boundary = AreaBoundary {
+            exterior: vec![ring(&[(0, 0), (0, 4), (4, 4), (4, 0), (0, 0)])],
+            interior: vec![ring(&[(1, 1), (2, 2), (1, 2), (1, 1)])],
+        };
+        assert_eq!(boundary.locate(&point(3, 3)), PointLocation::Inside);
+        assert_eq!(boundary.locate(&point(5, 1)), PointLocation::Outside);
+        // On an edge, on a vertex, level with a horizontal edge
+        assert_eq!(boundary.locate(&point(0, 2)), PointLocation::Boundary);
+        assert_eq!(boundary.locate(&point(4, 4)), PointLocation::Boundary);
+        assert_eq!(boundary.locate(&point(0, 6)), PointLocation::Outside);
+        // Inside the hole, and on its diagonal edge
+        let mid: BigRational = BigRational::new(3.into(), 2.into());
+        assert_eq!(
+            boundary.locate(&(BigInt::from(1).into(), mid.clone())),
+            PointLocation::Boundary
+        );
+        assert_eq!(
+            boundary.locate(&(mid.clone(), mid)),
+            PointLocation::Boundary
+        );
+        let in_hole = (
+            BigRational::new(5.into(), 4.into()),
+            BigRational::new(7.into(), 4.into()),
+        );
+        assert_eq!(boundary.locate(&in_hole), PointLocation::Outside);
+        assert!(!boundary.contains(&in_hole));
+    }
+}
@@ -0,0 +1,304 @@
+//! Cache of resolved feature geometry
+//!
+//! Resolving a line or area feature walks its FSPT edges and their VRPT
+//! nodes (and faces, in full topology) every time. With the cache enabled
+//! on a World, `TraversalContext::feature_geometry` resolves each feature
+//! once and hands out the shared result afterwards, so repeated renders and
+//! exports of the same World skip the topology walk.
+//!
+//! The cache sits behind a lock so it can fill up through `&World`. Anything
+//! that changes geometry (edits, applying updates) must invalidate it; the
+//! World editing API does so itself.
+//!
+//! Geometry resolved on the f64 path (`feature_geometry_approx`) isn't
+//! cached: it is cheap enough to resolve again.
+
+use crate::ecs::{EntityId, World};
+use crate::topology::{
+    approx_line, ApproxPoint, AreaBoundary, Coordinate, EdgeWalker, FeatureBoundaryCursor,
+    Orientation, TopologyResult, TraversalContext,
+};
+use num_rational::BigRational;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+/// PRIM values with geometry worth caching
+const PRIM_LINE: u8 = 2;
+const PRIM_AREA: u8 = 3;
+
+/// ResolvedGeometry: A line or area feature after topology resolution
+///
+/// Point features aren't cached: their positions are read straight from
+/// the referenced nodes.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ResolvedGeometry<P = (BigRational, BigRational)> {
+    /// One polyline per FSPT edge, in FSPT order with ORNT applied
+    Lines(Vec<Vec<P>>),
+    /// Exterior and interior rings
+    Area(AreaBoundary<P>),
+}
+
+/// ApproxGeometry: A line or area feature resolved in f64
+pub type ApproxGeometry = ResolvedGeometry<ApproxPoint>;
+
+impl ResolvedGeometry {
+    /// The same geometry in f64
+    pub fn to_approx(&self) -> ApproxGeometry {
+        match self {
+            Self::Lines(lines) => {
+                ApproxGeometry::Lines(lines.iter().map(|line| approx_line(line)).collect())
+            }
+            Self::Area(boundary) => ApproxGeometry::Area(boundary.to_approx()),
+        }
+    }
+}
+
+/// GeometryCache: Resolved geometry per feature entity
+#[derive(Debug, Default)]
+pub struct GeometryCache {
+    entries: RwLock<HashMap<EntityId, Arc<ResolvedGeometry>>>,
+}
+
+impl GeometryCache {
+    /// Number of cached features
+    pub fn len(&self) -> usize {
+        self.read().len()
+    }
+
+    /// True if nothing has been cached yet
+    pub fn is_empty(&self) -> bool {
+        self.read().is_empty()
+    }
+
+    /// Cached geometry of a feature, if resolved before
+    pub fn get(&self, feature: EntityId) -> Option<Arc<ResolvedGeometry>> {
+        self.read().get(&feature).cloned()
+    }
+
+    fn insert(&self, feature: EntityId, geometry: Arc<ResolvedGeometry>) {
+        self.entries
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(feature, geometry);
+    }
+
+    fn remove(&mut self, feature: EntityId) {
+        self.entries
+            .get_mut()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&feature);
+    }
+
+    fn clear(&mut self) {
+        self.entries
+            .get_mut()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+    }
+
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, HashMap<EntityId, Arc<ResolvedGeometry>>> {
+        // A panic while holding the lock can't leave a half-written entry
+        self.entries.read().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl World {
+    /// Turn on the resolved-geometry cache (off by default)
+    pub fn enable_geometry_cache(&mut self) {
+        self.geometry_cache
+            .get_or_insert_with(GeometryCache::default);
+    }
+
+    /// Turn off the cache and drop everything in it
+    pub fn disable_geometry_cache(&mut self) {
+        self.geometry_cache = None;
+    }
+
+    /// Drop all cached geometry, e.g. after applying an update
+    ///
+    /// Vectors are shared between features, so a geometry change anywhere
+    /// can affect any feature; this clears the whole cache.
+    pub fn invalidate_geometry(&mut self) {
+        if let Some(cache) = &mut self.geometry_cache {
+            cache.clear();
+        }
+    }
+
+    /// Drop one feature's cached geometry (e.g. when it is deleted)
+    pub fn invalidate_feature_geometry(&mut self, feature: EntityId) {
+        if let Some(cache) = &mut self.geometry_cache {
+            cache.remove(feature);
+        }
+    }
+}
+
+impl TraversalContext<'_> {
+    /// Resolved geometry of a line or area feature
+    ///
+    /// Served from the World's geometry cache when it is enabled, resolving
+    /// and caching on first use. Returns Ok(None) for features that aren't
+    /// lines or areas. Errors are not cached. Cached results were resolved
+    /// with the policies of whichever context asked first; a context with a
+    /// coordinate transform bypasses the cache.
+    pub fn feature_geometry(
+        &self,
+        feature: EntityId,
+    ) -> TopologyResult<Option<Arc<ResolvedGeometry>>> {
+        let cache = self
+            .world
+            .geometry_cache
+            .as_ref()
+            .filter(|_| self.transform.is_none());
+        if let Some(cached) = cache.and_then(|c| c.get(feature)) {
+            return Ok(Some(cached));
+        }
+
+        let Some(geometry) = self.resolve_feature_geometry(feature)? else {
+            return Ok(None);
+        };
+        let geometry = Arc::new(geometry);
+        if let Some(cache) = cache {
+            cache.insert(feature, Arc::clone(&geometry));
+        }
+        Ok(Some(geometry))
+    }
+
+    /// Resolved geometry of a line or area feature on the fast f64 path
+    ///
+    /// As `feature_geometry`, but resolved on every call (see
+    /// `crate::approx`).
+    pub fn feature_geometry_approx(
+        &self,
+        feature: EntityId,
+    ) -> TopologyResult<Option<ApproxGeometry>> {
+        self.resolve_feature_geometry(feature)
+    }
+
+    /// Walk the topology for a line or area feature
+    fn resolve_feature_geometry<P: Coordinate>(
+        &self,
+        feature: EntityId,
+    ) -> TopologyResult<Option<ResolvedGeometry<P>>> {
+        let Some(meta) = self.world.feature_meta.get(&feature) else {
+            return Ok(None);
+        };
+        match meta.prim {
+            PRIM_LINE => {
+                let mut lines = Vec::new();
+                let pointers = self.world.feature_pointers.get(&feature);
+                for sref in pointers.iter().flat_map(|p| &p.spatial_refs) {
+                    let Some(vmeta) = self.world.vector_meta.get(&sref.entity) else {
+                        continue;
+                    };
+                    match EdgeWalker::new(self).resolve_line(vmeta.name) {
+                        Ok(mut coords) => {
+                            if Orientation::from_ornt(sref.ornt).should_reverse() {
+                                coords.reverse();
+                            }
+                            lines.push(coords);
+                        }
+                        Err(e) => crate::diag::debug!("Skipping edge {:?}: {}", vmeta.name, e),
+                    }
+                }
+                Ok(Some(ResolvedGeometry::Lines(lines)))
+            }
+            PRIM_AREA => {
+                let boundary = FeatureBoundaryCursor::new(self, meta.foid).boundary()?;
+                Ok(Some(ResolvedGeometry::Area(boundary)))
+            }
+            _ => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::{
+        EntityType, ExactPositions, FeatureMeta, FeaturePointers, SpatialRef, VectorMeta,
+    };
+    use num_bigint::BigInt;
+    use s57_parse::bitstring::{FoidKey, NameKey};
+    use smallvec::smallvec;
+
+    #[test]
+    fn test_geometry_cache() {
+        let mut world = World::new();
+        let edge = world.create_entity(EntityType::Vector);
+        let name = NameKey { rcnm: 130, rcid: 1 };
+        world.name_index.insert(name, edge);
+        world.vector_meta.insert(
+            edge,
+            VectorMeta {
+                name,
+                rver: 1,
+                ruin: 1,
+            },
+        );
+        world.exact_positions.insert(
+            edge,
+            ExactPositions {
+                lat: smallvec![BigInt::from(1).into(), BigInt::from(2).into()],
+                lon: smallvec![BigInt::from(3).into(), BigInt::from(4).into()],
+            },
+        );
+        let feature = world.create_entity(EntityType::Feature);
+        world.feature_meta.insert(
+            feature,
+            FeatureMeta {
+                foid: FoidKey {
+                    agen: 550,
+                    fidn: 1,
+                    fids: 1,
+                },
+                prim: PRIM_LINE,
+                grup: 2,
+                objl: 30,
+                rver: 1,
+                ruin: 1,
+            },
+        );
+        world.feature_pointers.insert(
+            feature,
+            FeaturePointers {
+                related_features: vec![],
+                spatial_refs: vec![SpatialRef {
+                    entity: edge,
+                    ornt: 2,
+                    usag: 255,
+                    mask: 255,
+                }],
+            },
+        );
+
+        // Disabled by default: resolves every time, caches nothing
+        let first = TraversalContext::new(&world).feature_geometry(feature);
+        let first = first.unwrap().unwrap();
+        assert!(world.geometry_cache.is_none());
+        let ResolvedGeometry::Lines(lines) = first.as_ref() else {
+            panic!("expected lines");
+        };
+        // ORNT=2 reverses the edge
+        assert_eq!(lines[0][0].0, BigRational::from(BigInt::from(2)));
+
+        world.enable_geometry_cache();
+        let ctx = TraversalContext::new(&world);
+        let a = ctx.feature_geometry(feature).unwrap().unwrap();
+        let b = ctx.feature_geometry(feature).unwrap().unwrap();
+        assert!(Arc::ptr_eq(&a, &b));
+        assert_eq!(world.geometry_cache.as_ref().unwrap().len(), 1);
+
+        world.invalidate_geometry();
+        assert!(world.geometry_cache.as_ref().unwrap().is_empty());
+
+        // The f64 path resolves the same lines and leaves the cache alone
+        let ctx = TraversalContext::new(&world);
+        let approx = ctx.feature_geometry_approx(feature).unwrap();
+        assert_eq!(
+            approx,
+            Some(ResolvedGeometry::Lines(vec![vec![(2.0, 4.0), (1.0, 3.0)]]))
+        );
+        assert!(world.geometry_cache.as_ref().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,1009 @@
+//! Gridded depths from soundings and depth areas
+//!
+//! `DepthGrid::build` interpolates SOUNDG soundings onto a regular lat/lon
+//! grid, either by inverse distance weighting or linearly across a Delaunay
+//! triangulation (TIN) of the soundings. Depth areas then constrain the
+//! result: a cell whose centre lies in a DEPARE or DRGARE is clamped to that
+//! area's depth range, and one no sounding reaches takes the area's shoalest
+//! depth. When the World has depth areas, cells in none of them (land, or
+//! beyond the data) stay empty.
+//!
+//! Unlike most of the crate this works in f64. A raster is an approximation
+//! whatever the arithmetic, and exact point-in-area tests for every cell
+//! would cost far more than they are worth.
+//!
+//! Grids are written as XYZ text (`lon lat depth` per cell with a value) or
+//! as a single-band float32 GeoTIFF in WGS84 (EPSG:4326), NaN for no data.
+
+use crate::ecs::{parse_decimal, EntityType, World};
+use crate::geometry_cache::ResolvedGeometry;
+use crate::topology::TraversalContext;
+use num_traits::ToPrimitive;
+use std::fmt;
+
+/// DEPARE: Depth area
+const OBJL_DEPARE: u16 = 42;
+/// DRGARE: Dredged area
+const OBJL_DRGARE: u16 = 46;
+/// DRVAL1 / DRVAL2: Depth range values
+const ATTL_DRVAL1: u16 = 87;
+const ATTL_DRVAL2: u16 = 88;
+
+/// Cells along the longer side of the extent when no resolution is given
+const DEFAULT_CELLS: f64 = 512.0;
+/// Cell size in degrees for an extent that is a single point
+const FALLBACK_RESOLUTION: f64 = 1e-4;
+/// Largest grid built, in cells
+const MAX_CELLS: usize = 50_000_000;
+
+/// Interpolation: How soundings are spread between sample points
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Interpolation {
+    /// Inverse distance weighting over the soundings within `radius` cells,
+    /// weighted by distance to the power `power`
+    Idw { power: f64, radius: u32 },
+    /// Linear across a Delaunay triangulation; cells outside the
+    /// soundings' convex hull get no value
+    Tin,
+}
+
+impl Default for Interpolation {
+    fn default() -> Self {
+        Interpolation::Idw {
+            power: 2.0,
+            radius: 8,
+        }
+    }
+}
+
+/// GridOptions: Configuration for `DepthGrid::build`
+#[derive(Debug, Clone, Default)]
+pub struct GridOptions {
+    /// Cell size in degrees (None = 512 cells along the longer side)
+    pub resolution: Option<f64>,
+    pub interpolation: Interpolation,
+}
+
+impl GridOptions {
+    /// Create options with the default resolution and IDW
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the cell size in degrees
+    pub fn resolution(mut self, degrees: f64) -> Self {
+        self.resolution = Some(degrees);
+        self
+    }
+
+    /// Set the interpolation method
+    pub fn interpolation(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+}
+
+/// GridError: Why a grid could not be built
+#[derive(Debug, Clone, PartialEq)]
+pub enum GridError {
+    /// Nothing in the World has a position
+    NoData,
+    /// The resolution is not a positive number
+    InvalidResolution(f64),
+    /// The grid would exceed the cell limit
+    TooLarge { width: usize, height: usize },
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::NoData => write!(f, "nothing to grid: no positioned data"),
+            GridError::InvalidResolution(r) => write!(f, "invalid resolution {}", r),
+            GridError::TooLarge { width, height } => write!(
+                f,
+                "grid of {}x{} cells is too large (limit {} cells)",
+                width, height, MAX_CELLS
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
+/// DepthGrid: Depths on a regular lat/lon grid
+#[derive(Debug, Clone, PartialEq)]
+pub struct DepthGrid {
+    /// Western edge of the first column, degrees
+    pub min_lon: f64,
+    /// Northern edge of the first row, degrees
+    pub max_lat: f64,
+    /// Cell size, degrees
+    pub resolution: f64,
+    pub width: usize,
+    pub height: usize,
+    /// Depths (positive down) row by row, north to south; NaN = no data
+    pub values: Vec<f32>,
+}
+
+/// A sounding, with longitude scaled so distances are roughly isotropic
+#[derive(Debug, Clone, Copy)]
+struct Sample {
+    x: f64,
+    y: f64,
+    depth: f64,
+}
+
+impl DepthGrid {
+    /// Grid a World's soundings and depth areas
+    pub fn build(world: &World, options: &GridOptions) -> Result<Self, GridError> {
+        let extent = world.extent().ok_or(GridError::NoData)?;
+        let f = |v: &num_rational::BigRational| v.to_f64().unwrap_or(0.0);
+        let (min_lat, min_lon) = (f(&extent.min_lat), f(&extent.min_lon));
+        let (max_lat, max_lon) = (f(&extent.max_lat), f(&extent.max_lon));
+
+        // A lone sounding has no extent: give it one small cell
+        let span = (max_lat - min_lat).max(max_lon - min_lon);
+        let resolution = options.resolution.unwrap_or(if span > 0.0 {
+            span / DEFAULT_CELLS
+        } else {
+            FALLBACK_RESOLUTION
+        });
+        if !(resolution.is_finite() && resolution > 0.0) {
+            return Err(GridError::InvalidResolution(resolution));
+        }
+        let cells = |span: f64| ((span / resolution).ceil() as usize).max(1);
+        let (width, height) = (cells(max_lon - min_lon), cells(max_lat - min_lat));
+        if width.saturating_mul(height) > MAX_CELLS {
+            return Err(GridError::TooLarge { width, height });
+        }
+
+        let mut grid = DepthGrid {
+            min_lon,
+            max_lat,
+            resolution,
+            width,
+            height,
+            values: vec![f32::NAN; width * height],
+        };
+        // Longitude degrees shrink towards the poles
+        let x_scale = ((min_lat + max_lat) / 2.0).to_radians().cos().max(0.01);
+
+        let samples = soundings(world, x_scale);
+        match options.interpolation {
+            Interpolation::Idw { power, radius } => {
+                grid.idw(&samples, x_scale, power, radius.max(1))
+            }
+            Interpolation::Tin => grid.tin(&samples, x_scale),
+        }
+        grid.constrain_to_depth_areas(world);
+        Ok(grid)
+    }
+
+    /// Depth at a cell, None if it has no data
+    pub fn get(&self, column: usize, row: usize) -> Option<f32> {
+        if column >= self.width {
+            return None;
+        }
+        let value = *self.values.get(row * self.width + column)?;
+        (!value.is_nan()).then_some(value)
+    }
+
+    /// (lon, lat) of a cell's centre
+    pub fn cell_centre(&self, column: usize, row: usize) -> (f64, f64) {
+        (
+            self.min_lon + (column as f64 + 0.5) * self.resolution,
+            self.max_lat - (row as f64 + 0.5) * self.resolution,
+        )
+    }
+
+    /// (column, row) range of cells whose centres fall in a lon/lat box
+    fn cells_in(&self, min_lon: f64, min_lat: f64, max_lon: f64, max_lat: f64) -> CellRange {
+        let col = |lon: f64| (lon - self.min_lon) / self.resolution - 0.5;
+        let row = |lat: f64| (self.max_lat - lat) / self.resolution - 0.5;
+        let clamp = |v: f64, n: usize| v.clamp(0.0, n as f64) as usize;
+        CellRange {
+            columns: clamp(col(min_lon).ceil(), self.width)
+                ..clamp(col(max_lon).floor() + 1.0, self.width),
+            rows: clamp(row(max_lat).ceil(), self.height)
+                ..clamp(row(min_lat).floor() + 1.0, self.height),
+        }
+    }
+
+    fn idw(&mut self, samples: &[Sample], x_scale: f64, power: f64, radius: u32) {
+        // Bucket the soundings by cell so each cell only looks nearby
+        let mut buckets: Vec<Vec<usize>> = vec![Vec::new(); self.width * self.height];
+        for (i, sample) in samples.iter().enumerate() {
+            let lon = sample.x / x_scale;
+            let column = ((lon - self.min_lon) / self.resolution).floor();
+            let row = ((self.max_lat - sample.y) / self.resolution).floor();
+            // Soundings on the east and south edges belong to the last cell
+            if column >= 0.0 && row >= 0.0 {
+                let (column, row) = (column as usize, row as usize);
+                if column <= self.width && row <= self.height {
+                    let column = column.min(self.width - 1);
+                    let row = row.min(self.height - 1);
+                    buckets[row * self.width + column].push(i);
+                }
+            }
+        }
+
+        let reach = radius as usize;
+        let max_distance = radius as f64 * self.resolution;
+        for row in 0..self.height {
+            for column in 0..self.width {
+                let (lon, lat) = self.cell_centre(column, row);
+                let (x, y) = (lon * x_scale, lat);
+                let mut weights = 0.0;
+                let mut total = 0.0;
+                let mut exact = None;
+                'search: for r in row.saturating_sub(reach)..(row + reach + 1).min(self.height) {
+                    for c in column.saturating_sub(reach)..(column + reach + 1).min(self.width) {
+                        for &i in &buckets[r * self.width + c] {
+                            let sample = samples[i];
+                            let distance = (sample.x - x).hypot(sample.y - y);
+                            if distance > max_distance {
+                                continue;
+                            }
+                            if distance < 1e-12 {
+                                exact = Some(sample.depth);
+                                break 'search;
+                            }
+                            let weight = distance.powf(-power);
+                            weights += weight;
+                            total += weight * sample.depth;
+                        }
+                    }
+                }
+                let value = match exact {
+                    Some(depth) => Some(depth),
+                    None if weights > 0.0 => Some(total / weights),
+                    None => None,
+                };
+                if let Some(value) = value {
+                    self.values[row * self.width + column] = value as f32;
+                }
+            }
+        }
+    }
+
+    fn tin(&mut self, samples: &[Sample], x_scale: f64) {
+        let points: Vec<(f64, f64)> = samples.iter().map(|s| (s.x, s.y)).collect();
+        for [a, b, c] in delaunay(&points) {
+            let (pa, pb, pc) = (samples[a], samples[b], samples[c]);
+            let min_x = pa.x.min(pb.x).min(pc.x) / x_scale;
+            let max_x = pa.x.max(pb.x).max(pc.x) / x_scale;
+            let min_y = pa.y.min(pb.y).min(pc.y);
+            let max_y = pa.y.max(pb.y).max(pc.y);
+            let denom = (pb.y - pc.y) * (pa.x - pc.x) + (pc.x - pb.x) * (pa.y - pc.y);
+            if denom.abs() < f64::EPSILON {
+                continue;
+            }
+            let range = self.cells_in(min_x, min_y, max_x, max_y);
+            for row in range.rows.clone() {
+                for column in range.columns.clone() {
+                    let (lon, lat) = self.cell_centre(column, row);
+                    let (x, y) = (lon * x_scale, lat);
+                    let wa = ((pb.y - pc.y) * (x - pc.x) + (pc.x - pb.x) * (y - pc.y)) / denom;
+                    let wb = ((pc.y - pa.y) * (x - pc.x) + (pa.x - pc.x) * (y - pc.y)) / denom;
+                    let wc = 1.0 - wa - wb;
+                    let inside = -1e-9;
+                    if wa >= inside && wb >= inside && wc >= inside {
+                        let depth = wa * pa.depth + wb * pb.depth + wc * pc.depth;
+                        self.values[row * self.width + column] = depth as f32;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Clamp cells to the depth area around them, fill those without a
+    /// value, and blank those outside every depth area
+    fn constrain_to_depth_areas(&mut self, world: &World) {
+        let ctx = TraversalContext::new(world);
+        let mut covered = vec![false; self.values.len()];
+        let mut any = false;
+        for feature in world.entities_of_type(EntityType::Feature) {
+            let Some(meta) = world.feature_meta.get(&feature) else {
+                continue;
+            };
+            if meta.objl != OBJL_DEPARE && meta.objl != OBJL_DRGARE {
+                continue;
+            }
+            let Ok(Some(geometry)) = ctx.feature_geometry(feature) else {
+                continue;
+            };
+            let ResolvedGeometry::Area(area) = geometry.as_ref() else {
+                continue;
+            };
+            any = true;
+            let attrs = world.feature_attributes.get(&feature);
+            let value = |attl| {
+                attrs
+                    .and_then(|a| a.get(attl))
+                    .and_then(parse_decimal)
+                    .and_then(|v| v.to_f64())
+            };
+            let (drval1, drval2) = (value(ATTL_DRVAL1), value(ATTL_DRVAL2));
+
+            let rings: Vec<Vec<(f64, f64)>> = area
+                .exterior
+                .iter()
+                .chain(&area.interior)
+                .map(|ring| {
+                    ring.iter()
+                        .map(|(lat, lon)| {
+                            (lon.to_f64().unwrap_or(0.0), lat.to_f64().unwrap_or(0.0))
+                        })
+                        .collect()
+                })
+                .collect();
+            let points = || rings.iter().flatten();
+            let min_x = points().map(|p| p.0).fold(f64::INFINITY, f64::min);
+            let max_x = points().map(|p| p.0).fold(f64::NEG_INFINITY, f64::max);
+            let min_y = points().map(|p| p.1).fold(f64::INFINITY, f64::min);
+            let max_y = points().map(|p| p.1).fold(f64::NEG_INFINITY, f64::max);
+
+            let range = self.cells_in(min_x, min_y, max_x, max_y);
+            for row in range.rows.clone() {
+                for column in range.columns.clone() {
+                    let centre = self.cell_centre(column, row);
+                    if !inside_rings(&rings, centre) {
+                        continue;
+                    }
+                    let index = row * self.width + column;
+                    covered[index] = true;
+                    let cell = &mut self.values[index];
+                    if cell.is_nan() {
+                        if let Some(depth) = drval1.or(drval2) {
+                            *cell = depth as f32;
+                        }
+                        continue;
+                    }
+                    if let Some(low) = drval1 {
+                        *cell = cell.max(low as f32);
+                    }
+                    if let Some(high) = drval2 {
+                        *cell = cell.min(high as f32);
+                    }
+                }
+            }
+        }
+        if any {
+            for (value, covered) in self.values.iter_mut().zip(covered) {
+                if !covered {
+                    *value = f32::NAN;
+                }
+            }
+        }
+    }
+
+    /// XYZ text: `lon lat depth` per cell with data, north to south
+    pub fn to_xyz(&self) -> String {
+        let mut text = String::new();
+        for row in 0..self.height {
+            for column in 0..self.width {
+                if let Some(depth) = self.get(column, row) {
+                    let (lon, lat) = self.cell_centre(column, row);
+                    text.push_str(&format!("{} {} {}\n", lon, lat, depth));
+                }
+            }
+        }
+        text
+    }
+
+    /// Single-band float32 GeoTIFF, EPSG:4326, uncompressed
+    pub fn to_geotiff(&self) -> Vec<u8> {
+        let pixels: Vec<u8> = self.values.iter().flat_map(|v| v.to_le_bytes()).collect();
+        let shorts = |values: &[u16]| values.iter().flat_map(|v| v.to_le_bytes()).collect();
+        let longs = |values: &[u32]| values.iter().flat_map(|v| v.to_le_bytes()).collect();
+        let doubles = |values: &[f64]| values.iter().flat_map(|v| v.to_le_bytes()).collect();
+        let (width, height) = (self.width as u32, self.height as u32);
+
+        // GeoKeyDirectory: version 1.1.0 and three keys, each (id, location
+        // 0 = inline, count 1, value)
+        let geo_keys = [
+            1,
+            1,
+            0,
+            3, //
+            GT_MODEL_TYPE,
+            0,
+            1,
+            MODEL_TYPE_GEOGRAPHIC, //
+            GT_RASTER_TYPE,
+            0,
+            1,
+            RASTER_PIXEL_IS_AREA, //
+            GEOGRAPHIC_TYPE,
+            0,
+            1,
+            GCS_WGS_84,
+        ];
+        let mut entries: Vec<(u16, u16, u32, Vec<u8>)> = vec![
+            (256, TIFF_LONG, 1, longs(&[width])),
+            (257, TIFF_LONG, 1, longs(&[height])),
+            (258, TIFF_SHORT, 1, shorts(&[32])),
+            (259, TIFF_SHORT, 1, shorts(&[1])),
+            (262, TIFF_SHORT, 1, shorts(&[1])),
+            (273, TIFF_LONG, 1, longs(&[0])), // filled in below
+            (277, TIFF_SHORT, 1, shorts(&[1])),
+            (278, TIFF_LONG, 1, longs(&[height])),
+            (279, TIFF_LONG, 1, longs(&[pixels.len() as u32])),
+            (284, TIFF_SHORT, 1, shorts(&[1])),
+            (339, TIFF_SHORT, 1, shorts(&[3])),
+            (
+                33550,
+                TIFF_DOUBLE,
+                3,
+                doubles(&[self.resolution, self.resolution, 0.0]),
+            ),
+            (
+                33922,
+                TIFF_DOUBLE,
+                6,
+                doubles(&[0.0, 0.0, 0.0, self.min_lon, self.max_lat, 0.0]),
+            ),
+            (34735, TIFF_SHORT, geo_keys.len() as u32, shorts(&geo_keys)),
+            (42113, TIFF_ASCII, 4, b"nan\0".to_vec()),
+        ];
+
+        // Header, then the directory, then values too big to inline, then
+        // the pixels
+        let ifd_len = 2 + entries.len() * 12 + 4;
+        let extra_len: usize = entries
+            .iter()
+            .filter(|e| e.3.len() > 4)
+            .map(|e| e.3.len().next_multiple_of(2))
+            .sum();
+        let pixel_offset = 8 + ifd_len + extra_len;
+        entries[5].3 = longs(&[pixel_offset as u32]);
+
+        let mut out = Vec::with_capacity(pixel_offset + pixels.len());
+        out.extend_from_slice(b"II");
+        out.extend_from_slice(&42u16.to_le_bytes());
+        out.extend_from_slice(&8u32.to_le_bytes());
+        out.extend_from_slice(&(entries.len() as u16).to_le_bytes());
+        let mut extra = Vec::with_capacity(extra_len);
+        for (tag, kind, count, data) in &entries {
+            out.extend_from_slice(&tag.to_le_bytes());
+            out.extend_from_slice(&kind.to_le_bytes());
+            out.extend_from_slice(&count.to_le_bytes());
+            if data.len() <= 4 {
+                let mut inline = [0u8; 4];
+                inline[..data.len()].copy_from_slice(data);
+                out.extend_from_slice(&inline);
+            } else {
+                let offset = 8 + ifd_len + extra.len();
+                out.extend_from_slice(&(offset as u32).to_le_bytes());
+                extra.extend_from_slice(data);
+                if extra.len() % 2 == 1 {
+                    extra.push(0);
+                }
+            }
+        }
+        out.extend_from_slice(&0u32.to_le_bytes());
+        out.extend_from_slice(&extra);
+        out.extend_from_slice(&pixels);
+        out
+    }
+}
+
+/// TIFF field types
+const TIFF_ASCII: u16 = 2;
+const TIFF_SHORT: u16 = 3;
+const TIFF_LONG: u16 = 4;
+const TIFF_DOUBLE: u16 = 12;
+/// GeoTIFF keys and values
+const GT_MODEL_TYPE: u16 = 1024;
+const GT_RASTER_TYPE: u16 = 1025;
+const GEOGRAPHIC_TYPE: u16 = 2048;
+const MODEL_TYPE_GEOGRAPHIC: u16 = 2;
+const RASTER_PIXEL_IS_AREA: u16 = 1;
+const GCS_WGS_84: u16 = 4326;
+
+struct CellRange {
+    columns: std::ops::Range<usize>,
+    rows: std::ops::Range<usize>,
+}
+
+/// Every sounding in the World, with longitude scaled by `x_scale`
+fn soundings(world: &World, x_scale: f64) -> Vec<Sample> {
+    world
+        .soundings()
+        .map(|sounding| Sample {
+            x: sounding.lon.to_f64().unwrap_or(0.0) * x_scale,
+            y: sounding.lat.to_f64().unwrap_or(0.0),
+            depth: sounding.depth.to_f64().unwrap_or(0.0),
+        })
+        .collect()
+}
+
+/// Even-odd point-in-polygon over all of an area's rings (holes included)
+fn inside_rings(rings: &[Vec<(f64, f64)>], (x, y): (f64, f64)) -> bool {
+    let mut inside = false;
+    for ring in rings {
+        for (i, &(x1, y1)) in ring.iter().enumerate() {
+            let (x2, y2) = ring[(i + 1) % ring.len()];
+            if (y1 > y) != (y2 > y) && x < x1 + (y - y1) / (y2 - y1) * (x2 - x1) {
+                inside = !inside;
+            }
+        }
+    }
+    inside
+}
+
+/// Delaunay triangulation (Bowyer-Watson) as triangles of point indices
+///
+/// Each point is located by walking from the last triangle made towards
+/// it, and the triangles it invalidates are found by flooding out from
+/// there through neighbours, so an insertion touches only the triangles
+/// near the point. Of coincident points only one is used.
+fn delaunay(points: &[(f64, f64)]) -> Vec<[usize; 3]> {
+    if points.len() < 3 {
+        return Vec::new();
+    }
+    let (mut min_x, mut min_y) = (f64::INFINITY, f64::INFINITY);
+    let (mut max_x, mut max_y) = (f64::NEG_INFINITY, f64::NEG_INFINITY);
+    for &(x, y) in points {
+        min_x = min_x.min(x);
+        min_y = min_y.min(y);
+        max_x = max_x.max(x);
+        max_y = max_y.max(y);
+    }
+    let span = (max_x - min_x).max(max_y - min_y).max(1e-9) * 20.0;
+    let (cx, cy) = ((min_x + max_x) / 2.0, (min_y + max_y) / 2.0);
+
+    // Vertices: the points, then a counter-clockwise super-triangle around
+    // all of them
+    let n = points.len();
+    let mut vertices = points.to_vec();
+    vertices.extend([
+        (cx - span, cy - span),
+        (cx + span, cy - span),
+        (cx, cy + span),
+    ]);
+    let mut mesh = Mesh {
+        triangles: vec![Triangle {
+            vertices: [n, n + 1, n + 2],
+            neighbours: [None; 3],
+            alive: true,
+        }],
+    };
+
+    // Insert in bands across the points, alternating direction, so each
+    // walk starts next to the point it is looking for
+    let bands = ((n as f64).sqrt() / 2.0).ceil();
+    let height = (max_y - min_y).max(1e-9);
+    let key = |i: usize| {
+        let (x, y) = points[i];
+        let band = ((y - min_y) / height * bands).floor().min(bands - 1.0);
+        (band, if band % 2.0 == 0.0 { x } else { -x })
+    };
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| {
+        let ((band_i, x_i), (band_j, x_j)) = (key(i), key(j));
+        band_i.total_cmp(&band_j).then(x_i.total_cmp(&x_j))
+    });
+
+    let mut seen = std::collections::HashSet::new();
+    let mut bad = Vec::new();
+    let mut stack = Vec::new();
+    let mut boundary = Vec::new();
+    for i in order {
+        let p = vertices[i];
+        if !seen.insert((p.0.to_bits(), p.1.to_bits())) {
+            continue;
+        }
+        // Triangles whose circumcircle holds the point make way for it:
+        // the one containing it, and those connected to it that do too
+        let start = mesh.locate(&vertices, p);
+        bad.clear();
+        stack.push(start);
+        mesh.triangles[start].alive = false;
+        while let Some(t) = stack.pop() {
+            bad.push(t);
+            for neighbour in mesh.triangles[t].neighbours.into_iter().flatten() {
+                let u = &mut mesh.triangles[neighbour];
+                if u.alive && in_circumcircle(&vertices, &u.vertices, p) {
+                    u.alive = false;
+                    stack.push(neighbour);
+                }
+            }
+        }
+
+        // The hole's boundary: edges between a bad triangle and a good one
+        // (or the outside), counter-clockwise, with the good triangle
+        boundary.clear();
+        for &t in &bad {
+            let Triangle {
+                vertices: v,
+                neighbours,
+                ..
+            } = mesh.triangles[t];
+            for k in 0..3 {
+                let outer = neighbours[k].filter(|&u| mesh.triangles[u].alive);
+                if neighbours[k].is_none() || outer.is_some() {
+                    boundary.push((v[(k + 1) % 3], v[(k + 2) % 3], outer, t));
+                }
+            }
+        }
+
+        // Fan the hole from the point, linking each new triangle to the
+        // good one across its edge and to the new ones either side
+        let first = mesh.triangles.len();
+        let starting_at: std::collections::HashMap<usize, usize> = boundary
+            .iter()
+            .enumerate()
+            .map(|(k, &(a, _, _, _))| (a, first + k))
+            .collect();
+        for &(a, b, outer, old) in &boundary {
+            let t = mesh.triangles.len();
+            if let Some(outer) = outer {
+                let slot = mesh.triangles[outer]
+                    .neighbours
+                    .iter_mut()
+                    .find(|u| **u == Some(old));
+                if let Some(slot) = slot {
+                    *slot = Some(t);
+                }
+            }
+            mesh.triangles.push(Triangle {
+                vertices: [a, b, i],
+                neighbours: [starting_at.get(&b).copied(), None, outer],
+                alive: true,
+            });
+        }
+        for t in first..mesh.triangles.len() {
+            if let Some(next) = mesh.triangles[t].neighbours[0] {
+                mesh.triangles[next].neighbours[1] = Some(t);
+            }
+        }
+    }
+
+    mesh.triangles
+        .into_iter()
+        .filter(|t| t.alive && t.vertices.iter().all(|&v| v < n))
+        .map(|t| t.vertices)
+        .collect()
+}
+
+/// Triangulation under construction; replaced triangles stay in place,
+/// marked dead, so indices don't move
+struct Mesh {
+    triangles: Vec<Triangle>,
+}
+
+/// Counter-clockwise triangle with the neighbour across the edge opposite
+/// each vertex (None on the outside)
+#[derive(Clone, Copy)]
+struct Triangle {
+    vertices: [usize; 3],
+    neighbours: [Option<usize>; 3],
+    alive: bool,
+}
+
+impl Mesh {
+    /// A live triangle containing `p`, walking from the newest one across
+    /// whichever edge has `p` beyond it
+    ///
+    /// Rounding can keep a walk from settling on near-degenerate input, so
+    /// after as many steps as there are triangles it falls back to a scan.
+    fn locate(&self, vertices: &[(f64, f64)], p: (f64, f64)) -> usize {
+        let side = |a: usize, b: usize| {
+            let (a, b) = (vertices[a], vertices[b]);
+            (b.0 - a.0) * (p.1 - a.1) - (b.1 - a.1) * (p.0 - a.0)
+        };
+        let mut t = self.triangles.len() - 1;
+        for _ in 0..self.triangles.len() {
+            let Triangle {
+                vertices: v,
+                neighbours,
+                ..
+            } = self.triangles[t];
+            let beyond = (0..3).find(|&k| side(v[(k + 1) % 3], v[(k + 2) % 3]) < 0.0);
+            match beyond.and_then(|k| neighbours[k]) {
+                Some(next) => t = next,
+                None => return t,
+            }
+        }
+        self.triangles
+            .iter()
+            .position(|t| {
+                t.alive
+                    && (0..3).all(|k| side(t.vertices[(k + 1) % 3], t.vertices[(k + 2) % 3]) >= 0.0)
+            })
+            .unwrap_or(t)
+    }
+}
+
+/// True if `p` lies strictly inside the circumcircle of triangle `t`
+fn in_circumcircle(vertices: &[(f64, f64)], t: &[usize; 3], p: (f64, f64)) -> bool {
+    let (mut a, mut b, c) = (vertices[t[0]], vertices[t[1]], vertices[t[2]]);
+    // The determinant test needs a counter-clockwise triangle
+    let orientation = (b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0);
+    if orientation < 0.0 {
+        std::mem::swap(&mut a, &mut b);
+    }
+    let (ax, ay) = (a.0 - p.0, a.1 - p.1);
+    let (bx, by) = (b.0 - p.0, b.1 - p.1);
+    let (cx, cy) = (c.0 - p.0, c.1 - p.1);
+    let det = (ax * ax + ay * ay) * (bx * cy - cx * by) - (bx * bx + by * by) * (ax * cy - cx * ay)
+        + (cx * cx + cy * cy) * (ax * by - bx * ay);
+    det > 0.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::{
+        EntityId, ExactDepths, ExactPositions, FeatureAttributes, FeatureMeta, FeaturePointers,
+        SpatialRef, VectorMeta,
+    };
+    use crate::sounding::OBJL_SOUNDG;
+    use num_bigint::BigInt;
+    use num_rational::BigRational;
+    use s57_parse::bitstring::{FoidKey, NameKey};
+
+    fn int(v: i64) -> BigRational {
+        BigInt::from(v).into()
+    }
+
+    /// Feature over one vector holding `points` (lat, lon), with depths for
+    /// a sounding
+    fn add_feature(
+        world: &mut World,
+        id: u32,
+        objl: u16,
+        points: &[(i64, i64)],
+        depths: Option<&[i64]>,
+        attf: &[(u16, &str)],
+    ) -> EntityId {
+        let vector = world.create_entity(EntityType::Vector);
+        let name = NameKey {
+            rcnm: if depths.is_some() { 110 } else { 130 },
+            rcid: id,
+        };
+        world.name_index.insert(name, vector);
+        world.vector_meta.insert(
+            vector,
+            VectorMeta {
+                name,
+                rver: 1,
+                ruin: 1,
+            },
+        );
+        world.exact_positions.insert(
+            vector,
+            ExactPositions {
+                lat: points.iter().map(|p| int(p.0)).collect(),
+                lon: points.iter().map(|p| int(p.1)).collect(),
+            },
+        );
+        if let Some(depths) = depths {
+            world.exact_depths.insert(
+                vector,
+                ExactDepths {
+                    depth: depths.iter().map(|d| int(*d)).collect(),
+                    units: 1,
+                },
+            );
+        }
+
+        let feature = world.create_entity(EntityType::Feature);
+        let foid = FoidKey {
+            agen: 550,
+            fidn: id,
+            fids: 1,
+        };
+        world.foid_index.insert(foid, feature);
+        let prim = if depths.is_some() { 1 } else { 3 };
+        world.feature_meta.insert(
+            feature,
+            FeatureMeta {
+                foid,
+                prim,
+                grup: 2,
+                objl,
+                rver: 1,
+                ruin: 1,
+            },
+        );
+        world.feature_attributes.insert(
+            feature,
+            FeatureAttributes {
+                attf: attf.iter().map(|(a, v)| (*a, v.to_string())).collect(),
+                natf: Vec::new(),
+            },
+        );
+        world.feature_pointers.insert(
+            feature,
+            FeaturePointers {
+                related_features: vec![],
+                spatial_refs: vec![SpatialRef {
+                    entity: vector,
+                    ornt: 1,
+                    usag: if prim == 3 { 1 } else { 255 },
+                    mask: 255,
+                }],
+            },
+        );
+        feature
+    }
+
+    /// Soundings on the plane depth = 10 + lon over a 4 x 4 degree square
+    fn soundings_world() -> World {
+        let mut world = World::new();
+        let points = [(0, 0), (0, 4), (4, 0), (4, 4), (2, 2), (1, 3)];
+        let depths: Vec<i64> = points.iter().map(|p| 10 + p.1).collect();
+        add_feature(&mut world, 1, OBJL_SOUNDG, &points, Some(&depths), &[]);
+        world
+    }
+
+    fn rect(min_lon: i64, max_lon: i64) -> Vec<(i64, i64)> {
+        vec![
+            (0, min_lon),
+            (0, max_lon),
+            (4, max_lon),
+            (4, min_lon),
+            (0, min_lon),
+        ]
+    }
+
+    #[test]
+    fn test_interpolation() {
+        let world = soundings_world();
+
+        // A TIN reproduces a plane exactly
+        let options = GridOptions::new()
+            .resolution(1.0)
+            .interpolation(Interpolation::Tin);
+        let grid = DepthGrid::build(&world, &options).unwrap();
+        assert_eq!((grid.width, grid.height), (4, 4));
+        for row in 0..4 {
+            for column in 0..4 {
+                let (lon, _) = grid.cell_centre(column, row);
+                let depth = grid.get(column, row).unwrap();
+                assert!((depth as f64 - (10.0 + lon)).abs() < 1e-4);
+            }
+        }
+
+        // IDW stays within the soundings and gets deeper eastwards
+        let grid = DepthGrid::build(&world, &GridOptions::new().resolution(1.0)).unwrap();
+        let column_mean = |column| {
+            (0..4)
+                .map(|row| grid.get(column, row).unwrap())
+                .sum::<f32>()
+                / 4.0
+        };
+        assert!(grid.values.iter().all(|v| (10.0..=14.0).contains(v)));
+        assert!(column_mean(0) < column_mean(3));
+
+        // Depth areas clamp, fill and mask
+        let mut world = soundings_world();
+        add_feature(
+            &mut world,
+            2,
+            OBJL_DEPARE,
+            &rect(0, 2),
+            None,
+            &[(ATTL_DRVAL1, "0"), (ATTL_DRVAL2, "11")],
+        );
+        add_feature(
+            &mut world,
+            3,
+            OBJL_DEPARE,
+            &rect(2, 3),
+            None,
+            &[(ATTL_DRVAL1, "15")],
+        );
+        let grid = DepthGrid::build(&world, &options).unwrap();
+        let row: Vec<Option<f32>> = (0..4).map(|column| grid.get(column, 1)).collect();
+        assert_eq!(row, vec![Some(10.5), Some(11.0), Some(15.0), None]);
+
+        // No soundings: depth areas give their shoalest depth
+        let mut world = World::new();
+        add_feature(
+            &mut world,
+            1,
+            OBJL_DEPARE,
+            &rect(0, 4),
+            None,
+            &[(ATTL_DRVAL1, "5")],
+        );
+        let grid = DepthGrid::build(&world, &options).unwrap();
+        assert!(grid.values.iter().all(|v| *v == 5.0));
+
+        assert_eq!(
+            DepthGrid::build(&World::new(), &options),
+            Err(GridError::NoData)
+        );
+        assert!(matches!(
+            DepthGrid::build(&world, &GridOptions::new().resolution(1e-6)),
+            Err(GridError::TooLarge { .. })
+        ));
+    }
+
+    #[test]
+    fn test_grid_output() {
+        let mut grid = DepthGrid::build(
+            &soundings_world(),
+            &GridOptions::new()
+                .resolution(2.0)
+                .interpolation(Interpolation::Tin),
+        )
+        .unwrap();
+        grid.values[3] = f32::NAN;
+
+        let xyz = grid.to_xyz();
+        let lines: Vec<&str> = xyz.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], "1 3 11");
+
+        let tiff = grid.to_geotiff();
+        assert_eq!(&tiff[..4], b"II*\0");
+        let u16_at = |i: usize| u16::from_le_bytes([tiff[i], tiff[i + 1]]);
+        let u32_at = |i: usize| u32::from_le_bytes(tiff[i..i + 4].try_into().unwrap());
+        let entries = u16_at(8) as usize;
+        let tag = |wanted: u16| {
+            (0..entries)
+                .map(|e| 10 + e * 12)
+                .find(|&at| u16_at(at) == wanted)
+                .map(|at| u32_at(at + 8))
+                .unwrap()
+        };
+        assert_eq!((tag(256), tag(257)), (2, 2));
+        let pixels = tag(273) as usize;
+        assert_eq!(tiff.len(), pixels + 16);
+        assert_eq!(
+            f32::from_le_bytes(tiff[pixels..pixels + 4].try_into().unwrap()),
+            11.0
+        );
+        assert!(f32::from_le_bytes(tiff[pixels + 12..].try_into().unwrap()).is_nan());
+
+        // Tie point: the grid's north-west corner
+        let tiepoint = tag(33922) as usize;
+        let double = |i: usize| f64::from_le_bytes(tiff[i..i + 8].try_into().unwrap());
+        assert_eq!((double(tiepoint + 24), double(tiepoint + 32)), (0.0, 4.0));
+    }
+
+    #[test]
+    fn test_delaunay() {
+        // A 12 x 12 lattice, the inner points jittered, plus repeats
+        let mut seed = 1u64;
+        let mut jitter = || {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+            ((seed >> 33) as f64 / (1u64 << 31) as f64 - 0.5) * 0.6
+        };
+        let mut points = Vec::new();
+        for row in 0..12 {
+            for column in 0..12 {
+                let (x, y) = (column as f64, row as f64);
+                if (1..11).contains(&row) && (1..11).contains(&column) {
+                    points.push((x + jitter(), y + jitter()));
+                } else {
+                    points.push((x, y));
+                }
+            }
+        }
+        points.extend([points[0], points[50]]);
+
+        // Counter-clockwise triangles tiling the square, 2n - 2 - b of them
+        // for n points with b on the boundary, none with a point inside its
+        // circumcircle
+        let triangles = delaunay(&points);
+        assert_eq!(triangles.len(), 2 * 144 - 2 - 44);
+        let mut area = 0.0;
+        for t in &triangles {
+            let [a, b, c] = t.map(|v| points[v]);
+            let twice = (b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0);
+            assert!(twice > 0.0);
+            area += twice / 2.0;
+            assert!(!points.iter().any(|&p| in_circumcircle(&points, t, p)));
+            assert!(t.iter().all(|&v| v < 144));
+        }
+        assert!((area - 121.0).abs() < 1e-9);
+
+        assert!(delaunay(&points[..2]).is_empty());
+        assert_eq!(delaunay(&[(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)]).len(), 1);
+    }
+}
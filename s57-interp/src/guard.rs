@@ -0,0 +1,528 @@
+//! Own-ship safety check (ECDIS guard zone)
+//!
+//! `TraversalContext::check_own_ship` looks ahead of the ship along its
+//! course for as far as it will travel in the look-ahead time, across a
+//! corridor of the guard zone's width, and reports what lies in it:
+//! - water shallower than the safety depth (draft plus under-keel
+//!   clearance): DEPARE/DRGARE areas whose DRVAL1 is above it, and land
+//! - isolated dangers (OBSTRN, UWTROC, WRECKS) whose VALSOU is shallower
+//!   than the safety depth, or unknown
+//! - restricted areas (RESARE, MIPARE); entry prohibited or area to be
+//!   avoided (RESTRN 7, 14) raises an alarm, other restrictions a caution
+//!
+//! As in `hazard`, the corridor is measured in metres on a local
+//! equirectangular projection around the ship; areas are tested against it
+//! edge by edge, so one narrower than the vertex spacing isn't missed.
+//! Depths are taken to be in metres (DUNI 1).
+
+use crate::depth::{DepthBand, DepthThresholds};
+use crate::ecs::{parse_decimal, EntityId, EntityType};
+use crate::geometry_cache::ResolvedGeometry;
+use crate::hazard::{feature_vertices, EARTH_RADIUS_M, HAZARD_CLASSES};
+use crate::topology::{Ring, TraversalContext};
+use num_rational::BigRational;
+use num_traits::ToPrimitive;
+use std::fmt;
+
+/// DEPARE: Depth area
+const OBJL_DEPARE: u16 = 42;
+/// DRGARE: Dredged area
+const OBJL_DRGARE: u16 = 46;
+/// LNDARE: Land area
+const OBJL_LNDARE: u16 = 71;
+/// MIPARE: Military practice area
+const OBJL_MIPARE: u16 = 83;
+/// RESARE: Restricted area
+const OBJL_RESARE: u16 = 112;
+/// DRVAL1 / DRVAL2: Depth range values
+const ATTL_DRVAL1: u16 = 87;
+const ATTL_DRVAL2: u16 = 88;
+/// RESTRN: Restriction
+const ATTL_RESTRN: u16 = 131;
+/// VALSOU: Value of sounding
+const ATTL_VALSOU: u16 = 179;
+/// RESTRN values that forbid entry: entry prohibited, area to be avoided
+const PROHIBITED: [&str; 2] = ["7", "14"];
+
+/// Metres per second in one knot
+const KNOT_MS: f64 = 1852.0 / 3600.0;
+
+/// OwnShip: Position and motion of the ship being checked
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OwnShip {
+    /// Latitude in degrees
+    pub lat: f64,
+    /// Longitude in degrees
+    pub lon: f64,
+    /// Course over ground in degrees true
+    pub course: f64,
+    /// Speed over ground in knots
+    pub speed: f64,
+    /// Draft in metres
+    pub draft: f64,
+}
+
+/// GuardZone: Size of the area checked ahead of the ship
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GuardZone {
+    /// How far ahead to look, in seconds of travel
+    pub look_ahead_s: f64,
+    /// Half the corridor width, in metres either side of the track
+    pub half_width_m: f64,
+    /// Clearance wanted under the keel, in metres, added to the draft
+    pub under_keel_clearance_m: f64,
+}
+
+impl Default for GuardZone {
+    /// Six minutes ahead, 50 m either side, no extra clearance
+    fn default() -> Self {
+        Self {
+            look_ahead_s: 360.0,
+            half_width_m: 50.0,
+            under_keel_clearance_m: 0.0,
+        }
+    }
+}
+
+/// AlertLevel: How urgently an alert must be brought to the mariner
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AlertLevel {
+    /// Needs immediate attention
+    Alarm,
+    /// Worth attention
+    Caution,
+}
+
+/// AlertKind: What the guard zone found
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AlertKind {
+    /// Water shallower than the safety depth, or land
+    SafetyContour,
+    /// Obstruction, rock or wreck shallower than the safety depth
+    IsolatedDanger,
+    /// Area with entry prohibited or to be avoided
+    ProhibitedArea,
+    /// Other restricted or military practice area
+    RestrictedArea,
+}
+
+impl AlertKind {
+    /// Level of an alert of this kind
+    pub fn level(self) -> AlertLevel {
+        match self {
+            AlertKind::RestrictedArea => AlertLevel::Caution,
+            _ => AlertLevel::Alarm,
+        }
+    }
+}
+
+impl fmt::Display for AlertKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            AlertKind::SafetyContour => "crossing safety contour",
+            AlertKind::IsolatedDanger => "isolated danger",
+            AlertKind::ProhibitedArea => "prohibited area",
+            AlertKind::RestrictedArea => "restricted area",
+        })
+    }
+}
+
+/// SafetyAlert: A feature in the guard zone
+#[derive(Debug, Clone, PartialEq)]
+pub struct SafetyAlert {
+    /// Feature that raised the alert
+    pub feature: EntityId,
+    /// Object class code of the feature
+    pub objl: u16,
+    /// What was found
+    pub kind: AlertKind,
+    /// Distance along the track to where the ship reaches it (metres; 0 if
+    /// the ship is already in it)
+    pub distance_m: f64,
+    /// Time until the ship reaches it (seconds), None when not under way
+    pub time_s: Option<f64>,
+}
+
+impl SafetyAlert {
+    /// Level of the alert
+    pub fn level(&self) -> AlertLevel {
+        self.kind.level()
+    }
+}
+
+/// Ship-relative frame: metres along the course and to starboard of it
+struct Frame {
+    lat: f64,
+    lon: f64,
+    cos_lat: f64,
+    sin_course: f64,
+    cos_course: f64,
+    length: f64,
+    half_width: f64,
+}
+
+impl Frame {
+    fn new(ship: &OwnShip, zone: &GuardZone) -> Self {
+        let course = ship.course.to_radians();
+        Self {
+            lat: ship.lat,
+            lon: ship.lon,
+            cos_lat: ship.lat.to_radians().cos(),
+            sin_course: course.sin(),
+            cos_course: course.cos(),
+            length: ship.speed.max(0.0) * KNOT_MS * zone.look_ahead_s,
+            half_width: zone.half_width_m,
+        }
+    }
+
+    /// (along, across) in metres of a (lat, lon) position
+    fn project(&self, (lat, lon): (f64, f64)) -> (f64, f64) {
+        let east = (lon - self.lon).to_radians() * self.cos_lat * EARTH_RADIUS_M;
+        let north = (lat - self.lat).to_radians() * EARTH_RADIUS_M;
+        (
+            east * self.sin_course + north * self.cos_course,
+            east * self.cos_course - north * self.sin_course,
+        )
+    }
+
+    /// Along-track distance of a point inside the corridor
+    fn point(&self, (along, across): (f64, f64)) -> Option<f64> {
+        (0.0 <= along && along <= self.length && across.abs() <= self.half_width).then_some(along)
+    }
+
+    /// Smallest along-track distance of the part of a segment in the corridor
+    fn segment(&self, a: (f64, f64), b: (f64, f64)) -> Option<f64> {
+        let (mut enter, mut exit) = (0.0f64, 1.0f64);
+        let limits = [
+            (a.0, b.0 - a.0, 0.0, self.length),
+            (a.1, b.1 - a.1, -self.half_width, self.half_width),
+        ];
+        for (start, delta, low, high) in limits {
+            if delta == 0.0 {
+                if start < low || start > high {
+                    return None;
+                }
+                continue;
+            }
+            let (t_low, t_high) = ((low - start) / delta, (high - start) / delta);
+            enter = enter.max(t_low.min(t_high));
+            exit = exit.min(t_low.max(t_high));
+            if enter > exit {
+                return None;
+            }
+        }
+        let along = |t: f64| a.0 + t * (b.0 - a.0);
+        Some(along(enter).min(along(exit)))
+    }
+
+    /// Smallest along-track distance of any edge of some polylines in the
+    /// corridor; rings are closed back to their first point
+    fn edges<'r>(&self, lines: impl IntoIterator<Item = &'r Ring>, closed: bool) -> Option<f64> {
+        let project = |(lat, lon): &(BigRational, BigRational)| {
+            Some(self.project((lat.to_f64()?, lon.to_f64()?)))
+        };
+        lines
+            .into_iter()
+            .flat_map(|line| {
+                let points: Vec<(f64, f64)> = line.iter().filter_map(project).collect();
+                let closing = points
+                    .last()
+                    .copied()
+                    .zip(points.first().copied())
+                    .filter(|_| closed);
+                points
+                    .windows(2)
+                    .map(|w| (w[0], w[1]))
+                    .chain(closing)
+                    .filter_map(|(a, b)| self.segment(a, b))
+                    .collect::<Vec<_>>()
+            })
+            .min_by(f64::total_cmp)
+    }
+}
+
+impl TraversalContext<'_> {
+    /// Dangers and restricted areas in the guard zone ahead of the ship
+    ///
+    /// Each feature is reported once, where the ship first reaches it.
+    /// Results are ordered by distance, alarms before cautions at the same
+    /// distance.
+    pub fn check_own_ship(&self, ship: &OwnShip, zone: &GuardZone) -> Vec<SafetyAlert> {
+        let world = self.world;
+        let frame = Frame::new(ship, zone);
+        let safety_depth = ship.draft + zone.under_keel_clearance_m;
+        let thresholds = BigRational::from_float(safety_depth).map(DepthThresholds::new);
+        let position = BigRational::from_float(ship.lat).zip(BigRational::from_float(ship.lon));
+
+        let mut alerts = Vec::new();
+        for feature in world.entities_of_type(EntityType::Feature) {
+            let Some(meta) = world.feature_meta.get(&feature) else {
+                continue;
+            };
+            let attrs = world.feature_attributes.get(&feature);
+            let value = |attl| attrs.and_then(|a| a.get(attl));
+
+            let kind = match meta.objl {
+                OBJL_LNDARE => AlertKind::SafetyContour,
+                OBJL_DEPARE | OBJL_DRGARE => {
+                    let Some(thresholds) = &thresholds else {
+                        continue;
+                    };
+                    let drval1 = value(ATTL_DRVAL1).and_then(parse_decimal);
+                    let drval2 = value(ATTL_DRVAL2).and_then(parse_decimal);
+                    match DepthBand::classify(drval1.as_ref(), drval2.as_ref(), thresholds) {
+                        DepthBand::Dry | DepthBand::Shallow => AlertKind::SafetyContour,
+                        DepthBand::Safe | DepthBand::Deep => continue,
+                    }
+                }
+                OBJL_RESARE | OBJL_MIPARE => {
+                    let restrictions = value(ATTL_RESTRN).unwrap_or_default();
+                    if restrictions
+                        .split(',')
+                        .any(|r| PROHIBITED.contains(&r.trim()))
+                    {
+                        AlertKind::ProhibitedArea
+                    } else {
+                        AlertKind::RestrictedArea
+                    }
+                }
+                objl if HAZARD_CLASSES.contains(&objl) => {
+                    let valsou = value(ATTL_VALSOU)
+                        .and_then(parse_decimal)
+                        .and_then(|v| v.to_f64());
+                    if valsou.is_some_and(|depth| depth >= safety_depth) {
+                        continue;
+                    }
+                    AlertKind::IsolatedDanger
+                }
+                _ => continue,
+            };
+
+            let distance = match self.feature_geometry(feature) {
+                Ok(Some(geometry)) => match geometry.as_ref() {
+                    ResolvedGeometry::Area(boundary) => {
+                        if position.as_ref().is_some_and(|p| boundary.contains(p)) {
+                            Some(0.0)
+                        } else {
+                            frame.edges(boundary.exterior.iter().chain(&boundary.interior), true)
+                        }
+                    }
+                    ResolvedGeometry::Lines(lines) => frame.edges(lines, false),
+                },
+                Ok(None) => feature_vertices(world, feature)
+                    .into_iter()
+                    .filter_map(|point| frame.point(frame.project(point)))
+                    .min_by(f64::total_cmp),
+                Err(_) => None,
+            };
+            let Some(distance_m) = distance else {
+                continue;
+            };
+            let speed = ship.speed * KNOT_MS;
+            alerts.push(SafetyAlert {
+                feature,
+                objl: meta.objl,
+                kind,
+                distance_m,
+                time_s: (speed > 0.0).then(|| distance_m / speed),
+            });
+        }
+
+        alerts.sort_by(|a, b| {
+            a.distance_m
+                .total_cmp(&b.distance_m)
+                .then(a.level().cmp(&b.level()))
+        });
+        alerts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::{
+        ExactPositions, FeatureAttributes, FeatureMeta, FeaturePointers, SpatialRef, VectorMeta,
+        World,
+    };
+    use num_bigint::BigInt;
+    use s57_parse::bitstring::{FoidKey, NameKey};
+
+    /// Exact degrees from millidegrees
+    fn mdeg(v: i64) -> BigRational {
+        BigRational::new(BigInt::from(v), BigInt::from(1000))
+    }
+
+    /// Feature on one vector through `points` (lat, lon in millidegrees):
+    /// a node for points, a closed edge for areas
+    fn add_feature(
+        world: &mut World,
+        id: u32,
+        objl: u16,
+        points: &[(i64, i64)],
+        attf: &[(u16, &str)],
+    ) -> EntityId {
+        let area = points.len() > 1;
+        let vector = world.create_entity(EntityType::Vector);
+        let name = NameKey {
+            rcnm: if area { 130 } else { 110 },
+            rcid: id,
+        };
+        world.name_index.insert(name, vector);
+        world.vector_meta.insert(
+            vector,
+            VectorMeta {
+                name,
+                rver: 1,
+                ruin: 1,
+            },
+        );
+        world.exact_positions.insert(
+            vector,
+            ExactPositions {
+                lat: points.iter().map(|p| mdeg(p.0)).collect(),
+                lon: points.iter().map(|p| mdeg(p.1)).collect(),
+            },
+        );
+
+        let feature = world.create_entity(EntityType::Feature);
+        let foid = FoidKey {
+            agen: 550,
+            fidn: id,
+            fids: 1,
+        };
+        world.foid_index.insert(foid, feature);
+        world.feature_meta.insert(
+            feature,
+            FeatureMeta {
+                foid,
+                prim: if area { 3 } else { 1 },
+                grup: 1,
+                objl,
+                rver: 1,
+                ruin: 1,
+            },
+        );
+        world.feature_attributes.insert(
+            feature,
+            FeatureAttributes {
+                attf: attf.iter().map(|(a, v)| (*a, v.to_string())).collect(),
+                natf: Vec::new(),
+            },
+        );
+        world.feature_pointers.insert(
+            feature,
+            FeaturePointers {
+                related_features: vec![],
+                spatial_refs: vec![SpatialRef {
+                    entity: vector,
+                    ornt: 1,
+                    usag: 1,
+                    mask: 255,
+                }],
+            },
+        );
+        feature
+    }
+
+    fn square(lat: (i64, i64), lon: (i64, i64)) -> [(i64, i64); 5] {
+        [
+            (lat.0, lon.0),
+            (lat.0, lon.1),
+            (lat.1, lon.1),
+            (lat.1, lon.0),
+            (lat.0, lon.0),
+        ]
+    }
+
+    #[test]
+    fn test_check_own_ship() {
+        let mut world = World::new();
+        // Deep water all around the ship: safe
+        add_feature(
+            &mut world,
+            1,
+            OBJL_DEPARE,
+            &square((-50, 50), (-50, 50)),
+            &[(ATTL_DRVAL1, "20"), (ATTL_DRVAL2, "50")],
+        );
+        // Shoal dead ahead, 10-20 mdeg north
+        let shoal = add_feature(
+            &mut world,
+            2,
+            OBJL_DEPARE,
+            &square((10, 20), (-5, 5)),
+            &[(ATTL_DRVAL1, "2"), (ATTL_DRVAL2, "5")],
+        );
+        // Prohibited area whose western edge is 111 m east of the track
+        let prohibited = add_feature(
+            &mut world,
+            3,
+            OBJL_RESARE,
+            &square((5, 8), (1, 3)),
+            &[(ATTL_RESTRN, "7")],
+        );
+        // Practice area beyond the look-ahead distance
+        add_feature(&mut world, 4, OBJL_MIPARE, &square((30, 40), (-5, 5)), &[]);
+        // Deep wreck and rock of unknown depth on the track
+        add_feature(&mut world, 5, 159, &[(3, 0)], &[(ATTL_VALSOU, "12")]);
+        let rock = add_feature(&mut world, 6, 153, &[(4, 0)], &[]);
+        // Obstruction 222 m abeam, rock astern
+        add_feature(&mut world, 7, 86, &[(4, 2)], &[(ATTL_VALSOU, "1")]);
+        add_feature(&mut world, 8, 153, &[(-2, 0)], &[]);
+        let ctx = TraversalContext::new(&world);
+
+        // Due north at 10 knots: 1852 m (about 16.7 mdeg) in six minutes
+        let ship = OwnShip {
+            lat: 0.0,
+            lon: 0.0,
+            course: 0.0,
+            speed: 10.0,
+            draft: 5.0,
+        };
+        let alerts = ctx.check_own_ship(&ship, &GuardZone::default());
+        let found: Vec<(EntityId, AlertKind)> =
+            alerts.iter().map(|a| (a.feature, a.kind)).collect();
+        assert_eq!(
+            found,
+            [
+                (rock, AlertKind::IsolatedDanger),
+                (shoal, AlertKind::SafetyContour),
+            ]
+        );
+        assert!((alerts[0].distance_m - 444.8).abs() < 1.0);
+        assert!((alerts[0].time_s.unwrap() - 444.8 / KNOT_MS / 10.0).abs() < 1.0);
+        assert_eq!(alerts[1].level(), AlertLevel::Alarm);
+
+        // A wider zone takes in the prohibited area
+        let wide = GuardZone {
+            half_width_m: 150.0,
+            ..GuardZone::default()
+        };
+        let alerts = ctx.check_own_ship(&ship, &wide);
+        assert_eq!(alerts.len(), 3);
+        assert_eq!(alerts[1].feature, prohibited);
+        assert_eq!(alerts[1].kind, AlertKind::ProhibitedArea);
+
+        // More clearance makes the deep water around the ship unsafe
+        let clearance = GuardZone {
+            under_keel_clearance_m: 16.0,
+            ..GuardZone::default()
+        };
+        let alerts = ctx.check_own_ship(&ship, &clearance);
+        assert_eq!(alerts[0].distance_m, 0.0);
+        assert_eq!(alerts[0].kind, AlertKind::SafetyContour);
+
+        // Heading south, stopped: nothing ahead and no time to go
+        let stopped = OwnShip {
+            course: 180.0,
+            speed: 0.0,
+            ..ship
+        };
+        assert!(ctx
+            .check_own_ship(&stopped, &GuardZone::default())
+            .is_empty());
+        let alerts = ctx.check_own_ship(&stopped, &clearance);
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].time_s, None);
+    }
+}
@@ -0,0 +1,231 @@
+//! Route hazard scan
+//!
+//! Checks a route from the mariner overlay against the chart for isolated
+//! dangers (wrecks, obstructions, underwater rocks) lying within a corridor
+//! either side of each leg.
+//!
+//! Distances are in metres on a local equirectangular projection around
+//! each leg. That is accurate to well under a percent for the leg lengths
+//! and corridor widths used in passage planning, and it is the one place
+//! here where exact coordinates are converted to f64.
+
+use crate::ecs::{EntityId, EntityType, ExactPositions, World};
+use crate::overlay::MarinerObject;
+use num_traits::ToPrimitive;
+
+/// Mean Earth radius (metres)
+pub(crate) const EARTH_RADIUS_M: f64 = 6_371_008.8;
+
+/// Object classes treated as hazards: OBSTRN, UWTROC, WRECKS
+pub const HAZARD_CLASSES: [u16; 3] = [86, 153, 159];
+
+/// RouteHazard: A hazard found near a route leg
+#[derive(Debug, Clone, PartialEq)]
+pub struct RouteHazard {
+    /// Hazard feature entity
+    pub feature: EntityId,
+    /// Object class code of the feature
+    pub objl: u16,
+    /// 0-based index of the closest leg (leg i runs from point i to i+1)
+    pub leg: usize,
+    /// Distance from the leg to the closest vertex of the hazard (metres)
+    pub distance_m: f64,
+    /// Position of that vertex (degrees)
+    pub lat: f64,
+    /// Position of that vertex (degrees)
+    pub lon: f64,
+}
+
+/// Find hazards within `corridor_m` metres of any leg of `route`
+///
+/// A hazard is measured by its closest vertex, so line and area hazards are
+/// approximated by their outlines' vertices. Each feature is reported once,
+/// against its closest leg. Results are ordered by leg, then distance.
+pub fn scan_route(world: &World, route: &MarinerObject, corridor_m: f64) -> Vec<RouteHazard> {
+    let (lat, lon) = route.positions.to_f64();
+    let legs: Vec<((f64, f64), (f64, f64))> = lat
+        .iter()
+        .zip(lon.iter())
+        .map(|(lat, lon)| (*lat, *lon))
+        .collect::<Vec<_>>()
+        .windows(2)
+        .map(|w| (w[0], w[1]))
+        .collect();
+    if legs.is_empty() {
+        return Vec::new();
+    }
+
+    let mut hazards = Vec::new();
+    for feature in world.entities_of_type(EntityType::Feature) {
+        let Some(meta) = world.feature_meta.get(&feature) else {
+            continue;
+        };
+        if !HAZARD_CLASSES.contains(&meta.objl) {
+            continue;
+        }
+
+        let mut closest: Option<RouteHazard> = None;
+        for point in feature_vertices(world, feature) {
+            for (leg, (a, b)) in legs.iter().enumerate() {
+                let distance_m = distance_to_leg(point, *a, *b);
+                if distance_m <= corridor_m
+                    && closest.as_ref().is_none_or(|c| distance_m < c.distance_m)
+                {
+                    closest = Some(RouteHazard {
+                        feature,
+                        objl: meta.objl,
+                        leg,
+                        distance_m,
+                        lat: point.0,
+                        lon: point.1,
+                    });
+                }
+            }
+        }
+        hazards.extend(closest);
+    }
+
+    hazards.sort_by(|a, b| {
+        a.leg
+            .cmp(&b.leg)
+            .then(a.distance_m.total_cmp(&b.distance_m))
+    });
+    hazards
+}
+
+/// All (lat, lon) vertices of the vectors a feature is built from
+pub(crate) fn feature_vertices(world: &World, feature: EntityId) -> Vec<(f64, f64)> {
+    let mut points = Vec::new();
+    let mut add = |positions: &ExactPositions| {
+        for (lat, lon) in positions.lat.iter().zip(positions.lon.iter()) {
+            if let (Some(lat), Some(lon)) = (lat.to_f64(), lon.to_f64()) {
+                points.push((lat, lon));
+            }
+        }
+    };
+
+    let Some(pointers) = world.feature_pointers.get(&feature) else {
+        return points;
+    };
+    for sref in &pointers.spatial_refs {
+        if let Some(positions) = world.exact_positions.get(&sref.entity) {
+            add(positions);
+        }
+        if let Some(topology) = world.vector_topology.get(&sref.entity) {
+            for neighbor in &topology.neighbors {
+                if let Some(positions) = world.exact_positions.get(&neighbor.entity) {
+                    add(positions);
+                }
+            }
+        }
+    }
+    points
+}
+
+/// Distance in metres from point `p` to the segment `a`-`b` (all (lat, lon))
+fn distance_to_leg(p: (f64, f64), a: (f64, f64), b: (f64, f64)) -> f64 {
+    // Project to metres around the leg's start
+    let cos_lat = a.0.to_radians().cos();
+    let project = |(lat, lon): (f64, f64)| {
+        (
+            (lon - a.1).to_radians() * cos_lat * EARTH_RADIUS_M,
+            (lat - a.0).to_radians() * EARTH_RADIUS_M,
+        )
+    };
+    let (px, py) = project(p);
+    let (bx, by) = project(b);
+
+    let length_sq = bx * bx + by * by;
+    let t = if length_sq == 0.0 {
+        0.0
+    } else {
+        ((px * bx + py * by) / length_sq).clamp(0.0, 1.0)
+    };
+    (px - t * bx).hypot(py - t * by)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::{FeatureMeta, FeaturePointers, SpatialRef};
+    use crate::overlay::Overlay;
+    use num_bigint::BigInt;
+    use num_rational::BigRational;
+    use s57_parse::bitstring::FoidKey;
+    use smallvec::smallvec;
+
+    /// Exact degrees from millidegrees
+    fn mdeg(v: i64) -> BigRational {
+        BigRational::new(BigInt::from(v), BigInt::from(1000))
+    }
+
+    fn add_point_feature(world: &mut World, fidn: u32, objl: u16, lat: i64, lon: i64) {
+        let node = world.create_entity(EntityType::Vector);
+        world.exact_positions.insert(
+            node,
+            ExactPositions {
+                lat: smallvec![mdeg(lat)],
+                lon: smallvec![mdeg(lon)],
+            },
+        );
+        let feature = world.create_entity(EntityType::Feature);
+        world.feature_meta.insert(
+            feature,
+            FeatureMeta {
+                foid: FoidKey {
+                    agen: 550,
+                    fidn,
+                    fids: 1,
+                },
+                prim: 1,
+                grup: 2,
+                objl,
+                rver: 1,
+                ruin: 1,
+            },
+        );
+        world.feature_pointers.insert(
+            feature,
+            FeaturePointers {
+                related_features: Vec::new(),
+                spatial_refs: vec![SpatialRef {
+                    entity: node,
+                    ornt: 255,
+                    usag: 255,
+                    mask: 255,
+                }],
+            },
+        );
+    }
+
+    #[test]
+    fn test_scan_route_finds_hazards_in_corridor() {
+        let mut world = World::new();
+        // Route runs east along the equator from 0 to 0.1 degrees longitude
+        add_point_feature(&mut world, 1, 159, 1, 50); // wreck ~111 m north of leg 0
+        add_point_feature(&mut world, 2, 86, 10, 50); // obstruction ~1.1 km away
+        add_point_feature(&mut world, 3, 75, 0, 50); // LIGHTS on the track: not a hazard
+        add_point_feature(&mut world, 4, 153, 0, 150); // rock on leg 1
+
+        let mut overlay = Overlay::new();
+        overlay.add_route(
+            "Test",
+            vec![
+                (mdeg(0), mdeg(0)),
+                (mdeg(0), mdeg(100)),
+                (mdeg(0), mdeg(200)),
+            ],
+        );
+        let route = &overlay.objects[0];
+
+        let hazards = scan_route(&world, route, 500.0);
+        assert_eq!(hazards.len(), 2);
+        assert_eq!((hazards[0].objl, hazards[0].leg), (159, 0));
+        assert!((hazards[0].distance_m - 111.2).abs() < 1.0);
+        assert_eq!((hazards[1].objl, hazards[1].leg), (153, 1));
+        assert!(hazards[1].distance_m < 1e-6);
+
+        // Wider corridor picks up the obstruction too
+        assert_eq!(scan_route(&world, route, 2000.0).len(), 3);
+    }
+}
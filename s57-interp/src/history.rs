@@ -0,0 +1,310 @@
+//! Edition and update history of a cell
+//!
+//! An ENC archive keeps every edition of a cell (`NAME.000`, usually in a
+//! directory per edition) along with the updates issued for it (`NAME.001`,
+//! `NAME.002`, ...). `cell_history` finds them all under a directory, orders
+//! them by DSID edition and update number, replays them the way an ECDIS
+//! would, and reports what each step changed.
+//!
+//! A re-issue (a `.000` that already includes updates) is ordered after the
+//! update it includes, so an exact re-issue shows no changes.
+
+use crate::diff::WorldDiff;
+use crate::ecs::World;
+use crate::{build_world_with_options, ParseOptions};
+use s57_parse::update::DatasetId;
+use s57_parse::{ParseError, ParseErrorKind, Result, S57File};
+use std::path::{Path, PathBuf};
+
+/// HistoryEntry: One base cell or update file in a cell's history
+#[derive(Debug, Clone)]
+pub struct HistoryEntry {
+    pub path: PathBuf,
+    /// DSID EDTN
+    pub edition: u32,
+    /// DSID UPDN; 0 for a new edition
+    pub update: u32,
+    /// DSID ISDT (CCYYMMDD)
+    pub issue_date: String,
+    /// DSID UADT (CCYYMMDD); may be empty for updates
+    pub application_date: String,
+    /// Features changed relative to the previous entry; for the first
+    /// entry every feature counts as added
+    pub changes: WorldDiff,
+}
+
+impl HistoryEntry {
+    /// True for a base cell (new edition or re-issue), false for an update
+    pub fn is_base(&self) -> bool {
+        is_base(&self.path)
+    }
+}
+
+/// Timeline of a cell from every edition and update under `dir`
+///
+/// Files are matched by stem (case-insensitively) and a three-digit
+/// extension, searching `dir` recursively. Updates are applied strictly, as
+/// by `S57File::apply_update`: a missing update or one for another edition
+/// is an error naming the file.
+pub fn cell_history(dir: &Path, cell: &str, options: &ParseOptions) -> Result<Vec<HistoryEntry>> {
+    let mut paths = Vec::new();
+    find_cell_files(dir, cell, &mut paths).map_err(|e| io_error(dir, e))?;
+
+    let mut files = Vec::with_capacity(paths.len());
+    for path in paths {
+        let file = read_file(&path)?;
+        let id = file.dataset_id().ok_or_else(|| {
+            ParseError::at(
+                ParseErrorKind::Other(format!("{}: no readable DSID", path.display())),
+                0,
+            )
+        })?;
+        let edition = id.edtn.trim().parse().unwrap_or(0);
+        files.push((edition, id, path, file));
+    }
+    files.sort_by(|(ed_a, id_a, path_a, _), (ed_b, id_b, path_b, _)| {
+        (ed_a, id_a.updn, is_base(path_a), path_a).cmp(&(ed_b, id_b.updn, is_base(path_b), path_b))
+    });
+
+    let mut entries = Vec::with_capacity(files.len());
+    let mut current: Option<S57File> = None;
+    let mut previous = World::new();
+    for (edition, id, path, file) in files {
+        let cell = if is_base(&path) {
+            current.insert(file)
+        } else {
+            let cell = current.as_mut().ok_or_else(|| {
+                ParseError::at(
+                    ParseErrorKind::Other(format!(
+                        "{}: update without a base cell",
+                        path.display()
+                    )),
+                    0,
+                )
+            })?;
+            cell.apply_update(&file).map_err(|e| {
+                ParseError::at(
+                    ParseErrorKind::Other(format!("{}: {}", path.display(), e)),
+                    0,
+                )
+            })?;
+            cell
+        };
+        let world = build_world_with_options(cell, options)?;
+        let DatasetId {
+            updn, uadt, isdt, ..
+        } = id;
+        entries.push(HistoryEntry {
+            path,
+            edition,
+            update: updn,
+            issue_date: isdt,
+            application_date: uadt,
+            changes: previous.diff(&world),
+        });
+        previous = world;
+    }
+    Ok(entries)
+}
+
+fn is_base(path: &Path) -> bool {
+    path.extension().and_then(|e| e.to_str()) == Some("000")
+}
+
+fn find_cell_files(dir: &Path, cell: &str, out: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            find_cell_files(&path, cell, out)?;
+            continue;
+        }
+        let stem_matches = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .is_some_and(|s| s.eq_ignore_ascii_case(cell));
+        let numbered = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|e| e.len() == 3 && e.bytes().all(|b| b.is_ascii_digit()));
+        if stem_matches && numbered {
+            out.push(path);
+        }
+    }
+    Ok(())
+}
+
+fn read_file(path: &Path) -> Result<S57File> {
+    let data = std::fs::read(path).map_err(|e| io_error(path, e))?;
+    S57File::from_bytes(&data)
+}
+
+fn io_error(path: &Path, e: std::io::Error) -> ParseError {
+    ParseError::at(
+        ParseErrorKind::Other(format!("{}: {}", path.display(), e)),
+        0,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diff::ChangeKind;
+    use crate::ecs::RecordSource;
+    use s57_parse::iso8211::{write_file, Field, Record};
+
+    const UNIT_TERMINATOR: u8 = 0x1F;
+    const FIELD_TERMINATOR: u8 = 0x1E;
+
+    /// DSID and DSSI of a file; EXPP is 1 (new) for UPDN 0, else 2 (revision)
+    fn dsid(edtn: u32, updn: u32, isdt: &str) -> Record {
+        let expp = if updn == 0 { 1 } else { 2 };
+        let mut data = vec![10, 1, 0, 0, 0, expp, 1];
+        for text in ["GB100001.000", &edtn.to_string(), &updn.to_string()] {
+            data.extend(text.bytes());
+            data.push(UNIT_TERMINATOR);
+        }
+        data.extend(isdt.bytes()); // UADT
+        data.extend(isdt.bytes()); // ISDT
+        data.extend(b"03.1");
+        data.extend([1, UNIT_TERMINATOR, UNIT_TERMINATOR, 1, 0x26, 0x02]);
+        data.extend([UNIT_TERMINATOR, FIELD_TERMINATOR]);
+        let mut dssi = vec![2, 1, 1];
+        dssi.extend([0; 32]);
+        dssi.push(FIELD_TERMINATOR);
+        Record::from_fields(vec![
+            Field::new("DSID".to_string(), data),
+            Field::new("DSSI".to_string(), dssi),
+        ])
+    }
+
+    /// A point-less LNDMRK feature with OBJNAM
+    fn feature(rcid: u32, rver: u16, ruin: u8, objnam: &str) -> Record {
+        let mut frid = vec![100];
+        frid.extend(rcid.to_le_bytes());
+        frid.extend([255, 2]);
+        frid.extend(74u16.to_le_bytes());
+        frid.extend(rver.to_le_bytes());
+        frid.extend([ruin, FIELD_TERMINATOR]);
+
+        let mut foid = 550u16.to_le_bytes().to_vec();
+        foid.extend(rcid.to_le_bytes());
+        foid.extend(1u16.to_le_bytes());
+        foid.push(FIELD_TERMINATOR);
+
+        let mut attf = 116u16.to_le_bytes().to_vec();
+        attf.extend(objnam.bytes());
+        attf.extend([UNIT_TERMINATOR, FIELD_TERMINATOR]);
+
+        Record::from_fields(vec![
+            Field::new("FRID".to_string(), frid),
+            Field::new("FOID".to_string(), foid),
+            Field::new("ATTF".to_string(), attf),
+        ])
+    }
+
+    #[test]
+    fn test_cell_history() {
+        let dir = std::env::temp_dir().join(format!("s57-history-{}", std::process::id()));
+        let write = |path: &str, records: Vec<Record>| {
+            let path = dir.join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, write_file(&records).unwrap()).unwrap();
+        };
+        write(
+            "1/0/GB100001.000",
+            vec![
+                dsid(1, 0, "20250101"),
+                feature(1, 1, 1, "Tower"),
+                feature(2, 1, 1, "Mast"),
+            ],
+        );
+        write(
+            "1/1/GB100001.001",
+            vec![dsid(1, 1, "20250301"), feature(3, 1, 1, "Chimney")],
+        );
+        write(
+            "1/2/GB100001.002",
+            vec![
+                dsid(1, 2, "20250601"),
+                feature(1, 2, 3, "Old tower"),
+                feature(2, 2, 2, "Mast"),
+            ],
+        );
+        write(
+            "2/0/gb100001.000",
+            vec![dsid(2, 0, "20260101"), feature(1, 1, 1, "Old tower")],
+        );
+        write("other/GB100002.000", vec![dsid(1, 0, "20250101")]);
+
+        let history = cell_history(&dir, "GB100001", &ParseOptions::default()).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let steps: Vec<_> = history
+            .iter()
+            .map(|e| (e.edition, e.update, e.is_base(), e.issue_date.as_str()))
+            .collect();
+        assert_eq!(
+            steps,
+            [
+                (1, 0, true, "20250101"),
+                (1, 1, false, "20250301"),
+                (1, 2, false, "20250601"),
+                (2, 0, true, "20260101"),
+            ]
+        );
+        let summaries: Vec<_> = history.iter().map(|e| e.changes.to_string()).collect();
+        assert_eq!(
+            summaries,
+            [
+                "2 added, 0 removed, 0 modified",
+                "1 added, 0 removed, 0 modified",
+                "0 added, 1 removed, 1 modified",
+                "0 added, 1 removed, 0 modified",
+            ]
+        );
+        let removed = &history[3].changes.changes[0];
+        assert_eq!((removed.foid.fidn, removed.kind), (3, ChangeKind::Removed));
+    }
+
+    #[test]
+    fn test_record_sources() {
+        let parse = |records: Vec<Record>| S57File::from_bytes(&write_file(&records).unwrap());
+        let mut cell = parse(vec![
+            dsid(1, 0, "20250101"),
+            feature(1, 1, 1, "Tower"),
+            feature(2, 1, 1, "Mast"),
+        ])
+        .unwrap();
+        let update = parse(vec![dsid(1, 1, "20250301"), feature(3, 1, 1, "Chimney")]).unwrap();
+        cell.apply_update(&update).unwrap();
+
+        let world = build_world_with_options(&cell, &ParseOptions::default()).unwrap();
+        let source = |fidn| {
+            let key = s57_parse::bitstring::FoidKey {
+                agen: 550,
+                fidn,
+                fids: 1,
+            };
+            world.record_sources[&world.foid_index[&key]]
+        };
+        let base = source(2);
+        // These records have no DDR in front of them, so DSID is record 0
+        assert_eq!((base.record, base.update), (2, None));
+        let span = cell.records()[base.record].span().unwrap();
+        assert_eq!(base.offset, Some(span.offset));
+        // An inserted record's offset is into the update file
+        let offset = update.records()[1].span().unwrap().offset;
+        assert_eq!(
+            source(3),
+            RecordSource {
+                record: 3,
+                update: Some(1),
+                offset: Some(offset),
+            }
+        );
+        assert_eq!(
+            source(3).to_string(),
+            format!("record 3 (update 1) at byte {}", offset)
+        );
+    }
+}
@@ -11,32 +11,87 @@
 //! - Topology relationships
 //! - Feature attributes and cross-references
 
+pub mod approx;
+pub mod cell_coverage;
+#[cfg(feature = "fs")]
+pub mod chart_catalog;
+pub mod clip;
+pub mod coverage;
+pub mod curve;
+pub mod depth;
+pub mod depth_bands;
+mod diag;
+pub mod diff;
 pub mod ecs;
+pub mod edit;
+pub mod feature;
+pub mod fingerprint;
+pub mod fixed;
+pub mod foid_registry;
+#[cfg(feature = "geo")]
+pub mod geo;
+pub mod geojson;
+pub mod geometry;
+pub mod geometry_cache;
+pub mod grid;
+pub mod guard;
+pub mod hazard;
+#[cfg(feature = "fs")]
+pub mod history;
+pub mod memory;
+pub mod metadata;
+pub mod options;
+pub mod overlay;
+pub mod pick;
+pub mod projection;
+pub mod query;
+pub mod record_counts;
+pub mod region;
+pub mod relations;
+pub mod roundtrip;
+#[cfg(feature = "senc")]
+pub mod senc;
+#[cfg(feature = "serde")]
+pub mod serde_exact;
+pub mod sounding;
+pub mod spatial_index;
 pub mod systems;
+pub mod targets;
 pub mod topology;
+pub mod transform;
+pub mod validate;
+pub mod world_set;
 
 // Re-export key types from s57-parse for convenience
 pub use s57_parse::bitstring::{FoidKey, NameKey};
 pub use s57_parse::{ParseError, ParseErrorKind, Result};
 
-use ecs::{DatasetParams, FeatureAttributes, World};
+use ecs::{DatasetIdentification, DatasetParams, DatasetStructure, RawField, World};
 use num_bigint::BigInt;
-use s57_parse::ddr::{SubfieldValue, DDR};
+use options::UnknownFieldAction;
+pub use options::{ClassFilter, CoordinateBackend, ParseOptions};
+use s57_parse::ddr::{DecodeContext, ParsedField, DDR};
+use s57_parse::s57_schema::is_standard_field_tag;
 use s57_parse::S57File;
+use std::collections::{HashMap, HashSet};
 use systems::{
-    get_i32, get_u16, get_u32, FeatureBindSystem, FoidDecodeSystem, GeometrySystem,
-    NameDecodeSystem, TopologySystem,
+    get_bytes, get_i32, get_string, get_u16, get_u32, get_u8, AttributeSystem, FeatureBindSystem,
+    FoidDecodeSystem, GeometrySystem, NameDecodeSystem, TopologySystem, UpdateControlSystem,
 };
 
+/// Signature shared by the UpdateControlSystem field processors
+type ControlFn = fn(&mut World, ecs::EntityId, &ParsedField) -> Result<()>;
+
 /// Build a World from an S57File
 ///
 /// Processes all records in the S57 file and populates the ECS World with:
 /// - Vector entities (from VRID records)
 /// - Feature entities (from FRID/FOID records)
-/// - Exact coordinates (from SG2D/SG3D records)
+/// - Exact coordinates (from SG2D/SG3D records, or ARCC curves)
 /// - Topology (from VRPT records)
 /// - Feature bindings (from FSPT/FFPT records)
-/// - Attributes (from ATTF/NATF records)
+/// - Attributes (from ATTF/NATF/ATTV records)
+/// - Update controls (from VRPC/SGCC/FFPC/FSPC records)
 ///
 /// # Arguments
 /// * `file` - Parsed S57File from s57-parse
@@ -44,28 +99,104 @@ use systems::{
 /// # Returns
 /// World populated with all entities and components, or ParseError on failure
 pub fn build_world(file: &S57File) -> Result<World> {
+    build_world_with_options(file, &ParseOptions::default())
+}
+
+/// Build a World from an S57File with explicit options
+///
+/// Same as `build_world`, plus handling of fields with non-standard tags:
+/// each one is offered to `options.on_unknown_field` and, unless dropped,
+/// kept as a RawField on the record's entity (or in
+/// `World::unattached_raw_fields` if the record produced no entity).
+///
+/// Feature records whose class `options.classes` leaves out produce no
+/// entity, nor do vectors that only they use; their raw fields are
+/// dropped, and FFPT pointers to them are removed without a warning.
+///
+/// With `options.coordinates` set to `CoordinateBackend::Fixed`, SG2D/SG3D
+/// values go to `World::fixed_positions`/`fixed_depths` instead of the
+/// exact components. `options.approx_positions` adds f64 copies of every
+/// position on top.
+pub fn build_world_with_options(file: &S57File, options: &ParseOptions) -> Result<World> {
     let mut world = World::new();
     let records = file.records();
 
-    // Parse DDR first
-    let ddr = if let Some(ddr_record) = records.first() {
-        if ddr_record.leader.is_ddr() {
-            DDR::parse(ddr_record)?
-        } else {
-            return Err(ParseError::at(
-                ParseErrorKind::InvalidField("First record is not DDR".to_string()),
-                0,
-            ));
+    let (mut ddr, first_data) = load_ddr(records)?;
+    let data_records = &records[first_data..];
+    // ATTF/NATF values are decoded in the character sets DSSI declares
+    ddr.load_lexical_levels(data_records);
+
+    let mut fallback_tags: Vec<&str> = data_records
+        .iter()
+        .flat_map(|record| &record.fields)
+        .map(|field| field.tag.as_str())
+        .filter(|tag| ddr.is_fallback(tag))
+        .collect();
+    fallback_tags.sort_unstable();
+    fallback_tags.dedup();
+    if !fallback_tags.is_empty() {
+        diag::warning!(
+            "DDR has no usable definition of {}; decoding with the built-in S-57 definitions",
+            fallback_tags.join(", ")
+        );
+    }
+
+    // First pass: Extract dataset identification (DSID), structure (DSSI)
+    // and parameters (DSPM)
+    for record in data_records {
+        if world.dataset_id.is_none() {
+            if let Some(dsid_field) = record.fields.iter().find(|f| f.tag == "DSID") {
+                if let Ok(parsed) = ddr.parse_field_data(dsid_field) {
+                    if let Some(group) = parsed.groups().first() {
+                        let text = |label| get_string(group, label).unwrap_or_default();
+                        let code = |label| get_u8(group, label).ok().flatten().unwrap_or(0);
+                        world.dataset_id = Some(DatasetIdentification {
+                            dsnm: text("DSNM"),
+                            edtn: text("EDTN"),
+                            updn: text("UPDN"),
+                            uadt: text("UADT"),
+                            isdt: text("ISDT"),
+                            intu: code("INTU"),
+                            expp: code("EXPP"),
+                            sted: text("STED"),
+                            prsp: code("PRSP"),
+                            psdn: text("PSDN"),
+                            pred: text("PRED"),
+                            prof: code("PROF"),
+                            agen: get_u16(group, "AGEN").ok().flatten().unwrap_or(0),
+                            comt: text("COMT"),
+                        });
+                    }
+                }
+            }
         }
-    } else {
-        return Err(ParseError::at(
-            ParseErrorKind::InvalidField("Empty file".to_string()),
-            0,
-        ));
-    };
 
-    // First pass: Extract dataset parameters from DSPM field
-    for record in &records[1..] {
+        if world.dataset_structure.is_none() {
+            if let Some(dssi_field) = record.fields.iter().find(|f| f.tag == "DSSI") {
+                if let Ok(parsed) = ddr.parse_field_data(dssi_field) {
+                    if let Some(group) = parsed.groups().first() {
+                        let count = |label| get_u32(group, label).ok().flatten().unwrap_or(0);
+                        world.dataset_structure = Some(DatasetStructure {
+                            dstr: get_u8(group, "DSTR").ok().flatten().unwrap_or(255),
+                            aall: get_u8(group, "AALL").ok().flatten().unwrap_or(0),
+                            nall: get_u8(group, "NALL").ok().flatten().unwrap_or(0),
+                            nomr: count("NOMR"),
+                            nocr: count("NOCR"),
+                            nogr: count("NOGR"),
+                            nolr: count("NOLR"),
+                            noin: count("NOIN"),
+                            nocn: count("NOCN"),
+                            noed: count("NOED"),
+                            nofa: count("NOFA"),
+                        });
+                    }
+                }
+            }
+        }
+
+        if world.dataset_params.is_some() {
+            continue;
+        }
         if let Some(dspm_field) = record.fields.iter().find(|f| f.tag == "DSPM") {
             if let Ok(parsed) = ddr.parse_field_data(dspm_field) {
                 if let Some(group) = parsed.groups().first() {
@@ -90,19 +221,60 @@ pub fn build_world(file: &S57File) -> Result<World> {
                         sdat,
                         cscl,
                     });
-                    break;
                 }
             }
         }
     }
 
-    // Second pass: Create entities from VRID (vectors) and FRID/FOID (features)
-    for (record_idx, record) in records[1..].iter().enumerate() {
-        let record_num = record_idx + 1; // Adjust for 0-based indexing after skipping DDR
+    // FFPT may point at features later in the file, so it is resolved last
+    let mut pending_ffpt = Vec::new();
+
+    // Vectors the kept features need, and the features filtered out
+    let used = used_vectors(&ddr, data_records, &options.classes);
+    let mut skipped_features = HashSet::new();
+
+    // Second pass: Create entities from VRID (vectors) and FRID/FOID (features).
+    // Parsed fields are recycled into `scratch` once processed, so their
+    // vectors are reused from record to record
+    let mut scratch = DecodeContext::new();
+    for (record_idx, record) in data_records.iter().enumerate() {
+        let record_num = record_idx + first_data; // Record number in the file
+        diag::span!("record", index = record_num);
+
+        // Collect non-standard fields; attached to the entity once it exists
+        let mut pending_raw: Vec<RawField> = record
+            .fields
+            .iter()
+            .filter(|f| !is_standard_field_tag(&f.tag))
+            .filter(|f| options.unknown_field_action(record_num, f) == UnknownFieldAction::Keep)
+            .map(|f| RawField {
+                record: record_num,
+                tag: f.tag.clone(),
+                data: f.data.clone(),
+            })
+            .collect();
+
+        // Report fixed-size repeating fields that don't end on a group boundary
+        for field in &record.fields {
+            let trailing = ddr.trailing_bytes(field);
+            if trailing > 0 {
+                crate::diag::warning!(
+                    "{} at record {} has {} trailing byte(s) after its last complete group",
+                    field.tag,
+                    record_num,
+                    trailing
+                );
+            }
+        }
 
         // Process vector records
         if let Some(vrid_field) = record.fields.iter().find(|f| f.tag == "VRID") {
-            if let Ok(parsed) = ddr.parse_field_data(vrid_field) {
+            if let Ok(parsed) = ddr.parse_field_data_in(vrid_field, &mut scratch) {
+                if let (Some(used), Some(name)) = (&used, vrid_name(&parsed)) {
+                    if !used.contains(&name) {
+                        continue;
+                    }
+                }
                 let entity = match NameDecodeSystem::process_vrid(&mut world, &parsed) {
                     Ok(e) => e,
                     Err(e) => {
@@ -114,47 +286,142 @@ pub fn build_world(file: &S57File) -> Result<World> {
                                 .iter()
                                 .map(|(label, val)| format!("{}={:?}", label, val))
                                 .collect();
-                            log::warn!(
+                            crate::diag::warning!(
                                 "Skipping VRID at record {}: {} [fields: {}]",
                                 record_num,
                                 e,
                                 fields.join(", ")
                             );
                         } else {
-                            log::warn!("Skipping VRID at record {}: {} [no groups]", record_num, e);
+                            crate::diag::warning!(
+                                "Skipping VRID at record {}: {} [no groups]",
+                                record_num,
+                                e
+                            );
                         }
+                        world.unattached_raw_fields.append(&mut pending_raw);
                         continue;
                     }
                 };
+                attach_raw_fields(&mut world, entity, &mut pending_raw);
+                world
+                    .record_sources
+                    .insert(entity, ecs::RecordSource::new(record_num, record));
 
-                // Process SG2D geometry if present
+                // Process SG2D/SG3D geometry if present. These fields hold
+                // most of a cell's bytes, so they are decoded in bulk unless
+                // the DDR gives them an unusual format
                 if let Some(sg2d_field) = record.fields.iter().find(|f| f.tag == "SG2D") {
-                    if let Ok(parsed_sg2d) = ddr.parse_field_data(sg2d_field) {
-                        let _ = GeometrySystem::process_sg2d(&mut world, entity, &parsed_sg2d);
+                    let points =
+                        ddr.decode_i32_groups(sg2d_field, ["YCOO", "XCOO"])
+                            .or_else(|| {
+                                let parsed =
+                                    ddr.parse_field_data_in(sg2d_field, &mut scratch).ok()?;
+                                GeometrySystem::raw_coordinates(&parsed, ["YCOO", "XCOO"]).ok()
+                            });
+                    if let Some(points) = points {
+                        let _ = GeometrySystem::process_coordinates(
+                            &mut world,
+                            entity,
+                            &points,
+                            options.coordinates,
+                        );
                     }
                 }
-
-                // Process SG3D geometry if present
                 if let Some(sg3d_field) = record.fields.iter().find(|f| f.tag == "SG3D") {
-                    if let Ok(parsed_sg3d) = ddr.parse_field_data(sg3d_field) {
-                        let _ = GeometrySystem::process_sg3d(&mut world, entity, &parsed_sg3d);
+                    let labels = ["YCOO", "XCOO", "VE3D"];
+                    let points = ddr.decode_i32_groups(sg3d_field, labels).or_else(|| {
+                        let parsed = ddr.parse_field_data_in(sg3d_field, &mut scratch).ok()?;
+                        GeometrySystem::raw_coordinates(&parsed, labels).ok()
+                    });
+                    if let Some(points) = points {
+                        let _ = GeometrySystem::process_coordinates(
+                            &mut world,
+                            entity,
+                            &points,
+                            options.coordinates,
+                        );
+                    }
+                }
+
+                // Process ARCC arc/curve geometry if present (AR2D/EL2D/CT2D carry the points)
+                if let Some(arcc_field) = record.fields.iter().find(|f| f.tag == "ARCC") {
+                    let construction = record
+                        .fields
+                        .iter()
+                        .find(|f| matches!(f.tag.as_str(), "AR2D" | "EL2D" | "CT2D"));
+                    if let (Ok(parsed_arcc), Some(construction)) = (
+                        ddr.parse_field_data_in(arcc_field, &mut scratch),
+                        construction,
+                    ) {
+                        if let Ok(parsed_construction) =
+                            ddr.parse_field_data_in(construction, &mut scratch)
+                        {
+                            if let Err(e) = GeometrySystem::process_arcc(
+                                &mut world,
+                                entity,
+                                &parsed_arcc,
+                                &parsed_construction,
+                            ) {
+                                crate::diag::warning!(
+                                    "Failed to process ARCC at record {}: {}",
+                                    record_num,
+                                    e
+                                );
+                            }
+                        }
                     }
                 }
 
                 // Process VRPT topology if present
                 if let Some(vrpt_field) = record.fields.iter().find(|f| f.tag == "VRPT") {
-                    if let Ok(parsed_vrpt) = ddr.parse_field_data(vrpt_field) {
+                    if let Ok(parsed_vrpt) = ddr.parse_field_data_in(vrpt_field, &mut scratch) {
                         let _ = TopologySystem::process_vrpt(&mut world, entity, &parsed_vrpt);
+                        scratch.recycle(parsed_vrpt);
+                    }
+                }
+
+                // Process ATTV spatial attributes (QUAPOS, POSACC) if present
+                if let Some(attv_field) = record.fields.iter().find(|f| f.tag == "ATTV") {
+                    if let Ok(parsed_attv) = ddr.parse_field_data_in(attv_field, &mut scratch) {
+                        AttributeSystem::process_attv(&mut world, entity, &parsed_attv);
+                        scratch.recycle(parsed_attv);
+                    }
+                }
+
+                // Process VRPC/SGCC update controls if present (update datasets only)
+                for (tag, process) in [
+                    ("VRPC", UpdateControlSystem::process_vrpc as ControlFn),
+                    ("SGCC", UpdateControlSystem::process_sgcc),
+                ] {
+                    if let Some(field) = record.fields.iter().find(|f| f.tag == tag) {
+                        if let Ok(parsed) = ddr.parse_field_data_in(field, &mut scratch) {
+                            if let Err(e) = process(&mut world, entity, &parsed) {
+                                crate::diag::warning!(
+                                    "Failed to process {} at record {}: {}",
+                                    tag,
+                                    record_num,
+                                    e
+                                );
+                            }
+                        }
                     }
                 }
+                scratch.recycle(parsed);
             }
         }
 
         // Process feature records
         if let Some(frid_field) = record.fields.iter().find(|f| f.tag == "FRID") {
             if let Some(foid_field) = record.fields.iter().find(|f| f.tag == "FOID") {
-                if let Ok(parsed_frid) = ddr.parse_field_data(frid_field) {
-                    if let Ok(parsed_foid) = ddr.parse_field_data(foid_field) {
+                if let Ok(parsed_frid) = ddr.parse_field_data_in(frid_field, &mut scratch) {
+                    if let Ok(parsed_foid) = ddr.parse_field_data_in(foid_field, &mut scratch) {
+                        if let Some(objl) = frid_objl(&parsed_frid) {
+                            if !options.classes.keeps(objl) {
+                                skipped_features.extend(foid_key(&parsed_foid));
+                                continue;
+                            }
+                        }
                         let entity = match FoidDecodeSystem::process_feature(
                             &mut world,
                             &parsed_frid,
@@ -162,88 +429,223 @@ pub fn build_world(file: &S57File) -> Result<World> {
                         ) {
                             Ok(e) => e,
                             Err(e) => {
-                                log::warn!("Skipping FRID/FOID at record {}: {}", record_num, e);
+                                crate::diag::warning!(
+                                    "Skipping FRID/FOID at record {}: {}",
+                                    record_num,
+                                    e
+                                );
+                                world.unattached_raw_fields.append(&mut pending_raw);
                                 continue;
                             }
                         };
+                        attach_raw_fields(&mut world, entity, &mut pending_raw);
+                        world
+                            .record_sources
+                            .insert(entity, ecs::RecordSource::new(record_num, record));
 
                         // Process ATTF attributes if present
                         if let Some(attf_field) = record.fields.iter().find(|f| f.tag == "ATTF") {
-                            if let Ok(parsed_attf) = ddr.parse_field_data(attf_field) {
-                                let mut attf = Vec::new();
-                                for group in parsed_attf.groups() {
-                                    let attl = get_u16(group, "ATTL").ok().flatten().unwrap_or(0);
-                                    let atvl = get_string(group, "ATVL").unwrap_or_default();
-                                    attf.push((attl, atvl));
-                                }
-                                let attrs = world
-                                    .feature_attributes
-                                    .entry(entity)
-                                    .or_insert_with(FeatureAttributes::default);
-                                attrs.attf = attf;
+                            if let Ok(parsed_attf) =
+                                ddr.parse_field_data_in(attf_field, &mut scratch)
+                            {
+                                AttributeSystem::process_attf(&mut world, entity, &parsed_attf);
+                                scratch.recycle(parsed_attf);
                             }
                         }
 
                         // Process NATF attributes if present
                         if let Some(natf_field) = record.fields.iter().find(|f| f.tag == "NATF") {
-                            if let Ok(parsed_natf) = ddr.parse_field_data(natf_field) {
-                                let mut natf = Vec::new();
-                                for group in parsed_natf.groups() {
-                                    let attl = get_u16(group, "ATTL").ok().flatten().unwrap_or(0);
-                                    let atvl = get_string(group, "ATVL").unwrap_or_default();
-                                    natf.push((attl, atvl));
+                            if let Ok(parsed_natf) =
+                                ddr.parse_field_data_in(natf_field, &mut scratch)
+                            {
+                                AttributeSystem::process_natf(&mut world, entity, &parsed_natf);
+                                scratch.recycle(parsed_natf);
+                            }
+                        }
+
+                        // Process FFPC/FSPC update controls if present (update datasets only)
+                        for (tag, process) in [
+                            ("FFPC", UpdateControlSystem::process_ffpc as ControlFn),
+                            ("FSPC", UpdateControlSystem::process_fspc),
+                        ] {
+                            if let Some(field) = record.fields.iter().find(|f| f.tag == tag) {
+                                if let Ok(parsed) = ddr.parse_field_data_in(field, &mut scratch) {
+                                    if let Err(e) = process(&mut world, entity, &parsed) {
+                                        crate::diag::warning!(
+                                            "Failed to process {} at record {}: {}",
+                                            tag,
+                                            record_num,
+                                            e
+                                        );
+                                    }
                                 }
-                                let attrs = world
-                                    .feature_attributes
-                                    .entry(entity)
-                                    .or_insert_with(FeatureAttributes::default);
-                                attrs.natf = natf;
                             }
                         }
 
                         // Process FSPT spatial pointers if present
                         if let Some(fspt_field) = record.fields.iter().find(|f| f.tag == "FSPT") {
-                            if let Ok(parsed_fspt) = ddr.parse_field_data(fspt_field) {
+                            if let Ok(parsed_fspt) =
+                                ddr.parse_field_data_in(fspt_field, &mut scratch)
+                            {
                                 if let Err(e) = FeatureBindSystem::process_fspt(
                                     &mut world,
                                     entity,
                                     &parsed_fspt,
                                 ) {
-                                    log::warn!(
+                                    crate::diag::warning!(
                                         "Failed to process FSPT at record {}: {}",
                                         record_num,
                                         e
                                     );
                                 }
+                                scratch.recycle(parsed_fspt);
                             }
                         }
 
                         // Process FFPT feature pointers if present
                         if let Some(ffpt_field) = record.fields.iter().find(|f| f.tag == "FFPT") {
-                            if let Ok(parsed_ffpt) = ddr.parse_field_data(ffpt_field) {
-                                let _ = FeatureBindSystem::process_ffpt(
-                                    &mut world,
-                                    entity,
-                                    &parsed_ffpt,
-                                );
+                            if let Ok(parsed_ffpt) =
+                                ddr.parse_field_data_in(ffpt_field, &mut scratch)
+                            {
+                                pending_ffpt.push((record_num, entity, parsed_ffpt));
                             }
                         }
+                        scratch.recycle(parsed_foid);
+                        scratch.recycle(parsed_frid);
                     }
                 }
             }
         }
+
+        world.unattached_raw_fields.append(&mut pending_raw);
+    }
+
+    for (record_num, entity, parsed_ffpt) in pending_ffpt {
+        if let Err(e) = FeatureBindSystem::process_ffpt_skipping(
+            &mut world,
+            entity,
+            &parsed_ffpt,
+            &skipped_features,
+        ) {
+            crate::diag::warning!("Failed to process FFPT at record {}: {}", record_num, e);
+        }
+    }
+
+    if options.approx_positions {
+        world.compute_approx();
     }
 
     Ok(world)
 }
 
-/// Helper: extract string value from subfield group
-fn get_string(group: &[(String, SubfieldValue)], label: &str) -> Option<String> {
-    group.iter().find(|(l, _)| l == label).and_then(|(_, v)| {
-        if let SubfieldValue::String(s) = v {
-            Some(s.clone())
-        } else {
-            None
+/// The file's DDR and the index of its first data record
+///
+/// A missing or unreadable DDR falls back on the built-in S-57 field
+/// definitions.
+fn load_ddr(records: &[s57_parse::iso8211::Record]) -> Result<(DDR, usize)> {
+    let Some(first) = records.first() else {
+        return Err(ParseError::at(
+            ParseErrorKind::InvalidField("Empty file".to_string()),
+            0,
+        ));
+    };
+    if !first.leader.is_ddr() {
+        diag::warning!("First record is not a DDR, using built-in S-57 field definitions");
+        return Ok((DDR::canonical(), 0));
+    }
+    match DDR::parse(first) {
+        Ok(ddr) => Ok((ddr, 1)),
+        Err(e) => {
+            diag::warning!(
+                "Unreadable DDR ({}), using built-in S-57 field definitions",
+                e
+            );
+            Ok((DDR::canonical(), 1))
         }
+    }
+}
+
+/// Vectors used by the features `classes` keeps, through FSPT and then
+/// VRPT (faces to edges, edges to nodes); None if every class is kept
+fn used_vectors(
+    ddr: &DDR,
+    records: &[s57_parse::iso8211::Record],
+    classes: &ClassFilter,
+) -> Option<HashSet<NameKey>> {
+    if classes.is_all() {
+        return None;
+    }
+    fn field<'a>(
+        ddr: &'a DDR,
+        record: &'a s57_parse::iso8211::Record,
+        tag: &str,
+    ) -> Option<ParsedField<'a>> {
+        let field = record.fields.iter().find(|f| f.tag == tag)?;
+        ddr.parse_field_data(field).ok()
+    }
+    let mut used = HashSet::new();
+    let mut pointers: HashMap<NameKey, Vec<NameKey>> = HashMap::new();
+    for record in records {
+        if let Some(name) = field(ddr, record, "VRID").and_then(|vrid| vrid_name(&vrid)) {
+            if let Some(vrpt) = field(ddr, record, "VRPT") {
+                pointers.insert(name, pointer_names(&vrpt));
+            }
+        } else if let Some(objl) = field(ddr, record, "FRID").and_then(|frid| frid_objl(&frid)) {
+            if classes.keeps(objl) {
+                if let Some(fspt) = field(ddr, record, "FSPT") {
+                    used.extend(pointer_names(&fspt));
+                }
+            }
+        }
+    }
+    let mut stack: Vec<NameKey> = used.iter().copied().collect();
+    while let Some(name) = stack.pop() {
+        for &next in pointers.get(&name).into_iter().flatten() {
+            if used.insert(next) {
+                stack.push(next);
+            }
+        }
+    }
+    Some(used)
+}
+
+/// NAME of a parsed VRID
+fn vrid_name(vrid: &ParsedField) -> Option<NameKey> {
+    let group = vrid.groups().first()?;
+    Some(NameKey {
+        rcnm: get_u8(group, "RCNM").ok()??,
+        rcid: get_u32(group, "RCID").ok()??,
+    })
+}
+
+/// OBJL of a parsed FRID
+fn frid_objl(frid: &ParsedField) -> Option<u16> {
+    get_u16(frid.groups().first()?, "OBJL").ok()?
+}
+
+/// LNAM of a parsed FOID
+fn foid_key(foid: &ParsedField) -> Option<FoidKey> {
+    let group = foid.groups().first()?;
+    Some(FoidKey {
+        agen: get_u16(group, "AGEN").ok()??,
+        fidn: get_u32(group, "FIDN").ok()??,
+        fids: get_u16(group, "FIDS").ok()??,
     })
 }
+
+/// NAMEs of a parsed FSPT or VRPT
+fn pointer_names(pointers: &ParsedField) -> Vec<NameKey> {
+    pointers
+        .groups()
+        .iter()
+        .filter_map(|group| get_bytes(group, "NAME").ok()?)
+        .filter_map(|bytes| NameKey::decode(bytes).ok())
+        .collect()
+}
+
+/// Move pending raw fields onto an entity's RawField component
+fn attach_raw_fields(world: &mut World, entity: ecs::EntityId, pending: &mut Vec<RawField>) {
+    if !pending.is_empty() {
+        world.raw_fields.entry(entity).or_default().append(pending);
+    }
+}
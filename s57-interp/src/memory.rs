@@ -0,0 +1,250 @@
+//! Memory usage estimates for a World
+//!
+//! Sizes are estimates: they count inline struct sizes, Vec/HashMap capacity
+//! and the digit buffers of BigInt/BigRational values, but not allocator
+//! overhead or padding inside hash tables.
+
+use crate::approx::ApproxCoordinates;
+use crate::ecs::{Coordinates, ExactPositions, RawField, World};
+use crate::fixed::FixedCoordinates;
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use std::collections::HashMap;
+use std::fmt;
+use std::mem::size_of;
+
+/// ComponentStats: Size estimate for one component store
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComponentStats {
+    /// Component store or index name (World field name)
+    pub name: &'static str,
+    /// Number of entities that have the component
+    pub entities: usize,
+    /// Estimated heap bytes, including the map itself
+    pub heap_bytes: usize,
+}
+
+/// MemoryStats: Per-component entity counts and estimated heap usage
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MemoryStats {
+    /// Entities allocated (vectors + features)
+    pub entities: usize,
+    /// Number of coordinates across all ExactPositions and FixedPositions
+    pub coordinates: usize,
+    /// One entry per component store, in World field order
+    pub components: Vec<ComponentStats>,
+}
+
+impl MemoryStats {
+    /// Total estimated heap bytes across all component stores
+    pub fn total_heap_bytes(&self) -> usize {
+        self.components.iter().map(|c| c.heap_bytes).sum()
+    }
+
+    /// Look up a component store by name
+    pub fn component(&self, name: &str) -> Option<&ComponentStats> {
+        self.components.iter().find(|c| c.name == name)
+    }
+}
+
+impl fmt::Display for MemoryStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Entities: {} ({} coordinates)",
+            self.entities, self.coordinates
+        )?;
+        for c in &self.components {
+            writeln!(
+                f,
+                "  {:<20} {:>8} entities {:>12} bytes",
+                c.name, c.entities, c.heap_bytes
+            )?;
+        }
+        write!(f, "  {:<20} {:>30} bytes", "total", self.total_heap_bytes())
+    }
+}
+
+/// Heap bytes of a BigInt's digit buffer
+fn bigint_heap(value: &BigInt) -> usize {
+    value.bits().div_ceil(64) as usize * size_of::<u64>()
+}
+
+/// Heap bytes of a BigRational (numerator + denominator digits)
+fn rational_heap(value: &BigRational) -> usize {
+    bigint_heap(value.numer()) + bigint_heap(value.denom())
+}
+
+/// Heap bytes of a Vec's buffer plus per-element heap
+fn vec_heap<T>(v: &Vec<T>, element_heap: impl Fn(&T) -> usize) -> usize {
+    v.capacity() * size_of::<T>() + v.iter().map(element_heap).sum::<usize>()
+}
+
+/// Heap bytes of a coordinate list (its buffer only counts once spilled)
+fn coordinates_heap(c: &Coordinates) -> usize {
+    let buffer = if c.spilled() {
+        c.capacity() * size_of::<BigRational>()
+    } else {
+        0
+    };
+    buffer + c.iter().map(rational_heap).sum::<usize>()
+}
+
+/// Heap bytes of a fixed-point coordinate list (none until spilled)
+fn fixed_heap(c: &FixedCoordinates) -> usize {
+    if c.spilled() {
+        c.capacity() * size_of::<i64>()
+    } else {
+        0
+    }
+}
+
+/// Heap bytes of an f64 coordinate list (none until spilled)
+fn approx_heap(c: &ApproxCoordinates) -> usize {
+    if c.spilled() {
+        c.capacity() * size_of::<f64>()
+    } else {
+        0
+    }
+}
+
+/// Heap bytes of a (u16, String) attribute list
+fn attribute_heap(attrs: &Vec<(u16, String)>) -> usize {
+    vec_heap(attrs, |(_, value)| value.capacity())
+}
+
+/// Stats for a sparse component map (or index)
+fn component<K, V>(
+    name: &'static str,
+    map: &HashMap<K, V>,
+    value_heap: impl Fn(&V) -> usize,
+) -> ComponentStats {
+    // Swiss tables store (K, V) slots plus one control byte per slot
+    let table = map.capacity() * (size_of::<K>() + size_of::<V>() + 1);
+    ComponentStats {
+        name,
+        entities: map.len(),
+        heap_bytes: table + map.values().map(value_heap).sum::<usize>(),
+    }
+}
+
+impl World {
+    /// Estimate per-component entity counts and heap usage
+    ///
+    /// Intended for comparing coordinate representations and spotting
+    /// regressions, not for exact accounting.
+    pub fn memory_stats(&self) -> MemoryStats {
+        let positions_heap =
+            |p: &ExactPositions| coordinates_heap(&p.lat) + coordinates_heap(&p.lon);
+        let raw_heap =
+            |fields: &Vec<RawField>| vec_heap(fields, |f| f.tag.capacity() + f.data.capacity());
+
+        let components = vec![
+            component("vector_meta", &self.vector_meta, |_| 0),
+            component("vector_topology", &self.vector_topology, |t| {
+                vec_heap(&t.neighbors, |_| 0)
+            }),
+            component("feature_meta", &self.feature_meta, |_| 0),
+            component("feature_attributes", &self.feature_attributes, |a| {
+                attribute_heap(&a.attf) + attribute_heap(&a.natf)
+            }),
+            component("feature_pointers", &self.feature_pointers, |p| {
+                vec_heap(&p.related_features, |_| 0) + vec_heap(&p.spatial_refs, |_| 0)
+            }),
+            component("vector_attributes", &self.vector_attributes, |a| {
+                attribute_heap(&a.attv)
+            }),
+            component("update_controls", &self.update_controls, |_| 0),
+            component("raw_fields", &self.raw_fields, raw_heap),
+            component("record_sources", &self.record_sources, |_| 0),
+            component("exact_positions", &self.exact_positions, positions_heap),
+            component("exact_depths", &self.exact_depths, |d| {
+                coordinates_heap(&d.depth)
+            }),
+            component("fixed_positions", &self.fixed_positions, |p| {
+                fixed_heap(&p.y) + fixed_heap(&p.x)
+            }),
+            component("fixed_depths", &self.fixed_depths, |d| fixed_heap(&d.z)),
+            component("approx_positions", &self.approx_positions, |p| {
+                approx_heap(&p.lat) + approx_heap(&p.lon)
+            }),
+            component("approx_depths", &self.approx_depths, |d| {
+                approx_heap(&d.depth)
+            }),
+            component("name_index", &self.name_index, |_| 0),
+            component("foid_index", &self.foid_index, |_| 0),
+        ];
+
+        MemoryStats {
+            entities: self.entity_count(),
+            coordinates: self
+                .exact_positions
+                .values()
+                .map(|p| p.lat.len())
+                .sum::<usize>()
+                + self
+                    .fixed_positions
+                    .values()
+                    .map(|p| p.len())
+                    .sum::<usize>(),
+            components,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::EntityType;
+    use smallvec::smallvec;
+
+    #[test]
+    fn test_memory_stats_counts_coordinates() {
+        let mut world = World::new();
+        let empty = world.memory_stats();
+        assert_eq!(empty.entities, 0);
+        assert_eq!(empty.coordinates, 0);
+
+        let entity = world.create_entity(EntityType::Vector);
+        // Large numerators need heap digits
+        let big = BigRational::new(BigInt::from(u64::MAX) * BigInt::from(3), BigInt::from(7));
+        world.exact_positions.insert(
+            entity,
+            ExactPositions {
+                lat: smallvec![big.clone(), big.clone()],
+                lon: smallvec![big.clone(), big],
+            },
+        );
+
+        let stats = world.memory_stats();
+        assert_eq!(stats.entities, 1);
+        assert_eq!(stats.coordinates, 2);
+
+        let positions = stats.component("exact_positions").unwrap();
+        assert_eq!(positions.entities, 1);
+        // 4 rationals with 2-word numerators and 1-word denominators
+        assert!(positions.heap_bytes >= 4 * 3 * size_of::<u64>());
+        assert!(stats.total_heap_bytes() >= positions.heap_bytes);
+    }
+
+    #[test]
+    fn test_memory_stats_inline_coordinates() {
+        let mut world = World::new();
+        let entity = world.create_entity(EntityType::Vector);
+        let small = BigRational::new(BigInt::from(1), BigInt::from(10_000_000));
+        world.exact_positions.insert(
+            entity,
+            ExactPositions {
+                lat: smallvec![small.clone()],
+                lon: smallvec![small],
+            },
+        );
+
+        // A single node is stored inline: only the digit buffers are on the heap
+        let positions = world.memory_stats();
+        let positions = positions.component("exact_positions").unwrap();
+        let table = world.exact_positions.capacity()
+            * (size_of::<crate::ecs::EntityId>() + size_of::<ExactPositions>() + 1);
+        assert_eq!(positions.heap_bytes, table + 4 * size_of::<u64>());
+    }
+}
@@ -0,0 +1,263 @@
+//! Dataset metadata
+//!
+//! `World::metadata` gathers what a chart library or catalogue shows about
+//! a cell into one value: its name, edition and update from DSID, whether
+//! the file is a base cell, an update or a re-issue, the navigational
+//! purpose it was compiled for and the agency that produced it, its
+//! compilation scale from DSPM and its exact extent.
+
+use crate::ecs::World;
+use crate::region::BoundingBox;
+use std::fmt;
+
+/// NavigationalPurpose: Usage band a cell is compiled for (DSID INTU)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum NavigationalPurpose {
+    Overview = 1,
+    General = 2,
+    Coastal = 3,
+    Approach = 4,
+    Harbour = 5,
+    Berthing = 6,
+}
+
+impl NavigationalPurpose {
+    /// Purpose from an INTU value; None for 0 (unknown) and undefined values
+    pub fn from_intu(intu: u8) -> Option<Self> {
+        match intu {
+            1 => Some(NavigationalPurpose::Overview),
+            2 => Some(NavigationalPurpose::General),
+            3 => Some(NavigationalPurpose::Coastal),
+            4 => Some(NavigationalPurpose::Approach),
+            5 => Some(NavigationalPurpose::Harbour),
+            6 => Some(NavigationalPurpose::Berthing),
+            _ => None,
+        }
+    }
+
+    /// INTU value
+    pub fn intu(&self) -> u8 {
+        *self as u8
+    }
+}
+
+impl fmt::Display for NavigationalPurpose {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            NavigationalPurpose::Overview => "overview",
+            NavigationalPurpose::General => "general",
+            NavigationalPurpose::Coastal => "coastal",
+            NavigationalPurpose::Approach => "approach",
+            NavigationalPurpose::Harbour => "harbour",
+            NavigationalPurpose::Berthing => "berthing",
+        })
+    }
+}
+
+/// DatasetKind: What a cell file is in its update sequence
+///
+/// From DSID EXPP, EDTN and UPDN, as the ENC Product Specification sets
+/// them: a new edition and a re-issue are both new data sets, a re-issue
+/// having its updates already applied; an update is a revision, and a
+/// revision with edition 0 cancels the cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DatasetKind {
+    /// A new edition (EXPP 1, UPDN 0)
+    Base,
+    /// An update to apply to the base cell (EXPP 2)
+    Update,
+    /// The cell with its updates applied (EXPP 1, UPDN > 0)
+    ReIssue,
+    /// An update withdrawing the cell (EXPP 2, EDTN 0)
+    Cancellation,
+}
+
+impl DatasetKind {
+    /// Kind from the DSID values; None for an EXPP other than 1 or 2
+    pub fn from_dsid(expp: u8, edition: Option<u32>, update: Option<u32>) -> Option<Self> {
+        match expp {
+            1 if update.unwrap_or(0) > 0 => Some(DatasetKind::ReIssue),
+            1 => Some(DatasetKind::Base),
+            2 if edition == Some(0) => Some(DatasetKind::Cancellation),
+            2 => Some(DatasetKind::Update),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for DatasetKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            DatasetKind::Base => "base cell",
+            DatasetKind::Update => "update",
+            DatasetKind::ReIssue => "re-issue",
+            DatasetKind::Cancellation => "cancellation",
+        })
+    }
+}
+
+/// Producing agencies by AGEN code: (code, producer code, name)
+///
+/// Taken from IHO S-62, which is revised as producers join; this holds
+/// the producers whose cells are most widely distributed, and other codes
+/// are reported by number.
+const AGENCIES: &[(u16, &str, &str)] = &[
+    (540, "GB", "United Kingdom Hydrographic Office"),
+    (550, "US", "NOAA Office of Coast Survey"),
+];
+
+/// Name and two-letter producer code of a producing agency (DSID AGEN)
+pub fn agency(agen: u16) -> Option<(&'static str, &'static str)> {
+    AGENCIES
+        .iter()
+        .find(|(code, _, _)| *code == agen)
+        .map(|(_, producer, name)| (*producer, *name))
+}
+
+/// DatasetMetadata: Identification, scale and extent of a cell
+///
+/// Fields are None (or empty) when the cell doesn't carry them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DatasetMetadata {
+    /// Data set name (DSNM), e.g. "US5MA1AA.000"
+    pub name: String,
+    /// Edition number (EDTN)
+    pub edition: Option<u32>,
+    /// Update number (UPDN); 0 for a base cell
+    pub update: Option<u32>,
+    /// Issue date (ISDT, CCYYMMDD)
+    pub issue_date: String,
+    /// Update application date (UADT, CCYYMMDD)
+    pub update_date: String,
+    /// Base cell, update or re-issue (EXPP with EDTN and UPDN)
+    pub kind: Option<DatasetKind>,
+    /// Navigational purpose (INTU)
+    pub purpose: Option<NavigationalPurpose>,
+    /// Producing agency code (AGEN); see `agency` for its name
+    pub agency: Option<u16>,
+    /// Compilation scale denominator (DSPM CSCL)
+    pub compilation_scale: Option<u32>,
+    /// Exact extent of every positioned vector
+    pub extent: Option<BoundingBox>,
+}
+
+impl World {
+    /// Identification, scale and extent of the dataset
+    pub fn metadata(&self) -> DatasetMetadata {
+        let id = self.dataset_id.as_ref();
+        let text = |value: Option<&String>| value.cloned().unwrap_or_default();
+        let number = |value: Option<&String>| value?.trim().parse().ok();
+        let edition = number(id.map(|id| &id.edtn));
+        let update = number(id.map(|id| &id.updn));
+        DatasetMetadata {
+            name: text(id.map(|id| &id.dsnm)),
+            edition,
+            update,
+            issue_date: text(id.map(|id| &id.isdt)),
+            update_date: text(id.map(|id| &id.uadt)),
+            kind: id.and_then(|id| DatasetKind::from_dsid(id.expp, edition, update)),
+            purpose: id.and_then(|id| NavigationalPurpose::from_intu(id.intu)),
+            agency: id.map(|id| id.agen).filter(|&agen| agen != 0),
+            compilation_scale: self
+                .dataset_params
+                .as_ref()
+                .map(|params| params.cscl)
+                .filter(|&cscl| cscl > 1),
+            extent: self.extent(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::{DatasetIdentification, DatasetParams, EntityType, ExactPositions};
+    use crate::fixed::FixedPositions;
+    use num_bigint::BigInt;
+    use num_rational::BigRational;
+    use smallvec::smallvec;
+
+    #[test]
+    fn test_metadata() {
+        let ratio = |n: i64, d: i64| BigRational::new(BigInt::from(n), BigInt::from(d));
+        let mut world = World::new();
+        assert_eq!(world.metadata().extent, None);
+        assert_eq!(world.metadata().edition, None);
+
+        world.dataset_id = Some(DatasetIdentification {
+            dsnm: "US5MA1AA.000".to_string(),
+            edtn: "3".to_string(),
+            updn: "0".to_string(),
+            uadt: "20240101".to_string(),
+            isdt: "20240102".to_string(),
+            intu: 5,
+            expp: 1,
+            agen: 550,
+            ..Default::default()
+        });
+        world.dataset_params = Some(DatasetParams {
+            comf: BigInt::from(10_000_000),
+            somf: BigInt::from(10),
+            duni: 1,
+            huni: 1,
+            puni: 1,
+            hdat: 2,
+            vdat: 0,
+            sdat: 0,
+            cscl: 22000,
+        });
+
+        // One exact and one fixed-point vector
+        let exact = world.create_entity(EntityType::Vector);
+        world.exact_positions.insert(
+            exact,
+            ExactPositions {
+                lat: smallvec![ratio(425, 10)],
+                lon: smallvec![ratio(-705, 10)],
+            },
+        );
+        let fixed = world.create_entity(EntityType::Vector);
+        world.fixed_positions.insert(
+            fixed,
+            FixedPositions {
+                y: smallvec![426_000_001],
+                x: smallvec![-704_000_000],
+                comf: 10_000_000,
+            },
+        );
+
+        let metadata = world.metadata();
+        assert_eq!(metadata.name, "US5MA1AA.000");
+        assert_eq!((metadata.edition, metadata.update), (Some(3), Some(0)));
+        assert_eq!(metadata.issue_date, "20240102");
+        assert_eq!(metadata.purpose, Some(NavigationalPurpose::Harbour));
+        assert_eq!(metadata.purpose.unwrap().to_string(), "harbour");
+        assert_eq!(metadata.kind, Some(DatasetKind::Base));
+        assert_eq!(
+            metadata.agency.and_then(agency),
+            Some(("US", "NOAA Office of Coast Survey"))
+        );
+        assert_eq!(metadata.compilation_scale, Some(22000));
+        assert_eq!(
+            metadata.extent,
+            Some(BoundingBox::new(
+                ratio(425, 10),
+                ratio(-705, 10),
+                ratio(426_000_001, 10_000_000),
+                ratio(-704, 10)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_dataset_kind() {
+        let kind = DatasetKind::from_dsid;
+        assert_eq!(kind(1, Some(4), Some(0)), Some(DatasetKind::Base));
+        assert_eq!(kind(1, Some(4), Some(7)), Some(DatasetKind::ReIssue));
+        assert_eq!(kind(2, Some(4), Some(8)), Some(DatasetKind::Update));
+        assert_eq!(kind(2, Some(0), Some(9)), Some(DatasetKind::Cancellation));
+        assert_eq!(kind(0, Some(4), Some(0)), None);
+        assert_eq!(DatasetKind::ReIssue.to_string(), "re-issue");
+        assert_eq!(agency(1), None);
+    }
+}
@@ -0,0 +1,266 @@
+//! Options for building a World
+//!
+//! `build_world` uses `ParseOptions::default()`. Callers that need to control
+//! how non-standard input is handled, which object classes are built or how
+//! coordinates are stored, pass their own options to `build_world_with_options`.
+
+use s57_parse::iso8211::Field;
+use std::collections::HashSet;
+use std::fmt;
+
+/// What to do with a field whose tag is not part of the S-57 data structure
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnknownFieldAction {
+    /// Keep the field bytes as a RawField in the World
+    #[default]
+    Keep,
+    /// Discard the field
+    Drop,
+}
+
+/// Unknown field hook: called with the 1-based data record number and field
+pub type UnknownFieldHook = Box<dyn Fn(usize, &Field) -> UnknownFieldAction>;
+
+/// ClassFilter: Which object classes (OBJL) become features
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum ClassFilter {
+    /// Every class
+    #[default]
+    All,
+    /// Only these classes
+    Only(HashSet<u16>),
+    /// Every class but these
+    Except(HashSet<u16>),
+}
+
+impl ClassFilter {
+    /// True if features of class `objl` are built
+    pub fn keeps(&self, objl: u16) -> bool {
+        match self {
+            ClassFilter::All => true,
+            ClassFilter::Only(classes) => classes.contains(&objl),
+            ClassFilter::Except(classes) => !classes.contains(&objl),
+        }
+    }
+
+    /// True if every class is built
+    pub fn is_all(&self) -> bool {
+        *self == ClassFilter::All
+    }
+}
+
+/// CoordinateBackend: How SG2D/SG3D values are stored
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CoordinateBackend {
+    /// BigRational `exact_positions`/`exact_depths`
+    #[default]
+    Exact,
+    /// Raw i64 values with COMF/SOMF kept separately (`fixed_positions`/
+    /// `fixed_depths`); still lossless, with far fewer allocations
+    Fixed,
+}
+
+/// ParseOptions: Configuration for `build_world_with_options`
+#[derive(Default)]
+pub struct ParseOptions {
+    /// Called for every field with a non-standard tag (e.g. producer
+    /// extensions). Without a hook all such fields are kept.
+    pub on_unknown_field: Option<UnknownFieldHook>,
+    /// Object classes to build. Feature records of other classes are
+    /// skipped before their attributes or pointers are decoded, and so are
+    /// vectors only they use.
+    pub classes: ClassFilter,
+    /// Representation of SG2D/SG3D coordinates
+    pub coordinates: CoordinateBackend,
+    /// Also fill `approx_positions`/`approx_depths` (see `World::compute_approx`)
+    pub approx_positions: bool,
+}
+
+impl ParseOptions {
+    /// Create options with default behaviour
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the unknown field hook
+    pub fn on_unknown_field<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(usize, &Field) -> UnknownFieldAction + 'static,
+    {
+        self.on_unknown_field = Some(Box::new(hook));
+        self
+    }
+
+    /// Build only features of these object classes
+    pub fn only_classes(mut self, classes: impl IntoIterator<Item = u16>) -> Self {
+        self.classes = ClassFilter::Only(classes.into_iter().collect());
+        self
+    }
+
+    /// Build features of every object class except these
+    pub fn without_classes(mut self, classes: impl IntoIterator<Item = u16>) -> Self {
+        self.classes = ClassFilter::Except(classes.into_iter().collect());
+        self
+    }
+
+    /// Store SG2D/SG3D coordinates as fixed-point values; see
+    /// `World::promote_fixed`
+    pub fn fixed_point(mut self) -> Self {
+        self.coordinates = CoordinateBackend::Fixed;
+        self
+    }
+
+    /// Keep f64 copies of every position for the fast geometry path
+    pub fn approx_positions(mut self) -> Self {
+        self.approx_positions = true;
+        self
+    }
+
+    /// Decide what to do with an unknown field
+    pub(crate) fn unknown_field_action(&self, record: usize, field: &Field) -> UnknownFieldAction {
+        match &self.on_unknown_field {
+            Some(hook) => hook(record, field),
+            None => UnknownFieldAction::Keep,
+        }
+    }
+}
+
+impl fmt::Debug for ParseOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ParseOptions")
+            .field("on_unknown_field", &self.on_unknown_field.is_some())
+            .field("classes", &self.classes)
+            .field("coordinates", &self.coordinates)
+            .field("approx_positions", &self.approx_positions)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{build_world_with_options, FoidKey, NameKey};
+    use s57_parse::iso8211::{write_file, Record};
+    use s57_parse::S57File;
+
+    fn name(rcnm: u8, rcid: u32) -> Vec<u8> {
+        [vec![rcnm], rcid.to_le_bytes().to_vec()].concat()
+    }
+
+    fn lnam(fidn: u32) -> Vec<u8> {
+        [
+            550u16.to_le_bytes().to_vec(),
+            fidn.to_le_bytes().to_vec(),
+            1u16.to_le_bytes().to_vec(),
+        ]
+        .concat()
+    }
+
+    /// Node, or edge between two connected nodes
+    fn vector(rcnm: u8, rcid: u32, nodes: &[u32]) -> Record {
+        let mut vrid = name(rcnm, rcid);
+        vrid.extend([1, 0, 1, 0x1E]);
+        let mut fields = vec![Field::new("VRID".to_string(), vrid)];
+        if !nodes.is_empty() {
+            let mut vrpt = Vec::new();
+            for (topi, node) in nodes.iter().enumerate() {
+                vrpt.extend(name(120, *node));
+                vrpt.extend([255, 255, topi as u8 + 1, 255]);
+            }
+            vrpt.push(0x1E);
+            fields.push(Field::new("VRPT".to_string(), vrpt));
+        }
+        Record::from_fields(fields)
+    }
+
+    /// Line feature on one edge, with an optional FFPT pointer
+    fn line(fidn: u32, objl: u16, edge: u32, ffpt: Option<u32>) -> Record {
+        let mut frid = vec![100];
+        frid.extend(fidn.to_le_bytes());
+        frid.extend([2, 2]);
+        frid.extend(objl.to_le_bytes());
+        frid.extend([1, 0, 1, 0x1E]);
+        let mut fspt = name(130, edge);
+        fspt.extend([1, 255, 255, 0x1E]);
+        let mut fields = vec![
+            Field::new("FRID".to_string(), frid),
+            Field::new("FOID".to_string(), [lnam(fidn), vec![0x1E]].concat()),
+            Field::new("FSPT".to_string(), fspt),
+        ];
+        if let Some(target) = ffpt {
+            let data = [lnam(target), vec![3, 0x1F, 0x1E]].concat();
+            fields.push(Field::new("FFPT".to_string(), data));
+        }
+        Record::from_fields(fields)
+    }
+
+    #[test]
+    fn test_class_filter() {
+        // The coastline (COALNE) and depth contour (DEPCNT) share node 2
+        let records = vec![
+            vector(120, 1, &[]),
+            vector(120, 2, &[]),
+            vector(120, 3, &[]),
+            vector(130, 1, &[1, 2]),
+            vector(130, 2, &[2, 3]),
+            line(1, 30, 1, Some(2)),
+            line(2, 43, 2, None),
+        ];
+        let file = S57File::from_bytes(&write_file(&records).unwrap()).unwrap();
+        let key = |rcnm, rcid| NameKey { rcnm, rcid };
+        let foid = |fidn| FoidKey {
+            agen: 550,
+            fidn,
+            fids: 1,
+        };
+
+        let world = build_world_with_options(&file, &ParseOptions::new()).unwrap();
+        assert_eq!(world.name_index.len(), 5);
+        assert_eq!(world.foid_index.len(), 2);
+
+        for options in [
+            ParseOptions::new().only_classes([30]),
+            ParseOptions::new().without_classes([43]),
+        ] {
+            let world = build_world_with_options(&file, &options).unwrap();
+            let mut names: Vec<_> = world.name_index.keys().copied().collect();
+            names.sort_by_key(|name| (name.rcnm, name.rcid));
+            assert_eq!(names, [key(120, 1), key(120, 2), key(130, 1)]);
+            assert!(!world.foid_index.contains_key(&foid(2)));
+            // The peer pointer to the filtered contour is gone
+            let coastline = world.foid_index[&foid(1)];
+            assert!(world.feature_pointers[&coastline]
+                .related_features
+                .is_empty());
+        }
+    }
+
+    #[test]
+    fn test_unknown_field_hook() {
+        let field = Field::new("XNOA".to_string(), vec![0x01, 0x1E]);
+
+        // Default keeps everything
+        let options = ParseOptions::new();
+        assert_eq!(
+            options.unknown_field_action(1, &field),
+            UnknownFieldAction::Keep
+        );
+
+        // Hook decides per field
+        let options = ParseOptions::new().on_unknown_field(|record, field| {
+            if record > 1 && field.tag.starts_with('X') {
+                UnknownFieldAction::Drop
+            } else {
+                UnknownFieldAction::Keep
+            }
+        });
+        assert_eq!(
+            options.unknown_field_action(1, &field),
+            UnknownFieldAction::Keep
+        );
+        assert_eq!(
+            options.unknown_field_action(2, &field),
+            UnknownFieldAction::Drop
+        );
+    }
+}
@@ -0,0 +1,156 @@
+//! Mariner overlay: user-created objects drawn over the chart
+//!
+//! Routes, waypoints and notes added by the user are kept in an Overlay next
+//! to the chart entities rather than as features in the ECS, in the spirit of
+//! S-52 mariner's objects: they never get a FOID, never take part in
+//! topology, and can be cleared without touching the chart data.
+
+use crate::ecs::{Coordinates, ExactPositions};
+use crate::region::BoundingBox;
+use num_rational::BigRational;
+use smallvec::smallvec;
+
+/// MarinerObjectKind: What a mariner object represents
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MarinerObjectKind {
+    /// Single named position
+    Waypoint,
+    /// Ordered list of positions (legs between consecutive points)
+    Route,
+    /// Free text attached to a position
+    Note,
+}
+
+/// MarinerObject: One user-created overlay object
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MarinerObject {
+    /// Object kind
+    pub kind: MarinerObjectKind,
+    /// Display name (route/waypoint name, or note title)
+    pub name: String,
+    /// Free text (note body, route description)
+    pub text: Option<String>,
+    /// Exact positions (one for waypoints and notes)
+    pub positions: ExactPositions,
+}
+
+impl MarinerObject {
+    /// Extent of the object, or None if it has no positions
+    pub fn bbox(&self) -> Option<BoundingBox> {
+        BoundingBox::from_points(self.positions.lat.iter().zip(self.positions.lon.iter()))
+    }
+}
+
+/// Overlay: Mariner objects stored alongside a World
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Overlay {
+    /// Objects in the order they were added (later objects draw on top)
+    pub objects: Vec<MarinerObject>,
+}
+
+impl Overlay {
+    /// Create an empty overlay
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check whether the overlay has no objects
+    pub fn is_empty(&self) -> bool {
+        self.objects.is_empty()
+    }
+
+    /// Number of objects
+    pub fn len(&self) -> usize {
+        self.objects.len()
+    }
+
+    /// Add a named waypoint
+    pub fn add_waypoint(&mut self, name: &str, lat: BigRational, lon: BigRational) {
+        self.objects.push(MarinerObject {
+            kind: MarinerObjectKind::Waypoint,
+            name: name.to_string(),
+            text: None,
+            positions: ExactPositions {
+                lat: smallvec![lat],
+                lon: smallvec![lon],
+            },
+        });
+    }
+
+    /// Add a route through `points` given as (lat, lon)
+    pub fn add_route(&mut self, name: &str, points: Vec<(BigRational, BigRational)>) {
+        let (lat, lon): (Coordinates, Coordinates) = points.into_iter().unzip();
+        self.objects.push(MarinerObject {
+            kind: MarinerObjectKind::Route,
+            name: name.to_string(),
+            text: None,
+            positions: ExactPositions { lat, lon },
+        });
+    }
+
+    /// Add a text note at a position
+    pub fn add_note(&mut self, name: &str, text: &str, lat: BigRational, lon: BigRational) {
+        self.objects.push(MarinerObject {
+            kind: MarinerObjectKind::Note,
+            name: name.to_string(),
+            text: Some(text.to_string()),
+            positions: ExactPositions {
+                lat: smallvec![lat],
+                lon: smallvec![lon],
+            },
+        });
+    }
+
+    /// Objects of one kind
+    pub fn of_kind(&self, kind: MarinerObjectKind) -> impl Iterator<Item = &MarinerObject> {
+        self.objects.iter().filter(move |o| o.kind == kind)
+    }
+
+    /// Remove every object
+    pub fn clear(&mut self) {
+        self.objects.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_bigint::BigInt;
+
+    fn deg(v: i64) -> BigRational {
+        BigRational::from_integer(BigInt::from(v))
+    }
+
+    #[test]
+    fn test_overlay_objects() {
+        let mut overlay = Overlay::new();
+        assert!(overlay.is_empty());
+
+        overlay.add_waypoint("WP1", deg(10), deg(20));
+        overlay.add_route(
+            "Outbound",
+            vec![(deg(10), deg(20)), (deg(12), deg(19)), (deg(11), deg(25))],
+        );
+        overlay.add_note("Anchor", "Good holding", deg(11), deg(21));
+        assert_eq!(overlay.len(), 3);
+
+        let route = overlay
+            .of_kind(MarinerObjectKind::Route)
+            .next()
+            .expect("route");
+        assert_eq!(route.positions.lat.len(), 3);
+        assert_eq!(
+            route.bbox(),
+            Some(BoundingBox::new(deg(10), deg(19), deg(12), deg(25)))
+        );
+
+        let note = overlay.of_kind(MarinerObjectKind::Note).next().unwrap();
+        assert_eq!(note.text.as_deref(), Some("Good holding"));
+
+        overlay.clear();
+        assert!(overlay.is_empty());
+    }
+}
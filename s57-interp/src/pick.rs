@@ -0,0 +1,315 @@
+//! S-52 pick report
+//!
+//! A pick report lists the features under the cursor. S-52 asks for them in
+//! a fixed order so the mariner sees what matters first: dangers, then aids
+//! to navigation, then other point and line features, then the areas the
+//! position lies in, and the meta objects (data quality, coverage) last.
+//! `TraversalContext::pick_report` finds and orders the features at a
+//! position; `World::sort_pick_report` orders an existing list.
+
+use crate::ecs::{EntityId, World};
+use crate::geometry_cache::ResolvedGeometry;
+use crate::hazard::HAZARD_CLASSES;
+use crate::topology::TraversalContext;
+use num_rational::BigRational;
+use num_traits::{One, Zero};
+use std::fmt;
+
+/// Aids to navigation: beacons, buoys, daymarks, fog signals, lights,
+/// light floats and vessels, radar and radio stations, retro-reflectors
+/// and topmarks
+const AID_CLASSES: [u16; 21] = [
+    5, 6, 7, 8, 9, 14, 15, 16, 17, 18, 19, 39, 58, 75, 76, 77, 102, 103, 105, 113, 144,
+];
+
+/// First OBJL code of the meta object classes (M_COVR, M_QUAL, ...);
+/// collection objects (C_AGGR, C_ASSO, C_STAC) follow them
+const FIRST_META_OBJL: u16 = 300;
+
+/// PRIM of area features
+const PRIM_AREA: u8 = 3;
+
+/// PickGroup: Section of a pick report, in report order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PickGroup {
+    /// Isolated dangers: obstructions, underwater rocks, wrecks
+    Danger,
+    /// Aids to navigation
+    Aid,
+    /// Other point and line features
+    Other,
+    /// Area features
+    Area,
+    /// Meta and collection objects
+    Meta,
+}
+
+impl PickGroup {
+    /// Group of a feature from its object class and primitive
+    pub fn of(objl: u16, prim: u8) -> Self {
+        if HAZARD_CLASSES.contains(&objl) {
+            PickGroup::Danger
+        } else if AID_CLASSES.contains(&objl) {
+            PickGroup::Aid
+        } else if objl >= FIRST_META_OBJL {
+            PickGroup::Meta
+        } else if prim == PRIM_AREA {
+            PickGroup::Area
+        } else {
+            PickGroup::Other
+        }
+    }
+}
+
+impl fmt::Display for PickGroup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            PickGroup::Danger => "dangers",
+            PickGroup::Aid => "aids to navigation",
+            PickGroup::Other => "other features",
+            PickGroup::Area => "areas",
+            PickGroup::Meta => "meta objects",
+        })
+    }
+}
+
+impl World {
+    /// Pick report group of a feature, or None if it has no FRID
+    pub fn pick_group(&self, feature: EntityId) -> Option<PickGroup> {
+        let meta = self.feature_meta.get(&feature)?;
+        Some(PickGroup::of(meta.objl, meta.prim))
+    }
+
+    /// Put features in pick report order
+    ///
+    /// Orders by group, then object class, then FOID, so the result doesn't
+    /// depend on the order features were found in. Entities without a FRID
+    /// go last.
+    pub fn sort_pick_report(&self, features: &mut [EntityId]) {
+        features.sort_by_key(|feature| {
+            let meta = self.feature_meta.get(feature);
+            (
+                meta.is_none(),
+                meta.map(|meta| {
+                    (
+                        PickGroup::of(meta.objl, meta.prim),
+                        meta.objl,
+                        (meta.foid.agen, meta.foid.fidn, meta.foid.fids),
+                    )
+                }),
+            )
+        });
+    }
+}
+
+impl TraversalContext<'_> {
+    /// Features at a (lat, lon) point, in pick report order
+    ///
+    /// Areas are picked when they contain the point. Point features are
+    /// picked when one of their positions, and line features when one of
+    /// their segments, comes within `tolerance` degrees of the point in
+    /// latitude and longitude (a square around it, tested exactly).
+    pub fn pick_report(
+        &self,
+        point: &(BigRational, BigRational),
+        tolerance: &BigRational,
+    ) -> Vec<EntityId> {
+        let min = (&point.0 - tolerance, &point.1 - tolerance);
+        let max = (&point.0 + tolerance, &point.1 + tolerance);
+        let in_box = |p: &(BigRational, BigRational)| {
+            min.0 <= p.0 && p.0 <= max.0 && min.1 <= p.1 && p.1 <= max.1
+        };
+
+        let world = self.world;
+        let mut picked: Vec<EntityId> = world
+            .feature_meta
+            .keys()
+            .copied()
+            .filter(|&feature| match self.feature_geometry(feature) {
+                Ok(Some(geometry)) => match geometry.as_ref() {
+                    ResolvedGeometry::Area(boundary) => boundary.contains(point),
+                    ResolvedGeometry::Lines(lines) => lines.iter().any(|line| {
+                        line.len() == 1 && in_box(&line[0])
+                            || line
+                                .windows(2)
+                                .any(|s| segment_touches_box(&s[0], &s[1], &min, &max))
+                    }),
+                },
+                Ok(None) => world
+                    .feature_pointers
+                    .get(&feature)
+                    .is_some_and(|pointers| {
+                        pointers.spatial_refs.iter().any(|sref| {
+                            world
+                                .exact_positions
+                                .get(&sref.entity)
+                                .is_some_and(|positions| {
+                                    positions
+                                        .lat
+                                        .iter()
+                                        .zip(positions.lon.iter())
+                                        .any(|(lat, lon)| in_box(&(lat.clone(), lon.clone())))
+                                })
+                        })
+                    }),
+                Err(_) => false,
+            })
+            .collect();
+        world.sort_pick_report(&mut picked);
+        picked
+    }
+}
+
+/// True if segment a-b meets the box [min, max] (clipped parametrically)
+fn segment_touches_box(
+    a: &(BigRational, BigRational),
+    b: &(BigRational, BigRational),
+    min: &(BigRational, BigRational),
+    max: &(BigRational, BigRational),
+) -> bool {
+    let mut enter = BigRational::zero();
+    let mut exit = BigRational::one();
+    for (start, end, low, high) in [(&a.0, &b.0, &min.0, &max.0), (&a.1, &b.1, &min.1, &max.1)] {
+        let delta = end - start;
+        if delta.is_zero() {
+            if start < low || start > high {
+                return false;
+            }
+            continue;
+        }
+        let t_low = (low - start) / &delta;
+        let t_high = (high - start) / &delta;
+        let (near, far) = if t_low <= t_high {
+            (t_low, t_high)
+        } else {
+            (t_high, t_low)
+        };
+        enter = enter.max(near);
+        exit = exit.min(far);
+        if enter > exit {
+            return false;
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::{
+        EntityType, ExactPositions, FeatureMeta, FeaturePointers, SpatialRef, VectorMeta,
+    };
+    use num_bigint::BigInt;
+    use s57_parse::bitstring::{FoidKey, NameKey};
+
+    fn int(v: i64) -> BigRational {
+        BigInt::from(v).into()
+    }
+
+    /// Feature on one vector through `points` (lat, lon): a node for
+    /// points, an edge for lines and areas
+    fn add_feature(
+        world: &mut World,
+        id: u32,
+        objl: u16,
+        prim: u8,
+        points: &[(i64, i64)],
+    ) -> EntityId {
+        let vector = world.create_entity(EntityType::Vector);
+        let name = NameKey {
+            rcnm: if prim == 1 { 110 } else { 130 },
+            rcid: id,
+        };
+        world.name_index.insert(name, vector);
+        world.vector_meta.insert(
+            vector,
+            VectorMeta {
+                name,
+                rver: 1,
+                ruin: 1,
+            },
+        );
+        world.exact_positions.insert(
+            vector,
+            ExactPositions {
+                lat: points.iter().map(|p| int(p.0)).collect(),
+                lon: points.iter().map(|p| int(p.1)).collect(),
+            },
+        );
+
+        let feature = world.create_entity(EntityType::Feature);
+        let foid = FoidKey {
+            agen: 550,
+            fidn: id,
+            fids: 1,
+        };
+        world.foid_index.insert(foid, feature);
+        world.feature_meta.insert(
+            feature,
+            FeatureMeta {
+                foid,
+                prim,
+                grup: if objl >= FIRST_META_OBJL { 2 } else { 1 },
+                objl,
+                rver: 1,
+                ruin: 1,
+            },
+        );
+        world.feature_pointers.insert(
+            feature,
+            FeaturePointers {
+                related_features: vec![],
+                spatial_refs: vec![SpatialRef {
+                    entity: vector,
+                    ornt: 1,
+                    usag: 1,
+                    mask: 255,
+                }],
+            },
+        );
+        feature
+    }
+
+    #[test]
+    fn test_pick_report_order() {
+        assert_eq!(PickGroup::of(159, 1), PickGroup::Danger);
+        assert_eq!(PickGroup::of(159, 3), PickGroup::Danger);
+        assert_eq!(PickGroup::of(17, 1), PickGroup::Aid);
+        assert_eq!(PickGroup::of(30, 2), PickGroup::Other);
+        assert_eq!(PickGroup::of(42, 3), PickGroup::Area);
+        assert_eq!(PickGroup::of(308, 3), PickGroup::Meta);
+
+        let mut world = World::new();
+        let square = [(0, 0), (0, 4), (4, 4), (4, 0), (0, 0)];
+        let quality = add_feature(&mut world, 1, 308, 3, &square);
+        let depare = add_feature(&mut world, 2, 42, 3, &square);
+        let coastline = add_feature(&mut world, 3, 30, 2, &[(0, 1), (4, 3)]);
+        let light = add_feature(&mut world, 4, 75, 1, &[(2, 2)]);
+        let buoy = add_feature(&mut world, 5, 17, 1, &[(2, 2)]);
+        let wreck = add_feature(&mut world, 6, 159, 1, &[(2, 2)]);
+        // Outside the tolerance of (2, 2)
+        add_feature(&mut world, 7, 86, 1, &[(3, 3)]);
+        add_feature(&mut world, 8, 30, 2, &[(0, 0), (1, 1)]);
+        let ctx = TraversalContext::new(&world);
+
+        let tolerance = BigRational::new(BigInt::from(1), BigInt::from(10));
+        let picked = ctx.pick_report(&(int(2), int(2)), &tolerance);
+        assert_eq!(picked, [wreck, buoy, light, coastline, depare, quality]);
+        let groups: Vec<_> = picked.iter().filter_map(|&f| world.pick_group(f)).collect();
+        assert!(groups.is_sorted());
+        assert_eq!(groups[0].to_string(), "dangers");
+
+        // Sorting doesn't depend on the input order
+        let mut reversed: Vec<EntityId> = picked.iter().rev().copied().collect();
+        reversed.insert(2, world.create_entity(EntityType::Vector));
+        world.sort_pick_report(&mut reversed);
+        assert_eq!(reversed[..6], picked[..]);
+        assert!(world.pick_group(reversed[6]).is_none());
+
+        // Away from the light: the obstruction and the areas
+        let picked = TraversalContext::new(&world).pick_report(&(int(3), int(3)), &tolerance);
+        assert_eq!(picked.len(), 3);
+        assert_eq!(world.pick_group(picked[0]), Some(PickGroup::Danger));
+        assert_eq!(picked[1..], [depare, quality]);
+    }
+}
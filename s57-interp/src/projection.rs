@@ -0,0 +1,190 @@
+//! Output map projections
+//!
+//! Chart coordinates stay geographic (exact lat/lon) everywhere else; a
+//! projection is applied only when output is written, in f64. Projected
+//! coordinates are (x east, y north): degrees for plate carrée, metres for
+//! the others.
+
+use crate::region::BoundingBox;
+use num_traits::ToPrimitive;
+
+/// WGS84 semi-major axis (metres)
+const WGS84_A: f64 = 6_378_137.0;
+/// WGS84 flattening
+const WGS84_F: f64 = 1.0 / 298.257_223_563;
+/// Latitude limit of Web Mercator, where the map becomes square
+const WEB_MERCATOR_MAX_LAT: f64 = 85.051_128_779_806_59;
+/// Scale factor on the central meridian of transverse Mercator (as UTM)
+const TM_SCALE: f64 = 0.9996;
+/// False easting of transverse Mercator (as UTM)
+const TM_FALSE_EASTING: f64 = 500_000.0;
+/// False northing of transverse Mercator south of the equator (as UTM)
+const TM_FALSE_NORTHING_SOUTH: f64 = 10_000_000.0;
+
+/// Projection: How (lat, lon) maps to plane coordinates
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Projection {
+    /// Longitude and latitude used directly as x and y (degrees)
+    #[default]
+    PlateCarree,
+    /// Spherical Mercator as used by web maps (EPSG:3857), in metres.
+    /// Latitudes beyond ±85.05° are clamped.
+    WebMercator,
+    /// Ellipsoidal transverse Mercator on WGS84, in metres, with UTM's scale
+    /// factor and false easting/northing (the WMS AUTO:42002 projection).
+    /// Accurate to well under a metre within a few degrees of the central
+    /// meridian, which covers any single cell.
+    TransverseMercator {
+        /// Central meridian (degrees)
+        central_meridian: f64,
+        /// Latitude the projection is set up around (degrees); only its
+        /// hemisphere matters, picking the false northing
+        origin_lat: f64,
+    },
+}
+
+impl Projection {
+    /// Transverse Mercator centred on a box
+    pub fn transverse_mercator_around(bbox: &BoundingBox) -> Self {
+        let deg = |v: &num_rational::BigRational| v.to_f64().unwrap_or(0.0);
+        let centre_lon = deg(&bbox.min_lon) + deg(&bbox.lon_span()) / 2.0;
+        Projection::TransverseMercator {
+            central_meridian: wrap_lon(centre_lon),
+            origin_lat: (deg(&bbox.min_lat) + deg(&bbox.max_lat)) / 2.0,
+        }
+    }
+
+    /// True if projected coordinates are still degrees of lon/lat
+    pub fn is_geographic(&self) -> bool {
+        *self == Projection::PlateCarree
+    }
+
+    /// Project a point to (x, y)
+    pub fn project(&self, lat: f64, lon: f64) -> (f64, f64) {
+        match *self {
+            Projection::PlateCarree => (lon, lat),
+            Projection::WebMercator => {
+                let lat = lat.clamp(-WEB_MERCATOR_MAX_LAT, WEB_MERCATOR_MAX_LAT);
+                let y = (std::f64::consts::FRAC_PI_4 + lat.to_radians() / 2.0)
+                    .tan()
+                    .ln();
+                (WGS84_A * lon.to_radians(), WGS84_A * y)
+            }
+            Projection::TransverseMercator {
+                central_meridian,
+                origin_lat,
+            } => {
+                let (x, y) = transverse_mercator(lat, wrap_lon(lon - central_meridian));
+                let false_northing = if origin_lat < 0.0 {
+                    TM_FALSE_NORTHING_SOUTH
+                } else {
+                    0.0
+                };
+                (x + TM_FALSE_EASTING, y + false_northing)
+            }
+        }
+    }
+
+    /// CRS identifier for the projected coordinates
+    ///
+    /// An OGC CRS URI, except for transverse Mercator, which has no
+    /// registered code and is given as a WMS AUTO:42002 code with its
+    /// central meridian and origin latitude.
+    pub fn crs(&self) -> String {
+        match *self {
+            Projection::PlateCarree => "http://www.opengis.net/def/crs/OGC/1.3/CRS84".to_string(),
+            Projection::WebMercator => "http://www.opengis.net/def/crs/EPSG/0/3857".to_string(),
+            Projection::TransverseMercator {
+                central_meridian,
+                origin_lat,
+            } => format!("AUTO:42002,9001,{},{}", central_meridian, origin_lat),
+        }
+    }
+}
+
+/// Wrap a longitude (or longitude difference) into -180..180
+fn wrap_lon(lon: f64) -> f64 {
+    (lon + 180.0).rem_euclid(360.0) - 180.0
+}
+
+/// Transverse Mercator (x, y) in metres before false easting/northing, for
+/// a point `dlon` degrees east of the central meridian (Snyder, USGS
+/// Professional Paper 1395, eqs. 3-21 and 8-9 to 8-10)
+fn transverse_mercator(lat: f64, dlon: f64) -> (f64, f64) {
+    let e2 = WGS84_F * (2.0 - WGS84_F);
+    let ep2 = e2 / (1.0 - e2);
+    let (e4, e6) = (e2 * e2, e2 * e2 * e2);
+
+    let phi = lat.to_radians();
+    let (sin, cos) = phi.sin_cos();
+    let n = WGS84_A / (1.0 - e2 * sin * sin).sqrt();
+    let t = phi.tan().powi(2);
+    let c = ep2 * cos * cos;
+    let a = dlon.to_radians() * cos;
+
+    // Meridian distance from the equator
+    let m = WGS84_A
+        * ((1.0 - e2 / 4.0 - 3.0 * e4 / 64.0 - 5.0 * e6 / 256.0) * phi
+            - (3.0 * e2 / 8.0 + 3.0 * e4 / 32.0 + 45.0 * e6 / 1024.0) * (2.0 * phi).sin()
+            + (15.0 * e4 / 256.0 + 45.0 * e6 / 1024.0) * (4.0 * phi).sin()
+            - (35.0 * e6 / 3072.0) * (6.0 * phi).sin());
+
+    let x = TM_SCALE
+        * n
+        * (a + (1.0 - t + c) * a.powi(3) / 6.0
+            + (5.0 - 18.0 * t + t * t + 72.0 * c - 58.0 * ep2) * a.powi(5) / 120.0);
+    let y = TM_SCALE
+        * (m + n
+            * phi.tan()
+            * (a * a / 2.0
+                + (5.0 - t + 9.0 * c + 4.0 * c * c) * a.powi(4) / 24.0
+                + (61.0 - 58.0 * t + t * t + 600.0 * c - 330.0 * ep2) * a.powi(6) / 720.0));
+    (x, y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: (f64, f64), expected: (f64, f64), tolerance: f64) {
+        assert!(
+            (actual.0 - expected.0).abs() < tolerance && (actual.1 - expected.1).abs() < tolerance,
+            "{:?} != {:?}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_projections() {
+        assert_eq!(
+            Projection::PlateCarree.project(43.5, -70.25),
+            (-70.25, 43.5)
+        );
+
+        // Web Mercator: the antimeridian and the latitude limit map to the
+        // edge of the square world
+        let edge = std::f64::consts::PI * WGS84_A;
+        assert_close(
+            Projection::WebMercator.project(90.0, 180.0),
+            (edge, edge),
+            1e-3,
+        );
+        assert_close(Projection::WebMercator.project(0.0, 0.0), (0.0, 0.0), 1e-9);
+
+        // Transverse Mercator as UTM zone 19 (central meridian 69W), checked
+        // against the Krüger series for a point off Portland, Maine
+        let utm19 = Projection::TransverseMercator {
+            central_meridian: -69.0,
+            origin_lat: 43.0,
+        };
+        assert_close(
+            utm19.project(43.65, -70.25),
+            (399_194.72, 4_833_759.38),
+            0.05,
+        );
+        // On the central meridian, at the equator: the false easting
+        assert_close(utm19.project(0.0, -69.0), (500_000.0, 0.0), 1e-6);
+        assert_eq!(utm19.crs(), "AUTO:42002,9001,-69,43");
+    }
+}
@@ -0,0 +1,243 @@
+//! Point queries against resolved area geometry
+//!
+//! Identify (which areas cover a position), depth-at-point from depth and
+//! dredged areas, and zone of confidence from M_QUAL. All use the exact
+//! point-in-area predicate, so a position on the edge two areas share is
+//! reported in both instead of falling through the gap between them.
+
+use crate::ecs::{parse_decimal, EntityId, EntityType};
+use crate::geometry_cache::ResolvedGeometry;
+use crate::topology::TraversalContext;
+use num_rational::BigRational;
+
+/// DEPARE: Depth area
+const OBJL_DEPARE: u16 = 42;
+/// DRGARE: Dredged area
+const OBJL_DRGARE: u16 = 46;
+/// M_QUAL: Quality of data
+const OBJL_M_QUAL: u16 = 308;
+/// CATZOC: Category of zone of confidence
+const ATTL_CATZOC: u16 = 72;
+/// DRVAL1 / DRVAL2: Depth range values
+const ATTL_DRVAL1: u16 = 87;
+const ATTL_DRVAL2: u16 = 88;
+
+/// DepthRange: Depth range of the area covering a point
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DepthRange {
+    /// DEPARE or DRGARE feature the range comes from
+    pub feature: EntityId,
+    /// Shoalest depth (DRVAL1), if given
+    pub drval1: Option<BigRational>,
+    /// Deepest depth (DRVAL2), if given
+    pub drval2: Option<BigRational>,
+}
+
+impl TraversalContext<'_> {
+    /// Area features whose geometry contains a (lat, lon) point
+    ///
+    /// Points on an area's boundary count as inside it. Areas whose
+    /// boundary can't be resolved are skipped.
+    pub fn areas_at(&self, point: &(BigRational, BigRational)) -> Vec<EntityId> {
+        self.world
+            .entities_of_type(EntityType::Feature)
+            .into_iter()
+            .filter(|feature| {
+                matches!(
+                    self.feature_geometry(*feature),
+                    Ok(Some(geometry))
+                        if matches!(geometry.as_ref(), ResolvedGeometry::Area(b) if b.contains(point))
+                )
+            })
+            .collect()
+    }
+
+    /// Depth range at a point, from the DEPARE/DRGARE areas covering it
+    ///
+    /// Where several areas cover the point (it lies on a shared edge), the
+    /// shoalest one wins, which is the safe answer for navigation.
+    pub fn depth_at(&self, point: &(BigRational, BigRational)) -> Option<DepthRange> {
+        self.areas_of_class_at(point, &[OBJL_DEPARE, OBJL_DRGARE])
+            .into_iter()
+            .map(|feature| {
+                let attrs = self.world.feature_attributes.get(&feature);
+                let value = |attl| attrs.and_then(|a| a.get(attl)).and_then(parse_decimal);
+                DepthRange {
+                    feature,
+                    drval1: value(ATTL_DRVAL1),
+                    drval2: value(ATTL_DRVAL2),
+                }
+            })
+            .min_by(|a, b| match (&a.drval1, &b.drval1) {
+                (Some(x), Some(y)) => x.cmp(y),
+                // An area without DRVAL1 is less informative; prefer one with it
+                (Some(_), None) => std::cmp::Ordering::Less,
+                (None, Some(_)) => std::cmp::Ordering::Greater,
+                (None, None) => std::cmp::Ordering::Equal,
+            })
+    }
+
+    /// Zone of confidence (CATZOC, 1=A1 .. 6=U) of the M_QUAL covering a point
+    ///
+    /// On a boundary between two M_QUAL areas the worse (higher) category
+    /// is returned.
+    pub fn zoc_at(&self, point: &(BigRational, BigRational)) -> Option<u8> {
+        self.areas_of_class_at(point, &[OBJL_M_QUAL])
+            .into_iter()
+            .filter_map(|feature| {
+                self.world
+                    .feature_attributes
+                    .get(&feature)?
+                    .get(ATTL_CATZOC)?
+                    .trim()
+                    .parse()
+                    .ok()
+            })
+            .max()
+    }
+
+    /// areas_at restricted to some object classes
+    fn areas_of_class_at(
+        &self,
+        point: &(BigRational, BigRational),
+        classes: &[u16],
+    ) -> Vec<EntityId> {
+        self.areas_at(point)
+            .into_iter()
+            .filter(|feature| {
+                self.world
+                    .feature_meta
+                    .get(feature)
+                    .is_some_and(|meta| classes.contains(&meta.objl))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::{
+        ExactPositions, FeatureAttributes, FeatureMeta, FeaturePointers, SpatialRef, VectorMeta,
+        World,
+    };
+    use num_bigint::BigInt;
+    use s57_parse::bitstring::{FoidKey, NameKey};
+
+    fn int(v: i64) -> BigRational {
+        BigInt::from(v).into()
+    }
+
+    /// Area feature bounded by one closed edge through `corners` (lat, lon)
+    fn add_area(
+        world: &mut World,
+        id: u32,
+        objl: u16,
+        corners: &[(i64, i64)],
+        attf: &[(u16, &str)],
+    ) {
+        let edge = world.create_entity(EntityType::Vector);
+        let name = NameKey {
+            rcnm: 130,
+            rcid: id,
+        };
+        world.name_index.insert(name, edge);
+        world.vector_meta.insert(
+            edge,
+            VectorMeta {
+                name,
+                rver: 1,
+                ruin: 1,
+            },
+        );
+        world.exact_positions.insert(
+            edge,
+            ExactPositions {
+                lat: corners.iter().map(|c| int(c.0)).collect(),
+                lon: corners.iter().map(|c| int(c.1)).collect(),
+            },
+        );
+
+        let feature = world.create_entity(EntityType::Feature);
+        let foid = FoidKey {
+            agen: 550,
+            fidn: id,
+            fids: 1,
+        };
+        world.foid_index.insert(foid, feature);
+        world.feature_meta.insert(
+            feature,
+            FeatureMeta {
+                foid,
+                prim: 3,
+                grup: 1,
+                objl,
+                rver: 1,
+                ruin: 1,
+            },
+        );
+        world.feature_attributes.insert(
+            feature,
+            FeatureAttributes {
+                attf: attf.iter().map(|(a, v)| (*a, v.to_string())).collect(),
+                natf: Vec::new(),
+            },
+        );
+        world.feature_pointers.insert(
+            feature,
+            FeaturePointers {
+                related_features: vec![],
+                spatial_refs: vec![SpatialRef {
+                    entity: edge,
+                    ornt: 1,
+                    usag: 1,
+                    mask: 255,
+                }],
+            },
+        );
+    }
+
+    #[test]
+    fn test_point_queries_on_shared_boundary() {
+        let mut world = World::new();
+        // Two depth areas sharing the edge lon = 2
+        add_area(
+            &mut world,
+            1,
+            OBJL_DEPARE,
+            &[(0, 0), (0, 2), (2, 2), (2, 0), (0, 0)],
+            &[(ATTL_DRVAL1, "5"), (ATTL_DRVAL2, "10")],
+        );
+        add_area(
+            &mut world,
+            2,
+            OBJL_DEPARE,
+            &[(0, 2), (0, 4), (2, 4), (2, 2), (0, 2)],
+            &[(ATTL_DRVAL1, "10"), (ATTL_DRVAL2, "20")],
+        );
+        add_area(
+            &mut world,
+            3,
+            OBJL_M_QUAL,
+            &[(0, 0), (0, 4), (2, 4), (2, 0), (0, 0)],
+            &[(ATTL_CATZOC, "3")],
+        );
+        let ctx = TraversalContext::new(&world);
+
+        // Strictly inside the deeper area
+        let depth = ctx.depth_at(&(int(1), int(3))).unwrap();
+        assert_eq!(depth.drval1, Some(int(10)));
+
+        // On the shared edge: both areas, shoalest wins
+        let on_edge = (int(1), int(2));
+        assert_eq!(ctx.areas_at(&on_edge).len(), 3);
+        assert_eq!(ctx.depth_at(&on_edge).unwrap().drval1, Some(int(5)));
+        assert_eq!(ctx.zoc_at(&on_edge), Some(3));
+
+        // Outside everything
+        let outside = (int(3), int(1));
+        assert!(ctx.areas_at(&outside).is_empty());
+        assert_eq!(ctx.depth_at(&outside), None);
+        assert_eq!(ctx.zoc_at(&outside), None);
+    }
+}
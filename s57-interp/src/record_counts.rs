@@ -0,0 +1,382 @@
+//! DSSI record count cross-check
+//!
+//! The DSSI field declares how many meta, cartographic, geo and collection
+//! feature records and how many isolated node, connected node, edge and face
+//! vector records a file contains. Comparing those against the records that
+//! actually made it into the World catches truncated files and records that
+//! were skipped while building. `World::validate_structure` adds the checks
+//! on the rest of DSSI: the topology level and the lexical levels.
+
+use crate::ecs::{DatasetStructure, TopologyLevel, World};
+use std::fmt;
+
+/// RecordKind: The record categories counted in DSSI
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RecordKind {
+    /// Meta feature records (NOMR), OBJL 300-399
+    Meta,
+    /// Cartographic feature records (NOCR), OBJL 500-599
+    Cartographic,
+    /// Geo feature records (NOGR), all other classes
+    Geo,
+    /// Collection feature records (NOLR), OBJL 400-499
+    Collection,
+    /// Isolated node records (NOIN), RCNM 110
+    IsolatedNode,
+    /// Connected node records (NOCN), RCNM 120
+    ConnectedNode,
+    /// Edge records (NOED), RCNM 130
+    Edge,
+    /// Face records (NOFA), RCNM 140
+    Face,
+}
+
+impl RecordKind {
+    /// All kinds, in DSSI subfield order
+    pub const ALL: [RecordKind; 8] = [
+        RecordKind::Meta,
+        RecordKind::Cartographic,
+        RecordKind::Geo,
+        RecordKind::Collection,
+        RecordKind::IsolatedNode,
+        RecordKind::ConnectedNode,
+        RecordKind::Edge,
+        RecordKind::Face,
+    ];
+
+    /// Classify a feature record by its object class
+    pub fn of_feature(objl: u16) -> Self {
+        match objl {
+            300..=399 => RecordKind::Meta,
+            400..=499 => RecordKind::Collection,
+            500..=599 => RecordKind::Cartographic,
+            _ => RecordKind::Geo,
+        }
+    }
+
+    /// Classify a vector record by its RCNM, if it is one DSSI counts
+    pub fn of_vector(rcnm: u8) -> Option<Self> {
+        match rcnm {
+            110 => Some(RecordKind::IsolatedNode),
+            120 => Some(RecordKind::ConnectedNode),
+            130 => Some(RecordKind::Edge),
+            140 => Some(RecordKind::Face),
+            _ => None,
+        }
+    }
+
+    /// Count declared for this kind in a DSSI field
+    pub fn declared(self, dssi: &DatasetStructure) -> u32 {
+        match self {
+            RecordKind::Meta => dssi.nomr,
+            RecordKind::Cartographic => dssi.nocr,
+            RecordKind::Geo => dssi.nogr,
+            RecordKind::Collection => dssi.nolr,
+            RecordKind::IsolatedNode => dssi.noin,
+            RecordKind::ConnectedNode => dssi.nocn,
+            RecordKind::Edge => dssi.noed,
+            RecordKind::Face => dssi.nofa,
+        }
+    }
+
+    /// Human-readable description
+    pub fn description(self) -> &'static str {
+        match self {
+            RecordKind::Meta => "meta records",
+            RecordKind::Cartographic => "cartographic records",
+            RecordKind::Geo => "geo records",
+            RecordKind::Collection => "collection records",
+            RecordKind::IsolatedNode => "isolated node records",
+            RecordKind::ConnectedNode => "connected node records",
+            RecordKind::Edge => "edge records",
+            RecordKind::Face => "face records",
+        }
+    }
+}
+
+/// RecordCountMismatch: A DSSI count that doesn't match the parsed records
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecordCountMismatch {
+    pub kind: RecordKind,
+    /// Count declared in DSSI
+    pub declared: u32,
+    /// Records of this kind present in the World
+    pub actual: usize,
+}
+
+impl fmt::Display for RecordCountMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: DSSI declares {}, found {}",
+            self.kind.description(),
+            self.declared,
+            self.actual
+        )
+    }
+}
+
+impl World {
+    /// Count feature and vector records of a kind
+    pub fn record_count(&self, kind: RecordKind) -> usize {
+        match kind {
+            RecordKind::Meta
+            | RecordKind::Cartographic
+            | RecordKind::Geo
+            | RecordKind::Collection => self
+                .feature_meta
+                .values()
+                .filter(|meta| RecordKind::of_feature(meta.objl) == kind)
+                .count(),
+            _ => self
+                .vector_meta
+                .values()
+                .filter(|meta| RecordKind::of_vector(meta.name.rcnm) == Some(kind))
+                .count(),
+        }
+    }
+
+    /// Compare the DSSI record counts against the records actually parsed
+    ///
+    /// Returns one entry per kind whose counts differ; empty if they all
+    /// match or the file had no DSSI field. Only meaningful for a World built
+    /// from a single file, before updates are applied.
+    pub fn check_record_counts(&self) -> Vec<RecordCountMismatch> {
+        let Some(dssi) = &self.dataset_structure else {
+            return Vec::new();
+        };
+        RecordKind::ALL
+            .into_iter()
+            .filter_map(|kind| {
+                let declared = kind.declared(dssi);
+                let actual = self.record_count(kind);
+                (declared as usize != actual).then_some(RecordCountMismatch {
+                    kind,
+                    declared,
+                    actual,
+                })
+            })
+            .collect()
+    }
+}
+
+/// StructureIssue: A way the World disagrees with its DSSI field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StructureIssue {
+    /// A declared record count doesn't match the records parsed
+    RecordCount(RecordCountMismatch),
+    /// DSTR is not a known topology level
+    UnknownTopology(u8),
+    /// Records the declared topology level has no place for: connected
+    /// nodes in cartographic spaghetti, faces below full topology
+    UnexpectedRecords {
+        kind: RecordKind,
+        topology: TopologyLevel,
+        count: usize,
+    },
+    /// AALL (ATTF) or NALL (NATF) outside the levels the field allows
+    InvalidLexicalLevel { field: &'static str, level: u8 },
+}
+
+impl fmt::Display for StructureIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StructureIssue::RecordCount(mismatch) => mismatch.fmt(f),
+            StructureIssue::UnknownTopology(dstr) => write!(f, "unknown DSTR {}", dstr),
+            StructureIssue::UnexpectedRecords {
+                kind,
+                topology,
+                count,
+            } => write!(f, "{} in {}: {}", kind.description(), topology, count),
+            StructureIssue::InvalidLexicalLevel { field, level } => {
+                write!(f, "{} lexical level {} is not allowed", field, level)
+            }
+        }
+    }
+}
+
+impl World {
+    /// Cross-check the World against its whole DSSI field
+    ///
+    /// Reports the record count mismatches of `check_record_counts`, an
+    /// unknown DSTR, vector records the declared topology level can't
+    /// contain, and lexical levels other than 0-1 for AALL or 0-2 for NALL.
+    /// Empty if everything agrees or the file had no DSSI field.
+    pub fn validate_structure(&self) -> Vec<StructureIssue> {
+        let Some(dssi) = &self.dataset_structure else {
+            return Vec::new();
+        };
+        let mut issues: Vec<StructureIssue> = self
+            .check_record_counts()
+            .into_iter()
+            .map(StructureIssue::RecordCount)
+            .collect();
+
+        match dssi.topology() {
+            Some(topology) => {
+                let unexpected: &[RecordKind] = match topology {
+                    TopologyLevel::CartographicSpaghetti => {
+                        &[RecordKind::ConnectedNode, RecordKind::Face]
+                    }
+                    TopologyLevel::ChainNode | TopologyLevel::PlanarGraph => &[RecordKind::Face],
+                    TopologyLevel::FullTopology | TopologyLevel::NotRelevant => &[],
+                };
+                for &kind in unexpected {
+                    let count = self.record_count(kind);
+                    if count > 0 {
+                        issues.push(StructureIssue::UnexpectedRecords {
+                            kind,
+                            topology,
+                            count,
+                        });
+                    }
+                }
+            }
+            None => issues.push(StructureIssue::UnknownTopology(dssi.dstr)),
+        }
+
+        for (field, level, max) in [("AALL", dssi.aall, 1), ("NALL", dssi.nall, 2)] {
+            if level > max {
+                issues.push(StructureIssue::InvalidLexicalLevel { field, level });
+            }
+        }
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::{EntityType, FeatureMeta, VectorMeta};
+    use s57_parse::bitstring::{FoidKey, NameKey};
+
+    #[test]
+    fn test_check_record_counts() {
+        let mut world = World::new();
+        assert!(world.check_record_counts().is_empty());
+
+        for (fidn, objl) in [(1, 42), (2, 302)] {
+            let feature = world.create_entity(EntityType::Feature);
+            world.feature_meta.insert(
+                feature,
+                FeatureMeta {
+                    foid: FoidKey {
+                        agen: 550,
+                        fidn,
+                        fids: 1,
+                    },
+                    prim: 3,
+                    grup: 1,
+                    objl,
+                    rver: 1,
+                    ruin: 1,
+                },
+            );
+        }
+        let node = world.create_entity(EntityType::Vector);
+        world.vector_meta.insert(
+            node,
+            VectorMeta {
+                name: NameKey { rcnm: 120, rcid: 1 },
+                rver: 1,
+                ruin: 1,
+            },
+        );
+
+        world.dataset_structure = Some(DatasetStructure {
+            dstr: 2,
+            nomr: 1,
+            nogr: 2,
+            nocn: 1,
+            ..Default::default()
+        });
+        let mismatches = world.check_record_counts();
+        assert_eq!(
+            mismatches,
+            vec![RecordCountMismatch {
+                kind: RecordKind::Geo,
+                declared: 2,
+                actual: 1,
+            }]
+        );
+        assert_eq!(
+            mismatches[0].to_string(),
+            "geo records: DSSI declares 2, found 1"
+        );
+    }
+
+    #[test]
+    fn test_validate_structure() {
+        let mut world = World::new();
+        assert!(world.validate_structure().is_empty());
+
+        for (rcid, rcnm) in [(1, 120), (2, 140)] {
+            let vector = world.create_entity(EntityType::Vector);
+            world.vector_meta.insert(
+                vector,
+                VectorMeta {
+                    name: NameKey { rcnm, rcid },
+                    rver: 1,
+                    ruin: 1,
+                },
+            );
+        }
+        let declared = DatasetStructure {
+            dstr: 4,
+            nall: 2,
+            nocn: 1,
+            nofa: 1,
+            ..Default::default()
+        };
+        world.dataset_structure = Some(declared.clone());
+        assert!(world.validate_structure().is_empty());
+
+        // A face in a spaghetti file whose ATTF claims UCS-2
+        world.dataset_structure = Some(DatasetStructure {
+            dstr: 1,
+            aall: 2,
+            ..declared.clone()
+        });
+        let issues = world.validate_structure();
+        assert_eq!(
+            issues,
+            vec![
+                StructureIssue::UnexpectedRecords {
+                    kind: RecordKind::ConnectedNode,
+                    topology: TopologyLevel::CartographicSpaghetti,
+                    count: 1,
+                },
+                StructureIssue::UnexpectedRecords {
+                    kind: RecordKind::Face,
+                    topology: TopologyLevel::CartographicSpaghetti,
+                    count: 1,
+                },
+                StructureIssue::InvalidLexicalLevel {
+                    field: "AALL",
+                    level: 2,
+                },
+            ]
+        );
+        assert_eq!(
+            issues[1].to_string(),
+            "face records in cartographic spaghetti: 1"
+        );
+
+        world.dataset_structure = Some(DatasetStructure {
+            dstr: 7,
+            nofa: 0,
+            ..declared
+        });
+        assert_eq!(
+            world.validate_structure(),
+            vec![
+                StructureIssue::RecordCount(RecordCountMismatch {
+                    kind: RecordKind::Face,
+                    declared: 0,
+                    actual: 1,
+                }),
+                StructureIssue::UnknownTopology(7),
+            ]
+        );
+    }
+}
@@ -0,0 +1,1062 @@
+//! Region extraction across one or more Worlds
+//!
+//! Selects every feature whose extent touches a bounding box and copies it
+//! into a fresh World. A feature inside the box is copied whole, together
+//! with the vectors it is built from. One that crosses the box edge is cut
+//! to it: areas through `clip::intersection`, lines through
+//! `clip::clip_line` and multi-point soundings point by point, each cut
+//! piece on a new edge or node of its own.
+//!
+//! When several cells are merged, a feature split across cells (it carries
+//! the same FOID in each) becomes one feature holding the parts from every
+//! cell, and vector NAMEs that collide with an already-copied vector get a
+//! fresh RCID. Where overlapping cells of different compilation scales both
+//! chart a feature, the most detailed copy is the one kept.
+
+use crate::clip::{clip_line, intersection};
+use crate::ecs::{
+    EntityId, EntityType, ExactDepths, ExactPositions, FeaturePointers, FeatureRef, SpatialRef,
+    VectorMeta, VectorNeighbor, VectorTopology, World,
+};
+use crate::foid_registry::FoidRegistry;
+use crate::geometry_cache::ResolvedGeometry;
+use crate::topology::{AreaBoundary, TraversalContext};
+use num_rational::BigRational;
+use num_traits::FromPrimitive;
+use s57_parse::bitstring::NameKey;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::ops::{Add, Sub};
+
+/// BoundingBox: Exact lat/lon extent (degrees)
+///
+/// A box with `min_lon > max_lon` crosses the antimeridian: it covers
+/// `min_lon..=180` and `-180..=max_lon` (the RFC 7946 bbox convention).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoundingBox {
+    pub min_lat: BigRational,
+    pub min_lon: BigRational,
+    pub max_lat: BigRational,
+    pub max_lon: BigRational,
+}
+
+impl BoundingBox {
+    /// Create a bounding box from exact corners
+    pub fn new(
+        min_lat: BigRational,
+        min_lon: BigRational,
+        max_lat: BigRational,
+        max_lon: BigRational,
+    ) -> Self {
+        Self {
+            min_lat,
+            min_lon,
+            max_lat,
+            max_lon,
+        }
+    }
+
+    /// Create a bounding box from f64 degrees (e.g. user input)
+    ///
+    /// Returns None if any value is not finite.
+    pub fn from_degrees(min_lat: f64, min_lon: f64, max_lat: f64, max_lon: f64) -> Option<Self> {
+        Some(Self::new(
+            BigRational::from_float(min_lat)?,
+            BigRational::from_float(min_lon)?,
+            BigRational::from_float(max_lat)?,
+            BigRational::from_float(max_lon)?,
+        ))
+    }
+
+    /// Degenerate box around a single point
+    pub fn from_point(lat: &BigRational, lon: &BigRational) -> Self {
+        Self::new(lat.clone(), lon.clone(), lat.clone(), lon.clone())
+    }
+
+    /// Smallest box around a set of (lat, lon) points
+    ///
+    /// The longitude range is the shortest one covering every point, so a
+    /// cell straddling 180° gets a narrow box that crosses the antimeridian
+    /// rather than one spanning the whole world. Returns None for no points.
+    pub fn from_points<'a>(
+        points: impl IntoIterator<Item = (&'a BigRational, &'a BigRational)>,
+    ) -> Option<Self> {
+        let mut lat_range: Option<(BigRational, BigRational)> = None;
+        let mut lons = Vec::new();
+        for (lat, lon) in points {
+            match lat_range.as_mut() {
+                Some((min, max)) => {
+                    if lat < min {
+                        *min = lat.clone();
+                    }
+                    if lat > max {
+                        *max = lat.clone();
+                    }
+                }
+                None => lat_range = Some((lat.clone(), lat.clone())),
+            }
+            lons.push((lon.clone(), lon.clone()));
+        }
+        let (min_lat, max_lat) = lat_range?;
+        let (min_lon, max_lon) = covering_lon_range(lons)?;
+        Some(Self::new(min_lat, min_lon, max_lat, max_lon))
+    }
+
+    /// Smallest box covering both boxes
+    pub fn union(&self, other: &BoundingBox) -> BoundingBox {
+        let (min_lon, max_lon) = covering_lon_range([
+            (self.min_lon.clone(), self.max_lon.clone()),
+            (other.min_lon.clone(), other.max_lon.clone()),
+        ])
+        .expect("two ranges");
+        BoundingBox::new(
+            (&self.min_lat).min(&other.min_lat).clone(),
+            min_lon,
+            (&self.max_lat).max(&other.max_lat).clone(),
+            max_lon,
+        )
+    }
+
+    /// Grow the box to include a point
+    ///
+    /// Longitude grows without wrapping; use `from_points` or `union` when
+    /// the extent may cross the antimeridian.
+    pub fn expand(&mut self, lat: &BigRational, lon: &BigRational) {
+        if *lat < self.min_lat {
+            self.min_lat = lat.clone();
+        }
+        if *lat > self.max_lat {
+            self.max_lat = lat.clone();
+        }
+        if *lon < self.min_lon {
+            self.min_lon = lon.clone();
+        }
+        if *lon > self.max_lon {
+            self.max_lon = lon.clone();
+        }
+    }
+
+    /// True if the box crosses the antimeridian
+    pub fn crosses_antimeridian(&self) -> bool {
+        self.min_lon > self.max_lon
+    }
+
+    /// Width of the box in degrees of longitude
+    pub fn lon_span(&self) -> BigRational {
+        let span = &self.max_lon - &self.min_lon;
+        if self.crosses_antimeridian() {
+            span + degrees(360)
+        } else {
+            span
+        }
+    }
+
+    /// Check whether two boxes overlap (touching edges count)
+    pub fn intersects(&self, other: &BoundingBox) -> bool {
+        self.min_lat <= other.max_lat
+            && other.min_lat <= self.max_lat
+            && self.lon_parts().iter().any(|(west, east)| {
+                other
+                    .lon_parts()
+                    .iter()
+                    .any(|(other_west, other_east)| west <= other_east && other_west <= east)
+            })
+    }
+
+    /// Check whether `other` lies wholly inside the box (edges included)
+    pub fn contains(&self, other: &BoundingBox) -> bool {
+        self.min_lat <= other.min_lat
+            && other.max_lat <= self.max_lat
+            && other.lon_parts().iter().all(|(other_west, other_east)| {
+                self.lon_parts()
+                    .iter()
+                    .any(|(west, east)| west <= other_west && other_east <= east)
+            })
+    }
+
+    /// The box as one rectangle per non-wrapping longitude interval
+    fn rectangles(&self) -> Vec<AreaBoundary> {
+        self.lon_parts()
+            .into_iter()
+            .map(|(west, east)| {
+                AreaBoundary::rectangle(self.min_lat.clone(), west, self.max_lat.clone(), east)
+            })
+            .collect()
+    }
+
+    /// Longitude range as one or two non-wrapping (west, east) intervals
+    fn lon_parts(&self) -> Vec<(BigRational, BigRational)> {
+        if self.crosses_antimeridian() {
+            vec![
+                (self.min_lon.clone(), degrees(180)),
+                (degrees(-180), self.max_lon.clone()),
+            ]
+        } else {
+            vec![(self.min_lon.clone(), self.max_lon.clone())]
+        }
+    }
+}
+
+fn degrees(value: i32) -> BigRational {
+    BigRational::from_integer(value.into())
+}
+
+/// Shortest longitude range covering every (west, east) range
+///
+/// Ranges with west > east cross the antimeridian. The result leaves out
+/// the widest stretch of longitude that no range touches; it crosses the
+/// antimeridian (west > east) when that stretch isn't the one across 180°.
+/// Works on exact or f64 degrees in -180..=180. Returns None for no ranges.
+pub fn covering_lon_range<T>(ranges: impl IntoIterator<Item = (T, T)>) -> Option<(T, T)>
+where
+    T: Clone + PartialOrd + Add<Output = T> + Sub<Output = T> + FromPrimitive,
+{
+    let deg = |v: i32| T::from_i32(v).expect("degrees fit");
+    let mut spans = Vec::new();
+    for (west, east) in ranges {
+        if west <= east {
+            spans.push((west, east));
+        } else {
+            spans.push((west, deg(180)));
+            spans.push((deg(-180), east));
+        }
+    }
+    spans.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+
+    let mut merged: Vec<(T, T)> = Vec::new();
+    for (west, east) in spans {
+        if let Some(last) = merged.last_mut() {
+            if west <= last.1 {
+                if east > last.1 {
+                    last.1 = east;
+                }
+                continue;
+            }
+        }
+        merged.push((west, east));
+    }
+
+    // The stretch across 180° wins ties, keeping ordinary boxes unwrapped
+    let (first, last) = (merged.first()?, merged.last()?);
+    let mut widest = first.0.clone() + deg(360) - last.1.clone();
+    let mut range = (first.0.clone(), last.1.clone());
+    for pair in merged.windows(2) {
+        let gap = pair[1].0.clone() - pair[0].1.clone();
+        if gap > widest {
+            widest = gap;
+            range = (pair[1].0.clone(), pair[0].1.clone());
+        }
+    }
+    Some(range)
+}
+
+impl World {
+    /// Extent of every positioned vector in the World
+    ///
+    /// Exact, from either coordinate backend. Returns None if nothing has a
+    /// position.
+    pub fn extent(&self) -> Option<BoundingBox> {
+        let fixed: Vec<ExactPositions> = self
+            .fixed_positions
+            .values()
+            .map(|positions| positions.to_exact())
+            .collect();
+        BoundingBox::from_points(
+            self.exact_positions
+                .values()
+                .chain(&fixed)
+                .flat_map(|positions| positions.lat.iter().zip(positions.lon.iter())),
+        )
+    }
+
+    /// Compute the extent of a feature from the vectors it references
+    ///
+    /// Uses the FSPT spatial references and, for edges, their VRPT nodes,
+    /// from either coordinate backend. Returns None if the feature has no
+    /// positioned vectors.
+    pub fn feature_bbox(&self, feature: EntityId) -> Option<BoundingBox> {
+        let pointers = self.feature_pointers.get(&feature)?;
+        let mut vectors = Vec::new();
+
+        for sref in &pointers.spatial_refs {
+            vectors.extend(self.exact_positions_of(sref.entity));
+            if let Some(topology) = self.vector_topology.get(&sref.entity) {
+                for neighbor in &topology.neighbors {
+                    vectors.extend(self.exact_positions_of(neighbor.entity));
+                }
+            }
+        }
+
+        BoundingBox::from_points(
+            vectors
+                .iter()
+                .flat_map(|positions| positions.lat.iter().zip(positions.lon.iter())),
+        )
+    }
+}
+
+/// Extract all features touching `bbox` from `worlds` into a single World
+///
+/// Dataset parameters are taken from the first world that has them. Feature
+/// to feature pointers (FFPT) are kept only when the target feature was also
+/// extracted. Mariner overlay objects touching `bbox` are carried over too.
+///
+/// A FOID charted in several cells is taken from the cell with the smallest
+/// compilation scale denominator (see `FoidRegistry::best`); the parts
+/// charted by cells of equal scale, such as a feature split at a cell edge,
+/// are merged into the feature copied from the first of them. Features
+/// whose geometry doesn't resolve are copied whole rather than cut.
+pub fn extract_region(worlds: &[&World], bbox: &BoundingBox) -> World {
+    let mut out = World::new();
+    out.dataset_params = worlds.iter().find_map(|w| w.dataset_params.clone());
+    let registry = FoidRegistry::build(worlds);
+    let clip = bbox.rectangles();
+
+    for src in worlds {
+        let cscl = src.dataset_params.as_ref().map(|p| p.cscl);
+        let ctx = TraversalContext::new(src);
+        let mut copier = Copier {
+            src,
+            map: HashMap::new(),
+        };
+        let mut copied_features = Vec::new();
+
+        for feature in src.entities_of_type(EntityType::Feature) {
+            let Some(meta) = src.feature_meta.get(&feature) else {
+                continue;
+            };
+            let more_detailed = registry
+                .best(&meta.foid)
+                .is_some_and(|best| best.cscl.unwrap_or(u32::MAX) < cscl.unwrap_or(u32::MAX));
+            if more_detailed {
+                continue;
+            }
+            let Some(extent) = src
+                .feature_bbox(feature)
+                .filter(|extent| extent.intersects(bbox))
+            else {
+                continue;
+            };
+
+            let spatial_refs = if bbox.contains(&extent) {
+                copier.copy_spatial_refs(&mut out, feature)
+            } else {
+                match copier.clip_spatial_refs(&mut out, &ctx, feature, bbox, &clip) {
+                    Some(refs) if refs.is_empty() => continue,
+                    Some(refs) => refs,
+                    None => copier.copy_spatial_refs(&mut out, feature),
+                }
+            };
+
+            match out.foid_index.get(&meta.foid) {
+                Some(&existing) => {
+                    copier.map.insert(feature, existing);
+                    if let Some(pointers) = out.feature_pointers.get_mut(&existing) {
+                        pointers.spatial_refs.extend(spatial_refs);
+                    }
+                }
+                None => {
+                    let new_feature = copier.copy_feature(&mut out, feature, spatial_refs);
+                    copied_features.push((feature, new_feature));
+                }
+            }
+        }
+
+        // Second pass: remap FFPT now that every extracted feature has an id
+        for (old, new) in copied_features {
+            let Some(related) = src
+                .feature_pointers
+                .get(&old)
+                .map(|p| p.related_features.clone())
+            else {
+                continue;
+            };
+            let remapped: Vec<FeatureRef> = related
+                .iter()
+                .filter_map(|r| {
+                    Some(FeatureRef {
+                        entity: *copier.map.get(&r.entity)?,
+                        rind: r.rind,
+                    })
+                })
+                .collect();
+            if let Some(pointers) = out.feature_pointers.get_mut(&new) {
+                pointers.related_features = remapped;
+            }
+        }
+
+        out.overlay.objects.extend(
+            src.overlay
+                .objects
+                .iter()
+                .filter(|o| o.bbox().is_some_and(|extent| extent.intersects(bbox)))
+                .cloned(),
+        );
+    }
+
+    out
+}
+
+/// Copies entities from one World into another, remembering the id mapping
+struct Copier<'a> {
+    src: &'a World,
+    map: HashMap<EntityId, EntityId>,
+}
+
+impl Copier<'_> {
+    /// Copy a feature, pointing it at `spatial_refs` in the output World
+    fn copy_feature(
+        &mut self,
+        out: &mut World,
+        feature: EntityId,
+        spatial_refs: Vec<SpatialRef>,
+    ) -> EntityId {
+        let new = out.create_entity(EntityType::Feature);
+        self.map.insert(feature, new);
+
+        if let Some(meta) = self.src.feature_meta.get(&feature) {
+            out.foid_index.insert(meta.foid, new);
+            out.feature_meta.insert(new, meta.clone());
+        }
+        if let Some(attrs) = self.src.feature_attributes.get(&feature) {
+            out.feature_attributes.insert(new, attrs.clone());
+        }
+        self.copy_common(out, feature, new);
+
+        if self.src.feature_pointers.contains_key(&feature) {
+            out.feature_pointers.insert(
+                new,
+                FeaturePointers {
+                    related_features: Vec::new(),
+                    spatial_refs,
+                },
+            );
+        }
+
+        new
+    }
+
+    /// Copy every vector a feature references, returning the remapped FSPT
+    fn copy_spatial_refs(&mut self, out: &mut World, feature: EntityId) -> Vec<SpatialRef> {
+        let Some(pointers) = self.src.feature_pointers.get(&feature) else {
+            return Vec::new();
+        };
+        pointers
+            .spatial_refs
+            .iter()
+            .map(|sref| SpatialRef {
+                entity: self.copy_vector(out, sref.entity),
+                ..*sref
+            })
+            .collect()
+    }
+
+    /// Cut a feature's geometry to the box, returning FSPT to the pieces
+    ///
+    /// `clip` is the box as rectangles (two across the antimeridian). The
+    /// pieces go on new vectors; masking doesn't carry over to them. An
+    /// empty result means nothing of the feature lies inside. Returns None
+    /// if the geometry doesn't resolve.
+    fn clip_spatial_refs(
+        &mut self,
+        out: &mut World,
+        ctx: &TraversalContext,
+        feature: EntityId,
+        bbox: &BoundingBox,
+        clip: &[AreaBoundary],
+    ) -> Option<Vec<SpatialRef>> {
+        let piece = |entity, usag| SpatialRef {
+            entity,
+            ornt: 1,
+            usag,
+            mask: 255,
+        };
+        let mut refs = Vec::new();
+        match ctx.feature_geometry(feature).ok()?.as_deref() {
+            Some(ResolvedGeometry::Area(boundary)) => {
+                for rect in clip {
+                    let cut = intersection(boundary, rect);
+                    for ring in cut.exterior {
+                        refs.push(piece(new_edge(out, ring), 1));
+                    }
+                    for ring in cut.interior {
+                        refs.push(piece(new_edge(out, ring), 2));
+                    }
+                }
+            }
+            Some(ResolvedGeometry::Lines(lines)) => {
+                for line in lines {
+                    for rect in clip {
+                        for cut in clip_line(line, rect) {
+                            refs.push(piece(new_edge(out, cut), 255));
+                        }
+                    }
+                }
+            }
+            None => {
+                let pointers = self.src.feature_pointers.get(&feature)?;
+                for sref in &pointers.spatial_refs {
+                    if let Some(entity) = self.clip_points(out, sref.entity, bbox) {
+                        refs.push(SpatialRef { entity, ..*sref });
+                    }
+                }
+            }
+        }
+        Some(refs)
+    }
+
+    /// Copy the positions (and depths) of a point vector that lie in the box
+    ///
+    /// A vector wholly inside is copied as it is; one partly inside becomes
+    /// a new node with just those points, held exactly whichever backend
+    /// the source used. Returns None if none are inside.
+    fn clip_points(
+        &mut self,
+        out: &mut World,
+        vector: EntityId,
+        bbox: &BoundingBox,
+    ) -> Option<EntityId> {
+        let positions = self.src.exact_positions_of(vector)?;
+        let inside: Vec<usize> = (0..positions.lat.len().min(positions.lon.len()))
+            .filter(|&i| {
+                bbox.contains(&BoundingBox::from_point(
+                    &positions.lat[i],
+                    &positions.lon[i],
+                ))
+            })
+            .collect();
+        if inside.is_empty() {
+            return None;
+        }
+        if inside.len() == positions.lat.len() {
+            return Some(self.copy_vector(out, vector));
+        }
+
+        let rcnm = self
+            .src
+            .vector_meta
+            .get(&vector)
+            .map_or(110, |meta| meta.name.rcnm);
+        let new = new_vector(out, rcnm);
+        out.exact_positions.insert(
+            new,
+            ExactPositions {
+                lat: inside.iter().map(|&i| positions.lat[i].clone()).collect(),
+                lon: inside.iter().map(|&i| positions.lon[i].clone()).collect(),
+            },
+        );
+        if let Some(depths) = self.src.exact_depths_of(vector) {
+            out.exact_depths.insert(
+                new,
+                ExactDepths {
+                    depth: inside
+                        .iter()
+                        .filter_map(|&i| depths.depth.get(i).cloned())
+                        .collect(),
+                    units: depths.units,
+                },
+            );
+        }
+        if let Some(attrs) = self.src.vector_attributes.get(&vector) {
+            out.vector_attributes.insert(new, attrs.clone());
+        }
+        Some(new)
+    }
+
+    /// Copy a vector (and, recursively, its VRPT neighbors) once
+    fn copy_vector(&mut self, out: &mut World, vector: EntityId) -> EntityId {
+        if let Some(existing) = self.map.get(&vector) {
+            return *existing;
+        }
+
+        let new = out.create_entity(EntityType::Vector);
+        self.map.insert(vector, new);
+
+        if let Some(meta) = self.src.vector_meta.get(&vector) {
+            let mut meta = meta.clone();
+            if out.name_index.contains_key(&meta.name) {
+                meta.name = next_free_name(out, meta.name.rcnm);
+            }
+            out.name_index.insert(meta.name, new);
+            out.vector_meta.insert(new, meta);
+        }
+        if let Some(positions) = self.src.exact_positions.get(&vector) {
+            out.exact_positions.insert(new, positions.clone());
+        }
+        if let Some(depths) = self.src.exact_depths.get(&vector) {
+            out.exact_depths.insert(new, depths.clone());
+        }
+        if let Some(positions) = self.src.fixed_positions.get(&vector) {
+            out.fixed_positions.insert(new, positions.clone());
+        }
+        if let Some(depths) = self.src.fixed_depths.get(&vector) {
+            out.fixed_depths.insert(new, depths.clone());
+        }
+        if let Some(positions) = self.src.approx_positions.get(&vector) {
+            out.approx_positions.insert(new, positions.clone());
+        }
+        if let Some(depths) = self.src.approx_depths.get(&vector) {
+            out.approx_depths.insert(new, depths.clone());
+        }
+        if let Some(attrs) = self.src.vector_attributes.get(&vector) {
+            out.vector_attributes.insert(new, attrs.clone());
+        }
+        self.copy_common(out, vector, new);
+
+        if let Some(topology) = self.src.vector_topology.get(&vector) {
+            let neighbors = topology
+                .neighbors
+                .iter()
+                .map(|n| VectorNeighbor {
+                    entity: self.copy_vector(out, n.entity),
+                    ..*n
+                })
+                .collect();
+            out.vector_topology
+                .insert(new, VectorTopology { neighbors });
+        }
+
+        new
+    }
+
+    /// Copy components shared by features and vectors
+    fn copy_common(&self, out: &mut World, old: EntityId, new: EntityId) {
+        if let Some(controls) = self.src.update_controls.get(&old) {
+            out.update_controls.insert(new, controls.clone());
+        }
+        if let Some(raw) = self.src.raw_fields.get(&old) {
+            out.raw_fields.insert(new, raw.clone());
+        }
+        if let Some(source) = self.src.record_sources.get(&old) {
+            out.record_sources.insert(new, *source);
+        }
+    }
+}
+
+/// A new vector with a fresh NAME of record type `rcnm`
+fn new_vector(out: &mut World, rcnm: u8) -> EntityId {
+    let new = out.create_entity(EntityType::Vector);
+    let name = next_free_name(out, rcnm);
+    out.name_index.insert(name, new);
+    out.vector_meta.insert(
+        new,
+        VectorMeta {
+            name,
+            rver: 1,
+            ruin: 1,
+        },
+    );
+    new
+}
+
+/// A new edge running through `points`, end points included
+fn new_edge(out: &mut World, points: Vec<(BigRational, BigRational)>) -> EntityId {
+    let new = new_vector(out, 130);
+    let (lat, lon) = points.into_iter().unzip();
+    out.exact_positions.insert(new, ExactPositions { lat, lon });
+    new
+}
+
+/// First unused NAME for a record type in the output World
+fn next_free_name(out: &World, rcnm: u8) -> NameKey {
+    let max_rcid = out
+        .name_index
+        .keys()
+        .filter(|name| name.rcnm == rcnm)
+        .map(|name| name.rcid)
+        .max()
+        .unwrap_or(0);
+    NameKey {
+        rcnm,
+        rcid: max_rcid + 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::{DatasetParams, FeatureMeta, VectorMeta};
+    use crate::fixed::FixedPositions;
+    use num_bigint::BigInt;
+    use s57_parse::bitstring::FoidKey;
+    use smallvec::smallvec;
+
+    fn deg(v: i64) -> BigRational {
+        BigRational::from_integer(BigInt::from(v))
+    }
+
+    /// World with one point feature per (fidn, lat, lon), all on VI 1..n
+    fn world_with_points(points: &[(u32, i64, i64)]) -> World {
+        let mut world = World::new();
+        for (i, (fidn, lat, lon)) in points.iter().enumerate() {
+            let node = world.create_entity(EntityType::Vector);
+            let name = NameKey {
+                rcnm: 110,
+                rcid: i as u32 + 1,
+            };
+            world.name_index.insert(name, node);
+            world.vector_meta.insert(
+                node,
+                VectorMeta {
+                    name,
+                    rver: 1,
+                    ruin: 1,
+                },
+            );
+            world.exact_positions.insert(
+                node,
+                ExactPositions {
+                    lat: smallvec![deg(*lat)],
+                    lon: smallvec![deg(*lon)],
+                },
+            );
+
+            let feature = world.create_entity(EntityType::Feature);
+            let foid = FoidKey {
+                agen: 550,
+                fidn: *fidn,
+                fids: 1,
+            };
+            world.foid_index.insert(foid, feature);
+            world.feature_meta.insert(
+                feature,
+                FeatureMeta {
+                    foid,
+                    prim: 1,
+                    grup: 2,
+                    objl: 75,
+                    rver: 1,
+                    ruin: 1,
+                },
+            );
+            world.feature_pointers.insert(
+                feature,
+                FeaturePointers {
+                    related_features: Vec::new(),
+                    spatial_refs: vec![SpatialRef {
+                        entity: node,
+                        ornt: 255,
+                        usag: 255,
+                        mask: 255,
+                    }],
+                },
+            );
+        }
+        world
+    }
+
+    #[test]
+    fn test_extract_region_merges_cells() {
+        // Cell A: features at (10,10) and (50,50); cell B: (12,12) and a duplicate of FOID 1
+        let a = world_with_points(&[(1, 10, 10), (2, 50, 50)]);
+        let mut b = world_with_points(&[(3, 12, 12), (1, 11, 11)]);
+        b.overlay.add_waypoint("In", deg(5), deg(5));
+        b.overlay.add_waypoint("Out", deg(30), deg(5));
+
+        let bbox = BoundingBox::new(deg(0), deg(0), deg(20), deg(20));
+        let out = extract_region(&[&a, &b], &bbox);
+
+        // FOID 1 and FOID 3; FOID 2 is outside. FOID 1 is charted in both
+        // cells, so B's part joins the feature copied from A
+        assert_eq!(out.entities_of_type(EntityType::Feature).len(), 2);
+        assert_eq!(out.entities_of_type(EntityType::Vector).len(), 3);
+        assert_eq!(out.overlay.len(), 1);
+        assert_eq!(out.overlay.objects[0].name, "In");
+        let f1 = out.foid_index[&FoidKey {
+            agen: 550,
+            fidn: 1,
+            fids: 1,
+        }];
+        assert_eq!(out.feature_pointers[&f1].spatial_refs.len(), 2);
+        assert_eq!(
+            out.feature_bbox(f1),
+            Some(BoundingBox::new(deg(10), deg(10), deg(11), deg(11)))
+        );
+
+        // Both cells used VI:1 and VI:2, so B's copies were renamed
+        assert_eq!(out.name_index.len(), 3);
+        for rcid in 1..=3 {
+            assert!(out.name_index.contains_key(&NameKey { rcnm: 110, rcid }));
+        }
+
+        let f3 = out.foid_index[&FoidKey {
+            agen: 550,
+            fidn: 3,
+            fids: 1,
+        }];
+        assert_eq!(
+            out.feature_bbox(f3),
+            Some(BoundingBox::from_point(&deg(12), &deg(12)))
+        );
+    }
+
+    /// Add a line (PRIM 2) or area (PRIM 3) feature on a single edge
+    fn add_edge_feature(world: &mut World, fidn: u32, prim: u8, points: &[(i64, i64)]) {
+        let edge = world.create_entity(EntityType::Vector);
+        let name = NameKey {
+            rcnm: 130,
+            rcid: fidn,
+        };
+        world.name_index.insert(name, edge);
+        world.vector_meta.insert(
+            edge,
+            VectorMeta {
+                name,
+                rver: 1,
+                ruin: 1,
+            },
+        );
+        world.exact_positions.insert(
+            edge,
+            ExactPositions {
+                lat: points.iter().map(|(lat, _)| deg(*lat)).collect(),
+                lon: points.iter().map(|(_, lon)| deg(*lon)).collect(),
+            },
+        );
+
+        let feature = world.create_entity(EntityType::Feature);
+        let foid = FoidKey {
+            agen: 550,
+            fidn,
+            fids: 1,
+        };
+        world.foid_index.insert(foid, feature);
+        world.feature_meta.insert(
+            feature,
+            FeatureMeta {
+                foid,
+                prim,
+                grup: 2,
+                objl: if prim == 3 { 42 } else { 30 },
+                rver: 1,
+                ruin: 1,
+            },
+        );
+        world.feature_pointers.insert(
+            feature,
+            FeaturePointers {
+                related_features: Vec::new(),
+                spatial_refs: vec![SpatialRef {
+                    entity: edge,
+                    ornt: 1,
+                    usag: if prim == 3 { 1 } else { 255 },
+                    mask: 255,
+                }],
+            },
+        );
+    }
+
+    fn feature(world: &World, fidn: u32) -> EntityId {
+        world.foid_index[&FoidKey {
+            agen: 550,
+            fidn,
+            fids: 1,
+        }]
+    }
+
+    #[test]
+    fn test_extract_region_clips_to_box() {
+        let mut world = world_with_points(&[(1, 5, 5)]);
+        // An area from (-10,-10) to (10,10), a line across the box edge and
+        // an area touching the box only at its corner
+        let square = [(-10, -10), (-10, 10), (10, 10), (10, -10), (-10, -10)];
+        add_edge_feature(&mut world, 2, 3, &square);
+        add_edge_feature(&mut world, 3, 2, &[(5, -10), (5, 10)]);
+        let corner = [(0, 0), (0, -5), (-5, -5), (-5, 0), (0, 0)];
+        add_edge_feature(&mut world, 4, 3, &corner);
+
+        let bbox = BoundingBox::new(deg(0), deg(0), deg(20), deg(20));
+        let out = extract_region(&[&world], &bbox);
+
+        // The point is inside and copied as it is; the corner area has
+        // nothing inside the box
+        assert_eq!(out.entities_of_type(EntityType::Feature).len(), 3);
+        assert!(!out.foid_index.contains_key(&FoidKey {
+            agen: 550,
+            fidn: 4,
+            fids: 1,
+        }));
+
+        let ctx = TraversalContext::new(&out);
+        let area = ctx.feature_geometry(feature(&out, 2)).unwrap().unwrap();
+        let ResolvedGeometry::Area(boundary) = area.as_ref() else {
+            panic!("not an area: {:?}", area);
+        };
+        assert_eq!(boundary.area(), deg(100));
+        assert_eq!(
+            out.feature_bbox(feature(&out, 2)),
+            Some(BoundingBox::new(deg(0), deg(0), deg(10), deg(10)))
+        );
+
+        let line = ctx.feature_geometry(feature(&out, 3)).unwrap().unwrap();
+        assert_eq!(
+            *line,
+            ResolvedGeometry::Lines(vec![vec![(deg(5), deg(0)), (deg(5), deg(10))]])
+        );
+    }
+
+    #[test]
+    fn test_extract_region_clips_soundings() {
+        // One sounding vector with points either side of the box edge
+        let mut world = world_with_points(&[(1, 5, 5)]);
+        let node = world.name_index[&NameKey { rcnm: 110, rcid: 1 }];
+        world.exact_positions.insert(
+            node,
+            ExactPositions {
+                lat: smallvec![deg(5), deg(5), deg(5)],
+                lon: smallvec![deg(-5), deg(5), deg(15)],
+            },
+        );
+        world.exact_depths.insert(
+            node,
+            ExactDepths {
+                depth: smallvec![deg(1), deg(2), deg(3)],
+                units: 1,
+            },
+        );
+
+        let bbox = BoundingBox::new(deg(0), deg(0), deg(20), deg(10));
+        let out = extract_region(&[&world], &bbox);
+        let sref = &out.feature_pointers[&feature(&out, 1)].spatial_refs[0];
+        assert_eq!(out.exact_positions[&sref.entity].lon.as_slice(), [deg(5)]);
+        assert_eq!(out.exact_depths[&sref.entity].depth.as_slice(), [deg(2)]);
+    }
+
+    #[test]
+    fn test_extract_region_merges_split_area() {
+        // One area split along latitude 5 between two cells of equal scale
+        let mut south = World::new();
+        add_edge_feature(
+            &mut south,
+            1,
+            3,
+            &[(0, 0), (0, 10), (5, 10), (5, 0), (0, 0)],
+        );
+        let mut north = World::new();
+        add_edge_feature(
+            &mut north,
+            1,
+            3,
+            &[(5, 0), (5, 10), (10, 10), (10, 0), (5, 0)],
+        );
+
+        let bbox = BoundingBox::new(deg(0), deg(0), deg(20), deg(20));
+        let out = extract_region(&[&south, &north], &bbox);
+        assert_eq!(out.entities_of_type(EntityType::Feature).len(), 1);
+        let area = feature(&out, 1);
+        assert_eq!(out.feature_pointers[&area].spatial_refs.len(), 2);
+        assert_eq!(
+            out.feature_bbox(area),
+            Some(BoundingBox::new(deg(0), deg(0), deg(10), deg(10)))
+        );
+    }
+
+    #[test]
+    fn test_extract_region_fixed_point() {
+        // The same points held in fixed point (COMF 10), as
+        // `ParseOptions::fixed_point` builds them
+        let mut world = world_with_points(&[(1, 5, 5), (2, 50, 50)]);
+        for (entity, positions) in std::mem::take(&mut world.exact_positions) {
+            let raw = |v: &BigRational| (v * BigInt::from(10)).to_integer().try_into().unwrap();
+            world.fixed_positions.insert(
+                entity,
+                FixedPositions {
+                    y: positions.lat.iter().map(raw).collect(),
+                    x: positions.lon.iter().map(raw).collect(),
+                    comf: 10,
+                },
+            );
+        }
+        assert_eq!(
+            world.feature_bbox(feature(&world, 1)),
+            Some(BoundingBox::from_point(&deg(5), &deg(5)))
+        );
+
+        let bbox = BoundingBox::new(deg(0), deg(0), deg(20), deg(20));
+        let out = extract_region(&[&world], &bbox);
+        assert_eq!(out.entities_of_type(EntityType::Feature).len(), 1);
+        assert_eq!(out.fixed_positions.len(), 1);
+        assert!(out.exact_positions.is_empty());
+        assert_eq!(
+            out.extent(),
+            Some(BoundingBox::from_point(&deg(5), &deg(5)))
+        );
+    }
+
+    #[test]
+    fn test_extract_region_prefers_detailed_copy() {
+        // FOID 1 is charted in an overview cell (listed first) and a harbour cell
+        let scaled = |cscl: u32, points: &[(u32, i64, i64)]| {
+            let mut world = world_with_points(points);
+            world.dataset_params = Some(DatasetParams {
+                comf: BigInt::from(10_000_000),
+                somf: BigInt::from(10),
+                duni: 1,
+                huni: 1,
+                puni: 1,
+                hdat: 2,
+                vdat: 0,
+                sdat: 0,
+                cscl,
+            });
+            world
+        };
+        let overview = scaled(350_000, &[(1, 10, 10)]);
+        let harbour = scaled(12_000, &[(1, 11, 11)]);
+
+        let bbox = BoundingBox::new(deg(0), deg(0), deg(20), deg(20));
+        let out = extract_region(&[&overview, &harbour], &bbox);
+        assert_eq!(out.entities_of_type(EntityType::Feature).len(), 1);
+        let f1 = out.foid_index[&FoidKey {
+            agen: 550,
+            fidn: 1,
+            fids: 1,
+        }];
+        assert_eq!(
+            out.feature_bbox(f1),
+            Some(BoundingBox::from_point(&deg(11), &deg(11)))
+        );
+    }
+
+    #[test]
+    fn test_bbox_across_antimeridian() {
+        // A cell from 179E to 179W comes out 2 degrees wide, not 358
+        let points = [
+            (deg(10), deg(179)),
+            (deg(11), deg(-179)),
+            (deg(12), deg(180)),
+        ];
+        let bbox = BoundingBox::from_points(points.iter().map(|(lat, lon)| (lat, lon))).unwrap();
+        assert_eq!(
+            bbox,
+            BoundingBox::new(deg(10), deg(179), deg(12), deg(-179))
+        );
+        assert!(bbox.crosses_antimeridian());
+        assert_eq!(bbox.lon_span(), deg(2));
+
+        // Either side of the meridian, and a box spanning the rest of the world
+        let east = BoundingBox::new(deg(0), deg(170), deg(20), deg(179));
+        let west = BoundingBox::new(deg(0), deg(-180), deg(20), deg(-179));
+        let middle = BoundingBox::new(deg(0), deg(-170), deg(20), deg(170));
+        assert!(bbox.intersects(&east));
+        assert!(bbox.intersects(&west));
+        assert!(!bbox.intersects(&middle));
+
+        let merged = east.union(&BoundingBox::new(deg(5), deg(-175), deg(25), deg(-170)));
+        assert_eq!(
+            merged,
+            BoundingBox::new(deg(0), deg(170), deg(25), deg(-170))
+        );
+        assert_eq!(middle.union(&middle), middle);
+
+        // Plain ranges stay plain, also in f64
+        assert_eq!(
+            covering_lon_range([(-10.0, 5.0), (20.0, 30.0)]),
+            Some((-10.0, 30.0))
+        );
+        assert_eq!(
+            covering_lon_range([(-175.0, -170.0), (160.0, 175.0)]),
+            Some((160.0, -170.0))
+        );
+    }
+}
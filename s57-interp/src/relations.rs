@@ -0,0 +1,206 @@
+//! Feature-to-feature relationships
+//!
+//! FFPT pointers are kept on whichever feature carries them, with RIND
+//! giving the role of the feature pointed at. Producers encode the same
+//! relationship from either end, or from both: a buoy may list its light as
+//! a slave, or the light may name the buoy as its master. The edges here
+//! are those pointers made symmetric, so a query gives the same answer
+//! however the cell encoded it.
+
+use crate::ecs::{EntityId, Relationship, World};
+
+/// RelationshipEdge: `to` is `from`'s `kind` (e.g. its slave)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RelationshipEdge {
+    pub from: EntityId,
+    pub to: EntityId,
+    pub kind: Relationship,
+}
+
+impl World {
+    /// Every relationship, in both directions
+    ///
+    /// Each FFPT pointer with a RIND of master, slave or peer gives an edge
+    /// and its inverse; pointers written at both ends give the same pair
+    /// once. Pointers without a usable RIND are left out. Edges are sorted
+    /// by `from`, then `to`.
+    pub fn relationships(&self) -> Vec<RelationshipEdge> {
+        let mut edges = Vec::new();
+        for (&from, pointers) in &self.feature_pointers {
+            for related in &pointers.related_features {
+                let Some(kind) = related.rind else {
+                    continue;
+                };
+                edges.push(RelationshipEdge {
+                    from,
+                    to: related.entity,
+                    kind,
+                });
+                edges.push(RelationshipEdge {
+                    from: related.entity,
+                    to: from,
+                    kind: kind.inverse(),
+                });
+            }
+        }
+        edges.sort();
+        edges.dedup();
+        edges
+    }
+
+    /// Features related to `feature`, with the role each plays for it
+    pub fn related_features(&self, feature: EntityId) -> Vec<(EntityId, Relationship)> {
+        let mut related: Vec<_> = self
+            .feature_pointers
+            .iter()
+            .flat_map(|(&from, pointers)| {
+                pointers.related_features.iter().filter_map(move |r| {
+                    let kind = r.rind?;
+                    if from == feature {
+                        Some((r.entity, kind))
+                    } else if r.entity == feature {
+                        Some((from, kind.inverse()))
+                    } else {
+                        None
+                    }
+                })
+            })
+            .collect();
+        related.sort();
+        related.dedup();
+        related
+    }
+
+    /// Slaves of a master feature (e.g. the light and topmark of a buoy)
+    pub fn slaves_of(&self, feature: EntityId) -> Vec<EntityId> {
+        self.related_with(feature, Relationship::Slave)
+    }
+
+    /// Master of a slave feature; the first by entity order if a cell
+    /// gives it several
+    pub fn master_of(&self, feature: EntityId) -> Option<EntityId> {
+        self.related_with(feature, Relationship::Master)
+            .first()
+            .copied()
+    }
+
+    /// Peers of a feature
+    pub fn peers_of(&self, feature: EntityId) -> Vec<EntityId> {
+        self.related_with(feature, Relationship::Peer)
+    }
+
+    fn related_with(&self, feature: EntityId, kind: Relationship) -> Vec<EntityId> {
+        self.related_features(feature)
+            .into_iter()
+            .filter(|(_, k)| *k == kind)
+            .map(|(entity, _)| entity)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::build_world;
+    use crate::ecs::{EntityType, FeatureRef};
+    use s57_parse::bitstring::FoidKey;
+    use s57_parse::iso8211::{write_file, Field, Record};
+    use s57_parse::S57File;
+
+    fn point_to(world: &mut World, from: EntityId, to: EntityId, rind: Option<Relationship>) {
+        world
+            .feature_pointers
+            .entry(from)
+            .or_default()
+            .related_features
+            .push(FeatureRef { entity: to, rind });
+    }
+
+    #[test]
+    fn test_relationships_from_either_end() {
+        let mut world = World::new();
+        let [buoy, light, topmark, fog, other] =
+            [(); 5].map(|_| world.create_entity(EntityType::Feature));
+        // The buoy names its light; the topmark names the buoy; both ends
+        // of buoy/fog are encoded; RIND 255 is ignored
+        point_to(&mut world, buoy, light, Some(Relationship::Slave));
+        point_to(&mut world, topmark, buoy, Some(Relationship::Master));
+        point_to(&mut world, buoy, fog, Some(Relationship::Slave));
+        point_to(&mut world, fog, buoy, Some(Relationship::Master));
+        point_to(&mut world, light, other, Some(Relationship::Peer));
+        point_to(&mut world, other, topmark, None);
+
+        assert_eq!(world.slaves_of(buoy), [light, topmark, fog]);
+        assert_eq!(world.master_of(light), Some(buoy));
+        assert_eq!(world.master_of(topmark), Some(buoy));
+        assert_eq!(world.master_of(buoy), None);
+        assert_eq!(world.peers_of(other), [light]);
+        assert_eq!(
+            world.related_features(light),
+            [(buoy, Relationship::Master), (other, Relationship::Peer)]
+        );
+
+        // buoy-light, buoy-topmark, buoy-fog, light-other, each both ways
+        let edges = world.relationships();
+        assert_eq!(edges.len(), 8);
+        assert!(edges.contains(&RelationshipEdge {
+            from: topmark,
+            to: buoy,
+            kind: Relationship::Master
+        }));
+    }
+
+    /// Feature record with FFPT pointers (FIDN, RIND)
+    fn feature(fidn: u32, objl: u16, ffpt: &[(u32, u8)]) -> Record {
+        let foid = |fidn: u32| {
+            let mut data = 550u16.to_le_bytes().to_vec();
+            data.extend(fidn.to_le_bytes());
+            data.extend(1u16.to_le_bytes());
+            data
+        };
+        let mut frid = vec![100];
+        frid.extend(fidn.to_le_bytes());
+        frid.extend([255, 2]);
+        frid.extend(objl.to_le_bytes());
+        frid.extend(1u16.to_le_bytes());
+        frid.extend([1, 0x1E]);
+        let mut fields = vec![
+            Field::new("FRID".to_string(), frid),
+            Field::new("FOID".to_string(), [foid(fidn), vec![0x1E]].concat()),
+        ];
+        if !ffpt.is_empty() {
+            let mut data = Vec::new();
+            for (target, rind) in ffpt {
+                data.extend(foid(*target));
+                data.extend([*rind, 0x1F]);
+            }
+            data.push(0x1E);
+            fields.push(Field::new("FFPT".to_string(), data));
+        }
+        Record::from_fields(fields)
+    }
+
+    #[test]
+    fn test_pointers_resolved_after_every_feature() {
+        // The buoy points forward at its light and at a feature the cell
+        // lacks; only the dangling pointer is dropped
+        let records = vec![feature(1, 17, &[(2, 2), (9, 2)]), feature(2, 75, &[])];
+        let file = S57File::from_bytes(&write_file(&records).unwrap()).unwrap();
+        let world = build_world(&file).unwrap();
+        let foid = |fidn| FoidKey {
+            agen: 550,
+            fidn,
+            fids: 1,
+        };
+        let buoy = world.foid_index[&foid(1)];
+        let light = world.foid_index[&foid(2)];
+        assert_eq!(
+            world.feature_pointers[&buoy].related_features,
+            [FeatureRef {
+                entity: light,
+                rind: Some(Relationship::Slave),
+            }]
+        );
+        assert_eq!(world.master_of(light), Some(buoy));
+    }
+}
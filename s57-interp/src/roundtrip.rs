@@ -0,0 +1,166 @@
+//! Write/parse round-trip check for a cell
+//!
+//! `check_round_trip` parses a cell, writes its records out again with the
+//! ISO 8211 writer, parses the result and builds a World from both. The two
+//! Worlds must agree on their content fingerprint and on every record
+//! count, so a cell that survives is one the writer reproduces without
+//! changing what it charts. Run it on your own cells from a test:
+//!
+//! ```no_run
+//! let data = std::fs::read("cells/US5MA22M.000").unwrap();
+//! s57_interp::roundtrip::check_round_trip(&data).unwrap();
+//! ```
+
+use crate::fingerprint::Fingerprint;
+use crate::record_counts::RecordKind;
+use crate::{build_world, ParseError};
+use s57_parse::S57File;
+use std::fmt;
+
+/// RoundTripError: Why a cell did not survive being written and reparsed
+#[derive(Debug)]
+pub enum RoundTripError {
+    /// The original cell could not be parsed or interpreted
+    Original(ParseError),
+    /// The records could not be written
+    Write(ParseError),
+    /// The written bytes could not be parsed or interpreted
+    Reparse(ParseError),
+    /// A different number of ISO 8211 records came back
+    Records { before: usize, after: usize },
+    /// A different number of records of one kind came back
+    RecordCount {
+        kind: RecordKind,
+        before: usize,
+        after: usize,
+    },
+    /// The features, attributes or geometry changed
+    Fingerprint {
+        before: Fingerprint,
+        after: Fingerprint,
+    },
+}
+
+impl fmt::Display for RoundTripError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RoundTripError::Original(e) => write!(f, "original cell: {}", e),
+            RoundTripError::Write(e) => write!(f, "writing records: {}", e),
+            RoundTripError::Reparse(e) => write!(f, "rewritten cell: {}", e),
+            RoundTripError::Records { before, after } => {
+                write!(f, "{} records written, {} parsed back", before, after)
+            }
+            RoundTripError::RecordCount {
+                kind,
+                before,
+                after,
+            } => write!(
+                f,
+                "{}: {} before, {} after",
+                kind.description(),
+                before,
+                after
+            ),
+            RoundTripError::Fingerprint { before, after } => {
+                write!(f, "fingerprint changed from {} to {}", before, after)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RoundTripError {}
+
+/// Parse `data`, write it, reparse it and compare the two Worlds
+///
+/// Returns the content fingerprint both Worlds share.
+pub fn check_round_trip(data: &[u8]) -> Result<Fingerprint, RoundTripError> {
+    let file = S57File::from_bytes(data).map_err(RoundTripError::Original)?;
+    let world = build_world(&file).map_err(RoundTripError::Original)?;
+
+    let written = file.to_bytes().map_err(RoundTripError::Write)?;
+    let reparsed = S57File::from_bytes(&written).map_err(RoundTripError::Reparse)?;
+    let rebuilt = build_world(&reparsed).map_err(RoundTripError::Reparse)?;
+
+    let (before, after) = (file.records().len(), reparsed.records().len());
+    if before != after {
+        return Err(RoundTripError::Records { before, after });
+    }
+    for kind in RecordKind::ALL {
+        let (before, after) = (world.record_count(kind), rebuilt.record_count(kind));
+        if before != after {
+            return Err(RoundTripError::RecordCount {
+                kind,
+                before,
+                after,
+            });
+        }
+    }
+    let (before, after) = (world.fingerprint(), rebuilt.fingerprint());
+    if before != after {
+        return Err(RoundTripError::Fingerprint { before, after });
+    }
+    Ok(before)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use s57_parse::iso8211::{write_file, Field, Record};
+
+    const UNIT_TERMINATOR: u8 = 0x1F;
+    const FIELD_TERMINATOR: u8 = 0x1E;
+
+    /// A point-less feature record: FRID, FOID and ATTF
+    fn feature(rcid: u32, objl: u16, attributes: &[(u16, String)]) -> Record {
+        let mut frid = vec![100];
+        frid.extend(rcid.to_le_bytes());
+        frid.extend([1, 1]);
+        frid.extend(objl.to_le_bytes());
+        frid.extend(1u16.to_le_bytes());
+        frid.extend([1, FIELD_TERMINATOR]);
+
+        let mut foid = 550u16.to_le_bytes().to_vec();
+        foid.extend(rcid.to_le_bytes());
+        foid.extend(1u16.to_le_bytes());
+        foid.push(FIELD_TERMINATOR);
+
+        let mut attf = Vec::new();
+        for (attl, atvl) in attributes {
+            attf.extend(attl.to_le_bytes());
+            attf.extend(atvl.bytes());
+            attf.push(UNIT_TERMINATOR);
+        }
+        attf.push(FIELD_TERMINATOR);
+
+        Record::from_fields(vec![
+            Field::new("FRID".to_string(), frid),
+            Field::new("FOID".to_string(), foid),
+            Field::new("ATTF".to_string(), attf),
+        ])
+    }
+
+    fn arb_feature() -> impl Strategy<Value = (u16, Vec<(u16, String)>)> {
+        (
+            1u16..600,
+            prop::collection::vec((1u16..400, "[A-Za-z0-9 .,]{0,12}"), 1..5),
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn test_feature_round_trip(features in prop::collection::vec(arb_feature(), 1..20)) {
+            let records: Vec<Record> = features
+                .iter()
+                .enumerate()
+                .map(|(i, (objl, attributes))| feature(i as u32 + 1, *objl, attributes))
+                .collect();
+            let data = write_file(&records).unwrap();
+            let fingerprint = check_round_trip(&data).unwrap();
+
+            let world = build_world(&S57File::from_bytes(&data).unwrap()).unwrap();
+            prop_assert_eq!(world.feature_meta.len(), features.len());
+            prop_assert_eq!(world.fingerprint(), fingerprint);
+        }
+    }
+}
@@ -0,0 +1,282 @@
+//! Compiled cells (SENC)
+//!
+//! Building a World from a large cell parses every record and decodes every
+//! coordinate, and drawing it walks the topology of each line and area
+//! feature: seconds of work that a viewer would repeat on every open. A
+//! SENC ("system ENC") file does that work once. `Senc::compile` builds
+//! the World with fixed-point and f64 positions, resolves the geometry of
+//! every line and area feature in f64 and packs the spatial index;
+//! `to_bytes` writes the lot and `Senc::from_bytes` reads it back without
+//! touching the cell, decoding a BigRational or walking an edge.
+//!
+//! The file is the 8-byte magic `S57SENC\0`, a little-endian u32 format
+//! version and the bincode-encoded `Senc`. The layout follows the World's
+//! components, so `VERSION` goes up whenever one of them changes; files of
+//! another version are rejected rather than misread, and should be
+//! compiled again from the cell.
+
+use crate::ecs::{EntityId, EntityType, World};
+use crate::geometry_cache::ApproxGeometry;
+use crate::spatial_index::SpatialIndex;
+use crate::topology::TraversalContext;
+use crate::{build_world_with_options, ParseOptions};
+use s57_parse::{Result, S57File};
+use std::collections::HashMap;
+use std::fmt;
+
+/// First bytes of every SENC file
+pub const MAGIC: [u8; 8] = *b"S57SENC\0";
+
+/// Format version written by `to_bytes`, the only one `from_bytes` reads
+pub const VERSION: u32 = 1;
+
+/// SencError: Why a SENC file could not be written or read
+#[derive(Debug)]
+pub enum SencError {
+    /// The bytes don't start with `MAGIC`
+    NotSenc,
+    /// The file was written in another format version
+    Version { found: u32 },
+    /// bincode could not encode the World
+    Encode(String),
+    /// The payload is truncated or corrupt
+    Decode(String),
+}
+
+impl fmt::Display for SencError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SencError::NotSenc => write!(f, "not a SENC file"),
+            SencError::Version { found } => write!(
+                f,
+                "SENC format version {} (this build reads version {}); compile the cell again",
+                found, VERSION
+            ),
+            SencError::Encode(e) => write!(f, "encoding SENC: {}", e),
+            SencError::Decode(e) => write!(f, "corrupt SENC: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for SencError {}
+
+/// Senc: A compiled cell, ready to draw and query
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct Senc {
+    /// The World, with fixed-point and f64 positions in place of exact
+    /// ones; `promote_fixed` restores the exact values
+    pub world: World,
+    /// Geometry of every line and area feature, resolved in f64
+    #[serde(with = "crate::serde_exact::pairs")]
+    pub geometry: HashMap<EntityId, ApproxGeometry>,
+    /// R-tree over the extent of every positioned feature
+    pub index: SpatialIndex,
+}
+
+impl Senc {
+    /// Build the World from a cell and do the work a viewer would repeat
+    ///
+    /// Features whose topology doesn't resolve are left out of `geometry`,
+    /// as a render would skip them.
+    pub fn compile(file: &S57File) -> Result<Self> {
+        let options = ParseOptions::new().fixed_point().approx_positions();
+        let world = build_world_with_options(file, &options)?;
+        Ok(Self::from_world(world))
+    }
+
+    /// Resolve the geometry and build the index of an already-built World
+    ///
+    /// For Worlds that don't come straight from one cell, such as a region
+    /// extracted from several. The World keeps whichever coordinate backend
+    /// it was built with.
+    pub fn from_world(mut world: World) -> Self {
+        // The index rounds outward from exact extents, so build it from
+        // exact positions and then go back to the compact fixed-point ones
+        let fixed_positions = world.fixed_positions.clone();
+        let fixed_depths = world.fixed_depths.clone();
+        world.promote_fixed();
+        let index = world.build_spatial_index();
+        for entity in fixed_positions.keys() {
+            world.exact_positions.remove(entity);
+        }
+        for entity in fixed_depths.keys() {
+            world.exact_depths.remove(entity);
+        }
+        world.fixed_positions = fixed_positions;
+        world.fixed_depths = fixed_depths;
+
+        let ctx = TraversalContext::new(&world);
+        let geometry = world
+            .entities_of_type(EntityType::Feature)
+            .into_iter()
+            .filter_map(|feature| match ctx.feature_geometry_approx(feature) {
+                Ok(geometry) => Some((feature, geometry?)),
+                Err(e) => {
+                    crate::diag::debug!("Skipping geometry of {:?}: {}", feature, e);
+                    None
+                }
+            })
+            .collect();
+
+        Senc {
+            world,
+            geometry,
+            index,
+        }
+    }
+
+    /// Encode as a SENC file
+    pub fn to_bytes(&self) -> std::result::Result<Vec<u8>, SencError> {
+        let payload = bincode::serialize(self).map_err(|e| SencError::Encode(e.to_string()))?;
+        let mut bytes = Vec::with_capacity(MAGIC.len() + 4 + payload.len());
+        bytes.extend_from_slice(&MAGIC);
+        bytes.extend_from_slice(&VERSION.to_le_bytes());
+        bytes.extend(payload);
+        Ok(bytes)
+    }
+
+    /// Decode a SENC file written by `to_bytes`
+    pub fn from_bytes(bytes: &[u8]) -> std::result::Result<Self, SencError> {
+        let payload = bytes.strip_prefix(&MAGIC).ok_or(SencError::NotSenc)?;
+        let (version, payload) = payload
+            .split_first_chunk::<4>()
+            .ok_or_else(|| SencError::Decode("no format version".to_string()))?;
+        let found = u32::from_le_bytes(*version);
+        if found != VERSION {
+            return Err(SencError::Version { found });
+        }
+        bincode::deserialize(payload).map_err(|e| SencError::Decode(e.to_string()))
+    }
+}
+
+impl World {
+    /// The World from a SENC file
+    ///
+    /// Use `Senc::from_bytes` to keep the resolved geometry and the spatial
+    /// index as well.
+    pub fn from_senc(bytes: &[u8]) -> std::result::Result<World, SencError> {
+        Senc::from_bytes(bytes).map(|senc| senc.world)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry_cache::ResolvedGeometry;
+    use s57_parse::iso8211::{write_file, Record};
+
+    fn name(rcnm: u8, rcid: u32) -> Vec<u8> {
+        let mut data = vec![rcnm];
+        data.extend(rcid.to_le_bytes());
+        data
+    }
+
+    fn vrid(rcnm: u8, rcid: u32) -> Vec<u8> {
+        let mut data = name(rcnm, rcid);
+        data.extend([1, 0, 1, 0x1E]);
+        data
+    }
+
+    /// SG2D from (lat, lon) in units of 1e-7 degrees
+    fn sg2d(points: &[(i32, i32)]) -> Vec<u8> {
+        let mut data: Vec<u8> = points
+            .iter()
+            .flat_map(|(y, x)| y.to_le_bytes().into_iter().chain(x.to_le_bytes()))
+            .collect();
+        data.push(0x1E);
+        data
+    }
+
+    /// A COALNE along one edge between two connected nodes
+    fn cell() -> S57File {
+        let mut dspm = vec![20];
+        dspm.extend(1u32.to_le_bytes());
+        dspm.extend([2, 3, 23]);
+        dspm.extend(22000u32.to_le_bytes());
+        dspm.extend([1, 1, 1, 1]);
+        dspm.extend(10_000_000u32.to_le_bytes());
+        dspm.extend(10u32.to_le_bytes());
+        dspm.extend([0x1F, 0x1E]);
+
+        let mut vrpt = name(120, 1);
+        vrpt.extend([255, 255, 1, 255]);
+        vrpt.extend(name(120, 2));
+        vrpt.extend([255, 255, 2, 255]);
+        vrpt.push(0x1E);
+
+        let mut frid = vec![100];
+        frid.extend(1u32.to_le_bytes());
+        frid.extend([2, 2]);
+        frid.extend(30u16.to_le_bytes());
+        frid.extend(1u16.to_le_bytes());
+        frid.extend([1, 0x1E]);
+        let mut foid = 550u16.to_le_bytes().to_vec();
+        foid.extend(1u32.to_le_bytes());
+        foid.extend(1u16.to_le_bytes());
+        foid.push(0x1E);
+        let mut fspt = name(130, 3);
+        fspt.extend([1, 1, 2, 0x1E]);
+
+        let records = [
+            Record::from_fields([("DSPM", dspm)]),
+            Record::from_fields([
+                ("VRID", vrid(120, 1)),
+                ("SG2D", sg2d(&[(436_000_000, -703_000_000)])),
+            ]),
+            Record::from_fields([
+                ("VRID", vrid(120, 2)),
+                ("SG2D", sg2d(&[(437_000_000, -701_000_000)])),
+            ]),
+            Record::from_fields([
+                ("VRID", vrid(130, 3)),
+                ("VRPT", vrpt),
+                ("SG2D", sg2d(&[(437_000_000, -703_000_000)])),
+            ]),
+            Record::from_fields([("FRID", frid), ("FOID", foid), ("FSPT", fspt)]),
+        ];
+        S57File::from_bytes(&write_file(&records).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_senc_round_trip() {
+        let senc = Senc::compile(&cell()).unwrap();
+        let coalne = senc.world.entities_of_type(EntityType::Feature)[0];
+        assert!(senc.world.exact_positions.is_empty());
+        assert_eq!(senc.world.fixed_positions.len(), 3);
+        let Some(ResolvedGeometry::Lines(lines)) = senc.geometry.get(&coalne) else {
+            panic!("expected resolved lines");
+        };
+        assert_eq!(lines[0], [(43.6, -70.3), (43.7, -70.3), (43.7, -70.1)]);
+        assert_eq!(senc.index.query_point(43.65, -70.2), [coalne]);
+
+        let bytes = senc.to_bytes().unwrap();
+        assert!(bytes.starts_with(&MAGIC));
+        let loaded = Senc::from_bytes(&bytes).unwrap();
+        assert_eq!(loaded.geometry, senc.geometry);
+        assert_eq!(loaded.index.query_point(43.65, -70.2), [coalne]);
+        assert_eq!(
+            loaded.world.fingerprint(),
+            Senc::compile(&cell()).unwrap().world.fingerprint()
+        );
+        assert_eq!(
+            World::from_senc(&bytes).unwrap().feature_meta[&coalne].objl,
+            30
+        );
+
+        // Other versions, other files and damaged payloads are refused
+        let mut newer = bytes.clone();
+        newer[MAGIC.len()] = 2;
+        assert!(matches!(
+            Senc::from_bytes(&newer),
+            Err(SencError::Version { found: 2 })
+        ));
+        assert!(matches!(
+            Senc::from_bytes(b"not a senc"),
+            Err(SencError::NotSenc)
+        ));
+        assert!(matches!(
+            Senc::from_bytes(&bytes[..bytes.len() / 2]),
+            Err(SencError::Decode(_))
+        ));
+    }
+}
@@ -0,0 +1,218 @@
+//! Serde helpers for exact values (feature "serde")
+//!
+//! num-rational's own serde support writes a BigRational as a pair of
+//! BigInts, each a sign and a list of u32 digits: compact, but unreadable in
+//! JSON and tied to num-bigint's internal layout. These modules write exact
+//! values as decimal strings instead, "5/2" for a ratio and "10000000" for an
+//! integer (or a ratio with denominator 1), which read back to the same
+//! value with any version and in any format. Use them with
+//! `#[serde(with = "s57_interp::serde_exact::rational")]`.
+//!
+//! `pairs` writes a HashMap as a list of (key, value) pairs, so maps keyed by
+//! `EntityId`, `NameKey` or `FoidKey` survive formats such as JSON whose map
+//! keys must be strings.
+
+/// A single BigRational as "numer/denom", or "numer" when denom is 1
+pub mod rational {
+    use num_rational::BigRational;
+    use serde::de::{Deserialize, Deserializer, Error};
+    use serde::ser::Serializer;
+    use std::borrow::Cow;
+
+    pub fn serialize<S: Serializer>(value: &BigRational, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<BigRational, D::Error> {
+        let text = Cow::<str>::deserialize(deserializer)?;
+        parse(&text).map_err(D::Error::custom)
+    }
+
+    /// Parse one exact value, rejecting a zero denominator
+    pub(crate) fn parse(text: &str) -> Result<BigRational, String> {
+        text.parse::<BigRational>()
+            .map_err(|_| format!("invalid exact value {:?}", text))
+    }
+}
+
+/// A single BigInt as a decimal string
+pub mod bigint {
+    use num_bigint::BigInt;
+    use serde::de::{Deserialize, Deserializer, Error};
+    use serde::ser::Serializer;
+    use std::borrow::Cow;
+
+    pub fn serialize<S: Serializer>(value: &BigInt, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<BigInt, D::Error> {
+        let text = Cow::<str>::deserialize(deserializer)?;
+        text.parse()
+            .map_err(|_| D::Error::custom(format!("invalid integer {:?}", text)))
+    }
+}
+
+/// `Coordinates` as a list of `rational` strings
+pub mod coordinates {
+    use crate::ecs::Coordinates;
+    use serde::de::{Deserialize, Deserializer, Error};
+    use serde::ser::Serializer;
+    use std::borrow::Cow;
+
+    pub fn serialize<S: Serializer>(
+        values: &Coordinates,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(values.iter().map(ToString::to_string))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Coordinates, D::Error> {
+        Vec::<Cow<str>>::deserialize(deserializer)?
+            .iter()
+            .map(|text| super::rational::parse(text).map_err(D::Error::custom))
+            .collect()
+    }
+}
+
+/// A HashMap as a list of (key, value) pairs
+pub mod pairs {
+    use serde::de::{Deserialize, Deserializer};
+    use serde::ser::{Serialize, Serializer};
+    use std::collections::HashMap;
+    use std::hash::Hash;
+
+    pub fn serialize<K, V, S>(map: &HashMap<K, V>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        K: Serialize,
+        V: Serialize,
+        S: Serializer,
+    {
+        serializer.collect_seq(map)
+    }
+
+    pub fn deserialize<'de, K, V, D>(deserializer: D) -> Result<HashMap<K, V>, D::Error>
+    where
+        K: Deserialize<'de> + Eq + Hash,
+        V: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        Ok(Vec::<(K, V)>::deserialize(deserializer)?
+            .into_iter()
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ecs::{
+        DatasetParams, EntityType, ExactDepths, ExactPositions, FeatureAttributes, VectorMeta,
+        World,
+    };
+    use crate::fixed::FixedPositions;
+    use num_bigint::BigInt;
+    use num_rational::BigRational;
+    use s57_parse::bitstring::NameKey;
+    use smallvec::smallvec;
+
+    #[test]
+    fn test_world_round_trip() {
+        let ratio = |n: i64, d: i64| BigRational::new(BigInt::from(n), BigInt::from(d));
+        let mut world = World::new();
+        world.dataset_params = Some(DatasetParams {
+            comf: BigInt::from(10_000_000),
+            somf: BigInt::from(10),
+            duni: 1,
+            huni: 1,
+            puni: 1,
+            hdat: 2,
+            vdat: 0,
+            sdat: 0,
+            cscl: 22000,
+        });
+
+        // A removed entity leaves a slot whose key must stay stale after loading
+        let removed = world.create_entity(EntityType::Vector);
+        world.remove_entity(removed);
+        let node = world.create_entity(EntityType::Vector);
+        let name = NameKey { rcnm: 110, rcid: 7 };
+        world.name_index.insert(name, node);
+        world.vector_meta.insert(
+            node,
+            VectorMeta {
+                name,
+                rver: 1,
+                ruin: 1,
+            },
+        );
+        world.exact_positions.insert(
+            node,
+            ExactPositions {
+                lat: smallvec![ratio(425_000_001, 10_000_000)],
+                lon: smallvec![ratio(-705, 10)],
+            },
+        );
+        world.exact_depths.insert(
+            node,
+            ExactDepths {
+                depth: smallvec![ratio(57, 10)],
+                units: 1,
+            },
+        );
+        let fixed = world.create_entity(EntityType::Vector);
+        world.fixed_positions.insert(
+            fixed,
+            FixedPositions {
+                y: smallvec![1, 2, 3],
+                x: smallvec![4, 5, 6],
+                comf: 10_000_000,
+            },
+        );
+        let feature = world.create_entity(EntityType::Feature);
+        world.feature_attributes.insert(
+            feature,
+            FeatureAttributes {
+                attf: vec![(116, "Boston Light".to_string())],
+                natf: Vec::new(),
+            },
+        );
+        world.overlay.add_waypoint("WP1", ratio(1, 3), ratio(-2, 3));
+        world.enable_geometry_cache();
+
+        let json = serde_json::to_string(&world).unwrap();
+        assert!(json.contains(r#""lat":["425000001/10000000"]"#));
+        assert!(json.contains(r#""comf":"10000000""#));
+
+        let loaded: World = serde_json::from_str(&json).unwrap();
+        assert!(loaded.geometry_cache.is_none());
+        assert!(!loaded.is_valid(removed));
+        assert_eq!(loaded.entity_type(feature), Some(EntityType::Feature));
+        assert_eq!(loaded.name_index[&name], node);
+        assert_eq!(
+            loaded.exact_positions[&node].lat,
+            world.exact_positions[&node].lat
+        );
+        assert_eq!(loaded.exact_depths[&node].depth[0], ratio(57, 10));
+        assert_eq!(
+            loaded.fixed_positions[&fixed],
+            world.fixed_positions[&fixed]
+        );
+        assert_eq!(
+            loaded.feature_attributes[&feature].get(116),
+            Some("Boston Light")
+        );
+        assert_eq!(loaded.overlay.objects[0].positions.lat[0], ratio(1, 3));
+        assert_eq!(
+            loaded.dataset_params.unwrap().comf,
+            BigInt::from(10_000_000)
+        );
+
+        // Malformed exact values are rejected
+        let bad = json.replace("425000001/10000000", "1/0");
+        assert!(serde_json::from_str::<World>(&bad).is_err());
+    }
+}
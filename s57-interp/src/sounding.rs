@@ -0,0 +1,193 @@
+//! Soundings with exact depths
+//!
+//! `World::soundings` lists every SG3D point of the SOUNDG features as exact
+//! lat/lon/depth values, scaled by COMF and SOMF without rounding, for
+//! hydrographic post-processing that must not lose a digit of the source.
+
+use crate::ecs::{EntityId, EntityType, World};
+use num_rational::BigRational;
+
+/// SOUNDG: Sounding
+pub(crate) const OBJL_SOUNDG: u16 = 129;
+
+/// Sounding: One depth of a SOUNDG feature
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sounding {
+    /// SOUNDG feature the sounding belongs to
+    pub feature: EntityId,
+    /// Latitude in degrees
+    pub lat: BigRational,
+    /// Longitude in degrees
+    pub lon: BigRational,
+    /// Depth in DUNI units (positive down)
+    pub depth: BigRational,
+}
+
+impl World {
+    /// Every sounding of every SOUNDG feature, in feature then SG3D order
+    ///
+    /// Vectors without depths (SG2D rather than SG3D) are skipped. Works for
+    /// worlds built with either coordinate backend.
+    pub fn soundings(&self) -> impl Iterator<Item = Sounding> + '_ {
+        self.entities_of_type(EntityType::Feature)
+            .into_iter()
+            .filter(|feature| {
+                self.feature_meta.get(feature).map(|meta| meta.objl) == Some(OBJL_SOUNDG)
+            })
+            .flat_map(move |feature| {
+                let refs = self
+                    .feature_pointers
+                    .get(&feature)
+                    .map(|pointers| pointers.spatial_refs.as_slice())
+                    .unwrap_or_default();
+                refs.iter()
+                    .flat_map(move |sref| self.vector_soundings(feature, sref.entity))
+            })
+    }
+
+    /// Soundings of one SG3D vector
+    fn vector_soundings(&self, feature: EntityId, vector: EntityId) -> Vec<Sounding> {
+        let sounding = |(lat, lon), depth| Sounding {
+            feature,
+            lat,
+            lon,
+            depth,
+        };
+        if let (Some(positions), Some(depths)) = (
+            self.fixed_positions.get(&vector),
+            self.fixed_depths.get(&vector),
+        ) {
+            let depths = depths.to_exact().depth;
+            return (0..positions.len())
+                .filter_map(|i| positions.exact(i))
+                .zip(depths)
+                .map(|(position, depth)| sounding(position, depth))
+                .collect();
+        }
+        let (Some(positions), Some(depths)) = (
+            self.exact_positions.get(&vector),
+            self.exact_depths.get(&vector),
+        ) else {
+            return Vec::new();
+        };
+        positions
+            .lat
+            .iter()
+            .cloned()
+            .zip(positions.lon.iter().cloned())
+            .zip(depths.depth.iter().cloned())
+            .map(|(position, depth)| sounding(position, depth))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::{ExactDepths, ExactPositions, FeatureMeta, FeaturePointers, SpatialRef};
+    use crate::fixed::{FixedDepths, FixedPositions};
+    use num_bigint::BigInt;
+    use s57_parse::bitstring::FoidKey;
+    use smallvec::smallvec;
+
+    fn feature(world: &mut World, fidn: u32, objl: u16, vectors: &[EntityId]) -> EntityId {
+        let feature = world.create_entity(EntityType::Feature);
+        world.feature_meta.insert(
+            feature,
+            FeatureMeta {
+                foid: FoidKey {
+                    agen: 550,
+                    fidn,
+                    fids: 1,
+                },
+                prim: 1,
+                grup: 2,
+                objl,
+                rver: 1,
+                ruin: 1,
+            },
+        );
+        world.feature_pointers.insert(
+            feature,
+            FeaturePointers {
+                spatial_refs: vectors
+                    .iter()
+                    .map(|&entity| SpatialRef {
+                        entity,
+                        ornt: 255,
+                        usag: 255,
+                        mask: 255,
+                    })
+                    .collect(),
+                ..Default::default()
+            },
+        );
+        feature
+    }
+
+    #[test]
+    fn test_soundings() {
+        let ratio = |n: i64, d: i64| BigRational::new(BigInt::from(n), BigInt::from(d));
+        let mut world = World::new();
+
+        // Exact SG3D cluster, fixed-point SG3D and an SG2D node without depths
+        let exact = world.create_entity(EntityType::Vector);
+        world.exact_positions.insert(
+            exact,
+            ExactPositions {
+                lat: smallvec![ratio(501, 10), ratio(502, 10)],
+                lon: smallvec![ratio(-12, 10), ratio(-13, 10)],
+            },
+        );
+        world.exact_depths.insert(
+            exact,
+            ExactDepths {
+                depth: smallvec![ratio(57, 10), ratio(123, 10)],
+                units: 1,
+            },
+        );
+        let fixed = world.create_entity(EntityType::Vector);
+        world.fixed_positions.insert(
+            fixed,
+            FixedPositions {
+                y: smallvec![500_000_001],
+                x: smallvec![-10_000_000],
+                comf: 10_000_000,
+            },
+        );
+        world.fixed_depths.insert(
+            fixed,
+            FixedDepths {
+                z: smallvec![3],
+                somf: 10,
+                units: 1,
+            },
+        );
+        let flat = world.create_entity(EntityType::Vector);
+        world.exact_positions.insert(
+            flat,
+            ExactPositions {
+                lat: smallvec![ratio(50, 1)],
+                lon: smallvec![ratio(-1, 1)],
+            },
+        );
+
+        let soundg = feature(&mut world, 1, OBJL_SOUNDG, &[exact, fixed, flat]);
+        feature(&mut world, 2, 42, &[exact]);
+
+        let soundings: Vec<Sounding> = world.soundings().collect();
+        assert_eq!(soundings.len(), 3);
+        assert!(soundings.iter().all(|s| s.feature == soundg));
+        assert_eq!(
+            soundings[1],
+            Sounding {
+                feature: soundg,
+                lat: ratio(502, 10),
+                lon: ratio(-13, 10),
+                depth: ratio(123, 10),
+            }
+        );
+        assert_eq!(soundings[2].lat, ratio(500_000_001, 10_000_000));
+        assert_eq!(soundings[2].depth, ratio(3, 10));
+    }
+}
@@ -0,0 +1,378 @@
+//! R-tree over feature bounding boxes
+//!
+//! `World::build_spatial_index` packs the extent of every positioned
+//! feature into a static R-tree (Sort-Tile-Recursive bulk loading), so a
+//! viewport or a point only has to look at the features near it instead of
+//! scanning the whole World.
+//!
+//! The tree holds f64 boxes, each rounded outward from the exact
+//! `feature_bbox`, so a query never misses a feature whose exact box it
+//! touches. It can return a feature whose exact box just misses the query;
+//! results are candidates for an exact test such as `AreaBoundary::contains`.
+//! A box crossing the antimeridian is stored as its two halves.
+
+use crate::ecs::{EntityId, EntityType, World};
+use num_rational::BigRational;
+use num_traits::ToPrimitive;
+use std::ops::Range;
+
+/// Children per tree node
+const NODE_CAPACITY: usize = 16;
+
+/// Rect: Non-wrapping f64 lat/lon box
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Rect {
+    min_lat: f64,
+    min_lon: f64,
+    max_lat: f64,
+    max_lon: f64,
+}
+
+impl Rect {
+    fn intersects(&self, other: &Rect) -> bool {
+        self.min_lat <= other.max_lat
+            && other.min_lat <= self.max_lat
+            && self.min_lon <= other.max_lon
+            && other.min_lon <= self.max_lon
+    }
+
+    fn union(&self, other: &Rect) -> Rect {
+        Rect {
+            min_lat: self.min_lat.min(other.min_lat),
+            min_lon: self.min_lon.min(other.min_lon),
+            max_lat: self.max_lat.max(other.max_lat),
+            max_lon: self.max_lon.max(other.max_lon),
+        }
+    }
+
+    fn centre(&self) -> (f64, f64) {
+        (
+            (self.min_lat + self.max_lat) / 2.0,
+            (self.min_lon + self.max_lon) / 2.0,
+        )
+    }
+
+    /// Query rectangles for a box given in degrees; two when it crosses
+    /// the antimeridian (`min_lon > max_lon`)
+    fn query(min_lat: f64, min_lon: f64, max_lat: f64, max_lon: f64) -> Vec<Rect> {
+        let rect = |min_lon, max_lon| Rect {
+            min_lat,
+            min_lon,
+            max_lat,
+            max_lon,
+        };
+        if min_lon > max_lon {
+            vec![rect(min_lon, 180.0), rect(-180.0, max_lon)]
+        } else {
+            vec![rect(min_lon, max_lon)]
+        }
+    }
+}
+
+/// Node: Bounding rectangle of a run of children in the level below
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Node {
+    rect: Rect,
+    children: Range<usize>,
+}
+
+/// SpatialIndex: Static R-tree of feature bounding boxes
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpatialIndex {
+    /// Indexed boxes, grouped so each leaf covers a contiguous run
+    entries: Vec<(Rect, EntityId)>,
+    /// Tree levels from the leaves up; `levels[0]` nodes point into
+    /// `entries`, the others into the level below. The last level holds
+    /// only the root.
+    levels: Vec<Vec<Node>>,
+}
+
+impl World {
+    /// Build an R-tree over the extent of every feature
+    ///
+    /// Extents come from `feature_bbox`; features without positioned
+    /// vectors are left out. The index is a snapshot: rebuild it after
+    /// editing the World.
+    pub fn build_spatial_index(&self) -> SpatialIndex {
+        let mut entries = Vec::new();
+        for feature in self.entities_of_type(EntityType::Feature) {
+            let Some(bbox) = self.feature_bbox(feature) else {
+                continue;
+            };
+            let (Some(min_lat), Some(max_lat)) =
+                (outward(&bbox.min_lat, false), outward(&bbox.max_lat, true))
+            else {
+                continue;
+            };
+            let (Some(min_lon), Some(max_lon)) =
+                (outward(&bbox.min_lon, false), outward(&bbox.max_lon, true))
+            else {
+                continue;
+            };
+            for rect in Rect::query(min_lat, min_lon, max_lat, max_lon) {
+                entries.push((rect, feature));
+            }
+        }
+        SpatialIndex::pack(entries)
+    }
+}
+
+impl SpatialIndex {
+    /// Number of indexed boxes (a feature across the antimeridian has two)
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// True if no feature has a position
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Features whose bounding box touches a box given in degrees
+    ///
+    /// A box with `min_lon > max_lon` crosses the antimeridian, as in
+    /// `BoundingBox`. Each feature is reported once, in entity order.
+    pub fn query_bbox(
+        &self,
+        min_lat: f64,
+        min_lon: f64,
+        max_lat: f64,
+        max_lon: f64,
+    ) -> Vec<EntityId> {
+        let mut found = Vec::new();
+        if let Some(root) = self.levels.len().checked_sub(1) {
+            for query in Rect::query(min_lat, min_lon, max_lat, max_lon) {
+                self.search(root, 0, &query, &mut found);
+            }
+        }
+        found.sort();
+        found.dedup();
+        found
+    }
+
+    /// Features whose bounding box contains a point (lat, lon in degrees)
+    ///
+    /// Points on a box edge count as inside it.
+    pub fn query_point(&self, lat: f64, lon: f64) -> Vec<EntityId> {
+        self.query_bbox(lat, lon, lat, lon)
+    }
+
+    fn search(&self, level: usize, node: usize, query: &Rect, found: &mut Vec<EntityId>) {
+        let node = &self.levels[level][node];
+        if !node.rect.intersects(query) {
+            return;
+        }
+        if level == 0 {
+            found.extend(
+                self.entries[node.children.clone()]
+                    .iter()
+                    .filter(|(rect, _)| rect.intersects(query))
+                    .map(|(_, feature)| *feature),
+            );
+        } else {
+            for child in node.children.clone() {
+                self.search(level - 1, child, query, found);
+            }
+        }
+    }
+
+    /// Bulk-load the tree, one level at a time from the leaves up
+    fn pack(mut entries: Vec<(Rect, EntityId)>) -> Self {
+        if entries.is_empty() {
+            return Self::default();
+        }
+        let mut levels = Vec::new();
+        let mut level: Vec<Node> = tile(&mut entries, |(rect, _)| *rect)
+            .into_iter()
+            .map(|children| node(children, |i| entries[i].0))
+            .collect();
+        while level.len() > 1 {
+            let groups = tile(&mut level, |node| node.rect);
+            let parents = groups
+                .into_iter()
+                .map(|children| node(children, |i| level[i].rect))
+                .collect();
+            levels.push(std::mem::replace(&mut level, parents));
+        }
+        levels.push(level);
+        Self { entries, levels }
+    }
+}
+
+/// Node covering a run of children
+fn node(children: Range<usize>, rect_of: impl Fn(usize) -> Rect) -> Node {
+    let rect = children
+        .clone()
+        .map(&rect_of)
+        .reduce(|a, b| a.union(&b))
+        .expect("non-empty run");
+    Node { rect, children }
+}
+
+/// Sort-Tile-Recursive grouping: order `items` into vertical slices by
+/// longitude, each sorted by latitude, and cut them into runs of at most
+/// `NODE_CAPACITY`
+fn tile<T>(items: &mut [T], rect_of: impl Fn(&T) -> Rect) -> Vec<Range<usize>> {
+    let nodes = items.len().div_ceil(NODE_CAPACITY);
+    let slices = (nodes as f64).sqrt().ceil() as usize;
+    let per_slice = slices.max(1) * NODE_CAPACITY;
+    items.sort_by(|a, b| rect_of(a).centre().1.total_cmp(&rect_of(b).centre().1));
+
+    let mut groups = Vec::with_capacity(nodes);
+    for (i, slice) in items.chunks_mut(per_slice).enumerate() {
+        slice.sort_by(|a, b| rect_of(a).centre().0.total_cmp(&rect_of(b).centre().0));
+        let start = i * per_slice;
+        for offset in (0..slice.len()).step_by(NODE_CAPACITY) {
+            groups.push(start + offset..start + (offset + NODE_CAPACITY).min(slice.len()));
+        }
+    }
+    groups
+}
+
+/// Degrees as f64, stepped one ulp outward (up for a maximum, down for a
+/// minimum) so rounding can't shrink a box
+fn outward(value: &BigRational, up: bool) -> Option<f64> {
+    let v = value.to_f64().filter(|v| v.is_finite())?;
+    Some(if up { v.next_up() } else { v.next_down() })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::{ExactPositions, FeatureMeta, FeaturePointers, SpatialRef, VectorMeta};
+    use crate::region::BoundingBox;
+    use num_bigint::BigInt;
+    use s57_parse::bitstring::{FoidKey, NameKey};
+
+    fn deg(v: i64) -> BigRational {
+        BigInt::from(v).into()
+    }
+
+    /// Feature over one node holding `points` (lat, lon)
+    fn add(world: &mut World, fidn: u32, points: &[(i64, i64)]) -> EntityId {
+        let node = world.create_entity(EntityType::Vector);
+        let name = NameKey {
+            rcnm: 110,
+            rcid: fidn,
+        };
+        world.name_index.insert(name, node);
+        world.vector_meta.insert(
+            node,
+            VectorMeta {
+                name,
+                rver: 1,
+                ruin: 1,
+            },
+        );
+        world.exact_positions.insert(
+            node,
+            ExactPositions {
+                lat: points.iter().map(|p| deg(p.0)).collect(),
+                lon: points.iter().map(|p| deg(p.1)).collect(),
+            },
+        );
+
+        let feature = world.create_entity(EntityType::Feature);
+        let foid = FoidKey {
+            agen: 550,
+            fidn,
+            fids: 1,
+        };
+        world.foid_index.insert(foid, feature);
+        world.feature_meta.insert(
+            feature,
+            FeatureMeta {
+                foid,
+                prim: 1,
+                grup: 2,
+                objl: 129,
+                rver: 1,
+                ruin: 1,
+            },
+        );
+        world.feature_pointers.insert(
+            feature,
+            FeaturePointers {
+                related_features: Vec::new(),
+                spatial_refs: vec![SpatialRef {
+                    entity: node,
+                    ornt: 255,
+                    usag: 255,
+                    mask: 255,
+                }],
+            },
+        );
+        feature
+    }
+
+    #[test]
+    fn test_spatial_index_matches_scan() {
+        // A grid of small features, enough for a three-level tree
+        let mut world = World::new();
+        let mut fidn = 0;
+        for lat in -20..20 {
+            for lon in -20..20 {
+                fidn += 1;
+                add(
+                    &mut world,
+                    fidn,
+                    &[(lat * 2, lon * 3), (lat * 2 + 1, lon * 3 + 2)],
+                );
+            }
+        }
+        let index = world.build_spatial_index();
+        assert_eq!(index.len(), 1600);
+        assert_eq!(index.levels.len(), 3);
+
+        for (min_lat, min_lon, max_lat, max_lon) in [
+            (-5, -7, 3, 4),
+            (0, 0, 0, 0),
+            (10, 40, 12, 100),
+            (-90, -180, 90, 180),
+        ] {
+            let query = BoundingBox::new(deg(min_lat), deg(min_lon), deg(max_lat), deg(max_lon));
+            let mut expected: Vec<_> = world
+                .entities_of_type(EntityType::Feature)
+                .into_iter()
+                .filter(|f| world.feature_bbox(*f).is_some_and(|b| b.intersects(&query)))
+                .collect();
+            expected.sort();
+            let found = index.query_bbox(
+                min_lat as f64,
+                min_lon as f64,
+                max_lat as f64,
+                max_lon as f64,
+            );
+            assert_eq!(found, expected, "query {:?}", query);
+        }
+    }
+
+    #[test]
+    fn test_spatial_index_point_and_antimeridian() {
+        let mut world = World::new();
+        let harbour = add(&mut world, 1, &[(50, -2), (51, -1)]);
+        let strait = add(&mut world, 2, &[(10, 179), (11, -179)]);
+        add(&mut world, 3, &[(0, 0)]);
+        let index = world.build_spatial_index();
+        assert_eq!(index.len(), 4);
+
+        assert_eq!(index.query_point(50.5, -1.5), [harbour]);
+        // Corners count, with no rounding loss
+        assert_eq!(index.query_point(51.0, -1.0), [harbour]);
+        assert!(index.query_point(51.5, -1.0).is_empty());
+
+        // Either side of 180, and a query box crossing it
+        assert_eq!(index.query_point(10.5, 179.5), [strait]);
+        assert_eq!(index.query_point(10.5, -179.5), [strait]);
+        assert!(index.query_point(10.5, 0.5).is_empty());
+        assert_eq!(index.query_bbox(0.0, 170.0, 20.0, -170.0), [strait]);
+
+        assert!(World::new()
+            .build_spatial_index()
+            .query_point(0.0, 0.0)
+            .is_empty());
+    }
+}
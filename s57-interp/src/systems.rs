@@ -4,20 +4,26 @@
 //! structured entities and components. Each system focuses on a specific
 //! transformation step in the pipeline.
 
+use crate::curve::{densify_arc, densify_bezier, densify_ellipse, ArcType, Point};
 use crate::ecs::{
-    EntityType, ExactDepths, ExactPositions, FeatureMeta, FeaturePointers, SpatialRef, VectorMeta,
-    VectorNeighbor, VectorTopology, World,
+    Coordinates, EntityType, ExactDepths, ExactPositions, FeatureMeta, FeatureRef, Relationship,
+    SpatialRef, UpdateControl, UpdateInstruction, VectorAttributes, VectorMeta, VectorNeighbor,
+    VectorTopology, World,
 };
+use crate::fixed::{FixedDepths, FixedPositions};
+use crate::options::CoordinateBackend;
 use num_bigint::BigInt;
 use num_rational::BigRational;
+use num_traits::ToPrimitive;
 use s57_parse::bitstring::{FoidKey, NameKey};
 use s57_parse::ddr::{ParsedField, SubfieldValue};
 use s57_parse::{ParseError, ParseErrorKind, Result};
+use std::collections::HashSet;
 
 /// Helper: Extract u8 from subfield group
 /// Returns Ok(None) if field not present, Err if present but wrong type or out of range
-pub(crate) fn get_u8(group: &[(String, SubfieldValue)], label: &str) -> Result<Option<u8>> {
-    match group.iter().find(|(l, _)| l == label) {
+pub(crate) fn get_u8(group: &[(&str, SubfieldValue)], label: &str) -> Result<Option<u8>> {
+    match group.iter().find(|(l, _)| *l == label) {
         None => Ok(None),
         Some((_, SubfieldValue::Integer(i))) if *i >= 0 && *i <= u8::MAX as i32 => {
             Ok(Some(*i as u8))
@@ -35,8 +41,8 @@ pub(crate) fn get_u8(group: &[(String, SubfieldValue)], label: &str) -> Result<O
 
 /// Helper: Extract u16 from subfield group
 /// Returns Ok(None) if field not present, Err if present but wrong type or out of range
-pub(crate) fn get_u16(group: &[(String, SubfieldValue)], label: &str) -> Result<Option<u16>> {
-    match group.iter().find(|(l, _)| l == label) {
+pub(crate) fn get_u16(group: &[(&str, SubfieldValue)], label: &str) -> Result<Option<u16>> {
+    match group.iter().find(|(l, _)| *l == label) {
         None => Ok(None),
         Some((_, SubfieldValue::Integer(i))) if *i >= 0 && *i <= u16::MAX as i32 => {
             Ok(Some(*i as u16))
@@ -56,8 +62,8 @@ pub(crate) fn get_u16(group: &[(String, SubfieldValue)], label: &str) -> Result<
 
 /// Helper: Extract u32 from subfield group
 /// Returns Ok(None) if field not present, Err if present but wrong type or negative
-pub(crate) fn get_u32(group: &[(String, SubfieldValue)], label: &str) -> Result<Option<u32>> {
-    match group.iter().find(|(l, _)| l == label) {
+pub(crate) fn get_u32(group: &[(&str, SubfieldValue)], label: &str) -> Result<Option<u32>> {
+    match group.iter().find(|(l, _)| *l == label) {
         None => Ok(None),
         Some((_, SubfieldValue::Integer(i))) if *i >= 0 => Ok(Some(*i as u32)),
         Some((_, SubfieldValue::UnsignedInteger(u))) => Ok(Some(*u)),
@@ -73,8 +79,8 @@ pub(crate) fn get_u32(group: &[(String, SubfieldValue)], label: &str) -> Result<
 
 /// Helper: Extract i32 from subfield group
 /// Returns Ok(None) if field not present, Err if present but wrong type
-pub(crate) fn get_i32(group: &[(String, SubfieldValue)], label: &str) -> Result<Option<i32>> {
-    match group.iter().find(|(l, _)| l == label) {
+pub(crate) fn get_i32(group: &[(&str, SubfieldValue)], label: &str) -> Result<Option<i32>> {
+    match group.iter().find(|(l, _)| *l == label) {
         None => Ok(None),
         Some((_, SubfieldValue::Integer(i))) => Ok(Some(*i)),
         Some((_, SubfieldValue::UnsignedInteger(u))) if *u <= i32::MAX as u32 => {
@@ -90,12 +96,27 @@ pub(crate) fn get_i32(group: &[(String, SubfieldValue)], label: &str) -> Result<
     }
 }
 
+/// Helper: Extract string from subfield group
+/// Returns None if field not present or not a string
+pub(crate) fn get_string(group: &[(&str, SubfieldValue)], label: &str) -> Option<String> {
+    group.iter().find(|(l, _)| *l == label).and_then(|(_, v)| {
+        if let SubfieldValue::String(s) = v {
+            Some(s.to_string())
+        } else {
+            None
+        }
+    })
+}
+
 /// Helper: Extract bytes from subfield group
 /// Returns Ok(None) if field not present, Err if present but wrong type
-fn get_bytes<'a>(group: &'a [(String, SubfieldValue)], label: &str) -> Result<Option<&'a [u8]>> {
-    match group.iter().find(|(l, _)| l == label) {
+pub(crate) fn get_bytes<'a>(
+    group: &'a [(&str, SubfieldValue)],
+    label: &str,
+) -> Result<Option<&'a [u8]>> {
+    match group.iter().find(|(l, _)| *l == label) {
         None => Ok(None),
-        Some((_, SubfieldValue::Bytes(bytes))) => Ok(Some(bytes.as_slice())),
+        Some((_, SubfieldValue::Bytes(bytes))) => Ok(Some(bytes.as_ref())),
         Some((_, _)) => Err(ParseError::at(
             ParseErrorKind::InvalidField(format!("{} has wrong type, expected bytes", label)),
             0,
@@ -309,8 +330,10 @@ impl FoidDecodeSystem {
 ///
 /// Requires DatasetParams to be set in World for COMF/SOMF values.
 ///
-/// Input: ParsedField from s57-parse + DatasetParams
-/// Output: ExactPositions and ExactDepths components (BigRational)
+/// Input: ParsedField from s57-parse, or raw values decoded in bulk, plus
+/// DatasetParams
+/// Output: ExactPositions and ExactDepths components (BigRational), or
+/// their fixed-point counterparts
 pub struct GeometrySystem;
 
 impl GeometrySystem {
@@ -328,69 +351,198 @@ impl GeometrySystem {
         entity: crate::ecs::EntityId,
         sg2d: &ParsedField,
     ) -> Result<()> {
-        // Get dataset params (required for COMF)
+        let points = Self::raw_coordinates(sg2d, ["YCOO", "XCOO"])?;
+        Self::process_coordinates(world, entity, &points, CoordinateBackend::Exact)
+    }
+
+    /// Process SG3D field into exact positions and depths
+    ///
+    /// # Arguments
+    /// * `world` - ECS world with DatasetParams
+    /// * `entity` - Entity to attach positions/depths to
+    /// * `sg3d` - Parsed SG3D field
+    ///
+    /// # Returns
+    /// Ok(()) if successful, or ParseError if params/data missing
+    pub fn process_sg3d(
+        world: &mut World,
+        entity: crate::ecs::EntityId,
+        sg3d: &ParsedField,
+    ) -> Result<()> {
+        let points = Self::raw_coordinates(sg3d, ["YCOO", "XCOO", "VE3D"])?;
+        Self::process_coordinates(world, entity, &points, CoordinateBackend::Exact)
+    }
+
+    /// Process SG2D or SG3D field into fixed-point positions (and depths)
+    ///
+    /// The `CoordinateBackend::Fixed` counterpart of `process_sg2d` and
+    /// `process_sg3d`: the same values, kept as raw integers with COMF/SOMF.
+    ///
+    /// # Arguments
+    /// * `world` - ECS world with DatasetParams
+    /// * `entity` - Entity to attach positions/depths to
+    /// * `field` - Parsed SG2D or SG3D field (depths are read from VE3D)
+    ///
+    /// # Returns
+    /// Ok(()) if successful, or ParseError if params/data missing
+    pub fn process_fixed(
+        world: &mut World,
+        entity: crate::ecs::EntityId,
+        field: &ParsedField,
+    ) -> Result<()> {
+        if field.tag == "SG3D" {
+            let points = Self::raw_coordinates(field, ["YCOO", "XCOO", "VE3D"])?;
+            Self::process_coordinates(world, entity, &points, CoordinateBackend::Fixed)
+        } else {
+            let points = Self::raw_coordinates(field, ["YCOO", "XCOO"])?;
+            Self::process_coordinates(world, entity, &points, CoordinateBackend::Fixed)
+        }
+    }
+
+    /// Raw (YCOO, XCOO[, VE3D]) values of a parsed SG2D/SG3D field, in
+    /// `labels` order
+    ///
+    /// The subfield-by-subfield path, for fields `DDR::decode_i32_groups`
+    /// can't read in bulk.
+    pub fn raw_coordinates<const N: usize>(
+        field: &ParsedField,
+        labels: [&str; N],
+    ) -> Result<Vec<[i32; N]>> {
+        field
+            .groups()
+            .iter()
+            .map(|group| {
+                let mut values = [0; N];
+                for (value, label) in values.iter_mut().zip(labels) {
+                    *value = get_i32(group, label)?.ok_or_else(|| {
+                        ParseError::at(
+                            ParseErrorKind::InvalidField(format!(
+                                "{} missing {}",
+                                field.tag, label
+                            )),
+                            0,
+                        )
+                    })?;
+                }
+                Ok(values)
+            })
+            .collect()
+    }
+
+    /// Store raw SG2D (N = 2) or SG3D (N = 3) values on a vector
+    ///
+    /// `points` are (YCOO, XCOO) or (YCOO, XCOO, VE3D), as returned by
+    /// `DDR::decode_i32_groups` or `raw_coordinates`. The exact backend
+    /// divides by COMF/SOMF into ExactPositions/ExactDepths; the fixed one
+    /// keeps the integers in FixedPositions/FixedDepths.
+    ///
+    /// # Returns
+    /// Ok(()) if successful, or ParseError if params/data missing
+    pub fn process_coordinates<const N: usize>(
+        world: &mut World,
+        entity: crate::ecs::EntityId,
+        points: &[[i32; N]],
+        backend: CoordinateBackend,
+    ) -> Result<()> {
+        // Get dataset params (required for COMF/SOMF)
         let params = world.dataset_params.as_ref().ok_or_else(|| {
             ParseError::at(
                 ParseErrorKind::InvalidField("Dataset params not set".to_string()),
                 0,
             )
         })?;
-
-        let groups = sg2d.groups();
-        if groups.is_empty() {
+        let depths = N == 3;
+        if points.is_empty() {
+            let tag = if depths { "SG3D" } else { "SG2D" };
             return Err(ParseError::at(
-                ParseErrorKind::InvalidField("SG2D has no data".to_string()),
+                ParseErrorKind::InvalidField(format!("{} has no data", tag)),
                 0,
             ));
         }
-
-        // Extract coordinate pairs and convert to exact BigRational
-        let mut lat = Vec::with_capacity(groups.len());
-        let mut lon = Vec::with_capacity(groups.len());
-
-        for group in groups {
-            let y = get_i32(group, "YCOO")?.ok_or_else(|| {
-                ParseError::at(
-                    ParseErrorKind::InvalidField("SG2D missing YCOO".to_string()),
-                    0,
-                )
-            })?;
-            let x = get_i32(group, "XCOO")?.ok_or_else(|| {
-                ParseError::at(
-                    ParseErrorKind::InvalidField("SG2D missing XCOO".to_string()),
-                    0,
-                )
-            })?;
-
-            // lat = y / COMF (degrees)
-            lat.push(BigRational::new(BigInt::from(y), params.comf.clone()));
-            // lon = x / COMF (degrees)
-            lon.push(BigRational::new(BigInt::from(x), params.comf.clone()));
+        let depth = |point: &[i32; N]| point.get(2).copied().unwrap_or(0);
+
+        match backend {
+            CoordinateBackend::Exact => {
+                let exact = |value: i32, factor: &BigInt| {
+                    BigRational::new(BigInt::from(value), factor.clone())
+                };
+                // lat = y / COMF, lon = x / COMF (degrees)
+                let lat = points.iter().map(|p| exact(p[0], &params.comf)).collect();
+                let lon = points.iter().map(|p| exact(p[1], &params.comf)).collect();
+                let units = params.duni;
+                if depths {
+                    // depth = z / SOMF (DUNI units, typically metres)
+                    let depth = points
+                        .iter()
+                        .map(|p| exact(depth(p), &params.somf))
+                        .collect();
+                    world
+                        .exact_depths
+                        .insert(entity, ExactDepths { depth, units });
+                }
+                world
+                    .exact_positions
+                    .insert(entity, ExactPositions { lat, lon });
+            }
+            CoordinateBackend::Fixed => {
+                let factor = |value: &BigInt, name: &str| {
+                    value.to_i64().ok_or_else(|| {
+                        ParseError::at(
+                            ParseErrorKind::InvalidField(format!(
+                                "{} {} out of range",
+                                name, value
+                            )),
+                            0,
+                        )
+                    })
+                };
+                let comf = factor(&params.comf, "COMF")?;
+                let somf = factor(&params.somf, "SOMF")?;
+                let units = params.duni;
+                let y = points.iter().map(|p| i64::from(p[0])).collect();
+                let x = points.iter().map(|p| i64::from(p[1])).collect();
+                if depths {
+                    let z = points.iter().map(|p| i64::from(depth(p))).collect();
+                    world
+                        .fixed_depths
+                        .insert(entity, FixedDepths { z, somf, units });
+                }
+                world
+                    .fixed_positions
+                    .insert(entity, FixedPositions { y, x, comf });
+            }
         }
 
-        // Create ExactPositions component
-        world
-            .exact_positions
-            .insert(entity, ExactPositions { lat, lon });
-
         Ok(())
     }
 
-    /// Process SG3D field into exact positions and depths
+    /// Process ARCC arc/curve definition into densified exact positions
+    ///
+    /// `construction` is the AR2D, EL2D or CT2D field that accompanies ARCC.
+    /// Its YCOO/XCOO pairs are read in order:
+    /// - AR2D: start point, centre point, end point
+    /// - EL2D: start, centre, end, conjugate diameter points (major, minor)
+    /// - CT2D: curve control points
+    ///
+    /// Arcs and ellipses are interpolated clockwise; piecewise Bézier curves
+    /// use ORDR as the curve degree. B-spline types (ATYP 3 and 5) fall back
+    /// to the control polygon. Interpolated points are snapped to the COMF
+    /// grid, so construction points keep their exact values.
     ///
     /// # Arguments
     /// * `world` - ECS world with DatasetParams
-    /// * `entity` - Entity to attach positions/depths to
-    /// * `sg3d` - Parsed SG3D field
+    /// * `entity` - Entity to attach positions to
+    /// * `arcc` - Parsed ARCC field (ATYP, SURF, ORDR, RESO, FPMF)
+    /// * `construction` - Parsed AR2D/EL2D/CT2D field
     ///
     /// # Returns
     /// Ok(()) if successful, or ParseError if params/data missing
-    pub fn process_sg3d(
+    pub fn process_arcc(
         world: &mut World,
         entity: crate::ecs::EntityId,
-        sg3d: &ParsedField,
+        arcc: &ParsedField,
+        construction: &ParsedField,
     ) -> Result<()> {
-        // Get dataset params (required for COMF/SOMF)
         let params = world.dataset_params.as_ref().ok_or_else(|| {
             ParseError::at(
                 ParseErrorKind::InvalidField("Dataset params not set".to_string()),
@@ -398,61 +550,104 @@ impl GeometrySystem {
             )
         })?;
 
-        let groups = sg3d.groups();
-        if groups.is_empty() {
+        let group = arcc.groups().first().ok_or_else(|| {
+            ParseError::at(
+                ParseErrorKind::InvalidField("ARCC has no data".to_string()),
+                0,
+            )
+        })?;
+        let atyp = get_u8(group, "ATYP")?.ok_or_else(|| {
+            ParseError::at(
+                ParseErrorKind::InvalidField("ARCC missing ATYP".to_string()),
+                0,
+            )
+        })?;
+        let arc_type = ArcType::from_code(atyp).ok_or_else(|| {
+            ParseError::at(
+                ParseErrorKind::InvalidField(format!("ARCC has unknown ATYP {}", atyp)),
+                0,
+            )
+        })?;
+        let ordr = get_u8(group, "ORDR").ok().flatten().unwrap_or(3);
+        let reso = group
+            .iter()
+            .find(|(l, _)| *l == "RESO")
+            .and_then(|(_, v)| v.as_float());
+
+        // Collect YCOO/XCOO pairs in field order (raw COMF units)
+        let mut raw: Vec<(i32, i32)> = Vec::new();
+        let mut pending_y = None;
+        for (label, value) in construction.groups().iter().flatten() {
+            let coord = match value {
+                SubfieldValue::Integer(i) => Some(*i),
+                SubfieldValue::UnsignedInteger(u) => i32::try_from(*u).ok(),
+                _ => None,
+            };
+            match (*label, coord) {
+                ("YCOO", Some(y)) => pending_y = Some(y),
+                ("XCOO", Some(x)) => {
+                    if let Some(y) = pending_y.take() {
+                        raw.push((y, x));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let required = match arc_type {
+            ArcType::ArcCentre => 3,
+            ArcType::EllipticalArc => 5,
+            _ => 2,
+        };
+        if raw.len() < required {
             return Err(ParseError::at(
-                ParseErrorKind::InvalidField("SG3D has no data".to_string()),
+                ParseErrorKind::InvalidField(format!(
+                    "{} has {} points, ATYP {} needs {}",
+                    construction.tag,
+                    raw.len(),
+                    atyp,
+                    required
+                )),
                 0,
             ));
         }
 
-        // Extract coordinate triplets and convert to exact BigRational
-        let mut lat = Vec::with_capacity(groups.len());
-        let mut lon = Vec::with_capacity(groups.len());
-        let mut depth = Vec::with_capacity(groups.len());
-
-        for group in groups {
-            let y = get_i32(group, "YCOO")?.ok_or_else(|| {
-                ParseError::at(
-                    ParseErrorKind::InvalidField("SG3D missing YCOO".to_string()),
-                    0,
-                )
-            })?;
-            let x = get_i32(group, "XCOO")?.ok_or_else(|| {
-                ParseError::at(
-                    ParseErrorKind::InvalidField("SG3D missing XCOO".to_string()),
-                    0,
-                )
-            })?;
-            let z = get_i32(group, "VE3D")?.ok_or_else(|| {
-                ParseError::at(
-                    ParseErrorKind::InvalidField("SG3D missing VE3D".to_string()),
-                    0,
-                )
-            })?;
+        // Project onto a local plane so circles stay circular at this latitude
+        let comf = params.comf.to_f64().unwrap_or(10_000_000.0);
+        let lat0 = raw.iter().map(|(y, _)| *y as f64).sum::<f64>() / raw.len() as f64 / comf;
+        let scale = lat0.to_radians().cos().max(1e-6);
+        let planar: Vec<Point> = raw
+            .iter()
+            .map(|(y, x)| (*x as f64 / comf * scale, *y as f64 / comf))
+            .collect();
+        let resolution = reso.filter(|r| *r > 0.0).map(|r| r / comf);
+
+        let points = match arc_type {
+            ArcType::ArcCentre => densify_arc(planar[0], planar[1], planar[2], resolution),
+            ArcType::EllipticalArc => densify_ellipse(
+                planar[0], planar[1], planar[2], planar[3], planar[4], resolution,
+            ),
+            ArcType::PiecewiseBezier => densify_bezier(&planar, ordr as usize, 16),
+            ArcType::UniformBSpline | ArcType::NonUniformRationalBSpline => {
+                crate::diag::debug!("ARCC ATYP {} not interpolated, using control polygon", atyp);
+                planar
+            }
+        };
 
-            // lat = y / COMF (degrees)
-            lat.push(BigRational::new(BigInt::from(y), params.comf.clone()));
-            // lon = x / COMF (degrees)
-            lon.push(BigRational::new(BigInt::from(x), params.comf.clone()));
-            // depth = z / SOMF (DUNI units, typically metres)
-            depth.push(BigRational::new(BigInt::from(z), params.somf.clone()));
+        // Snap back onto the COMF grid as exact rationals
+        let mut lat = Coordinates::with_capacity(points.len());
+        let mut lon = Coordinates::with_capacity(points.len());
+        for (x, y) in points {
+            let raw_y = (y * comf).round() as i64;
+            let raw_x = (x / scale * comf).round() as i64;
+            lat.push(BigRational::new(BigInt::from(raw_y), params.comf.clone()));
+            lon.push(BigRational::new(BigInt::from(raw_x), params.comf.clone()));
         }
 
-        // Create ExactPositions component
         world
             .exact_positions
             .insert(entity, ExactPositions { lat, lon });
 
-        // Create ExactDepths component
-        world.exact_depths.insert(
-            entity,
-            ExactDepths {
-                depth,
-                units: params.duni,
-            },
-        );
-
         Ok(())
     }
 }
@@ -556,6 +751,150 @@ impl TopologySystem {
     }
 }
 
+/// AttributeSystem: Process ATTF/NATF/ATTV records into attribute components
+///
+/// Extracts attribute label/value pairs from attribute fields:
+/// - ATTL (Attribute Label): attribute code from the S-57 catalogue
+/// - ATVL (Attribute Value): attribute value as text
+///
+/// Populates:
+/// - FeatureAttributes component (ATTF/NATF on feature records)
+/// - VectorAttributes component (ATTV on vector records, e.g. QUAPOS/POSACC)
+///
+/// Groups with a missing ATTL are kept with code 0 and missing ATVL values
+/// become empty strings, so attribute order is preserved for display.
+pub struct AttributeSystem;
+
+impl AttributeSystem {
+    /// Process ATTF field into FeatureAttributes::attf
+    pub fn process_attf(world: &mut World, entity: crate::ecs::EntityId, attf: &ParsedField) {
+        let pairs = Self::decode_pairs(attf);
+        world.feature_attributes.entry(entity).or_default().attf = pairs;
+    }
+
+    /// Process NATF field into FeatureAttributes::natf
+    pub fn process_natf(world: &mut World, entity: crate::ecs::EntityId, natf: &ParsedField) {
+        let pairs = Self::decode_pairs(natf);
+        world.feature_attributes.entry(entity).or_default().natf = pairs;
+    }
+
+    /// Process ATTV field into VectorAttributes component
+    pub fn process_attv(world: &mut World, entity: crate::ecs::EntityId, attv: &ParsedField) {
+        let pairs = Self::decode_pairs(attv);
+        world
+            .vector_attributes
+            .insert(entity, VectorAttributes { attv: pairs });
+    }
+
+    /// Decode (ATTL, ATVL) pairs from the repeating groups of an attribute field
+    fn decode_pairs(field: &ParsedField) -> Vec<(u16, String)> {
+        field
+            .groups()
+            .iter()
+            .map(|group| {
+                let attl = get_u16(group, "ATTL").ok().flatten().unwrap_or(0);
+                let atvl = get_string(group, "ATVL").unwrap_or_default();
+                (attl, atvl)
+            })
+            .collect()
+    }
+}
+
+/// UpdateControlSystem: Process VRPC/SGCC/FFPC/FSPC control fields
+///
+/// Update datasets (.001, .002, ...) use these fields to say which slice of
+/// the target record's VRPT, SG2D/SG3D, FFPT or FSPT field is inserted,
+/// deleted or modified. Each field has three subfields:
+/// - *UI: update instruction (1=insert, 2=delete, 3=modify)
+/// - *IX: 1-based index of the first pointer/coordinate affected
+/// - *N*: number of pointers/coordinates affected
+///
+/// Populates:
+/// - UpdateControls component
+pub struct UpdateControlSystem;
+
+impl UpdateControlSystem {
+    /// Process VRPC field (VPUI/VPIX/NVPT)
+    pub fn process_vrpc(
+        world: &mut World,
+        entity: crate::ecs::EntityId,
+        vrpc: &ParsedField,
+    ) -> Result<()> {
+        let control = Self::decode(vrpc, "VPUI", "VPIX", "NVPT")?;
+        world.update_controls.entry(entity).or_default().vrpc = Some(control);
+        Ok(())
+    }
+
+    /// Process SGCC field (CCUI/CCIX/CCNC)
+    pub fn process_sgcc(
+        world: &mut World,
+        entity: crate::ecs::EntityId,
+        sgcc: &ParsedField,
+    ) -> Result<()> {
+        let control = Self::decode(sgcc, "CCUI", "CCIX", "CCNC")?;
+        world.update_controls.entry(entity).or_default().sgcc = Some(control);
+        Ok(())
+    }
+
+    /// Process FFPC field (FFUI/FFIX/NFPT)
+    pub fn process_ffpc(
+        world: &mut World,
+        entity: crate::ecs::EntityId,
+        ffpc: &ParsedField,
+    ) -> Result<()> {
+        let control = Self::decode(ffpc, "FFUI", "FFIX", "NFPT")?;
+        world.update_controls.entry(entity).or_default().ffpc = Some(control);
+        Ok(())
+    }
+
+    /// Process FSPC field (FSUI/FSIX/NSPT)
+    pub fn process_fspc(
+        world: &mut World,
+        entity: crate::ecs::EntityId,
+        fspc: &ParsedField,
+    ) -> Result<()> {
+        let control = Self::decode(fspc, "FSUI", "FSIX", "NSPT")?;
+        world.update_controls.entry(entity).or_default().fspc = Some(control);
+        Ok(())
+    }
+
+    /// Decode the (instruction, index, count) triple of a control field
+    fn decode(field: &ParsedField, ui: &str, ix: &str, n: &str) -> Result<UpdateControl> {
+        let group = field.groups().first().ok_or_else(|| {
+            ParseError::at(
+                ParseErrorKind::InvalidField(format!("{} has no groups", field.tag)),
+                0,
+            )
+        })?;
+
+        let missing = |label: &str| {
+            ParseError::at(
+                ParseErrorKind::InvalidField(format!("{} missing {}", field.tag, label)),
+                0,
+            )
+        };
+
+        let code = get_u8(group, ui)?.ok_or_else(|| missing(ui))?;
+        let instruction = UpdateInstruction::from_code(code).ok_or_else(|| {
+            ParseError::at(
+                ParseErrorKind::InvalidField(format!(
+                    "{} has invalid update instruction {}",
+                    ui, code
+                )),
+                0,
+            )
+        })?;
+        let index = get_u16(group, ix)?.ok_or_else(|| missing(ix))?;
+        let count = get_u16(group, n)?.ok_or_else(|| missing(n))?;
+
+        Ok(UpdateControl {
+            instruction,
+            index,
+            count,
+        })
+    }
+}
+
 /// FeatureBindSystem: Process FSPT/FFPT records to link features to vectors/features
 ///
 /// Extracts feature relationship pointers:
@@ -640,10 +979,7 @@ impl FeatureBindSystem {
         }
 
         // Get or create FeaturePointers component
-        let pointers = world
-            .feature_pointers
-            .entry(entity)
-            .or_insert_with(FeaturePointers::default);
+        let pointers = world.feature_pointers.entry(entity).or_default();
 
         pointers.spatial_refs = spatial_refs;
 
@@ -652,6 +988,9 @@ impl FeatureBindSystem {
 
     /// Process FFPT field to extract feature-to-feature pointers
     ///
+    /// Run once every feature exists, so pointers can be resolved whatever
+    /// the record order.
+    ///
     /// # Arguments
     /// * `world` - ECS world with indices
     /// * `entity` - Feature entity to update
@@ -663,6 +1002,17 @@ impl FeatureBindSystem {
         world: &mut World,
         entity: crate::ecs::EntityId,
         ffpt: &ParsedField,
+    ) -> Result<()> {
+        Self::process_ffpt_skipping(world, entity, ffpt, &HashSet::new())
+    }
+
+    /// `process_ffpt`, quietly dropping pointers to the `skipped` features
+    /// (those a class filter left out of the world)
+    pub(crate) fn process_ffpt_skipping(
+        world: &mut World,
+        entity: crate::ecs::EntityId,
+        ffpt: &ParsedField,
+        skipped: &HashSet<FoidKey>,
     ) -> Result<()> {
         let groups = ffpt.groups();
         if groups.is_empty() {
@@ -690,25 +1040,30 @@ impl FeatureBindSystem {
                 )
             })?;
 
-            // Resolve LNAM to EntityId via foid_index
-            let feature_entity = *world.foid_index.get(&foid).ok_or_else(|| {
-                ParseError::at(
-                    ParseErrorKind::InvalidField(format!(
-                        "Referenced feature LNAM not found: agen={}, fidn={}, fids={}",
-                        foid.agen, foid.fidn, foid.fids
-                    )),
-                    0,
-                )
-            })?;
-
-            related_features.push(feature_entity);
+            // Resolve LNAM to EntityId via foid_index; a pointer to a feature
+            // the cell lacks is dropped alone, keeping the other relationships
+            let Some(&feature_entity) = world.foid_index.get(&foid) else {
+                if skipped.contains(&foid) {
+                    continue;
+                }
+                crate::diag::warning!(
+                    "Dropping FFPT pointer to missing feature agen={}, fidn={}, fids={}",
+                    foid.agen,
+                    foid.fidn,
+                    foid.fids
+                );
+                continue;
+            };
+
+            let rind = get_u8(group, "RIND")?.and_then(Relationship::from_code);
+            related_features.push(FeatureRef {
+                entity: feature_entity,
+                rind,
+            });
         }
 
         // Get or create FeaturePointers component
-        let pointers = world
-            .feature_pointers
-            .entry(entity)
-            .or_insert_with(FeaturePointers::default);
+        let pointers = world.feature_pointers.entry(entity).or_default();
 
         pointers.related_features = related_features;
 
@@ -728,10 +1083,10 @@ mod tests {
 
         // Create mock VRID field
         let vrid_data = vec![
-            ("RCNM".to_string(), SubfieldValue::Integer(110)),
-            ("RCID".to_string(), SubfieldValue::Integer(42)),
-            ("RVER".to_string(), SubfieldValue::Integer(1)),
-            ("RUIN".to_string(), SubfieldValue::Integer(1)),
+            ("RCNM", SubfieldValue::Integer(110)),
+            ("RCID", SubfieldValue::Integer(42)),
+            ("RVER", SubfieldValue::Integer(1)),
+            ("RUIN", SubfieldValue::Integer(1)),
         ];
 
         let vrid = create_mock_parsed_field("VRID", vec![vrid_data]);
@@ -757,10 +1112,195 @@ mod tests {
         assert_eq!(world.name_index.get(&name), Some(&entity));
     }
 
+    #[test]
+    fn test_attribute_system_attv() {
+        let mut world = World::new();
+        let entity = world.create_entity(EntityType::Vector);
+
+        let def = attribute_field_def("ATTV");
+        let attv = ParsedField {
+            tag: "ATTV",
+            field_def: &def,
+            groups: vec![
+                vec![
+                    ("ATTL", SubfieldValue::Integer(402)),
+                    ("ATVL", SubfieldValue::String("4".into())),
+                ],
+                vec![
+                    ("ATTL", SubfieldValue::Integer(401)),
+                    ("ATVL", SubfieldValue::String("12.5".into())),
+                ],
+            ],
+            trailing_bytes: 0,
+        };
+
+        AttributeSystem::process_attv(&mut world, entity, &attv);
+
+        let attrs = world.vector_attributes.get(&entity).unwrap();
+        assert_eq!(attrs.attv.len(), 2);
+        assert_eq!(attrs.get(402), Some("4"));
+        assert_eq!(attrs.get(401), Some("12.5"));
+
+        let quality = attrs.positional_quality().unwrap();
+        assert_eq!(quality.quapos, Some(4));
+    }
+
+    #[test]
+    fn test_attribute_system_attf_natf() {
+        let mut world = World::new();
+        let entity = world.create_entity(EntityType::Feature);
+
+        let def = attribute_field_def("ATTF");
+        let attf = ParsedField {
+            tag: "ATTF",
+            field_def: &def,
+            groups: vec![vec![
+                ("ATTL", SubfieldValue::Integer(116)),
+                ("ATVL", SubfieldValue::String("Harbor".into())),
+            ]],
+            trailing_bytes: 0,
+        };
+        let natf = ParsedField {
+            tag: "NATF",
+            field_def: &def,
+            groups: vec![vec![
+                ("ATTL", SubfieldValue::Integer(301)),
+                ("ATVL", SubfieldValue::Null),
+            ]],
+            trailing_bytes: 0,
+        };
+
+        AttributeSystem::process_attf(&mut world, entity, &attf);
+        AttributeSystem::process_natf(&mut world, entity, &natf);
+
+        let attrs = world.feature_attributes.get(&entity).unwrap();
+        assert_eq!(attrs.attf, vec![(116, "Harbor".to_string())]);
+        assert_eq!(attrs.natf, vec![(301, String::new())]);
+    }
+
+    #[test]
+    fn test_update_control_system() {
+        let mut world = World::new();
+        let entity = world.create_entity(EntityType::Vector);
+
+        let def = attribute_field_def("SGCC");
+        let sgcc = ParsedField {
+            tag: "SGCC",
+            field_def: &def,
+            groups: vec![vec![
+                ("CCUI", SubfieldValue::UnsignedInteger(2)),
+                ("CCIX", SubfieldValue::UnsignedInteger(3)),
+                ("CCNC", SubfieldValue::UnsignedInteger(4)),
+            ]],
+            trailing_bytes: 0,
+        };
+
+        UpdateControlSystem::process_sgcc(&mut world, entity, &sgcc).unwrap();
+
+        let controls = world.update_controls.get(&entity).unwrap();
+        assert_eq!(
+            controls.sgcc,
+            Some(UpdateControl {
+                instruction: UpdateInstruction::Delete,
+                index: 3,
+                count: 4,
+            })
+        );
+        assert!(controls.vrpc.is_none());
+
+        // Instruction code outside 1..=3 is rejected
+        let bad = ParsedField {
+            tag: "VRPC",
+            field_def: &def,
+            groups: vec![vec![
+                ("VPUI", SubfieldValue::UnsignedInteger(9)),
+                ("VPIX", SubfieldValue::UnsignedInteger(1)),
+                ("NVPT", SubfieldValue::UnsignedInteger(1)),
+            ]],
+            trailing_bytes: 0,
+        };
+        assert!(UpdateControlSystem::process_vrpc(&mut world, entity, &bad).is_err());
+    }
+
+    #[test]
+    fn test_geometry_system_arcc_arc() {
+        let mut world = World::new();
+        world.dataset_params = Some(crate::ecs::DatasetParams {
+            comf: BigInt::from(10_000_000),
+            somf: BigInt::from(10),
+            duni: 1,
+            huni: 1,
+            puni: 1,
+            hdat: 2,
+            vdat: 0,
+            sdat: 0,
+            cscl: 1,
+        });
+        let entity = world.create_entity(EntityType::Vector);
+
+        let def = attribute_field_def("ARCC");
+        let arcc = ParsedField {
+            tag: "ARCC",
+            field_def: &def,
+            groups: vec![vec![
+                ("ATYP", SubfieldValue::UnsignedInteger(1)),
+                ("SURF", SubfieldValue::UnsignedInteger(2)),
+                ("ORDR", SubfieldValue::UnsignedInteger(2)),
+            ]],
+            trailing_bytes: 0,
+        };
+        // Start north of the centre, end east of it (raw COMF units at the equator)
+        let point = |y: i32, x: i32| {
+            vec![
+                ("YCOO", SubfieldValue::Integer(y)),
+                ("XCOO", SubfieldValue::Integer(x)),
+            ]
+        };
+        let ar2d = ParsedField {
+            tag: "AR2D",
+            field_def: &def,
+            groups: vec![point(1000, 0), point(0, 0), point(0, 1000)],
+            trailing_bytes: 0,
+        };
+
+        GeometrySystem::process_arcc(&mut world, entity, &arcc, &ar2d).unwrap();
+
+        let positions = world.exact_positions.get(&entity).unwrap();
+        let comf = BigInt::from(10_000_000);
+        assert_eq!(positions.lat.len(), 19);
+        assert_eq!(
+            positions.lat[0],
+            BigRational::new(BigInt::from(1000), comf.clone())
+        );
+        assert_eq!(
+            positions.lon[0],
+            BigRational::new(BigInt::from(0), comf.clone())
+        );
+        assert_eq!(
+            positions.lat[18],
+            BigRational::new(BigInt::from(0), comf.clone())
+        );
+        assert_eq!(
+            positions.lon[18],
+            BigRational::new(BigInt::from(1000), comf)
+        );
+    }
+
+    fn attribute_field_def(tag: &str) -> s57_parse::ddr::FieldDef {
+        s57_parse::ddr::FieldDef {
+            tag: tag.to_string(),
+            name: "Attribute field".to_string(),
+            array_descriptor: "*ATTL!ATVL".to_string(),
+            format_controls: "(b12,A)".to_string(),
+            subfields: vec![],
+            is_repeating: true,
+        }
+    }
+
     // Helper to create mock ParsedField for testing
     fn create_mock_parsed_field(
         _tag: &str,
-        _groups: Vec<Vec<(String, SubfieldValue)>>,
+        _groups: Vec<Vec<(&str, SubfieldValue)>>,
     ) -> ParsedField<'static> {
         // This is a simplified mock - in real code ParsedField has lifetime tied to FieldDef
         // For testing purposes, we'll use unsafe to extend the lifetime
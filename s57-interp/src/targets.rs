@@ -0,0 +1,158 @@
+//! Dynamic targets composited onto a chart render
+//!
+//! A `TargetSource` is queried each time a chart is drawn, so applications
+//! can overlay live positions (e.g. AIS vessels) without touching the feature
+//! drawing code. `CsvTargets` reads them from a file that a separate process
+//! keeps up to date; `parse_targets` reads the same format from text.
+
+#[cfg(feature = "fs")]
+use std::path::PathBuf;
+
+/// Dynamic point target (e.g. an AIS vessel) drawn on top of the chart
+#[derive(Debug, Clone, PartialEq)]
+pub struct Target {
+    /// Latitude (degrees)
+    pub lat: f64,
+    /// Longitude (degrees)
+    pub lon: f64,
+    /// Heading or course over ground (degrees clockwise from north)
+    pub heading: Option<f64>,
+    /// Label drawn next to the symbol (e.g. vessel name or MMSI)
+    pub label: Option<String>,
+}
+
+/// Source of dynamic targets, queried each time the chart is rendered
+pub trait TargetSource {
+    /// Current targets
+    fn targets(&self) -> Vec<Target>;
+}
+
+impl TargetSource for Vec<Target> {
+    fn targets(&self) -> Vec<Target> {
+        self.clone()
+    }
+}
+
+/// TargetSource backed by a CSV file
+///
+/// Each line is `lat,lon[,heading[,label]]` in degrees; blank lines and lines
+/// starting with '#' are ignored. The file is re-read on every query.
+#[cfg(feature = "fs")]
+pub struct CsvTargets {
+    path: PathBuf,
+}
+
+#[cfg(feature = "fs")]
+impl CsvTargets {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+}
+
+#[cfg(feature = "fs")]
+impl TargetSource for CsvTargets {
+    fn targets(&self) -> Vec<Target> {
+        match std::fs::read_to_string(&self.path) {
+            Ok(text) => parse_targets(&text, &self.path.display().to_string()),
+            Err(e) => {
+                log::warn!("cannot read {}: {}", self.path.display(), e);
+                Vec::new()
+            }
+        }
+    }
+}
+
+/// Parse the text of a targets file, skipping blank, comment and invalid
+/// lines; `source` names the text in warnings (e.g. the file path)
+pub fn parse_targets(text: &str, source: &str) -> Vec<Target> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+        .filter_map(|(i, line)| {
+            let target = parse_line(line);
+            if target.is_none() {
+                log::warn!("{}:{}: invalid target line", source, i + 1);
+            }
+            target
+        })
+        .collect()
+}
+
+/// Parse `lat,lon[,heading[,label]]`; the label may contain commas
+fn parse_line(line: &str) -> Option<Target> {
+    let mut parts = line.splitn(4, ',').map(str::trim);
+    let lat = parts.next()?.parse().ok()?;
+    let lon = parts.next()?.parse().ok()?;
+    let heading = match parts.next() {
+        Some("") | None => None,
+        Some(h) => Some(h.parse().ok()?),
+    };
+    let label = parts.next().filter(|l| !l.is_empty()).map(str::to_string);
+
+    Some(Target {
+        lat,
+        lon,
+        heading,
+        label,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn target(lat: f64, lon: f64, heading: Option<f64>, label: Option<&str>) -> Target {
+        Target {
+            lat,
+            lon,
+            heading,
+            label: label.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_parse_line() {
+        assert_eq!(
+            parse_line("50.5,-1.25"),
+            Some(target(50.5, -1.25, None, None))
+        );
+        assert_eq!(
+            parse_line(" 50.5 , -1.25 , 270 "),
+            Some(target(50.5, -1.25, Some(270.0), None))
+        );
+        // Empty heading; the label keeps its commas
+        assert_eq!(
+            parse_line("50.5,-1.25,,Tug, 2 tows"),
+            Some(target(50.5, -1.25, None, Some("Tug, 2 tows")))
+        );
+        assert_eq!(
+            parse_line("50.5,-1.25,90,"),
+            Some(target(50.5, -1.25, Some(90.0), None))
+        );
+    }
+
+    #[test]
+    fn test_parse_line_malformed() {
+        assert_eq!(parse_line(""), None);
+        assert_eq!(parse_line("50.5"), None);
+        assert_eq!(parse_line("50.5,east"), None);
+        assert_eq!(parse_line("north,-1.25"), None);
+        assert_eq!(parse_line("50.5,-1.25,ahead,Tug"), None);
+    }
+
+    #[test]
+    fn test_parse_targets() {
+        let text = "# lat,lon,heading,label\n\
+                    50.5,-1.25,270,Tug\n\
+                    \n  # indented comment\n\
+                    not a target\n\
+                    51,0\n";
+        assert_eq!(
+            parse_targets(text, "targets.csv"),
+            [
+                target(50.5, -1.25, Some(270.0), Some("Tug")),
+                target(51.0, 0.0, None, None),
+            ]
+        );
+    }
+}
@@ -0,0 +1,285 @@
+//! Point types geometry can be resolved into
+//!
+//! The walker and cursors are generic over the point they produce. Exact
+//! (lat, lon) BigRational pairs are what validation and the planar
+//! predicates in `geometry` need; `ApproxPoint` f64 pairs are far cheaper
+//! to build and compare, and are enough to draw with.
+
+use super::TraversalContext;
+use crate::ecs::EntityId;
+use crate::geometry::{orientation, RingOrientation};
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{Signed, ToPrimitive};
+use std::fmt;
+
+/// ApproxPoint: (lat, lon) in f64 degrees
+pub type ApproxPoint = (f64, f64);
+
+/// Exact (lat, lon) points converted to f64
+pub fn approx_line(points: &[(BigRational, BigRational)]) -> Vec<ApproxPoint> {
+    let approx = |v: &BigRational| v.to_f64().unwrap_or(0.0);
+    points
+        .iter()
+        .map(|(lat, lon)| (approx(lat), approx(lon)))
+        .collect()
+}
+
+/// Coordinate: A (lat, lon) point the topology walker can resolve into
+pub trait Coordinate: Clone + PartialEq + Default + fmt::Debug {
+    /// Direct (SG2D/SG3D) positions of a vector, passed through the
+    /// context's transform; None if it has none
+    fn direct(ctx: &TraversalContext, entity: EntityId) -> Option<Vec<Self>>;
+
+    /// True if both coordinates differ by less than 1/denom
+    fn within(&self, other: &Self, denom: i64) -> bool;
+
+    /// The point as exact values, for error reports
+    fn to_exact(&self) -> (BigRational, BigRational);
+
+    /// Winding direction of a ring of these points
+    fn orientation(ring: &[Self]) -> RingOrientation;
+}
+
+impl Coordinate for (BigRational, BigRational) {
+    fn direct(ctx: &TraversalContext, entity: EntityId) -> Option<Vec<Self>> {
+        let positions = ctx.world.exact_positions_of(entity)?;
+        let (lat, lon) = positions.to_f64();
+        // Convert f64 back to BigRational (temporary until we store rationals directly)
+        let zero = || BigRational::new(BigInt::from(0), BigInt::from(1));
+        Some(
+            lat.iter()
+                .zip(lon.iter())
+                .map(|(la, lo)| {
+                    let lat = BigRational::from_float(*la).unwrap_or_else(zero);
+                    let lon = BigRational::from_float(*lo).unwrap_or_else(zero);
+                    match &ctx.transform {
+                        Some(transform) => transform.transform(&lat, &lon),
+                        None => (lat, lon),
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    fn within(&self, other: &Self, denom: i64) -> bool {
+        // Approximate distance check: |dx| + |dy| < tolerance (Manhattan distance)
+        let tolerance = BigRational::new(1.into(), denom.into());
+        (&self.0 - &other.0).abs() < tolerance && (&self.1 - &other.1).abs() < tolerance
+    }
+
+    fn to_exact(&self) -> (BigRational, BigRational) {
+        self.clone()
+    }
+
+    fn orientation(ring: &[Self]) -> RingOrientation {
+        orientation(ring)
+    }
+}
+
+/// Read from `approx_positions`, else converted from the fixed-point or
+/// exact positions. A transform works on exact values, so each point makes
+/// a round trip through BigRational; the fast path is the untransformed one.
+impl Coordinate for ApproxPoint {
+    fn direct(ctx: &TraversalContext, entity: EntityId) -> Option<Vec<Self>> {
+        let world = ctx.world;
+        let points: Vec<Self> = match world.approx_positions.get(&entity) {
+            Some(approx) => approx.points().collect(),
+            None => world.approx_points(entity)?.points().collect(),
+        };
+        let Some(transform) = &ctx.transform else {
+            return Some(points);
+        };
+        let exact = |v: f64| BigRational::from_float(v).unwrap_or_else(|| BigInt::from(0).into());
+        let approx = |v: &BigRational| v.to_f64().unwrap_or(0.0);
+        Some(
+            points
+                .into_iter()
+                .map(|(lat, lon)| {
+                    let (lat, lon) = transform.transform(&exact(lat), &exact(lon));
+                    (approx(&lat), approx(&lon))
+                })
+                .collect(),
+        )
+    }
+
+    fn within(&self, other: &Self, denom: i64) -> bool {
+        let tolerance = 1.0 / denom as f64;
+        (self.0 - other.0).abs() < tolerance && (self.1 - other.1).abs() < tolerance
+    }
+
+    fn to_exact(&self) -> (BigRational, BigRational) {
+        let exact = |v: f64| BigRational::from_float(v).unwrap_or_else(|| BigInt::from(0).into());
+        (exact(self.0), exact(self.1))
+    }
+
+    fn orientation(ring: &[Self]) -> RingOrientation {
+        if ring.len() < 3 {
+            return RingOrientation::Degenerate;
+        }
+        // Twice the signed area, x = lon and y = lat as in `signed_area2`
+        let area2: f64 = ring
+            .iter()
+            .zip(ring.iter().cycle().skip(1))
+            .map(|((lat1, lon1), (lat2, lon2))| lon1 * lat2 - lon2 * lat1)
+            .sum();
+        if area2 > 0.0 {
+            RingOrientation::CounterClockwise
+        } else if area2 < 0.0 {
+            RingOrientation::Clockwise
+        } else {
+            RingOrientation::Degenerate
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::{EntityType, ExactPositions, World};
+    use crate::fixed::FixedPositions;
+
+    /// Exact value of a fixed-point coordinate
+    fn exact(value: i64, comf: i64) -> BigRational {
+        BigRational::new(value.into(), comf.into())
+    }
+
+    /// The same three COMF 10^7 positions on an exact and a fixed-point vector
+    fn world() -> (World, EntityId, EntityId) {
+        const COMF: i64 = 10_000_000;
+        let y = [505_000_001, 505_000_002, -1];
+        let x = [-12_345_678, -12_345_678, 1_799_999_999];
+        let mut world = World::new();
+
+        let exact_vector = world.create_entity(EntityType::Vector);
+        world.exact_positions.insert(
+            exact_vector,
+            ExactPositions {
+                lat: y.iter().map(|&v| exact(v, COMF)).collect(),
+                lon: x.iter().map(|&v| exact(v, COMF)).collect(),
+            },
+        );
+        let fixed_vector = world.create_entity(EntityType::Vector);
+        world.fixed_positions.insert(
+            fixed_vector,
+            FixedPositions {
+                y: y.iter().copied().collect(),
+                x: x.iter().copied().collect(),
+                comf: COMF,
+            },
+        );
+        (world, exact_vector, fixed_vector)
+    }
+
+    #[test]
+    fn test_exact_direct() {
+        let (world, exact_vector, fixed_vector) = world();
+        let ctx = TraversalContext::new(&world);
+
+        // Both backends resolve to the same exact points
+        let points = <(BigRational, BigRational)>::direct(&ctx, exact_vector).unwrap();
+        assert_eq!(points.len(), 3);
+        assert_eq!(
+            <(BigRational, BigRational)>::direct(&ctx, fixed_vector).unwrap(),
+            points
+        );
+        // Read through f64, so within far less than one unit of the stored
+        // value
+        let stored = (
+            exact(505_000_001, 10_000_000),
+            exact(-12_345_678, 10_000_000),
+        );
+        assert!(points[0].within(&stored, 1_000_000_000));
+
+        // A vector without positions has no direct points
+        let mut world = world;
+        let empty = world.create_entity(EntityType::Vector);
+        let ctx = TraversalContext::new(&world);
+        assert!(<(BigRational, BigRational)>::direct(&ctx, empty).is_none());
+    }
+
+    #[test]
+    fn test_exact_within_and_orientation() {
+        let point = |lat: i64, lon: i64| (exact(lat, 10_000_000), exact(lon, 10_000_000));
+        let a = point(505_000_001, 0);
+        // One unit of 10^-7 degrees apart
+        let b = point(505_000_002, 0);
+        assert!(a.within(&b, 1_000_000));
+        assert!(!a.within(&b, 10_000_000));
+        assert_eq!(a.to_exact(), a);
+
+        let ring = [point(0, 0), point(0, 10), point(10, 10), point(0, 0)];
+        assert_eq!(
+            <(BigRational, BigRational)>::orientation(&ring),
+            RingOrientation::CounterClockwise
+        );
+    }
+
+    #[test]
+    fn test_approx_direct() {
+        let (mut world, exact_vector, fixed_vector) = world();
+        let exact_points = {
+            let ctx = TraversalContext::new(&world);
+            <(BigRational, BigRational)>::direct(&ctx, exact_vector).unwrap()
+        };
+
+        // Converted on the fly from exact and from fixed-point positions
+        let ctx = TraversalContext::new(&world);
+        let from_exact = ApproxPoint::direct(&ctx, exact_vector).unwrap();
+        let from_fixed = ApproxPoint::direct(&ctx, fixed_vector).unwrap();
+        assert_eq!(from_exact, approx_line(&exact_points));
+        assert_eq!(from_fixed, from_exact);
+
+        // Read from approx_positions once computed, with the same values
+        world.compute_approx();
+        world.exact_positions.clear();
+        world.fixed_positions.clear();
+        let ctx = TraversalContext::new(&world);
+        assert_eq!(ApproxPoint::direct(&ctx, exact_vector).unwrap(), from_exact);
+        assert_eq!(ApproxPoint::direct(&ctx, fixed_vector).unwrap(), from_exact);
+    }
+
+    #[test]
+    fn test_approx_round_trip() {
+        let (world, exact_vector, _) = world();
+        let ctx = TraversalContext::new(&world);
+        let exact_points = <(BigRational, BigRational)>::direct(&ctx, exact_vector).unwrap();
+        let approx_points = ApproxPoint::direct(&ctx, exact_vector).unwrap();
+
+        for (approx, exact) in approx_points.iter().zip(&exact_points) {
+            // f64 keeps every COMF 10^7 coordinate to well under one unit...
+            assert!(approx.to_exact().within(exact, 1_000_000_000));
+            // ...and its exact value converts back to the same f64
+            assert_eq!(approx_line(&[approx.to_exact()]), [*approx]);
+        }
+        // Neighbouring units stay distinct
+        assert_ne!(approx_points[0], approx_points[1]);
+        assert!(approx_points[0].within(&approx_points[1], 1_000_000));
+        assert!(!approx_points[0].within(&approx_points[1], 10_000_000));
+    }
+
+    #[test]
+    fn test_approx_orientation() {
+        let ring = [(0.0, 0.0), (0.0, 1.0), (1.0, 1.0), (0.0, 0.0)];
+        assert_eq!(
+            ApproxPoint::orientation(&ring),
+            RingOrientation::CounterClockwise
+        );
+        let reversed: Vec<_> = ring.iter().rev().copied().collect();
+        assert_eq!(
+            ApproxPoint::orientation(&reversed),
+            RingOrientation::Clockwise
+        );
+        assert_eq!(
+            ApproxPoint::orientation(&ring[..2]),
+            RingOrientation::Degenerate
+        );
+
+        // Same winding as the exact predicate
+        let exact_ring: Vec<_> = ring.iter().map(|p| p.to_exact()).collect();
+        assert_eq!(
+            <(BigRational, BigRational)>::orientation(&exact_ring),
+            RingOrientation::CounterClockwise
+        );
+    }
+}
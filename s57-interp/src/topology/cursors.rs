@@ -2,11 +2,45 @@
 //!
 //! Provides high-level iterators over edges, rings, and feature boundaries.
 
+use super::coordinate::{approx_line, ApproxPoint, Coordinate};
+use super::masking::EdgeMask;
 use super::walker::EdgeWalker;
 use super::{errors::TopologyResult, TraversalContext};
+use crate::ecs::SpatialRef;
 use num_rational::BigRational;
 use s57_parse::bitstring::FoidKey;
 
+/// Closed ring of (lat, lon) coordinates, exact unless another point type
+/// is given
+pub type Ring<P = (BigRational, BigRational)> = Vec<P>;
+
+/// Closed ring of f64 (lat, lon) coordinates
+pub type ApproxRing = Ring<ApproxPoint>;
+
+/// AreaBoundary: An area feature's rings, by usage
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AreaBoundary<P = (BigRational, BigRational)> {
+    /// Exterior rings (USAG 1, or 3 where truncated at the data limit)
+    pub exterior: Vec<Ring<P>>,
+    /// Interior rings (USAG 2): holes in the area
+    pub interior: Vec<Ring<P>>,
+}
+
+/// ApproxBoundary: An area feature's rings in f64
+pub type ApproxBoundary = AreaBoundary<ApproxPoint>;
+
+impl AreaBoundary {
+    /// The same rings in f64
+    pub fn to_approx(&self) -> ApproxBoundary {
+        let approx = |rings: &[Ring]| rings.iter().map(|ring| approx_line(ring)).collect();
+        ApproxBoundary {
+            exterior: approx(&self.exterior),
+            interior: approx(&self.interior),
+        }
+    }
+}
+
 /// Cursor for iterating over feature boundaries
 ///
 /// Resolves FSPT pointers to build complete boundary rings for area features
@@ -23,172 +57,506 @@ impl<'a> FeatureBoundaryCursor<'a> {
 
     /// Resolve all boundary rings for this feature
     ///
-    /// Returns vector of rings, where first is outer boundary and rest are holes.
-    /// Each ring is a closed polyline (first point == last point).
+    /// Returns exterior rings first, then interior rings (holes). Each ring
+    /// is a closed polyline (first point == last point). See
+    /// `resolve_boundary` to tell exterior and interior rings apart.
+    pub fn resolve_rings(&self) -> TopologyResult<Vec<Ring>> {
+        let boundary = self.resolve_boundary()?;
+        let mut rings = boundary.exterior;
+        rings.extend(boundary.interior);
+        Ok(rings)
+    }
+
+    /// Resolve the feature's boundary into exterior and interior rings
     ///
     /// # Algorithm
     ///
     /// 1. Look up feature data by FOID
-    /// 2. Get FSPT spatial pointers
-    /// 3. Group pointers by USAG (1=exterior, 2=interior/hole)
-    /// 4. For each group, resolve edges via EdgeWalker
-    /// 5. Stitch edges into rings
-    /// 6. Close rings if not already closed
-    pub fn resolve_rings(&self) -> TopologyResult<Vec<Vec<(BigRational, BigRational)>>> {
-        // Look up feature entity by FOID
-        let entity = self
-            .ctx
-            .world
-            .foid_index
-            .get(&self.foid)
-            .copied()
-            .ok_or_else(|| super::errors::TopologyError::NoGeometry {
-                vector: s57_parse::bitstring::NameKey {
-                    rcnm: 100, // Feature record
-                    rcid: self.foid.fidn,
-                },
-            })?;
+    /// 2. Get FSPT spatial pointers; a pointer to a face (full topology)
+    ///    is replaced by the face's VRPT edges, which carry their own usage
+    /// 3. Group edges by USAG (1/3=exterior, 2=interior/hole)
+    /// 4. Walk each group in order, resolving edges via EdgeWalker
+    /// 5. Start a new ring whenever the current one closes
+    /// 6. Close a trailing ring if not already closed
+    /// 7. Wind exterior rings counter-clockwise and holes clockwise
+    pub fn resolve_boundary(&self) -> TopologyResult<AreaBoundary> {
+        self.boundary()
+    }
 
-        // Get spatial references (FSPT pointers)
-        let feature_pointers = self
-            .ctx
-            .world
-            .feature_pointers
-            .get(&entity)
-            .ok_or_else(|| super::errors::TopologyError::NoGeometry {
-                vector: s57_parse::bitstring::NameKey {
-                    rcnm: 100,
-                    rcid: self.foid.fidn,
-                },
-            })?;
+    /// Resolve the feature's boundary on the fast f64 path
+    ///
+    /// As `resolve_boundary`; winding is decided in f64, so a ring of
+    /// (nearly) zero area may come back either way round.
+    pub fn resolve_boundary_approx(&self) -> TopologyResult<ApproxBoundary> {
+        self.boundary()
+    }
 
-        if feature_pointers.spatial_refs.is_empty() {
-            // No spatial references - return empty
-            return Ok(vec![]);
-        }
+    pub(crate) fn boundary<P: Coordinate>(&self) -> TopologyResult<AreaBoundary<P>> {
+        let edges = self.edges()?;
 
-        // Create edge walker for resolving vectors
-        let mut walker = EdgeWalker::new(self.ctx);
+        // USAG=3 (exterior boundary truncated at the data limit) still
+        // belongs to the exterior ring
+        let exterior_refs: Vec<_> = edges.iter().filter(|r| matches!(r.usag, 1 | 3)).collect();
+        let interior_refs: Vec<_> = edges.iter().filter(|r| r.usag == 2).collect();
 
-        // Separate exterior (USAG=1) from interior/holes (USAG=2)
-        let exterior_refs: Vec<_> = feature_pointers
-            .spatial_refs
-            .iter()
-            .filter(|r| r.usag == 1)
-            .collect();
+        let mut boundary = AreaBoundary {
+            exterior: self.resolve_rings_from_refs(&exterior_refs)?,
+            interior: self.resolve_rings_from_refs(&interior_refs)?,
+        };
+        boundary.normalize_winding();
+        Ok(boundary)
+    }
 
-        let interior_refs: Vec<_> = feature_pointers
-            .spatial_refs
-            .iter()
-            .filter(|r| r.usag == 2)
-            .collect();
+    /// The boundary as drawn: polylines with masked edges left out
+    ///
+    /// Edges are taken in FSPT order, faces expanded, with ORNT applied.
+    /// Consecutive visible edges that meet are joined into one polyline and
+    /// a masked edge ends the current one, so an unmasked ring comes back
+    /// whole. Works for line features as well as areas.
+    pub fn resolve_outline(
+        &self,
+        mask: &EdgeMask,
+    ) -> TopologyResult<Vec<Vec<(BigRational, BigRational)>>> {
+        self.outline(mask)
+    }
 
-        let mut rings = Vec::new();
+    /// The boundary as drawn, as `resolve_outline`, on the fast f64 path
+    pub fn resolve_outline_approx(&self, mask: &EdgeMask) -> TopologyResult<Vec<Vec<ApproxPoint>>> {
+        self.outline(mask)
+    }
 
-        // Resolve exterior rings (USAG=1)
-        // Note: typically there's one exterior ring, but could be multiple disconnected boundaries
-        if !exterior_refs.is_empty() {
-            // Try resolving all exterior refs as one connected ring
-            let exterior_ring = self.resolve_ring_from_refs(&mut walker, &exterior_refs)?;
-            if !exterior_ring.is_empty() {
-                rings.push(exterior_ring);
+    fn outline<P: Coordinate>(&self, mask: &EdgeMask) -> TopologyResult<Vec<Vec<P>>> {
+        let mut lines = Vec::new();
+        let mut line = Vec::new();
+        for edge in self.edges()? {
+            if mask.is_masked(&edge) {
+                if !line.is_empty() {
+                    lines.push(std::mem::take(&mut line));
+                }
+                continue;
             }
-            // TODO: handle case where exterior refs form multiple disconnected rings
-            // This would require connectivity analysis to group refs into separate rings
-        }
-
-        // Resolve interior rings (USAG=2) - holes/islands
-        // Each interior ref typically represents a separate island/hole
-        // If an island boundary spans multiple edges, they should be sequential in the FSPT list
-        if !interior_refs.is_empty() {
-            // Current approach: each interior ref is resolved as its own ring
-            // This works when each ref is either:
-            // - A single closed edge forming a complete island boundary
-            // - Part of a sequence that resolve_ring_from_refs will stitch together
-            for iref in interior_refs {
-                let interior_ring = self.resolve_ring_from_refs(&mut walker, &[iref])?;
-                if !interior_ring.is_empty() {
-                    rings.push(interior_ring);
+            let coords = self.edge_coords(&edge)?;
+            match (line.last(), coords.first()) {
+                (_, None) => {}
+                (Some(last), Some(first)) if last == first => {
+                    line.extend_from_slice(&coords[1..]);
                 }
+                (None, Some(_)) => line = coords,
+                (Some(_), Some(_)) => lines.push(std::mem::replace(&mut line, coords)),
             }
-            // TODO: For complex cases with multiple edges per island, implement connectivity
-            // analysis to group interior refs by which island they belong to
         }
+        if !line.is_empty() {
+            lines.push(line);
+        }
+        Ok(lines)
+    }
 
-        Ok(rings)
+    /// True if any of the feature's edges is masked
+    ///
+    /// False when the feature's edges can't be looked up.
+    pub fn is_masked(&self, mask: &EdgeMask) -> bool {
+        self.edges()
+            .is_ok_and(|edges| edges.iter().any(|edge| mask.is_masked(edge)))
     }
 
-    /// Resolve a single ring from a set of FSPT references
-    fn resolve_ring_from_refs(
-        &self,
-        walker: &mut EdgeWalker,
-        refs: &[&crate::ecs::SpatialRef],
-    ) -> TopologyResult<Vec<(BigRational, BigRational)>> {
-        let mut ring: Vec<(BigRational, BigRational)> = Vec::new();
+    /// The feature's FSPT references, faces expanded into their edges
+    pub(crate) fn edges(&self) -> TopologyResult<Vec<SpatialRef>> {
+        let no_geometry = || super::errors::TopologyError::NoGeometry {
+            vector: s57_parse::bitstring::NameKey {
+                rcnm: 100, // Feature record
+                rcid: self.foid.fidn,
+            },
+        };
+        let entity = self
+            .ctx
+            .world
+            .foid_index
+            .get(&self.foid)
+            .copied()
+            .ok_or_else(no_geometry)?;
+        let feature_pointers = self
+            .ctx
+            .world
+            .feature_pointers
+            .get(&entity)
+            .ok_or_else(no_geometry)?;
+        Ok(self.boundary_edges(&feature_pointers.spatial_refs))
+    }
 
+    /// FSPT references with faces expanded into their bounding edges
+    ///
+    /// In full topology (DSSI DSTR=4) area features point at face records
+    /// (RCNM=140), and the face's VRPT lists its edges with ORNT/USAG.
+    /// Other topology levels point at the edges directly.
+    fn boundary_edges(&self, refs: &[SpatialRef]) -> Vec<SpatialRef> {
+        let world = self.ctx.world;
+        let mut edges = Vec::with_capacity(refs.len());
         for sref in refs {
-            // Get the vector NAME from the entity
-            let vector_name = self
-                .ctx
-                .world
+            let is_face = world
                 .vector_meta
                 .get(&sref.entity)
-                .map(|meta| meta.name)
-                .ok_or_else(|| super::errors::TopologyError::DanglingReference {
-                    from: s57_parse::bitstring::NameKey {
-                        rcnm: 100,
-                        rcid: self.foid.fidn,
-                    },
-                    to: s57_parse::bitstring::NameKey { rcnm: 0, rcid: 0 },
-                })?;
-
-            // Resolve the edge geometry
-            let mut edge_coords = walker.resolve_line_2d(vector_name)?;
-
-            // Apply orientation
-            let ornt = super::types::Orientation::from_ornt(sref.ornt);
-            if ornt.should_reverse() {
-                edge_coords.reverse();
+                .is_some_and(|meta| meta.name.rcnm == 140);
+            match world.vector_topology.get(&sref.entity) {
+                Some(topology) if is_face => {
+                    edges.extend(topology.neighbors.iter().map(|n| SpatialRef {
+                        entity: n.entity,
+                        ornt: n.ornt,
+                        usag: n.usag,
+                        mask: n.mask,
+                    }))
+                }
+                _ => edges.push(*sref),
             }
+        }
+        edges
+    }
 
+    /// Resolve rings from a sequence of edge references
+    ///
+    /// Edges are stitched in order; each time the ring being built closes,
+    /// it is emitted and the next edge starts a new ring. This handles both
+    /// an island made of several edges and several islands in one list.
+    fn resolve_rings_from_refs<P: Coordinate>(
+        &self,
+        refs: &[&SpatialRef],
+    ) -> TopologyResult<Vec<Ring<P>>> {
+        let mut rings = Vec::new();
+        let mut ring: Ring<P> = Vec::new();
+
+        for sref in refs {
+            let edge_coords = self.edge_coords(sref)?;
             if edge_coords.is_empty() {
                 continue; // Skip empty edges
             }
 
             // Append to ring (avoiding duplicate endpoints)
-            if !ring.is_empty() && !edge_coords.is_empty() {
-                let last_pt = ring.last().unwrap();
-                let first_pt = &edge_coords[0];
-
-                // If endpoints match, skip the duplicate
-                if last_pt.0 == first_pt.0 && last_pt.1 == first_pt.1 {
+            match ring.last() {
+                Some(last_pt) if *last_pt == edge_coords[0] => {
                     ring.extend_from_slice(&edge_coords[1..]);
-                } else {
-                    // Endpoints don't match - just append
-                    // (continuity policy in walker already handled this)
-                    ring.extend(edge_coords);
                 }
-            } else {
-                ring.extend(edge_coords);
+                // Endpoints don't match - just append
+                // (continuity policy in walker already handled this)
+                _ => ring.extend(edge_coords),
+            }
+
+            if ring.len() > 2 && Self::is_closed(&ring) {
+                rings.push(std::mem::take(&mut ring));
             }
         }
 
-        // Close the ring if not already closed
-        if !ring.is_empty() && !Self::is_closed(&ring) {
+        // Close a trailing ring if its edges didn't meet up
+        if !ring.is_empty() {
             let first = ring[0].clone();
             ring.push(first);
+            rings.push(ring);
         }
 
-        Ok(ring)
+        Ok(rings)
+    }
+
+    /// An edge's coordinates in the direction its pointer gives
+    fn edge_coords<P: Coordinate>(&self, sref: &SpatialRef) -> TopologyResult<Vec<P>> {
+        // Get the vector NAME from the entity
+        let vector_name = self
+            .ctx
+            .world
+            .vector_meta
+            .get(&sref.entity)
+            .map(|meta| meta.name)
+            .ok_or(super::errors::TopologyError::DanglingReference {
+                from: s57_parse::bitstring::NameKey {
+                    rcnm: 100,
+                    rcid: self.foid.fidn,
+                },
+                to: s57_parse::bitstring::NameKey { rcnm: 0, rcid: 0 },
+            })?;
+
+        // Resolve the edge geometry; a fresh walker per edge, since
+        // neighbouring edges legitimately share their nodes
+        let mut edge_coords = EdgeWalker::new(self.ctx).resolve_line(vector_name)?;
+
+        // Apply orientation
+        let ornt = super::types::Orientation::from_ornt(sref.ornt);
+        if ornt.should_reverse() {
+            edge_coords.reverse();
+        }
+        Ok(edge_coords)
     }
 
     /// Check if a ring is closed (first == last point)
-    fn is_closed(ring: &[(BigRational, BigRational)]) -> bool {
+    fn is_closed<P: Coordinate>(ring: &[P]) -> bool {
         if ring.len() < 2 {
             return false;
         }
-        let first = &ring[0];
-        let last = &ring[ring.len() - 1];
-        first.0 == last.0 && first.1 == last.1
+        ring[0] == ring[ring.len() - 1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::{
+        EntityId, EntityType, ExactPositions, FeatureMeta, FeaturePointers, VectorMeta,
+        VectorNeighbor, VectorTopology, World,
+    };
+    use num_bigint::BigInt;
+    use s57_parse::bitstring::NameKey;
+
+    fn vector(world: &mut World, rcnm: u8, rcid: u32, points: &[(i64, i64)]) -> EntityId {
+        let entity = world.create_entity(EntityType::Vector);
+        let name = NameKey { rcnm, rcid };
+        world.name_index.insert(name, entity);
+        world.vector_meta.insert(
+            entity,
+            VectorMeta {
+                name,
+                rver: 1,
+                ruin: 1,
+            },
+        );
+        if !points.is_empty() {
+            world.exact_positions.insert(
+                entity,
+                ExactPositions {
+                    lat: points.iter().map(|p| BigInt::from(p.0).into()).collect(),
+                    lon: points.iter().map(|p| BigInt::from(p.1).into()).collect(),
+                },
+            );
+        }
+        entity
+    }
+
+    fn neighbor(entity: EntityId, ornt: u8, usag: u8, topi: u8) -> VectorNeighbor {
+        VectorNeighbor {
+            entity,
+            ornt,
+            usag,
+            topi,
+            mask: 255,
+        }
+    }
+
+    fn edge(world: &mut World, rcid: u32, points: &[(i64, i64)], begin: EntityId, end: EntityId) {
+        let edge = vector(world, 130, rcid, points);
+        world.vector_topology.insert(
+            edge,
+            VectorTopology {
+                neighbors: vec![neighbor(begin, 255, 255, 1), neighbor(end, 255, 255, 2)],
+            },
+        );
+    }
+
+    #[test]
+    fn test_face_with_island() {
+        let mut world = World::new();
+        // Square 0..4 with its corners as connected nodes; SG2D holds only
+        // the intermediate corners
+        let a = vector(&mut world, 120, 1, &[(0, 0)]);
+        let c = vector(&mut world, 120, 2, &[(4, 4)]);
+        let n = vector(&mut world, 120, 3, &[(1, 1)]);
+        edge(&mut world, 1, &[(0, 4)], a, c);
+        edge(&mut world, 2, &[(4, 0)], c, a);
+        // Island as a single closed edge from and to node n
+        edge(&mut world, 3, &[(1, 2), (2, 2)], n, n);
+
+        let face = vector(&mut world, 140, 1, &[]);
+        let edges: Vec<_> = (1..=3)
+            .map(|rcid| world.name_index[&NameKey { rcnm: 130, rcid }])
+            .collect();
+        world.vector_topology.insert(
+            face,
+            VectorTopology {
+                neighbors: vec![
+                    neighbor(edges[0], 1, 1, 255),
+                    neighbor(edges[1], 1, 1, 255),
+                    neighbor(edges[2], 1, 2, 255),
+                ],
+            },
+        );
+
+        let feature = world.create_entity(EntityType::Feature);
+        let foid = FoidKey {
+            agen: 550,
+            fidn: 1,
+            fids: 1,
+        };
+        world.foid_index.insert(foid, feature);
+        world.feature_meta.insert(
+            feature,
+            FeatureMeta {
+                foid,
+                prim: 3,
+                grup: 1,
+                objl: 71,
+                rver: 1,
+                ruin: 1,
+            },
+        );
+        world.feature_pointers.insert(
+            feature,
+            FeaturePointers {
+                related_features: vec![],
+                spatial_refs: vec![SpatialRef {
+                    entity: face,
+                    ornt: 255,
+                    usag: 255,
+                    mask: 255,
+                }],
+            },
+        );
+
+        let ctx = TraversalContext::new(&world);
+        let boundary = FeatureBoundaryCursor::new(&ctx, foid)
+            .resolve_boundary()
+            .unwrap();
+        let int = |v: i64| BigRational::from(BigInt::from(v));
+        let ring = |points: &[(i64, i64)]| -> Ring {
+            points.iter().map(|p| (int(p.0), int(p.1))).collect()
+        };
+        assert_eq!(
+            boundary.exterior,
+            vec![ring(&[(0, 0), (0, 4), (4, 4), (4, 0), (0, 0)])]
+        );
+        // The island edge runs counter-clockwise; holes come back clockwise
+        assert_eq!(
+            boundary.interior,
+            vec![ring(&[(1, 1), (2, 2), (1, 2), (1, 1)])]
+        );
+
+        // The f64 path gives the same rings, and needs nothing exact once
+        // the World has f64 copies
+        let expected = boundary.to_approx();
+        let resolved = FeatureBoundaryCursor::new(&ctx, foid).resolve_boundary_approx();
+        assert_eq!(resolved.unwrap(), expected);
+
+        world.compute_approx();
+        world.exact_positions.clear();
+        let ctx = TraversalContext::new(&world);
+        let resolved = FeatureBoundaryCursor::new(&ctx, foid).resolve_boundary_approx();
+        assert_eq!(resolved.unwrap(), expected);
+        assert!(FeatureBoundaryCursor::new(&ctx, foid)
+            .resolve_boundary()
+            .is_err());
+    }
+
+    fn feature(world: &mut World, fidn: u32, objl: u16, refs: &[(EntityId, u8, u8)]) -> FoidKey {
+        let entity = world.create_entity(EntityType::Feature);
+        let foid = FoidKey {
+            agen: 550,
+            fidn,
+            fids: 1,
+        };
+        world.foid_index.insert(foid, entity);
+        world.feature_meta.insert(
+            entity,
+            FeatureMeta {
+                foid,
+                prim: 3,
+                grup: 1,
+                objl,
+                rver: 1,
+                ruin: 1,
+            },
+        );
+        world.feature_pointers.insert(
+            entity,
+            FeaturePointers {
+                related_features: vec![],
+                spatial_refs: refs
+                    .iter()
+                    .map(|&(entity, usag, mask)| SpatialRef {
+                        entity,
+                        ornt: 1,
+                        usag,
+                        mask,
+                    })
+                    .collect(),
+            },
+        );
+        foid
+    }
+
+    #[test]
+    fn test_outline_masking() {
+        let mut world = World::new();
+        // Square 0..4 of three edges; the third runs along the data limit
+        let a = vector(&mut world, 120, 1, &[(0, 0)]);
+        let b = vector(&mut world, 120, 2, &[(0, 4)]);
+        let c = vector(&mut world, 120, 3, &[(4, 4)]);
+        edge(&mut world, 1, &[(0, 2)], a, b);
+        edge(&mut world, 2, &[(2, 4)], b, c);
+        edge(&mut world, 3, &[(4, 0)], c, a);
+        let e: Vec<_> = (1..=3)
+            .map(|rcid| world.name_index[&NameKey { rcnm: 130, rcid }])
+            .collect();
+        let masked = feature(
+            &mut world,
+            1,
+            42,
+            &[(e[0], 1, 255), (e[1], 1, 255), (e[2], 1, 1)],
+        );
+        let truncated = feature(
+            &mut world,
+            2,
+            42,
+            &[(e[0], 1, 255), (e[1], 1, 255), (e[2], 3, 255)],
+        );
+        let shown = feature(
+            &mut world,
+            3,
+            42,
+            &[(e[0], 1, 255), (e[1], 1, 255), (e[2], 3, 2)],
+        );
+        let plain = feature(
+            &mut world,
+            4,
+            42,
+            &[(e[0], 1, 255), (e[1], 1, 255), (e[2], 1, 255)],
+        );
+
+        let int = |v: i64| BigRational::from(BigInt::from(v));
+        let line = |points: &[(i64, i64)]| -> Vec<_> {
+            points.iter().map(|p| (int(p.0), int(p.1))).collect()
+        };
+        let open = vec![line(&[(0, 0), (0, 2), (0, 4), (2, 4), (4, 4)])];
+        let closed = vec![line(&[
+            (0, 0),
+            (0, 2),
+            (0, 4),
+            (2, 4),
+            (4, 4),
+            (4, 0),
+            (0, 0),
+        ])];
+
+        let ctx = TraversalContext::new(&world);
+        let mask = EdgeMask::pointers_only();
+        let outline = |foid, mask: &EdgeMask| {
+            FeatureBoundaryCursor::new(&ctx, foid)
+                .resolve_outline(mask)
+                .unwrap()
+        };
+        assert_eq!(outline(masked, &mask), open);
+        assert_eq!(outline(truncated, &mask), open);
+        assert_eq!(outline(shown, &mask), closed);
+        assert_eq!(outline(plain, &mask), closed);
+        assert!(!FeatureBoundaryCursor::new(&ctx, plain).is_masked(&mask));
+
+        // Masking leaves the area's rings whole
+        let boundary = FeatureBoundaryCursor::new(&ctx, masked)
+            .resolve_boundary()
+            .unwrap();
+        assert_eq!(boundary.exterior.len(), 1);
+
+        // An edge shared with M_COVR is masked without a MASK of its own
+        feature(&mut world, 5, 302, &[(e[2], 1, 255)]);
+        let ctx = TraversalContext::new(&world);
+        let mask = EdgeMask::new(&ctx);
+        assert_eq!(mask.coverage_edges(), 1);
+        let outline = FeatureBoundaryCursor::new(&ctx, plain)
+            .resolve_outline(&mask)
+            .unwrap();
+        assert_eq!(outline, open);
+        assert!(FeatureBoundaryCursor::new(&ctx, plain).is_masked(&mask));
     }
 }
@@ -28,10 +28,10 @@ pub enum TopologyError {
     ContinuityBreak {
         /// Index in the chain where break occurs
         at_index: usize,
-        /// Last point of previous edge
-        lhs_end: (BigRational, BigRational),
+        /// Last point of previous edge (boxed to keep the error type small)
+        lhs_end: Box<(BigRational, BigRational)>,
         /// First point of next edge
-        rhs_start: (BigRational, BigRational),
+        rhs_start: Box<(BigRational, BigRational)>,
         /// Child vector causing the break
         child: NameKey,
     },
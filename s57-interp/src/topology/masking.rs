@@ -0,0 +1,120 @@
+//! Edge masking at cell boundaries
+//!
+//! Where a cell's data stops, coastlines and depth areas are cut off along
+//! the data limit. Those cut edges aren't part of any charted boundary, and
+//! an ECDIS doesn't draw them, so a chart doesn't show a false line along
+//! its edge. An edge is masked when its FSPT or VRPT pointer says so
+//! (MASK=1). Unless the pointer asks for the edge to be shown (MASK=2), it
+//! is also masked when it is marked as truncated by the data limit (USAG=3)
+//! or when it also bounds an M_COVR coverage area.
+//!
+//! Masking only affects what is stroked: a masked area is still filled
+//! with its whole boundary.
+
+use super::{FeatureBoundaryCursor, TraversalContext};
+use crate::ecs::{EntityId, SpatialRef};
+use std::collections::HashSet;
+
+/// Object class code of M_COVR
+const OBJL_M_COVR: u16 = 302;
+
+/// MASK value: mask the edge
+const MASK_MASK: u8 = 1;
+/// MASK value: show the edge
+const MASK_SHOW: u8 = 2;
+/// USAG value: exterior boundary truncated by the data limit
+const USAG_TRUNCATED: u8 = 3;
+
+/// EdgeMask: Decides which feature edges are left undrawn
+#[derive(Debug, Clone, Default)]
+pub struct EdgeMask {
+    /// Edges of M_COVR features
+    coverage: HashSet<EntityId>,
+}
+
+impl EdgeMask {
+    /// Mask from the pointers and the world's M_COVR boundaries
+    ///
+    /// M_COVR features whose edges can't be resolved are skipped.
+    pub fn new(ctx: &TraversalContext) -> Self {
+        let coverage = ctx
+            .world
+            .feature_meta
+            .values()
+            .filter(|meta| meta.objl == OBJL_M_COVR)
+            .filter_map(|meta| FeatureBoundaryCursor::new(ctx, meta.foid).edges().ok())
+            .flatten()
+            .map(|edge| edge.entity)
+            .collect();
+        Self { coverage }
+    }
+
+    /// Mask from the pointers alone (MASK and USAG), ignoring M_COVR
+    pub fn pointers_only() -> Self {
+        Self::default()
+    }
+
+    /// True if the edge a pointer refers to should not be drawn
+    pub fn is_masked(&self, edge: &SpatialRef) -> bool {
+        match edge.mask {
+            MASK_MASK => true,
+            MASK_SHOW => false,
+            _ => edge.usag == USAG_TRUNCATED || self.coverage.contains(&edge.entity),
+        }
+    }
+
+    /// Number of edges masked for bounding M_COVR areas
+    pub fn coverage_edges(&self) -> usize {
+        self.coverage.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::{EntityType, World};
+
+    fn pointer(entity: EntityId, usag: u8, mask: u8) -> SpatialRef {
+        SpatialRef {
+            entity,
+            ornt: 1,
+            usag,
+            mask,
+        }
+    }
+
+    #[test]
+    fn test_pointer_masking() {
+        let mut world = World::new();
+        let edge = world.create_entity(EntityType::Vector);
+        let mask = EdgeMask::pointers_only();
+
+        // Unmasked: exterior or interior boundary, no MASK given
+        assert!(!mask.is_masked(&pointer(edge, 1, 255)));
+        assert!(!mask.is_masked(&pointer(edge, 2, 255)));
+        // Masked by the pointer, whatever the usage
+        assert!(mask.is_masked(&pointer(edge, 1, MASK_MASK)));
+        // Truncated by the data limit, unless the pointer shows it
+        assert!(mask.is_masked(&pointer(edge, USAG_TRUNCATED, 255)));
+        assert!(!mask.is_masked(&pointer(edge, USAG_TRUNCATED, MASK_SHOW)));
+        assert_eq!(mask.coverage_edges(), 0);
+    }
+
+    #[test]
+    fn test_coverage_masking() {
+        let mut world = World::new();
+        let coverage_edge = world.create_entity(EntityType::Vector);
+        let other_edge = world.create_entity(EntityType::Vector);
+        let mask = EdgeMask {
+            coverage: [coverage_edge].into_iter().collect(),
+        };
+
+        // Only the edge shared with M_COVR is masked
+        assert!(mask.is_masked(&pointer(coverage_edge, 1, 255)));
+        assert!(!mask.is_masked(&pointer(other_edge, 1, 255)));
+        // MASK on the pointer still wins
+        assert!(!mask.is_masked(&pointer(coverage_edge, 1, MASK_SHOW)));
+        assert!(mask.is_masked(&pointer(other_edge, 1, MASK_MASK)));
+        assert_eq!(mask.coverage_edges(), 1);
+    }
+}
@@ -7,19 +7,27 @@
 //!
 //! - **Cursors**: Lazy iterators over coordinate streams
 //! - **Walkers**: Recursive edge resolution with cycle detection
+//! - **Coordinates**: Exact BigRational points, or f64 for the fast path
 //! - **Policies**: Configurable error handling and continuity rules
 
+pub mod coordinate;
 pub mod cursors;
 pub mod errors;
+pub mod masking;
 pub mod types;
 pub mod walker;
 
-pub use cursors::FeatureBoundaryCursor;
+pub use coordinate::{approx_line, ApproxPoint, Coordinate};
+pub use cursors::{ApproxBoundary, ApproxRing, AreaBoundary, FeatureBoundaryCursor, Ring};
 pub use errors::{ContinuityPolicy, CyclePolicy, TopologyError, TopologyResult};
+pub use masking::EdgeMask;
 pub use types::{FsptPointer, Orientation, VrptPointer};
 pub use walker::EdgeWalker;
 
 use crate::ecs::World;
+use crate::transform::CoordinateTransform;
+use num_rational::BigRational;
+use std::sync::Arc;
 
 /// Main context for topology traversal operations
 ///
@@ -31,6 +39,8 @@ pub struct TraversalContext<'a> {
     pub cycle_policy: CyclePolicy,
     /// Continuity break policy
     pub continuity_policy: ContinuityPolicy,
+    /// Applied to every position as it is resolved (None = as charted)
+    pub transform: Option<Arc<dyn CoordinateTransform>>,
 }
 
 impl<'a> TraversalContext<'a> {
@@ -40,6 +50,7 @@ impl<'a> TraversalContext<'a> {
             world,
             cycle_policy: CyclePolicy::Error,
             continuity_policy: ContinuityPolicy::Error,
+            transform: None,
         }
     }
 
@@ -54,4 +65,25 @@ impl<'a> TraversalContext<'a> {
         self.continuity_policy = policy;
         self
     }
+
+    /// Set the coordinate transform
+    pub fn with_transform(mut self, transform: impl CoordinateTransform + 'static) -> Self {
+        self.transform = Some(Arc::new(transform));
+        self
+    }
+
+    /// A position with the context's transform applied
+    ///
+    /// For positions read outside the walker, such as the nodes of point
+    /// features.
+    pub fn transform_point(
+        &self,
+        lat: &BigRational,
+        lon: &BigRational,
+    ) -> (BigRational, BigRational) {
+        match &self.transform {
+            Some(transform) => transform.transform(lat, lon),
+            None => (lat.clone(), lon.clone()),
+        }
+    }
 }
@@ -3,11 +3,12 @@
 //! Resolves vector geometry by following VRPT chains and applying
 //! orientation/continuity rules.
 
+use super::coordinate::{ApproxPoint, Coordinate};
 use super::errors::{TopologyError, TopologyResult};
 use super::types::Orientation;
 use super::TraversalContext;
+use crate::ecs::EntityId;
 use num_rational::BigRational;
-use num_traits::Signed;
 use s57_parse::bitstring::NameKey;
 use std::collections::HashMap;
 
@@ -40,19 +41,32 @@ impl<'a> EdgeWalker<'a> {
     ///
     /// Returns coordinates in (lat, lon) order with exact rational precision.
     /// Follows VRPT chain if no direct geometry available.
+    pub fn resolve_line_2d(
+        &mut self,
+        name: NameKey,
+    ) -> TopologyResult<Vec<(BigRational, BigRational)>> {
+        self.resolve_line(name)
+    }
+
+    /// Resolve a vector to a 2D polyline of f64 (lat, lon) points
+    ///
+    /// As `resolve_line_2d`, on the fast f64 path (see `crate::approx`).
+    pub fn resolve_line_approx(&mut self, name: NameKey) -> TopologyResult<Vec<ApproxPoint>> {
+        self.resolve_line(name)
+    }
+
+    /// Resolve a vector to a 2D polyline of any point type
     ///
     /// # Algorithm
     ///
     /// 1. Check depth limit
     /// 2. Check cycle policy
     /// 3. Look up vector data
-    /// 4. If has direct geometry (SG2D), return it
+    /// 4. If has direct geometry (SG2D), return it; for edges, framed by
+    ///    their begin/end nodes
     /// 5. If has VRPT pointers, recursively resolve and stitch
     /// 6. Otherwise, error (no geometry)
-    pub fn resolve_line_2d(
-        &mut self,
-        name: NameKey,
-    ) -> TopologyResult<Vec<(BigRational, BigRational)>> {
+    pub fn resolve_line<P: Coordinate>(&mut self, name: NameKey) -> TopologyResult<Vec<P>> {
         // Check depth limit
         if self.depth >= MAX_DEPTH {
             return Err(TopologyError::MaxDepthExceeded {
@@ -84,22 +98,21 @@ impl<'a> EdgeWalker<'a> {
             })?;
 
         // Try direct geometry first
-        if let Some(positions) = self.ctx.world.exact_positions.get(&entity) {
-            let (lat, lon) = positions.to_f64();
-            // Convert f64 back to BigRational (temporary until we store rationals directly)
-            use num_bigint::BigInt;
-            let coords: Vec<(BigRational, BigRational)> = lat
-                .iter()
-                .zip(lon.iter())
-                .map(|(la, lo)| {
-                    (
-                        BigRational::from_float(*la)
-                            .unwrap_or_else(|| BigRational::new(BigInt::from(0), BigInt::from(1))),
-                        BigRational::from_float(*lo)
-                            .unwrap_or_else(|| BigRational::new(BigInt::from(0), BigInt::from(1))),
-                    )
-                })
-                .collect();
+        if let Some(mut coords) = P::direct(self.ctx, entity) {
+            // An edge's SG2D holds only its intermediate points; the end
+            // points are its begin/end connected nodes (VRPT TOPI=1/2)
+            if name.rcnm == 130 {
+                if let Some(begin) = self.edge_node(entity, 1) {
+                    if coords.first() != Some(&begin) {
+                        coords.insert(0, begin);
+                    }
+                }
+                if let Some(end) = self.edge_node(entity, 2) {
+                    if coords.last() != Some(&end) {
+                        coords.push(end);
+                    }
+                }
+            }
 
             self.depth -= 1;
             self.chain.pop();
@@ -123,7 +136,7 @@ impl<'a> EdgeWalker<'a> {
         }
 
         // Recursively resolve each VRPT pointer and stitch together
-        let mut result: Vec<(BigRational, BigRational)> = Vec::new();
+        let mut result: Vec<P> = Vec::new();
 
         for (idx, neighbor) in vrpt_neighbors.iter().enumerate() {
             // Resolve entity → NAME for the referenced vector
@@ -133,7 +146,7 @@ impl<'a> EdgeWalker<'a> {
                 .vector_meta
                 .get(&neighbor.entity)
                 .map(|meta| meta.name)
-                .ok_or_else(|| TopologyError::DanglingReference {
+                .ok_or(TopologyError::DanglingReference {
                     from: name,
                     to: NameKey { rcnm: 0, rcid: 0 }, // Unknown name
                 })?;
@@ -164,7 +177,7 @@ impl<'a> EdgeWalker<'a> {
                 let first_pt = &child_coords[0];
 
                 // If endpoints match exactly, skip the duplicate
-                if last_pt.0 == first_pt.0 && last_pt.1 == first_pt.1 {
+                if last_pt == first_pt {
                     result.extend_from_slice(&child_coords[1..]);
                 } else {
                     // Not matching - continuity policy will handle this
@@ -180,13 +193,20 @@ impl<'a> EdgeWalker<'a> {
         Ok(result)
     }
 
+    /// Position of an edge's begin (TOPI=1) or end (TOPI=2) node
+    fn edge_node<P: Coordinate>(&self, edge: EntityId, topi: u8) -> Option<P> {
+        let topology = self.ctx.world.vector_topology.get(&edge)?;
+        let node = topology.neighbors.iter().find(|n| n.topi == topi)?;
+        P::direct(self.ctx, node.entity)?.into_iter().next()
+    }
+
     /// Resolve a vector with orientation applied
-    fn resolve_with_orientation(
+    fn resolve_with_orientation<P: Coordinate>(
         &mut self,
         name: NameKey,
         ornt: Orientation,
-    ) -> TopologyResult<Vec<(BigRational, BigRational)>> {
-        let mut coords = self.resolve_line_2d(name)?;
+    ) -> TopologyResult<Vec<P>> {
+        let mut coords = self.resolve_line(name)?;
 
         if ornt.should_reverse() {
             coords.reverse();
@@ -231,49 +251,36 @@ impl<'a> EdgeWalker<'a> {
     }
 
     /// Check endpoint continuity
-    fn check_continuity(
+    fn check_continuity<P: Coordinate>(
         &self,
-        lhs_end: &(BigRational, BigRational),
-        rhs_start: &(BigRational, BigRational),
+        lhs_end: &P,
+        rhs_start: &P,
         child: NameKey,
         index: usize,
     ) -> TopologyResult<()> {
         use super::errors::ContinuityPolicy;
 
         // Check if endpoints match exactly
-        if lhs_end.0 == rhs_start.0 && lhs_end.1 == rhs_start.1 {
+        if lhs_end == rhs_start {
             return Ok(()); // Perfect continuity
         }
 
+        let break_error = || TopologyError::ContinuityBreak {
+            at_index: index,
+            lhs_end: Box::new(lhs_end.to_exact()),
+            rhs_start: Box::new(rhs_start.to_exact()),
+            child,
+        };
         match self.ctx.continuity_policy {
-            ContinuityPolicy::Error => {
-                return Err(TopologyError::ContinuityBreak {
-                    at_index: index,
-                    lhs_end: lhs_end.clone(),
-                    rhs_start: rhs_start.clone(),
-                    child,
-                });
-            }
+            ContinuityPolicy::Error => Err(break_error()),
             ContinuityPolicy::SnapWithinTolerance(denom) => {
-                // Calculate distance using rational arithmetic
-                let dx = &lhs_end.0 - &rhs_start.0;
-                let dy = &lhs_end.1 - &rhs_start.1;
-
-                // Approximate distance check: |dx| + |dy| < tolerance (Manhattan distance)
-                let tolerance = BigRational::new(1.into(), denom.into());
-
-                if dx.abs() < tolerance && dy.abs() < tolerance {
+                if lhs_end.within(rhs_start, denom) {
                     // Within tolerance - snap is handled by caller skipping duplicate point
                     return Ok(());
                 }
 
                 // Outside tolerance
-                return Err(TopologyError::ContinuityBreak {
-                    at_index: index,
-                    lhs_end: lhs_end.clone(),
-                    rhs_start: rhs_start.clone(),
-                    child,
-                });
+                Err(break_error())
             }
             ContinuityPolicy::InsertGapMarker => {
                 // Allow the gap - caller will insert both points
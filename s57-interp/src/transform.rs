@@ -0,0 +1,202 @@
+//! Coordinate transforms applied while geometry is resolved
+//!
+//! A `TraversalContext` can carry a `CoordinateTransform`, which every
+//! vector position passes through as the topology walker reads it: line and
+//! area geometry, edge end nodes and, through
+//! `TraversalContext::transform_point`, point features. Anything built from
+//! that context (GeoJSON export, rendering) then sees the transformed
+//! coordinates, so a local grid conversion or datum shift needs no
+//! post-processing of the output.
+//!
+//! Positions go in and come out as exact (lat, lon) pairs. A transform to a
+//! plane grid returns (northing, easting) in the same order, as
+//! `Projection` does here.
+
+use crate::projection::Projection;
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::ToPrimitive;
+use std::fmt;
+
+/// CoordinateTransform: Maps one exact (lat, lon) position to another
+pub trait CoordinateTransform: Send + Sync {
+    /// Transform a position; the result is in (lat, lon) or (northing,
+    /// easting) order
+    fn transform(&self, lat: &BigRational, lon: &BigRational) -> (BigRational, BigRational);
+}
+
+impl fmt::Debug for dyn CoordinateTransform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CoordinateTransform")
+    }
+}
+
+impl<F> CoordinateTransform for F
+where
+    F: Fn(&BigRational, &BigRational) -> (BigRational, BigRational) + Send + Sync,
+{
+    fn transform(&self, lat: &BigRational, lon: &BigRational) -> (BigRational, BigRational) {
+        self(lat, lon)
+    }
+}
+
+/// Shift: Constant offset in degrees, e.g. a datum shift valid over one cell
+#[derive(Debug, Clone, PartialEq)]
+pub struct Shift {
+    pub lat: BigRational,
+    pub lon: BigRational,
+}
+
+impl CoordinateTransform for Shift {
+    fn transform(&self, lat: &BigRational, lon: &BigRational) -> (BigRational, BigRational) {
+        (lat + &self.lat, lon + &self.lon)
+    }
+}
+
+/// Scale: Both coordinates multiplied by one factor, e.g. degrees to minutes
+#[derive(Debug, Clone, PartialEq)]
+pub struct Scale(pub BigRational);
+
+impl CoordinateTransform for Scale {
+    fn transform(&self, lat: &BigRational, lon: &BigRational) -> (BigRational, BigRational) {
+        (lat * &self.0, lon * &self.0)
+    }
+}
+
+/// Projected (northing, easting), in the projection's units
+///
+/// The projection runs in f64, so results are exact only to f64 precision.
+impl CoordinateTransform for Projection {
+    fn transform(&self, lat: &BigRational, lon: &BigRational) -> (BigRational, BigRational) {
+        let (x, y) = self.project(lat.to_f64().unwrap_or(0.0), lon.to_f64().unwrap_or(0.0));
+        let exact = |v: f64| BigRational::from_float(v).unwrap_or_else(|| BigInt::from(0).into());
+        (exact(y), exact(x))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::{
+        EntityType, ExactPositions, FeatureMeta, FeaturePointers, SpatialRef, VectorMeta, World,
+    };
+    use crate::geojson::{to_geojson, GeoJsonOptions};
+    use crate::geometry_cache::ResolvedGeometry;
+    use crate::topology::TraversalContext;
+    use s57_parse::bitstring::{FoidKey, NameKey};
+    use serde_json::json;
+    use smallvec::smallvec;
+
+    fn int(v: i64) -> BigRational {
+        BigInt::from(v).into()
+    }
+
+    /// A line feature over an edge from (1, 3) to (2, 4)
+    fn world_with_line() -> (World, crate::ecs::EntityId) {
+        let mut world = World::new();
+        let edge = world.create_entity(EntityType::Vector);
+        let name = NameKey { rcnm: 130, rcid: 1 };
+        world.name_index.insert(name, edge);
+        world.vector_meta.insert(
+            edge,
+            VectorMeta {
+                name,
+                rver: 1,
+                ruin: 1,
+            },
+        );
+        world.exact_positions.insert(
+            edge,
+            ExactPositions {
+                lat: smallvec![int(1), int(2)],
+                lon: smallvec![int(3), int(4)],
+            },
+        );
+        let feature = world.create_entity(EntityType::Feature);
+        world.feature_meta.insert(
+            feature,
+            FeatureMeta {
+                foid: FoidKey {
+                    agen: 550,
+                    fidn: 1,
+                    fids: 1,
+                },
+                prim: 2,
+                grup: 2,
+                objl: 30,
+                rver: 1,
+                ruin: 1,
+            },
+        );
+        world.feature_pointers.insert(
+            feature,
+            FeaturePointers {
+                related_features: vec![],
+                spatial_refs: vec![SpatialRef {
+                    entity: edge,
+                    ornt: 1,
+                    usag: 255,
+                    mask: 255,
+                }],
+            },
+        );
+        (world, feature)
+    }
+
+    fn line(
+        ctx: &TraversalContext,
+        feature: crate::ecs::EntityId,
+    ) -> Vec<(BigRational, BigRational)> {
+        let geometry = ctx.feature_geometry(feature).unwrap().unwrap();
+        let ResolvedGeometry::Lines(lines) = geometry.as_ref() else {
+            panic!("expected lines");
+        };
+        lines[0].clone()
+    }
+
+    #[test]
+    fn test_transform_during_resolution() {
+        let (mut world, feature) = world_with_line();
+        world.enable_geometry_cache();
+
+        // Fill the cache untransformed first: the shifted context must not
+        // be served from it
+        let plain = TraversalContext::new(&world);
+        assert_eq!(line(&plain, feature)[0], (int(1), int(3)));
+
+        let shifted = TraversalContext::new(&world).with_transform(Shift {
+            lat: int(10),
+            lon: int(-1),
+        });
+        assert_eq!(
+            line(&shifted, feature),
+            vec![(int(11), int(2)), (int(12), int(3))]
+        );
+        assert_eq!(line(&plain, feature)[0], (int(1), int(3)));
+
+        let scaled = TraversalContext::new(&world).with_transform(Scale(int(60)));
+        assert_eq!(line(&scaled, feature)[1], (int(120), int(240)));
+
+        // Any closure will do, e.g. swapping axes
+        let swapped = TraversalContext::new(&world)
+            .with_transform(|lat: &BigRational, lon: &BigRational| (lon.clone(), lat.clone()));
+        assert_eq!(swapped.transform_point(&int(1), &int(3)), (int(3), int(1)));
+
+        // Projections give (northing, easting)
+        let projected = Projection::PlateCarree.transform(&int(1), &int(3));
+        assert_eq!(projected, (int(1), int(3)));
+        let (northing, easting) = Projection::WebMercator.transform(&int(0), &int(1));
+        assert!(northing.to_f64().unwrap().abs() < 1e-6);
+        assert!(easting > int(111_000) && easting < int(112_000));
+
+        let options = GeoJsonOptions::new().transform(Shift {
+            lat: int(10),
+            lon: int(-1),
+        });
+        let out = to_geojson(&world, &options);
+        assert_eq!(
+            out["features"][0]["geometry"]["coordinates"],
+            json!([[2.0, 11.0], [3.0, 12.0]])
+        );
+    }
+}
@@ -0,0 +1,577 @@
+//! Validation against a subset of the IHO S-58 ENC checks
+//!
+//! S-58 lists the checks an ENC must pass before it is issued. `validate`
+//! runs those that need nothing but the cell's own records:
+//! - mandatory attributes of common object classes
+//! - geometric primitive (PRIM) allowed for the object class
+//! - FSPT and VRPT pointers to vectors that aren't in the cell
+//! - FOIDs shared by more than one feature record
+//! - vectors no feature and no other vector refers to
+//! - edges whose VRPT begin or end node isn't in the cell
+//!
+//! The checks read the records rather than a World: building one drops
+//! exactly what they look for (a duplicate FOID replaces the first feature,
+//! a dangling pointer resolves to nothing). Each finding carries the number
+//! of the record it concerns, counted as in `S57File::records` (the DDR is
+//! record 0).
+//!
+//! The class rules cover the skin-of-earth, meta and aids-to-navigation
+//! classes an ENC must get right; classes without a rule are not checked
+//! for attributes or primitives.
+
+use crate::systems::{get_string, get_u16, get_u8};
+use crate::{foid_key, frid_objl, load_ddr, pointer_names, vrid_name};
+use s57_catalogue::{AttributeInfo, ObjectClass};
+use s57_parse::bitstring::{FoidKey, NameKey};
+use s57_parse::ddr::{ParsedField, DDR};
+use s57_parse::iso8211::Record;
+use s57_parse::{Result, S57File};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+/// PRIM values
+const PRIM_POINT: u8 = 1;
+const PRIM_LINE: u8 = 2;
+const PRIM_AREA: u8 = 3;
+const PRIM_NONE: u8 = 255;
+
+/// RCNM values of the vector records
+const RCNM_ISOLATED_NODE: u8 = 110;
+const RCNM_CONNECTED_NODE: u8 = 120;
+const RCNM_EDGE: u8 = 130;
+const RCNM_FACE: u8 = 140;
+
+/// ClassRule: Primitives and mandatory attributes of one object class
+struct ClassRule {
+    objl: u16,
+    primitives: &'static [u8],
+    mandatory: &'static [u16],
+}
+
+/// Rules from the ENC Product Specification's object and attribute tables
+const CLASS_RULES: &[ClassRule] = &[
+    // BCNCAR: BCNSHP, CATCAM
+    rule(5, &[PRIM_POINT], &[2, 13]),
+    // BCNISD: BCNSHP
+    rule(6, &[PRIM_POINT], &[2]),
+    // BCNLAT: BCNSHP, CATLAM
+    rule(7, &[PRIM_POINT], &[2, 36]),
+    // BCNSAW: BCNSHP
+    rule(8, &[PRIM_POINT], &[2]),
+    // BCNSPP: BCNSHP
+    rule(9, &[PRIM_POINT], &[2]),
+    // BOYCAR: BOYSHP, CATCAM
+    rule(14, &[PRIM_POINT], &[4, 13]),
+    // BOYISD: BOYSHP
+    rule(16, &[PRIM_POINT], &[4]),
+    // BOYLAT: BOYSHP, CATLAM
+    rule(17, &[PRIM_POINT], &[4, 36]),
+    // BOYSAW: BOYSHP
+    rule(18, &[PRIM_POINT], &[4]),
+    // BOYSPP: BOYSHP
+    rule(19, &[PRIM_POINT], &[4]),
+    // COALNE
+    rule(30, &[PRIM_LINE], &[]),
+    // DEPARE: DRVAL1, DRVAL2
+    rule(42, &[PRIM_LINE, PRIM_AREA], &[87, 88]),
+    // DEPCNT: VALDCO
+    rule(43, &[PRIM_LINE], &[174]),
+    // DRGARE: DRVAL1
+    rule(46, &[PRIM_AREA], &[87]),
+    // LNDARE
+    rule(71, &[PRIM_POINT, PRIM_LINE, PRIM_AREA], &[]),
+    // LIGHTS: COLOUR
+    rule(75, &[PRIM_POINT], &[75]),
+    // NAVLNE: ORIENT
+    rule(85, &[PRIM_LINE], &[117]),
+    // OBSTRN
+    rule(86, &[PRIM_POINT, PRIM_LINE, PRIM_AREA], &[]),
+    // RECTRC: ORIENT
+    rule(109, &[PRIM_LINE, PRIM_AREA], &[117]),
+    // SOUNDG
+    rule(129, &[PRIM_POINT], &[]),
+    // UWTROC: VALSOU, WATLEV
+    rule(153, &[PRIM_POINT], &[179, 187]),
+    // WRECKS
+    rule(159, &[PRIM_POINT, PRIM_AREA], &[]),
+    // M_CSCL: CSCALE
+    rule(301, &[PRIM_AREA], &[80]),
+    // M_COVR: CATCOV
+    rule(302, &[PRIM_AREA], &[18]),
+    // M_QUAL: CATZOC
+    rule(308, &[PRIM_AREA], &[72]),
+    // M_SDAT: VERDAT
+    rule(309, &[PRIM_AREA], &[185]),
+    // M_VDAT: VERDAT
+    rule(312, &[PRIM_AREA], &[185]),
+    // C_AGGR, C_ASSO, C_STAC
+    rule(400, &[PRIM_NONE], &[]),
+    rule(401, &[PRIM_NONE], &[]),
+    rule(402, &[PRIM_NONE], &[]),
+];
+
+const fn rule(objl: u16, primitives: &'static [u8], mandatory: &'static [u16]) -> ClassRule {
+    ClassRule {
+        objl,
+        primitives,
+        mandatory,
+    }
+}
+
+/// Severity: How serious a finding is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// The cell breaks the standard
+    Error,
+    /// The cell is valid but probably not what its producer meant
+    Warning,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        })
+    }
+}
+
+/// Check: Which check produced a finding
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Check {
+    /// A mandatory attribute is missing or empty
+    MandatoryAttribute,
+    /// PRIM is not allowed for the object class
+    Primitive,
+    /// FSPT or VRPT points to a vector not in the cell
+    DanglingPointer,
+    /// Another feature record has the same FOID
+    DuplicateFoid,
+    /// No feature or vector refers to the vector
+    UnreferencedVector,
+    /// An edge's begin or end node is missing
+    MissingEndNode,
+}
+
+impl Check {
+    /// Stable identifier for machine-readable output
+    pub fn code(&self) -> &'static str {
+        match self {
+            Check::MandatoryAttribute => "mandatory-attribute",
+            Check::Primitive => "primitive",
+            Check::DanglingPointer => "dangling-pointer",
+            Check::DuplicateFoid => "duplicate-foid",
+            Check::UnreferencedVector => "unreferenced-vector",
+            Check::MissingEndNode => "missing-end-node",
+        }
+    }
+
+    /// Severity of the check's findings
+    pub fn severity(&self) -> Severity {
+        match self {
+            Check::UnreferencedVector => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+}
+
+/// Finding: One failed check
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    pub check: Check,
+    /// Record the finding is about
+    pub record: usize,
+    pub message: String,
+}
+
+impl Finding {
+    /// Severity of the check that failed
+    pub fn severity(&self) -> Severity {
+        self.check.severity()
+    }
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "record {}: {} [{}]: {}",
+            self.record,
+            self.severity(),
+            self.check.code(),
+            self.message
+        )
+    }
+}
+
+/// A feature record as the checks need it
+struct FeatureRecord {
+    record: usize,
+    foid: Option<FoidKey>,
+    objl: u16,
+    prim: u8,
+    attributes: HashMap<u16, String>,
+    spatial: Vec<NameKey>,
+}
+
+/// A vector record as the checks need it
+struct VectorRecord {
+    record: usize,
+    name: NameKey,
+    pointers: Vec<NameKey>,
+}
+
+/// Run every check over a cell's records
+///
+/// Findings are ordered by record, then by check. Fails only if the file
+/// has no records.
+pub fn validate(file: &S57File) -> Result<Vec<Finding>> {
+    let records = file.records();
+    let (ddr, first_data) = load_ddr(records)?;
+
+    let mut features = Vec::new();
+    let mut vectors = Vec::new();
+    for (index, record) in records.iter().enumerate().skip(first_data) {
+        let field = |tag| parsed(&ddr, record, tag);
+        if let Some(name) = field("VRID").and_then(|vrid| vrid_name(&vrid)) {
+            vectors.push(VectorRecord {
+                record: index,
+                name,
+                pointers: field("VRPT").map_or_else(Vec::new, |vrpt| pointer_names(&vrpt)),
+            });
+        } else if let Some(frid) = field("FRID") {
+            let Some(group) = frid.groups().first() else {
+                continue;
+            };
+            let attributes = ["ATTF", "NATF"]
+                .into_iter()
+                .filter_map(field)
+                .flat_map(|attrs| {
+                    attrs
+                        .groups()
+                        .iter()
+                        .filter_map(|group| {
+                            let attl = get_u16(group, "ATTL").ok()??;
+                            Some((attl, get_string(group, "ATVL").unwrap_or_default()))
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect();
+            features.push(FeatureRecord {
+                record: index,
+                foid: field("FOID").and_then(|foid| foid_key(&foid)),
+                objl: frid_objl(&frid).unwrap_or(0),
+                prim: get_u8(group, "PRIM").ok().flatten().unwrap_or(PRIM_NONE),
+                attributes,
+                spatial: field("FSPT").map_or_else(Vec::new, |fspt| pointer_names(&fspt)),
+            });
+        }
+    }
+
+    let mut findings = Vec::new();
+    check_classes(&features, &mut findings);
+    check_foids(&features, &mut findings);
+    check_pointers(&features, &vectors, &mut findings);
+    findings.sort_by_key(|finding| (finding.record, finding.check));
+    Ok(findings)
+}
+
+/// Parse the first field with a tag
+fn parsed<'a>(ddr: &'a DDR, record: &'a Record, tag: &str) -> Option<ParsedField<'a>> {
+    let field = record.fields.iter().find(|f| f.tag == tag)?;
+    ddr.parse_field_data(field).ok()
+}
+
+/// Mandatory attributes and primitives
+fn check_classes(features: &[FeatureRecord], findings: &mut Vec<Finding>) {
+    let rules: HashMap<u16, &ClassRule> = CLASS_RULES.iter().map(|r| (r.objl, r)).collect();
+    for feature in features {
+        let Some(rule) = rules.get(&feature.objl) else {
+            continue;
+        };
+        let class = class_name(feature.objl);
+        if !rule.primitives.contains(&feature.prim) {
+            let allowed: Vec<&str> = rule.primitives.iter().map(|&p| primitive(p)).collect();
+            findings.push(Finding {
+                check: Check::Primitive,
+                record: feature.record,
+                message: format!(
+                    "{} has PRIM {} ({}); allowed: {}",
+                    class,
+                    feature.prim,
+                    primitive(feature.prim),
+                    allowed.join(", ")
+                ),
+            });
+        }
+        for &attl in rule.mandatory {
+            let present = feature
+                .attributes
+                .get(&attl)
+                .is_some_and(|value| !value.trim().is_empty());
+            if !present {
+                let acronym = AttributeInfo::from_code(attl).map_or("?", |info| info.acronym);
+                findings.push(Finding {
+                    check: Check::MandatoryAttribute,
+                    record: feature.record,
+                    message: format!("{} has no value for mandatory {}", class, acronym),
+                });
+            }
+        }
+    }
+}
+
+/// FOIDs used by more than one feature record
+fn check_foids(features: &[FeatureRecord], findings: &mut Vec<Finding>) {
+    let mut first: HashMap<FoidKey, usize> = HashMap::new();
+    for feature in features {
+        let Some(foid) = feature.foid else {
+            continue;
+        };
+        match first.get(&foid) {
+            Some(&record) => findings.push(Finding {
+                check: Check::DuplicateFoid,
+                record: feature.record,
+                message: format!(
+                    "FOID {}/{}/{} is already used by record {}",
+                    foid.agen, foid.fidn, foid.fids, record
+                ),
+            }),
+            None => {
+                first.insert(foid, feature.record);
+            }
+        }
+    }
+}
+
+/// Dangling FSPT/VRPT pointers, missing edge nodes and unreferenced vectors
+fn check_pointers(
+    features: &[FeatureRecord],
+    vectors: &[VectorRecord],
+    findings: &mut Vec<Finding>,
+) {
+    let names: HashSet<NameKey> = vectors.iter().map(|v| v.name).collect();
+    let mut referenced = HashSet::new();
+
+    for feature in features {
+        for name in &feature.spatial {
+            referenced.insert(*name);
+            if !names.contains(name) {
+                findings.push(Finding {
+                    check: Check::DanglingPointer,
+                    record: feature.record,
+                    message: format!(
+                        "FSPT points to missing {} {}",
+                        vector_kind(name.rcnm),
+                        name_text(name)
+                    ),
+                });
+            }
+        }
+    }
+
+    for vector in vectors {
+        referenced.extend(vector.pointers.iter().copied());
+        let is_edge = vector.name.rcnm == RCNM_EDGE;
+        for name in vector.pointers.iter().filter(|name| !names.contains(name)) {
+            let check = if is_edge {
+                Check::MissingEndNode
+            } else {
+                Check::DanglingPointer
+            };
+            findings.push(Finding {
+                check,
+                record: vector.record,
+                message: format!(
+                    "VRPT of {} {} points to missing {} {}",
+                    vector_kind(vector.name.rcnm),
+                    name_text(&vector.name),
+                    vector_kind(name.rcnm),
+                    name_text(name)
+                ),
+            });
+        }
+        let nodes = vector
+            .pointers
+            .iter()
+            .filter(|name| matches!(name.rcnm, RCNM_ISOLATED_NODE | RCNM_CONNECTED_NODE))
+            .count();
+        if is_edge && nodes < 2 {
+            findings.push(Finding {
+                check: Check::MissingEndNode,
+                record: vector.record,
+                message: format!(
+                    "edge {} has {} of its 2 end nodes",
+                    name_text(&vector.name),
+                    nodes
+                ),
+            });
+        }
+    }
+
+    for vector in vectors {
+        if !referenced.contains(&vector.name) {
+            findings.push(Finding {
+                check: Check::UnreferencedVector,
+                record: vector.record,
+                message: format!(
+                    "{} {} is not used by any feature or vector",
+                    vector_kind(vector.name.rcnm),
+                    name_text(&vector.name)
+                ),
+            });
+        }
+    }
+}
+
+/// Acronym of an object class, or its OBJL for classes outside the catalogue
+fn class_name(objl: u16) -> String {
+    ObjectClass::from_code(objl).map_or_else(|| format!("OBJL {}", objl), |c| c.to_string())
+}
+
+fn primitive(prim: u8) -> &'static str {
+    match prim {
+        PRIM_POINT => "point",
+        PRIM_LINE => "line",
+        PRIM_AREA => "area",
+        PRIM_NONE => "none",
+        _ => "unknown",
+    }
+}
+
+fn vector_kind(rcnm: u8) -> &'static str {
+    match rcnm {
+        RCNM_ISOLATED_NODE => "isolated node",
+        RCNM_CONNECTED_NODE => "connected node",
+        RCNM_EDGE => "edge",
+        RCNM_FACE => "face",
+        _ => "vector",
+    }
+}
+
+/// RCNM/RCID of a vector name
+fn name_text(name: &NameKey) -> String {
+    format!("{}/{}", name.rcnm, name.rcid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use s57_parse::iso8211::write_file;
+
+    fn name(rcnm: u8, rcid: u32) -> Vec<u8> {
+        let mut data = vec![rcnm];
+        data.extend(rcid.to_le_bytes());
+        data
+    }
+
+    fn vrid(rcnm: u8, rcid: u32) -> Vec<u8> {
+        let mut data = name(rcnm, rcid);
+        data.extend([1, 0, 1, 0x1E]);
+        data
+    }
+
+    /// FSPT pointing at vectors
+    fn fspt(names: &[(u8, u32)]) -> Vec<u8> {
+        let mut data = Vec::new();
+        for &(rcnm, rcid) in names {
+            data.extend(name(rcnm, rcid));
+            data.extend([1, 255, 255]);
+        }
+        data.push(0x1E);
+        data
+    }
+
+    /// VRPT from a begin node to an end node
+    fn vrpt(names: &[(u8, u32)]) -> Vec<u8> {
+        let mut data = Vec::new();
+        for (topi, &(rcnm, rcid)) in [1, 2].into_iter().zip(names) {
+            data.extend(name(rcnm, rcid));
+            data.extend([255, 255, topi, 255]);
+        }
+        data.push(0x1E);
+        data
+    }
+
+    fn frid(rcid: u32, prim: u8, objl: u16) -> Vec<u8> {
+        let mut data = vec![100];
+        data.extend(rcid.to_le_bytes());
+        data.extend([prim, 1]);
+        data.extend(objl.to_le_bytes());
+        data.extend([1, 0, 1, 0x1E]);
+        data
+    }
+
+    fn foid(fidn: u32) -> Vec<u8> {
+        let mut data = 550u16.to_le_bytes().to_vec();
+        data.extend(fidn.to_le_bytes());
+        data.extend(1u16.to_le_bytes());
+        data.push(0x1E);
+        data
+    }
+
+    fn attf(pairs: &[(u16, &str)]) -> Vec<u8> {
+        let mut data = Vec::new();
+        for (attl, value) in pairs {
+            data.extend(attl.to_le_bytes());
+            data.extend(value.as_bytes());
+            data.push(0x1F);
+        }
+        data.push(0x1E);
+        data
+    }
+
+    #[test]
+    fn test_validate() {
+        let records = vec![
+            // Edge 1 between nodes 1 and 2, but node 2 is missing
+            Record::from_fields([("VRID", vrid(120, 1))]),
+            Record::from_fields([
+                ("VRID", vrid(130, 1)),
+                ("VRPT", vrpt(&[(120, 1), (120, 2)])),
+            ]),
+            // Isolated node nothing uses
+            Record::from_fields([("VRID", vrid(110, 3))]),
+            // DEPARE without DRVAL2, drawn as a point
+            Record::from_fields([
+                ("FRID", frid(1, 1, 42)),
+                ("FOID", foid(1)),
+                ("ATTF", attf(&[(87, "5")])),
+                ("FSPT", fspt(&[(130, 1)])),
+            ]),
+            // Same FOID again, pointing at a missing face
+            Record::from_fields([
+                ("FRID", frid(2, 3, 71)),
+                ("FOID", foid(1)),
+                ("FSPT", fspt(&[(140, 9)])),
+            ]),
+            // Valid DEPCNT
+            Record::from_fields([
+                ("FRID", frid(3, 2, 43)),
+                ("FOID", foid(3)),
+                ("ATTF", attf(&[(174, "10")])),
+                ("FSPT", fspt(&[(130, 1)])),
+            ]),
+        ];
+        let file = S57File::from_bytes(&write_file(&records).unwrap()).unwrap();
+        let findings = validate(&file).unwrap();
+        let found: Vec<(usize, Check)> = findings.iter().map(|f| (f.record, f.check)).collect();
+        assert_eq!(
+            found,
+            [
+                (1, Check::MissingEndNode),
+                (2, Check::UnreferencedVector),
+                (3, Check::MandatoryAttribute),
+                (3, Check::Primitive),
+                (4, Check::DanglingPointer),
+                (4, Check::DuplicateFoid),
+            ]
+        );
+        assert!(findings[2].message.contains("DRVAL2"));
+        assert_eq!(findings[4].severity(), Severity::Error);
+        assert_eq!(
+            findings[1].to_string(),
+            "record 2: warning [unreferenced-vector]: isolated node 110/3 is not used by any feature or vector"
+        );
+    }
+}
@@ -0,0 +1,399 @@
+//! Several cells loaded as one dataset
+//!
+//! A region is usually charted by many cells: neighbours of the same usage
+//! band, and cells of other bands overlapping them. `WorldSet` keeps one
+//! World per cell, so each keeps its own entities and scales, and answers
+//! questions about the whole set: its extent, which cells cover an area,
+//! and where a feature is charted. A feature charted in several cells has
+//! the same FOID in each; lookups return the most detailed copy (see
+//! `FoidRegistry`).
+
+use crate::ecs::{EntityId, World};
+use crate::foid_registry::{FoidEntry, FoidRegistry};
+use crate::region::BoundingBox;
+#[cfg(feature = "fs")]
+use crate::{build_world_with_options, ParseOptions};
+use s57_parse::bitstring::FoidKey;
+#[cfg(feature = "fs")]
+use s57_parse::{ParseError, ParseErrorKind, Result, S57File};
+#[cfg(feature = "fs")]
+use std::path::Path;
+use std::path::PathBuf;
+
+/// SetCell: One cell of a WorldSet
+#[derive(Debug)]
+pub struct SetCell {
+    /// Cell name: the base cell's file name without extension
+    pub name: String,
+    /// Base cell file (.000), if the cell was read from disk
+    pub path: Option<PathBuf>,
+    /// Update files applied to the base cell, in order
+    pub updates: Vec<PathBuf>,
+    /// The cell's World, with its updates applied
+    pub world: World,
+}
+
+impl SetCell {
+    /// Cell built elsewhere, e.g. from bytes
+    pub fn new(name: impl Into<String>, world: World) -> Self {
+        Self {
+            name: name.into(),
+            path: None,
+            updates: Vec::new(),
+            world,
+        }
+    }
+}
+
+/// WorldSet: Cells of a region, with lookups across them
+#[derive(Debug, Default)]
+pub struct WorldSet {
+    cells: Vec<SetCell>,
+    /// Extent of each cell, by index
+    extents: Vec<Option<BoundingBox>>,
+    registry: FoidRegistry,
+}
+
+impl WorldSet {
+    /// Set of already built cells; they keep the given order
+    pub fn new(cells: Vec<SetCell>) -> Self {
+        let worlds: Vec<&World> = cells.iter().map(|cell| &cell.world).collect();
+        let registry = FoidRegistry::build(&worlds);
+        let extents = worlds.iter().map(|world| world.extent()).collect();
+        Self {
+            cells,
+            extents,
+            registry,
+        }
+    }
+
+    /// Load every base cell (.000) under `dir`, with its updates
+    ///
+    /// See `load_dir_with_options`.
+    #[cfg(feature = "fs")]
+    pub fn load_dir(dir: &Path) -> Result<Self> {
+        Self::load_dir_with_options(dir, &ParseOptions::default())
+    }
+
+    /// Load every base cell (.000) under `dir`, with its updates
+    ///
+    /// `dir` is searched recursively, so an ENC_ROOT with a directory per
+    /// cell works as well as a flat directory. Cells are ordered by path.
+    /// The update files next to each base cell (`NAME.001`, ...) are
+    /// applied strictly, as by `S57File::apply_update`. Any file that
+    /// can't be read, parsed or updated is an error naming it.
+    #[cfg(feature = "fs")]
+    pub fn load_dir_with_options(dir: &Path, options: &ParseOptions) -> Result<Self> {
+        let mut paths = Vec::new();
+        find_base_cells(dir, &mut paths).map_err(|e| file_error(dir, e))?;
+        paths.sort();
+
+        let mut cells = Vec::with_capacity(paths.len());
+        for path in paths {
+            let mut file = read_file(&path)?;
+            let updates = s57_parse::update::update_paths(&path);
+            for update_path in &updates {
+                let update = read_file(update_path)?;
+                file.apply_update(&update)
+                    .map_err(|e| file_error(update_path, e))?;
+            }
+            let world =
+                build_world_with_options(&file, options).map_err(|e| file_error(&path, e))?;
+            let name = path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            cells.push(SetCell {
+                name,
+                path: Some(path),
+                updates,
+                world,
+            });
+        }
+        Ok(Self::new(cells))
+    }
+
+    /// Every cell, in load order
+    pub fn cells(&self) -> &[SetCell] {
+        &self.cells
+    }
+
+    /// Number of cells
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// True if the set has no cells
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The first cell with this name (case-insensitive)
+    pub fn cell(&self, name: &str) -> Option<&SetCell> {
+        self.cells
+            .iter()
+            .find(|cell| cell.name.eq_ignore_ascii_case(name))
+    }
+
+    /// FOID index over every cell; `FoidEntry::cell` indexes `cells()`
+    pub fn registry(&self) -> &FoidRegistry {
+        &self.registry
+    }
+
+    /// Extent of every positioned vector in every cell
+    pub fn extent(&self) -> Option<BoundingBox> {
+        self.extents
+            .iter()
+            .flatten()
+            .fold(None, |extent: Option<BoundingBox>, cell| {
+                Some(extent.map_or_else(|| cell.clone(), |extent| extent.union(cell)))
+            })
+    }
+
+    /// Cells whose extent intersects `bbox`
+    pub fn cells_in<'a>(&'a self, bbox: &'a BoundingBox) -> impl Iterator<Item = &'a SetCell> {
+        self.cells
+            .iter()
+            .zip(&self.extents)
+            .filter(|(_, extent)| extent.as_ref().is_some_and(|e| e.intersects(bbox)))
+            .map(|(cell, _)| cell)
+    }
+
+    /// The most detailed copy of a feature, and the cell it is in
+    pub fn feature(&self, foid: &FoidKey) -> Option<(&SetCell, EntityId)> {
+        self.resolve(self.registry.best(foid)?)
+    }
+
+    /// The copy of a feature to show at a display scale of 1:`scale`
+    pub fn feature_for_scale(&self, foid: &FoidKey, scale: u32) -> Option<(&SetCell, EntityId)> {
+        self.resolve(self.registry.best_for_scale(foid, scale)?)
+    }
+
+    /// `feature` for an LNAM (B64: AGEN, FIDN, FIDS); None if it isn't 8
+    /// bytes or isn't charted
+    pub fn feature_by_lnam(&self, lnam: &[u8]) -> Option<(&SetCell, EntityId)> {
+        self.feature(&FoidKey::decode(lnam).ok()?)
+    }
+
+    /// Every copy of a feature, in cell order
+    pub fn copies(&self, foid: &FoidKey) -> impl Iterator<Item = (&SetCell, EntityId)> {
+        self.registry
+            .get(foid)
+            .iter()
+            .filter_map(|entry| self.resolve(entry))
+    }
+
+    fn resolve(&self, entry: &FoidEntry) -> Option<(&SetCell, EntityId)> {
+        Some((self.cells.get(entry.cell)?, entry.entity))
+    }
+}
+
+/// Base cells (.000) under `dir`, recursively
+#[cfg(feature = "fs")]
+fn find_base_cells(dir: &Path, out: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            find_base_cells(&path, out)?;
+        } else if path.extension().and_then(|e| e.to_str()) == Some("000") {
+            out.push(path);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(feature = "fs")]
+fn read_file(path: &Path) -> Result<S57File> {
+    let data = std::fs::read(path).map_err(|e| file_error(path, e))?;
+    S57File::from_bytes(&data).map_err(|e| file_error(path, e))
+}
+
+#[cfg(feature = "fs")]
+fn file_error(path: &Path, e: impl std::fmt::Display) -> ParseError {
+    ParseError::at(
+        ParseErrorKind::Other(format!("{}: {}", path.display(), e)),
+        0,
+    )
+}
+
+#[cfg(all(test, feature = "fs"))]
+mod tests {
+    use super::*;
+    use s57_parse::iso8211::{write_file, Field, Record};
+
+    const UNIT_TERMINATOR: u8 = 0x1F;
+    const FIELD_TERMINATOR: u8 = 0x1E;
+
+    /// DSID and DSSI of edition 1 of `name`
+    fn dsid(name: &str, updn: u32) -> Record {
+        let expp = if updn == 0 { 1 } else { 2 };
+        let mut data = vec![10, 1, 0, 0, 0, expp, 1];
+        for text in [name, "1", &updn.to_string()] {
+            data.extend(text.bytes());
+            data.push(UNIT_TERMINATOR);
+        }
+        data.extend(b"20250101"); // UADT
+        data.extend(b"20250101"); // ISDT
+        data.extend(b"03.1");
+        data.extend([1, UNIT_TERMINATOR, UNIT_TERMINATOR, 1, 0x26, 0x02]);
+        data.extend([UNIT_TERMINATOR, FIELD_TERMINATOR]);
+        let mut dssi = vec![2, 1, 1];
+        dssi.extend([0; 32]);
+        dssi.push(FIELD_TERMINATOR);
+        Record::from_fields(vec![
+            Field::new("DSID".to_string(), data),
+            Field::new("DSSI".to_string(), dssi),
+        ])
+    }
+
+    /// Dataset parameters: COMF 10^7, SOMF 10, compiled at 1:`cscl`
+    fn dspm(cscl: u32) -> Record {
+        let mut dspm = vec![20];
+        dspm.extend(1u32.to_le_bytes());
+        dspm.extend([2, 3, 23]);
+        dspm.extend(cscl.to_le_bytes());
+        dspm.extend([1, 1, 1, 1]);
+        dspm.extend(10_000_000u32.to_le_bytes());
+        dspm.extend(10u32.to_le_bytes());
+        dspm.extend([UNIT_TERMINATOR, FIELD_TERMINATOR]);
+        Record::from_fields(vec![Field::new("DSPM".to_string(), dspm)])
+    }
+
+    /// An isolated node at (lat, lon) in units of 1e-7 degrees
+    fn node(rcid: u32, lat: i32, lon: i32) -> Record {
+        let mut vrid = vec![110];
+        vrid.extend(rcid.to_le_bytes());
+        vrid.extend(1u16.to_le_bytes());
+        vrid.extend([1, FIELD_TERMINATOR]);
+        let mut sg2d = lat.to_le_bytes().to_vec();
+        sg2d.extend(lon.to_le_bytes());
+        sg2d.push(FIELD_TERMINATOR);
+        Record::from_fields(vec![
+            Field::new("VRID".to_string(), vrid),
+            Field::new("SG2D".to_string(), sg2d),
+        ])
+    }
+
+    /// A LNDMRK point feature on node `node`
+    fn landmark(fidn: u32, node: u32) -> Record {
+        let mut frid = vec![100];
+        frid.extend(fidn.to_le_bytes());
+        frid.extend([1, 2]);
+        frid.extend(74u16.to_le_bytes());
+        frid.extend(1u16.to_le_bytes());
+        frid.extend([1, FIELD_TERMINATOR]);
+        let mut fspt = vec![110];
+        fspt.extend(node.to_le_bytes());
+        fspt.extend([255, 255, 255, FIELD_TERMINATOR]);
+        Record::from_fields(vec![
+            Field::new("FRID".to_string(), frid),
+            Field::new(
+                "FOID".to_string(),
+                [&lnam(fidn)[..], &[FIELD_TERMINATOR]].concat(),
+            ),
+            Field::new("FSPT".to_string(), fspt),
+        ])
+    }
+
+    fn foid(fidn: u32) -> FoidKey {
+        FoidKey {
+            agen: 550,
+            fidn,
+            fids: 1,
+        }
+    }
+
+    fn lnam(fidn: u32) -> [u8; 8] {
+        foid(fidn).encode()
+    }
+
+    #[test]
+    fn test_load_dir() {
+        let dir = std::env::temp_dir().join(format!("s57-world-set-{}", std::process::id()));
+        let write = |path: &str, records: Vec<Record>| {
+            let path = dir.join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, write_file(&records).unwrap()).unwrap();
+        };
+        // A coastal cell with landmarks 1 and 2, and an update adding 3
+        write(
+            "GB4X0001/GB4X0001.000",
+            vec![
+                dsid("GB4X0001.000", 0),
+                dspm(50_000),
+                node(1, 500_000_000, -10_000_000),
+                node(2, 501_000_000, -11_000_000),
+                landmark(1, 1),
+                landmark(2, 2),
+            ],
+        );
+        write(
+            "GB4X0001/GB4X0001.001",
+            vec![
+                dsid("GB4X0001.000", 1),
+                node(3, 502_000_000, -12_000_000),
+                landmark(3, 3),
+            ],
+        );
+        // A harbour cell charting landmark 1 again, in more detail
+        write(
+            "GB5X0002/GB5X0002.000",
+            vec![
+                dsid("GB5X0002.000", 0),
+                dspm(12_000),
+                node(1, 500_000_000, -10_000_000),
+                landmark(1, 1),
+            ],
+        );
+        write("README.TXT", Vec::new());
+
+        let set = WorldSet::load_dir(&dir);
+        std::fs::remove_dir_all(&dir).unwrap();
+        let set = set.unwrap();
+
+        let names: Vec<_> = set.cells().iter().map(|cell| cell.name.as_str()).collect();
+        assert_eq!(names, ["GB4X0001", "GB5X0002"]);
+        assert_eq!(set.cell("gb4x0001").unwrap().updates.len(), 1);
+
+        // Landmark 1 is in both; the 1:12000 copy is the more detailed
+        let (cell, _) = set.feature(&foid(1)).unwrap();
+        assert_eq!(cell.name, "GB5X0002");
+        let (cell, _) = set.feature_for_scale(&foid(1), 60_000).unwrap();
+        assert_eq!(cell.name, "GB4X0001");
+        assert_eq!(set.copies(&foid(1)).count(), 2);
+
+        // Landmark 3 came with the update
+        let (cell, entity) = set.feature_by_lnam(&lnam(3)).unwrap();
+        assert_eq!(cell.name, "GB4X0001");
+        assert_eq!(cell.world.feature_meta[&entity].foid, foid(3));
+        assert!(set.feature_by_lnam(&lnam(4)).is_none());
+
+        let extent = set.extent().unwrap();
+        let degrees = |tenths: i64| num_rational::BigRational::new(tenths.into(), 10.into());
+        assert_eq!(
+            (
+                extent.min_lat,
+                extent.max_lat,
+                extent.min_lon,
+                extent.max_lon
+            ),
+            (degrees(500), degrees(502), degrees(-12), degrees(-10))
+        );
+        let harbour = BoundingBox::from_degrees(49.9, -1.05, 50.05, -0.95).unwrap();
+        assert_eq!(set.cells_in(&harbour).count(), 2);
+        let offshore = BoundingBox::from_degrees(50.15, -1.25, 50.25, -1.15).unwrap();
+        let names: Vec<_> = set.cells_in(&offshore).map(|cell| &cell.name).collect();
+        assert_eq!(names, ["GB4X0001"]);
+    }
+
+    #[test]
+    fn test_load_dir_errors() {
+        let missing = std::env::temp_dir().join("s57-world-set-missing");
+        let error = WorldSet::load_dir(&missing).unwrap_err();
+        assert!(
+            error.to_string().contains("s57-world-set-missing"),
+            "{}",
+            error
+        );
+    }
+}
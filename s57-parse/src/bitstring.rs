@@ -14,6 +14,7 @@ use crate::error::{ParseError, ParseErrorKind, Result};
 /// - RCNM: Record Name (lower 8 bits) - record type (VI=110, VC=120, VE=130, VF=140)
 /// - RCID: Record ID (next 32 bits) - unique within record type
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NameKey {
     /// Record name (type): VI=110, VC=120, VE=130, VF=140
     pub rcnm: u8,
@@ -79,6 +80,7 @@ impl NameKey {
 ///
 /// This follows the FOID (Feature Object Identifier) structure from S-57.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FoidKey {
     /// Producing agency code (e.g., 550 = NOAA)
     pub agen: u16,
@@ -0,0 +1,364 @@
+//! Exchange set catalogue (CATALOG.031) records
+//!
+//! An S-57 exchange set ships a catalogue file listing every file in the set.
+//! It contains two kinds of data records:
+//!
+//! - CATD (Catalogue Directory): one per file, with its path, implementation
+//!   (BIN for cells, ASC/TXT for text, TIF for pictures), coverage and CRC
+//! - CATX (Catalogue Cross Reference): links between two catalogue records,
+//!   e.g. a cell and the text or picture files its features reference
+//!
+//! Catalogue files use ASCII formats (A, I, R) rather than the binary
+//! formats of cell files, so values are accepted in either representation.
+//!
+//! CATD CRCS holds the CRC-32 of each file; `crc32` computes it for
+//! checking the files on disk.
+
+use crate::ddr::{SubfieldValue, DDR};
+use crate::error::{ParseError, ParseErrorKind, Result};
+use crate::S57File;
+
+/// CatalogueEntry: Decoded CATD field
+#[derive(Debug, Clone, PartialEq)]
+pub struct CatalogueEntry {
+    /// Record name (always "CD" for catalogue directory records)
+    pub rcnm: String,
+    /// Record identification number
+    pub rcid: u32,
+    /// File name, relative to the exchange set root (e.g. "US5PVDGD/US5PVDGD.000")
+    pub file: String,
+    /// Long file name
+    pub lfil: String,
+    /// Volume (e.g. "V01X01")
+    pub volm: String,
+    /// Implementation: BIN (cell), ASC (catalogue), TXT (text), TIF (picture)
+    pub impl_: String,
+    /// Southernmost latitude of coverage (degrees)
+    pub slat: Option<f64>,
+    /// Westernmost longitude of coverage (degrees)
+    pub wlon: Option<f64>,
+    /// Northernmost latitude of coverage (degrees)
+    pub nlat: Option<f64>,
+    /// Easternmost longitude of coverage (degrees)
+    pub elon: Option<f64>,
+    /// CRC-32 of the file as 8 hex digits (empty if not given)
+    pub crcs: String,
+    /// Comment
+    pub comt: String,
+}
+
+impl CatalogueEntry {
+    /// Decode a CATD subfield group
+    pub fn from_group(group: &[(&str, SubfieldValue)]) -> Result<Self> {
+        let file = text(group, "FILE");
+        if file.is_empty() {
+            return Err(ParseError::at(
+                ParseErrorKind::InvalidField("CATD missing FILE".to_string()),
+                0,
+            ));
+        }
+
+        Ok(CatalogueEntry {
+            rcnm: text(group, "RCNM"),
+            rcid: number(group, "RCID").unwrap_or(0.0) as u32,
+            file,
+            lfil: text(group, "LFIL"),
+            volm: text(group, "VOLM"),
+            impl_: text(group, "IMPL"),
+            slat: number(group, "SLAT"),
+            wlon: number(group, "WLON"),
+            nlat: number(group, "NLAT"),
+            elon: number(group, "ELON"),
+            crcs: text(group, "CRCS"),
+            comt: text(group, "COMT"),
+        })
+    }
+
+    /// True for binary (ISO 8211) files: cells and their updates
+    pub fn is_binary(&self) -> bool {
+        self.impl_.eq_ignore_ascii_case("BIN")
+    }
+
+    /// Base file name without the directory part
+    pub fn file_name(&self) -> &str {
+        self.file
+            .rsplit(['/', '\\'])
+            .next()
+            .unwrap_or(self.file.as_str())
+    }
+
+    /// Coverage as (south, west, north, east) if all four bounds are present
+    pub fn bounds(&self) -> Option<(f64, f64, f64, f64)> {
+        Some((self.slat?, self.wlon?, self.nlat?, self.elon?))
+    }
+
+    /// CRCS as a number; None if empty or not hexadecimal
+    pub fn crc(&self) -> Option<u32> {
+        u32::from_str_radix(&self.crcs, 16).ok()
+    }
+}
+
+/// CRC-32 of a file's bytes, as recorded in CATD CRCS
+///
+/// Polynomial 0x04C11DB7, reflected, with initial value and final XOR
+/// 0xFFFFFFFF: the same CRC-32 as zip and Ethernet.
+pub fn crc32(data: &[u8]) -> u32 {
+    const TABLE: [u32; 256] = {
+        let mut table = [0u32; 256];
+        let mut i = 0;
+        while i < 256 {
+            let mut crc = i as u32;
+            let mut bit = 0;
+            while bit < 8 {
+                crc = if crc & 1 != 0 {
+                    (crc >> 1) ^ 0xEDB8_8320
+                } else {
+                    crc >> 1
+                };
+                bit += 1;
+            }
+            table[i] = crc;
+            i += 1;
+        }
+        table
+    };
+    !data.iter().fold(!0u32, |crc, &byte| {
+        TABLE[((crc ^ byte as u32) & 0xFF) as usize] ^ (crc >> 8)
+    })
+}
+
+/// CatalogueCrossReference: Decoded CATX field
+#[derive(Debug, Clone, PartialEq)]
+pub struct CatalogueCrossReference {
+    /// Record name (always "CR" for cross reference records)
+    pub rcnm: String,
+    /// Record identification number
+    pub rcid: u32,
+    /// RCID of the first catalogue directory record
+    pub nam1: u32,
+    /// RCID of the second catalogue directory record
+    pub nam2: u32,
+    /// Comment
+    pub comt: String,
+}
+
+impl CatalogueCrossReference {
+    /// Decode a CATX subfield group
+    pub fn from_group(group: &[(&str, SubfieldValue)]) -> Result<Self> {
+        let name = |label: &str| {
+            name_rcid(group, label).ok_or_else(|| {
+                ParseError::at(
+                    ParseErrorKind::InvalidField(format!("CATX missing {}", label)),
+                    0,
+                )
+            })
+        };
+
+        Ok(CatalogueCrossReference {
+            rcnm: text(group, "RCNM"),
+            rcid: number(group, "RCID").unwrap_or(0.0) as u32,
+            nam1: name("NAM1")?,
+            nam2: name("NAM2")?,
+            comt: text(group, "COMT"),
+        })
+    }
+}
+
+/// ExchangeSet: Catalogue of an S-57 exchange set
+///
+/// Built from the parsed CATALOG.031 file. Entries keep catalogue order.
+#[derive(Debug, Clone, Default)]
+pub struct ExchangeSet {
+    /// Catalogue directory entries (CATD)
+    pub entries: Vec<CatalogueEntry>,
+    /// Cross references between entries (CATX)
+    pub cross_references: Vec<CatalogueCrossReference>,
+}
+
+impl ExchangeSet {
+    /// Build an ExchangeSet from a parsed catalogue file
+    ///
+    /// Records without CATD/CATX fields are ignored. A malformed CATD or CATX
+    /// field is an error, since a catalogue with holes can't be trusted.
+    pub fn from_catalog(file: &S57File) -> Result<Self> {
+        let records = file.records();
+        let ddr_record = records.first().ok_or_else(|| {
+            ParseError::at(
+                ParseErrorKind::InvalidField("Empty catalogue file".to_string()),
+                0,
+            )
+        })?;
+        let ddr = DDR::parse(ddr_record)?;
+
+        let mut set = ExchangeSet::default();
+        for record in &records[1..] {
+            for field in &record.fields {
+                match field.tag.as_str() {
+                    "CATD" => {
+                        let parsed = ddr.parse_field_data(field)?;
+                        for group in parsed.groups() {
+                            set.entries.push(CatalogueEntry::from_group(group)?);
+                        }
+                    }
+                    "CATX" => {
+                        let parsed = ddr.parse_field_data(field)?;
+                        for group in parsed.groups() {
+                            set.cross_references
+                                .push(CatalogueCrossReference::from_group(group)?);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        Ok(set)
+    }
+
+    /// Parse an ExchangeSet directly from CATALOG.031 bytes
+    pub fn from_bytes(data: &[u8]) -> Result<Self> {
+        Self::from_catalog(&S57File::from_bytes(data)?)
+    }
+
+    /// Binary entries (base cells and updates)
+    pub fn cells(&self) -> impl Iterator<Item = &CatalogueEntry> {
+        self.entries.iter().filter(|e| e.is_binary())
+    }
+
+    /// Look up an entry by file name (case-insensitive, with or without directory)
+    pub fn find(&self, name: &str) -> Option<&CatalogueEntry> {
+        self.entries
+            .iter()
+            .find(|e| e.file.eq_ignore_ascii_case(name) || e.file_name().eq_ignore_ascii_case(name))
+    }
+
+    /// Look up an entry by its catalogue RCID
+    pub fn entry_by_rcid(&self, rcid: u32) -> Option<&CatalogueEntry> {
+        self.entries.iter().find(|e| e.rcid == rcid)
+    }
+
+    /// Entries cross-referenced with the given entry (in either direction)
+    pub fn related(&self, entry: &CatalogueEntry) -> Vec<&CatalogueEntry> {
+        self.cross_references
+            .iter()
+            .filter_map(|x| {
+                if x.nam1 == entry.rcid {
+                    self.entry_by_rcid(x.nam2)
+                } else if x.nam2 == entry.rcid {
+                    self.entry_by_rcid(x.nam1)
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
+/// Subfield value as trimmed text (empty if missing or not textual)
+fn text(group: &[(&str, SubfieldValue)], label: &str) -> String {
+    match group.iter().find(|(l, _)| *l == label).map(|(_, v)| v) {
+        Some(SubfieldValue::String(s)) => s.trim().to_string(),
+        Some(SubfieldValue::Integer(i)) => i.to_string(),
+        Some(SubfieldValue::UnsignedInteger(u)) => u.to_string(),
+        _ => String::new(),
+    }
+}
+
+/// Subfield value as a number, accepting binary values or ASCII text
+fn number(group: &[(&str, SubfieldValue)], label: &str) -> Option<f64> {
+    let value = group.iter().find(|(l, _)| *l == label).map(|(_, v)| v)?;
+    match value {
+        SubfieldValue::String(s) => s.trim().parse().ok(),
+        other => other.as_float(),
+    }
+}
+
+/// RCID referenced by a NAM1/NAM2 subfield
+///
+/// Catalogues encode these as ASCII RCIDs, but a B(40) NAME (RCNM + RCID) is
+/// accepted too.
+fn name_rcid(group: &[(&str, SubfieldValue)], label: &str) -> Option<u32> {
+    match group.iter().find(|(l, _)| *l == label).map(|(_, v)| v)? {
+        SubfieldValue::Bytes(b) if b.len() == 5 => {
+            Some(u32::from_le_bytes([b[1], b[2], b[3], b[4]]))
+        }
+        _ => number(group, label).map(|n| n as u32),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn group<'a>(values: &[(&'a str, SubfieldValue<'a>)]) -> Vec<(&'a str, SubfieldValue<'a>)> {
+        values.to_vec()
+    }
+
+    fn catd(rcid: &str, file: &str, impl_: &str) -> CatalogueEntry {
+        CatalogueEntry::from_group(&group(&[
+            ("RCNM", SubfieldValue::String("CD".into())),
+            ("RCID", SubfieldValue::String(rcid.into())),
+            ("FILE", SubfieldValue::String(file.into())),
+            ("LFIL", SubfieldValue::String("".into())),
+            ("VOLM", SubfieldValue::String("V01X01".into())),
+            ("IMPL", SubfieldValue::String(impl_.into())),
+            ("SLAT", SubfieldValue::String("32.5".into())),
+            ("WLON", SubfieldValue::String("-117.5".into())),
+            ("NLAT", SubfieldValue::String("33.0".into())),
+            ("ELON", SubfieldValue::String("-117.0".into())),
+            ("CRCS", SubfieldValue::String("1A2B3C4D".into())),
+            ("COMT", SubfieldValue::String("".into())),
+        ]))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_catd_from_ascii_group() {
+        let entry = catd("0000000002", "US5PVDGD\\US5PVDGD.000", "BIN");
+
+        assert_eq!(entry.rcnm, "CD");
+        assert_eq!(entry.rcid, 2);
+        assert_eq!(entry.file_name(), "US5PVDGD.000");
+        assert!(entry.is_binary());
+        assert_eq!(entry.bounds(), Some((32.5, -117.5, 33.0, -117.0)));
+        assert_eq!(entry.crcs, "1A2B3C4D");
+        assert_eq!(entry.crc(), Some(0x1A2B_3C4D));
+    }
+
+    #[test]
+    fn test_crc32() {
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn test_catd_missing_file_is_error() {
+        let result =
+            CatalogueEntry::from_group(&group(&[("RCNM", SubfieldValue::String("CD".into()))]));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_exchange_set_cross_references() {
+        let cell = catd("2", "US5PVDGD/US5PVDGD.000", "BIN");
+        let text = catd("3", "US5PVDGD/US5PVD01.TXT", "TXT");
+        let xref = CatalogueCrossReference::from_group(&group(&[
+            ("RCNM", SubfieldValue::String("CR".into())),
+            ("RCID", SubfieldValue::String("1".into())),
+            ("NAM1", SubfieldValue::String("2".into())),
+            ("NAM2", SubfieldValue::String("3".into())),
+        ]))
+        .unwrap();
+
+        let set = ExchangeSet {
+            entries: vec![cell, text],
+            cross_references: vec![xref],
+        };
+
+        assert_eq!(set.cells().count(), 1);
+        let cell = set.find("us5pvdgd.000").unwrap();
+        let related = set.related(cell);
+        assert_eq!(related.len(), 1);
+        assert_eq!(related[0].file_name(), "US5PVD01.TXT");
+    }
+}
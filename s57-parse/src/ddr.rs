@@ -6,8 +6,11 @@
 
 use crate::error::{ParseError, ParseErrorKind, Result};
 use crate::iso8211::{Field, Record};
-use crate::s57_schema::OverrideSchema;
-use std::collections::HashMap;
+use crate::lexical::LexicalLevel;
+use crate::s57_schema::{OverrideSchema, CANONICAL_FIELD_DEFS};
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
 
 /// Field format type
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -34,7 +37,7 @@ pub enum FormatType {
 }
 
 /// Field subfield definition
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SubfieldDef {
     /// Subfield label (e.g., "RCNM", "RCID")
     pub label: String,
@@ -78,14 +81,47 @@ impl FieldDef {
             labels.split('!').filter(|s| !s.trim().is_empty()).count()
         }
     }
+
+    /// Size in bytes of one group, if every subfield is fixed-width
+    ///
+    /// E.g. 8 for SG2D (2b24), 12 for SG3D (3b24). Returns None when any
+    /// subfield is variable-length (terminated by UT/FT).
+    pub fn group_size(&self) -> Option<usize> {
+        if self.subfields.is_empty() {
+            return None;
+        }
+        self.subfields.iter().map(|s| s.width).sum()
+    }
+
+    /// Byte offset and width of a subfield within the first group
+    ///
+    /// None if the label isn't defined, or if it or any subfield before it
+    /// is variable-length.
+    pub fn subfield_offset(&self, label: &str) -> Option<(usize, usize)> {
+        let mut offset = 0;
+        for subfield in &self.subfields {
+            let width = subfield.width?;
+            if subfield.label == label {
+                return Some((offset, width));
+            }
+            offset += width;
+        }
+        None
+    }
 }
 
 /// Data Descriptive Record parser
 pub struct DDR {
     /// Field definitions indexed by tag
     field_defs: HashMap<String, FieldDef>,
+    /// Tags defined from `CANONICAL_FIELD_DEFS` rather than the DDR itself
+    fallback_tags: HashSet<String>,
     /// Override schema for S-57 field optionality
     schema: OverrideSchema,
+    /// Character set of ATTF and ATTV values (DSSI AALL)
+    aall: LexicalLevel,
+    /// Character set of NATF values (DSSI NALL)
+    nall: LexicalLevel,
 }
 
 impl DDR {
@@ -105,7 +141,7 @@ impl DDR {
         // Each field (starting from index 2) is a data descriptive field where:
         // - The field's tag (from directory) is the tag being defined
         // - The field's data contains the definition (name, array descriptor, format controls)
-        for field in &record.fields[2..] {
+        for field in record.fields.iter().skip(2) {
             // Skip the special 0000 and 0001 fields if they appear again
             if field.tag == "0000" || field.tag == "0001" {
                 continue;
@@ -113,27 +149,136 @@ impl DDR {
 
             // Parse field definition from this field's data
             if let Ok(mut def) = Self::parse_field_definition(field) {
-                // Apply format overrides from schema
-                for subfield in &mut def.subfields {
-                    // Apply format type override if present
-                    if let Some(override_format) =
-                        schema.get_format_override(&def.tag, &subfield.label)
-                    {
-                        subfield.format = override_format;
-                        // For AsciiFixed, ensure width is set correctly
-                        if matches!(override_format, FormatType::AsciiFixed)
-                            && subfield.width.is_none()
-                        {
-                            // Default to 4 for R(4) -> A(4) conversion
-                            subfield.width = Some(4);
-                        }
-                    }
-                }
+                Self::apply_overrides(&schema, &mut def);
                 field_defs.insert(def.tag.clone(), def);
             }
         }
 
-        Ok(DDR { field_defs, schema })
+        let fallback_tags = Self::fill_canonical(&schema, &mut field_defs);
+        Ok(DDR {
+            field_defs,
+            fallback_tags,
+            schema,
+            aall: LexicalLevel::Ascii,
+            nall: LexicalLevel::Ascii,
+        })
+    }
+
+    /// A DDR made only of the built-in S-57 definitions, for a cell whose
+    /// DDR is missing or unreadable
+    pub fn canonical() -> Self {
+        let schema = OverrideSchema::new();
+        let mut field_defs = HashMap::new();
+        let fallback_tags = Self::fill_canonical(&schema, &mut field_defs);
+        DDR {
+            field_defs,
+            fallback_tags,
+            schema,
+            aall: LexicalLevel::Ascii,
+            nall: LexicalLevel::Ascii,
+        }
+    }
+
+    /// Add the canonical definition of every S-57 field that `field_defs`
+    /// lacks or defines without usable subfields; returns the tags added
+    fn fill_canonical(
+        schema: &OverrideSchema,
+        field_defs: &mut HashMap<String, FieldDef>,
+    ) -> HashSet<String> {
+        let mut added = HashSet::new();
+        for &(tag, name, array_descriptor, format_controls) in CANONICAL_FIELD_DEFS {
+            if field_defs
+                .get(tag)
+                .is_some_and(|def| !def.subfields.is_empty())
+            {
+                continue;
+            }
+            let mut def = FieldDef {
+                tag: tag.to_string(),
+                name: name.to_string(),
+                array_descriptor: array_descriptor.to_string(),
+                format_controls: format_controls.to_string(),
+                subfields: Self::parse_format_controls(array_descriptor, format_controls),
+                is_repeating: array_descriptor.starts_with('*'),
+            };
+            Self::apply_overrides(schema, &mut def);
+            field_defs.insert(tag.to_string(), def);
+            added.insert(tag.to_string());
+        }
+        added
+    }
+
+    /// Apply the schema's format overrides to a field definition
+    fn apply_overrides(schema: &OverrideSchema, def: &mut FieldDef) {
+        for subfield in &mut def.subfields {
+            if let Some(override_format) = schema.get_format_override(&def.tag, &subfield.label) {
+                subfield.format = override_format;
+                // For AsciiFixed, ensure width is set correctly
+                if matches!(override_format, FormatType::AsciiFixed) && subfield.width.is_none() {
+                    // Default to 4 for R(4) -> A(4) conversion
+                    subfield.width = Some(4);
+                }
+            }
+        }
+    }
+
+    /// Character set of ATVL values in ATTF/ATTV (AALL) and NATF (NALL)
+    pub fn lexical_levels(&self) -> (LexicalLevel, LexicalLevel) {
+        (self.aall, self.nall)
+    }
+
+    /// Decode ATVL values with these AALL and NALL levels
+    pub fn set_lexical_levels(&mut self, aall: LexicalLevel, nall: LexicalLevel) {
+        self.aall = aall;
+        self.nall = nall;
+    }
+
+    /// Take the lexical levels from the first DSSI among `records`
+    ///
+    /// Returns false, leaving the levels as they were, if there is no
+    /// readable DSSI. Undefined level codes read as ASCII.
+    pub fn load_lexical_levels(&mut self, records: &[Record]) -> bool {
+        let Some(dssi) = records
+            .iter()
+            .find_map(|record| record.fields.iter().find(|f| f.tag == "DSSI"))
+        else {
+            return false;
+        };
+        let Ok(parsed) = self.parse_field_data(dssi) else {
+            return false;
+        };
+        // b11 subfields may decode as signed or unsigned
+        let level = |label| {
+            let code = match parsed.get_value(label) {
+                Some(SubfieldValue::Integer(code)) => u8::try_from(*code).ok(),
+                Some(SubfieldValue::UnsignedInteger(code)) => u8::try_from(*code).ok(),
+                _ => None,
+            };
+            code.and_then(LexicalLevel::from_code).unwrap_or_default()
+        };
+        let (aall, nall) = (level("AALL"), level("NALL"));
+        self.set_lexical_levels(aall, nall);
+        true
+    }
+
+    /// True if `tag` holds UCS-2 values
+    fn has_ucs2(&self, tag: &str) -> bool {
+        self.text_level(tag, "ATVL") == Some(LexicalLevel::Ucs2)
+    }
+
+    /// Lexical level of a text subfield, if its field has one
+    fn text_level(&self, tag: &str, label: &str) -> Option<LexicalLevel> {
+        match (tag, label) {
+            ("ATTF" | "ATTV", "ATVL") => Some(self.aall),
+            ("NATF", "ATVL") => Some(self.nall),
+            _ => None,
+        }
+    }
+
+    /// True if `tag` is decoded with a built-in definition because the DDR
+    /// has none (or a damaged one)
+    pub fn is_fallback(&self, tag: &str) -> bool {
+        self.fallback_tags.contains(tag)
     }
 
     /// Parse a single field definition from a DDR field
@@ -301,7 +446,7 @@ impl DDR {
                 // b1x = unsigned (b11, b12, b14)
                 // b2x = signed (b21, b22, b24)
                 if let Ok(code) = width_str.parse::<usize>() {
-                    if code >= 20 && code < 30 {
+                    if (20..30).contains(&code) {
                         FormatType::BinarySigned // b21, b22, b24
                     } else {
                         FormatType::BinaryUnsigned // b11, b12, b14
@@ -400,7 +545,20 @@ impl DDR {
     }
 
     /// Parse a field's data using its definition
-    pub fn parse_field_data<'a, 'b>(&'a self, field: &'b Field) -> Result<ParsedField<'a>> {
+    pub fn parse_field_data<'a>(&'a self, field: &'a Field) -> Result<ParsedField<'a>> {
+        self.parse_field_data_in(field, &mut DecodeContext::new())
+    }
+
+    /// Parse a field's data, taking its group vectors from a `DecodeContext`
+    ///
+    /// Same result as `parse_field_data`; recycle the parsed field into
+    /// `ctx` once done with it so the next call reuses its allocations.
+    pub fn parse_field_data_in<'a>(
+        &'a self,
+        field: &'a Field,
+        ctx: &mut DecodeContext<'a>,
+    ) -> Result<ParsedField<'a>> {
+        crate::diag::span!("field", tag = %field.tag, len = field.data.len());
         let def = self.get_field_def(&field.tag).ok_or_else(|| {
             ParseError::at(
                 ParseErrorKind::InvalidField(format!("No definition for field {}", field.tag)),
@@ -408,7 +566,7 @@ impl DDR {
             )
         })?;
 
-        let mut subfield_values = Vec::new();
+        let mut subfield_values = ctx.take_groups();
         let mut offset = 0;
         let data = &field.data;
 
@@ -418,18 +576,39 @@ impl DDR {
         // Safety: if no subfields defined, can't parse
         if def.subfields.is_empty() {
             return Ok(ParsedField {
-                tag: field.tag.clone(),
+                tag: &field.tag,
                 field_def: def,
                 groups: vec![],
+                trailing_bytes: 0,
             });
         }
 
+        // Fields made only of fixed-width subfields (SG2D, SG3D, VRPT, FSPT, ...)
+        // have a known group size. Their payload must be an exact multiple of it;
+        // only complete groups are parsed and any remainder is reported.
+        let group_size = def.group_size();
+        let (limit, trailing_bytes) = Self::split_groups(def, data);
+        if trailing_bytes > 0 {
+            crate::diag::debug!(
+                "{}: {} trailing byte(s) after {} complete group(s) of {} bytes",
+                field.tag,
+                trailing_bytes,
+                limit / group_size.unwrap_or(1),
+                group_size.unwrap_or(0)
+            );
+        }
+
         loop {
-            if offset >= data.len() || data[offset] == 0x1E {
+            if offset >= limit {
+                break;
+            }
+            // Variable-width fields end at the field terminator; in fixed-size
+            // groups 0x1E is ordinary data and `limit` marks the end instead
+            if group_size.is_none() && data[offset] == 0x1E {
                 break;
             }
 
-            let mut current_group = Vec::new();
+            let mut current_group = ctx.take_group();
             let start_offset = offset;
 
             for (subfield_idx, subfield_def) in def.subfields.iter().enumerate() {
@@ -450,6 +629,18 @@ impl DDR {
                         &subfield_def.format,
                         &subfield_def.label,
                     )
+                } else if let Some(level) = self
+                    .text_level(&field.tag, &subfield_def.label)
+                    .filter(|&level| level != LexicalLevel::Ascii)
+                {
+                    // Latin-1 or UCS-2 attribute value, decoded to Unicode
+                    let (len, terminator) = level.value_extent(&data[offset..]);
+                    let text = level.decode(&data[offset..offset + len]);
+                    offset += len + terminator;
+                    SubfieldValue::String(match text {
+                        Cow::Borrowed(text) => Cow::Borrowed(text.trim()),
+                        Cow::Owned(text) => Cow::Owned(text.trim().to_string()),
+                    })
                 } else {
                     // Variable width - check if this is an optional field that may be omitted
                     let is_optional = self.schema.is_optional(&field.tag, &subfield_def.label);
@@ -478,7 +669,7 @@ impl DDR {
 
                             // If current byte is non-ASCII and next field is binary, field is omitted
                             if next_field_is_binary
-                                && (current_byte < 0x20 || current_byte >= 0x7F)
+                                && !(0x20..0x7F).contains(&current_byte)
                                 && current_byte != 0x1F
                             {
                                 // Field omitted - don't advance offset, skip to next subfield
@@ -500,7 +691,7 @@ impl DDR {
                                 }
                                 // For ASCII fields, empty data = empty string (not null)
                                 if subfield_data.is_empty() {
-                                    SubfieldValue::String(String::new())
+                                    SubfieldValue::String(Cow::Borrowed(""))
                                 } else {
                                     Self::parse_subfield_value(
                                         subfield_data,
@@ -528,7 +719,7 @@ impl DDR {
                                 FormatType::Ascii | FormatType::AsciiFixed
                             )
                         {
-                            SubfieldValue::String(String::new())
+                            SubfieldValue::String(Cow::Borrowed(""))
                         } else {
                             Self::parse_subfield_value(
                                 subfield_data,
@@ -539,11 +730,13 @@ impl DDR {
                     }
                 };
 
-                current_group.push((subfield_def.label.clone(), value));
+                current_group.push((subfield_def.label.as_str(), value));
             }
 
             if !current_group.is_empty() {
                 subfield_values.push(current_group);
+            } else {
+                ctx.spare_groups.push(current_group);
             }
 
             // NOTE: Do NOT skip unit terminators here!
@@ -564,14 +757,174 @@ impl DDR {
         }
 
         Ok(ParsedField {
-            tag: field.tag.clone(),
+            tag: &field.tag,
             field_def: def,
             groups: subfield_values,
+            trailing_bytes,
         })
     }
 
+    /// Byte range of each group in a field's data, field terminator excluded
+    ///
+    /// Fixed-size groups are cut at the group size (a trailing partial
+    /// group is dropped); otherwise variable-length subfields are taken up
+    /// to and including their unit terminator. A non-repeating field is one
+    /// group.
+    pub fn group_ranges(&self, field: &Field) -> Result<Vec<Range<usize>>> {
+        let def = self.get_field_def(&field.tag).ok_or_else(|| {
+            ParseError::at(
+                ParseErrorKind::InvalidField(format!("No definition for field {}", field.tag)),
+                0,
+            )
+        })?;
+        let data = &field.data;
+        let payload_len = match data.last() {
+            Some(0x1E) => data.len() - 1,
+            Some(0x00) if data.ends_with(&[0x1E, 0x00]) && self.has_ucs2(&field.tag) => {
+                data.len() - 2
+            }
+            _ => data.len(),
+        };
+        let is_repeating = def.array_descriptor.contains('*');
+
+        if let Some(size) = def.group_size().filter(|&size| size > 0) {
+            let (limit, _) = Self::split_groups(def, data);
+            return Ok((0..limit / size)
+                .map(|i| i * size..(i + 1) * size)
+                .collect());
+        }
+        if !is_repeating || def.subfields.is_empty() {
+            return Ok(std::iter::once(0..payload_len).collect());
+        }
+
+        let mut ranges = Vec::new();
+        let mut offset = 0;
+        while offset < payload_len {
+            let start = offset;
+            for subfield in &def.subfields {
+                match subfield.width {
+                    Some(width) => offset += width,
+                    None if self.text_level(&field.tag, &subfield.label)
+                        == Some(LexicalLevel::Ucs2) =>
+                    {
+                        let rest = &data[offset.min(payload_len)..payload_len];
+                        let (len, _) = LexicalLevel::Ucs2.value_extent(rest);
+                        // Take the two-byte unit terminator with the subfield
+                        offset += len + 2;
+                    }
+                    None => {
+                        while offset < payload_len && data[offset] != 0x1F {
+                            offset += 1;
+                        }
+                        // Take the unit terminator with the subfield
+                        offset += 1;
+                    }
+                }
+            }
+            if offset > payload_len {
+                return Err(ParseError::at(
+                    ParseErrorKind::InvalidField(format!(
+                        "{} ends inside a group at byte {}",
+                        field.tag, start
+                    )),
+                    start,
+                ));
+            }
+            ranges.push(start..offset);
+        }
+        Ok(ranges)
+    }
+
+    /// Decode 4-byte binary integer subfields straight from the field bytes
+    ///
+    /// For the coordinate fields (SG2D `YCOO!XCOO`, SG3D `YCOO!XCOO!VE3D`),
+    /// which make up most of a cell: one `[i32; N]` per complete group,
+    /// ordered as `labels`, read in fixed-size chunks without building a
+    /// `SubfieldValue` per subfield. Trailing bytes are dropped as in
+    /// `parse_field_data`.
+    ///
+    /// None when the field has no definition, isn't made of fixed-width
+    /// groups, or any label isn't a b14/b24 subfield; use `parse_field_data`
+    /// then.
+    pub fn decode_i32_groups<const N: usize>(
+        &self,
+        field: &Field,
+        labels: [&str; N],
+    ) -> Option<Vec<[i32; N]>> {
+        let def = self.get_field_def(&field.tag)?;
+        let size = def.group_size().filter(|&size| size > 0)?;
+        let mut offsets = [0; N];
+        for (offset, label) in offsets.iter_mut().zip(labels) {
+            let subfield = def.subfields.iter().find(|s| s.label == label)?;
+            if !matches!(
+                subfield.format,
+                FormatType::BinarySigned | FormatType::BinaryUnsigned
+            ) {
+                return None;
+            }
+            let (start, width) = def.subfield_offset(label)?;
+            if width != 4 {
+                return None;
+            }
+            *offset = start;
+        }
+
+        let (limit, _) = Self::split_groups(def, &field.data);
+        Some(
+            field.data[..limit]
+                .chunks_exact(size)
+                .map(|group| {
+                    offsets.map(|offset| {
+                        i32::from_le_bytes([
+                            group[offset],
+                            group[offset + 1],
+                            group[offset + 2],
+                            group[offset + 3],
+                        ])
+                    })
+                })
+                .collect(),
+        )
+    }
+
+    /// Number of bytes after the last complete group of a fixed-size field
+    ///
+    /// Cheap check that doesn't decode any subfields. Always 0 for fields
+    /// with variable-length subfields or without a definition.
+    pub fn trailing_bytes(&self, field: &Field) -> usize {
+        self.get_field_def(&field.tag)
+            .map(|def| Self::split_groups(def, &field.data).1)
+            .unwrap_or(0)
+    }
+
+    /// Split field data into (end of complete groups, trailing byte count)
+    ///
+    /// The field terminator is not counted as trailing data. Non-repeating
+    /// fields hold at most one group.
+    fn split_groups(def: &FieldDef, data: &[u8]) -> (usize, usize) {
+        let payload_len = match data.last() {
+            Some(0x1E) => data.len() - 1,
+            _ => data.len(),
+        };
+        match def.group_size() {
+            Some(size) if size > 0 => {
+                let complete = if def.array_descriptor.contains('*') {
+                    payload_len / size
+                } else {
+                    (payload_len / size).min(1)
+                };
+                (complete * size, payload_len - complete * size)
+            }
+            _ => (data.len(), 0),
+        }
+    }
+
     /// Parse a subfield value based on its format and label
-    fn parse_subfield_value(data: &[u8], format: &FormatType, _label: &str) -> SubfieldValue {
+    fn parse_subfield_value<'a>(
+        data: &'a [u8],
+        format: &FormatType,
+        _label: &str,
+    ) -> SubfieldValue<'a> {
         if data.is_empty() {
             return SubfieldValue::Null;
         }
@@ -590,7 +943,7 @@ impl DDR {
                         let u_val = u32::from_le_bytes([data[0], data[1], data[2], data[3]]);
                         SubfieldValue::UnsignedInteger(u_val)
                     }
-                    _ => SubfieldValue::Bytes(data.to_vec()),
+                    _ => SubfieldValue::Bytes(Cow::Borrowed(data)),
                 }
             }
             FormatType::BinarySigned => {
@@ -607,15 +960,15 @@ impl DDR {
                             data[0], data[1], data[2], data[3],
                         ]))
                     }
-                    _ => SubfieldValue::Bytes(data.to_vec()),
+                    _ => SubfieldValue::Bytes(Cow::Borrowed(data)),
                 }
             }
             FormatType::Ascii | FormatType::AsciiFixed => {
                 // ASCII text (A or A(n)) - keep as string, don't try to parse as number
                 if let Ok(s) = std::str::from_utf8(data) {
-                    SubfieldValue::String(s.trim().to_string())
+                    SubfieldValue::String(Cow::Borrowed(s.trim()))
                 } else {
-                    SubfieldValue::Bytes(data.to_vec())
+                    SubfieldValue::Bytes(Cow::Borrowed(data))
                 }
             }
             FormatType::IntegerAscii | FormatType::IntegerAsciiFixed => {
@@ -625,10 +978,10 @@ impl DDR {
                     if let Ok(i) = trimmed.parse::<i32>() {
                         SubfieldValue::Integer(i)
                     } else {
-                        SubfieldValue::String(trimmed.to_string())
+                        SubfieldValue::String(Cow::Borrowed(trimmed))
                     }
                 } else {
-                    SubfieldValue::Bytes(data.to_vec())
+                    SubfieldValue::Bytes(Cow::Borrowed(data))
                 }
             }
             FormatType::RealBinary => {
@@ -646,10 +999,10 @@ impl DDR {
                         ];
                         SubfieldValue::Real(f64::from_le_bytes(bytes))
                     }
-                    _ => SubfieldValue::Bytes(data.to_vec()),
+                    _ => SubfieldValue::Bytes(Cow::Borrowed(data)),
                 }
             }
-            FormatType::BitString | FormatType::Mixed => SubfieldValue::Bytes(data.to_vec()),
+            FormatType::BitString | FormatType::Mixed => SubfieldValue::Bytes(Cow::Borrowed(data)),
         }
     }
 
@@ -660,35 +1013,144 @@ impl DDR {
 }
 
 /// Parsed field with subfield values
+///
+/// Borrows from the DDR and the field: labels are the `FieldDef`'s, and
+/// text and byte values are slices of the field data.
 #[derive(Debug)]
 pub struct ParsedField<'a> {
     /// Field tag
-    pub tag: String,
+    pub tag: &'a str,
     /// Field definition
     pub field_def: &'a FieldDef,
     /// Groups of subfield values (one group per array element)
-    pub groups: Vec<Vec<(String, SubfieldValue)>>,
+    pub groups: Vec<Vec<(&'a str, SubfieldValue<'a>)>>,
+    /// Bytes left after the last complete fixed-size group (0 if well-formed)
+    ///
+    /// Only computed for fields whose subfields are all fixed-width; those
+    /// bytes are not parsed into a partial group.
+    pub trailing_bytes: usize,
 }
 
 impl<'a> ParsedField<'a> {
     /// Get value of a subfield by label from the first group
-    pub fn get_value(&self, label: &str) -> Option<&SubfieldValue> {
+    pub fn get_value(&self, label: &str) -> Option<&SubfieldValue<'a>> {
         self.groups
             .first()?
             .iter()
-            .find(|(l, _)| l == label)
+            .find(|(l, _)| *l == label)
             .map(|(_, v)| v)
     }
 
     /// Get all groups (for repeating fields)
-    pub fn groups(&self) -> &[Vec<(String, SubfieldValue)>] {
+    pub fn groups(&self) -> &[Vec<(&'a str, SubfieldValue<'a>)>] {
         &self.groups
     }
+
+    /// True if the field data ended exactly on a group boundary
+    pub fn is_well_formed(&self) -> bool {
+        self.trailing_bytes == 0
+    }
+
+    /// Copy labels and values out of the DDR and field data
+    pub fn into_owned(self) -> OwnedField {
+        OwnedField {
+            tag: self.tag.to_string(),
+            groups: self
+                .groups
+                .into_iter()
+                .map(|group| {
+                    group
+                        .into_iter()
+                        .map(|(label, value)| (label.to_string(), value.into_owned()))
+                        .collect()
+                })
+                .collect(),
+            trailing_bytes: self.trailing_bytes,
+        }
+    }
+}
+
+/// OwnedField: A `ParsedField` that owns its labels and values
+///
+/// Outlives the DDR and record it was parsed from, so it can be stored,
+/// sent between threads or (with feature "serde") serialized.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OwnedField {
+    /// Field tag
+    pub tag: String,
+    /// Groups of (label, value) pairs, as `ParsedField::groups`
+    pub groups: Vec<Vec<(String, SubfieldValue<'static>)>>,
+    /// Bytes left after the last complete fixed-size group
+    pub trailing_bytes: usize,
+}
+
+impl OwnedField {
+    /// Get value of a subfield by label from the first group
+    pub fn get_value(&self, label: &str) -> Option<&SubfieldValue<'static>> {
+        self.groups
+            .first()?
+            .iter()
+            .find(|(l, _)| l == label)
+            .map(|(_, v)| v)
+    }
+}
+
+/// Most spare vectors a `DecodeContext` keeps of each kind
+const MAX_SPARE_VECTORS: usize = 4096;
+
+/// DecodeContext: Scratch buffers reused across field parses
+///
+/// Parsing a field allocates one vector for its groups and one per group.
+/// `DDR::parse_field_data_in` takes them from the context instead, and
+/// `recycle` hands them back cleared, so a loop over many records (as in
+/// building a World from a large cell) mostly reuses the same few
+/// allocations. Text and byte values already borrow from the field data, so
+/// only the vectors need pooling.
+#[derive(Debug, Default)]
+pub struct DecodeContext<'a> {
+    /// Empty vectors of groups, with their capacity
+    spare_fields: Vec<Vec<Vec<(&'a str, SubfieldValue<'a>)>>>,
+    /// Empty groups, with their capacity
+    spare_groups: Vec<Vec<(&'a str, SubfieldValue<'a>)>>,
+}
+
+impl<'a> DecodeContext<'a> {
+    /// Context with no buffers yet
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return a parsed field's vectors for reuse
+    pub fn recycle(&mut self, parsed: ParsedField<'a>) {
+        let mut groups = parsed.groups;
+        for mut group in groups.drain(..) {
+            if self.spare_groups.len() < MAX_SPARE_VECTORS {
+                group.clear();
+                self.spare_groups.push(group);
+            }
+        }
+        if self.spare_fields.len() < MAX_SPARE_VECTORS {
+            self.spare_fields.push(groups);
+        }
+    }
+
+    fn take_groups(&mut self) -> Vec<Vec<(&'a str, SubfieldValue<'a>)>> {
+        self.spare_fields.pop().unwrap_or_default()
+    }
+
+    fn take_group(&mut self) -> Vec<(&'a str, SubfieldValue<'a>)> {
+        self.spare_groups.pop().unwrap_or_default()
+    }
 }
 
 /// Subfield value
-#[derive(Debug, Clone)]
-pub enum SubfieldValue {
+///
+/// Text and bytes borrow from the field data when parsed; build owned
+/// values with `.into()` (e.g. `SubfieldValue::String("CD".into())`).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SubfieldValue<'a> {
     /// Null/empty value
     Null,
     /// Signed integer value (b21, b22, b24)
@@ -698,12 +1160,12 @@ pub enum SubfieldValue {
     /// Real/float value
     Real(f64),
     /// String value
-    String(String),
+    String(Cow<'a, str>),
     /// Raw bytes
-    Bytes(Vec<u8>),
+    Bytes(Cow<'a, [u8]>),
 }
 
-impl SubfieldValue {
+impl SubfieldValue<'_> {
     /// Get as integer if possible (only for signed integers)
     pub fn as_int(&self) -> Option<i32> {
         match self {
@@ -737,6 +1199,18 @@ impl SubfieldValue {
             _ => None,
         }
     }
+
+    /// Copy borrowed text or bytes so the value no longer borrows the field
+    pub fn into_owned(self) -> SubfieldValue<'static> {
+        match self {
+            SubfieldValue::Null => SubfieldValue::Null,
+            SubfieldValue::Integer(i) => SubfieldValue::Integer(i),
+            SubfieldValue::UnsignedInteger(u) => SubfieldValue::UnsignedInteger(u),
+            SubfieldValue::Real(f) => SubfieldValue::Real(f),
+            SubfieldValue::String(s) => SubfieldValue::String(Cow::Owned(s.into_owned())),
+            SubfieldValue::Bytes(b) => SubfieldValue::Bytes(Cow::Owned(b.into_owned())),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -805,13 +1279,17 @@ mod tests {
 
         let mut ddr = DDR {
             field_defs: std::collections::HashMap::new(),
+            fallback_tags: HashSet::new(),
             schema,
+            aall: LexicalLevel::Ascii,
+            nall: LexicalLevel::Ascii,
         };
         ddr.field_defs.insert("DSID".to_string(), field_def);
 
         let field = Field {
             tag: "DSID".to_string(),
             data: field_data,
+            span: None,
         };
 
         let result = ddr.parse_field_data(&field);
@@ -834,21 +1312,21 @@ mod tests {
 
         // Validate expected values
         // RCNM should be 10
-        let rcnm = group.iter().find(|(label, _)| label == "RCNM");
+        let rcnm = group.iter().find(|(label, _)| *label == "RCNM");
         assert!(rcnm.is_some(), "RCNM not found");
         if let Some((_, SubfieldValue::Integer(val))) = rcnm {
             assert_eq!(*val, 10, "RCNM should be 10");
         }
 
         // DSNM should be "US5PVDGD.000"
-        let dsnm = group.iter().find(|(label, _)| label == "DSNM");
+        let dsnm = group.iter().find(|(label, _)| *label == "DSNM");
         assert!(dsnm.is_some(), "DSNM not found");
         if let Some((_, SubfieldValue::String(val))) = dsnm {
             assert_eq!(val, "US5PVDGD.000", "DSNM should be 'US5PVDGD.000'");
         }
 
         // UADT should be "20250703" (8 characters)
-        let uadt = group.iter().find(|(label, _)| label == "UADT");
+        let uadt = group.iter().find(|(label, _)| *label == "UADT");
         assert!(uadt.is_some(), "UADT not found");
         if let Some((_, value)) = uadt {
             if let SubfieldValue::String(val) = value {
@@ -860,7 +1338,7 @@ mod tests {
         }
 
         // ISDT should be "20250703" (8 characters)
-        let isdt = group.iter().find(|(label, _)| label == "ISDT");
+        let isdt = group.iter().find(|(label, _)| *label == "ISDT");
         assert!(isdt.is_some(), "ISDT not found");
         if let Some((_, value)) = isdt {
             if let SubfieldValue::String(val) = value {
@@ -872,7 +1350,7 @@ mod tests {
         }
 
         // STED should be "03.1" (4 characters after format override)
-        let sted = group.iter().find(|(label, _)| label == "STED");
+        let sted = group.iter().find(|(label, _)| *label == "STED");
         assert!(sted.is_some(), "STED not found");
         if let Some((_, value)) = sted {
             if let SubfieldValue::String(val) = value {
@@ -883,7 +1361,7 @@ mod tests {
         }
 
         // PRSP should be 1 (b11)
-        let prsp = group.iter().find(|(label, _)| label == "PRSP");
+        let prsp = group.iter().find(|(label, _)| *label == "PRSP");
         assert!(prsp.is_some(), "PRSP not found");
         if let Some((_, SubfieldValue::Integer(val))) = prsp {
             assert_eq!(*val, 1, "PRSP should be 1, got: {}", val);
@@ -892,7 +1370,7 @@ mod tests {
         }
 
         // PSDN should be empty string (optional, present but empty - just UT with no content)
-        let psdn = group.iter().find(|(label, _)| label == "PSDN");
+        let psdn = group.iter().find(|(label, _)| *label == "PSDN");
         assert!(psdn.is_some(), "PSDN not found");
         if let Some((_, value)) = psdn {
             if let SubfieldValue::String(val) = value {
@@ -904,12 +1382,12 @@ mod tests {
 
         // PRED is optional and may be empty string or omitted
         // In this file it appears to be empty
-        let pred = group.iter().find(|(label, _)| label == "PRED");
+        let pred = group.iter().find(|(label, _)| *label == "PRED");
         assert!(pred.is_some(), "PRED not found");
         // Just check it exists, value can vary
 
         // PROF should be 1 (b11) for ENC profile
-        let prof = group.iter().find(|(label, _)| label == "PROF");
+        let prof = group.iter().find(|(label, _)| *label == "PROF");
         assert!(prof.is_some(), "PROF not found");
         if let Some((_, SubfieldValue::Integer(val))) = prof {
             assert_eq!(*val, 1, "PROF should be 1 for ENC, got: {}", val);
@@ -918,7 +1396,7 @@ mod tests {
         }
 
         // AGEN should be 550 (b12) for NOAA
-        let agen = group.iter().find(|(label, _)| label == "AGEN");
+        let agen = group.iter().find(|(label, _)| *label == "AGEN");
         assert!(agen.is_some(), "AGEN not found");
         if let Some((_, SubfieldValue::Integer(val))) = agen {
             assert_eq!(*val, 550, "AGEN should be 550 for NOAA, got: {}", val);
@@ -927,7 +1405,7 @@ mod tests {
         }
 
         // COMT should be "Produced by NOAA"
-        let comt = group.iter().find(|(label, _)| label == "COMT");
+        let comt = group.iter().find(|(label, _)| *label == "COMT");
         assert!(comt.is_some(), "COMT not found");
         if let Some((_, value)) = comt {
             if let SubfieldValue::String(val) = value {
@@ -990,13 +1468,17 @@ mod tests {
 
         let mut ddr = DDR {
             field_defs: std::collections::HashMap::new(),
+            fallback_tags: HashSet::new(),
             schema: OverrideSchema::new(),
+            aall: LexicalLevel::Ascii,
+            nall: LexicalLevel::Ascii,
         };
         ddr.field_defs.insert("SG3D".to_string(), field_def);
 
         let field = Field {
             tag: "SG3D".to_string(),
             data: field_data.clone(),
+            span: None,
         };
 
         let result = ddr.parse_field_data(&field);
@@ -1022,9 +1504,9 @@ mod tests {
         for (i, group) in groups.iter().enumerate() {
             assert_eq!(group.len(), 3, "Each group should have 3 subfields");
 
-            let ycoo = group.iter().find(|(label, _)| label == "YCOO");
-            let xcoo = group.iter().find(|(label, _)| label == "XCOO");
-            let ve3d = group.iter().find(|(label, _)| label == "VE3D");
+            let ycoo = group.iter().find(|(label, _)| *label == "YCOO");
+            let xcoo = group.iter().find(|(label, _)| *label == "XCOO");
+            let ve3d = group.iter().find(|(label, _)| *label == "VE3D");
 
             assert!(ycoo.is_some(), "YCOO not found in group {}", i);
             assert!(xcoo.is_some(), "XCOO not found in group {}", i);
@@ -1056,9 +1538,9 @@ mod tests {
 
         // Verify specific expected values from manual hex parsing
         let group0 = &groups[0];
-        let ycoo0 = group0.iter().find(|(label, _)| label == "YCOO").unwrap();
-        let xcoo0 = group0.iter().find(|(label, _)| label == "XCOO").unwrap();
-        let ve3d0 = group0.iter().find(|(label, _)| label == "VE3D").unwrap();
+        let ycoo0 = group0.iter().find(|(label, _)| *label == "YCOO").unwrap();
+        let xcoo0 = group0.iter().find(|(label, _)| *label == "XCOO").unwrap();
+        let ve3d0 = group0.iter().find(|(label, _)| *label == "VE3D").unwrap();
 
         if let SubfieldValue::Integer(y) = ycoo0.1 {
             assert_eq!(y, 417637947, "group_0 YCOO");
@@ -1108,13 +1590,17 @@ mod tests {
 
         let mut ddr = DDR {
             field_defs: std::collections::HashMap::new(),
+            fallback_tags: HashSet::new(),
             schema: OverrideSchema::new(),
+            aall: LexicalLevel::Ascii,
+            nall: LexicalLevel::Ascii,
         };
         ddr.field_defs.insert("FOID".to_string(), field_def);
 
         let field = Field {
             tag: "FOID".to_string(),
             data: field_data.clone(),
+            span: None,
         };
 
         let result = ddr.parse_field_data(&field);
@@ -1135,19 +1621,19 @@ mod tests {
         }
 
         // Validate all fields are present and correct
-        let agen = group.iter().find(|(label, _)| label == "AGEN");
+        let agen = group.iter().find(|(label, _)| *label == "AGEN");
         assert!(agen.is_some(), "AGEN not found");
         if let Some((_, SubfieldValue::Integer(val))) = agen {
             assert_eq!(*val, 550, "AGEN should be 550 (NOAA)");
         }
 
-        let fidn = group.iter().find(|(label, _)| label == "FIDN");
+        let fidn = group.iter().find(|(label, _)| *label == "FIDN");
         assert!(fidn.is_some(), "FIDN not found");
         if let Some((_, SubfieldValue::UnsignedInteger(val))) = fidn {
             assert_eq!(*val, 450231236, "FIDN should be 450231236");
         }
 
-        let fids = group.iter().find(|(label, _)| label == "FIDS");
+        let fids = group.iter().find(|(label, _)| *label == "FIDS");
         assert!(fids.is_some(), "FIDS not found");
         if let Some((_, SubfieldValue::Integer(val))) = fids {
             assert_eq!(*val, 2018, "FIDS should be 2018");
@@ -1193,13 +1679,17 @@ mod tests {
 
         let mut ddr = DDR {
             field_defs: std::collections::HashMap::new(),
+            fallback_tags: HashSet::new(),
             schema: OverrideSchema::new(),
+            aall: LexicalLevel::Ascii,
+            nall: LexicalLevel::Ascii,
         };
         ddr.field_defs.insert("VRID".to_string(), field_def);
 
         let field = Field {
             tag: "VRID".to_string(),
             data: field_data.clone(),
+            span: None,
         };
 
         let result = ddr.parse_field_data(&field);
@@ -1220,13 +1710,13 @@ mod tests {
         }
 
         // Validate all fields are present and correct
-        let rcnm = group.iter().find(|(label, _)| label == "RCNM");
+        let rcnm = group.iter().find(|(label, _)| *label == "RCNM");
         assert!(rcnm.is_some(), "RCNM not found");
         if let Some((_, SubfieldValue::Integer(val))) = rcnm {
             assert_eq!(*val, 110, "RCNM should be 110");
         }
 
-        let rcid = group.iter().find(|(label, _)| label == "RCID");
+        let rcid = group.iter().find(|(label, _)| *label == "RCID");
         assert!(
             rcid.is_some(),
             "RCID not found - parser stopped early at 0x1E byte"
@@ -1238,13 +1728,13 @@ mod tests {
             );
         }
 
-        let rver = group.iter().find(|(label, _)| label == "RVER");
+        let rver = group.iter().find(|(label, _)| *label == "RVER");
         assert!(rver.is_some(), "RVER not found");
         if let Some((_, SubfieldValue::Integer(val))) = rver {
             assert_eq!(*val, 1, "RVER should be 1");
         }
 
-        let ruin = group.iter().find(|(label, _)| label == "RUIN");
+        let ruin = group.iter().find(|(label, _)| *label == "RUIN");
         assert!(ruin.is_some(), "RUIN not found");
         if let Some((_, SubfieldValue::Integer(val))) = ruin {
             assert_eq!(*val, 1, "RUIN should be 1");
@@ -1354,13 +1844,17 @@ mod tests {
 
         let mut ddr = DDR {
             field_defs: std::collections::HashMap::new(),
+            fallback_tags: HashSet::new(),
             schema: OverrideSchema::new(),
+            aall: LexicalLevel::Ascii,
+            nall: LexicalLevel::Ascii,
         };
         ddr.field_defs.insert("SG2D".to_string(), field_def);
 
         let field = Field {
             tag: "SG2D".to_string(),
             data: field_data.clone(),
+            span: None,
         };
 
         let result = ddr.parse_field_data(&field);
@@ -1380,8 +1874,8 @@ mod tests {
         for (i, group) in groups.iter().enumerate() {
             assert_eq!(group.len(), 2, "Each group should have 2 subfields");
 
-            let ycoo = group.iter().find(|(label, _)| label == "YCOO");
-            let xcoo = group.iter().find(|(label, _)| label == "XCOO");
+            let ycoo = group.iter().find(|(label, _)| *label == "YCOO");
+            let xcoo = group.iter().find(|(label, _)| *label == "XCOO");
 
             assert!(ycoo.is_some(), "YCOO not found in group {}", i);
             assert!(xcoo.is_some(), "XCOO not found in group {}", i);
@@ -1402,14 +1896,14 @@ mod tests {
                     let lat_degrees = (*y as f64) / 10_000_000.0;
                     let lon_degrees = (*x as f64) / 10_000_000.0;
 
-                    if lat_degrees < 30.0 || lat_degrees > 50.0 {
+                    if !(30.0..=50.0).contains(&lat_degrees) {
                         panic!(
                             "Group {} has invalid YCOO: {} ({:.7}°) - outside reasonable range for Maine chart [30°, 50°]",
                             i, y, lat_degrees
                         );
                     }
 
-                    if lon_degrees < -80.0 || lon_degrees > -60.0 {
+                    if !(-80.0..=-60.0).contains(&lon_degrees) {
                         panic!(
                             "Group {} has invalid XCOO: {} ({:.7}°) - outside reasonable range for Maine chart [-80°, -60°]",
                             i, x, lon_degrees
@@ -1421,8 +1915,8 @@ mod tests {
 
         // Verify group 0 specifically (first valid coordinate)
         let group0 = &groups[0];
-        let ycoo0 = group0.iter().find(|(label, _)| label == "YCOO").unwrap();
-        let xcoo0 = group0.iter().find(|(label, _)| label == "XCOO").unwrap();
+        let ycoo0 = group0.iter().find(|(label, _)| *label == "YCOO").unwrap();
+        let xcoo0 = group0.iter().find(|(label, _)| *label == "XCOO").unwrap();
 
         if let SubfieldValue::Integer(y) = ycoo0.1 {
             assert_eq!(y, 442323250, "group_0 YCOO = 44.2323250°");
@@ -1436,6 +1930,178 @@ mod tests {
         // are incorrectly treated as unit terminators, corrupting the coordinate stream
     }
 
+    #[test]
+    fn test_parse_sg3d_trailing_bytes_reported() {
+        // Two complete SG3D groups (12 bytes each) followed by 5 stray bytes
+        let mut field_data: Vec<u8> = Vec::new();
+        for (y, x, z) in [(1i32, 2i32, 3i32), (4, 5, 6)] {
+            field_data.extend_from_slice(&y.to_le_bytes());
+            field_data.extend_from_slice(&x.to_le_bytes());
+            field_data.extend_from_slice(&z.to_le_bytes());
+        }
+        field_data.extend_from_slice(&[0x01, 0x1E, 0x1F, 0x02, 0x03]);
+        field_data.push(0x1E);
+
+        let array_descriptor = "*YCOO!XCOO!VE3D".to_string();
+        let format_controls = "(3b24)".to_string();
+        let subfields = DDR::parse_format_controls(&array_descriptor, &format_controls);
+
+        let field_def = FieldDef {
+            tag: "SG3D".to_string(),
+            name: "3-D coordinate (sounding array) field".to_string(),
+            array_descriptor,
+            format_controls,
+            subfields,
+            is_repeating: true,
+        };
+        assert_eq!(field_def.group_size(), Some(12));
+
+        let mut ddr = DDR {
+            field_defs: std::collections::HashMap::new(),
+            fallback_tags: HashSet::new(),
+            schema: OverrideSchema::new(),
+            aall: LexicalLevel::Ascii,
+            nall: LexicalLevel::Ascii,
+        };
+        ddr.field_defs.insert("SG3D".to_string(), field_def);
+
+        let field = Field {
+            tag: "SG3D".to_string(),
+            data: field_data,
+            span: None,
+        };
+        let parsed = ddr.parse_field_data(&field).unwrap();
+
+        // Only complete groups are returned, the remainder is reported
+        assert_eq!(parsed.groups().len(), 2);
+        assert_eq!(parsed.trailing_bytes, 5);
+        assert!(!parsed.is_well_formed());
+        assert_eq!(ddr.trailing_bytes(&field), 5);
+        assert!(parsed.groups()[1]
+            .iter()
+            .all(|(_, v)| matches!(v, SubfieldValue::Integer(_))));
+    }
+
+    #[test]
+    fn test_decode_i32_groups() {
+        let ddr = DDR::canonical();
+        let mut data = Vec::new();
+        for (y, x, z) in [
+            (417_637_947i32, -713_835_163i32, 22i32),
+            (-1, 0x1E1E1E1E, 7),
+        ] {
+            data.extend(y.to_le_bytes());
+            data.extend(x.to_le_bytes());
+            data.extend(z.to_le_bytes());
+        }
+        data.extend([0x01, 0x02, 0x1E]);
+        let field = Field::new("SG3D".to_string(), data);
+
+        // Same values as subfield parsing, in the requested order
+        let parsed = ddr.parse_field_data(&field).unwrap();
+        let bulk = ddr
+            .decode_i32_groups(&field, ["YCOO", "XCOO", "VE3D"])
+            .unwrap();
+        assert_eq!(bulk.len(), parsed.groups().len());
+        for (values, group) in bulk.iter().zip(parsed.groups()) {
+            let subfields: Vec<i32> = group.iter().filter_map(|(_, v)| v.as_int()).collect();
+            assert_eq!(values.as_slice(), subfields);
+        }
+        assert_eq!(
+            ddr.decode_i32_groups(&field, ["VE3D", "YCOO"]),
+            Some(vec![[22, 417_637_947], [7, -1]])
+        );
+
+        // Unknown labels, other widths and variable-length fields fall back
+        assert_eq!(ddr.decode_i32_groups(&field, ["ZCOO"]), None);
+        let vrid = Field::new("VRID".to_string(), vec![110, 1, 0, 0, 0, 1, 0, 1, 0x1E]);
+        assert_eq!(ddr.decode_i32_groups(&vrid, ["RCNM"]), None);
+        assert_eq!(ddr.decode_i32_groups(&vrid, ["RCID"]), Some(vec![[1]]));
+        let dsid = Field::new("DSID".to_string(), vec![10, 0x1F, 0x1E]);
+        assert_eq!(ddr.decode_i32_groups(&dsid, ["RCNM"]), None);
+    }
+
+    #[test]
+    fn test_decode_context_reuses_vectors() {
+        let ddr = DDR::canonical();
+        let mut data = Vec::new();
+        for (rcnm, rcid) in [(120u8, 7u32), (130, 8), (130, 9)] {
+            data.push(rcnm);
+            data.extend(rcid.to_le_bytes());
+            data.extend([255, 255, 255, 255]);
+        }
+        data.push(0x1E);
+        let vrpt = Field::new("VRPT".to_string(), data);
+        let attv = Field::new("ATTV".to_string(), b"\x95\x00BUOY\x1F\x1E".to_vec());
+
+        let mut ctx = DecodeContext::new();
+        let parsed = ddr.parse_field_data_in(&vrpt, &mut ctx).unwrap();
+        let fresh = ddr.parse_field_data(&vrpt).unwrap();
+        assert_eq!(format!("{:?}", parsed), format!("{:?}", fresh));
+        let groups = parsed.groups.as_ptr();
+        ctx.recycle(parsed);
+
+        // The next field gets the recycled vectors, emptied
+        let parsed = ddr.parse_field_data_in(&attv, &mut ctx).unwrap();
+        assert_eq!(parsed.groups.as_ptr(), groups);
+        assert_eq!(parsed.groups().len(), 1);
+        assert_eq!(
+            parsed.get_value("ATVL").and_then(|v| v.as_str()),
+            Some("BUOY")
+        );
+        ctx.recycle(parsed);
+        let parsed = ddr.parse_field_data_in(&vrpt, &mut ctx).unwrap();
+        assert_eq!(format!("{:?}", parsed), format!("{:?}", fresh));
+    }
+
+    #[test]
+    fn test_parsed_field_into_owned() {
+        let ddr = DDR::canonical();
+        let owned = {
+            let attv = Field::new("ATTV".to_string(), b"\x95\x00BUOY\x1F\x1E".to_vec());
+            ddr.parse_field_data(&attv).unwrap().into_owned()
+        };
+        assert_eq!(owned.tag, "ATTV");
+        assert_eq!(owned.get_value("ATTL"), Some(&SubfieldValue::Integer(149)));
+        assert_eq!(
+            owned.get_value("ATVL"),
+            Some(&SubfieldValue::String("BUOY".into()))
+        );
+        assert_eq!(owned.trailing_bytes, 0);
+    }
+
+    #[test]
+    fn test_parse_attribute_lexical_levels() {
+        let mut ddr = DDR::canonical();
+        // ATTL 116 (OBJNAM) "Smøgen" in Latin-1, 301 (NOBJNM) "Ἁθῆναι" in UCS-2
+        let attf = Field::new("ATTF".to_string(), b"\x74\x00Sm\xf8gen\x1f\x1e".to_vec());
+        let mut natf = vec![0x2D, 0x01];
+        for unit in "Ἁθῆναι".encode_utf16() {
+            natf.extend(unit.to_le_bytes());
+        }
+        natf.extend([0x1F, 0x00, 0x2E, 0x01, 0x41, 0x00, 0x1F, 0x00, 0x1E]);
+        let natf = Field::new("NATF".to_string(), natf);
+
+        // At the default level 0 the Latin-1 byte is not valid text
+        let parsed = ddr.parse_field_data(&attf).unwrap();
+        assert_eq!(parsed.get_value("ATVL").and_then(|v| v.as_str()), None);
+
+        ddr.set_lexical_levels(LexicalLevel::Latin1, LexicalLevel::Ucs2);
+        let parsed = ddr.parse_field_data(&attf).unwrap();
+        assert_eq!(
+            parsed.get_value("ATVL").and_then(|v| v.as_str()),
+            Some("Smøgen")
+        );
+        let parsed = ddr.parse_field_data(&natf).unwrap();
+        let values: Vec<_> = parsed
+            .groups()
+            .iter()
+            .map(|group| group[1].1.as_str())
+            .collect();
+        assert_eq!(values, [Some("Ἁθῆναι"), Some("A")]);
+        assert_eq!(ddr.group_ranges(&natf).unwrap(), [0..16, 16..22]);
+    }
+
     #[test]
     fn test_parse_fspt_with_b40_bitstring() {
         // FSPT field with NAME as B(40) bitstring (40 bits = 5 bytes)
@@ -1483,13 +2149,17 @@ mod tests {
 
         let mut ddr = DDR {
             field_defs: std::collections::HashMap::new(),
+            fallback_tags: HashSet::new(),
             schema: OverrideSchema::new(),
+            aall: LexicalLevel::Ascii,
+            nall: LexicalLevel::Ascii,
         };
         ddr.field_defs.insert("FSPT".to_string(), field_def);
 
         let field = Field {
             tag: "FSPT".to_string(),
             data: field_data.clone(),
+            span: None,
         };
 
         let result = ddr.parse_field_data(&field);
@@ -1510,7 +2180,7 @@ mod tests {
         }
 
         // Validate NAME is exactly 5 bytes
-        let name = group.iter().find(|(label, _)| label == "NAME");
+        let name = group.iter().find(|(label, _)| *label == "NAME");
         assert!(name.is_some(), "NAME not found");
         if let Some((_, SubfieldValue::Bytes(bytes))) = name {
             assert_eq!(
@@ -1525,10 +2195,60 @@ mod tests {
         }
 
         // Validate other fields
-        let ornt = group.iter().find(|(label, _)| label == "ORNT");
+        let ornt = group.iter().find(|(label, _)| *label == "ORNT");
         assert!(ornt.is_some(), "ORNT not found");
         if let Some((_, SubfieldValue::Integer(val))) = ornt {
             assert_eq!(*val, 1, "ORNT should be 1");
         }
     }
+
+    #[test]
+    fn test_canonical_fallback() {
+        // A DDR that defines FOID itself and FRID without any subfields
+        let schema = OverrideSchema::new();
+        let mut field_defs = HashMap::new();
+        let foid = FieldDef {
+            tag: "FOID".to_string(),
+            name: "Producer's FOID".to_string(),
+            array_descriptor: "AGEN!FIDN!FIDS".to_string(),
+            format_controls: "(b12,b14,b12)".to_string(),
+            subfields: DDR::parse_format_controls("AGEN!FIDN!FIDS", "(b12,b14,b12)"),
+            is_repeating: false,
+        };
+        field_defs.insert("FOID".to_string(), foid);
+        let damaged = FieldDef {
+            tag: "FRID".to_string(),
+            name: String::new(),
+            array_descriptor: String::new(),
+            format_controls: String::new(),
+            subfields: Vec::new(),
+            is_repeating: false,
+        };
+        field_defs.insert("FRID".to_string(), damaged);
+
+        let added = DDR::fill_canonical(&schema, &mut field_defs);
+        assert!(added.contains("FRID"));
+        assert!(added.contains("SG2D"));
+        assert!(!added.contains("FOID"));
+        assert_eq!(field_defs["FOID"].name, "Producer's FOID");
+
+        // FRID decodes with the built-in definition:
+        // RCNM 100, RCID 7, PRIM 2, GRUP 1, OBJL 43 (DEPCNT), RVER 1, RUIN 1
+        let ddr = DDR::canonical();
+        assert!(ddr.is_fallback("FRID"));
+        let field = Field {
+            tag: "FRID".to_string(),
+            data: vec![100, 7, 0, 0, 0, 2, 1, 43, 0, 1, 0, 1, 0x1e],
+            span: None,
+        };
+        let parsed = ddr.parse_field_data(&field).unwrap();
+        assert!(matches!(
+            parsed.get_value("OBJL"),
+            Some(SubfieldValue::Integer(43))
+        ));
+        assert!(matches!(
+            parsed.get_value("RUIN"),
+            Some(SubfieldValue::Integer(1))
+        ));
+    }
 }
@@ -0,0 +1,285 @@
+//! Differences between field definitions
+//!
+//! Producers don't always define S-57 fields as the standard does: a
+//! subfield with another format or width, a label missing, or a field whose
+//! definition is unusable so the parser quietly falls back on the built-in
+//! one. `DDR::diff` lists where two DDRs part ways, e.g. a producer's cell
+//! against a known-good one or against `DDR::canonical()`.
+
+use crate::ddr::{FieldDef, FormatType, SubfieldDef, DDR};
+use std::collections::BTreeSet;
+use std::fmt;
+
+/// DdrDifference: One way two DDRs define their fields differently
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DdrDifference {
+    /// A field only the first DDR defines
+    OnlyInFirst { tag: String },
+    /// A field only the second DDR defines
+    OnlyInSecond { tag: String },
+    /// A field one DDR defines itself and the other gets built in
+    BuiltIn {
+        tag: String,
+        first: bool,
+        second: bool,
+    },
+    /// A field repeating in one DDR and not the other
+    Repeating {
+        tag: String,
+        first: bool,
+        second: bool,
+    },
+    /// A field's subfield labels, in order
+    Labels {
+        tag: String,
+        first: Vec<String>,
+        second: Vec<String>,
+    },
+    /// A subfield both DDRs define, with a different format or width
+    Subfield {
+        tag: String,
+        label: String,
+        first: SubfieldDef,
+        second: SubfieldDef,
+    },
+}
+
+impl DdrDifference {
+    /// Tag of the field that differs
+    pub fn tag(&self) -> &str {
+        match self {
+            Self::OnlyInFirst { tag }
+            | Self::OnlyInSecond { tag }
+            | Self::BuiltIn { tag, .. }
+            | Self::Repeating { tag, .. }
+            | Self::Labels { tag, .. }
+            | Self::Subfield { tag, .. } => tag,
+        }
+    }
+}
+
+impl fmt::Display for DdrDifference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let defined = |built_in: bool| if built_in { "built in" } else { "defined" };
+        let repeating = |repeating: bool| if repeating { "repeating" } else { "single" };
+        match self {
+            Self::OnlyInFirst { tag } => write!(f, "{}: only in the first DDR", tag),
+            Self::OnlyInSecond { tag } => write!(f, "{}: only in the second DDR", tag),
+            Self::BuiltIn { tag, first, second } => {
+                write!(f, "{}: {} vs {}", tag, defined(*first), defined(*second))
+            }
+            Self::Repeating { tag, first, second } => {
+                write!(
+                    f,
+                    "{}: {} vs {}",
+                    tag,
+                    repeating(*first),
+                    repeating(*second)
+                )
+            }
+            Self::Labels { tag, first, second } => {
+                write!(f, "{}: {} vs {}", tag, first.join("!"), second.join("!"))
+            }
+            Self::Subfield {
+                tag,
+                label,
+                first,
+                second,
+            } => write!(
+                f,
+                "{}.{}: {} vs {}",
+                tag,
+                label,
+                first.format_spec(),
+                second.format_spec()
+            ),
+        }
+    }
+}
+
+impl SubfieldDef {
+    /// The subfield's format as an ISO 8211 format control, e.g. "b14",
+    /// "A(8)" or "B(40)"
+    ///
+    /// Rebuilt from the parsed format and width, so equivalent spellings in
+    /// different DDRs come out the same.
+    pub fn format_spec(&self) -> String {
+        let sized = |code: &str, width: Option<usize>| match width {
+            Some(width) => format!("{}({})", code, width),
+            None => code.to_string(),
+        };
+        match self.format {
+            FormatType::BinaryUnsigned => format!("b1{}", self.width.unwrap_or(0)),
+            FormatType::BinarySigned => format!("b2{}", self.width.unwrap_or(0)),
+            FormatType::Ascii => "A".to_string(),
+            FormatType::AsciiFixed => sized("A", self.width),
+            FormatType::IntegerAscii => "I".to_string(),
+            FormatType::IntegerAsciiFixed => sized("I", self.width),
+            FormatType::RealBinary => sized("R", self.width),
+            FormatType::BitString => sized("B", self.width.map(|bytes| bytes * 8)),
+            FormatType::Mixed => "C".to_string(),
+        }
+    }
+}
+
+impl DDR {
+    /// Differences between this DDR's field definitions and `other`'s
+    ///
+    /// Definitions are compared as parsed, after the built-in fallbacks
+    /// and format overrides, so the result shows what decoding will do
+    /// differently. Sorted by tag; ISO 8211 control fields (0000, 0001)
+    /// are skipped.
+    pub fn diff(&self, other: &DDR) -> Vec<DdrDifference> {
+        let tags: BTreeSet<&String> = self
+            .field_defs()
+            .keys()
+            .chain(other.field_defs().keys())
+            .filter(|tag| !tag.starts_with('0'))
+            .collect();
+        let mut differences = Vec::new();
+        for tag in tags {
+            let tag = tag.clone();
+            match (self.get_field_def(&tag), other.get_field_def(&tag)) {
+                (Some(first), Some(second)) => {
+                    let (built_first, built_second) =
+                        (self.is_fallback(&tag), other.is_fallback(&tag));
+                    if built_first != built_second {
+                        differences.push(DdrDifference::BuiltIn {
+                            tag: tag.clone(),
+                            first: built_first,
+                            second: built_second,
+                        });
+                    }
+                    compare_fields(&tag, first, second, &mut differences);
+                }
+                (Some(_), None) => differences.push(DdrDifference::OnlyInFirst { tag }),
+                (None, Some(_)) => differences.push(DdrDifference::OnlyInSecond { tag }),
+                (None, None) => {}
+            }
+        }
+        differences
+    }
+}
+
+/// Push the ways two definitions of one field differ
+fn compare_fields(
+    tag: &str,
+    first: &FieldDef,
+    second: &FieldDef,
+    differences: &mut Vec<DdrDifference>,
+) {
+    if first.is_repeating != second.is_repeating {
+        differences.push(DdrDifference::Repeating {
+            tag: tag.to_string(),
+            first: first.is_repeating,
+            second: second.is_repeating,
+        });
+    }
+    let labels =
+        |def: &FieldDef| -> Vec<String> { def.subfields.iter().map(|s| s.label.clone()).collect() };
+    if labels(first) != labels(second) {
+        differences.push(DdrDifference::Labels {
+            tag: tag.to_string(),
+            first: labels(first),
+            second: labels(second),
+        });
+    }
+    for subfield in &first.subfields {
+        let Some(other) = second.subfields.iter().find(|s| s.label == subfield.label) else {
+            continue;
+        };
+        if (subfield.format, subfield.width) != (other.format, other.width) {
+            differences.push(DdrDifference::Subfield {
+                tag: tag.to_string(),
+                label: subfield.label.clone(),
+                first: subfield.clone(),
+                second: other.clone(),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::iso8211::{Field, Leader, Record};
+
+    /// A DDR record defining `fields` as (tag, array descriptor, format controls)
+    fn ddr(fields: &[(&str, &str, &str)]) -> DDR {
+        let mut record_fields = vec![
+            Field::new("0000".to_string(), b"0000;&   \x1e".to_vec()),
+            Field::new("0001".to_string(), b"0100;&   \x1e".to_vec()),
+        ];
+        for (tag, descriptor, formats) in fields {
+            let mut data = b"1600;&   ".to_vec();
+            data.extend_from_slice(tag.as_bytes());
+            data.push(0x1F);
+            data.extend_from_slice(descriptor.as_bytes());
+            data.push(0x1F);
+            data.extend_from_slice(formats.as_bytes());
+            data.push(0x1E);
+            record_fields.push(Field::new(tag.to_string(), data));
+        }
+        let record = Record {
+            leader: Leader::new('L'),
+            ..Record::from_fields(record_fields)
+        };
+        DDR::parse(&record).unwrap()
+    }
+
+    #[test]
+    fn test_ddr_diff() {
+        let standard = ddr(&[
+            ("VRID", "RCNM!RCID!RVER!RUIN", "(b11,b14,b12,b11)"),
+            ("SG2D", "*YCOO!XCOO", "(2b24)"),
+        ]);
+        assert!(standard.diff(&standard).is_empty());
+
+        // RVER widened, SG2D not repeating, and a producer field
+        let producer = ddr(&[
+            ("VRID", "RCNM!RCID!RVER!RUIN", "(b11,b14,b14,b11)"),
+            ("SG2D", "YCOO!XCOO", "(b24,b24)"),
+            ("XTRA", "NOTE", "(A)"),
+        ]);
+        let differences = standard.diff(&producer);
+        let text: Vec<String> = differences.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            text,
+            [
+                "SG2D: repeating vs single",
+                "VRID.RVER: b12 vs b14",
+                "XTRA: only in the second DDR",
+            ]
+        );
+        assert_eq!(differences[1].tag(), "VRID");
+
+        // Against the built-in definitions, fields a DDR leaves out show up
+        // as built in on one side only
+        let canonical = DDR::canonical();
+        let from_canonical = canonical.diff(&standard);
+        assert!(from_canonical.contains(&DdrDifference::BuiltIn {
+            tag: "VRID".to_string(),
+            first: true,
+            second: false,
+        }));
+        assert!(!from_canonical
+            .iter()
+            .any(|d| matches!(d, DdrDifference::BuiltIn { tag, .. } if tag == "FRID")));
+    }
+
+    #[test]
+    fn test_format_spec() {
+        let spec = |format, width| {
+            SubfieldDef {
+                label: "X".to_string(),
+                format,
+                width,
+            }
+            .format_spec()
+        };
+        assert_eq!(spec(FormatType::BinarySigned, Some(4)), "b24");
+        assert_eq!(spec(FormatType::AsciiFixed, Some(8)), "A(8)");
+        assert_eq!(spec(FormatType::Ascii, None), "A");
+        assert_eq!(spec(FormatType::BitString, Some(5)), "B(40)");
+    }
+}
@@ -0,0 +1,40 @@
+//! Diagnostic macros
+//!
+//! With the `tracing` feature, parse diagnostics are `tracing` events and
+//! records and fields are parsed inside spans, so a subscriber can time and
+//! filter them. Without it they are plain `log` records and spans compile
+//! to nothing. `tracing` events still reach a `log` logger when no
+//! subscriber is installed.
+
+/// A trace-level diagnostic
+macro_rules! trace {
+    ($($arg:tt)+) => {{
+        #[cfg(feature = "tracing")]
+        tracing::trace!($($arg)+);
+        #[cfg(not(feature = "tracing"))]
+        log::trace!($($arg)+);
+    }};
+}
+
+/// A debug-level diagnostic
+macro_rules! debug {
+    ($($arg:tt)+) => {{
+        #[cfg(feature = "tracing")]
+        tracing::debug!($($arg)+);
+        #[cfg(not(feature = "tracing"))]
+        log::debug!($($arg)+);
+    }};
+}
+
+/// Enter a trace-level span for the rest of the enclosing block
+///
+/// Takes a span name and `tracing` field syntax, e.g.
+/// `span!("record", index = 3, offset)`.
+macro_rules! span {
+    ($name:literal $(, $($field:tt)*)?) => {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::trace_span!($name $(, $($field)*)?).entered();
+    };
+}
+
+pub(crate) use {debug, span, trace};
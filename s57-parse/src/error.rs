@@ -48,6 +48,9 @@ pub enum ParseErrorKind {
         area_len: usize,
     },
 
+    #[error("invalid update: {0}")]
+    InvalidUpdate(String),
+
     #[error("unexpected end of file")]
     UnexpectedEof,
 
@@ -0,0 +1,157 @@
+//! Deterministic text for subfield values
+//!
+//! The YAML and JSON printers share these so the same value always prints
+//! the same way: reals with a fixed number of significant digits (never
+//! `{:?}`'s shortest round-trip form, which changes with the value's
+//! history), bytes as one lowercase hex string, and strings double-quoted
+//! with every character that YAML or JSON can't hold raw escaped. A quoted
+//! string is valid as both a YAML double-quoted scalar and a JSON string.
+
+use crate::ddr::SubfieldValue;
+use std::fmt::Write;
+
+/// Significant digits written for a real
+pub const REAL_SIGNIFICANT_DIGITS: usize = 10;
+
+/// A finite real to `REAL_SIGNIFICANT_DIGITS` significant digits
+///
+/// Trailing zeros are dropped but a decimal point is always kept ("3.0"),
+/// so the value reads back as a real. Magnitudes below 1e-6 or from 1e21
+/// use an exponent ("1.5e-9"). None for NaN and infinities, which YAML and
+/// JSON spell differently.
+pub fn real(value: f64) -> Option<String> {
+    if !value.is_finite() {
+        return None;
+    }
+    if value == 0.0 {
+        return Some("0.0".to_string());
+    }
+    let scientific = format!("{:.*e}", REAL_SIGNIFICANT_DIGITS - 1, value);
+    let (mantissa, exponent) = scientific.split_once('e')?;
+    let exponent: i32 = exponent.parse().ok()?;
+    let (sign, mantissa) = match mantissa.strip_prefix('-') {
+        Some(magnitude) => ("-", magnitude),
+        None => ("", mantissa),
+    };
+    let digits: String = mantissa.chars().filter(char::is_ascii_digit).collect();
+    let digits = digits.trim_end_matches('0');
+
+    let text = if !(-7..21).contains(&exponent) {
+        let (first, rest) = digits.split_at(1);
+        let rest = if rest.is_empty() { "0" } else { rest };
+        format!("{}.{}e{}", first, rest, exponent)
+    } else if exponent < 0 {
+        let zeros = "0".repeat((-exponent - 1) as usize);
+        format!("0.{}{}", zeros, digits)
+    } else {
+        let point = exponent as usize + 1;
+        if point >= digits.len() {
+            format!("{}{}.0", digits, "0".repeat(point - digits.len()))
+        } else {
+            format!("{}.{}", &digits[..point], &digits[point..])
+        }
+    };
+    Some(format!("{}{}", sign, text))
+}
+
+/// Bytes as one lowercase hex string, two digits per byte
+pub fn hex(bytes: &[u8]) -> String {
+    let mut text = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        let _ = write!(text, "{:02x}", byte);
+    }
+    text
+}
+
+/// A string double-quoted, with quotes, backslashes and control, line
+/// separator and non-character code points escaped
+pub fn quote(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            '\u{0}'..='\u{1F}'
+            | '\u{7F}'..='\u{9F}'
+            | '\u{2028}'
+            | '\u{2029}'
+            | '\u{FEFF}'
+            | '\u{FFFE}'
+            | '\u{FFFF}' => {
+                let _ = write!(quoted, "\\u{:04x}", c as u32);
+            }
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// A subfield value as a YAML scalar
+pub fn yaml_value(value: &SubfieldValue) -> String {
+    match value {
+        SubfieldValue::Null => "null".to_string(),
+        SubfieldValue::Integer(i) => i.to_string(),
+        SubfieldValue::UnsignedInteger(u) => u.to_string(),
+        SubfieldValue::Real(f) => real(*f).unwrap_or_else(|| {
+            match (f.is_nan(), f.is_sign_negative()) {
+                (true, _) => ".nan",
+                (false, false) => ".inf",
+                (false, true) => "-.inf",
+            }
+            .to_string()
+        }),
+        SubfieldValue::String(s) => quote(s),
+        SubfieldValue::Bytes(b) => quote(&hex(b)),
+    }
+}
+
+/// A subfield value as JSON text; non-finite reals are null
+pub fn json_value(value: &SubfieldValue) -> String {
+    match value {
+        SubfieldValue::Real(f) => real(*f).unwrap_or_else(|| "null".to_string()),
+        _ => yaml_value(value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_real() {
+        assert_eq!(real(0.0).unwrap(), "0.0");
+        assert_eq!(real(-0.0).unwrap(), "0.0");
+        assert_eq!(real(3.0).unwrap(), "3.0");
+        assert_eq!(real(-70.25).unwrap(), "-70.25");
+        assert_eq!(real(1500.0).unwrap(), "1500.0");
+        assert_eq!(real(0.000123).unwrap(), "0.000123");
+        assert_eq!(real(1.5e-9).unwrap(), "1.5e-9");
+        assert_eq!(real(2e21).unwrap(), "2.0e21");
+        // An R(4) value keeps its float noise, cut at a fixed width
+        assert_eq!(real(0.1f32 as f64).unwrap(), "0.1000000015");
+        assert_eq!(real(1.0 / 3.0).unwrap(), "0.3333333333");
+        assert_eq!(real(f64::NAN), None);
+    }
+
+    #[test]
+    fn test_subfield_values() {
+        assert_eq!(hex(&[0x0A, 0xFF, 0x00]), "0aff00");
+        assert_eq!(
+            quote("Say \"hi\"\\\n\u{1}\u{2028}ø"),
+            r#""Say \"hi\"\\\n\u0001\u2028ø""#
+        );
+        assert_eq!(
+            yaml_value(&SubfieldValue::Bytes(vec![1, 2].into())),
+            "\"0102\""
+        );
+        assert_eq!(yaml_value(&SubfieldValue::Real(f64::NEG_INFINITY)), "-.inf");
+        assert_eq!(json_value(&SubfieldValue::Real(f64::NAN)), "null");
+        assert_eq!(json_value(&SubfieldValue::Integer(-4)), "-4");
+        assert_eq!(yaml_value(&SubfieldValue::Null), "null");
+    }
+}
@@ -47,8 +47,13 @@ pub fn interpret_field_tag(tag: &str) -> &'static str {
         "ATTV" => "Vector Record Attribute",
         "VRPC" => "Vector Record Pointer Control",
         "VRPT" => "Vector Record Pointer",
+        "SGCC" => "Coordinate Control",
         "SG2D" => "2D Coordinate (Geometry)",
         "SG3D" => "3D Coordinate (Geometry)",
+        "ARCC" => "Arc/Curve Definition",
+        "AR2D" => "Arc Coordinates",
+        "EL2D" => "Ellipse Coordinates",
+        "CT2D" => "Curve Coordinates",
         _ => "Unknown field",
     }
 }
@@ -283,3 +288,21 @@ pub fn interpret_orientation(ornt: u8) -> &'static str {
         _ => "Unknown orientation",
     }
 }
+
+/// Interpret quality of position (QUAPOS spatial attribute)
+pub fn interpret_quality_of_position(quapos: u8) -> &'static str {
+    match quapos {
+        1 => "Surveyed",
+        2 => "Unsurveyed",
+        3 => "Inadequately surveyed",
+        4 => "Approximated",
+        5 => "Position doubtful",
+        6 => "Unreliable",
+        7 => "Reported (not surveyed)",
+        8 => "Reported (not confirmed)",
+        9 => "Estimated",
+        10 => "Precisely known",
+        11 => "Calculated",
+        _ => "Unknown quality",
+    }
+}
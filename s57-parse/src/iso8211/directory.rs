@@ -1,6 +1,6 @@
 use super::Leader;
+use crate::diag::trace;
 use crate::error::{ParseError, ParseErrorKind, Result};
-use log::trace;
 
 const FIELD_TERMINATOR: u8 = 0x1E; // ASCII 30 (1/14)
 
@@ -9,12 +9,14 @@ const FIELD_TERMINATOR: u8 = 0x1E; // ASCII 30 (1/14)
 /// The directory contains entries that map field tags to their
 /// positions and lengths in the field area.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Directory {
     pub entries: Vec<DirectoryEntry>,
 }
 
 /// A single directory entry
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DirectoryEntry {
     /// Field tag (4 characters for S-57)
     pub tag: String,
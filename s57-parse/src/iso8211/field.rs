@@ -1,19 +1,38 @@
+use super::Span;
+
+/// Decoded 0000 field control field: (field_controls, external_title, tag_pairs)
+pub type FieldControlField = (String, String, Vec<(String, String)>);
+
 /// ISO 8211 Field
 ///
 /// Represents a single field from the field area.
 /// Fields are identified by their tag and contain raw byte data.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Field {
     /// Field tag (e.g., "DSID", "FRID", "VRID")
     pub tag: String,
     /// Raw field data (including any subfield structure)
     pub data: Vec<u8>,
+    /// Where the field was read from; None for fields built in memory
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub span: Option<Span>,
 }
 
 impl Field {
-    /// Create a new field
+    /// Create a new field, not tied to any file position
     pub fn new(tag: String, data: Vec<u8>) -> Self {
-        Field { tag, data }
+        Field {
+            tag,
+            data,
+            span: None,
+        }
+    }
+
+    /// Byte span of the field's data, field terminator included, in the
+    /// file it was parsed from
+    pub fn span(&self) -> Option<Span> {
+        self.span
     }
 
     /// Get field data as a string (if it's ASCII text)
@@ -34,7 +53,7 @@ impl Field {
     /// Structure: Field controls | External file title | UT | List of field tag pairs | FT
     ///
     /// Returns (field_controls, external_title, tag_pairs)
-    pub fn parse_field_control_field(&self) -> Option<(String, String, Vec<(String, String)>)> {
+    pub fn parse_field_control_field(&self) -> Option<FieldControlField> {
         if self.tag != "0000" {
             return None;
         }
@@ -140,6 +159,13 @@ impl Field {
     }
 }
 
+/// A field from its tag and data, as `Field::new`
+impl From<(&str, Vec<u8>)> for Field {
+    fn from((tag, data): (&str, Vec<u8>)) -> Self {
+        Field::new(tag.to_string(), data)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,11 +1,12 @@
+use crate::diag::trace;
 use crate::error::{ParseError, ParseErrorKind, Result};
-use log::trace;
 
 /// ISO 8211 Record Leader (24 bytes fixed length)
 ///
 /// The leader contains metadata about the record structure.
 /// See ISO 8211 specification section 3.7.2
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Leader {
     /// Total length of the record in bytes (positions 0-4)
     pub record_length: u32,
@@ -36,6 +37,30 @@ pub struct Leader {
 }
 
 impl Leader {
+    /// Leader for a record built in memory: 'L' for a DDR, 'D' for a data
+    /// record
+    ///
+    /// Lengths and entry map sizes are left at their smallest;
+    /// `write_record` works out the real ones.
+    pub fn new(leader_identifier: char) -> Self {
+        let ddr = leader_identifier == 'L';
+        Leader {
+            record_length: 0,
+            interchange_level: if ddr { '3' } else { ' ' },
+            leader_identifier,
+            inline_code_extension_indicator: if ddr { 'E' } else { ' ' },
+            version_number: if ddr { '1' } else { ' ' },
+            application_indicator: ' ',
+            field_control_length: if ddr { "09" } else { "  " }.to_string(),
+            base_address_of_field_area: 0,
+            extended_character_set: " ! ".to_string(),
+            size_of_field_length_field: 1,
+            size_of_field_position_field: 1,
+            reserved: '0',
+            size_of_field_tag: 4,
+        }
+    }
+
     /// Parse a 24-byte leader
     pub fn parse(data: &[u8]) -> Result<Self> {
         if data.len() < 24 {
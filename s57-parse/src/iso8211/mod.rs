@@ -8,20 +8,99 @@
 mod directory;
 mod field;
 mod leader;
+mod reader;
+mod writer;
 
 pub use directory::{Directory, DirectoryEntry};
 pub use field::Field;
 pub use leader::Leader;
+pub use reader::RecordReader;
+pub use writer::{write_file, write_record};
 
+use crate::diag::{debug, span, trace};
 use crate::error::{ParseError, ParseErrorKind, Result};
-use log::{debug, trace};
+use std::ops::Range;
 
 /// ISO 8211 logical record
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Record {
     pub leader: Leader,
     pub directory: Directory,
     pub fields: Vec<Field>,
+    /// Where the record was read from; None for records built in memory
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub span: Option<Span>,
+    /// UPDN of the update file that inserted or last changed the record;
+    /// None for records of the base cell
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub update: Option<u32>,
+}
+
+impl Record {
+    /// A data record holding `fields`, built in memory
+    ///
+    /// e.g. `Record::from_fields([("FRID", frid), ("FOID", foid)])`. The
+    /// directory is left empty for `write_record` to lay out.
+    pub fn from_fields<F: Into<Field>>(fields: impl IntoIterator<Item = F>) -> Self {
+        Record {
+            leader: Leader::new('D'),
+            directory: Directory {
+                entries: Vec::new(),
+            },
+            fields: fields.into_iter().map(Into::into).collect(),
+            span: None,
+            update: None,
+        }
+    }
+
+    /// Byte span of the whole record, leader to last field terminator, in
+    /// the file it was parsed from
+    pub fn span(&self) -> Option<Span> {
+        self.span
+    }
+
+    /// The field whose bytes contain file offset `offset`
+    pub fn field_at(&self, offset: usize) -> Option<&Field> {
+        self.fields
+            .iter()
+            .find(|field| field.span.is_some_and(|span| span.contains(offset)))
+    }
+}
+
+/// Span: Byte range of a record or field in its file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Span {
+    /// Offset of the first byte from the start of the file
+    pub offset: usize,
+    /// Length in bytes
+    pub len: usize,
+}
+
+impl Span {
+    /// Offset just past the last byte
+    pub fn end(&self) -> usize {
+        self.offset + self.len
+    }
+
+    /// The span as a range of file offsets
+    pub fn range(&self) -> Range<usize> {
+        self.offset..self.end()
+    }
+
+    /// True if file offset `offset` lies within the span
+    pub fn contains(&self, offset: usize) -> bool {
+        self.range().contains(&offset)
+    }
+}
+
+/// Record that holds file offset `offset`, with its index
+pub fn record_at(records: &[Record], offset: usize) -> Option<(usize, &Record)> {
+    records
+        .iter()
+        .enumerate()
+        .find(|(_, record)| record.span.is_some_and(|span| span.contains(offset)))
 }
 
 /// Parse an entire ISO 8211 file
@@ -31,6 +110,7 @@ pub fn parse_file(data: &[u8]) -> Result<Vec<Record>> {
     let mut offset = 0;
 
     while offset < data.len() {
+        span!("record", index = records.len(), offset);
         trace!("Parsing record at offset {}", offset);
         let (record, bytes_read) = parse_record(&data[offset..], offset)?;
         debug!(
@@ -52,6 +132,64 @@ pub fn parse_file(data: &[u8]) -> Result<Vec<Record>> {
     Ok(records)
 }
 
+/// Parse an ISO 8211 file, skipping records that don't parse
+///
+/// Where `parse_file` stops at the first malformed record, this records the
+/// error and resynchronizes on the next offset that holds a whole, parseable
+/// record, so one corrupt record (or a corrupt record length) costs only
+/// that record. Returns the records recovered and the errors met, in file
+/// order; a damaged tail that holds no further record ends with one error.
+pub fn parse_file_lossy(data: &[u8]) -> (Vec<Record>, Vec<ParseError>) {
+    debug!(
+        "Parsing ISO 8211 file leniently, total size: {} bytes",
+        data.len()
+    );
+    let mut records = Vec::new();
+    let mut errors = Vec::new();
+    let mut offset = 0;
+
+    while offset < data.len() {
+        span!("record", index = records.len(), offset);
+        match parse_record(&data[offset..], offset) {
+            Ok((record, bytes_read)) => {
+                records.push(record);
+                offset += bytes_read;
+            }
+            Err(e) => {
+                debug!("Skipping malformed record at offset {}: {}", offset, e);
+                errors.push(e);
+                match resync(data, offset + 1) {
+                    Some(next) => offset = next,
+                    None => break,
+                }
+            }
+        }
+    }
+
+    debug!(
+        "Recovered {} records, {} errors",
+        records.len(),
+        errors.len()
+    );
+    (records, errors)
+}
+
+/// First offset from `from` where a whole record parses
+///
+/// Cheap checks on the leader (digits where the lengths go, a known leader
+/// identifier) rule out almost every offset before a full parse is tried.
+fn resync(data: &[u8], from: usize) -> Option<usize> {
+    (from..data.len().saturating_sub(23)).find(|&offset| {
+        let leader = &data[offset..offset + 24];
+        let digits = |range: Range<usize>| leader[range].iter().all(u8::is_ascii_digit);
+        digits(0..5)
+            && matches!(leader[6], b'L' | b'D' | b'R')
+            && digits(12..17)
+            && digits(20..24)
+            && parse_record(&data[offset..], offset).is_ok()
+    })
+}
+
 /// Parse a single ISO 8211 record
 fn parse_record(data: &[u8], file_offset: usize) -> Result<(Record, usize)> {
     if data.len() < 24 {
@@ -59,7 +197,8 @@ fn parse_record(data: &[u8], file_offset: usize) -> Result<(Record, usize)> {
     }
 
     // Parse leader (24 bytes)
-    let leader = Leader::parse(&data[0..24])?;
+    let leader =
+        Leader::parse(&data[0..24]).map_err(|e| ParseError::at(e.kind, file_offset + e.offset))?;
     let record_length = leader.record_length as usize;
 
     if data.len() < record_length {
@@ -74,8 +213,19 @@ fn parse_record(data: &[u8], file_offset: usize) -> Result<(Record, usize)> {
 
     let record_data = &data[0..record_length];
 
-    // Parse directory
+    // The directory lies between the leader and the field area
     let base_addr = leader.base_address_of_field_area as usize;
+    if base_addr < 24 || base_addr > record_length {
+        return Err(ParseError::at(
+            ParseErrorKind::InvalidLeader(format!(
+                "base address {} outside record of {} bytes",
+                base_addr, record_length
+            )),
+            file_offset + 12,
+        ));
+    }
+
+    // Parse directory
     let directory_data = &record_data[24..base_addr];
     let directory = Directory::parse(directory_data, &leader, file_offset + 24)?;
 
@@ -88,6 +238,11 @@ fn parse_record(data: &[u8], file_offset: usize) -> Result<(Record, usize)> {
             leader,
             directory,
             fields,
+            span: Some(Span {
+                offset: file_offset,
+                len: record_length,
+            }),
+            update: None,
         },
         record_length,
     ))
@@ -117,7 +272,11 @@ fn parse_fields(
         }
 
         let field_data = &field_area[start..start + length];
-        let field = Field::new(entry.tag.clone(), field_data.to_vec());
+        let mut field = Field::new(entry.tag.clone(), field_data.to_vec());
+        field.span = Some(Span {
+            offset: base_offset + start,
+            len: length,
+        });
         fields.push(field);
     }
 
@@ -149,4 +308,81 @@ mod tests {
         let leader = Leader::parse(leader_bytes).unwrap();
         assert_eq!(leader.record_length, 1582);
     }
+
+    #[test]
+    fn test_spans() {
+        let data = write_file(&[
+            Record::from_fields([("FRID", b"\x64\x01\x1e".to_vec())]),
+            Record::from_fields([("VRID", b"\x6e\x02\x1e".to_vec()), ("SG2D", vec![7; 9])]),
+        ])
+        .unwrap();
+        let records = parse_file(&data).unwrap();
+
+        let spans: Vec<_> = records.iter().map(|r| r.span().unwrap()).collect();
+        assert_eq!(spans[0].offset, 0);
+        assert_eq!(spans[0].end(), spans[1].offset);
+        assert_eq!(spans[1].end(), data.len());
+        for field in records.iter().flat_map(|r| &r.fields) {
+            assert_eq!(&data[field.span().unwrap().range()], field.data.as_slice());
+        }
+
+        let sg2d = records[1].fields[1].span().unwrap();
+        let (index, found) = record_at(&records, sg2d.offset + 3).unwrap();
+        assert_eq!(index, 1);
+        assert_eq!(found.field_at(sg2d.offset + 3).unwrap().tag, "SG2D");
+        assert!(found.field_at(spans[1].offset).is_none()); // the leader
+        assert!(record_at(&records, data.len()).is_none());
+    }
+
+    #[test]
+    fn test_parse_file_lossy() {
+        let record = |tag: &str, rcid: u8| Record::from_fields([(tag, vec![0x64, rcid, 0x1e])]);
+        let data = write_file(&[record("FRID", 1), record("FRID", 2), record("VRID", 3)]).unwrap();
+        let clean = parse_file(&data).unwrap();
+        let second = clean[1].span().unwrap();
+        let rcids = |records: &[Record]| -> Vec<u8> {
+            records.iter().map(|r| r.fields[0].data[1]).collect()
+        };
+
+        let (records, errors) = parse_file_lossy(&data);
+        assert_eq!(rcids(&records), [1, 2, 3]);
+        assert!(errors.is_empty());
+
+        // A garbled record length: parse_file gives up, the lossy parse
+        // skips to the third record
+        let mut garbled = data.clone();
+        garbled[second.offset..second.offset + 5].copy_from_slice(b"9x999");
+        let error = parse_file(&garbled).unwrap_err();
+        assert!(matches!(error.kind, ParseErrorKind::InvalidLeader(_)));
+        assert_eq!(error.offset, second.offset);
+        let (records, errors) = parse_file_lossy(&garbled);
+        assert_eq!(rcids(&records), [1, 3]);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].offset, second.offset);
+        assert_eq!(records[1].span(), clean[2].span());
+
+        // A directory entry running past the field area
+        let mut broken = data.clone();
+        broken[second.offset + 28] = b'9';
+        let (records, errors) = parse_file_lossy(&broken);
+        assert_eq!(rcids(&records), [1, 3]);
+        assert!(matches!(
+            errors[..],
+            [ParseError {
+                kind: ParseErrorKind::FieldOutOfBounds { .. },
+                ..
+            }]
+        ));
+
+        // A truncated tail leaves one error and everything before it
+        let (records, errors) = parse_file_lossy(&data[..data.len() - 4]);
+        assert_eq!(rcids(&records), [1, 2]);
+        assert!(matches!(
+            errors[..],
+            [ParseError {
+                kind: ParseErrorKind::RecordTooLarge { .. },
+                ..
+            }]
+        ));
+    }
 }
@@ -0,0 +1,186 @@
+//! Streaming ISO 8211 reader
+//!
+//! `parse_file` needs the whole file in memory. `RecordReader` instead pulls
+//! one record at a time from any `io::Read`: it reads the 24-byte leader,
+//! takes the record length from it, reads the rest of the record and parses
+//! it, so memory use is bounded by the largest record rather than the file.
+
+use super::{parse_record, Leader, Record};
+use crate::diag::{span, trace};
+use crate::error::{ParseError, ParseErrorKind, Result};
+use std::io::{self, Read};
+
+/// Leader length, which is also the smallest possible record
+const LEADER_LENGTH: usize = 24;
+
+/// RecordReader: Iterator over the records of an ISO 8211 stream
+///
+/// Yields `Err` once for the first malformed or truncated record (or I/O
+/// error) and then stops. Wrap unbuffered sources such as `File` in a
+/// `BufReader`.
+pub struct RecordReader<R> {
+    reader: R,
+    offset: usize,
+    index: usize,
+    done: bool,
+}
+
+impl<R: Read> RecordReader<R> {
+    /// Read records from `reader`, starting at its current position
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            offset: 0,
+            index: 0,
+            done: false,
+        }
+    }
+
+    /// Byte offset of the next record in the stream
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The underlying reader
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Read and parse the next record; Ok(None) at a clean end of stream
+    fn read_record(&mut self) -> Result<Option<Record>> {
+        let mut buf = vec![0; LEADER_LENGTH];
+        let got = read_full(&mut self.reader, &mut buf, self.offset)?;
+        if got == 0 {
+            return Ok(None);
+        }
+        if got < LEADER_LENGTH {
+            return Err(ParseError::at(
+                ParseErrorKind::UnexpectedEof,
+                self.offset + got,
+            ));
+        }
+
+        let leader =
+            Leader::parse(&buf).map_err(|e| ParseError::at(e.kind, self.offset + e.offset))?;
+        let record_length = leader.record_length as usize;
+        if record_length < LEADER_LENGTH {
+            return Err(ParseError::at(
+                ParseErrorKind::InvalidLeader(format!(
+                    "record length {} is shorter than the leader",
+                    record_length
+                )),
+                self.offset,
+            ));
+        }
+
+        buf.resize(record_length, 0);
+        let got = read_full(&mut self.reader, &mut buf[LEADER_LENGTH..], self.offset)?;
+        if got < record_length - LEADER_LENGTH {
+            return Err(ParseError::at(
+                ParseErrorKind::RecordTooLarge {
+                    record_length,
+                    available: LEADER_LENGTH + got,
+                },
+                self.offset,
+            ));
+        }
+
+        let (record, bytes_read) = parse_record(&buf, self.offset)?;
+        self.offset += bytes_read;
+        Ok(Some(record))
+    }
+}
+
+impl<R: Read> Iterator for RecordReader<R> {
+    type Item = Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        span!("record", index = self.index, offset = self.offset);
+        trace!("Reading record at offset {}", self.offset);
+        match self.read_record() {
+            Ok(Some(record)) => {
+                self.index += 1;
+                Some(Ok(record))
+            }
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+/// Fill `buf` as far as the stream allows, returning how many bytes were
+/// read (fewer than `buf.len()` only at end of stream)
+fn read_full<R: Read>(reader: &mut R, buf: &mut [u8], offset: usize) -> Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(ParseError::at(ParseErrorKind::Io(e), offset + filled)),
+        }
+    }
+    Ok(filled)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{parse_file, write_file};
+    use super::*;
+
+    fn record(tag: &str, data: &[u8]) -> Record {
+        Record::from_fields([(tag, data.to_vec())])
+    }
+
+    /// A reader that hands out at most three bytes per call
+    struct Trickle<'a>(&'a [u8]);
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let n = buf.len().min(3).min(self.0.len());
+            buf[..n].copy_from_slice(&self.0[..n]);
+            self.0 = &self.0[n..];
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn test_record_reader() {
+        let records = vec![
+            record("FRID", b"\x64\x01\x1e"),
+            record("SG2D", &[7; 40]),
+            record("ATTF", b"\x01\x00a\x1f\x1e"),
+        ];
+        let data = write_file(&records).unwrap();
+        let expected = parse_file(&data).unwrap();
+
+        let mut reader = RecordReader::new(Trickle(&data));
+        let streamed: Vec<Record> = reader.by_ref().collect::<Result<_>>().unwrap();
+        assert_eq!(streamed.len(), expected.len());
+        for (a, b) in streamed.iter().zip(&expected) {
+            assert_eq!(a.fields[0].tag, b.fields[0].tag);
+            assert_eq!(a.fields[0].data, b.fields[0].data);
+            assert_eq!(a.span(), b.span());
+            assert_eq!(a.fields[0].span(), b.fields[0].span());
+        }
+        assert_eq!(reader.offset(), data.len());
+
+        // A truncated last record is one error, then the end
+        let cut = &data[..data.len() - 2];
+        let mut reader = RecordReader::new(cut);
+        assert!(reader.next().unwrap().is_ok());
+        assert!(reader.next().unwrap().is_ok());
+        let err = reader.next().unwrap().unwrap_err();
+        assert!(matches!(err.kind, ParseErrorKind::RecordTooLarge { .. }));
+        assert!(reader.next().is_none());
+    }
+}
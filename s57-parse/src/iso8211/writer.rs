@@ -0,0 +1,186 @@
+//! ISO 8211 record writer
+//!
+//! The inverse of `parse_file`: lays records out again as leader, directory
+//! and field area. Field data is written as stored, field terminator
+//! included. The record length, base address and entry map are recomputed
+//! to fit; every other leader value is kept.
+
+use super::{Leader, Record};
+use crate::error::{ParseError, ParseErrorKind, Result};
+
+const FIELD_TERMINATOR: u8 = 0x1E;
+/// Largest record length the 5-digit leader field can hold
+const MAX_RECORD_LENGTH: usize = 99_999;
+
+/// Encode records as an ISO 8211 file
+pub fn write_file(records: &[Record]) -> Result<Vec<u8>> {
+    let mut out = Vec::new();
+    for record in records {
+        let offset = out.len();
+        out.extend(write_record(record).map_err(|e| ParseError::at(e.kind, offset + e.offset))?);
+    }
+    Ok(out)
+}
+
+/// Encode one record
+///
+/// Fails if the record would be longer than a leader can describe.
+pub fn write_record(record: &Record) -> Result<Vec<u8>> {
+    let leader = &record.leader;
+    let tag_size = record
+        .fields
+        .iter()
+        .map(|f| f.tag.len())
+        .max()
+        .unwrap_or(leader.size_of_field_tag as usize)
+        .max(leader.size_of_field_tag as usize);
+
+    // Field positions relative to the field area
+    let mut positions = Vec::with_capacity(record.fields.len());
+    let mut field_area_len = 0;
+    for field in &record.fields {
+        positions.push(field_area_len);
+        field_area_len += field.data.len();
+    }
+    let largest_length = record.fields.iter().map(|f| f.data.len()).max();
+    let length_size = digits(largest_length.unwrap_or(0)).max(leader.size_of_field_length_field);
+    let position_size =
+        digits(positions.last().copied().unwrap_or(0)).max(leader.size_of_field_position_field);
+
+    let entry_size = tag_size + length_size as usize + position_size as usize;
+    let base_address = 24 + entry_size * record.fields.len() + 1;
+    let record_length = base_address + field_area_len;
+    if record_length > MAX_RECORD_LENGTH || tag_size > 9 {
+        return Err(ParseError::at(
+            ParseErrorKind::InvalidLeader(format!(
+                "record of {} bytes does not fit an ISO 8211 leader",
+                record_length
+            )),
+            0,
+        ));
+    }
+
+    let mut out = Vec::with_capacity(record_length);
+    write_leader(
+        &mut out,
+        leader,
+        record_length,
+        base_address,
+        [length_size, position_size, tag_size as u8],
+    );
+    for (field, position) in record.fields.iter().zip(&positions) {
+        out.extend(format!("{:<width$}", field.tag, width = tag_size).bytes());
+        out.extend(format!("{:0width$}", field.data.len(), width = length_size as usize).bytes());
+        out.extend(format!("{:0width$}", position, width = position_size as usize).bytes());
+    }
+    out.push(FIELD_TERMINATOR);
+    for field in &record.fields {
+        out.extend(&field.data);
+    }
+    Ok(out)
+}
+
+/// Write a 24-byte leader; `entry_map` is the sizes of the directory's
+/// length, position and tag fields
+fn write_leader(
+    out: &mut Vec<u8>,
+    leader: &Leader,
+    record_length: usize,
+    base_address: usize,
+    entry_map: [u8; 3],
+) {
+    let [length_size, position_size, tag_size] = entry_map;
+    out.extend(format!("{:05}", record_length).bytes());
+    out.extend(
+        [
+            leader.interchange_level,
+            leader.leader_identifier,
+            leader.inline_code_extension_indicator,
+            leader.version_number,
+            leader.application_indicator,
+        ]
+        .iter()
+        .map(|&c| byte(c)),
+    );
+    out.extend(fixed(&leader.field_control_length, 2));
+    out.extend(format!("{:05}", base_address).bytes());
+    out.extend(fixed(&leader.extended_character_set, 3));
+    out.extend(format!("{}{}", length_size, position_size).bytes());
+    out.push(byte(leader.reserved));
+    out.extend(format!("{}", tag_size).bytes());
+}
+
+/// Number of decimal digits needed for `value`
+fn digits(value: usize) -> u8 {
+    value.to_string().len() as u8
+}
+
+/// A leader character as the byte it was parsed from (a space if it
+/// cannot have been)
+fn byte(c: char) -> u8 {
+    u8::try_from(c).unwrap_or(b' ')
+}
+
+/// Exactly `width` bytes of `value`, space-padded or truncated
+fn fixed(value: &str, width: usize) -> Vec<u8> {
+    let mut bytes: Vec<u8> = value.bytes().take(width).collect();
+    bytes.resize(width, b' ');
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{parse_file, Field};
+    use super::*;
+    use proptest::prelude::*;
+
+    fn record(identifier: char, fields: Vec<Field>) -> Record {
+        Record {
+            leader: Leader::new(identifier),
+            ..Record::from_fields(fields)
+        }
+    }
+
+    fn arb_field() -> impl Strategy<Value = Field> {
+        let tag = "[0-9A-Z]{4}";
+        // Field data always ends in a field terminator, as parsed data does
+        let data = prop::collection::vec(any::<u8>(), 0..300).prop_map(|mut data| {
+            data.push(FIELD_TERMINATOR);
+            data
+        });
+        (tag, data).prop_map(|(tag, data)| Field::new(tag, data))
+    }
+
+    fn arb_record() -> impl Strategy<Value = Record> {
+        (
+            prop::sample::select(vec!['L', 'D', 'R']),
+            prop::collection::vec(arb_field(), 1..12),
+        )
+            .prop_map(|(identifier, fields)| record(identifier, fields))
+    }
+
+    proptest! {
+        #[test]
+        fn test_write_parse_round_trip(records in prop::collection::vec(arb_record(), 1..6)) {
+            let bytes = write_file(&records).unwrap();
+            let parsed = parse_file(&bytes).unwrap();
+            prop_assert_eq!(parsed.len(), records.len());
+            for (original, parsed) in records.iter().zip(&parsed) {
+                prop_assert_eq!(parsed.leader.leader_identifier, original.leader.leader_identifier);
+                prop_assert_eq!(parsed.fields.len(), original.fields.len());
+                for (a, b) in original.fields.iter().zip(&parsed.fields) {
+                    prop_assert_eq!(&a.tag, &b.tag);
+                    prop_assert_eq!(&a.data, &b.data);
+                }
+            }
+            // Writing what was parsed gives the same bytes
+            prop_assert_eq!(write_file(&parsed).unwrap(), bytes);
+        }
+    }
+
+    #[test]
+    fn test_record_too_long() {
+        let field = Field::new("SG2D".to_string(), vec![0; MAX_RECORD_LENGTH]);
+        assert!(write_record(&record('D', vec![field])).is_err());
+    }
+}
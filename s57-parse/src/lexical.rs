@@ -0,0 +1,137 @@
+//! Lexical levels: the character sets of attribute values
+//!
+//! DSSI declares one lexical level for ATTF (AALL) and one for NATF (NALL).
+//! Levels 0 and 1 are single-byte (ASCII and ISO 8859-1) and end a value
+//! with the usual unit terminator 0x1F. Level 2 is UCS-2 little-endian, only
+//! allowed in NATF: every character, the unit terminator (0x1F 0x00) and the
+//! field terminator (0x1E 0x00) take two bytes, so a single 0x1F byte inside
+//! a character must not end the value.
+
+use std::borrow::Cow;
+
+/// LexicalLevel: Character set of ATTF/NATF values (DSSI AALL/NALL)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LexicalLevel {
+    /// Level 0: ISO 646 IRV (ASCII)
+    #[default]
+    Ascii,
+    /// Level 1: ISO 8859-1 (Latin-1)
+    Latin1,
+    /// Level 2: ISO 10646 UCS-2, little-endian
+    Ucs2,
+}
+
+impl LexicalLevel {
+    /// Level from its AALL/NALL code; None for undefined codes
+    pub fn from_code(code: u8) -> Option<Self> {
+        match code {
+            0 => Some(LexicalLevel::Ascii),
+            1 => Some(LexicalLevel::Latin1),
+            2 => Some(LexicalLevel::Ucs2),
+            _ => None,
+        }
+    }
+
+    /// AALL/NALL code
+    pub fn code(self) -> u8 {
+        match self {
+            LexicalLevel::Ascii => 0,
+            LexicalLevel::Latin1 => 1,
+            LexicalLevel::Ucs2 => 2,
+        }
+    }
+
+    /// Bytes per character, and per unit or field terminator
+    pub fn unit_width(self) -> usize {
+        match self {
+            LexicalLevel::Ucs2 => 2,
+            _ => 1,
+        }
+    }
+
+    /// Length of the value at the start of `data`, and of the unit
+    /// terminator after it
+    ///
+    /// The value ends at a unit terminator, which is consumed with it, or
+    /// at a field terminator or the end of data, which are not (terminator
+    /// length 0).
+    pub fn value_extent(self, data: &[u8]) -> (usize, usize) {
+        if self != LexicalLevel::Ucs2 {
+            return match data.iter().position(|&b| b == 0x1F || b == 0x1E) {
+                Some(end) if data[end] == 0x1F => (end, 1),
+                Some(end) => (end, 0),
+                None => (data.len(), 0),
+            };
+        }
+        let mut end = 0;
+        while end + 1 < data.len() {
+            match (data[end], data[end + 1]) {
+                (0x1F, 0x00) => return (end, 2),
+                (0x1E, 0x00) => return (end, 0),
+                _ => end += 2,
+            }
+        }
+        // A lone byte left over is a single-byte field terminator or noise
+        if data.get(end) == Some(&0x1E) {
+            (end, 0)
+        } else {
+            (data.len(), 0)
+        }
+    }
+
+    /// Decode a value (terminator excluded)
+    ///
+    /// ASCII bytes that aren't valid UTF-8 and unpaired UCS-2 surrogates
+    /// become U+FFFD.
+    pub fn decode(self, bytes: &[u8]) -> Cow<'_, str> {
+        match self {
+            LexicalLevel::Ascii => String::from_utf8_lossy(bytes),
+            LexicalLevel::Latin1 => match std::str::from_utf8(bytes) {
+                Ok(text) if bytes.is_ascii() => Cow::Borrowed(text),
+                _ => Cow::Owned(bytes.iter().map(|&b| char::from(b)).collect()),
+            },
+            LexicalLevel::Ucs2 => {
+                let units = bytes
+                    .chunks_exact(2)
+                    .map(|pair| u16::from_le_bytes([pair[0], pair[1]]));
+                let mut text: String = char::decode_utf16(units)
+                    .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+                    .collect();
+                if bytes.len() % 2 == 1 {
+                    text.push(char::REPLACEMENT_CHARACTER);
+                }
+                Cow::Owned(text)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lexical_levels() {
+        assert_eq!(LexicalLevel::from_code(2), Some(LexicalLevel::Ucs2));
+        assert_eq!(LexicalLevel::from_code(3), None);
+        assert_eq!(LexicalLevel::Latin1.code(), 1);
+
+        // Latin-1: one byte per character
+        let data = b"Sm\xf8gen\x1fnext";
+        assert_eq!(LexicalLevel::Latin1.value_extent(data), (6, 1));
+        assert_eq!(LexicalLevel::Latin1.decode(&data[..6]), "Smøgen");
+        assert!(matches!(
+            LexicalLevel::Latin1.decode(b"Kiel"),
+            Cow::Borrowed("Kiel")
+        ));
+
+        // UCS-2: "Ἁ" is 0x1F09, whose low byte must not end the value
+        let data = b"\x09\x1fA\x00\x1f\x00\x1e";
+        assert_eq!(LexicalLevel::Ucs2.value_extent(data), (4, 2));
+        assert_eq!(LexicalLevel::Ucs2.decode(&data[..4]), "ἉA");
+        assert_eq!(LexicalLevel::Ucs2.value_extent(&data[6..]), (0, 0));
+        assert_eq!(LexicalLevel::Ucs2.value_extent(b"A\x00\x1e\x00"), (2, 0));
+        assert_eq!(LexicalLevel::Ucs2.decode(b"A\x00B"), "A\u{FFFD}");
+    }
+}
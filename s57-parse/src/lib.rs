@@ -6,11 +6,17 @@
 //! S-57 files are encoded using the ISO 8211 standard.
 
 pub mod bitstring;
+pub mod catalog;
 pub mod ddr;
+pub mod ddr_diff;
+mod diag;
 pub mod error;
+pub mod format;
 pub mod interpret;
 pub mod iso8211;
+pub mod lexical;
 pub mod s57_schema;
+pub mod update;
 
 pub use error::{ParseError, ParseErrorKind, Result};
 
@@ -26,8 +32,24 @@ impl S57File {
         Ok(S57File { records })
     }
 
+    /// Parse an S-57 file from bytes, skipping records that don't parse
+    ///
+    /// Returns the file made of the records that could be recovered and the
+    /// errors for the ones that were skipped; see `iso8211::parse_file_lossy`.
+    /// Skipped records may leave dangling pointers for `build_world` to
+    /// report.
+    pub fn from_bytes_lossy(data: &[u8]) -> (Self, Vec<ParseError>) {
+        let (records, errors) = iso8211::parse_file_lossy(data);
+        (S57File { records }, errors)
+    }
+
     /// Get all records in the file
     pub fn records(&self) -> &[iso8211::Record] {
         &self.records
     }
+
+    /// Encode the records as ISO 8211 bytes again
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        iso8211::write_file(&self.records)
+    }
 }
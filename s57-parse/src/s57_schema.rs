@@ -10,6 +10,135 @@
 use crate::ddr::FormatType;
 use std::collections::{HashMap, HashSet};
 
+/// Field tags defined by the S-57 Edition 3.1 data structure
+///
+/// Covers the record identifier, data set, catalogue, data dictionary,
+/// feature and vector record fields. Anything else in a data record is a
+/// producer-specific extension.
+pub const STANDARD_FIELD_TAGS: &[&str] = &[
+    "0001", "DSID", "DSSI", "DSPM", "DSPR", "DSRC", "DSHT", "DSAC", "CATD", "CATX", "DDDF", "DDDR",
+    "DDDI", "DDOM", "DDRF", "DDSI", "DDSC", "FRID", "FOID", "ATTF", "NATF", "FFPC", "FFPT", "FSPC",
+    "FSPT", "VRID", "ATTV", "VRPC", "VRPT", "SGCC", "SG2D", "SG3D", "ARCC", "AR2D", "EL2D", "CT2D",
+];
+
+/// Canonical definitions of the fields ENC data records use, as
+/// (tag, name, array descriptor, format controls)
+///
+/// These are the definitions S-57 Edition 3.1 (Part 3, chapter 7) gives
+/// and every ENC DDR repeats. The parser falls back on them when a DDR is
+/// damaged or leaves a field out. The curve fields (ARCC, AR2D, EL2D, CT2D)
+/// are not included; the ENC product specification does not allow them.
+pub const CANONICAL_FIELD_DEFS: &[(&str, &str, &str, &str)] = &[
+    (
+        "DSID",
+        "Data set identification field",
+        "RCNM!RCID!EXPP!INTU!DSNM!EDTN!UPDN!UADT!ISDT!STED!PRSP!PSDN!PRED!PROF!AGEN!COMT",
+        "(b11,b14,2b11,3A,2A(8),R(4),b11,2A,b11,b12,A)",
+    ),
+    (
+        "DSSI",
+        "Data set structure information field",
+        "DSTR!AALL!NALL!NOMR!NOCR!NOGR!NOLR!NOIN!NOCN!NOED!NOFA",
+        "(3b11,8b14)",
+    ),
+    (
+        "DSPM",
+        "Data set parameter field",
+        "RCNM!RCID!HDAT!VDAT!SDAT!CSCL!DUNI!HUNI!PUNI!COUN!COMF!SOMF!COMT",
+        "(b11,b14,3b11,b14,4b11,2b14,A)",
+    ),
+    (
+        "FRID",
+        "Feature record identifier field",
+        "RCNM!RCID!PRIM!GRUP!OBJL!RVER!RUIN",
+        "(b11,b14,2b11,2b12,b11)",
+    ),
+    (
+        "FOID",
+        "Feature object identifier field",
+        "AGEN!FIDN!FIDS",
+        "(b12,b14,b12)",
+    ),
+    (
+        "ATTF",
+        "Feature record attribute field",
+        "*ATTL!ATVL",
+        "(b12,A)",
+    ),
+    (
+        "NATF",
+        "Feature record national attribute field",
+        "*ATTL!ATVL",
+        "(b12,A)",
+    ),
+    (
+        "FFPC",
+        "Feature record to feature object pointer control field",
+        "FFUI!FFIX!NFPT",
+        "(b11,2b12)",
+    ),
+    (
+        "FFPT",
+        "Feature record to feature object pointer field",
+        "*LNAM!RIND!COMT",
+        "(B(64),b11,A)",
+    ),
+    (
+        "FSPC",
+        "Feature record to spatial record pointer control field",
+        "FSUI!FSIX!NSPT",
+        "(b11,2b12)",
+    ),
+    (
+        "FSPT",
+        "Feature record to spatial record pointer field",
+        "*NAME!ORNT!USAG!MASK",
+        "(B(40),3b11)",
+    ),
+    (
+        "VRID",
+        "Vector record identifier field",
+        "RCNM!RCID!RVER!RUIN",
+        "(b11,b14,b12,b11)",
+    ),
+    (
+        "ATTV",
+        "Vector record attribute field",
+        "*ATTL!ATVL",
+        "(b12,A)",
+    ),
+    (
+        "VRPC",
+        "Vector record pointer control field",
+        "VPUI!VPIX!NVPT",
+        "(b11,2b12)",
+    ),
+    (
+        "VRPT",
+        "Vector record pointer field",
+        "*NAME!ORNT!USAG!TOPI!MASK",
+        "(B(40),4b11)",
+    ),
+    (
+        "SGCC",
+        "Coordinate control field",
+        "CCUI!CCIX!CCNC",
+        "(b11,2b12)",
+    ),
+    ("SG2D", "2-D coordinate field", "*YCOO!XCOO", "(2b24)"),
+    (
+        "SG3D",
+        "3-D coordinate (sounding array) field",
+        "*YCOO!XCOO!VE3D",
+        "(3b24)",
+    ),
+];
+
+/// Check whether a field tag is part of the S-57 data structure
+pub fn is_standard_field_tag(tag: &str) -> bool {
+    STANDARD_FIELD_TAGS.contains(&tag)
+}
+
 /// Override schema database for ISO 8211 parsing
 /// Contains field-specific metadata not present in the DDR
 ///
@@ -119,6 +248,14 @@ mod tests {
         assert!(schema.is_optional("DSID", "COMT"));
     }
 
+    #[test]
+    fn test_standard_field_tags() {
+        assert!(is_standard_field_tag("VRID"));
+        assert!(is_standard_field_tag("SG3D"));
+        assert!(is_standard_field_tag("CATD"));
+        assert!(!is_standard_field_tag("XNOA"));
+    }
+
     #[test]
     fn test_unknown_field_default_required() {
         let schema = OverrideSchema::new();
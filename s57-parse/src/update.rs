@@ -0,0 +1,1567 @@
+//! Applying ENC update files (.001, .002, ...) to a base cell
+//!
+//! S-57 corrections are distributed as update datasets. Each data record in
+//! an update names its target by RCNM/RCID and says what to do with RUIN:
+//! - 1 (insert): the record is new and is added as it stands
+//! - 2 (delete): the target record is removed
+//! - 3 (modify): the target's attributes are merged with the update's
+//!   (an ATVL of DEL, 0x7F, removes the attribute) and its VRPT, SG2D/SG3D,
+//!   FSPT and FFPT groups are spliced as the VRPC, SGCC, FSPC and FFPC
+//!   control fields say; an edge must come out of a VRPC with one
+//!   beginning and one end node, both connected nodes in the cell
+//!
+//! Deletes and modifies must carry the target's RVER plus one. Updates must
+//! be applied in order: each one has to name the same dataset (DSNM),
+//! edition (EDTN) and producing agency (AGEN) as the cell and the next
+//! update number (UPDN), and an update that carries a DSPM must not change
+//! any of the cell's parameters. The merged cell carries the last update's
+//! DSID, and its DSSI record counts are recomputed.
+//!
+//! By default any inconsistency is an `InvalidUpdate` error and nothing is
+//! applied, as an ECDIS must reject an update it cannot apply exactly. For
+//! repairing or inspecting a cell, `apply_update_with` takes a
+//! `ConflictPolicy` instead: records that miss their target, insert over an
+//! existing record or carry the wrong RVER are then skipped or forced, and
+//! listed in a `ConflictReport`.
+
+use crate::bitstring::NameKey;
+use crate::ddr::{ParsedField, SubfieldValue, DDR};
+use crate::error::{ParseError, ParseErrorKind, Result};
+use crate::iso8211::{parse_file, write_record, Field, Record};
+use crate::S57File;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+#[cfg(feature = "fs")]
+use std::path::{Path, PathBuf};
+
+const FIELD_TERMINATOR: u8 = 0x1E;
+/// An ATVL of DEL deletes the attribute
+const DELETE_VALUE: u8 = 0x7F;
+
+/// Record update instructions (RUIN)
+const RUIN_INSERT: u32 = 1;
+const RUIN_DELETE: u32 = 2;
+const RUIN_MODIFY: u32 = 3;
+
+/// Update control fields and the pointer or coordinate fields they edit
+const CONTROLS: [(&str, &[&str]); 4] = [
+    ("VRPC", &["VRPT"]),
+    ("SGCC", &["SG2D", "SG3D"]),
+    ("FSPC", &["FSPT"]),
+    ("FFPC", &["FFPT"]),
+];
+
+/// Bytes of a FOID, and of the LNAM that points at it (AGEN, FIDN, FIDS)
+const LNAM_LEN: usize = 8;
+
+/// RCNM of connected nodes and edges
+const RCNM_CONNECTED_NODE: u32 = 120;
+const RCNM_EDGE: u32 = 130;
+/// Bytes of a NAME pointer (RCNM, RCID)
+const NAME_LEN: usize = 5;
+/// Byte of a VRPT group holding TOPI, after NAME, ORNT and USAG
+const VRPT_TOPI: usize = 7;
+/// TOPI of an edge's beginning and end nodes
+const TOPI_BEGIN: u8 = 1;
+const TOPI_END: u8 = 2;
+
+/// Attribute fields merged attribute by attribute on modify
+const ATTRIBUTE_FIELDS: [&str; 3] = ["ATTF", "NATF", "ATTV"];
+
+/// Apply update files in order to a base cell, returning the merged cell
+pub fn apply_updates(base: &S57File, updates: &[S57File]) -> Result<S57File> {
+    apply_updates_with(base, updates, ConflictPolicy::Fail).map(|(merged, _)| merged)
+}
+
+/// Apply update files in order, resolving record conflicts by `policy`
+///
+/// Returns the merged cell and the conflicts of every update.
+pub fn apply_updates_with(
+    base: &S57File,
+    updates: &[S57File],
+    policy: ConflictPolicy,
+) -> Result<(S57File, ConflictReport)> {
+    let mut merged = S57File {
+        records: base.records.clone(),
+    };
+    let mut report = ConflictReport::default();
+    for update in updates {
+        let conflicts = merged.apply_update_with(update, policy)?;
+        report.conflicts.extend(conflicts.conflicts);
+    }
+    Ok((merged, report))
+}
+
+/// Update files next to a base cell (`NAME.000`): `NAME.001`, `NAME.002`,
+/// ... in order, stopping at the first missing number
+#[cfg(feature = "fs")]
+pub fn update_paths(base: &Path) -> Vec<PathBuf> {
+    if base.extension().and_then(|e| e.to_str()) != Some("000") {
+        return Vec::new();
+    }
+    (1..=999)
+        .map(|n| base.with_extension(format!("{:03}", n)))
+        .take_while(|path| path.is_file())
+        .collect()
+}
+
+impl S57File {
+    /// DSID name, edition, update number, dates and agency; None without a
+    /// readable DSID
+    pub fn dataset_id(&self) -> Option<DatasetId> {
+        Dataset::new(&self.records).dsid()
+    }
+
+    /// Apply one update file; on error the cell is left unchanged
+    pub fn apply_update(&mut self, update: &S57File) -> Result<()> {
+        self.apply_update_with(update, ConflictPolicy::Fail)
+            .map(|_| ())
+    }
+
+    /// Apply one update file, resolving record conflicts by `policy`
+    ///
+    /// Returns the conflicts that were skipped or forced. Sequence errors
+    /// (wrong dataset, edition or update number) and, under
+    /// `ConflictPolicy::Fail`, any conflict are errors, and leave the cell
+    /// unchanged.
+    pub fn apply_update_with(
+        &mut self,
+        update: &S57File,
+        policy: ConflictPolicy,
+    ) -> Result<ConflictReport> {
+        let base = Dataset::new(&self.records);
+        let mut upd = Dataset::new(&update.records);
+        // An update without a DSSI keeps the cell's character sets
+        let upd_has_dssi = upd
+            .data_records()
+            .iter()
+            .any(|r| r.fields.iter().any(|f| f.tag == "DSSI"));
+        if !upd_has_dssi {
+            let (aall, nall) = base.ddr.lexical_levels();
+            upd.ddr.set_lexical_levels(aall, nall);
+        }
+        let base_dsid = base
+            .dsid()
+            .ok_or_else(|| invalid("cell has no DSID".to_string()))?;
+        let upd_dsid = upd
+            .dsid()
+            .ok_or_else(|| invalid("update has no DSID".to_string()))?;
+        check_sequence(&base_dsid, &upd_dsid)?;
+        check_parameters(&base, &upd, upd_dsid.updn)?;
+        let updn = upd_dsid.updn;
+        let from_update = |record: &Record| Record {
+            update: Some(updn),
+            ..record.clone()
+        };
+        let force = policy == ConflictPolicy::Force;
+        let mut report = ConflictReport::default();
+
+        let mut records: Vec<Option<Record>> = self.records.iter().cloned().map(Some).collect();
+        let mut deleted_foids: HashSet<Vec<u8>> = HashSet::new();
+        let mut index: HashMap<(u32, u32), usize> = HashMap::new();
+        for (i, record) in self.records.iter().enumerate() {
+            if let Some(key) = base.record_id(record) {
+                index.insert((key.rcnm, key.rcid), i);
+            }
+        }
+
+        for record in &update.records[upd.first_data..] {
+            let Some(id) = upd.record_id(record) else {
+                continue;
+            };
+            let key = (id.rcnm, id.rcid);
+            let mut conflict = |kind: ConflictKind, resolution: Resolution| {
+                let conflict = UpdateConflict {
+                    updn,
+                    rcnm: key.0,
+                    rcid: key.1,
+                    kind,
+                    resolution,
+                };
+                if policy == ConflictPolicy::Fail {
+                    return Err(invalid(conflict.to_string()));
+                }
+                report.conflicts.push(conflict);
+                Ok(())
+            };
+            let forced = if force {
+                Resolution::Forced
+            } else {
+                Resolution::Skipped
+            };
+
+            match id.ruin {
+                RUIN_INSERT => {
+                    if let Some(&slot) = index.get(&key) {
+                        conflict(ConflictKind::DuplicateInsert, forced)?;
+                        if force {
+                            records[slot] = Some(from_update(record));
+                        }
+                        continue;
+                    }
+                    index.insert(key, records.len());
+                    records.push(Some(from_update(record)));
+                }
+                RUIN_DELETE | RUIN_MODIFY => {
+                    let Some(slot) = index.get(&key).copied().filter(|&i| records[i].is_some())
+                    else {
+                        conflict(ConflictKind::MissingTarget, Resolution::Skipped)?;
+                        continue;
+                    };
+                    let target = records[slot].as_ref().expect("checked above");
+                    let expected = base.record_id(target).map_or(0, |t| t.rver) + 1;
+                    if id.rver != expected {
+                        let kind = ConflictKind::VersionMismatch {
+                            ruin: id.ruin,
+                            expected,
+                            found: id.rver,
+                        };
+                        conflict(kind, forced)?;
+                        if !force {
+                            continue;
+                        }
+                    }
+                    if id.ruin == RUIN_DELETE {
+                        if let Some(foid) = target.fields.iter().find(|f| f.tag == "FOID") {
+                            deleted_foids.extend(foid.data.get(..LNAM_LEN).map(<[u8]>::to_vec));
+                        }
+                        records[slot] = None;
+                        index.remove(&key);
+                    } else {
+                        let modified = modify(&base.ddr, target, &upd.ddr, record, id.rver)
+                            .and_then(|modified| {
+                                if key.0 == RCNM_EDGE
+                                    && record.fields.iter().any(|f| f.tag == "VRPC")
+                                {
+                                    check_edge_nodes(&base.ddr, &modified, &index)?;
+                                }
+                                Ok(modified)
+                            });
+                        match modified {
+                            Ok(mut modified) => {
+                                modified.update = Some(updn);
+                                records[slot] = Some(modified);
+                            }
+                            Err(e) => conflict(
+                                ConflictKind::InvalidModification(e.to_string()),
+                                Resolution::Skipped,
+                            )?,
+                        }
+                    }
+                }
+                other => conflict(ConflictKind::UnknownInstruction(other), Resolution::Skipped)?,
+            }
+        }
+
+        let mut records: Vec<Record> = records.into_iter().flatten().collect();
+        prune_feature_pointers(&base.ddr, &mut records, &deleted_foids)?;
+        replace_dsid(&base, &upd, &mut records)?;
+        refresh_dssi(&base.ddr, &mut records)?;
+        self.records = records;
+        Ok(report)
+    }
+}
+
+/// ConflictPolicy: What to do with an update record that can't be applied
+/// as written
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConflictPolicy {
+    /// Reject the whole update, as an ECDIS must
+    #[default]
+    Fail,
+    /// Leave the conflicting record out and apply the rest
+    Skip,
+    /// Apply the record regardless where possible: RVER mismatches are
+    /// ignored and inserts replace the existing record. Conflicts that
+    /// can't be forced (no target, a modify that doesn't fit) are skipped.
+    Force,
+}
+
+/// ConflictKind: Why an update record couldn't be applied as written
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConflictKind {
+    /// Delete or modify of a record the cell doesn't have
+    MissingTarget,
+    /// Insert of a record the cell already has
+    DuplicateInsert,
+    /// RVER isn't the target's plus one, e.g. a delete of a record that was
+    /// modified after the update was produced
+    VersionMismatch {
+        ruin: u32,
+        expected: u32,
+        found: u32,
+    },
+    /// A modify whose attributes or control fields don't fit the target
+    InvalidModification(String),
+    /// RUIN other than insert, delete or modify
+    UnknownInstruction(u32),
+}
+
+/// Resolution: What was done about a conflict
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resolution {
+    /// The update record was left out
+    Skipped,
+    /// The update record was applied anyway
+    Forced,
+}
+
+impl fmt::Display for Resolution {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Resolution::Skipped => f.write_str("skipped"),
+            Resolution::Forced => f.write_str("forced"),
+        }
+    }
+}
+
+/// UpdateConflict: One update record that couldn't be applied as written
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UpdateConflict {
+    /// Update number (UPDN) of the file the record came from
+    pub updn: u32,
+    /// Target record name
+    pub rcnm: u32,
+    pub rcid: u32,
+    pub kind: ConflictKind,
+    pub resolution: Resolution,
+}
+
+impl fmt::Display for UpdateConflict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let record = format!("record {}/{}", self.rcnm, self.rcid);
+        write!(f, "update {}: ", self.updn)?;
+        match &self.kind {
+            ConflictKind::MissingTarget => write!(f, "no target for {}", record),
+            ConflictKind::DuplicateInsert => write!(f, "insert of existing {}", record),
+            ConflictKind::VersionMismatch {
+                ruin,
+                expected,
+                found,
+            } => {
+                let what = if *ruin == RUIN_DELETE {
+                    "deletion"
+                } else {
+                    "modification"
+                };
+                write!(
+                    f,
+                    "{} of {} has RVER {}, expected {}",
+                    what, record, found, expected
+                )
+            }
+            ConflictKind::InvalidModification(e) => write!(f, "modifying {}: {}", record, e),
+            ConflictKind::UnknownInstruction(ruin) => write!(f, "RUIN {} on {}", ruin, record),
+        }
+    }
+}
+
+/// ConflictReport: Conflicts skipped or forced while applying updates
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConflictReport {
+    pub conflicts: Vec<UpdateConflict>,
+}
+
+impl ConflictReport {
+    /// True if every update record applied cleanly
+    pub fn is_empty(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+}
+
+fn invalid(message: String) -> ParseError {
+    ParseError::at(ParseErrorKind::InvalidUpdate(message), 0)
+}
+
+/// A file's DDR (or the built-in definitions) and where its data starts
+struct Dataset<'a> {
+    ddr: DDR,
+    records: &'a [Record],
+    first_data: usize,
+}
+
+/// DatasetId: DSID values that place a file in its cell's update sequence
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DatasetId {
+    /// Data set name, e.g. "GB100001.000"
+    pub dsnm: String,
+    /// Edition number
+    pub edtn: String,
+    /// Update number (0 for a new edition)
+    pub updn: u32,
+    /// Update application date (CCYYMMDD)
+    pub uadt: String,
+    /// Issue date (CCYYMMDD)
+    pub isdt: String,
+    /// Producing agency code; None if the DSID doesn't carry one
+    pub agen: Option<u32>,
+}
+
+/// RCNM/RCID of a feature or vector record, with its RVER and RUIN
+struct RecordId {
+    rcnm: u32,
+    rcid: u32,
+    rver: u32,
+    ruin: u32,
+}
+
+impl<'a> Dataset<'a> {
+    fn new(records: &'a [Record]) -> Self {
+        let mut dataset = match records.first() {
+            Some(first) if first.leader.is_ddr() => Dataset {
+                ddr: DDR::parse(first).unwrap_or_else(|_| DDR::canonical()),
+                records,
+                first_data: 1,
+            },
+            _ => Dataset {
+                ddr: DDR::canonical(),
+                records,
+                first_data: 0,
+            },
+        };
+        // Attribute groups are split by their unit terminators, which are
+        // two bytes wide in UCS-2 NATF
+        let data_records = dataset.data_records();
+        dataset.ddr.load_lexical_levels(data_records);
+        dataset
+    }
+
+    fn data_records(&self) -> &'a [Record] {
+        &self.records[self.first_data..]
+    }
+
+    fn dsid(&self) -> Option<DatasetId> {
+        let field = self
+            .data_records()
+            .iter()
+            .find_map(|r| r.fields.iter().find(|f| f.tag == "DSID"))?;
+        let parsed = self.ddr.parse_field_data(field).ok()?;
+        let text = |label| {
+            parsed
+                .get_value(label)
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string()
+        };
+        Some(DatasetId {
+            dsnm: text("DSNM"),
+            edtn: text("EDTN"),
+            updn: text("UPDN").trim().parse().ok()?,
+            uadt: text("UADT"),
+            isdt: text("ISDT"),
+            agen: uint(&parsed, "AGEN"),
+        })
+    }
+
+    /// DSPM subfields that describe the data (datums, scale, units and
+    /// factors), as text; None without a DSPM field
+    fn dspm(&self) -> Option<Vec<(String, String)>> {
+        let field = self
+            .data_records()
+            .iter()
+            .find_map(|r| r.fields.iter().find(|f| f.tag == "DSPM"))?;
+        let parsed = self.ddr.parse_field_data(field).ok()?;
+        let values = parsed.groups().first()?;
+        Some(
+            values
+                .iter()
+                .filter(|(label, _)| !matches!(*label, "RCNM" | "RCID" | "COMT"))
+                .map(|(label, value)| (label.to_string(), subfield_text(value)))
+                .collect(),
+        )
+    }
+
+    fn record_id(&self, record: &Record) -> Option<RecordId> {
+        let field = record
+            .fields
+            .iter()
+            .find(|f| f.tag == "FRID" || f.tag == "VRID")?;
+        let parsed = self.ddr.parse_field_data(field).ok()?;
+        Some(RecordId {
+            rcnm: uint(&parsed, "RCNM")?,
+            rcid: uint(&parsed, "RCID")?,
+            rver: uint(&parsed, "RVER").unwrap_or(1),
+            ruin: uint(&parsed, "RUIN").unwrap_or(RUIN_INSERT),
+        })
+    }
+}
+
+/// An unsigned subfield of the first group, however it was decoded
+fn uint(parsed: &ParsedField, label: &str) -> Option<u32> {
+    let value = parsed.get_value(label)?;
+    value
+        .as_uint()
+        .or_else(|| value.as_int().and_then(|i| u32::try_from(i).ok()))
+}
+
+/// A subfield value as text, so values encoded differently by two DDRs
+/// (b14 vs b24, padded strings) compare equal
+fn subfield_text(value: &SubfieldValue) -> String {
+    match value {
+        SubfieldValue::Null => String::new(),
+        SubfieldValue::Integer(i) => i.to_string(),
+        SubfieldValue::UnsignedInteger(u) => u.to_string(),
+        SubfieldValue::Real(r) => r.to_string(),
+        SubfieldValue::String(s) => s.trim().to_string(),
+        SubfieldValue::Bytes(b) => b.iter().map(|byte| format!("{:02x}", byte)).collect(),
+    }
+}
+
+/// The update must continue the cell's own update sequence
+fn check_sequence(base: &DatasetId, update: &DatasetId) -> Result<()> {
+    if base.dsnm.trim() != update.dsnm.trim() {
+        return Err(invalid(format!(
+            "update is for {}, not {}",
+            update.dsnm, base.dsnm
+        )));
+    }
+    if base.edtn.trim() != update.edtn.trim() {
+        return Err(invalid(format!(
+            "update {} is for edition {}, cell is edition {}",
+            update.updn, update.edtn, base.edtn
+        )));
+    }
+    if update.updn != base.updn + 1 {
+        return Err(invalid(format!(
+            "update {} does not follow update {}",
+            update.updn, base.updn
+        )));
+    }
+    if let (Some(cell_agen), Some(update_agen)) = (base.agen, update.agen) {
+        if cell_agen != update_agen {
+            return Err(invalid(format!(
+                "update {} is from agency {}, cell is from agency {}",
+                update.updn, update_agen, cell_agen
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// An update that repeats the DSPM must not change it: the cell's
+/// coordinates and depths would be read with the wrong datums or factors
+fn check_parameters(base: &Dataset, upd: &Dataset, updn: u32) -> Result<()> {
+    let (Some(cell), Some(update)) = (base.dspm(), upd.dspm()) else {
+        return Ok(());
+    };
+    let changed: Vec<String> = update
+        .iter()
+        .filter_map(|(label, value)| {
+            let (_, before) = cell.iter().find(|(l, _)| l == label)?;
+            (before != value).then(|| format!("{} {} -> {}", label, before, value))
+        })
+        .collect();
+    if changed.is_empty() {
+        return Ok(());
+    }
+    Err(invalid(format!(
+        "update {} changes DSPM: {}",
+        updn,
+        changed.join(", ")
+    )))
+}
+
+/// Apply a modify record to its target, returning the new target
+fn modify(
+    base_ddr: &DDR,
+    target: &Record,
+    upd_ddr: &DDR,
+    update: &Record,
+    rver: u32,
+) -> Result<Record> {
+    let mut fields = target.fields.clone();
+
+    // The target takes the update's record version
+    if let Some(field) = fields
+        .iter_mut()
+        .find(|f| f.tag == "FRID" || f.tag == "VRID")
+    {
+        write_subfield(base_ddr, field, "RVER", rver)?;
+    }
+
+    for tag in ATTRIBUTE_FIELDS {
+        if let Some(changes) = update.fields.iter().find(|f| f.tag == tag) {
+            merge_attributes(base_ddr, &mut fields, upd_ddr, changes)?;
+        }
+    }
+
+    for (control_tag, targets) in CONTROLS {
+        let Some(control) = update.fields.iter().find(|f| f.tag == control_tag) else {
+            continue;
+        };
+        let control = upd_ddr.parse_field_data(control)?;
+        let values: Vec<u32> = control
+            .groups()
+            .first()
+            .map(|group| {
+                group
+                    .iter()
+                    .filter_map(|(_, v)| {
+                        v.as_uint()
+                            .or_else(|| v.as_int().and_then(|i| u32::try_from(i).ok()))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        let [instruction, index, count] = values[..] else {
+            return Err(invalid(format!("{} is incomplete", control_tag)));
+        };
+
+        // The pointer or coordinate field the control applies to
+        let tag = targets
+            .iter()
+            .find(|t| fields.iter().any(|f| f.tag == **t))
+            .or_else(|| {
+                targets
+                    .iter()
+                    .find(|t| update.fields.iter().any(|f| f.tag == **t))
+            })
+            .ok_or_else(|| invalid(format!("{} has no field to apply to", control_tag)))?;
+        let new_groups = match update.fields.iter().find(|f| f.tag == *tag) {
+            Some(field) => groups(upd_ddr, field)?,
+            None => Vec::new(),
+        };
+        let current = match fields.iter().find(|f| f.tag == *tag) {
+            Some(field) => groups(base_ddr, field)?,
+            None => Vec::new(),
+        };
+        let spliced = splice(
+            current,
+            instruction,
+            index as usize,
+            count as usize,
+            new_groups,
+        )
+        .map_err(|e| invalid(format!("{}: {}", control_tag, e)))?;
+        if spliced.is_empty() {
+            fields.retain(|f| f.tag != *tag);
+        } else {
+            set_field(&mut fields, tag, assemble(&spliced));
+        }
+    }
+
+    rebuild(Record {
+        leader: target.leader.clone(),
+        directory: target.directory.clone(),
+        fields,
+        span: None,
+        update: target.update,
+    })
+}
+
+/// The groups of a field as separate byte strings
+fn groups(ddr: &DDR, field: &Field) -> Result<Vec<Vec<u8>>> {
+    Ok(ddr
+        .group_ranges(field)?
+        .into_iter()
+        .map(|range| field.data[range].to_vec())
+        .collect())
+}
+
+/// Field data from its groups
+fn assemble(groups: &[Vec<u8>]) -> Vec<u8> {
+    let mut data: Vec<u8> = groups.concat();
+    data.push(FIELD_TERMINATOR);
+    data
+}
+
+/// Replace a field's data, adding the field if the record lacks it
+fn set_field(fields: &mut Vec<Field>, tag: &str, data: Vec<u8>) {
+    match fields.iter_mut().find(|f| f.tag == tag) {
+        Some(field) => field.data = data,
+        None => fields.push(Field::new(tag.to_string(), data)),
+    }
+}
+
+/// Insert, delete or replace `count` groups from the 1-based `index`
+fn splice(
+    mut groups: Vec<Vec<u8>>,
+    instruction: u32,
+    index: usize,
+    count: usize,
+    new_groups: Vec<Vec<u8>>,
+) -> std::result::Result<Vec<Vec<u8>>, String> {
+    let start = index
+        .checked_sub(1)
+        .ok_or_else(|| "index must start at 1".to_string())?;
+    let needs_groups = instruction != RUIN_DELETE;
+    if needs_groups && new_groups.len() < count {
+        return Err(format!(
+            "{} groups to apply, update carries {}",
+            count,
+            new_groups.len()
+        ));
+    }
+    let new_groups = new_groups.into_iter().take(count);
+    match instruction {
+        RUIN_INSERT if start <= groups.len() => {
+            groups.splice(start..start, new_groups);
+        }
+        RUIN_DELETE | RUIN_MODIFY if start + count <= groups.len() => {
+            let replacement: Vec<Vec<u8>> = if instruction == RUIN_MODIFY {
+                new_groups.collect()
+            } else {
+                Vec::new()
+            };
+            groups.splice(start..start + count, replacement);
+        }
+        RUIN_INSERT | RUIN_DELETE | RUIN_MODIFY => {
+            return Err(format!(
+                "groups {}..{} out of range for a field of {}",
+                index,
+                index + count,
+                groups.len()
+            ))
+        }
+        other => return Err(format!("unknown update instruction {}", other)),
+    }
+    Ok(groups)
+}
+
+/// An edge's VRPT must still name one beginning and one end node after a
+/// VRPC, each a connected node the cell has
+///
+/// Splicing by index can't tell an edge from any other pointer list, so a
+/// VRPC that misses its index would otherwise leave the edge detached or
+/// joined to a node that was deleted.
+fn check_edge_nodes(ddr: &DDR, edge: &Record, index: &HashMap<(u32, u32), usize>) -> Result<()> {
+    let pointers = match edge.fields.iter().find(|f| f.tag == "VRPT") {
+        Some(field) => groups(ddr, field)?,
+        None => Vec::new(),
+    };
+    for (topi, end) in [(TOPI_BEGIN, "beginning"), (TOPI_END, "end")] {
+        let nodes: Vec<&Vec<u8>> = pointers
+            .iter()
+            .filter(|g| g.get(VRPT_TOPI) == Some(&topi))
+            .collect();
+        let [node] = nodes[..] else {
+            return Err(invalid(format!(
+                "VRPC leaves the edge with {} {} nodes",
+                nodes.len(),
+                end
+            )));
+        };
+        let known = node
+            .get(..NAME_LEN)
+            .and_then(|name| NameKey::decode(name).ok())
+            .is_some_and(|name| {
+                let key = (u32::from(name.rcnm), name.rcid);
+                key.0 == RCNM_CONNECTED_NODE && index.contains_key(&key)
+            });
+        if !known {
+            return Err(invalid(format!(
+                "VRPC points the edge's {} at a connected node the cell doesn't have",
+                end
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Drop FFPT pointers to features the update deleted
+///
+/// An update deleting a feature should also remove the pointers to it with
+/// FFPC, but producers don't always; a dangling LNAM would leave the
+/// relationship pointing at nothing. The pointing records keep their RVER,
+/// since later updates are written against it.
+fn prune_feature_pointers(
+    ddr: &DDR,
+    records: &mut [Record],
+    deleted: &HashSet<Vec<u8>>,
+) -> Result<()> {
+    if deleted.is_empty() {
+        return Ok(());
+    }
+    for record in records.iter_mut() {
+        let Some(field) = record.fields.iter().find(|f| f.tag == "FFPT") else {
+            continue;
+        };
+        let pointers = groups(ddr, field)?;
+        let kept: Vec<Vec<u8>> = pointers
+            .iter()
+            .filter(|g| g.get(..LNAM_LEN).is_none_or(|lnam| !deleted.contains(lnam)))
+            .cloned()
+            .collect();
+        if kept.len() == pointers.len() {
+            continue;
+        }
+        let mut fields = record.fields.clone();
+        if kept.is_empty() {
+            fields.retain(|f| f.tag != "FFPT");
+        } else {
+            set_field(&mut fields, "FFPT", assemble(&kept));
+        }
+        *record = rebuild(Record {
+            leader: record.leader.clone(),
+            directory: record.directory.clone(),
+            fields,
+            span: None,
+            update: record.update,
+        })?;
+    }
+    Ok(())
+}
+
+/// Merge an update's ATTF/NATF/ATTV into the target's field of that tag
+///
+/// Groups are matched on their ATTL (the first two bytes): a new value
+/// replaces the old one, DEL removes it, and new attributes are appended.
+fn merge_attributes(
+    base_ddr: &DDR,
+    fields: &mut Vec<Field>,
+    upd_ddr: &DDR,
+    changes: &Field,
+) -> Result<()> {
+    let mut current = match fields.iter().find(|f| f.tag == changes.tag) {
+        Some(field) => groups(base_ddr, field)?,
+        None => Vec::new(),
+    };
+    for change in groups(upd_ddr, changes)? {
+        let Some(attl) = change.get(..2) else {
+            continue;
+        };
+        let position = current.iter().position(|g| g.get(..2) == Some(attl));
+        let delete = is_delete(&change[2..]);
+        match (position, delete) {
+            (Some(i), true) => {
+                current.remove(i);
+            }
+            (Some(i), false) => current[i] = change,
+            (None, false) => current.push(change),
+            (None, true) => {}
+        }
+    }
+    if current.is_empty() {
+        fields.retain(|f| f.tag != changes.tag);
+    } else {
+        set_field(fields, &changes.tag, assemble(&current));
+    }
+    Ok(())
+}
+
+/// True if an ATVL (with its unit terminator) is the delete marker alone
+///
+/// The marker is one DEL in ASCII and Latin-1 fields (lexical levels 0
+/// and 1) and the UCS-2 character 0x007F in NATF at level 2. A value that
+/// merely starts with DEL is an ordinary value.
+fn is_delete(atvl: &[u8]) -> bool {
+    matches!(
+        atvl,
+        [DELETE_VALUE]
+            | [DELETE_VALUE, 0x1F]
+            | [DELETE_VALUE, 0x00]
+            | [DELETE_VALUE, 0x00, 0x1F, 0x00]
+    )
+}
+
+/// Overwrite a fixed-width binary subfield of a field's first group
+fn write_subfield(ddr: &DDR, field: &mut Field, label: &str, value: u32) -> Result<()> {
+    let (offset, width) = ddr
+        .get_field_def(&field.tag)
+        .and_then(|def| def.subfield_offset(label))
+        .filter(|&(offset, width)| width <= 4 && offset + width <= field.data.len())
+        .ok_or_else(|| invalid(format!("cannot write {} in {}", label, field.tag)))?;
+    field.data[offset..offset + width].copy_from_slice(&value.to_le_bytes()[..width]);
+    field.span = None;
+    Ok(())
+}
+
+/// Re-encode a record so its leader and directory match its fields again
+///
+/// The result was assembled in memory, so it has no span in any file; it
+/// keeps the record's update number.
+fn rebuild(record: Record) -> Result<Record> {
+    let bytes = write_record(&record)?;
+    let mut rebuilt = parse_file(&bytes)?
+        .pop()
+        .ok_or_else(|| invalid("record vanished while rebuilding".to_string()))?;
+    rebuilt.span = None;
+    rebuilt.update = record.update;
+    for field in &mut rebuilt.fields {
+        field.span = None;
+    }
+    Ok(rebuilt)
+}
+
+/// Put the update's DSID in place of the cell's, keeping the cell's
+/// exchange purpose (EXPP: new dataset rather than update)
+fn replace_dsid(base: &Dataset, upd: &Dataset, records: &mut [Record]) -> Result<()> {
+    let Some(new_dsid) = upd
+        .data_records()
+        .iter()
+        .find_map(|r| r.fields.iter().find(|f| f.tag == "DSID"))
+    else {
+        return Ok(());
+    };
+    let Some(record) = records
+        .iter_mut()
+        .find(|r| r.fields.iter().any(|f| f.tag == "DSID"))
+    else {
+        return Ok(());
+    };
+    let Some(field) = record.fields.iter_mut().find(|f| f.tag == "DSID") else {
+        return Ok(());
+    };
+    let expp = base
+        .ddr
+        .parse_field_data(field)
+        .ok()
+        .and_then(|parsed| uint(&parsed, "EXPP"));
+    field.data = new_dsid.data.clone();
+    if let Some(expp) = expp {
+        write_subfield(&upd.ddr, field, "EXPP", expp)?;
+    }
+    record.update = upd.dsid().map(|id| id.updn);
+    *record = rebuild(record.clone())?;
+    Ok(())
+}
+
+/// Recount the feature and vector records declared in DSSI
+fn refresh_dssi(ddr: &DDR, records: &mut [Record]) -> Result<()> {
+    let mut counts: HashMap<&str, u32> = HashMap::new();
+    for record in records.iter() {
+        for field in &record.fields {
+            let label = match field.tag.as_str() {
+                "FRID" => {
+                    let Ok(parsed) = ddr.parse_field_data(field) else {
+                        continue;
+                    };
+                    match uint(&parsed, "OBJL").unwrap_or(0) {
+                        300..=399 => "NOMR",
+                        400..=499 => "NOLR",
+                        500..=599 => "NOCR",
+                        _ => "NOGR",
+                    }
+                }
+                "VRID" => {
+                    let Ok(parsed) = ddr.parse_field_data(field) else {
+                        continue;
+                    };
+                    match uint(&parsed, "RCNM").unwrap_or(0) {
+                        110 => "NOIN",
+                        120 => "NOCN",
+                        130 => "NOED",
+                        140 => "NOFA",
+                        _ => continue,
+                    }
+                }
+                _ => continue,
+            };
+            *counts.entry(label).or_default() += 1;
+        }
+    }
+
+    for record in records.iter_mut() {
+        let Some(field) = record.fields.iter_mut().find(|f| f.tag == "DSSI") else {
+            continue;
+        };
+        for label in [
+            "NOMR", "NOCR", "NOGR", "NOLR", "NOIN", "NOCN", "NOED", "NOFA",
+        ] {
+            write_subfield(ddr, field, label, counts.get(label).copied().unwrap_or(0))?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::iso8211::write_file;
+
+    /// Build a cell from records the way it would arrive on disk
+    fn file(records: Vec<Record>) -> S57File {
+        S57File::from_bytes(&write_file(&records).unwrap()).unwrap()
+    }
+
+    fn dsid(dsnm: &str, expp: u8, updn: &str) -> Record {
+        let mut data = vec![10, 1, 0, 0, 0, expp, 1];
+        for text in [dsnm, "1", updn] {
+            data.extend_from_slice(text.as_bytes());
+            data.push(0x1F);
+        }
+        data.extend_from_slice(b"2026010120260101");
+        data.extend_from_slice(b"03.1");
+        data.extend_from_slice(&[1, 0x1F, 0x1F, 1, 0x26, 0x02, 0x1F, 0x1E]);
+        let mut dssi = vec![2, 1, 1];
+        dssi.extend_from_slice(&[0; 32]);
+        dssi.push(0x1E);
+        Record::from_fields([("DSID", data), ("DSSI", dssi)])
+    }
+
+    /// DSPM in WGS 84 with metre depths and heights
+    fn dspm(cscl: u32) -> Vec<u8> {
+        let mut data = vec![20];
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(&[2, 17, 23]);
+        data.extend_from_slice(&cscl.to_le_bytes());
+        data.extend_from_slice(&[1, 1, 1, 1]);
+        data.extend_from_slice(&10_000_000u32.to_le_bytes());
+        data.extend_from_slice(&10u32.to_le_bytes());
+        data.extend_from_slice(&[0x1F, 0x1E]);
+        data
+    }
+
+    fn frid(rcid: u32, objl: u16, rver: u16, ruin: u8) -> Vec<u8> {
+        let mut data = vec![100];
+        data.extend_from_slice(&rcid.to_le_bytes());
+        data.extend_from_slice(&[3, 1]);
+        data.extend_from_slice(&objl.to_le_bytes());
+        data.extend_from_slice(&rver.to_le_bytes());
+        data.extend_from_slice(&[ruin, 0x1E]);
+        data
+    }
+
+    fn fspt(rcids: &[u32]) -> Vec<u8> {
+        let mut data = Vec::new();
+        for rcid in rcids {
+            data.push(130);
+            data.extend_from_slice(&rcid.to_le_bytes());
+            data.extend_from_slice(&[1, 255, 255]);
+        }
+        data.push(0x1E);
+        data
+    }
+
+    fn base() -> S57File {
+        file(vec![
+            dsid("GB100001.000", 1, "0"),
+            Record::from_fields([
+                ("FRID", frid(1, 42, 1, 1)),
+                ("ATTF", b"\x01\x00a\x1f\x02\x00b\x1f\x1e".to_vec()),
+                ("FSPT", fspt(&[7, 8])),
+            ]),
+            Record::from_fields([("FRID", frid(2, 42, 1, 1))]),
+        ])
+    }
+
+    fn first_update() -> S57File {
+        file(vec![
+            dsid("GB100001.000", 2, "1"),
+            Record::from_fields([
+                ("FRID", frid(1, 42, 2, 3)),
+                ("ATTF", b"\x01\x00\x7f\x1f\x03\x00c\x1f\x1e".to_vec()),
+                ("FSPC", vec![1, 3, 0, 1, 0, 0x1E]),
+                ("FSPT", fspt(&[9])),
+            ]),
+            Record::from_fields([("FRID", frid(2, 42, 2, 2))]),
+            Record::from_fields([("FRID", frid(3, 305, 1, 1))]),
+        ])
+    }
+
+    fn feature_record(cell: &S57File, rcid: u32) -> Option<&Record> {
+        let frid = frid(rcid, 0, 0, 0);
+        cell.records().iter().find(|r| {
+            r.fields
+                .iter()
+                .any(|f| f.tag == "FRID" && f.data[..5] == frid[..5])
+        })
+    }
+
+    fn field<'a>(cell: &'a S57File, rcid: u32, tag: &str) -> Option<&'a Field> {
+        feature_record(cell, rcid)?
+            .fields
+            .iter()
+            .find(|f| f.tag == tag)
+    }
+
+    #[test]
+    fn test_apply_update() {
+        let merged = apply_updates(&base(), &[first_update()]).unwrap();
+
+        // Reads back as a well-formed file
+        let merged = S57File::from_bytes(&merged.to_bytes().unwrap()).unwrap();
+        assert_eq!(merged.records().len(), 3);
+
+        // Modified: attribute 1 deleted, 2 kept, 3 added; FSPT appended
+        assert_eq!(
+            field(&merged, 1, "ATTF").unwrap().data,
+            b"\x02\x00b\x1f\x03\x00c\x1f\x1e"
+        );
+        assert_eq!(field(&merged, 1, "FSPT").unwrap().data, fspt(&[7, 8, 9]));
+        assert_eq!(field(&merged, 1, "FRID").unwrap().data[9..11], [2, 0]);
+        assert!(field(&merged, 1, "FSPC").is_none());
+
+        // Deleted and inserted
+        assert!(field(&merged, 2, "FRID").is_none());
+        assert!(field(&merged, 3, "FRID").is_some());
+
+        // DSID is the update's apart from EXPP; DSSI is recounted
+        let ds = Dataset::new(merged.records());
+        let id = ds.dsid().unwrap();
+        assert_eq!(id.updn, 1);
+        assert_eq!(field_by_tag(&merged, "DSID").data[5], 1);
+        let dssi = &field_by_tag(&merged, "DSSI").data;
+        assert_eq!(dssi[3..7], [1, 0, 0, 0]); // NOMR: the 305 feature
+        assert_eq!(dssi[11..15], [1, 0, 0, 0]); // NOGR
+
+        // A modify that replaces and one that deletes pointer groups
+        let second = file(vec![
+            dsid("GB100001.000", 2, "2"),
+            Record::from_fields([
+                ("FRID", frid(1, 42, 3, 3)),
+                ("FSPC", vec![3, 1, 0, 1, 0, 0x1E]),
+                ("FSPT", fspt(&[6])),
+            ]),
+            Record::from_fields([
+                ("FRID", frid(3, 305, 2, 3)),
+                ("ATTF", b"\x05\x00x\x1f\x1e".to_vec()),
+            ]),
+        ]);
+        let third = file(vec![
+            dsid("GB100001.000", 2, "3"),
+            Record::from_fields([
+                ("FRID", frid(1, 42, 4, 3)),
+                ("FSPC", vec![2, 2, 0, 2, 0, 0x1E]),
+            ]),
+        ]);
+        let mut cell = merged;
+        cell.apply_update(&second).unwrap();
+        assert_eq!(field(&cell, 1, "FSPT").unwrap().data, fspt(&[6, 8, 9]));
+        // Changed fields no longer match the bytes of any file
+        assert!(field(&cell, 1, "FSPT").unwrap().span().is_none());
+        assert!(field_by_tag(&cell, "DSSI").span().is_none());
+        assert_eq!(field(&cell, 3, "ATTF").unwrap().data, b"\x05\x00x\x1f\x1e");
+        cell.apply_update(&third).unwrap();
+        assert_eq!(field(&cell, 1, "FSPT").unwrap().data, fspt(&[6]));
+
+        // Each record names the update that last changed it
+        let update = |rcid| feature_record(&cell, rcid).unwrap().update;
+        assert_eq!((update(1), update(3)), (Some(3), Some(2)));
+    }
+
+    fn foid(fidn: u32) -> Vec<u8> {
+        let mut data = 550u16.to_le_bytes().to_vec();
+        data.extend_from_slice(&fidn.to_le_bytes());
+        data.extend_from_slice(&1u16.to_le_bytes());
+        data
+    }
+
+    /// FFPT pointing at features by FIDN, each as a slave
+    fn ffpt(fidns: &[u32]) -> Vec<u8> {
+        let mut data = Vec::new();
+        for fidn in fidns {
+            data.extend(foid(*fidn));
+            data.extend_from_slice(&[2, 0x1F]);
+        }
+        data.push(0x1E);
+        data
+    }
+
+    #[test]
+    fn test_feature_pointer_updates() {
+        let with_terminator = |mut data: Vec<u8>| {
+            data.push(0x1E);
+            data
+        };
+        let cell = file(vec![
+            dsid("GB100001.000", 1, "0"),
+            Record::from_fields([
+                ("FRID", frid(1, 17, 1, 1)),
+                ("FOID", with_terminator(foid(1))),
+            ]),
+            Record::from_fields([
+                ("FRID", frid(2, 17, 1, 1)),
+                ("FOID", with_terminator(foid(2))),
+                ("FFPT", ffpt(&[1, 3])),
+            ]),
+            Record::from_fields([
+                ("FRID", frid(3, 75, 1, 1)),
+                ("FOID", with_terminator(foid(3))),
+            ]),
+        ]);
+        // Deleting feature 1 without an FFPC still drops the pointer to it
+        let first = file(vec![
+            dsid("GB100001.000", 2, "1"),
+            Record::from_fields([("FRID", frid(1, 17, 2, 2))]),
+        ]);
+        // Then an FFPC deletes the remaining pointer
+        let second = file(vec![
+            dsid("GB100001.000", 2, "2"),
+            Record::from_fields([
+                ("FRID", frid(2, 17, 2, 3)),
+                ("FFPC", vec![2, 1, 0, 1, 0, 0x1E]),
+            ]),
+        ]);
+
+        let mut merged = apply_updates(&cell, &[first]).unwrap();
+        assert_eq!(field(&merged, 2, "FFPT").unwrap().data, ffpt(&[3]));
+        // The pointing record keeps its version for later updates
+        assert_eq!(field(&merged, 2, "FRID").unwrap().data[9..11], [1, 0]);
+
+        merged.apply_update(&second).unwrap();
+        assert!(field(&merged, 2, "FFPT").is_none());
+    }
+
+    fn vrid(rcnm: u8, rcid: u32, rver: u16, ruin: u8) -> Vec<u8> {
+        let mut data = vec![rcnm];
+        data.extend_from_slice(&rcid.to_le_bytes());
+        data.extend_from_slice(&rver.to_le_bytes());
+        data.extend_from_slice(&[ruin, 0x1E]);
+        data
+    }
+
+    /// SG2D from (y, x) pairs
+    fn sg2d(coordinates: &[(i32, i32)]) -> Vec<u8> {
+        let mut data = Vec::new();
+        for (y, x) in coordinates {
+            data.extend_from_slice(&y.to_le_bytes());
+            data.extend_from_slice(&x.to_le_bytes());
+        }
+        data.push(0x1E);
+        data
+    }
+
+    #[test]
+    fn test_coordinate_updates() {
+        let cell = file(vec![
+            dsid("GB100001.000", 1, "0"),
+            Record::from_fields([
+                ("VRID", vrid(130, 1, 1, 1)),
+                ("SG2D", sg2d(&[(1, 1), (2, 2), (3, 3)])),
+            ]),
+        ]);
+        let update = |updn: &str, rver: u16, sgcc: [u8; 5], coordinates: &[(i32, i32)]| {
+            let mut fields = vec![
+                ("VRID", vrid(130, 1, rver, 3)),
+                ("SGCC", [&sgcc[..], &[0x1E]].concat()),
+            ];
+            if !coordinates.is_empty() {
+                fields.push(("SG2D", sg2d(coordinates)));
+            }
+            file(vec![
+                dsid("GB100001.000", 2, updn),
+                Record::from_fields(fields),
+            ])
+        };
+        let coordinates = |cell: &S57File| {
+            cell.records()
+                .iter()
+                .flat_map(|r| &r.fields)
+                .find(|f| f.tag == "SG2D")
+                .map(|f| f.data.clone())
+        };
+
+        // Insert two before the second, replace the fourth, delete the first
+        let mut cell =
+            apply_updates(&cell, &[update("1", 2, [1, 2, 0, 2, 0], &[(5, 5), (6, 6)])]).unwrap();
+        assert_eq!(
+            coordinates(&cell),
+            Some(sg2d(&[(1, 1), (5, 5), (6, 6), (2, 2), (3, 3)]))
+        );
+        cell.apply_update(&update("2", 3, [3, 4, 0, 1, 0], &[(7, 7)]))
+            .unwrap();
+        cell.apply_update(&update("3", 4, [2, 1, 0, 1, 0], &[]))
+            .unwrap();
+        assert_eq!(
+            coordinates(&cell),
+            Some(sg2d(&[(5, 5), (6, 6), (7, 7), (3, 3)]))
+        );
+
+        // Past the end is rejected and leaves the cell alone
+        assert_rejected(
+            &cell,
+            &update("4", 5, [2, 4, 0, 2, 0], &[]),
+            "SGCC: groups 4..6 out of range for a field of 4",
+        );
+
+        // Deleting every coordinate drops the field
+        cell.apply_update(&update("4", 5, [2, 1, 0, 4, 0], &[]))
+            .unwrap();
+        assert_eq!(coordinates(&cell), None);
+    }
+
+    /// VRPT pointers to connected nodes: (RCID, TOPI)
+    fn vrpt(nodes: &[(u32, u8)]) -> Vec<u8> {
+        let mut data = Vec::new();
+        for (rcid, topi) in nodes {
+            data.push(120);
+            data.extend_from_slice(&rcid.to_le_bytes());
+            data.extend_from_slice(&[255, 255, *topi, 255]);
+        }
+        data.push(0x1E);
+        data
+    }
+
+    #[test]
+    fn test_edge_pointer_updates() {
+        let node = |rcid| {
+            Record::from_fields([("VRID", vrid(120, rcid, 1, 1)), ("SG2D", sg2d(&[(0, 0)]))])
+        };
+        let cell = file(vec![
+            dsid("GB100001.000", 1, "0"),
+            node(1),
+            node(2),
+            node(3),
+            Record::from_fields([
+                ("VRID", vrid(130, 10, 1, 1)),
+                ("VRPT", vrpt(&[(1, 1), (2, 2)])),
+            ]),
+        ]);
+        let update = |updn: &str, rver: u16, vrpc: [u8; 5], nodes: &[(u32, u8)]| {
+            let mut fields = vec![
+                ("VRID", vrid(130, 10, rver, 3)),
+                ("VRPC", [&vrpc[..], &[0x1E]].concat()),
+            ];
+            if !nodes.is_empty() {
+                fields.push(("VRPT", vrpt(nodes)));
+            }
+            file(vec![
+                dsid("GB100001.000", 2, updn),
+                Record::from_fields(fields),
+            ])
+        };
+        let pointers = |cell: &S57File| field_by_tag(cell, "VRPT").data.clone();
+
+        // Move the end of the edge from node 2 to node 3
+        let mut cell = apply_updates(&cell, &[update("1", 2, [3, 2, 0, 1, 0], &[(3, 2)])]).unwrap();
+        assert_eq!(pointers(&cell), vrpt(&[(1, 1), (3, 2)]));
+
+        // An edge can't lose its beginning node or end on a missing one
+        assert_rejected(
+            &cell,
+            &update("2", 3, [2, 1, 0, 1, 0], &[]),
+            "VRPC leaves the edge with 0 beginning nodes",
+        );
+        assert_rejected(
+            &cell,
+            &update("2", 3, [1, 3, 0, 1, 0], &[(2, 2)]),
+            "VRPC leaves the edge with 2 end nodes",
+        );
+        let dangling = update("2", 3, [3, 1, 0, 1, 0], &[(9, 1)]);
+        assert_rejected(&cell, &dangling, "connected node the cell doesn't have");
+
+        // Skipped, the edge keeps its nodes and version
+        let (merged, report) =
+            apply_updates_with(&cell, std::slice::from_ref(&dangling), ConflictPolicy::Skip)
+                .unwrap();
+        assert_eq!(report.conflicts.len(), 1);
+        assert_eq!(pointers(&merged), vrpt(&[(1, 1), (3, 2)]));
+
+        // Replacing the beginning node in place is fine
+        cell.apply_update(&update("2", 3, [3, 1, 0, 1, 0], &[(2, 1)]))
+            .unwrap();
+        assert_eq!(pointers(&cell), vrpt(&[(2, 1), (3, 2)]));
+    }
+
+    #[test]
+    fn test_attribute_deletes() {
+        let update = file(vec![
+            dsid("GB100001.000", 2, "1"),
+            Record::from_fields([
+                ("FRID", frid(1, 42, 2, 3)),
+                // 1 deleted, 2 emptied (value unknown), 3 not there to delete,
+                // 4 added with a value that only starts with DEL
+                (
+                    "ATTF",
+                    b"\x01\x00\x7f\x1f\x02\x00\x1f\x03\x00\x7f\x1f\x04\x00\x7fx\x1f\x1e".to_vec(),
+                ),
+            ]),
+            Record::from_fields([
+                ("FRID", frid(2, 42, 2, 3)),
+                ("ATTF", b"\x07\x00\x7f\x1f\x1e".to_vec()),
+            ]),
+        ]);
+        let merged = apply_updates(&base(), &[update]).unwrap();
+        assert_eq!(
+            field(&merged, 1, "ATTF").unwrap().data,
+            b"\x02\x00\x1f\x04\x00\x7fx\x1f\x1e"
+        );
+        // Deleting from a feature without attributes adds no ATTF
+        assert!(field(&merged, 2, "ATTF").is_none());
+
+        // UCS-2 DEL (lexical level 2) deletes; a character ending in 0x7F doesn't
+        assert!(is_delete(b"\x7f\x00\x1f\x00"));
+        assert!(!is_delete(b"\x7f\x01\x1f\x00"));
+    }
+
+    #[test]
+    fn test_ucs2_attribute_updates() {
+        // NALL 2: NATF values and terminators are two bytes wide
+        let header = |expp, updn| {
+            let mut record = dsid("GB100001.000", expp, updn);
+            for field in record.fields.iter_mut().filter(|f| f.tag == "DSSI") {
+                field.data[2] = 2;
+            }
+            record
+        };
+        // NOBJNM (301) "Ἁ" (0x1F09) holds a 0x1F byte; NINFOM (302) "A"
+        let nobjnm = b"\x2d\x01\x09\x1f\x1f\x00".to_vec();
+        let natf = [nobjnm.clone(), b"\x2e\x01A\x00\x1f\x00\x1e".to_vec()].concat();
+        let base = file(vec![
+            header(1, "0"),
+            Record::from_fields([("FRID", frid(1, 42, 1, 1)), ("NATF", natf)]),
+        ]);
+        // UCS-2 DEL removes NINFOM and leaves NOBJNM whole
+        let update = file(vec![
+            header(2, "1"),
+            Record::from_fields([
+                ("FRID", frid(1, 42, 2, 3)),
+                ("NATF", b"\x2e\x01\x7f\x00\x1f\x00\x1e".to_vec()),
+            ]),
+        ]);
+        let merged = apply_updates(&base, &[update]).unwrap();
+        assert_eq!(
+            field(&merged, 1, "NATF").unwrap().data,
+            [nobjnm, vec![0x1E]].concat()
+        );
+    }
+
+    fn field_by_tag<'a>(cell: &'a S57File, tag: &str) -> &'a Field {
+        cell.records()
+            .iter()
+            .flat_map(|r| &r.fields)
+            .find(|f| f.tag == tag)
+            .unwrap()
+    }
+
+    fn assert_rejected(cell: &S57File, update: &S57File, message: &str) {
+        let mut copy = S57File {
+            records: cell.records.clone(),
+        };
+        let err = copy.apply_update(update).unwrap_err();
+        match &err.kind {
+            ParseErrorKind::InvalidUpdate(m) => {
+                assert!(m.contains(message), "{:?} lacks {:?}", m, message)
+            }
+            other => panic!("unexpected error {:?}", other),
+        }
+        // Nothing was applied
+        assert_eq!(copy.to_bytes().unwrap(), cell.to_bytes().unwrap());
+    }
+
+    #[test]
+    fn test_invalid_updates() {
+        let cell = base();
+
+        // Sequencing
+        let wrong_cell = file(vec![dsid("GB100002.000", 2, "1")]);
+        assert_rejected(&cell, &wrong_cell, "not GB100001.000");
+        let skipped = file(vec![dsid("GB100001.000", 2, "2")]);
+        assert_rejected(&cell, &skipped, "does not follow update 0");
+        let merged = apply_updates(&cell, &[first_update()]).unwrap();
+        assert_rejected(&merged, &first_update(), "does not follow update 1");
+
+        // Producing agency (AGEN is the DSID's second-to-last subfield)
+        let mut foreign = dsid("GB100001.000", 2, "1");
+        let agen = foreign.fields[0].data.len() - 4;
+        foreign.fields[0].data[agen..agen + 2].copy_from_slice(&540u16.to_le_bytes());
+        assert_rejected(
+            &cell,
+            &file(vec![foreign]),
+            "from agency 540, cell is from agency 550",
+        );
+
+        // Dataset parameters, when the update repeats them
+        let scaled = file(vec![
+            dsid("GB100001.000", 1, "0"),
+            Record::from_fields([("DSPM", dspm(22_000))]),
+        ]);
+        let rescaled = file(vec![
+            dsid("GB100001.000", 2, "1"),
+            Record::from_fields([("DSPM", dspm(45_000))]),
+        ]);
+        assert_rejected(
+            &scaled,
+            &rescaled,
+            "update 1 changes DSPM: CSCL 22000 -> 45000",
+        );
+        let repeated = file(vec![
+            dsid("GB100001.000", 2, "1"),
+            Record::from_fields([("DSPM", dspm(22_000))]),
+        ]);
+        apply_updates(&scaled, &[repeated]).unwrap();
+
+        // Record versions and targets
+        let stale = file(vec![
+            dsid("GB100001.000", 2, "1"),
+            Record::from_fields([("FRID", frid(1, 42, 3, 2))]),
+        ]);
+        assert_rejected(&cell, &stale, "has RVER 3, expected 2");
+        let missing = file(vec![
+            dsid("GB100001.000", 2, "1"),
+            Record::from_fields([("FRID", frid(9, 42, 2, 3))]),
+        ]);
+        assert_rejected(&cell, &missing, "no target for record 100/9");
+        let duplicate = file(vec![
+            dsid("GB100001.000", 2, "1"),
+            Record::from_fields([("FRID", frid(2, 42, 1, 1))]),
+        ]);
+        assert_rejected(&cell, &duplicate, "insert of existing");
+        let out_of_range = file(vec![
+            dsid("GB100001.000", 2, "1"),
+            Record::from_fields([
+                ("FRID", frid(1, 42, 2, 3)),
+                ("FSPC", vec![2, 2, 0, 5, 0, 0x1E]),
+            ]),
+        ]);
+        assert_rejected(&cell, &out_of_range, "out of range");
+    }
+
+    #[test]
+    fn test_update_conflicts() {
+        let cell = base();
+        let update = file(vec![
+            dsid("GB100001.000", 2, "1"),
+            // Deletes record 1 as if it were still at RVER 2
+            Record::from_fields([("FRID", frid(1, 42, 3, 2))]),
+            Record::from_fields([("FRID", frid(9, 42, 2, 3))]),
+            Record::from_fields([("FRID", frid(2, 43, 1, 1))]),
+            Record::from_fields([("FRID", frid(3, 305, 1, 1))]),
+        ]);
+        assert_rejected(&cell, &update, "deletion of record 100/1 has RVER 3");
+
+        let (skipped, report) =
+            apply_updates_with(&cell, std::slice::from_ref(&update), ConflictPolicy::Skip).unwrap();
+        let kinds: Vec<_> = report.conflicts.iter().map(|c| &c.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                &ConflictKind::VersionMismatch {
+                    ruin: 2,
+                    expected: 2,
+                    found: 3
+                },
+                &ConflictKind::MissingTarget,
+                &ConflictKind::DuplicateInsert,
+            ]
+        );
+        assert!(report
+            .conflicts
+            .iter()
+            .all(|c| c.resolution == Resolution::Skipped));
+        assert_eq!(
+            report.conflicts[1].to_string(),
+            "update 1: no target for record 100/9"
+        );
+        assert!(field(&skipped, 1, "FRID").is_some());
+        assert_eq!(field(&skipped, 2, "FRID").unwrap().data[7], 42);
+        assert!(field(&skipped, 3, "FRID").is_some());
+
+        // Forcing deletes record 1 and replaces record 2; the missing target
+        // can only be skipped
+        let (forced, report) = apply_updates_with(&cell, &[update], ConflictPolicy::Force).unwrap();
+        let resolutions: Vec<_> = report.conflicts.iter().map(|c| c.resolution).collect();
+        assert_eq!(
+            resolutions,
+            [Resolution::Forced, Resolution::Skipped, Resolution::Forced]
+        );
+        assert!(field(&forced, 1, "FRID").is_none());
+        assert_eq!(field(&forced, 2, "FRID").unwrap().data[7], 43);
+        assert_eq!(forced.records().len(), 3);
+    }
+
+    #[test]
+    #[cfg(feature = "fs")]
+    fn test_update_paths() {
+        let dir = std::env::temp_dir().join(format!("s57-update-paths-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        for name in ["CELL.000", "CELL.001", "CELL.002", "CELL.004"] {
+            std::fs::write(dir.join(name), b"").unwrap();
+        }
+        let paths = update_paths(&dir.join("CELL.000"));
+        assert_eq!(paths, vec![dir.join("CELL.001"), dir.join("CELL.002")]);
+        assert!(update_paths(&dir.join("CELL.001")).is_empty());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
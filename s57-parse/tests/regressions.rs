@@ -0,0 +1,94 @@
+//! Regression corpus: malformed inputs the parser must reject without panicking
+//!
+//! Every file in `tests/corpus` is a minimized input, usually a fuzzing
+//! find. Each one is parsed as an ISO 8211 file and every field of every
+//! data record is decoded against the file's DDR (or the built-in field
+//! definitions when the DDR is unusable). Errors are fine; a panic fails the
+//! test and names the input.
+//!
+//! To keep a new finding covered, drop its minimized input into
+//! `tests/corpus` under a name saying what is wrong with it. To run a
+//! fuzzer's whole output directory once, point `S57_REGRESSION_CORPUS` at it.
+
+use s57_parse::ddr::DDR;
+use s57_parse::S57File;
+use std::panic;
+use std::path::{Path, PathBuf};
+
+/// Parse and decode everything the parser can reach in `data`
+fn exercise(data: &[u8]) {
+    let Ok(file) = S57File::from_bytes(data) else {
+        return;
+    };
+    let records = file.records();
+    let ddr = records
+        .first()
+        .filter(|r| r.leader.is_ddr())
+        .and_then(|r| DDR::parse(r).ok())
+        .unwrap_or_else(DDR::canonical);
+    for record in records.iter().filter(|r| !r.leader.is_ddr()) {
+        for field in &record.fields {
+            if let Ok(parsed) = ddr.parse_field_data(field) {
+                parsed.is_well_formed();
+                ddr.trailing_bytes(field);
+            }
+        }
+    }
+}
+
+/// Files in `dir`, sorted so failures are reported in a stable order
+fn corpus_files(dir: &Path) -> Vec<PathBuf> {
+    let entries = std::fs::read_dir(dir)
+        .unwrap_or_else(|e| panic!("reading corpus {}: {}", dir.display(), e));
+    let mut files: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_file())
+        .collect();
+    files.sort();
+    files
+}
+
+/// Run every input in `dir`, returning the ones that panicked
+fn run_corpus(dir: &Path) -> Vec<PathBuf> {
+    let mut panicked = Vec::new();
+    for path in corpus_files(dir) {
+        let data =
+            std::fs::read(&path).unwrap_or_else(|e| panic!("reading {}: {}", path.display(), e));
+        if panic::catch_unwind(|| exercise(&data)).is_err() {
+            panicked.push(path);
+        }
+    }
+    panicked
+}
+
+fn assert_no_panics(dir: &Path) {
+    let panicked = run_corpus(dir);
+    assert!(
+        panicked.is_empty(),
+        "parser panicked on {} input(s):\n{}",
+        panicked.len(),
+        panicked
+            .iter()
+            .map(|path| format!("  {}", path.display()))
+            .collect::<Vec<_>>()
+            .join("\n")
+    );
+}
+
+#[test]
+fn test_regression_corpus() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/corpus");
+    assert!(
+        !corpus_files(&dir).is_empty(),
+        "regression corpus {} is empty",
+        dir.display()
+    );
+    assert_no_panics(&dir);
+}
+
+#[test]
+fn test_external_corpus() {
+    if let Some(dir) = std::env::var_os("S57_REGRESSION_CORPUS") {
+        assert_no_panics(Path::new(&dir));
+    }
+}
@@ -0,0 +1,72 @@
+//! WebAssembly bindings for parsing cells in the browser
+//!
+//! Built for `wasm32-unknown-unknown` with wasm-bindgen, e.g.
+//! `wasm-pack build s57-wasm --target web`. The parser and World are used
+//! without their `fs` feature, so nothing touches a file system: the
+//! caller fetches the cell and hands over its bytes.
+//!
+//! ```js
+//! import init, { parseCell } from "./pkg/s57_wasm.js";
+//!
+//! await init();
+//! const bytes = new Uint8Array(await (await fetch("US5MA22M.000")).arrayBuffer());
+//! const collection = parseCell(bytes); // GeoJSON FeatureCollection
+//! ```
+
+use s57_interp::geojson::{to_geojson, GeoJsonOptions};
+use s57_parse::S57File;
+use serde_json::Value;
+use wasm_bindgen::prelude::*;
+
+/// Parse a base cell and convert it to an RFC 7946 FeatureCollection
+///
+/// Errors name what failed (parsing or building the World) and why.
+pub fn cell_to_geojson(bytes: &[u8]) -> Result<Value, String> {
+    let file = S57File::from_bytes(bytes).map_err(|e| format!("parse error: {}", e))?;
+    let world = s57_interp::build_world(&file).map_err(|e| format!("build error: {}", e))?;
+    Ok(to_geojson(&world, &GeoJsonOptions::new()))
+}
+
+/// Parse a cell's bytes into a GeoJSON FeatureCollection object
+///
+/// Throws an `Error` if the bytes aren't a readable S-57 cell.
+#[wasm_bindgen(js_name = parseCell)]
+pub fn parse_cell(bytes: &[u8]) -> Result<JsValue, JsError> {
+    let collection = cell_to_geojson(bytes).map_err(|e| JsError::new(&e))?;
+    js_sys::JSON::parse(&collection.to_string())
+        .map_err(|_| JsError::new("GeoJSON could not be handed to JavaScript"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use s57_parse::iso8211::{write_file, Field, Record};
+
+    #[test]
+    fn test_cell_to_geojson() {
+        // A LNDMRK (OBJL 74) without geometry
+        let mut frid = vec![100];
+        frid.extend(1u32.to_le_bytes());
+        frid.extend([255, 2]);
+        frid.extend(74u16.to_le_bytes());
+        frid.extend([1, 0, 1, 0x1E]);
+        let mut foid = 550u16.to_le_bytes().to_vec();
+        foid.extend(1u32.to_le_bytes());
+        foid.extend(1u16.to_le_bytes());
+        foid.push(0x1E);
+        let cell = write_file(&[Record::from_fields(vec![
+            Field::new("FRID".to_string(), frid),
+            Field::new("FOID".to_string(), foid),
+        ])])
+        .unwrap();
+
+        let collection = cell_to_geojson(&cell).unwrap();
+        assert_eq!(collection["type"], "FeatureCollection");
+        let feature = &collection["features"][0];
+        assert_eq!(feature["id"], "550:1:1");
+        assert_eq!(feature["properties"]["OBJL"], "LNDMRK");
+
+        let error = cell_to_geojson(b"not a cell").unwrap_err();
+        assert!(error.starts_with("parse error: "), "{}", error);
+    }
+}